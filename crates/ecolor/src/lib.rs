@@ -7,6 +7,7 @@
 //! If you want a compact color representation, use [`Color32`].
 //! If you want to manipulate RGBA colors in linear space use [`Rgba`].
 //! If you want to manipulate colors in a way closer to how humans think about colors, use [`HsvaGamma`].
+//! If you want perceptually uniform lightness, use [`Oklcha`].
 //!
 //! ## Conventions
 //! The word "gamma" or "srgb" is used to refer to values in the non-linear space defined by
@@ -33,6 +34,9 @@ pub use hsva_gamma::*;
 mod hsva;
 pub use hsva::*;
 
+mod oklcha;
+pub use oklcha::*;
+
 #[cfg(feature = "color-hex")]
 mod hex_color_macro;
 #[cfg(feature = "color-hex")]
@@ -0,0 +1,189 @@
+use crate::{Color32, Rgba};
+
+/// Lightness, chroma, hue and alpha in the perceptual `OKLCH` color space.
+/// No premultiplied alpha.
+///
+/// This is the polar form of [Oklab](https://bottosson.github.io/posts/oklab/),
+/// where equal steps in lightness look like equal steps to a human,
+/// and changing the hue does not change the perceived lightness.
+///
+/// Not all `OKLCH` colors can be shown on a screen:
+/// when converting to RGB, the channels are clamped to the sRGB gamut.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklcha {
+    /// perceived lightness 0-1
+    pub l: f32,
+
+    /// chroma, i.e. colorfulness, from 0 and up.
+    /// See [`Self::MAX_SRGB_CHROMA`].
+    pub c: f32,
+
+    /// hue 0-1
+    pub h: f32,
+
+    /// alpha 0-1. A negative value signifies an additive color (and alpha is ignored).
+    pub a: f32,
+}
+
+impl Oklcha {
+    /// A chroma that is enough to reach all the colors of sRGB.
+    pub const MAX_SRGB_CHROMA: f32 = 0.37;
+
+    #[inline]
+    pub fn new(l: f32, c: f32, h: f32, a: f32) -> Self {
+        Self { l, c, h, a }
+    }
+
+    /// From linear RGBA without premultiplied alpha
+    #[inline]
+    pub fn from_rgba_unmultiplied(r: f32, g: f32, b: f32, a: f32) -> Self {
+        let (l, c, h) = oklch_from_rgb([r, g, b]);
+        Self { l, c, h, a }
+    }
+
+    /// To linear space rgba in 0-1 range, clamped to the sRGB gamut.
+    ///
+    /// Represents additive colors using a negative alpha.
+    #[inline]
+    pub fn to_rgba_unmultiplied(&self) -> [f32; 4] {
+        let Self { l, c, h, a } = *self;
+        let [r, g, b] = rgb_from_oklch((l, c, h)).map(|x| x.clamp(0.0, 1.0));
+        [r, g, b, a]
+    }
+
+    #[inline]
+    pub fn to_rgba_premultiplied(&self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_rgba_unmultiplied();
+        let additive = a < 0.0;
+        if additive {
+            [r, g, b, 0.0]
+        } else {
+            [a * r, a * g, a * b, a]
+        }
+    }
+
+    /// Can this color be shown without clamping it to sRGB?
+    #[inline]
+    pub fn is_in_srgb_gamut(&self) -> bool {
+        const EPSILON: f32 = 1e-4;
+        rgb_from_oklch((self.l, self.c, self.h))
+            .iter()
+            .all(|x| (-EPSILON..=1.0 + EPSILON).contains(x))
+    }
+}
+
+impl From<Oklcha> for Rgba {
+    #[inline]
+    fn from(oklcha: Oklcha) -> Self {
+        Self(oklcha.to_rgba_premultiplied())
+    }
+}
+
+impl From<Rgba> for Oklcha {
+    #[inline]
+    fn from(rgba: Rgba) -> Self {
+        let [r, g, b, a] = rgba.0;
+        if a <= 0.0 {
+            // Additive, or completely transparent
+            Self {
+                a: if r == 0.0 && g == 0.0 && b == 0.0 {
+                    0.0
+                } else {
+                    -0.5
+                },
+                ..Self::from_rgba_unmultiplied(r, g, b, 1.0)
+            }
+        } else {
+            Self::from_rgba_unmultiplied(r / a, g / a, b / a, a)
+        }
+    }
+}
+
+impl From<Oklcha> for Color32 {
+    #[inline]
+    fn from(oklcha: Oklcha) -> Self {
+        Self::from(Rgba::from(oklcha))
+    }
+}
+
+impl From<Color32> for Oklcha {
+    #[inline]
+    fn from(srgba: Color32) -> Self {
+        Self::from(Rgba::from(srgba))
+    }
+}
+
+/// From linear rgb in 0-1 range to `OKLCH`, with the hue in 0-1.
+#[inline]
+pub fn oklch_from_rgb([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    #![allow(clippy::many_single_char_names)]
+    let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let [l, m, s] = [l, m, s].map(f32::cbrt);
+
+    let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+    let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+    let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+    let chroma = a.hypot(b);
+    let hue = if chroma < 1e-6 {
+        0.0 // hue is undefined
+    } else {
+        (b.atan2(a) / std::f32::consts::TAU + 1.0).fract()
+    };
+    (lightness, chroma, hue)
+}
+
+/// From `OKLCH`, with the hue in 0-1, to linear rgb.
+///
+/// The result may be outside of the 0-1 range for colors outside of the sRGB gamut.
+#[inline]
+pub fn rgb_from_oklch((lightness, chroma, hue): (f32, f32, f32)) -> [f32; 3] {
+    #![allow(clippy::many_single_char_names)]
+    let (sin, cos) = (hue * std::f32::consts::TAU).sin_cos();
+    let a = chroma * cos;
+    let b = chroma * sin;
+
+    let l = lightness + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m = lightness - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s = lightness - 0.089_484_18 * a - 1.291_485_5 * b;
+
+    let [l, m, s] = [l, m, s].map(|x| x * x * x);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+#[test]
+fn test_oklch_known_values() {
+    let (l, c, _) = oklch_from_rgb([1.0, 1.0, 1.0]);
+    assert!((l - 1.0).abs() < 1e-4, "White has full lightness, got {l}");
+    assert!(c < 1e-4, "White has no chroma, got {c}");
+
+    let (l, c, h) = oklch_from_rgb([1.0, 0.0, 0.0]);
+    assert!((l - 0.628).abs() < 1e-3, "Red lightness: {l}");
+    assert!((c - 0.258).abs() < 1e-3, "Red chroma: {c}");
+    assert!((h * 360.0 - 29.23).abs() < 0.1, "Red hue: {}", h * 360.0);
+}
+
+#[test]
+fn test_oklch_roundtrip() {
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(15) {
+            for b in (0..=255).step_by(15) {
+                for a in [0, 1, 128, 255] {
+                    let srgba = Color32::from_rgba_unmultiplied(r, g, b, a);
+                    let oklcha = Oklcha::from(srgba);
+                    assert!(oklcha.is_in_srgb_gamut(), "{srgba:?} is in gamut");
+                    assert_eq!(srgba, Color32::from(oklcha), "{oklcha:?}");
+                }
+            }
+        }
+    }
+}
@@ -626,6 +626,7 @@ impl GlowWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders: _, // Not yet supported by this backend
         } = full_output;
 
         glutin.remove_viewports_not_in(&viewport_output);
@@ -1471,6 +1472,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        texture_renders: _, // Not yet supported by this backend
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -638,6 +638,7 @@ impl WgpuWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders: _, // Not yet supported by this backend
         } = full_output;
 
         remove_viewports_not_in(viewports, painter, viewport_from_window, &viewport_output);
@@ -980,6 +981,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        texture_renders: _, // Not yet supported by this backend
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -239,6 +239,7 @@ impl AppRunner {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders: _, // Not yet supported by this backend
         } = full_output;
 
         if viewport_output.len() > 1 {
@@ -323,6 +324,7 @@ impl AppRunner {
                 accesskit_update: _, // not currently implemented
             num_completed_passes: _,    // handled by `Context::run`
             request_discard_reasons: _, // handled by `Context::run`
+            screenshots: _,             // for the app
         } = platform_output;
 
         for command in commands {
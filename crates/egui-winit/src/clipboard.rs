@@ -82,6 +82,28 @@ impl Clipboard {
         Some(self.clipboard.clone())
     }
 
+    /// The image in the OS clipboard, if any.
+    ///
+    /// Always `None` without the "clipboard" feature.
+    pub fn get_image(&mut self) -> Option<egui::ColorImage> {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            return match clipboard.get_image() {
+                Ok(image) => Some(egui::ColorImage::from_rgba_unmultiplied(
+                    [image.width, image.height],
+                    &image.bytes,
+                )),
+                Err(arboard::Error::ContentNotAvailable) => None,
+                Err(err) => {
+                    log::error!("arboard paste error: {err}");
+                    None
+                }
+            };
+        }
+
+        None
+    }
+
     pub fn set_text(&mut self, text: String) {
         #[cfg(all(
             any(
@@ -772,11 +772,16 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
                 } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            self.egui_input.events.push(egui::Event::Paste(contents));
-                        }
+                    let contents = self.clipboard.get().unwrap_or_default();
+                    let contents = contents.replace("\r\n", "\n");
+                    if let Some(paths) = file_uri_list(&contents) {
+                        self.egui_input.events.push(egui::Event::PasteFiles(paths));
+                    } else if !contents.is_empty() {
+                        self.egui_input.events.push(egui::Event::Paste(contents));
+                    } else if let Some(image) = self.clipboard.get_image() {
+                        self.egui_input
+                            .events
+                            .push(egui::Event::PasteImage(std::sync::Arc::new(image)));
                     }
                     return;
                 }
@@ -843,6 +848,7 @@ impl State {
             accesskit_update,
             num_completed_passes: _,    // `egui::Context::run` handles this
             request_discard_reasons: _, // `egui::Context::run` handles this
+            screenshots: _,             // for the app
         } = platform_output;
 
         for command in commands {
@@ -1065,6 +1071,40 @@ fn is_paste_command(modifiers: egui::Modifiers, keycode: egui::Key) -> bool {
         || (cfg!(target_os = "windows") && modifiers.shift && keycode == egui::Key::Insert)
 }
 
+/// Parse text of the form `file:///home/me/a%20b.png`, one URI per line,
+/// which is how most Linux file managers copy files.
+fn file_uri_list(text: &str) -> Option<Vec<std::path::PathBuf>> {
+    let mut paths = vec![];
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue; // `text/uri-list` allows comments
+        }
+        let path = line.strip_prefix("file://")?;
+        let path = path.strip_prefix("localhost").unwrap_or(path);
+        if !path.starts_with('/') {
+            return None;
+        }
+        paths.push(percent_decode(path)?.into());
+    }
+    (!paths.is_empty()).then_some(paths)
+}
+
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
 fn translate_mouse_button(button: winit::event::MouseButton) -> Option<egui::PointerButton> {
     match button {
         winit::event::MouseButton::Left => Some(egui::PointerButton::Primary),
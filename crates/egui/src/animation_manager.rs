@@ -1,12 +1,72 @@
 use crate::{
     Id, IdMap, InputState,
-    emath::{NumExt as _, remap_clamp},
+    emath::{NumExt as _, easing, lerp},
 };
 
+/// How an animated value moves towards its target.
+///
+/// Used by [`crate::Context::animate_bool_eased`] and [`crate::Context::animate_value_eased`].
+#[derive(Clone, Copy, Debug)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Start slow, end fast.
+    EaseIn,
+
+    /// Start fast, end slow.
+    EaseOut,
+
+    /// Start and end slow.
+    EaseInOut,
+
+    /// Any function from [`emath::easing`], or your own.
+    ///
+    /// It should map `[0, 1]` onto `[0, 1]`, but may overshoot in between.
+    Curve(fn(f32) -> f32),
+
+    /// A damped spring, pulling the value towards its target.
+    ///
+    /// The spring keeps track of its velocity, so changing the target
+    /// mid-animation keeps the motion smooth.
+    /// The animation time is ignored: the spring settles when it settles.
+    ///
+    /// With `damping < 2.0 * stiffness.sqrt()` the spring overshoots its target
+    /// before settling, so the animated value may leave its usual range.
+    Spring {
+        /// How hard the spring pulls towards the target, per unit of distance.
+        stiffness: f32,
+
+        /// How much the spring resists motion, per unit of velocity.
+        damping: f32,
+    },
+}
+
+impl Easing {
+    /// A spring that settles in about half a second, with a small overshoot.
+    pub const SPRING: Self = Self::Spring {
+        stiffness: 200.0,
+        damping: 22.0,
+    };
+
+    /// The easing function, or `None` for [`Self::Spring`].
+    pub fn curve(self) -> Option<fn(f32) -> f32> {
+        match self {
+            Self::Linear => Some(easing::linear),
+            Self::EaseIn => Some(easing::cubic_in),
+            Self::EaseOut => Some(easing::cubic_out),
+            Self::EaseInOut => Some(easing::cubic_in_out),
+            Self::Curve(curve) => Some(curve),
+            Self::Spring { .. } => None,
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
     values: IdMap<ValueAnim>,
+    springs: IdMap<SpringAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -25,6 +85,13 @@ struct ValueAnim {
     toggle_time: f64,
 }
 
+#[derive(Clone, Debug)]
+struct SpringAnim {
+    value: f32,
+    velocity: f32,
+    last_tick: f64,
+}
+
 impl AnimationManager {
     /// See [`crate::Context::animate_bool`] for documentation
     pub fn animate_bool(
@@ -71,6 +138,7 @@ impl AnimationManager {
         animation_time: f32,
         id: Id,
         value: f32,
+        easing: fn(f32) -> f32,
     ) -> f32 {
         match self.values.get_mut(&id) {
             None => {
@@ -89,11 +157,9 @@ impl AnimationManager {
                 // On the frame we toggle we don't want to return the old value,
                 // so we extrapolate forwards by half a frame:
                 let time_since_toggle = time_since_toggle + input.predicted_dt / 2.0;
-                let current_value = remap_clamp(
-                    time_since_toggle,
-                    0.0..=animation_time,
-                    anim.from_value..=anim.to_value,
-                );
+                let t = (time_since_toggle / animation_time).clamp(0.0, 1.0);
+                let t = if t.is_finite() { easing(t) } else { 1.0 };
+                let current_value = lerp(anim.from_value..=anim.to_value, t);
                 if anim.to_value != value {
                     anim.from_value = current_value; //start new animation from current position of playing animation
                     anim.to_value = value;
@@ -107,4 +173,59 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See [`crate::Easing::Spring`] for documentation
+    pub fn animate_spring(
+        &mut self,
+        input: &InputState,
+        stiffness: f32,
+        damping: f32,
+        id: Id,
+        target: f32,
+    ) -> f32 {
+        /// Longest time step we integrate in one go, for stability with stiff springs.
+        const MAX_STEP: f32 = 1.0 / 240.0;
+
+        /// Close enough to the target (and slow enough) to stop.
+        const REST_EPSILON: f32 = 1e-3;
+
+        match self.springs.get_mut(&id) {
+            None => {
+                self.springs.insert(
+                    id,
+                    SpringAnim {
+                        value: target,
+                        velocity: 0.0,
+                        last_tick: input.time,
+                    },
+                );
+                target
+            }
+            Some(anim) => {
+                let elapsed = ((input.time - anim.last_tick) as f32)
+                    .at_most(input.stable_dt)
+                    .at_least(0.0);
+                anim.last_tick = input.time;
+
+                let steps = (elapsed / MAX_STEP).ceil() as usize;
+                if 0 < steps {
+                    let dt = elapsed / steps as f32;
+                    for _ in 0..steps {
+                        let acceleration =
+                            stiffness * (target - anim.value) - damping * anim.velocity;
+                        anim.velocity += acceleration * dt;
+                        anim.value += anim.velocity * dt;
+                    }
+                }
+
+                let at_rest = (target - anim.value).abs() < REST_EPSILON
+                    && anim.velocity.abs() < REST_EPSILON;
+                if at_rest || !anim.value.is_finite() || !anim.velocity.is_finite() {
+                    anim.value = target;
+                    anim.velocity = 0.0;
+                }
+                anim.value
+            }
+        }
+    }
 }
@@ -39,6 +39,12 @@ pub struct AreaState {
     /// Used to fade in the area.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub last_became_visible_at: Option<f64>,
+
+    /// The rect the area was constrained to last frame.
+    ///
+    /// Used to notice when it changes, e.g. because the screen shrunk.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub last_constrain_rect: Option<Rect>,
 }
 
 impl Default for AreaState {
@@ -49,6 +55,7 @@ impl Default for AreaState {
             size: None,
             interactable: true,
             last_became_visible_at: None,
+            last_constrain_rect: None,
         }
     }
 }
@@ -113,6 +120,8 @@ pub struct Area {
     enabled: bool,
     constrain: bool,
     constrain_rect: Option<Rect>,
+    constrain_margin: Option<f32>,
+    recover_offscreen: bool,
     order: Order,
     default_pos: Option<Pos2>,
     default_size: Vec2,
@@ -139,6 +148,8 @@ impl Area {
             interactable: true,
             constrain: true,
             constrain_rect: None,
+            constrain_margin: None,
+            recover_offscreen: true,
             enabled: true,
             order: Order::Middle,
             default_pos: None,
@@ -299,6 +310,36 @@ impl Area {
         self
     }
 
+    /// Let the area be moved partly outside of the rect it is constrained to,
+    /// as long as at least `margin` points of it stay inside, both horizontally and vertically.
+    ///
+    /// The top of the area always stays inside, so that the title bar of a [`crate::Window`] can be reached.
+    ///
+    /// By default, all of the area is kept inside.
+    #[inline]
+    pub fn constrain_margin(mut self, margin: f32) -> Self {
+        self.constrain_margin = Some(margin);
+        self
+    }
+
+    /// If the area is not constrained (see [`Self::constrain`]),
+    /// pull it back inside [`Context::screen_rect`] (or [`Self::constrain_to`])
+    /// if that rect has changed and the area is now mostly outside of it.
+    ///
+    /// This happens when the screen shrinks,
+    /// and on the first frame, e.g. after restoring the position from an earlier session.
+    /// Areas with a [`Self::fixed_pos`], [`Self::current_pos`] or [`Self::anchor`] are left alone.
+    ///
+    /// Set to `false` to keep unconstrained areas exactly where they are,
+    /// e.g. if you position them off-screen on purpose.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn recover_offscreen(mut self, recover_offscreen: bool) -> Self {
+        self.recover_offscreen = recover_offscreen;
+        self
+    }
+
     /// Where the "root" of the area is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -390,6 +431,7 @@ pub(crate) struct Prepared {
     enabled: bool,
     constrain: bool,
     constrain_rect: Rect,
+    constrain_margin: Option<f32>,
 
     /// We always make windows invisible the first frame to hide "first-frame-jitters".
     ///
@@ -431,6 +473,8 @@ impl Area {
             anchor,
             constrain,
             constrain_rect,
+            constrain_margin,
+            recover_offscreen,
             fade_in,
             layout,
             sizing_pass: force_sizing_pass,
@@ -448,6 +492,7 @@ impl Area {
             size: None,
             interactable,
             last_became_visible_at: None,
+            last_constrain_rect: None,
         });
         if force_sizing_pass {
             sizing_pass = true;
@@ -543,10 +588,24 @@ impl Area {
         };
 
         if constrain {
+            state.set_left_top_pos(
+                constrain_rect_with_margin(state.rect(), constrain_rect, constrain_margin).min,
+            );
+        } else if recover_offscreen
+            && new_pos.is_none()
+            && anchor.is_none()
+            && !sizing_pass
+            && state.last_constrain_rect != Some(constrain_rect)
+            && is_mostly_outside(state.rect(), constrain_rect)
+        {
             state.set_left_top_pos(
                 Context::constrain_window_rect_to_area(state.rect(), constrain_rect).min,
             );
         }
+        if !sizing_pass {
+            // Until we know the size (e.g. after loading the state) we can't tell if we are outside.
+            state.last_constrain_rect = Some(constrain_rect);
+        }
 
         state.set_left_top_pos(state.left_top_pos());
 
@@ -562,6 +621,7 @@ impl Area {
             enabled,
             constrain,
             constrain_rect,
+            constrain_margin,
             sizing_pass,
             fade_in,
             layout,
@@ -586,6 +646,11 @@ impl Prepared {
         self.constrain_rect
     }
 
+    /// Constrain a new rect for the area like [`Area::begin`] does.
+    pub(crate) fn constrain_window_rect(&self, rect: Rect) -> Rect {
+        constrain_rect_with_margin(rect, self.constrain_rect, self.constrain_margin)
+    }
+
     pub(crate) fn content_ui(&mut self, ctx: &Context) -> Ui {
         let max_rect = self.state.rect();
 
@@ -630,6 +695,10 @@ impl Prepared {
         self.move_response.id
     }
 
+    pub(crate) fn move_response(&self) -> &Response {
+        &self.move_response
+    }
+
     #[expect(clippy::needless_pass_by_value)] // intentional to swallow up `content_ui`.
     pub(crate) fn end(self, ctx: &Context, content_ui: Ui) -> Response {
         let Self {
@@ -666,6 +735,31 @@ impl Prepared {
     }
 }
 
+/// Keep `rect` inside `bounds`, or with at least `margin` points of it inside.
+fn constrain_rect_with_margin(rect: Rect, bounds: Rect, margin: Option<f32>) -> Rect {
+    let Some(margin) = margin else {
+        return Context::constrain_window_rect_to_area(rect, bounds);
+    };
+
+    let margin = emath::vec2(margin.min(rect.width()), margin.min(rect.height()));
+    let mut pos = rect.min;
+    pos.x = pos
+        .x
+        .at_most(bounds.right() - margin.x)
+        .at_least(bounds.left() + margin.x - rect.width());
+    pos.y = pos
+        .y
+        .at_most(bounds.bottom() - margin.y)
+        .at_least(bounds.top()); // keep the top reachable
+    Rect::from_min_size(pos, rect.size()).round_ui()
+}
+
+/// Is less than half of `rect` inside `bounds`?
+fn is_mostly_outside(rect: Rect, bounds: Rect) -> bool {
+    let inside = rect.intersect(bounds);
+    !inside.is_positive() || inside.area() < 0.5 * rect.area()
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
@@ -1,9 +1,9 @@
 use std::hash::Hash;
 
 use crate::{
-    Context, Id, InnerResponse, NumExt as _, Rect, Response, Sense, Stroke, TextStyle,
+    Context, Id, IdMap, InnerResponse, NumExt as _, Rect, Response, Sense, Stroke, TextStyle,
     TextWrapMode, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetInfo, WidgetText, WidgetType,
-    emath, epaint, pos2, remap, remap_clamp, vec2,
+    emath, epaint, layout_state::LayoutIds, pos2, remap, remap_clamp, vec2,
 };
 use emath::GuiRounding as _;
 use epaint::{Shape, StrokeKind};
@@ -18,6 +18,45 @@ pub(crate) struct InnerState {
     open_height: Option<f32>,
 }
 
+/// For [`CollapsingState::set_open_recursive`] and [`CollapsingState::is_open_recursive`].
+#[derive(Clone, Debug, Default)]
+struct RecursiveState {
+    /// The latest command for each scope: open or not, and its number.
+    commands: IdMap<(bool, u64)>,
+
+    num_commands: u64,
+
+    /// The number of the last command applied to each collapsing region.
+    applied: IdMap<u64>,
+
+    /// For each scope asked about: how many of its regions were shown this pass, and how many of them were open.
+    counts: IdMap<(usize, usize)>,
+
+    /// [`Self::counts`] of the previous pass.
+    prev_counts: IdMap<(usize, usize)>,
+
+    pass_nr: u64,
+}
+
+impl RecursiveState {
+    fn with<R>(ctx: &Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        let pass_nr = ctx.cumulative_pass_nr();
+        ctx.data_mut(|d| {
+            let state = d.get_temp_mut_or_default::<Self>(Id::NULL);
+            if state.pass_nr != pass_nr {
+                if state.pass_nr + 1 == pass_nr {
+                    state.prev_counts = state.counts.clone();
+                } else {
+                    state.prev_counts.clear();
+                }
+                state.counts.values_mut().for_each(|count| *count = (0, 0));
+                state.pass_nr = pass_nr;
+            }
+            f(state)
+        })
+    }
+}
+
 /// This is a a building block for building collapsing regions.
 ///
 /// It is used by [`CollapsingHeader`] and [`crate::Window`], but can also be used on its own.
@@ -27,18 +66,27 @@ pub(crate) struct InnerState {
 pub struct CollapsingState {
     id: Id,
     state: InnerState,
+
+    /// See [`Self::set_animation_time`].
+    animation_time: Option<f32>,
 }
 
 impl CollapsingState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data_mut(|d| {
-            d.get_persisted::<InnerState>(id)
-                .map(|state| Self { id, state })
+            d.get_persisted::<InnerState>(id).map(|state| Self {
+                id,
+                state,
+                animation_time: None,
+            })
         })
     }
 
     pub fn store(&self, ctx: &Context) {
-        ctx.data_mut(|d| d.insert_persisted(self.id, self.state));
+        ctx.data_mut(|d| {
+            d.insert_persisted(self.id, self.state);
+            LayoutIds::remember_collapsing(d, self.id);
+        });
     }
 
     pub fn remove(&self, ctx: &Context) {
@@ -56,6 +104,7 @@ impl CollapsingState {
                 open: default_open,
                 open_height: None,
             },
+            animation_time: None,
         })
     }
 
@@ -67,15 +116,143 @@ impl CollapsingState {
         self.state.open = open;
     }
 
+    /// How many seconds it takes to open or close. `0.0` means no animation.
+    ///
+    /// By default [`crate::Style::animation_time`] is used,
+    /// with an easing that slows down towards the end.
+    pub fn set_animation_time(&mut self, animation_time: f32) {
+        self.animation_time = Some(animation_time);
+    }
+
     pub fn toggle(&mut self, ui: &Ui) {
         self.state.open = !self.state.open;
         ui.ctx().request_repaint();
     }
 
+    /// The [`crate::UiStack`] tag of a scope for [`Self::set_open_recursive`].
+    ///
+    /// The value is the [`Id`] of the scope.
+    /// The body of every collapsing region is tagged with the [`Id`] of the region.
+    pub const SCOPE_TAG: &'static str = "egui_collapsing_scope";
+
+    /// Open or close all the collapsing regions in the scope `id`, e.g. for "Expand all" and "Collapse all" buttons.
+    ///
+    /// The scope is either a collapsing region itself, for the region and everything in its body
+    /// (see [`Self::id`] and [`CollapsingResponse::header_response`]),
+    /// or a [`Ui`] shown with [`Self::show_scope`].
+    ///
+    /// Regions that have not been shown yet are opened or closed when they are first shown,
+    /// even if they were collapsed at the time of the call.
+    /// They animate just like when they are clicked.
+    /// Afterwards, each region can be toggled as usual.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::collapsing_header::CollapsingState;
+    ///
+    /// let tree = ui.make_persistent_id("settings_tree");
+    /// let all_open = CollapsingState::is_open_recursive(ui.ctx(), tree);
+    /// if ui.button(if all_open { "Collapse all" } else { "Expand all" }).clicked() {
+    ///     CollapsingState::set_open_recursive(ui.ctx(), tree, !all_open);
+    /// }
+    /// CollapsingState::show_scope(ui, tree, |ui| {
+    ///     ui.collapsing("Video", |ui| {
+    ///         ui.collapsing("Advanced", |ui| ui.label("…"));
+    ///     });
+    ///     ui.collapsing("Audio", |ui| ui.label("…"));
+    /// });
+    /// # });
+    /// ```
+    pub fn set_open_recursive(ctx: &Context, id: Id, open: bool) {
+        RecursiveState::with(ctx, |state| {
+            state.num_commands += 1;
+            state.commands.insert(id, (open, state.num_commands));
+        });
+        ctx.request_repaint();
+    }
+
+    /// Were all the collapsing regions in the scope `id` open in the previous pass?
+    ///
+    /// Regions hidden in the body of a closed region are not counted,
+    /// but then that closed region is.
+    /// Returns `false` if no regions were shown in the scope.
+    ///
+    /// See [`Self::set_open_recursive`].
+    pub fn is_open_recursive(ctx: &Context, id: Id) -> bool {
+        RecursiveState::with(ctx, |state| {
+            state.counts.entry(id).or_default();
+            state
+                .prev_counts
+                .get(&id)
+                .is_some_and(|&(shown, open)| 0 < shown && shown == open)
+        })
+    }
+
+    /// Show `add_contents` in a scope for [`Self::set_open_recursive`].
+    pub fn show_scope<R>(
+        ui: &mut Ui,
+        id: Id,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let info = UiStackInfo::default().with_tag_value(Self::SCOPE_TAG, id);
+        ui.scope_builder(UiBuilder::new().ui_stack_info(info), add_contents)
+    }
+
+    /// The scopes of this region for [`Self::set_open_recursive`]: itself, and the ones around it.
+    fn recursive_scopes<'a>(&self, ui: &'a Ui) -> impl Iterator<Item = Id> + 'a {
+        std::iter::once(self.id).chain(
+            ui.stack()
+                .iter()
+                .filter_map(|frame| frame.tags().get_downcast::<Id>(Self::SCOPE_TAG).copied()),
+        )
+    }
+
+    /// Apply the latest new command of [`Self::set_open_recursive`], if any.
+    ///
+    /// Returns `true` if that changed the state.
+    fn apply_recursive(&mut self, ui: &Ui) -> bool {
+        let command = RecursiveState::with(ui.ctx(), |state| {
+            let applied = state.applied.get(&self.id).copied().unwrap_or_default();
+            let command = self
+                .recursive_scopes(ui)
+                .filter_map(|scope| state.commands.get(&scope).copied())
+                .filter(|&(_, number)| applied < number)
+                .max_by_key(|&(_, number)| number);
+            if let Some((_, number)) = command {
+                state.applied.insert(self.id, number);
+            }
+            command
+        });
+
+        let changed = command.is_some_and(|(open, _)| open != self.state.open);
+        if changed {
+            self.toggle(ui);
+        }
+        changed
+    }
+
+    /// Count this region for [`Self::is_open_recursive`].
+    fn count_recursive(&self, ui: &Ui) {
+        let open = self.state.open;
+        RecursiveState::with(ui.ctx(), |state| {
+            if state.counts.is_empty() {
+                return;
+            }
+            for scope in self.recursive_scopes(ui) {
+                if let Some((shown, num_open)) = state.counts.get_mut(&scope) {
+                    *shown += 1;
+                    *num_open += open as usize;
+                }
+            }
+        });
+    }
+
     /// 0 for closed, 1 for open, with tweening
     pub fn openness(&self, ctx: &Context) -> f32 {
         if ctx.memory(|mem| mem.everything_is_visible()) {
             1.0
+        } else if let Some(animation_time) = self.animation_time {
+            ctx.animate_bool_with_time(self.id, self.state.open, animation_time)
         } else {
             ctx.animate_bool_responsive(self.id, self.state.open)
         }
@@ -157,6 +334,7 @@ impl CollapsingState {
         ui: &mut Ui,
         add_header: impl FnOnce(&mut Ui) -> HeaderRet,
     ) -> HeaderResponse<'_, HeaderRet> {
+        self.apply_recursive(ui);
         let header_response = ui.horizontal(|ui| {
             let prev_item_spacing = ui.spacing_mut().item_spacing;
             ui.spacing_mut().item_spacing.x = 0.0; // the toggler button uses the full indent width
@@ -164,6 +342,7 @@ impl CollapsingState {
             ui.spacing_mut().item_spacing = prev_item_spacing;
             (collapser, add_header(ui))
         });
+        self.count_recursive(ui);
         HeaderResponse {
             state: self,
             ui,
@@ -206,7 +385,9 @@ impl CollapsingState {
         let openness = self.openness(ui.ctx());
 
         let builder = UiBuilder::new()
-            .ui_stack_info(UiStackInfo::new(UiKind::Collapsible))
+            .ui_stack_info(
+                UiStackInfo::new(UiKind::Collapsible).with_tag_value(Self::SCOPE_TAG, self.id),
+            )
             .closable();
 
         if openness <= 0.0 {
@@ -217,8 +398,9 @@ impl CollapsingState {
                 let max_height = if self.state.open && self.state.open_height.is_none() {
                     // First frame of expansion.
                     // We don't know full height yet, but we will next frame.
-                    // Just use a placeholder value that shows some movement:
-                    10.0
+                    // Lay out the body without showing it, rather than guessing and jumping:
+                    child_ui.set_invisible();
+                    0.0
                 } else {
                     let full_height = self.state.open_height.unwrap_or_default();
                     remap_clamp(openness, 0.0..=1.0, 0.0..=full_height).round_ui()
@@ -399,6 +581,8 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    animated: bool,
+    animation_time: Option<f32>,
 }
 
 impl CollapsingHeader {
@@ -421,6 +605,8 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            animated: true,
+            animation_time: None,
         }
     }
 
@@ -505,6 +691,33 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Should the body slide open and closed? If `false`, it toggles instantly.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// How many seconds it takes the body to slide open or closed, at constant speed.
+    ///
+    /// Default: [`crate::Style::animation_time`], with an easing that slows down towards the end.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::CollapsingHeader::new("Settings")
+    ///     .animated(true)
+    ///     .animation_time(0.3)
+    ///     .show(ui, |ui| { ui.label("Hi!"); });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn animation_time(mut self, animation_time: f32) -> Self {
+        self.animation_time = Some(animation_time);
+        self
+    }
 }
 
 struct Prepared {
@@ -529,6 +742,8 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            animated,
+            animation_time,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
@@ -563,15 +778,23 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        if !animated {
+            state.set_animation_time(0.0);
+        } else if let Some(animation_time) = animation_time {
+            state.set_animation_time(animation_time);
+        }
         if let Some(open) = open {
             if open != state.is_open() {
                 state.toggle(ui);
                 header_response.mark_changed();
             }
+        } else if state.apply_recursive(ui) {
+            header_response.mark_changed();
         } else if header_response.clicked() {
             state.toggle(ui);
             header_response.mark_changed();
         }
+        state.count_recursive(ui);
 
         header_response.widget_info(|| {
             WidgetInfo::labeled(WidgetType::CollapsingHeader, ui.is_enabled(), galley.text())
@@ -1,9 +1,12 @@
-use epaint::Shape;
+use std::{collections::HashSet, hash::BuildHasher, sync::Arc};
+
+use epaint::{Shape, text::LayoutSection};
 
 use crate::{
-    Align2, Context, Id, InnerResponse, NumExt as _, Painter, Popup, PopupCloseBehavior, Rect,
-    Response, ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo,
-    WidgetText, WidgetType, epaint, style::StyleModifier, style::WidgetVisuals, vec2,
+    Align2, Button, Context, Id, InnerResponse, Key, Modifiers, NumExt as _, Painter, Popup,
+    PopupCloseBehavior, Rect, Response, RichText, ScrollArea, Sense, Stroke, TextEdit, TextStyle,
+    TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText, WidgetType, epaint, response,
+    style::StyleModifier, style::WidgetVisuals, vec2,
 };
 
 #[expect(unused_imports)] // Documentation
@@ -44,6 +47,8 @@ pub struct ComboBox {
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
+    searchable: bool,
+    highlight_matches: bool,
 }
 
 impl ComboBox {
@@ -58,6 +63,8 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
+            highlight_matches: true,
         }
     }
 
@@ -73,6 +80,8 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
+            highlight_matches: true,
         }
     }
 
@@ -87,6 +96,8 @@ impl ComboBox {
             icon: None,
             wrap_mode: None,
             close_behavior: None,
+            searchable: false,
+            highlight_matches: true,
         }
     }
 
@@ -191,6 +202,33 @@ impl ComboBox {
         self
     }
 
+    /// Show a search field at the top of the popup, for filtering long lists of options.
+    ///
+    /// The search field gets keyboard focus when the popup opens.
+    /// Typing filters the options by case-insensitive substring,
+    /// the arrow keys move a highlight through the remaining options,
+    /// and Enter selects the highlighted one.
+    /// The search is reset when the popup closes.
+    ///
+    /// This only has an effect with [`Self::show_index`] and [`Self::show_filtered`],
+    /// since [`Self::show_ui`] has no way of knowing what the options are.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Underline the part of each option that matches the search.
+    ///
+    /// Only has an effect if [`Self::searchable`] is `true`. Default: `true`.
+    #[inline]
+    pub fn highlight_matches(mut self, highlight_matches: bool) -> Self {
+        self.highlight_matches = highlight_matches;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -198,6 +236,38 @@ impl ComboBox {
         self,
         ui: &mut Ui,
         menu_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let slf = self.searchable(false);
+        slf.show_ui_dyn(ui, Box::new(|ui, _| menu_contents(ui)))
+    }
+
+    /// Show the combo box, with menu contents that can be filtered with a search field.
+    ///
+    /// Add the options with [`ComboBoxFilter::selectable_value`] or
+    /// [`ComboBoxFilter::selectable_label`], which skip the options that don't match the search.
+    ///
+    /// Unless [`Self::searchable`] is set, there is no search field and all options are shown.
+    ///
+    /// Returns `InnerResponse { inner: None }` if the combo box is closed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let fruits = ["Apple", "Banana", "Cherry", "Dragon fruit"];
+    /// let mut selected = fruits[0];
+    /// egui::ComboBox::from_label("Fruit")
+    ///     .selected_text(selected)
+    ///     .searchable(true)
+    ///     .show_filtered(ui, |ui, filter| {
+    ///         for fruit in fruits {
+    ///             filter.selectable_value(ui, &mut selected, fruit, fruit);
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_filtered<R>(
+        self,
+        ui: &mut Ui,
+        menu_contents: impl FnOnce(&mut Ui, &mut ComboBoxFilter) -> R,
     ) -> InnerResponse<Option<R>> {
         self.show_ui_dyn(ui, Box::new(menu_contents))
     }
@@ -205,7 +275,7 @@ impl ComboBox {
     fn show_ui_dyn<'c, R>(
         self,
         ui: &mut Ui,
-        menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        menu_contents: Box<dyn FnOnce(&mut Ui, &mut ComboBoxFilter) -> R + 'c>,
     ) -> InnerResponse<Option<R>> {
         let Self {
             id_salt,
@@ -216,6 +286,8 @@ impl ComboBox {
             icon,
             wrap_mode,
             close_behavior,
+            searchable,
+            highlight_matches,
         } = self;
 
         let button_id = ui.make_persistent_id(id_salt);
@@ -230,6 +302,7 @@ impl ComboBox {
                 wrap_mode,
                 close_behavior,
                 (width, height),
+                searchable.then_some(highlight_matches),
             );
             if let Some(label) = label {
                 ir.response.widget_info(|| {
@@ -247,6 +320,7 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// The items can be filtered by typing if [`Self::searchable`] is set.
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -275,9 +349,12 @@ impl ComboBox {
         let mut changed = false;
 
         let mut response = slf
-            .show_ui(ui, |ui| {
+            .show_filtered(ui, |ui, filter| {
                 for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                    if filter
+                        .selectable_label(ui, i == *selected, get(i))
+                        .is_some_and(|response| response.clicked())
+                    {
                         *selected = i;
                         changed = true;
                     }
@@ -291,6 +368,95 @@ impl ComboBox {
         response
     }
 
+    /// Show a list of items with checkboxes, of which any number can be selected.
+    ///
+    /// The popup stays open while toggling items, and closes on Escape or when clicking outside it.
+    /// The focused item can be toggled with Space, and the arrow keys move the focus.
+    ///
+    /// Unless [`Self::selected_text`] is set, the button shows the selected items,
+    /// or their count if there are more than three.
+    ///
+    /// The returned [`Response`] is [`Response::changed`] if the selection changed.
+    ///
+    /// [`Self::searchable`] has no effect here.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let tags = ["bug", "feature", "docs", "question"];
+    /// let mut selected = std::collections::HashSet::new();
+    /// egui::ComboBox::from_label("Tags").show_multi_index(
+    ///     ui,
+    ///     &mut selected,
+    ///     tags.len(),
+    ///     |i| tags[i]
+    /// );
+    /// # });
+    /// ```
+    pub fn show_multi_index<Text: Into<WidgetText>, S: BuildHasher>(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut HashSet<usize, S>,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        if self.selected_text.is_empty() {
+            let mut indices: Vec<usize> = selected.iter().copied().filter(|&i| i < len).collect();
+            indices.sort_unstable();
+            self.selected_text = if 3 < indices.len() {
+                format!("{} selected", indices.len()).into()
+            } else {
+                indices
+                    .iter()
+                    .map(|&i| get(i).into().text().to_owned())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+                    .into()
+            };
+        }
+        self.wrap_mode.get_or_insert(TextWrapMode::Truncate);
+        self.close_behavior
+            .get_or_insert(PopupCloseBehavior::CloseOnClickOutside);
+
+        let button_id = ui.make_persistent_id(self.id_salt);
+        let was_open = Self::is_open(ui.ctx(), button_id);
+
+        let mut changed = false;
+
+        let mut response = self
+            .searchable(false)
+            .show_ui(ui, |ui| {
+                let clear_button = Button::new("Clear all").small();
+                if ui.add_enabled(!selected.is_empty(), clear_button).clicked() {
+                    selected.clear();
+                    changed = true;
+                }
+                ui.separator();
+
+                for i in 0..len {
+                    let mut checked = selected.contains(&i);
+                    let response = ui.checkbox(&mut checked, get(i));
+                    if !was_open && i == 0 {
+                        // Allow keyboard navigation right away:
+                        response.request_focus();
+                    }
+                    if response.changed() {
+                        if checked {
+                            selected.insert(i);
+                        } else {
+                            selected.remove(&i);
+                        }
+                        changed = true;
+                    }
+                }
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
     /// Check if the [`ComboBox`] with the given id has its popup menu currently opened.
     pub fn is_open(ctx: &Context, id: Id) -> bool {
         Popup::is_id_open(ctx, Self::widget_to_popup_id(id))
@@ -307,15 +473,21 @@ fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
     selected_text: WidgetText,
-    menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    menu_contents: Box<dyn FnOnce(&mut Ui, &mut ComboBoxFilter) -> R + 'c>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
     (width, height): (Option<f32>, Option<f32>),
+    search: Option<bool>,
 ) -> InnerResponse<Option<R>> {
     let popup_id = ComboBox::widget_to_popup_id(button_id);
+    let search_id = popup_id.with("search");
 
     let is_popup_open = Popup::is_id_open(ui.ctx(), popup_id);
+    if !is_popup_open {
+        // Start with an empty search the next time the popup opens:
+        ui.data_mut(|d| d.remove::<SearchState>(search_id));
+    }
 
     let wrap_mode = wrap_mode.unwrap_or_else(|| ui.wrap_mode());
 
@@ -385,18 +557,39 @@ fn combo_box_dyn<'c, R>(
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
+            let mut search_state = None;
+            let mut filter = ComboBoxFilter::default();
+            if let Some(highlight_matches) = search {
+                let state = search_state.insert(
+                    ui.data_mut(|d| d.get_temp::<SearchState>(search_id))
+                        .unwrap_or_default(),
+                );
+                filter = search_bar_ui(ui, search_id, state);
+                filter.highlight_matches = highlight_matches;
+            }
+
+            let output = ScrollArea::vertical().max_height(height).show(ui, |ui| {
+                // Often the button is very narrow, which means this popup
+                // is also very narrow. Having wrapping on would therefore
+                // result in labels that wrap very early.
+                // Instead, we turn it off by default so that the labels
+                // expand the width of the menu.
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+
+                if let Some(state) = &search_state {
+                    // Don't shrink the list as the options are filtered out:
+                    ui.set_min_size(state.list_size);
+                }
+                menu_contents(ui, &mut filter)
+            });
+
+            if let Some(mut state) = search_state {
+                state.num_matches = filter.num_matches;
+                state.list_size = state.list_size.max(output.inner_rect.size());
+                ui.data_mut(|d| d.insert_temp(search_id, state));
+            }
+
+            output.inner
         })
         .map(|r| r.inner);
 
@@ -406,6 +599,228 @@ fn combo_box_dyn<'c, R>(
     }
 }
 
+#[derive(Clone, Default)]
+struct SearchState {
+    query: String,
+
+    /// Index of the highlighted option, among the ones matching the search.
+    highlighted: usize,
+
+    /// How many options matched the search last frame.
+    num_matches: usize,
+
+    /// The largest size the list of options has had, to keep the popup from jumping around.
+    list_size: Vec2,
+}
+
+/// Shows the search field, and handles keyboard navigation of the filtered options.
+fn search_bar_ui(ui: &mut Ui, search_id: Id, state: &mut SearchState) -> ComboBoxFilter {
+    let text_edit_id = search_id.with("text_edit");
+    let is_first_frame = ui.data(|d| d.get_temp::<SearchState>(search_id).is_none());
+
+    let mut filter = ComboBoxFilter {
+        highlighted: state.highlighted.min(state.num_matches.saturating_sub(1)),
+        ..Default::default()
+    };
+
+    if ui.memory(|mem| mem.has_focus(text_edit_id)) {
+        let (down, up, enter) = ui.input_mut(|i| {
+            (
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+            )
+        });
+        if 0 < down || 0 < up {
+            filter.highlighted = (filter.highlighted + down)
+                .saturating_sub(up)
+                .min(state.num_matches.saturating_sub(1));
+            filter.scroll_to_highlighted = true;
+            // The highlight takes a frame to show up:
+            ui.ctx().request_repaint();
+        }
+        filter.select_highlighted = enter;
+    }
+
+    let response = TextEdit::singleline(&mut state.query)
+        .id(text_edit_id)
+        .hint_text("Search…")
+        .desired_width(f32::INFINITY)
+        .show(ui)
+        .response;
+    if is_first_frame {
+        response.request_focus();
+    }
+    if response.changed() {
+        filter.highlighted = 0;
+        filter.scroll_to_highlighted = true;
+    }
+
+    state.highlighted = filter.highlighted;
+    filter.query = state.query.clone();
+    filter
+}
+
+/// Decides which options of a [`ComboBox`] to show, based on what the user searched for.
+///
+/// See [`ComboBox::show_filtered`].
+#[derive(Clone, Debug)]
+pub struct ComboBoxFilter {
+    query: String,
+    highlight_matches: bool,
+    highlighted: usize,
+    scroll_to_highlighted: bool,
+    select_highlighted: bool,
+    num_matches: usize,
+}
+
+impl Default for ComboBoxFilter {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            highlight_matches: false,
+            highlighted: usize::MAX,
+            scroll_to_highlighted: false,
+            select_highlighted: false,
+            num_matches: 0,
+        }
+    }
+}
+
+impl ComboBoxFilter {
+    /// What the user has typed in the search field.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Does the given option text match the search?
+    pub fn matches(&self, text: &str) -> bool {
+        find_ignoring_case(text, &self.query).is_some()
+    }
+
+    /// Show a [`Ui::selectable_label`], unless the text doesn't match the search.
+    ///
+    /// Returns `None` if the option was filtered out.
+    /// The response is [`Response::clicked`] if the option was highlighted and Enter was pressed.
+    pub fn selectable_label(
+        &mut self,
+        ui: &mut Ui,
+        selected: bool,
+        text: impl Into<WidgetText>,
+    ) -> Option<Response> {
+        let text = text.into();
+        let matched = find_ignoring_case(text.text(), &self.query)?;
+
+        let index = self.num_matches;
+        self.num_matches += 1;
+
+        let text = if self.highlight_matches && !matched.is_empty() {
+            underline_range(ui, text, matched)
+        } else {
+            text
+        };
+
+        let mut response = ui.selectable_label(selected, text);
+        if index == self.highlighted {
+            response = response.highlight();
+            if self.scroll_to_highlighted {
+                response.scroll_to_me(None);
+            }
+            if self.select_highlighted {
+                response
+                    .flags
+                    .set(response::Flags::FAKE_PRIMARY_CLICKED, true);
+                ui.close();
+            }
+        }
+        Some(response)
+    }
+
+    /// Show a [`Ui::selectable_value`], unless the text doesn't match the search.
+    ///
+    /// Returns `None` if the option was filtered out.
+    pub fn selectable_value<Value: PartialEq>(
+        &mut self,
+        ui: &mut Ui,
+        current_value: &mut Value,
+        selected_value: Value,
+        text: impl Into<WidgetText>,
+    ) -> Option<Response> {
+        let mut response = self.selectable_label(ui, *current_value == selected_value, text)?;
+        if response.clicked() && *current_value != selected_value {
+            *current_value = selected_value;
+            response.mark_changed();
+        }
+        Some(response)
+    }
+}
+
+/// Find `needle` in `haystack`, ignoring case, and return the byte range of the match in `haystack`.
+fn find_ignoring_case(haystack: &str, needle: &str) -> Option<std::ops::Range<usize>> {
+    if needle.is_empty() {
+        return Some(0..0);
+    }
+
+    // We can't just lowercase both strings, because that may change the byte offsets.
+    let needle: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+    haystack.char_indices().find_map(|(start, _)| {
+        let mut needle = needle.iter();
+        for (offset, c) in haystack[start..].char_indices() {
+            for lower in c.to_lowercase() {
+                if needle.next() != Some(&lower) {
+                    return None;
+                }
+            }
+            if needle.len() == 0 {
+                return Some(start..start + offset + c.len_utf8());
+            }
+        }
+        None
+    })
+}
+
+fn underline_range(ui: &Ui, text: WidgetText, range: std::ops::Range<usize>) -> WidgetText {
+    let text = match text {
+        // Plain text would be laid out with the wrong font:
+        WidgetText::Text(text) => WidgetText::from(RichText::new(text)),
+        WidgetText::Galley(_) => return text,
+        text => text,
+    };
+    let job = text.into_layout_job(ui.style(), TextStyle::Button.into(), ui.text_valign());
+    let mut job = Arc::unwrap_or_clone(job);
+
+    let sections = std::mem::take(&mut job.sections);
+    for section in sections {
+        let byte_range = section.byte_range.clone();
+        let start = byte_range.start.clamp(range.start, range.end);
+        let end = byte_range.end.clamp(range.start, range.end);
+        for (part, underline) in [
+            (byte_range.start..start, false),
+            (start..end, true),
+            (end..byte_range.end, false),
+        ] {
+            if part.is_empty() {
+                continue;
+            }
+            let mut format = section.format.clone();
+            if underline {
+                format.underline = Stroke::new(1.0, format.color);
+            }
+            job.sections.push(LayoutSection {
+                leading_space: if part.start == byte_range.start {
+                    section.leading_space
+                } else {
+                    0.0
+                },
+                byte_range: part,
+                format,
+            });
+        }
+    }
+
+    job.into()
+}
+
 fn button_frame(
     ui: &mut Ui,
     id: Id,
@@ -470,3 +885,17 @@ fn paint_default_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
         Stroke::NONE,
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::find_ignoring_case;
+
+    #[test]
+    fn test_find_ignoring_case() {
+        assert_eq!(find_ignoring_case("Banana", ""), Some(0..0));
+        assert_eq!(find_ignoring_case("Banana", "NAN"), Some(2..5));
+        assert_eq!(find_ignoring_case("Banana", "x"), None);
+        assert_eq!(find_ignoring_case("Größe", "ÖSS"), None);
+        assert_eq!(find_ignoring_case("ÄÖÜ", "öü"), Some(2..6));
+    }
+}
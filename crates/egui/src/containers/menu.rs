@@ -10,10 +10,11 @@
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
-    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    Button, Color32, Context, Event, Frame, Id, InnerResponse, IntoAtoms, Key, Layout, Modifiers,
+    Popup, PopupCloseBehavior, PopupKind, Response, Style, Ui, UiBuilder, UiKind, UiStack,
+    UiStackInfo, Widget as _,
 };
-use emath::{Align, RectAlign, Vec2, vec2};
+use emath::{Align, Rect, RectAlign, Vec2, vec2};
 use epaint::Stroke;
 
 /// Apply a menu style to the [`Style`].
@@ -136,12 +137,61 @@ impl MenuConfig {
 pub struct MenuState {
     /// The currently open sub menu in this menu.
     pub open_item: Option<Id>,
+
+    /// The entry highlighted with the keyboard, if any.
+    pub highlighted: Option<Id>,
+
     last_visible_pass: u64,
+
+    /// The pass in which the menu was opened.
+    opened_pass: u64,
+
+    /// Is the menu navigated with the keyboard? Reset when the pointer moves.
+    keyboard_nav: bool,
+
+    /// Highlight the first entry as soon as we know what it is.
+    highlight_first: bool,
+
+    /// The menu and button a sub menu was opened from.
+    parent: Option<(Id, Id)>,
+
+    /// The widget that had keyboard focus when the menu was opened.
+    restore_focus: Option<Id>,
+
+    /// The entries of the menu in the last pass, in order.
+    entries: Vec<MenuEntry>,
+
+    /// The buttons that showed a [`SubMenu`] this pass.
+    sub_menu_buttons: Vec<Id>,
+}
+
+/// Something in a menu that can be highlighted and activated with the keyboard.
+#[derive(Clone, Debug)]
+struct MenuEntry {
+    id: Id,
+    rect: Rect,
+    label: String,
+    opens_sub_menu: bool,
 }
 
 impl MenuState {
     pub const ID: &'static str = "menu_state";
 
+    fn new(pass_nr: u64) -> Self {
+        Self {
+            open_item: None,
+            highlighted: None,
+            last_visible_pass: pass_nr,
+            opened_pass: pass_nr,
+            keyboard_nav: false,
+            highlight_first: false,
+            parent: None,
+            restore_focus: None,
+            entries: Vec::new(),
+            sub_menu_buttons: Vec::new(),
+        }
+    }
+
     /// Find the root of the menu and get the state
     pub fn from_ui<R>(ui: &Ui, f: impl FnOnce(&mut Self, &UiStack) -> R) -> R {
         let stack = find_menu_root(ui);
@@ -152,13 +202,10 @@ impl MenuState {
     pub fn from_id<R>(ctx: &Context, id: Id, f: impl FnOnce(&mut Self) -> R) -> R {
         let pass_nr = ctx.cumulative_pass_nr();
         ctx.data_mut(|data| {
-            let state = data.get_temp_mut_or_insert_with(id.with(Self::ID), || Self {
-                open_item: None,
-                last_visible_pass: pass_nr,
-            });
-            // If the menu was closed for at least a frame, reset the open item
+            let state = data.get_temp_mut_or_insert_with(id.with(Self::ID), || Self::new(pass_nr));
+            // If the menu was closed for at least a frame, start over
             if state.last_visible_pass + 1 < pass_nr {
-                state.open_item = None;
+                *state = Self::new(pass_nr);
             }
             state.last_visible_pass = pass_nr;
             f(state)
@@ -169,6 +216,267 @@ impl MenuState {
     pub fn is_deepest_sub_menu(ctx: &Context, id: Id) -> bool {
         Self::from_id(ctx, id, |state| state.open_item.is_none())
     }
+
+    /// Start over with the first entry highlighted, e.g. when opening a sub menu with the keyboard.
+    fn highlight_first_entry(ctx: &Context, id: Id) {
+        Self::from_id(ctx, id, |state| {
+            *state = Self::new(state.last_visible_pass);
+            state.highlight_first = true;
+        });
+    }
+
+    /// The keyboard focus to give back when closing this menu (and its parents) with the keyboard.
+    fn restore_focus(ctx: &Context, mut menu_id: Id) -> Option<Id> {
+        loop {
+            let (parent, restore_focus) =
+                Self::from_id(ctx, menu_id, |state| (state.parent, state.restore_focus));
+            match parent {
+                Some((parent_id, _)) => menu_id = parent_id,
+                None => return restore_focus,
+            }
+        }
+    }
+}
+
+/// Show the contents of a menu popup, and handle navigating it with the keyboard.
+///
+/// * Up/Down move the highlight between the entries (the enabled, clickable widgets).
+/// * Right opens the sub menu of the highlighted entry, Left and Escape close the sub menu.
+/// * Enter/Space activates the highlighted entry.
+/// * Typing a letter highlights the next entry starting with it.
+///
+/// Only the deepest open menu listens to the keyboard.
+pub(crate) fn menu_contents_ui<R>(
+    ui: &mut Ui,
+    menu_id: Id,
+    content: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let ctx = ui.ctx().clone();
+    let layer_id = ui.layer_id();
+    let pass_nr = ctx.cumulative_pass_nr();
+
+    let pointer_moved = ui.input(|i| i.pointer.delta() != Vec2::ZERO || i.pointer.any_pressed());
+    let focused = ctx.memory(|mem| mem.focused());
+    let focused_layer = focused
+        .and_then(|id| ctx.read_response(id))
+        .map(|response| response.layer_id);
+
+    let mut state = MenuState::from_id(&ctx, menu_id, |state| {
+        state.sub_menu_buttons.clear();
+        if pointer_moved {
+            // The pointer takes over:
+            state.keyboard_nav = false;
+            state.highlighted = None;
+        }
+        state.clone()
+    });
+    let is_root = state.parent.is_none();
+    let entries = std::mem::take(&mut state.entries);
+    let entry_index = |id: Id| entries.iter().position(|entry| entry.id == id);
+
+    // A text edit (or similar) in the menu gets to keep the keys:
+    let focus_in_menu = focused_layer == Some(layer_id);
+    let wants_keyboard =
+        state.open_item.is_none() && !(focus_in_menu && focused.and_then(entry_index).is_none());
+
+    let mut activated = false;
+    if wants_keyboard {
+        if let Some(focused) = focused {
+            // From now on, the keys go to the menu:
+            if !focus_in_menu {
+                if is_root && state.restore_focus.is_none() {
+                    state.restore_focus = Some(focused);
+                }
+            } else if state.highlighted != Some(focused) {
+                // An entry was focused, e.g. by the combo box when it opened:
+                state.highlighted = Some(focused);
+                state.keyboard_nav = true;
+                ctx.request_repaint();
+            }
+            ctx.memory_mut(|mem| mem.surrender_focus(focused));
+        }
+
+        if state.opened_pass == pass_nr
+            && ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space))
+        {
+            // Opened with the keyboard
+            state.highlight_first = true;
+        }
+
+        let (down, up, right, left, escape, activate, typed) = ui.input_mut(|i| {
+            let typed: Vec<char> = i
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => text.chars().next().filter(|c| !c.is_whitespace()),
+                    _ => None,
+                })
+                .collect();
+            if !typed.is_empty() {
+                i.events.retain(|event| !matches!(event, Event::Text(_)));
+            }
+            (
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowRight),
+                !is_root && i.consume_key(Modifiers::NONE, Key::ArrowLeft),
+                // The root menu is closed by the popup:
+                i.key_pressed(Key::Escape)
+                    && (is_root || i.consume_key(Modifiers::NONE, Key::Escape)),
+                i.key_pressed(Key::Enter) || i.key_pressed(Key::Space),
+                typed,
+            )
+        });
+
+        let num_entries = entries.len();
+        let current = state.highlighted.and_then(entry_index).or_else(|| {
+            // Start from where the pointer is:
+            entries
+                .iter()
+                .position(|entry| ctx.read_response(entry.id).is_some_and(|r| r.hovered()))
+        });
+        let mut new_highlight = None;
+        if 0 < num_entries && down != up {
+            let steps = down as isize - up as isize;
+            let start = current.map_or(if 0 < steps { -1 } else { 0 }, |i| i as isize);
+            new_highlight = Some((start + steps).rem_euclid(num_entries as isize) as usize);
+        }
+        for c in typed {
+            let start = new_highlight.or(current).map_or(0, |i| i + 1);
+            new_highlight = (0..num_entries)
+                .map(|offset| (start + offset) % num_entries)
+                .find(|&i| starts_with_ignoring_case(&entries[i].label, c))
+                .or(new_highlight);
+        }
+        if let Some(index) = new_highlight {
+            let entry = &entries[index];
+            state.highlighted = Some(entry.id);
+            state.keyboard_nav = true;
+            ui.scroll_to_rect(entry.rect, None);
+            // The highlight takes a frame to show up:
+            ctx.request_repaint();
+        }
+
+        let highlighted = state.highlighted.and_then(entry_index).map(|i| &entries[i]);
+        if let Some(entry) = highlighted {
+            if entry.opens_sub_menu && (right || activate) {
+                ui.input_mut(|i| {
+                    i.consume_key(Modifiers::NONE, Key::Enter);
+                    i.consume_key(Modifiers::NONE, Key::Space);
+                });
+                MenuState::highlight_first_entry(&ctx, SubMenu::id_from_widget_id(entry.id));
+                state.open_item = Some(SubMenu::id_from_widget_id(entry.id));
+                state.keyboard_nav = true;
+                ctx.request_repaint();
+            } else if activate {
+                // Enter/Space clicks the widget with focus:
+                ctx.memory_mut(|mem| mem.request_focus(entry.id));
+                activated = true;
+            }
+        }
+
+        if left || escape {
+            if let Some((parent_id, button_id)) = state.parent {
+                MenuState::from_id(&ctx, parent_id, |parent| {
+                    parent.open_item = None;
+                    parent.highlighted = Some(button_id);
+                    parent.keyboard_nav = true;
+                });
+                ctx.request_repaint();
+            } else if let Some(restore_focus) = state.restore_focus {
+                ctx.memory_mut(|mem| mem.request_focus(restore_focus));
+            }
+        }
+    }
+
+    ctx.pass_state_mut(|fs| {
+        fs.layers.entry(layer_id).or_default().ignore_hover = state.keyboard_nav;
+    });
+    ctx.store_widget_infos(layer_id);
+    // While a sub menu is open, its button shows that instead:
+    let highlighted = state.highlighted.filter(|_| state.open_item.is_none());
+    if let Some(highlighted) = highlighted {
+        ctx.highlight_widget(highlighted);
+    }
+
+    // The entries are children of the menu node, which points at the highlighted one:
+    #[cfg(feature = "accesskit")]
+    ctx.accesskit_node_builder(menu_id, |node| {
+        node.set_role(accesskit::Role::Menu);
+        if let Some(highlighted) = highlighted {
+            node.set_active_descendant(highlighted.accesskit_id());
+        }
+    });
+
+    let first_widget = ctx.pass_state(|fs| fs.widgets.get_layer(layer_id).count());
+    MenuState::from_id(&ctx, menu_id, |s| *s = state);
+
+    let inner = ctx.with_accessibility_parent(menu_id, || content(ui));
+
+    let entries: Vec<MenuEntry> = ctx.pass_state(|fs| {
+        fs.widgets
+            .get_layer(layer_id)
+            .skip(first_widget)
+            .filter(|widget| {
+                widget.enabled
+                    && widget.sense.senses_click()
+                    && widget.sense.is_focusable()
+                    && !widget.sense.senses_drag()
+            })
+            .map(|widget| MenuEntry {
+                id: widget.id,
+                rect: widget.interact_rect,
+                label: fs
+                    .widgets
+                    .info(widget.id)
+                    .and_then(|info| info.label.clone())
+                    .unwrap_or_default(),
+                opens_sub_menu: false,
+            })
+            .collect()
+    });
+
+    let close_on_activate = activated
+        && (MenuConfig::find(ui).close_behavior == PopupCloseBehavior::CloseOnClick
+            || ui.will_parent_close());
+    let highlighted_first = MenuState::from_id(&ctx, menu_id, |state| {
+        state.entries = entries;
+        for entry in &mut state.entries {
+            entry.opens_sub_menu = state.sub_menu_buttons.contains(&entry.id);
+        }
+        if state
+            .highlighted
+            .is_some_and(|id| !state.entries.iter().any(|entry| entry.id == id))
+        {
+            state.highlighted = None;
+        }
+        let first = state.entries.first().map(|entry| entry.id);
+        let highlight_first = state.highlight_first && first.is_some();
+        if highlight_first {
+            state.highlighted = first;
+            state.keyboard_nav = true;
+            state.highlight_first = false;
+        }
+        highlight_first
+    });
+    if highlighted_first {
+        ctx.request_repaint();
+    }
+
+    if close_on_activate {
+        if let Some(restore_focus) = MenuState::restore_focus(&ctx, menu_id) {
+            ctx.memory_mut(|mem| mem.request_focus(restore_focus));
+        }
+        ui.close();
+    }
+
+    inner
+}
+
+fn starts_with_ignoring_case(text: &str, c: char) -> bool {
+    text.chars()
+        .next()
+        .is_some_and(|first| first.to_lowercase().eq(c.to_lowercase()))
 }
 
 /// Horizontal menu bar where you can add [`MenuButton`]s.
@@ -409,9 +717,16 @@ impl SubMenu {
 
         let id = Self::id_from_widget_id(button_response.id);
 
-        let (open_item, menu_id, parent_config) = MenuState::from_ui(ui, |state, stack| {
-            (state.open_item, stack.id, MenuConfig::from_stack(stack))
-        });
+        let (open_item, menu_id, parent_config, keyboard_nav) =
+            MenuState::from_ui(ui, |state, stack| {
+                state.sub_menu_buttons.push(button_response.id);
+                (
+                    state.open_item,
+                    stack.id,
+                    MenuConfig::from_stack(stack),
+                    state.keyboard_nav,
+                )
+            });
 
         let mut menu_config = self.config.unwrap_or_else(|| parent_config.clone());
         menu_config.bar = false;
@@ -443,7 +758,8 @@ impl SubMenu {
 
         // In theory some other widget could cover the button and this check would still pass
         // But since we check if no other menu is open, nothing should be able to cover the button
-        let is_hovered = hover_pos.is_some_and(|pos| button_rect.contains(pos));
+        // While the menu is navigated with the keyboard, the pointer is ignored.
+        let is_hovered = !keyboard_nav && hover_pos.is_some_and(|pos| button_rect.contains(pos));
 
         // The clicked handler is there for accessibility (keyboard navigation)
         if (!is_any_open && is_hovered) || button_response.clicked() {
@@ -462,7 +778,14 @@ impl SubMenu {
         let expand = Vec2::new(0.0, frame.total_margin().sum().y / 2.0);
         response.interact_rect = response.interact_rect.expand2(expand);
 
+        if is_open {
+            MenuState::from_id(ui.ctx(), id, |state| {
+                state.parent = Some((menu_id, button_response.id));
+            });
+        }
+
         let popup_response = Popup::from_response(&response)
+            .kind(PopupKind::Menu)
             .id(id)
             .open(is_open)
             .align(RectAlign::RIGHT_START)
@@ -529,6 +852,7 @@ impl SubMenu {
                 ui.ctx().request_repaint();
             }
             let hovering_other_menu_entry = is_open
+                && !keyboard_nav
                 && !is_hovered
                 && !popup_response.response.contains_pointer()
                 && !is_moving_towards_rect
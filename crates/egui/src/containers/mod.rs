@@ -25,12 +25,12 @@ pub use {
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
     frame::Frame,
-    modal::{Modal, ModalResponse},
+    modal::{Modal, ModalOutcome, ModalResponse},
     old_popup::*,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
     resize::Resize,
-    scene::{DragPanButtons, Scene},
+    scene::{DragPanButtons, Scene, SceneTransform},
     scroll_area::ScrollArea,
     sides::Sides,
     tooltip::*,
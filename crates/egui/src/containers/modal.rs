@@ -1,7 +1,13 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering as AtomicOrdering},
+};
+
 use emath::{Align2, Vec2};
 
 use crate::{
-    Area, Color32, Context, Frame, Id, InnerResponse, Order, Response, Sense, Ui, UiBuilder, UiKind,
+    Area, Color32, Context, Frame, Id, InnerResponse, Order, Response, Sense, Ui, UiBuilder,
+    UiKind, UiStackInfo, ViewportId, Visuals,
 };
 
 /// A modal dialog.
@@ -9,14 +15,48 @@ use crate::{
 /// Similar to a [`crate::Window`] but centered and with a backdrop that
 /// blocks input to the rest of the UI.
 ///
+/// When the modal is opened, it takes the keyboard focus, and gives it to its first widget.
+/// Only the modal (and anything above it) can then get the focus, so <kbd>Tab</kbd> cycles through
+/// the widgets of the modal. When the modal is no longer shown (see [`ModalResponse::should_close`]),
+/// the focus goes back to the widget that had it before.
+///
 /// You can show multiple modals on top of each other. The topmost modal will always be
-/// the most recently shown one.
+/// the most recently shown one, and is the only one you can interact with.
 /// If multiple modals are newly shown in the same frame, the order of the modals not undefined
 /// (either first or second could be top).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut delete_modal_open = true;
+/// if delete_modal_open {
+///     let modal = egui::Modal::new(egui::Id::new("delete")).show(ui.ctx(), |ui| {
+///         ui.label("Delete the file?");
+///         if ui.button("Delete").clicked() {
+///             egui::Modal::confirm(ui);
+///         }
+///         if ui.button("Cancel").clicked() {
+///             ui.close();
+///         }
+///     });
+///     match modal.outcome() {
+///         Some(egui::ModalOutcome::Confirmed) => {
+///             // Delete the file…
+///             delete_modal_open = false;
+///         }
+///         Some(egui::ModalOutcome::Dismissed) => delete_modal_open = false,
+///         None => {}
+///     }
+/// }
+/// # });
+/// ```
 pub struct Modal {
     pub area: Area,
+
     pub backdrop_color: Color32,
+
     pub frame: Option<Frame>,
+    pub close_on_escape: bool,
+    pub close_on_backdrop_click: bool,
 }
 
 impl Modal {
@@ -26,8 +66,10 @@ impl Modal {
     pub fn new(id: Id) -> Self {
         Self {
             area: Self::default_area(id),
-            backdrop_color: Color32::from_black_alpha(100),
+            backdrop_color: Visuals::default().modal_backdrop_color,
             frame: None,
+            close_on_escape: true,
+            close_on_backdrop_click: true,
         }
     }
 
@@ -57,13 +99,33 @@ impl Modal {
 
     /// Set the backdrop color of the modal.
     ///
-    /// Default is `Color32::from_black_alpha(100)`.
+    /// Default is the [`Visuals::modal_backdrop_color`] of the default [`Visuals`].
+    /// Use `ui.visuals().modal_backdrop_color` to follow the current style instead.
     #[inline]
     pub fn backdrop_color(mut self, color: Color32) -> Self {
         self.backdrop_color = color;
         self
     }
 
+    /// Should pressing <kbd>Escape</kbd> close the topmost modal?
+    ///
+    /// Default is `true`.
+    #[inline]
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Should clicking outside the modal close it?
+    ///
+    /// Default is `true`.
+    /// Either way, the click doesn't reach whatever is behind the modal.
+    #[inline]
+    pub fn close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+
     /// Set the area of the modal.
     ///
     /// Default is [`Modal::default_area`].
@@ -73,21 +135,60 @@ impl Modal {
         self
     }
 
+    /// Close the modal `ui` is in, and make [`ModalResponse::outcome`] return
+    /// [`ModalOutcome::Confirmed`].
+    ///
+    /// Use [`Ui::close`] to close the modal without confirming it.
+    pub fn confirm(ui: &Ui) {
+        let tag = ui
+            .stack()
+            .iter()
+            .find_map(|stack| stack.info.tags.get_downcast::<ConfirmTag>(ConfirmTag::NAME));
+        if let Some(tag) = tag {
+            tag.0.store(true, AtomicOrdering::Relaxed);
+        }
+        ui.close_kind(UiKind::Modal);
+    }
+
     /// Show the modal.
     pub fn show<T>(self, ctx: &Context, content: impl FnOnce(&mut Ui) -> T) -> ModalResponse<T> {
         let Self {
             area,
             backdrop_color,
             frame,
+            close_on_escape,
+            close_on_backdrop_click,
         } = self;
+        let id = area.layer().id;
 
         let is_top_modal = ctx.memory_mut(|mem| {
             mem.set_modal_layer(area.layer());
             mem.top_modal_layer() == Some(area.layer())
         });
         let any_popup_open = crate::Popup::is_any_open(ctx);
+
+        let pass_nr = ctx.cumulative_pass_nr();
+        let viewport_id = ctx.viewport_id();
+        ctx.memory_mut(|mem| {
+            let previously_focused = mem.focused();
+            let open_modals = mem.data.get_temp_mut_or_default::<OpenModals>(Id::NULL);
+            if let Some(state) = open_modals.0.iter_mut().find(|state| state.id == id) {
+                state.last_pass_nr = pass_nr;
+            } else {
+                open_modals.0.push(ModalState {
+                    id,
+                    viewport_id,
+                    restore_focus: previously_focused,
+                    last_pass_nr: pass_nr,
+                });
+                // Take the focus away from whatever is behind the modal,
+                // and give it to the first widget of the modal:
+                mem.focus_next_interested();
+            }
+        });
+
         let InnerResponse {
-            inner: (inner, backdrop_response),
+            inner: (inner, backdrop_response, confirmed),
             response,
         } = area.show(ctx, |ui| {
             let bg_rect = ui.ctx().screen_rect();
@@ -101,23 +202,111 @@ impl Modal {
 
             // We need the extra scope with the sense since frame can't have a sense and since we
             // need to prevent the clicks from passing through to the backdrop.
-            let inner = ui
-                .scope_builder(UiBuilder::new().sense(Sense::CLICK | Sense::DRAG), |ui| {
-                    frame.show(ui, content).inner
+            let builder = UiBuilder::new()
+                .sense(Sense::CLICK | Sense::DRAG)
+                .ui_stack_info(
+                    UiStackInfo::default().with_tag_value(ConfirmTag::NAME, ConfirmTag::default()),
+                );
+            let (inner, confirmed) = ui
+                .scope_builder(builder, |ui| {
+                    let inner = frame.show(ui, content).inner;
+                    let confirmed = ui
+                        .stack()
+                        .info
+                        .tags
+                        .get_downcast::<ConfirmTag>(ConfirmTag::NAME)
+                        .is_some_and(|tag| tag.0.load(AtomicOrdering::Relaxed));
+                    (inner, confirmed)
                 })
                 .inner;
 
-            (inner, backdrop_response)
+            (inner, backdrop_response, confirmed)
         });
 
+        // this is a closure so that `Esc` is consumed only if the modal is topmost
+        let escape_clicked =
+            || ctx.input_mut(|i| i.consume_key(crate::Modifiers::NONE, crate::Key::Escape));
+
+        let should_close = (close_on_backdrop_click && backdrop_response.clicked())
+            || response.should_close()
+            || (close_on_escape && is_top_modal && !any_popup_open && escape_clicked());
+
         ModalResponse {
             response,
             backdrop_response,
             inner,
             is_top_modal,
             any_popup_open,
+            confirmed,
+            should_close,
         }
     }
+
+    pub(crate) fn register(ctx: &Context) {
+        ctx.on_end_pass("modal_end_pass", Arc::new(Self::end_pass));
+    }
+
+    /// Give the focus back for the modals that weren't shown this pass.
+    fn end_pass(ctx: &Context) {
+        let pass_nr = ctx.cumulative_pass_nr();
+        let viewport_id = ctx.viewport_id();
+        let any_closed = ctx.memory_mut(|mem| {
+            let open_modals = mem.data.get_temp_mut_or_default::<OpenModals>(Id::NULL);
+            let (closed, open): (Vec<_>, Vec<_>) = std::mem::take(&mut open_modals.0)
+                .into_iter()
+                .partition(|state| {
+                    state.viewport_id == viewport_id && state.last_pass_nr < pass_nr
+                });
+            open_modals.0 = open;
+
+            // The modal opened last gives the focus back first,
+            // so if several close at once the focus ends up where it was before the first one opened.
+            for state in closed.iter().rev() {
+                mem.set_focus(state.restore_focus);
+            }
+            !closed.is_empty()
+        });
+        if any_closed {
+            // Show the new focus:
+            ctx.request_repaint();
+        }
+    }
+}
+
+/// Set by [`Modal::confirm`].
+#[derive(Debug, Default)]
+struct ConfirmTag(AtomicBool);
+
+impl ConfirmTag {
+    const NAME: &'static str = "egui_modal_confirm_tag";
+}
+
+/// Remembered while a modal is open.
+#[derive(Clone, Copy, Debug)]
+struct ModalState {
+    id: Id,
+    viewport_id: ViewportId,
+
+    /// The widget that had the focus before the modal opened.
+    restore_focus: Option<Id>,
+
+    /// The modal is shown every pass while it is open.
+    last_pass_nr: u64,
+}
+
+/// All the open modals, in the order they were opened.
+#[derive(Clone, Debug, Default)]
+struct OpenModals(Vec<ModalState>);
+
+/// How a modal was closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModalOutcome {
+    /// [`Modal::confirm`] was called.
+    Confirmed,
+
+    /// The modal was closed in any other way, e.g. with [`Ui::close`], <kbd>Escape</kbd>,
+    /// or by clicking the backdrop.
+    Dismissed,
 }
 
 /// The response of a modal dialog.
@@ -141,24 +330,37 @@ pub struct ModalResponse<T> {
     /// We need to check this before the modal contents are shown, so we can know if any popup
     /// was open when checking if the escape key was clicked.
     pub any_popup_open: bool,
+
+    /// Was [`Modal::confirm`] called?
+    pub confirmed: bool,
+
+    should_close: bool,
 }
 
 impl<T> ModalResponse<T> {
     /// Should the modal be closed?
     /// Returns true if:
-    ///  - the backdrop was clicked
+    ///  - [`Ui::close`] or [`Modal::confirm`] was called
+    ///  - the backdrop was clicked (see [`Modal::close_on_backdrop_click`])
     ///  - this is the topmost modal, no popup is open and the escape key was pressed
+    ///    (see [`Modal::close_on_escape`])
+    ///
+    /// Once you stop showing the modal, the focus goes back to where it was before it opened.
     pub fn should_close(&self) -> bool {
-        let ctx = &self.response.ctx;
-
-        // this is a closure so that `Esc` is consumed only if the modal is topmost
-        let escape_clicked =
-            || ctx.input_mut(|i| i.consume_key(crate::Modifiers::NONE, crate::Key::Escape));
-
-        let ui_close_called = self.response.should_close();
+        self.should_close
+    }
 
-        self.backdrop_response.clicked()
-            || ui_close_called
-            || (self.is_top_modal && !self.any_popup_open && escape_clicked())
+    /// Was the modal closed this frame, and how?
+    ///
+    /// Returns `None` while the modal stays open.
+    /// Same as [`Self::should_close`], but tells apart [`Modal::confirm`] from other ways of closing.
+    pub fn outcome(&self) -> Option<ModalOutcome> {
+        if !self.should_close() {
+            None
+        } else if self.confirmed {
+            Some(ModalOutcome::Confirmed)
+        } else {
+            Some(ModalOutcome::Dismissed)
+        }
     }
 }
@@ -15,11 +15,14 @@
 //!
 //! Add your [`crate::Window`]:s after any top-level panels.
 
+use std::time::Duration;
+
 use emath::GuiRounding as _;
 
 use crate::{
     Align, Context, CursorIcon, Frame, Id, InnerResponse, LayerId, Layout, NumExt as _, Rangef,
-    Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, lerp, vec2,
+    Rect, Response, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetInfo,
+    WidgetType, layout_state::LayoutIds, lerp, vec2,
 };
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
@@ -43,8 +46,110 @@ impl PanelState {
         self.rect.size()
     }
 
-    fn store(self, ctx: &Context, bar_id: Id) {
-        ctx.data_mut(|d| d.insert_persisted(bar_id, self));
+    pub(crate) fn store(self, ctx: &Context, bar_id: Id) {
+        ctx.data_mut(|d| {
+            d.insert_persisted(bar_id, self);
+            LayoutIds::remember_panel(d, bar_id);
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Slide a panel out of view while the pointer is away from it.
+///
+/// See [`TopBottomPanel::auto_hide`] and [`SidePanel::auto_hide`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoHide {
+    /// How long the pointer must stay away from the panel before it hides.
+    pub delay: Duration,
+
+    /// How close the pointer must come to the edge of the screen to reveal a hidden panel, in points.
+    ///
+    /// While the panel is shown, the pointer keeps it shown when within this distance of it.
+    pub reveal_margin: f32,
+}
+
+impl Default for AutoHide {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_secs(2),
+            reveal_margin: 8.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct AutoHideState {
+    /// When the pointer was last near the panel, or a widget in it had keyboard focus.
+    last_active: f64,
+
+    /// Is the panel shown, or sliding into view?
+    revealed: bool,
+
+    /// Where the contents were last frame, including how far they had slid out.
+    contents_rect: Rect,
+}
+
+impl AutoHide {
+    /// How much of the panel to show, from `0.0` (hidden) to `1.0` (shown).
+    ///
+    /// `panel_rect` is where the shown panel goes,
+    /// and `edge` is the strip along the edge of the screen that reveals it.
+    fn how_shown(self, ui: &Ui, id: Id, panel_rect: Rect, edge: Rect) -> f32 {
+        let ctx = ui.ctx();
+        let state_id = id.with("__auto_hide");
+        let now = ui.input(|i| i.time);
+        let state: Option<AutoHideState> = ctx.data(|d| d.get_temp(state_id));
+
+        let reveal_rect = if state.is_none_or(|state| state.revealed) {
+            panel_rect.expand(self.reveal_margin)
+        } else {
+            edge
+        };
+        let pointer_near = ui
+            .input(|i| i.pointer.hover_pos())
+            .is_some_and(|pos| reveal_rect.contains(pos));
+
+        // Tabbing into a hidden panel reveals it:
+        let focused = ctx
+            .memory(|mem| mem.focused())
+            .and_then(|focused| ctx.read_response(focused));
+        let has_focus = state.zip(focused).is_some_and(|(state, focused)| {
+            focused.layer_id == ui.layer_id() && state.contents_rect.contains_rect(focused.rect)
+        });
+
+        let last_active = match state {
+            Some(state) if !pointer_near && !has_focus => state.last_active,
+            _ => now,
+        };
+        let hide_in = self.delay.as_secs_f64() - (now - last_active);
+        let revealed = 0.0 < hide_in;
+        if revealed {
+            ctx.request_repaint_after_secs(hide_in as f32);
+        }
+
+        ctx.data_mut(|d| {
+            d.insert_temp(
+                state_id,
+                AutoHideState {
+                    last_active,
+                    revealed,
+                    contents_rect: state.map_or(Rect::NOTHING, |state| state.contents_rect),
+                },
+            );
+        });
+        animate_expansion(ctx, id.with("__auto_hide_animation"), revealed)
+    }
+
+    fn remember_contents_rect(ctx: &Context, id: Id, contents_rect: Rect) {
+        let state_id = id.with("__auto_hide");
+        ctx.data_mut(|d| {
+            if let Some(mut state) = d.get_temp::<AutoHideState>(state_id) {
+                state.contents_rect = contents_rect;
+                d.insert_temp(state_id, state);
+            }
+        });
     }
 }
 
@@ -114,6 +219,9 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    collapsible: bool,
+    min_collapsed_width: f32,
+    auto_hide: Option<AutoHide>,
 }
 
 impl SidePanel {
@@ -137,9 +245,70 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            collapsible: false,
+            min_collapsed_width: 0.0,
+            auto_hide: None,
         }
     }
 
+    /// Can the user collapse the panel to a thin rail, and expand it back?
+    ///
+    /// If so, a small handle is shown on the inner edge of the panel.
+    /// Clicking it animates the width of the panel between expanded and collapsed.
+    /// The state is remembered, and can be read with [`Self::is_collapsed`],
+    /// e.g. to only show icons while collapsed.
+    ///
+    /// The contents are shown in both states, clipped to the panel.
+    /// The panel can only be resized while expanded.
+    ///
+    /// Default is `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let id = egui::Id::new("navigation");
+    /// egui::SidePanel::left(id)
+    ///     .collapsible(true)
+    ///     .min_collapsed_width(40.0)
+    ///     .show(ctx, |ui| {
+    ///         if egui::SidePanel::is_collapsed(ui.ctx(), id) {
+    ///             ui.label("🏠");
+    ///         } else {
+    ///             ui.label("🏠 Home");
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// The width of a [collapsed](Self::collapsible) panel, including margins.
+    ///
+    /// Use this to leave room for e.g. a rail of icons.
+    /// The panel is always wide enough to show the collapse handle.
+    ///
+    /// Default is `0.0`.
+    #[inline]
+    pub fn min_collapsed_width(mut self, min_collapsed_width: f32) -> Self {
+        self.min_collapsed_width = min_collapsed_width;
+        self
+    }
+
+    /// Is the [collapsible](Self::collapsible) panel with this id collapsed?
+    pub fn is_collapsed(ctx: &Context, id: impl Into<Id>) -> bool {
+        let id = id.into();
+        ctx.data_mut(|d| d.get_persisted(id.with("__collapsed")))
+            .unwrap_or(false)
+    }
+
+    /// Collapse or expand the [collapsible](Self::collapsible) panel with this id.
+    pub fn set_collapsed(ctx: &Context, id: impl Into<Id>, collapsed: bool) {
+        let id = id.into();
+        ctx.data_mut(|d| d.insert_persisted(id.with("__collapsed"), collapsed));
+    }
+
     /// Can panel be resized by dragging the edge of it?
     ///
     /// Default is `true`.
@@ -214,6 +383,18 @@ impl SidePanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Slide the panel out over the edge of the screen
+    /// when the pointer has been away from it for a while.
+    ///
+    /// See [`TopBottomPanel::auto_hide`].
+    ///
+    /// Default is `None`.
+    #[inline]
+    pub fn auto_hide(mut self, auto_hide: Option<AutoHide>) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
 }
 
 impl SidePanel {
@@ -240,20 +421,56 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            collapsible,
+            min_collapsed_width,
+            auto_hide,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
         let mut panel_rect = available_rect;
         let mut width = default_width;
+
+        // While collapsing, the panel state has the collapsed width, so we remember this separately:
+        let expanded_width_id = id.with("__expanded_width");
+
+        // Collapsed and how expanded:
+        let collapse = collapsible.then(|| {
+            let collapsed = Self::is_collapsed(ui.ctx(), id);
+            let how_expanded =
+                animate_expansion(ui.ctx(), id.with("__collapse_animation"), !collapsed);
+            (collapsed, how_expanded)
+        });
+        let fully_expanded = collapse.is_none_or(|(_, how_expanded)| how_expanded == 1.0);
+
         {
             if let Some(state) = PanelState::load(ui.ctx(), id) {
                 width = state.rect.width();
             }
+            if collapsible {
+                if let Some(expanded_width) = ui.data_mut(|d| d.get_persisted(expanded_width_id)) {
+                    width = expanded_width;
+                }
+            }
             width = clamp_to_range(width, width_range).at_most(available_rect.width());
+            if let Some((_, how_expanded)) = collapse {
+                let collapsed_width = min_collapsed_width
+                    .at_least(collapse_handle_size(ui.style()).x)
+                    .at_most(available_rect.width());
+                width = lerp(collapsed_width..=width, how_expanded);
+            }
             side.set_rect_width(&mut panel_rect, width);
             ui.ctx().check_for_id_clash(id, panel_rect, "SidePanel");
         }
 
+        let how_shown = auto_hide.map_or(1.0, |auto_hide| {
+            let mut edge = available_rect;
+            side.set_rect_width(&mut edge, auto_hide.reveal_margin);
+            auto_hide.how_shown(ui, id, panel_rect, edge)
+        });
+
+        // Only resize an expanded, fully shown panel:
+        let resizable = resizable && fully_expanded && how_shown == 1.0;
+
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
@@ -275,6 +492,14 @@ impl SidePanel {
 
         panel_rect = panel_rect.round_ui();
 
+        // An auto-hidden panel slides out over the edge:
+        let slide = vec2(side.sign() * (1.0 - how_shown) * panel_rect.width(), 0.0);
+        let mut clip_rect = panel_rect;
+        side.set_rect_width(&mut clip_rect, how_shown * panel_rect.width());
+        if how_shown == 0.0 {
+            clip_rect = Rect::NOTHING; // Nothing to click
+        }
+
         let mut panel_ui = ui.new_child(
             UiBuilder::new()
                 .id_salt(id)
@@ -282,19 +507,40 @@ impl SidePanel {
                     Side::Left => UiKind::LeftPanel,
                     Side::Right => UiKind::RightPanel,
                 }))
-                .max_rect(panel_rect)
+                .max_rect(panel_rect.translate(slide))
                 .layout(Layout::top_down(Align::Min)),
         );
-        panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        panel_ui.expand_to_include_rect(panel_rect.translate(slide));
+        panel_ui.set_clip_rect(clip_rect); // If we overflow, don't do so visibly (#4475)
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
-        let inner_response = frame.show(&mut panel_ui, |ui| {
+        let min_width = if fully_expanded {
+            width_range.min
+        } else {
+            width
+        };
+        let mut inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
-            ui.set_min_width((width_range.min - frame.inner_margin.sum().x).at_least(0.0));
+            ui.set_min_width((min_width - frame.inner_margin.sum().x).at_least(0.0));
             add_contents(ui)
         });
 
+        if !fully_expanded {
+            // Don't let the contents push the panel wider, so that the rest of the ui reflows smoothly:
+            inner_response.response.rect = panel_rect.translate(slide);
+        }
+
+        // The rect of the shown panel, which we remember the width of:
+        let mut full_rect = inner_response.response.rect;
+        if auto_hide.is_some() {
+            AutoHide::remember_contents_rect(ui.ctx(), id, full_rect);
+            full_rect = full_rect.translate(-slide);
+
+            // Only the part that is in view takes up space:
+            let mut shown_rect = full_rect;
+            side.set_rect_width(&mut shown_rect, how_shown * full_rect.width());
+            inner_response.response.rect = shown_rect;
+        }
         let rect = inner_response.response.rect;
 
         {
@@ -340,9 +586,18 @@ impl SidePanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        PanelState { rect: full_rect }.store(ui.ctx(), id);
+        if collapsible && fully_expanded {
+            ui.data_mut(|d| d.insert_persisted(expanded_width_id, full_rect.width()));
+        }
 
-        {
+        if let Some((collapsed, _)) = collapse {
+            if 0.0 < how_shown && collapse_handle_ui(ui, id, side, rect, collapsed).clicked() {
+                Self::set_collapsed(ui.ctx(), id, !collapsed);
+            }
+        }
+
+        if 0.0 < how_shown {
             let stroke = if is_resizing {
                 ui.style().visuals.widgets.active.fg_stroke // highly visible
             } else if resize_hover {
@@ -535,6 +790,56 @@ impl SidePanel {
     }
 }
 
+fn collapse_handle_size(style: &crate::Style) -> Vec2 {
+    vec2(style.spacing.icon_width, 2.0 * style.spacing.icon_width)
+}
+
+/// A small chevron on the inner edge of a collapsible [`SidePanel`],
+/// pointing in the direction the panel will move when clicked.
+fn collapse_handle_ui(ui: &Ui, id: Id, side: Side, panel_rect: Rect, collapsed: bool) -> Response {
+    let size = collapse_handle_size(ui.style());
+    let inner_x = side.opposite().side_x(panel_rect);
+    let x_range = match side {
+        Side::Left => Rangef::new(inner_x - size.x, inner_x),
+        Side::Right => Rangef::new(inner_x, inner_x + size.x),
+    };
+    let rect = Rect::from_x_y_ranges(x_range, Rangef::point(panel_rect.center().y))
+        .expand2(vec2(0.0, 0.5 * size.y));
+
+    let response = ui.interact(rect, id.with("__collapse_handle"), Sense::click());
+    let label = if collapsed {
+        "Expand panel"
+    } else {
+        "Collapse panel"
+    };
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if response.hovered() || response.has_focus() {
+            ui.painter()
+                .rect_filled(rect, visuals.corner_radius, visuals.weak_bg_fill);
+        }
+
+        // Expanding moves the inner edge away from the side, collapsing moves it towards it:
+        let direction = if collapsed { -side.sign() } else { side.sign() };
+        let center = rect.center();
+        let half = 0.25 * size.x;
+        let tip = center + vec2(direction * half, 0.0);
+        let back = center - vec2(direction * half, 0.0);
+        ui.painter().line(
+            vec![
+                back + vec2(0.0, -2.0 * half),
+                tip,
+                back + vec2(0.0, 2.0 * half),
+            ],
+            visuals.fg_stroke,
+        );
+    }
+
+    response
+}
+
 // ----------------------------------------------------------------------------
 
 /// [`Top`](TopBottomSide::Top) or [`Bottom`](TopBottomSide::Bottom)
@@ -601,6 +906,7 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    auto_hide: Option<AutoHide>,
 }
 
 impl TopBottomPanel {
@@ -624,6 +930,7 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            auto_hide: None,
         }
     }
 
@@ -704,6 +1011,40 @@ impl TopBottomPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Slide the panel out over the edge of the screen
+    /// when the pointer has been away from it for a while,
+    /// e.g. for the toolbar of a fullscreen viewer.
+    ///
+    /// The panel slides back when the pointer comes close to that edge of the screen,
+    /// or when keyboard focus moves into the panel.
+    /// The rest of the ui gets the space of the hidden panel,
+    /// and the contents of a hidden panel can't be clicked.
+    ///
+    /// Default is `None`.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use std::time::Duration;
+    ///
+    /// egui::TopBottomPanel::top("toolbar")
+    ///     .auto_hide(Some(egui::containers::panel::AutoHide {
+    ///         delay: Duration::from_secs(2),
+    ///         reveal_margin: 8.0,
+    ///     }))
+    ///     .show(ctx, |ui| {
+    ///         ui.horizontal(|ui| {
+    ///             let _ = ui.button("⏵");
+    ///             let _ = ui.button("⏸");
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn auto_hide(mut self, auto_hide: Option<AutoHide>) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
 }
 
 impl TopBottomPanel {
@@ -730,6 +1071,7 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            auto_hide,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
@@ -750,6 +1092,15 @@ impl TopBottomPanel {
                 .check_for_id_clash(id, panel_rect, "TopBottomPanel");
         }
 
+        let how_shown = auto_hide.map_or(1.0, |auto_hide| {
+            let mut edge = available_rect;
+            side.set_rect_height(&mut edge, auto_hide.reveal_margin);
+            auto_hide.how_shown(ui, id, panel_rect, edge)
+        });
+
+        // Only resize a fully shown panel:
+        let resizable = resizable && how_shown == 1.0;
+
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
@@ -772,6 +1123,14 @@ impl TopBottomPanel {
 
         panel_rect = panel_rect.round_ui();
 
+        // An auto-hidden panel slides out over the edge:
+        let slide = vec2(0.0, side.sign() * (1.0 - how_shown) * panel_rect.height());
+        let mut clip_rect = panel_rect;
+        side.set_rect_height(&mut clip_rect, how_shown * panel_rect.height());
+        if how_shown == 0.0 {
+            clip_rect = Rect::NOTHING; // Nothing to click
+        }
+
         let mut panel_ui = ui.new_child(
             UiBuilder::new()
                 .id_salt(id)
@@ -779,18 +1138,29 @@ impl TopBottomPanel {
                     TopBottomSide::Top => UiKind::TopPanel,
                     TopBottomSide::Bottom => UiKind::BottomPanel,
                 }))
-                .max_rect(panel_rect)
+                .max_rect(panel_rect.translate(slide))
                 .layout(Layout::top_down(Align::Min)),
         );
-        panel_ui.expand_to_include_rect(panel_rect);
-        panel_ui.set_clip_rect(panel_rect); // If we overflow, don't do so visibly (#4475)
+        panel_ui.expand_to_include_rect(panel_rect.translate(slide));
+        panel_ui.set_clip_rect(clip_rect); // If we overflow, don't do so visibly (#4475)
 
-        let inner_response = frame.show(&mut panel_ui, |ui| {
+        let mut inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
             ui.set_min_height((height_range.min - frame.inner_margin.sum().y).at_least(0.0));
             add_contents(ui)
         });
 
+        // The rect of the shown panel, which we remember the height of:
+        let mut full_rect = inner_response.response.rect;
+        if auto_hide.is_some() {
+            AutoHide::remember_contents_rect(ui.ctx(), id, full_rect);
+            full_rect = full_rect.translate(-slide);
+
+            // Only the part that is in view takes up space:
+            let mut shown_rect = full_rect;
+            side.set_rect_height(&mut shown_rect, how_shown * full_rect.height());
+            inner_response.response.rect = shown_rect;
+        }
         let rect = inner_response.response.rect;
 
         {
@@ -837,9 +1207,9 @@ impl TopBottomPanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        PanelState { rect: full_rect }.store(ui.ctx(), id);
 
-        {
+        if 0.0 < how_shown {
             let stroke = if is_resizing {
                 ui.style().visuals.widgets.active.fg_stroke // highly visible
             } else if resize_hover {
@@ -7,7 +7,7 @@ use emath::{Align, Pos2, Rect, RectAlign, Vec2, vec2};
 use crate::{
     Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
     Sense, Ui, UiKind, UiStackInfo,
-    containers::menu::{MenuConfig, MenuState, menu_style},
+    containers::menu::{MenuConfig, MenuState, menu_contents_ui, menu_style},
     style::StyleModifier,
 };
 
@@ -591,7 +591,15 @@ impl<'a> Popup<'a> {
         let mut response = area.show(&ctx, |ui| {
             style.apply(ui.style_mut());
             let frame = frame.unwrap_or_else(|| Frame::popup(ui.style()));
-            frame.show(ui, content).inner
+            frame
+                .show(ui, |ui| {
+                    if kind == PopupKind::Menu {
+                        menu_contents_ui(ui, id, content)
+                    } else {
+                        content(ui)
+                    }
+                })
+                .inner
         });
 
         let closed_by_click = match close_behavior {
@@ -1,6 +1,6 @@
 use crate::{
-    Align2, Color32, Context, CursorIcon, Id, NumExt as _, Rect, Response, Sense, Shape, Ui,
-    UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, pos2, vec2,
+    Align2, Color32, Context, CursorIcon, Id, NumExt as _, PointerButton, Rect, Response, Sense,
+    Shape, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, layout_state::LayoutIds, pos2, vec2,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -24,8 +24,19 @@ impl State {
         ctx.data_mut(|d| d.get_persisted(id))
     }
 
+    pub(crate) fn new(desired_size: Vec2) -> Self {
+        Self {
+            desired_size,
+            last_content_size: Vec2::ZERO,
+            requested_size: None,
+        }
+    }
+
     pub fn store(self, ctx: &Context, id: Id) {
-        ctx.data_mut(|d| d.insert_persisted(id, self));
+        ctx.data_mut(|d| {
+            d.insert_persisted(id, self);
+            LayoutIds::remember_size(d, id);
+        });
     }
 }
 
@@ -44,6 +55,9 @@ pub struct Resize {
 
     default_size: Vec2,
 
+    aspect_ratio: Option<f32>,
+    resize_step: Vec2,
+
     with_stroke: bool,
 }
 
@@ -56,6 +70,8 @@ impl Default for Resize {
             min_size: Vec2::splat(16.0),
             max_size: Vec2::splat(f32::INFINITY),
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
+            aspect_ratio: None,
+            resize_step: Vec2::ZERO,
             with_stroke: true,
         }
     }
@@ -172,6 +188,35 @@ impl Resize {
         self.resizable
     }
 
+    /// Keep `width / height` at this ratio when the user resizes, e.g. `16.0 / 9.0` for a video.
+    ///
+    /// When dragging a corner, the axis the pointer moved the most decides the size,
+    /// and the other one follows.
+    /// Double-clicking the resize corner shrinks the area to fit its contents, at this ratio.
+    ///
+    /// This only constrains interactive resizing.
+    /// [`Self::default_size`] is used as given, and [`Self::min_size`] and [`Self::max_size`]
+    /// take precedence over the ratio.
+    /// Contents that don't fit still make the area grow, like without a fixed ratio.
+    #[inline]
+    pub fn fixed_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Snap the size the user picks to multiples of this, e.g. the size of a character cell.
+    ///
+    /// A step of zero turns snapping off for that axis.
+    /// The snapping happens after [`Self::fixed_aspect_ratio`] is applied,
+    /// and has the same precedence.
+    ///
+    /// Default: [`Vec2::ZERO`].
+    #[inline]
+    pub fn resize_step(mut self, resize_step: impl Into<Vec2>) -> Self {
+        self.resize_step = resize_step.into();
+        self
+    }
+
     /// Not manually resizable, just takes the size of its contents.
     /// Text will not wrap, but will instead make your window width expand.
     pub fn auto_sized(self) -> Self {
@@ -197,6 +242,47 @@ impl Resize {
     }
 }
 
+impl Resize {
+    /// Apply [`Self::fixed_aspect_ratio`] and [`Self::resize_step`] to the size the user is dragging to.
+    ///
+    /// `drag_delta` is how far the pointer has moved since the drag started.
+    pub(crate) fn constrain_dragged_size(&self, size: Vec2, drag_delta: Vec2) -> Vec2 {
+        let width_leads = self
+            .aspect_ratio
+            .is_none_or(|ratio| drag_delta.y.abs() * ratio <= drag_delta.x.abs());
+        self.conform(size, width_leads, f32::round)
+    }
+
+    /// The smallest size that fits `content_size` and follows
+    /// [`Self::fixed_aspect_ratio`] and [`Self::resize_step`].
+    fn size_to_fit(&self, content_size: Vec2) -> Vec2 {
+        let width_leads = self
+            .aspect_ratio
+            .is_none_or(|ratio| content_size.y * ratio <= content_size.x);
+        self.conform(content_size, width_leads, f32::ceil)
+    }
+
+    fn conform(&self, mut size: Vec2, width_leads: bool, round: fn(f32) -> f32) -> Vec2 {
+        if let Some(ratio) = self
+            .aspect_ratio
+            .filter(|ratio| ratio.is_finite() && 0.0 < *ratio)
+        {
+            if width_leads {
+                size.y = size.x / ratio;
+            } else {
+                size.x = size.y * ratio;
+            }
+        }
+        for d in 0..2 {
+            let step = self.resize_step[d];
+            if 0.0 < step {
+                size[d] = round(size[d] / step) * step;
+            }
+        }
+        size
+    }
+}
+
 struct Prepared {
     id: Id,
     corner_id: Option<Id>,
@@ -243,10 +329,20 @@ impl Resize {
 
         if let Some(corner_id) = corner_id {
             if let Some(corner_response) = ui.ctx().read_response(corner_id) {
-                if let Some(pointer_pos) = corner_response.interact_pointer_pos() {
+                let press_origin = ui.input(|i| i.pointer.press_origin());
+                if let (Some(pointer_pos), Some(press_origin)) =
+                    (corner_response.interact_pointer_pos(), press_origin)
+                {
                     // Respond to the interaction early to avoid frame delay.
-                    user_requested_size =
-                        Some(pointer_pos - position + 0.5 * corner_response.rect.size());
+                    user_requested_size = Some(self.constrain_dragged_size(
+                        pointer_pos - position + 0.5 * corner_response.rect.size(),
+                        pointer_pos - press_origin,
+                    ));
+                }
+                if corner_response.hovered()
+                    && ui.input(|i| i.pointer.button_double_clicked(PointerButton::Primary))
+                {
+                    user_requested_size = Some(self.size_to_fit(state.last_content_size));
                 }
             }
         }
@@ -3,8 +3,8 @@ use core::f32;
 use emath::{GuiRounding as _, Pos2};
 
 use crate::{
-    InnerResponse, LayerId, PointerButton, Rangef, Rect, Response, Sense, Ui, UiBuilder, Vec2,
-    emath::TSTransform,
+    Context, InnerResponse, LayerId, PointerButton, Rangef, Rect, Response, Sense, Ui, UiBuilder,
+    Vec2, emath::TSTransform,
 };
 
 /// Creates a transformation that fits a given scene rectangle into the available screen size.
@@ -41,6 +41,8 @@ fn fit_to_rect_in_scene(
 /// * Supports zooming
 /// * Has no scroll bars
 /// * Has no limits on the scrolling
+///
+/// Use [`SceneTransform`] to convert between positions in the scene and on the screen.
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct Scene {
@@ -50,6 +52,86 @@ pub struct Scene {
     drag_pan_buttons: DragPanButtons,
 }
 
+/// Maps between positions in a [`Scene`] and positions on the screen.
+///
+/// Everything you add to a [`Scene`] uses the coordinates of the scene ("world" coordinates),
+/// including the positions given to [`Ui::put`] and the pointer positions of responses.
+/// Use this when you need to go between the two, e.g. to draw an overlay outside of the scene:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut scene_rect = egui::Rect::ZERO;
+/// let response = egui::Scene::new()
+///     .show(ui, &mut scene_rect, |ui| {
+///         ui.label("Hello");
+///     })
+///     .response;
+/// let transform = egui::SceneTransform::from_response(&response);
+/// let origin_on_screen = transform.to_screen(egui::Pos2::ZERO);
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SceneTransform {
+    /// From scene coordinates to screen (global) coordinates.
+    pub to_global: TSTransform,
+}
+
+impl SceneTransform {
+    #[inline]
+    pub fn new(to_global: TSTransform) -> Self {
+        Self { to_global }
+    }
+
+    /// The transform of the scene that this [`Ui`] is in.
+    ///
+    /// This is the identity if the [`Ui`] is not in a [`Scene`].
+    pub fn from_ui(ui: &Ui) -> Self {
+        Self::from_layer(ui.ctx(), ui.layer_id())
+    }
+
+    /// The transform of the scene that this [`Response`] is in,
+    /// e.g. the response returned by [`Scene::show`].
+    ///
+    /// This is the identity if the response is not in a [`Scene`].
+    pub fn from_response(response: &Response) -> Self {
+        Self::from_layer(&response.ctx, response.layer_id)
+    }
+
+    fn from_layer(ctx: &Context, layer_id: LayerId) -> Self {
+        Self::new(ctx.layer_transform_to_global(layer_id).unwrap_or_default())
+    }
+
+    /// How many screen points one scene unit is.
+    #[inline]
+    pub fn zoom(self) -> f32 {
+        self.to_global.scaling
+    }
+
+    /// Where on screen is this position in the scene?
+    #[inline]
+    pub fn to_screen(self, pos_in_scene: Pos2) -> Pos2 {
+        self.to_global * pos_in_scene
+    }
+
+    /// Where in the scene is this position on screen?
+    #[inline]
+    pub fn to_world(self, pos_on_screen: Pos2) -> Pos2 {
+        self.to_global.inverse() * pos_on_screen
+    }
+
+    /// Where on screen is this rectangle in the scene?
+    #[inline]
+    pub fn rect_to_screen(self, rect_in_scene: Rect) -> Rect {
+        self.to_global * rect_in_scene
+    }
+
+    /// Where in the scene is this rectangle on screen?
+    #[inline]
+    pub fn rect_to_world(self, rect_on_screen: Rect) -> Rect {
+        self.to_global.inverse() * rect_on_screen
+    }
+}
+
 /// Specifies which pointer buttons can be used to pan the scene by dragging.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct DragPanButtons(u8);
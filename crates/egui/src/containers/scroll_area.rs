@@ -3,8 +3,9 @@
 use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
 
 use crate::{
-    Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind,
-    UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    Color32, Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder,
+    UiKind, UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    text_selection::LabelSelectionState,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -12,6 +13,7 @@ use crate::{
 struct ScrollingToTarget {
     animation_time_span: (f64, f64),
     target_offset: f32,
+    easing: crate::style::ScrollEasing,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -37,6 +39,16 @@ pub struct State {
     #[cfg_attr(feature = "serde", serde(skip))]
     vel: Vec2,
 
+    /// How far the user has dragged past the ends, if [`ScrollArea::overscroll`] is enabled.
+    ///
+    /// Positive means past the end. The content moves less than this, see [`rubber_band`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
+    /// How fast [`Self::overscroll`] is changing while springing back.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll_vel: Vec2,
+
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
@@ -58,6 +70,8 @@ impl Default for State {
             content_is_too_large: Vec2b::FALSE,
             scroll_bar_interaction: Vec2b::FALSE,
             vel: Vec2::ZERO,
+            overscroll: Vec2::ZERO,
+            overscroll_vel: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
@@ -133,6 +147,41 @@ impl ScrollBarVisibility {
         Self::VisibleWhenNeeded,
         Self::AlwaysVisible,
     ];
+
+    /// Should the scroll bar be shown, given [`ScrollArea::scroll_bar_visibility`] for each axis?
+    fn show_bars(visibility: [Self; 2], needed: Vec2b, direction_enabled: Vec2b) -> Vec2b {
+        Vec2b::new(
+            visibility[0].show_bar(needed[0], direction_enabled[0]),
+            visibility[1].show_bar(needed[1], direction_enabled[1]),
+        )
+    }
+
+    fn show_bar(self, needed: bool, direction_enabled: bool) -> bool {
+        match self {
+            Self::AlwaysHidden => false,
+            Self::VisibleWhenNeeded => needed,
+            Self::AlwaysVisible => direction_enabled,
+        }
+    }
+}
+
+/// A colored tick on the vertical scroll bar of a [`ScrollArea`],
+/// showing where something interesting is in the content, e.g. a search hit or an error.
+///
+/// Clicking the marker scrolls to it. See [`ScrollArea::markers`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollMarker {
+    /// Where the marker is, in points from the top of the content.
+    pub offset: f32,
+
+    pub color: Color32,
+}
+
+impl ScrollMarker {
+    #[inline]
+    pub fn new(offset: f32, color: Color32) -> Self {
+        Self { offset, color }
+    }
 }
 
 /// What is the source of scrolling for a [`ScrollArea`].
@@ -246,12 +295,15 @@ impl AddAssign for ScrollSource {
 ///
 /// By default, scroll bars only show up when needed, i.e. when the contents
 /// is larger than the container.
-/// This is controlled by [`Self::scroll_bar_visibility`].
+/// This is controlled by [`Self::scroll_bar_visibility`],
+/// or separately for each axis with [`Self::horizontal_scroll_bar_visibility`]
+/// and [`Self::vertical_scroll_bar_visibility`].
 ///
 /// There are two flavors of scroll areas: solid and floating.
 /// Solid scroll bars use up space, reducing the amount of space available
 /// to the contents. Floating scroll bars float on top of the contents, covering it.
-/// You can change the scroll style by changing the [`crate::style::Spacing::scroll`].
+/// You can change the scroll style, including the width, rounding and colors of the bars,
+/// by changing the [`crate::style::Spacing::scroll`].
 ///
 /// ### Coordinate system
 /// * content: size of contents (generally large; that's why we want scroll bars)
@@ -282,7 +334,10 @@ pub struct ScrollArea {
     auto_shrink: Vec2b,
     max_size: Vec2,
     min_scrolled_size: Vec2,
-    scroll_bar_visibility: ScrollBarVisibility,
+
+    /// For the horizontal and vertical scroll bar, respectively.
+    scroll_bar_visibility: [ScrollBarVisibility; 2],
+
     scroll_bar_rect: Option<Rect>,
     id_salt: Option<Id>,
     offset_x: Option<f32>,
@@ -299,6 +354,23 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Keep scrolling after a drag is released?
+    kinetic: bool,
+
+    /// How quickly kinetic scrolling slows down, per second.
+    ///
+    /// `None` means a constant deceleration.
+    friction: Option<f32>,
+
+    /// Let drags and flings go past the ends, and spring back?
+    overscroll: bool,
+
+    /// Ticks to show on the vertical scroll bar.
+    markers: Vec<ScrollMarker>,
+
+    /// Does `Cmd+A` select all the text in the scroll area?
+    text_selection_scope: bool,
 }
 
 impl ScrollArea {
@@ -346,6 +418,11 @@ impl ScrollArea {
             wheel_scroll_multiplier: Vec2::splat(1.0),
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            kinetic: true,
+            friction: None,
+            overscroll: false,
+            markers: Vec::new(),
+            text_selection_scope: false,
         }
     }
 
@@ -398,9 +475,49 @@ impl ScrollArea {
     /// Set the visibility of both horizontal and vertical scroll bars.
     ///
     /// With `ScrollBarVisibility::VisibleWhenNeeded` (default), the scroll bar will be visible only when needed.
+    ///
+    /// Hidden scroll bars don't stop the user from scrolling with the scroll wheel,
+    /// or by dragging the contents (see [`Self::scroll_source`]).
+    ///
+    /// See also [`Self::horizontal_scroll_bar_visibility`] and [`Self::vertical_scroll_bar_visibility`].
     #[inline]
     pub fn scroll_bar_visibility(mut self, scroll_bar_visibility: ScrollBarVisibility) -> Self {
-        self.scroll_bar_visibility = scroll_bar_visibility;
+        self.scroll_bar_visibility = [scroll_bar_visibility; 2];
+        self
+    }
+
+    /// Set the visibility of the horizontal scroll bar, leaving the vertical one as is.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::scroll_area::ScrollBarVisibility;
+    /// egui::ScrollArea::both()
+    ///     .horizontal_scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+    ///     .vertical_scroll_bar_visibility(ScrollBarVisibility::AlwaysVisible)
+    ///     .show(ui, |ui| {
+    ///         // Add a lot of widgets here.
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn horizontal_scroll_bar_visibility(
+        mut self,
+        scroll_bar_visibility: ScrollBarVisibility,
+    ) -> Self {
+        self.scroll_bar_visibility[0] = scroll_bar_visibility;
+        self
+    }
+
+    /// Set the visibility of the vertical scroll bar, leaving the horizontal one as is.
+    ///
+    /// [`ScrollBarVisibility::AlwaysVisible`] keeps the width of the contents the same
+    /// whether or not they need scrolling, so the layout doesn't jump when they grow.
+    #[inline]
+    pub fn vertical_scroll_bar_visibility(
+        mut self,
+        scroll_bar_visibility: ScrollBarVisibility,
+    ) -> Self {
+        self.scroll_bar_visibility[1] = scroll_bar_visibility;
         self
     }
 
@@ -579,6 +696,10 @@ impl ScrollArea {
 
     /// Should the scroll area animate `scroll_to_*` functions?
     ///
+    /// The animation is configured with [`crate::Style::scroll_animation`],
+    /// and is cancelled as soon as the user scrolls or drags.
+    /// [`Self::scroll_offset`] is always applied instantly.
+    ///
     /// Default: `true`.
     #[inline]
     pub fn animated(mut self, animated: bool) -> Self {
@@ -586,6 +707,81 @@ impl ScrollArea {
         self
     }
 
+    /// Should the contents keep gliding when the user releases a drag?
+    ///
+    /// When enabled, the scroll area continues to move with the velocity of the drag,
+    /// and slows down according to [`Self::friction`].
+    ///
+    /// Only applies if [`Self::scroll_source()`] has set [`ScrollSource::drag`] to `true`.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    /// How quickly kinetic scrolling slows down.
+    ///
+    /// When set, the velocity decays exponentially, by a factor of `e` every `1 / friction` seconds,
+    /// which feels like scrolling on most touch screens.
+    /// A higher value means the scrolling stops sooner.
+    ///
+    /// By default, the velocity decreases by a constant 1000 points per second, every second.
+    ///
+    /// See [`Self::kinetic`].
+    #[inline]
+    pub fn friction(mut self, friction: f32) -> Self {
+        self.friction = Some(friction.max(0.0));
+        self
+    }
+
+    /// Let the user drag the contents past the ends, and make them spring back when released.
+    ///
+    /// The further past the end, the more resistance there is.
+    /// A [`Self::kinetic`] fling that hits an end bounces a little.
+    /// This is how scrolling usually feels on touch screens.
+    ///
+    /// This only applies to scrolling by dragging the contents (see [`ScrollSource::drag`]),
+    /// not to the mouse wheel or the scroll bars.
+    /// The offset in [`State::offset`], and the viewport given to [`Self::show_viewport`],
+    /// always stay within the contents.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Show these markers on the vertical scroll bar, e.g. for search hits or errors.
+    ///
+    /// Each marker is shown where it is in the content, relative to the length of the track,
+    /// and clicking it scrolls so that it is in the middle of the view.
+    /// They are only shown when the vertical scroll bar is.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::scroll_area::ScrollMarker;
+    /// let row_height = ui.text_style_height(&egui::TextStyle::Body);
+    /// let errors = [17, 42];
+    /// egui::ScrollArea::vertical()
+    ///     .markers(errors.map(|row| {
+    ///         ScrollMarker::new(row as f32 * row_height, egui::Color32::RED)
+    ///     }))
+    ///     .show_rows(ui, row_height, 100, |ui, rows| {
+    ///         for row in rows {
+    ///             ui.label(format!("Row {row}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn markers(mut self, markers: impl IntoIterator<Item = ScrollMarker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.direction_enabled[0] || self.direction_enabled[1]
@@ -614,6 +810,20 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Make `Cmd+A` select all the text in the selectable labels of this scroll area,
+    /// when the text selection is in it or the pointer is over it.
+    ///
+    /// Useful for e.g. a chat log, where one message is made of many labels.
+    ///
+    /// See [`LabelSelectionState::SELECTION_SCOPE_TAG`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn text_selection_scope(mut self, text_selection_scope: bool) -> Self {
+        self.text_selection_scope = text_selection_scope;
+        self
+    }
 }
 
 struct Prepared {
@@ -639,7 +849,7 @@ struct Prepared {
     /// and vice versa.
     current_bar_use: Vec2,
 
-    scroll_bar_visibility: ScrollBarVisibility,
+    scroll_bar_visibility: [ScrollBarVisibility; 2],
     scroll_bar_rect: Option<Rect>,
 
     /// Where on the screen the content is (excludes scroll bars).
@@ -660,6 +870,12 @@ struct Prepared {
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+    overscroll: bool,
+
+    /// Did the user drag the contents this frame?
+    content_dragged: bool,
+
+    markers: Vec<ScrollMarker>,
 }
 
 impl ScrollArea {
@@ -680,6 +896,11 @@ impl ScrollArea {
             wheel_scroll_multiplier,
             stick_to_end,
             animated,
+            kinetic,
+            friction,
+            overscroll,
+            markers,
+            text_selection_scope,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -693,14 +914,19 @@ impl ScrollArea {
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
 
-        state.offset.x = offset_x.unwrap_or(state.offset.x);
-        state.offset.y = offset_y.unwrap_or(state.offset.y);
+        // An explicit offset is set instantly, cancelling any animation:
+        for (d, offset) in [offset_x, offset_y].into_iter().enumerate() {
+            if let Some(offset) = offset {
+                state.offset[d] = offset;
+                state.offset_target[d] = None;
+            }
+        }
 
-        let show_bars: Vec2b = match scroll_bar_visibility {
-            ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => state.show_scroll,
-            ScrollBarVisibility::AlwaysVisible => direction_enabled,
-        };
+        let show_bars = ScrollBarVisibility::show_bars(
+            scroll_bar_visibility,
+            state.show_scroll,
+            direction_enabled,
+        );
 
         let show_bars_factor = Vec2::new(
             ctx.animate_bool_responsive(id.with("h"), show_bars[0]),
@@ -744,10 +970,21 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        let overscroll_offset = Vec2::new(
+            rubber_band(state.overscroll.x, inner_size.x),
+            rubber_band(state.overscroll.y, inner_size.y),
+        );
+        let content_max_rect = Rect::from_min_size(
+            inner_rect.min - state.offset - overscroll_offset,
+            content_max_size,
+        );
+        let mut ui_stack_info = UiStackInfo::new(UiKind::ScrollArea);
+        if text_selection_scope {
+            ui_stack_info = ui_stack_info.with_tag(LabelSelectionState::SELECTION_SCOPE_TAG);
+        }
         let mut content_ui = ui.new_child(
             UiBuilder::new()
-                .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
+                .ui_stack_info(ui_stack_info)
                 .max_rect(content_max_rect),
         );
 
@@ -771,6 +1008,7 @@ impl ScrollArea {
 
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+        let mut content_dragged = false;
 
         if scroll_source.drag
             && ui.is_enabled()
@@ -783,24 +1021,36 @@ impl ScrollArea {
                 .interact_rect
                 .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
 
-            if content_response_option
+            content_dragged = content_response_option
                 .as_ref()
-                .is_some_and(|response| response.dragged())
-            {
+                .is_some_and(|response| response.dragged());
+            if content_dragged {
                 for d in 0..2 {
                     if direction_enabled[d] {
-                        ui.input(|input| {
-                            state.offset[d] -= input.pointer.delta()[d];
-                        });
+                        let delta = -ui.input(|input| input.pointer.delta()[d]);
+                        if state.overscroll[d] == 0.0 {
+                            state.offset[d] += delta;
+                        } else {
+                            // Dragging back undoes the overscroll before it scrolls:
+                            let overscroll = state.overscroll[d] + delta;
+                            if 0.0 < overscroll * state.overscroll[d] {
+                                state.overscroll[d] = overscroll;
+                            } else {
+                                state.overscroll[d] = 0.0;
+                                state.offset[d] += overscroll;
+                            }
+                            state.overscroll_vel[d] = 0.0;
+                        }
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
                     }
                 }
             } else {
                 // Apply the cursor velocity to the scroll area when the user releases the drag.
-                if content_response_option
-                    .as_ref()
-                    .is_some_and(|response| response.drag_stopped())
+                if kinetic
+                    && content_response_option
+                        .as_ref()
+                        .is_some_and(|response| response.drag_stopped())
                 {
                     state.vel =
                         direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
@@ -810,11 +1060,18 @@ impl ScrollArea {
                     let stop_speed = 20.0; // Pixels per second.
                     let friction_coeff = 1000.0; // Pixels per second squared.
 
-                    let friction = friction_coeff * dt;
-                    if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
+                    let linear_friction = friction_coeff * dt;
+                    if !kinetic
+                        || state.vel[d].abs() < stop_speed
+                        || (friction.is_none() && linear_friction > state.vel[d].abs())
+                    {
                         state.vel[d] = 0.0;
                     } else {
-                        state.vel[d] -= friction * state.vel[d].signum();
+                        if let Some(friction) = friction {
+                            state.vel[d] *= (-friction * dt).exp();
+                        } else {
+                            state.vel[d] -= linear_friction * state.vel[d].signum();
+                        }
                         // Offset has an inverted coordinate system compared to
                         // the velocity, so we subtract it instead of adding it
                         state.offset[d] -= state.vel[d] * dt;
@@ -837,6 +1094,15 @@ impl ScrollArea {
             }
         }
 
+        if !content_dragged {
+            for d in 0..2 {
+                if state.overscroll[d] != 0.0 || state.overscroll_vel[d] != 0.0 {
+                    spring_back(&mut state.overscroll[d], &mut state.overscroll_vel[d], dt);
+                    ctx.request_repaint();
+                }
+            }
+        }
+
         // Scroll with an animation if we have a target offset (that hasn't been cleared by the code
         // above).
         for d in 0..2 {
@@ -853,7 +1119,7 @@ impl ScrollArea {
                         scroll_target.animation_time_span,
                         ui.input(|i| i.time),
                         dt,
-                        emath::ease_in_ease_out,
+                        |t| scroll_target.easing.apply(t),
                     );
                     if t < 1.0 {
                         state.offset[d] =
@@ -889,6 +1155,9 @@ impl ScrollArea {
             stick_to_end,
             saved_scroll_target,
             animated,
+            overscroll,
+            content_dragged,
+            markers,
         }
     }
 
@@ -1004,6 +1273,9 @@ impl Prepared {
             stick_to_end,
             saved_scroll_target,
             animated,
+            overscroll,
+            content_dragged,
+            markers,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1066,18 +1338,26 @@ impl Prepared {
 
                     if !animated {
                         state.offset[d] = target_offset;
-                    } else if let Some(animation) = &mut state.offset_target[d] {
+                    } else if let Some(scroll_target) = &mut state.offset_target[d] {
                         // For instance: the user is continuously calling `ui.scroll_to_cursor`,
-                        // so we don't want to reset the animation, but perhaps update the target:
-                        animation.target_offset = target_offset;
+                        // so we don't want to reset the animation, but perhaps update the target.
+                        // If the target moved, we carry on from where we are instead of starting over,
+                        // but make sure we have enough time left to get there:
+                        if 1.0 <= (target_offset - scroll_target.target_offset).abs() {
+                            let now = ui.input(|i| i.time);
+                            let end = now + animation.duration_for(delta) as f64;
+                            let span = &mut scroll_target.animation_time_span;
+                            span.1 = span.1.max(end);
+                        }
+                        scroll_target.target_offset = target_offset;
                     } else {
                         // The further we scroll, the more time we take.
                         let now = ui.input(|i| i.time);
-                        let animation_duration = (delta.abs() / animation.points_per_second)
-                            .clamp(animation.duration.min, animation.duration.max);
+                        let animation_duration = animation.duration_for(delta);
                         state.offset_target[d] = Some(ScrollingToTarget {
                             animation_time_span: (now, now + animation_duration as f64),
                             target_offset,
+                            easing: animation.easing,
                         });
                     }
                     ui.ctx().request_repaint();
@@ -1134,6 +1414,11 @@ impl Prepared {
                     });
                     let scroll_delta = scroll_delta * wheel_scroll_multiplier[d];
 
+                    if scroll_delta != 0.0 {
+                        // The user takes over from any ongoing animation:
+                        state.offset_target[d] = None;
+                    }
+
                     let scrolling_up = state.offset[d] > 0.0 && scroll_delta > 0.0;
                     let scrolling_down = state.offset[d] < max_offset[d] && scroll_delta < 0.0;
 
@@ -1151,17 +1436,16 @@ impl Prepared {
                         });
 
                         state.scroll_stuck_to_end[d] = false;
-                        state.offset_target[d] = None;
                     }
                 }
             }
         }
 
-        let show_scroll_this_frame = match scroll_bar_visibility {
-            ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
-            ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
-            ScrollBarVisibility::AlwaysVisible => direction_enabled,
-        };
+        let show_scroll_this_frame = ScrollBarVisibility::show_bars(
+            scroll_bar_visibility,
+            content_is_too_large,
+            direction_enabled,
+        );
 
         // Avoid frame delay; start showing scroll bar right away:
         if show_scroll_this_frame[0] && show_bars_factor.x <= 0.0 {
@@ -1181,6 +1465,21 @@ impl Prepared {
                 state.offset[d] = content_size[d] - inner_rect.size()[d];
             }
 
+            if overscroll {
+                // Turn dragging or gliding past the ends into overscroll:
+                let bounded = state.offset[d].at_most(max_offset[d]).at_least(0.0);
+                let overshoot = state.offset[d] - bounded;
+                if overshoot != 0.0 {
+                    if content_dragged {
+                        state.overscroll[d] += overshoot;
+                    } else if state.vel[d] != 0.0 {
+                        // Bounce:
+                        state.overscroll[d] += overshoot;
+                        state.overscroll_vel[d] = -state.vel[d];
+                    }
+                }
+            }
+
             let show_factor = show_bars_factor[d];
             if show_factor == 0.0 {
                 state.scroll_bar_interaction[d] = false;
@@ -1289,6 +1588,16 @@ impl Prepared {
 
             let handle_rect = calculate_handle_rect(d, &state.offset);
 
+            // Where the markers are on the track:
+            let marker_ranges: Vec<(Rangef, &ScrollMarker)> = if d == 1 {
+                markers
+                    .iter()
+                    .map(|marker| (Rangef::point(from_content(marker.offset)), marker))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
             let interact_id = id.with(d);
             let sense = if scroll_source.scroll_bar && ui.is_enabled() {
                 Sense::click_and_drag()
@@ -1302,7 +1611,17 @@ impl Prepared {
             if let Some(pointer_pos) = response.interact_pointer_pos() {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
-                        if handle_rect.contains(pointer_pos) {
+                        let clicked_marker = marker_ranges.iter().find(|(range, _)| {
+                            range.expand(MARKER_HIT_RADIUS).contains(pointer_pos[d])
+                        });
+                        if let Some((_, marker)) = clicked_marker {
+                            // Grab the handle where it needs to be to show the marker in the middle:
+                            let mut offset = state.offset;
+                            offset[d] = (marker.offset - 0.5 * inner_rect.size()[d])
+                                .at_most(max_offset[d])
+                                .at_least(0.0);
+                            pointer_pos[d] - calculate_handle_rect(d, &offset).min[d]
+                        } else if handle_rect.contains(pointer_pos) {
                             pointer_pos[d] - handle_rect.min[d]
                         } else {
                             let handle_top_pos_at_bottom =
@@ -1391,27 +1710,38 @@ impl Prepared {
                     1.0
                 };
 
-                let handle_color = if scroll_style.foreground_color {
-                    visuals.fg_stroke.color
-                } else {
-                    visuals.bg_fill
-                };
+                let handle_color = scroll_style.handle_color.unwrap_or({
+                    if scroll_style.foreground_color {
+                        visuals.fg_stroke.color
+                    } else {
+                        visuals.bg_fill
+                    }
+                });
+                let background_color = scroll_style
+                    .background_color
+                    .unwrap_or(ui.visuals().extreme_bg_color);
+                let corner_radius = scroll_style.corner_radius.unwrap_or(visuals.corner_radius);
 
                 // Background:
                 ui.painter().add(epaint::Shape::rect_filled(
                     outer_scroll_bar_rect,
-                    visuals.corner_radius,
-                    ui.visuals()
-                        .extreme_bg_color
-                        .gamma_multiply(background_opacity),
+                    corner_radius,
+                    background_color.gamma_multiply(background_opacity),
                 ));
 
                 // Handle:
                 ui.painter().add(epaint::Shape::rect_filled(
                     handle_rect,
-                    visuals.corner_radius,
+                    corner_radius,
                     handle_color.gamma_multiply(handle_opacity),
                 ));
+
+                // Markers, on top of the handle:
+                for (range, marker) in &marker_ranges {
+                    let rect = Rect::from_x_y_ranges(cross, range.expand(0.5 * MARKER_HEIGHT))
+                        .intersect(outer_scroll_bar_rect);
+                    ui.painter().rect_filled(rect, 0.0, marker.color);
+                }
             }
         }
 
@@ -1446,3 +1776,37 @@ impl Prepared {
         (content_size, state)
     }
 }
+
+/// How tall the ticks of [`ScrollMarker`]s are.
+const MARKER_HEIGHT: f32 = 2.0;
+
+/// How close to a [`ScrollMarker`] you need to click to scroll to it.
+const MARKER_HIT_RADIUS: f32 = 3.0;
+
+/// How far the contents move when the user has dragged `overscroll` points past the end.
+///
+/// The further past the end, the more resistance there is,
+/// and the contents never move further than `size`.
+fn rubber_band(overscroll: f32, size: f32) -> f32 {
+    if size <= 0.0 {
+        return 0.0;
+    }
+    let resistance = 0.55;
+    overscroll.signum() * size * (1.0 - 1.0 / (overscroll.abs() * resistance / size + 1.0))
+}
+
+/// Move the overscroll back towards zero, like a critically damped spring.
+fn spring_back(overscroll: &mut f32, vel: &mut f32, dt: f32) {
+    // Settles in about 200 ms:
+    let omega = 30.0;
+
+    let (x, v) = (*overscroll, *vel);
+    let decay = (-omega * dt).exp();
+    *overscroll = (x + (v + omega * x) * dt) * decay;
+    *vel = (v - omega * (v + omega * x) * dt) * decay;
+
+    if overscroll.abs() < 0.5 && vel.abs() < 20.0 {
+        *overscroll = 0.0;
+        *vel = 0.0;
+    }
+}
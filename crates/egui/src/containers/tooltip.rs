@@ -1,9 +1,31 @@
 use crate::pass_state::PerWidgetTooltipState;
 use crate::{
-    AreaState, Context, Id, InnerResponse, LayerId, Layout, Order, Popup, PopupAnchor, PopupKind,
-    Response, Sense,
+    AreaState, Context, Id, Image, InnerResponse, Label, LayerId, Layout, Order, Popup,
+    PopupAnchor, PopupKind, Response, Sense, WidgetText,
 };
-use emath::Vec2;
+use emath::{Align2, RectAlign, Vec2};
+
+/// Diagonally off each corner of the pointer, so the tooltip never covers the cursor.
+///
+/// The first one (bottom-right) is preferred, the others are used near the edges of the screen.
+const POINTER_ALIGNS: [RectAlign; 4] = [
+    RectAlign {
+        parent: Align2::RIGHT_BOTTOM,
+        child: Align2::LEFT_TOP,
+    },
+    RectAlign {
+        parent: Align2::LEFT_BOTTOM,
+        child: Align2::RIGHT_TOP,
+    },
+    RectAlign {
+        parent: Align2::RIGHT_TOP,
+        child: Align2::LEFT_BOTTOM,
+    },
+    RectAlign {
+        parent: Align2::LEFT_TOP,
+        child: Align2::RIGHT_BOTTOM,
+    },
+];
 
 pub struct Tooltip<'a> {
     pub popup: Popup<'a>,
@@ -69,10 +91,19 @@ impl Tooltip<'_> {
 
     /// Show a tooltip when hovering an enabled widget.
     pub fn for_enabled(response: &Response) -> Self {
+        let delay = response.ctx.style().interaction.tooltip_delay;
+        Self::for_enabled_with_delay(response, delay)
+    }
+
+    /// Like [`Self::for_enabled`], but wait `delay` seconds instead of
+    /// [`crate::style::Interaction::tooltip_delay`] before showing the tooltip.
+    ///
+    /// Use `0.0` to show it right away, e.g. for toolbar icons.
+    pub fn for_enabled_with_delay(response: &Response, delay: f32) -> Self {
         let mut tooltip = Self::for_widget(response);
         tooltip.popup = tooltip
             .popup
-            .open(response.enabled() && Self::should_show_tooltip(response));
+            .open(response.enabled() && Self::should_show_tooltip_with_delay(response, delay));
         tooltip
     }
 
@@ -85,10 +116,17 @@ impl Tooltip<'_> {
         tooltip
     }
 
-    /// Show the tooltip at the pointer position.
+    /// Show the tooltip next to the pointer, and follow it as it moves.
+    ///
+    /// The tooltip is placed below and to the right of the pointer,
+    /// and flips to the other side(s) when it would otherwise go off-screen.
     #[inline]
     pub fn at_pointer(mut self) -> Self {
-        self.popup = self.popup.at_pointer();
+        self.popup = self
+            .popup
+            .at_pointer()
+            .align(POINTER_ALIGNS[0])
+            .align_alternatives(&POINTER_ALIGNS[1..]);
         self
     }
 
@@ -146,6 +184,13 @@ impl Tooltip<'_> {
                 })
         });
 
+        if 0 < state.tooltip_count && popup.get_anchor() == PopupAnchor::Pointer {
+            // Stack under the previous tooltips, instead of diagonally off them:
+            popup = popup
+                .align(RectAlign::BOTTOM_START)
+                .align_alternatives(&[RectAlign::TOP_START]);
+        }
+
         let tooltip_area_id = Self::tooltip_id(parent_widget, state.tooltip_count);
         popup = popup.anchor(state.bounding_rect).id(tooltip_area_id);
 
@@ -212,6 +257,15 @@ impl Tooltip<'_> {
 
     /// Should we show a tooltip for this response?
     pub fn should_show_tooltip(response: &Response) -> bool {
+        let delay = response.ctx.style().interaction.tooltip_delay;
+        Self::should_show_tooltip_with_delay(response, delay)
+    }
+
+    /// Like [`Self::should_show_tooltip`], but with a custom `tooltip_delay` (in seconds).
+    ///
+    /// Once a tooltip has been shown, hovering another widget within
+    /// [`crate::style::Interaction::tooltip_grace_time`] still skips the delay.
+    pub fn should_show_tooltip_with_delay(response: &Response, tooltip_delay: f32) -> bool {
         if response.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -228,7 +282,6 @@ impl Tooltip<'_> {
 
         let style = response.ctx.style();
 
-        let tooltip_delay = style.interaction.tooltip_delay;
         let tooltip_grace_time = style.interaction.tooltip_grace_time;
 
         let (
@@ -397,3 +450,118 @@ impl Tooltip<'_> {
         })
     }
 }
+
+/// A tooltip with a bold title, body text, an image and a keyboard shortcut hint.
+///
+/// Show it with [`Response::on_hover_tooltip`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui::RichTooltip;
+/// ui.button("💾").on_hover_tooltip(
+///     RichTooltip::new("Save")
+///         .body("Write the document to disk.")
+///         .shortcut("Ctrl+S")
+///         .delay(0.0),
+/// );
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RichTooltip {
+    title: Option<WidgetText>,
+    body: Option<WidgetText>,
+    image: Option<String>,
+    shortcut: Option<WidgetText>,
+    max_width: Option<f32>,
+    delay: Option<f32>,
+}
+
+impl RichTooltip {
+    /// A tooltip with this title.
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..Default::default()
+        }
+    }
+
+    /// The text below the title, wrapped at [`Self::max_width`].
+    #[inline]
+    pub fn body(mut self, body: impl Into<WidgetText>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// An image to show below the body, loaded with [`crate::Image::from_uri`].
+    #[inline]
+    pub fn image(mut self, uri: impl Into<String>) -> Self {
+        self.image = Some(uri.into());
+        self
+    }
+
+    /// A keyboard shortcut hint to show next to the title, e.g. from [`Context::format_shortcut`].
+    #[inline]
+    pub fn shortcut(mut self, shortcut: impl Into<WidgetText>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// The width at which the text wraps.
+    ///
+    /// Default: [`crate::style::Spacing::tooltip_width`].
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Seconds to hover before the tooltip shows, e.g. `0.0` for toolbar icons.
+    ///
+    /// Default: [`crate::style::Interaction::tooltip_delay`].
+    #[inline]
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Show the tooltip if the (enabled) widget is hovered.
+    pub fn show(self, response: &Response) -> Option<InnerResponse<()>> {
+        let style = response.ctx.style();
+        let max_width = self.max_width.unwrap_or(style.spacing.tooltip_width);
+        let delay = self.delay.unwrap_or(style.interaction.tooltip_delay);
+        Tooltip::for_enabled_with_delay(response, delay)
+            .width(max_width)
+            .show(|ui| self.ui(ui, max_width))
+    }
+
+    fn ui(self, ui: &mut crate::Ui, max_width: f32) {
+        let Self {
+            title,
+            body,
+            image,
+            shortcut,
+            max_width: _,
+            delay: _,
+        } = self;
+
+        // Wrap instead of growing to the longest line:
+        ui.set_max_width(max_width);
+
+        if title.is_some() || shortcut.is_some() {
+            ui.horizontal_wrapped(|ui| {
+                if let Some(title) = title {
+                    ui.add(Label::new(title.strong()));
+                }
+                if let Some(shortcut) = shortcut {
+                    ui.add(Label::new(shortcut.weak()));
+                }
+            });
+        }
+        if let Some(body) = body {
+            ui.add(Label::new(body).wrap());
+        }
+        if let Some(uri) = image {
+            ui.add(Image::from_uri(uri).max_width(max_width));
+        }
+    }
+}
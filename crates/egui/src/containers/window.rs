@@ -44,6 +44,8 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    snapping: Option<bool>,
+    maximize_button: bool,
 }
 
 impl<'open> Window<'open> {
@@ -67,6 +69,8 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            snapping: None,
+            maximize_button: false,
         }
     }
 
@@ -140,6 +144,18 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// If `true`, the edges of the window snap to the edges of the screen and of other windows
+    /// while it is dragged.
+    ///
+    /// Hold down Alt to move the window freely.
+    ///
+    /// Default: [`crate::style::Interaction::window_snapping`].
+    #[inline]
+    pub fn snapping(mut self, snapping: bool) -> Self {
+        self.snapping = Some(snapping);
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -205,6 +221,24 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the `width / height` of the window contents at this ratio when the user resizes the window.
+    ///
+    /// See [`Resize::fixed_aspect_ratio`].
+    #[inline]
+    pub fn fixed_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.resize = self.resize.fixed_aspect_ratio(aspect_ratio);
+        self
+    }
+
+    /// Snap the size of the window contents to multiples of this when the user resizes the window.
+    ///
+    /// See [`Resize::resize_step`].
+    #[inline]
+    pub fn resize_step(mut self, resize_step: impl Into<Vec2>) -> Self {
+        self.resize = self.resize.resize_step(resize_step);
+        self
+    }
+
     /// Set current position of the window.
     /// If the window is movable it is up to you to keep track of where it moved to!
     #[inline]
@@ -247,6 +281,27 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Let the window be dragged partly outside of the rect it is constrained to,
+    /// as long as at least `margin` points of it stay inside.
+    ///
+    /// See [`Area::constrain_margin`].
+    #[inline]
+    pub fn constrain_margin(mut self, margin: f32) -> Self {
+        self.area = self.area.constrain_margin(margin);
+        self
+    }
+
+    /// Pull an unconstrained window back on screen if the screen changes and it ends up mostly outside.
+    ///
+    /// See [`Area::recover_offscreen`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn recover_offscreen(mut self, recover_offscreen: bool) -> Self {
+        self.area = self.area.recover_offscreen(recover_offscreen);
+        self
+    }
+
     /// Where the "root" of the window is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -349,6 +404,20 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Show a button in the title bar that maximizes the window,
+    /// so that it fills the area it is constrained to (see [`Self::constrain_to`]).
+    ///
+    /// Double-clicking the title bar then maximizes and restores the window too, instead of collapsing it.
+    /// Dragging a maximized window restores it to its previous size.
+    /// Whether the window is maximized is remembered like its position and size.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     #[inline]
@@ -438,6 +507,8 @@ impl Window<'_> {
             default_open,
             with_title_bar,
             fade_out,
+            snapping,
+            maximize_button,
         } = self;
 
         let header_color =
@@ -462,7 +533,25 @@ impl Window<'_> {
             CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
 
         let is_collapsed = with_title_bar && !collapsing.is_open();
-        let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
+
+        // While maximized, we remember the rect to restore the window to.
+        let maximize_button = maximize_button && with_title_bar;
+        let maximized_id = area_id.with("maximized");
+        let restore_id = area_id.with("restore");
+        let (mut restore_rect, restore_requested) = if maximize_button {
+            ctx.data_mut(|d| {
+                (
+                    d.get_persisted::<Rect>(maximized_id),
+                    d.remove_temp::<bool>(restore_id).unwrap_or_default(),
+                )
+            })
+        } else {
+            (None, false)
+        };
+
+        // A maximized window can't be resized either:
+        let possible =
+            PossibleInteractions::new(&area, &resize, is_collapsed || restore_rect.is_some());
 
         let resize = resize.resizable(false); // We resize it manually
         let mut resize = resize.id(resize_id);
@@ -503,6 +592,34 @@ impl Window<'_> {
             resize.max_size.y = resize.max_size.y.min(max_height);
         }
 
+        let margins = window_frame.total_margin().sum()
+            + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
+
+        if let Some(restore) = restore_rect {
+            let dragged =
+                area.move_response().dragged() && ctx.input(|i| i.pointer.is_decidedly_dragging());
+            if dragged || restore_requested {
+                // We restore the window right after the area has been constrained to its old size.
+                if dragged {
+                    restore_window_under_pointer(ctx, &mut area, restore, resize_id, margins);
+                } else {
+                    area.state_mut().set_left_top_pos(restore.min);
+                    request_window_size(ctx, resize_id, restore.size() - margins);
+                }
+                ctx.data_mut(|d| d.remove::<Rect>(maximized_id));
+                restore_rect = None;
+            } else {
+                // Follow the size of the viewport:
+                let maximized_rect = area.constrain_rect();
+                area.state_mut().set_left_top_pos(maximized_rect.min);
+                request_window_size(ctx, resize_id, maximized_rect.size() - margins);
+            }
+        }
+
+        if snapping.unwrap_or_else(|| ctx.style().interaction.window_snapping) {
+            snap_while_dragged(ctx, &mut area, area_layer_id);
+        }
+
         // First check for resize to avoid frame delay:
         let last_frame_outer_rect = area.state().rect();
         let resize_interaction = ctx.with_accessibility_parent(area.id(), || {
@@ -515,19 +632,15 @@ impl Window<'_> {
             )
         });
 
-        {
-            let margins = window_frame.total_margin().sum()
-                + vec2(0.0, title_bar_height_with_margin + title_content_spacing);
-
-            resize_response(
-                resize_interaction,
-                ctx,
-                margins,
-                area_layer_id,
-                &mut area,
-                resize_id,
-            );
-        }
+        resize_response(
+            resize_interaction,
+            &resize,
+            ctx,
+            margins,
+            area_layer_id,
+            &mut area,
+            resize_id,
+        );
 
         let mut area_content_ui = area.content_ui(ctx);
         if is_open {
@@ -537,6 +650,8 @@ impl Window<'_> {
             area_content_ui.multiply_opacity(opacity);
         }
 
+        let is_maximized = restore_rect.is_some();
+        let mut maximized = is_maximized;
         let content_inner = {
             ctx.with_accessibility_parent(area.id(), || {
                 // BEGIN FRAME --------------------------------
@@ -552,6 +667,7 @@ impl Window<'_> {
                         title,
                         show_close_button,
                         collapsible,
+                        maximize_button,
                         window_frame,
                         title_bar_height_with_margin,
                     );
@@ -578,6 +694,10 @@ impl Window<'_> {
                 let (content_inner, content_response) = collapsing
                     .show_body_unindented(&mut frame.content_ui, |ui| {
                         resize.show(ui, |ui| {
+                            if is_maximized {
+                                // Fill the window, even if the contents are smaller:
+                                ui.set_min_size(ui.available_size());
+                            }
                             if scroll.is_any_scroll_enabled() {
                                 scroll.show(ui, add_contents).inner
                             } else {
@@ -632,6 +752,7 @@ impl Window<'_> {
                         open.as_deref_mut(),
                         &mut collapsing,
                         collapsible,
+                        maximize_button.then_some(&mut maximized),
                     );
                 }
 
@@ -643,6 +764,17 @@ impl Window<'_> {
             })
         };
 
+        if maximized != restore_rect.is_some() {
+            // Takes effect next frame:
+            if restore_rect.is_some() {
+                ctx.data_mut(|d| d.insert_temp(restore_id, true));
+            } else {
+                let restore = area.state().rect();
+                ctx.data_mut(|d| d.insert_persisted(maximized_id, restore));
+            }
+            ctx.request_repaint();
+        }
+
         let full_response = area.end(ctx, area_content_ui);
 
         if full_response.should_close() {
@@ -659,6 +791,165 @@ impl Window<'_> {
     }
 }
 
+/// Make the window take on the given (inner) size next time its [`Resize`] is shown.
+fn request_window_size(ctx: &Context, resize_id: Id, size: Vec2) {
+    let mut state = resize::State::load(ctx, resize_id).unwrap_or_else(|| resize::State::new(size));
+    state.requested_size = Some(size);
+    state.store(ctx, resize_id);
+}
+
+/// Restore a maximized window that is being dragged,
+/// keeping the same relative part of the title bar under the pointer, like OS windows do.
+fn restore_window_under_pointer(
+    ctx: &Context,
+    area: &mut area::Prepared,
+    restore: Rect,
+    resize_id: Id,
+    margins: Vec2,
+) {
+    let maximized_rect = area.state().rect();
+    let (press_origin, pointer_pos) =
+        ctx.input(|i| (i.pointer.press_origin(), i.pointer.interact_pos()));
+    let (Some(press_origin), Some(pointer_pos)) = (press_origin, pointer_pos) else {
+        return;
+    };
+
+    let t = if 0.0 < maximized_rect.width() {
+        ((press_origin.x - maximized_rect.left()) / maximized_rect.width()).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let left = pointer_pos.x - t * restore.width();
+    let top = pointer_pos.y - (press_origin.y - maximized_rect.top());
+    area.state_mut().set_left_top_pos(pos2(left, top));
+    request_window_size(ctx, resize_id, restore.size() - margins);
+}
+
+/// Snap the edges of a dragged window to the edges of the screen and of other windows.
+///
+/// We keep track of where the window would be without snapping,
+/// so that it lets go once the pointer is dragged far enough away.
+fn snap_while_dragged(ctx: &Context, area: &mut area::Prepared, layer_id: LayerId) {
+    let free_pos_id = area.id().with("snap");
+    let response = area.move_response();
+    if !response.dragged() {
+        ctx.data_mut(|d| d.remove::<Pos2>(free_pos_id));
+        return;
+    }
+
+    let drag_delta = response.drag_delta();
+    let current_pos = area.state().left_top_pos();
+    let free_pos = ctx.data_mut(|d| {
+        let free_pos = d
+            .get_temp::<Pos2>(free_pos_id)
+            .map_or(current_pos, |pos| pos + drag_delta);
+        d.insert_temp(free_pos_id, free_pos);
+        free_pos
+    });
+    let mut rect = Rect::from_min_size(free_pos, area.state().rect().size());
+
+    if !ctx.input(|i| i.modifiers.alt) {
+        let distance = ctx.style().interaction.window_snap_distance;
+        let mut targets = vec![area.constrain_rect()];
+        ctx.memory(|mem| {
+            targets.extend(
+                mem.areas()
+                    .visible_windows()
+                    .filter(|(other, _)| *other != layer_id)
+                    .map(|(_, state)| state.rect()),
+            );
+        });
+
+        let snap_x = snap_axis(
+            rect.x_range(),
+            rect.y_range(),
+            targets.iter().map(|t| (t.x_range(), t.y_range())),
+            distance,
+        );
+        let snap_y = snap_axis(
+            rect.y_range(),
+            rect.x_range(),
+            targets.iter().map(|t| (t.y_range(), t.x_range())),
+            distance,
+        );
+
+        rect = rect.translate(vec2(
+            snap_x.map_or(0.0, |snap| snap.offset),
+            snap_y.map_or(0.0, |snap| snap.offset),
+        ));
+
+        // Show where we snapped to:
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, free_pos_id));
+        let stroke = ctx.style().visuals.selection.stroke;
+        if let Some(snap) = snap_x {
+            let y_range = Rangef::new(
+                snap.across.min.min(rect.top()),
+                snap.across.max.max(rect.bottom()),
+            );
+            painter.vline(snap.edge, y_range, stroke);
+        }
+        if let Some(snap) = snap_y {
+            let x_range = Rangef::new(
+                snap.across.min.min(rect.left()),
+                snap.across.max.max(rect.right()),
+            );
+            painter.hline(x_range, snap.edge, stroke);
+        }
+    }
+
+    if area.constrain() {
+        rect = area.constrain_window_rect(rect);
+    }
+    area.state_mut().set_left_top_pos(rect.min);
+}
+
+#[derive(Clone, Copy)]
+struct Snap {
+    /// How far to move the window.
+    offset: f32,
+
+    /// Where the edge we snapped to is.
+    edge: f32,
+
+    /// The extent of the thing we snapped to, along the edge.
+    across: Rangef,
+}
+
+/// Find the closest edge within `distance` of the edges of `range` along one axis.
+///
+/// Each target is its range along this axis, and its range along the other axis.
+/// Targets are only considered if they are close to us along the other axis.
+fn snap_axis(
+    range: Rangef,
+    across: Rangef,
+    targets: impl Iterator<Item = (Rangef, Rangef)>,
+    distance: f32,
+) -> Option<Snap> {
+    let mut best: Option<Snap> = None;
+    for (target, target_across) in targets {
+        if !target_across.expand(distance).intersects(across) {
+            continue;
+        }
+        for edge in [target.min, target.max] {
+            for own in [range.min, range.max] {
+                let offset = edge - own;
+                if offset.abs() <= distance
+                    && best
+                        .as_ref()
+                        .is_none_or(|best| offset.abs() < best.offset.abs())
+                {
+                    best = Some(Snap {
+                        offset,
+                        edge,
+                        across: target_across,
+                    });
+                }
+            }
+        }
+    }
+    best
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -822,6 +1113,7 @@ impl ResizeInteraction {
 
 fn resize_response(
     resize_interaction: ResizeInteraction,
+    resize: &Resize,
     ctx: &Context,
     margins: Vec2,
     area_layer_id: LayerId,
@@ -832,8 +1124,31 @@ fn resize_response(
         return;
     };
 
+    // Apply the aspect ratio and resize step, keeping the sides that aren't dragged in place:
+    let drag_delta = ctx.input(|i| {
+        let pointer = &i.pointer;
+        pointer
+            .interact_pos()
+            .zip(pointer.press_origin())
+            .map(|(pos, origin)| pos - origin)
+    });
+    let Some(drag_delta) = drag_delta else {
+        return; // The button was just released
+    };
+    let size = resize.constrain_dragged_size(new_rect.size() - margins, drag_delta) + margins;
+    if resize_interaction.left.drag {
+        new_rect.min.x = new_rect.max.x - size.x;
+    } else {
+        new_rect.max.x = new_rect.min.x + size.x;
+    }
+    if resize_interaction.top.drag {
+        new_rect.min.y = new_rect.max.y - size.y;
+    } else {
+        new_rect.max.y = new_rect.min.y + size.y;
+    }
+
     if area.constrain() {
-        new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
+        new_rect = area.constrain_window_rect(new_rect);
     }
 
     // TODO(emilk): add this to a Window state instead as a command "move here next frame"
@@ -1029,7 +1344,7 @@ fn resize_interaction(
 
 /// Fill in parts of the window frame when we resize by dragging that part
 fn paint_frame_interaction(ui: &Ui, rect: Rect, interaction: ResizeInteraction) {
-    use epaint::tessellator::path::add_circle_quadrant;
+    use epaint::tessellator::path::add_smooth_corner_quadrant;
 
     let visuals = if interaction.any_dragged() {
         ui.style().visuals.widgets.active
@@ -1054,6 +1369,10 @@ fn paint_frame_interaction(ui: &Ui, rect: Rect, interaction: ResizeInteraction)
     }
 
     let cr = CornerRadiusF32::from(ui.visuals().window_corner_radius);
+    // How far along the edges each corner reaches:
+    // Like the window frame, this follows the global style (see `Visuals::corner_smoothing`):
+    let corner_smoothing = ui.ctx().style().visuals.corner_smoothing;
+    let len = cr * (1.0 + corner_smoothing.clamp(0.0, 1.0));
 
     // Put the rect in the center of the fixed window stroke:
     let rect = rect.shrink(interaction.window_frame.stroke.width / 2.0);
@@ -1071,36 +1390,60 @@ fn paint_frame_interaction(ui: &Ui, rect: Rect, interaction: ResizeInteraction)
     let mut points = Vec::new();
 
     if right && !bottom && !top {
-        points.push(pos2(max.x, min.y + cr.ne));
-        points.push(pos2(max.x, max.y - cr.se));
+        points.push(pos2(max.x, min.y + len.ne));
+        points.push(pos2(max.x, max.y - len.se));
     }
     if right && bottom {
-        points.push(pos2(max.x, min.y + cr.ne));
-        points.push(pos2(max.x, max.y - cr.se));
-        add_circle_quadrant(&mut points, pos2(max.x - cr.se, max.y - cr.se), cr.se, 0.0);
+        points.push(pos2(max.x, min.y + len.ne));
+        points.push(pos2(max.x, max.y - len.se));
+        add_smooth_corner_quadrant(
+            &mut points,
+            pos2(max.x - len.se, max.y - len.se),
+            cr.se,
+            len.se,
+            0.0,
+        );
     }
     if bottom {
-        points.push(pos2(max.x - cr.se, max.y));
-        points.push(pos2(min.x + cr.sw, max.y));
+        points.push(pos2(max.x - len.se, max.y));
+        points.push(pos2(min.x + len.sw, max.y));
     }
     if left && bottom {
-        add_circle_quadrant(&mut points, pos2(min.x + cr.sw, max.y - cr.sw), cr.sw, 1.0);
+        add_smooth_corner_quadrant(
+            &mut points,
+            pos2(min.x + len.sw, max.y - len.sw),
+            cr.sw,
+            len.sw,
+            1.0,
+        );
     }
     if left {
-        points.push(pos2(min.x, max.y - cr.sw));
-        points.push(pos2(min.x, min.y + cr.nw));
+        points.push(pos2(min.x, max.y - len.sw));
+        points.push(pos2(min.x, min.y + len.nw));
     }
     if left && top {
-        add_circle_quadrant(&mut points, pos2(min.x + cr.nw, min.y + cr.nw), cr.nw, 2.0);
+        add_smooth_corner_quadrant(
+            &mut points,
+            pos2(min.x + len.nw, min.y + len.nw),
+            cr.nw,
+            len.nw,
+            2.0,
+        );
     }
     if top {
-        points.push(pos2(min.x + cr.nw, min.y));
-        points.push(pos2(max.x - cr.ne, min.y));
+        points.push(pos2(min.x + len.nw, min.y));
+        points.push(pos2(max.x - len.ne, min.y));
     }
     if right && top {
-        add_circle_quadrant(&mut points, pos2(max.x - cr.ne, min.y + cr.ne), cr.ne, 3.0);
-        points.push(pos2(max.x, min.y + cr.ne));
-        points.push(pos2(max.x, max.y - cr.se));
+        add_smooth_corner_quadrant(
+            &mut points,
+            pos2(max.x - len.ne, min.y + len.ne),
+            cr.ne,
+            len.ne,
+            3.0,
+        );
+        points.push(pos2(max.x, min.y + len.ne));
+        points.push(pos2(max.x, max.y - len.se));
     }
 
     ui.painter().add(Shape::line(points, stroke));
@@ -1122,11 +1465,13 @@ struct TitleBar {
 }
 
 impl TitleBar {
+    #[expect(clippy::fn_params_excessive_bools)]
     fn new(
         ui: &Ui,
         title: WidgetText,
         show_close_button: bool,
         collapsible: bool,
+        show_maximize_button: bool,
         window_frame: Frame,
         title_bar_height_with_margin: f32,
     ) -> Self {
@@ -1150,9 +1495,13 @@ impl TitleBar {
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title should be centered):
-            2.0 * (left_pad + button_size.x + item_spacing.x) + title_galley.size().x
+        let buttons_on_left = usize::from(collapsible);
+        let buttons_on_right = usize::from(show_close_button) + usize::from(show_maximize_button);
+        let buttons_per_side = buttons_on_left.max(buttons_on_right);
+        let minimum_width = if 0 < buttons_per_side {
+            // If at least one button is shown we make room for buttons on both sides (since title should be centered):
+            2.0 * (left_pad + buttons_per_side as f32 * (button_size.x + item_spacing.x))
+                + title_galley.size().x
         } else {
             left_pad + title_galley.size().x + left_pad
         };
@@ -1186,6 +1535,8 @@ impl TitleBar {
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    /// - `maximized`: if `None`, no "Maximize" button will be rendered, otherwise renders and processes
+    ///   the "Maximize" button, and double click on the title toggles it instead of `collapsing`
     fn ui(
         self,
         ui: &mut Ui,
@@ -1193,6 +1544,7 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
+        mut maximized: Option<&mut bool>,
     ) {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
@@ -1217,6 +1569,7 @@ impl TitleBar {
             });
         }
 
+        let has_close_button = open.is_some();
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
@@ -1224,6 +1577,15 @@ impl TitleBar {
             }
         }
 
+        if let Some(maximized) = maximized.as_deref_mut() {
+            if self
+                .maximize_button_ui(ui, has_close_button, *maximized)
+                .clicked()
+            {
+                *maximized = !*maximized;
+            }
+        }
+
         let text_pos =
             emath::align::center_size_in_rect(self.title_galley.size(), title_inner_rect)
                 .left_top();
@@ -1249,8 +1611,11 @@ impl TitleBar {
                 .hline(title_inner_rect.x_range(), y, window_frame.stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, maximize- and collapse buttons:
+        let mut double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        if has_close_button && maximized.is_some() {
+            double_click_rect.max.x -= ui.spacing().icon_width + ui.spacing().item_spacing.x;
+        }
 
         if false {
             ui.ctx().debug_painter().debug_rect(
@@ -1265,9 +1630,12 @@ impl TitleBar {
         if ui
             .interact(double_click_rect, id, Sense::click())
             .double_clicked()
-            && collapsible
         {
-            collapsing.toggle(ui);
+            if let Some(maximized) = maximized {
+                *maximized = !*maximized;
+            } else if collapsible {
+                collapsing.toggle(ui);
+            }
         }
     }
 
@@ -1285,6 +1653,21 @@ impl TitleBar {
         let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
         close_button(ui, button_rect)
     }
+
+    /// Paints the "Maximize" button at the right side of the title bar,
+    /// next to the "Close" button if there is one, and processes clicks on it.
+    fn maximize_button_ui(&self, ui: &mut Ui, has_close_button: bool, maximized: bool) -> Response {
+        let mut button_center = Align2::RIGHT_CENTER
+            .align_size_within_rect(Vec2::splat(self.inner_rect.height()), self.inner_rect)
+            .center();
+        if has_close_button {
+            button_center.x -= ui.spacing().icon_width + ui.spacing().item_spacing.x;
+        }
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Rect::from_center_size(button_center, button_size);
+        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
+        maximize_button(ui, button_rect, maximized)
+    }
 }
 
 /// Paints the "Close" button of the window and processes clicks on it.
@@ -1314,3 +1697,37 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+/// Paints the "Maximize" button of the window and processes clicks on it.
+///
+/// The button is a square, or two overlapping squares if the window is already maximized
+/// (so the button restores it).
+fn maximize_button(ui: &mut Ui, rect: Rect, maximized: bool) -> Response {
+    let maximize_id = ui.auto_id_with("window_maximize_button");
+    let response = ui.interact(rect, maximize_id, Sense::click());
+    let label = if maximized {
+        "Restore window"
+    } else {
+        "Maximize window"
+    };
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), label));
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    if maximized {
+        let offset = (rect.width() / 4.0).round_ui();
+        let back = Rect::from_min_max(rect.min + vec2(offset, 0.0), rect.max - vec2(0.0, offset));
+        let front = Rect::from_min_max(rect.min + vec2(0.0, offset), rect.max - vec2(offset, 0.0));
+        ui.painter()
+            .rect_stroke(back, 0.0, stroke, StrokeKind::Middle);
+        ui.painter()
+            .rect_stroke(front, 0.0, stroke, StrokeKind::Middle);
+    } else {
+        ui.painter()
+            .rect_stroke(rect, 0.0, stroke, StrokeKind::Middle);
+    }
+    response
+}
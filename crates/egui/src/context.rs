@@ -1,26 +1,36 @@
 #![warn(missing_docs)] // Let's keep `Context` well-documented.
 
-use std::{borrow::Cow, cell::RefCell, panic::Location, sync::Arc, time::Duration};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    panic::Location,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use emath::{GuiRounding as _, OrderedFloat};
 use epaint::{
-    ClippedPrimitive, ClippedShape, Color32, ImageData, ImageDelta, Pos2, Rect, StrokeKind,
-    TessellationOptions, TextureAtlas, TextureId, Vec2,
+    ClippedPrimitive, ClippedShape, Color32, ColorImage, ImageData, ImageDelta, Pos2, Rect,
+    StrokeKind, TessellationOptions, TextureAtlas, TextureId, Vec2,
     emath::{self, TSTransform},
     mutex::RwLock,
     stats::PaintStats,
     tessellator,
-    text::{FontInsert, FontPriority, Fonts},
+    text::{FontFamily, FontInsert, FontPriority, Fonts, InsertFontFamily},
     vec2,
 };
 
 use crate::{
-    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
-    ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
-    ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
+    Align2, CursorIcon, DeferredViewportUiCallback, Easing, FontDefinitions, Grid, Id,
+    ImmediateViewport, ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId,
+    LayoutState, Memory, ModifierNames, Modifiers, NumExt as _, Order, Painter, PointerButton,
+    RawInput, Response, RichText, ScrollArea, Sense, ShortcutRegistry, Style, TextStyle,
+    TextureHandle, TextureOptions, Ui, UiBuilder, ViewportBuilder, ViewportCommand, ViewportId,
+    ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput, Widget as _, WidgetRect,
+    WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
     data::output::PlatformOutput,
@@ -29,9 +39,9 @@ use crate::{
     interaction,
     layers::GraphicLayers,
     load::{self, Bytes, Loaders, SizedTexture},
-    memory::{Options, Theme},
+    memory::{FontEdit, Options, Theme},
     os::OperatingSystem,
-    output::FullOutput,
+    output::{FullOutput, RenderToTexture},
     pass_state::PassState,
     resize, response, scroll_area,
     util::IdTypeMap,
@@ -173,8 +183,18 @@ impl ContextImpl {
         viewport_id: ViewportId,
         cause: RepaintCause,
     ) {
+        let idle_repaint_interval = self.memory.options.idle_repaint_interval;
         let viewport = self.viewports.entry(viewport_id).or_default();
 
+        if let Some(idle_repaint_interval) = idle_repaint_interval {
+            let is_idle = viewport.input.events.is_empty()
+                && !viewport.repaint.requested_immediate_repaint_prev_pass();
+            if Duration::ZERO < delay && is_idle {
+                // Power-saving: nothing is happening, so there is no hurry.
+                delay = delay.max(idle_repaint_interval);
+            }
+        }
+
         if delay == Duration::ZERO {
             // Each request results in two repaints, just to give some things time to settle.
             // This solves some corner-cases of missing repaints on frame-delayed responses.
@@ -226,6 +246,80 @@ impl ContextImpl {
 
 // ----------------------------------------------------------------------------
 
+fn apply_font_edit(font_definitions: &mut FontDefinitions, edit: FontEdit) {
+    match edit {
+        FontEdit::Remove(name) => {
+            font_definitions.font_data.remove(&name);
+            for fonts in font_definitions.families.values_mut() {
+                fonts.retain(|font| font != &name);
+            }
+            for fonts in font_definitions.scripts.values_mut() {
+                fonts.retain(|font| font != &name);
+            }
+        }
+        FontEdit::SetPriority { name, family } => {
+            if !font_definitions.font_data.contains_key(&name) {
+                #[cfg(feature = "log")]
+                log::warn!("Can't change the priority of {name:?}: no such font");
+                return;
+            }
+            let fonts = font_definitions.families.entry(family.family).or_default();
+            fonts.retain(|font| font != &name);
+            match family.priority {
+                FontPriority::Highest => fonts.insert(0, name),
+                FontPriority::Lowest => fonts.push(name),
+            }
+        }
+    }
+}
+
+/// The [`crate::UserData`] of screenshots requested with [`Context::request_screenshot`].
+struct ScreenshotRequest {
+    rect: Option<Rect>,
+}
+
+/// Cut out the requested part of a screenshot, in whole pixels.
+fn crop_screenshot(
+    viewport_id: ViewportId,
+    image: &Arc<ColorImage>,
+    rect: Option<Rect>,
+    pixels_per_point: f32,
+) -> crate::Screenshot {
+    let [width, height] = image.size;
+    let screen_rect = Rect::from_min_size(
+        Pos2::ZERO,
+        vec2(width as f32, height as f32) / pixels_per_point,
+    );
+
+    let Some(rect) = rect else {
+        return crate::Screenshot {
+            viewport_id,
+            rect: screen_rect,
+            image: Arc::clone(image),
+        };
+    };
+
+    let to_pixel = |points: f32, size: usize| {
+        ((points * pixels_per_point).round().at_least(0.0) as usize).min(size)
+    };
+    let min_x = to_pixel(rect.min.x, width);
+    let min_y = to_pixel(rect.min.y, height);
+    let max_x = to_pixel(rect.max.x, width).max(min_x);
+    let max_y = to_pixel(rect.max.y, height).max(min_y);
+
+    let image = image.region_by_pixels([min_x, min_y], [max_x - min_x, max_y - min_y]);
+    let rect = Rect::from_min_max(
+        Pos2::new(min_x as f32, min_y as f32) / pixels_per_point,
+        Pos2::new(max_x as f32, max_y as f32) / pixels_per_point,
+    );
+
+    crate::Screenshot {
+        viewport_id,
+        rect,
+        image: Arc::new(image),
+    }
+}
+
 /// State stored per viewport.
 ///
 /// Mostly for internal use.
@@ -280,6 +374,7 @@ pub struct ViewportState {
     // Most of the things in `PlatformOutput` are not actually viewport dependent.
     pub output: PlatformOutput,
     pub commands: Vec<ViewportCommand>,
+    pub texture_renders: Vec<RenderToTexture>,
 
     // ----------------------
     // Cross-frame statistics:
@@ -507,6 +602,27 @@ impl ContextImpl {
 
         let screen_rect = viewport.input.screen_rect;
 
+        for event in &viewport.input.events {
+            if let crate::Event::Screenshot {
+                viewport_id,
+                user_data,
+                image,
+            } = event
+            {
+                let request = (user_data.data.as_ref())
+                    .and_then(|data| data.downcast_ref::<ScreenshotRequest>());
+                if let Some(ScreenshotRequest { rect }) = request {
+                    let screenshot = crop_screenshot(
+                        *viewport_id,
+                        image,
+                        *rect,
+                        viewport.input.pixels_per_point,
+                    );
+                    viewport.output.screenshots.push(screenshot);
+                }
+            }
+        }
+
         viewport.this_pass.begin_pass(screen_rect);
 
         {
@@ -520,6 +636,7 @@ impl ContextImpl {
                     &viewport.prev_pass.widgets,
                     &layers,
                     &self.memory.to_global,
+                    &viewport.prev_pass.context_menus_when_disabled,
                     pos,
                     interact_radius,
                 )
@@ -545,6 +662,7 @@ impl ContextImpl {
                 size: Some(screen_rect.size()),
                 interactable: true,
                 last_became_visible_at: None,
+                last_constrain_rect: None,
             },
         );
 
@@ -610,6 +728,17 @@ impl ContextImpl {
             log::trace!("Adding new fonts");
         }
 
+        if !self.memory.font_edits.is_empty() {
+            // Recreate all the fonts, which also gives us a new font atlas without the old glyphs:
+            self.fonts.clear();
+            for edit in self.memory.font_edits.drain(..) {
+                apply_font_edit(&mut self.font_definitions, edit);
+            }
+
+            #[cfg(feature = "log")]
+            log::trace!("Editing fonts");
+        }
+
         let text_alpha_from_coverage = self.memory.options.style().visuals.text_alpha_from_coverage;
 
         let mut is_new = false;
@@ -752,7 +881,17 @@ impl ContextImpl {
 /// }
 /// ```
 #[derive(Clone)]
-pub struct Context(Arc<RwLock<ContextImpl>>);
+pub struct Context(Arc<ContextShared>);
+
+/// What is behind the [`Arc`] of a [`Context`].
+struct ContextShared {
+    ctx: RwLock<ContextImpl>,
+
+    /// Does any layer want [`crate::WidgetInfo`]s stored this pass, i.e. is a menu open?
+    ///
+    /// Kept outside the lock, because [`Context::register_widget_info`] checks it for every widget.
+    store_widget_infos: AtomicBool,
+}
 
 impl std::fmt::Debug for Context {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -772,12 +911,17 @@ impl Default for Context {
             embed_viewports: true,
             ..Default::default()
         };
-        let ctx = Self(Arc::new(RwLock::new(ctx_impl)));
+        let ctx = Self(Arc::new(ContextShared {
+            ctx: RwLock::new(ctx_impl),
+            store_widget_infos: AtomicBool::new(false),
+        }));
 
         // Register built-in plugins:
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::Modal::register(&ctx);
+        crate::ShortcutRegistry::register(&ctx);
 
         ctx
     }
@@ -786,12 +930,12 @@ impl Default for Context {
 impl Context {
     /// Do read-only (shared access) transaction on Context
     fn read<R>(&self, reader: impl FnOnce(&ContextImpl) -> R) -> R {
-        reader(&self.0.read())
+        reader(&self.0.ctx.read())
     }
 
     /// Do read-write (exclusive access) transaction on Context
     fn write<R>(&self, writer: impl FnOnce(&mut ContextImpl) -> R) -> R {
-        writer(&mut self.0.write())
+        writer(&mut self.0.ctx.write())
     }
 
     /// Run the ui code for one frame.
@@ -910,7 +1054,14 @@ impl Context {
     pub fn begin_pass(&self, new_input: RawInput) {
         profiling::function_scope!();
 
-        self.write(|ctx| ctx.begin_pass(new_input));
+        let is_outermost_viewport = self.write(|ctx| {
+            let is_outermost_viewport = ctx.viewport_stack.is_empty();
+            ctx.begin_pass(new_input);
+            is_outermost_viewport
+        });
+        if is_outermost_viewport {
+            self.0.store_widget_infos.store(false, Ordering::Relaxed);
+        }
 
         // Plugins run just after the pass starts:
         self.read(|ctx| ctx.plugins.clone()).on_begin_pass(self);
@@ -1339,6 +1490,10 @@ impl Context {
             let input = &viewport.input;
             let memory = &mut ctx.memory;
 
+            // Disabled widgets can opt in to secondary clicks, for their context menu:
+            let context_menu_when_disabled =
+                !enabled && viewport.prev_pass.context_menus_when_disabled.contains(&id);
+
             if enabled
                 && sense.senses_click()
                 && memory.has_focus(id)
@@ -1356,7 +1511,8 @@ impl Context {
                 res.flags.set(Flags::FAKE_PRIMARY_CLICKED, true);
             }
 
-            if enabled && sense.senses_click() && Some(id) == viewport.interact_widgets.long_touched
+            if (enabled && sense.senses_click() || context_menu_when_disabled)
+                && Some(id) == viewport.interact_widgets.long_touched
             {
                 res.flags.set(Flags::LONG_TOUCHED, true);
             }
@@ -1370,9 +1526,14 @@ impl Context {
             );
 
             if res.enabled() {
+                let ignore_hover = viewport
+                    .this_pass
+                    .layers
+                    .get(&layer_id)
+                    .is_some_and(|layer| layer.ignore_hover);
                 res.flags.set(
                     Flags::HOVERED,
-                    viewport.interact_widgets.hovered.contains(&id),
+                    !ignore_hover && viewport.interact_widgets.hovered.contains(&id),
                 );
                 res.flags.set(
                     Flags::DRAGGED,
@@ -1397,8 +1558,10 @@ impl Context {
                     PointerEvent::Pressed { .. } => {
                         any_press = true;
                     }
-                    PointerEvent::Released { click, .. } => {
-                        if enabled && sense.senses_click() && clicked && click.is_some() {
+                    PointerEvent::Released { click, button } => {
+                        let senses_click = enabled && sense.senses_click()
+                            || context_menu_when_disabled && *button == PointerButton::Secondary;
+                        if senses_click && clicked && click.is_some() {
                             res.flags.set(Flags::CLICKED, true);
                         }
 
@@ -1441,19 +1604,41 @@ impl Context {
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
     /// With some debug flags it will store the widget info in [`crate::WidgetRects`] for later display.
+    /// It is also stored for the widgets in menus, which use the labels for type-ahead.
     #[inline]
     pub fn register_widget_info(&self, id: Id, make_info: impl Fn() -> crate::WidgetInfo) {
         #[cfg(debug_assertions)]
-        self.write(|ctx| {
-            if ctx.memory.options.style().debug.show_interactive_widgets {
-                ctx.viewport().this_pass.widgets.set_info(id, make_info());
-            }
-        });
-
+        let debug = self.read(|ctx| ctx.memory.options.style().debug.show_interactive_widgets);
         #[cfg(not(debug_assertions))]
-        {
-            _ = (self, id, make_info);
+        let debug = false;
+
+        // Usually nothing wants the info, so avoid taking the lock:
+        if !debug && !self.0.store_widget_infos.load(Ordering::Relaxed) {
+            return;
         }
+
+        let store = debug
+            || self.pass_state(|fs| {
+                fs.widgets.get(id).is_some_and(|widget| {
+                    fs.layers
+                        .get(&widget.layer_id)
+                        .is_some_and(|layer| layer.store_widget_infos)
+                })
+            });
+
+        if store {
+            // `make_info` may need the context, so we call it outside the lock:
+            let info = make_info();
+            self.write(|ctx| ctx.viewport().this_pass.widgets.set_info(id, info));
+        }
+    }
+
+    /// Store the [`crate::WidgetInfo`] of the widgets in this layer during this pass.
+    ///
+    /// Used by menus to find their entries by label.
+    pub(crate) fn store_widget_infos(&self, layer_id: LayerId) {
+        self.0.store_widget_infos.store(true, Ordering::Relaxed);
+        self.pass_state_mut(|fs| fs.layers.entry(layer_id).or_default().store_widget_infos = true);
     }
 
     /// Get a full-screen painter for a new or existing layer
@@ -1544,13 +1729,57 @@ impl Context {
 
     /// Copy the given image to the system clipboard.
     ///
+    /// This sends an [`crate::OutputCommand::CopyImage`] to the integration.
+    /// Integrations that can't put images on the clipboard log an error and do nothing,
+    /// so this is always safe to call.
+    ///
     /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
     /// HTTPS or localhost). If this method is used outside of a secure context, it will log an
     /// error and do nothing. See <https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts>.
+    ///
+    /// Together with [`Self::request_screenshot`] this can be used to copy a part of the screen:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("Copy screenshot").clicked() {
+    ///     ui.ctx().request_screenshot(None);
+    /// }
+    /// for screenshot in ui.ctx().output(|o| o.screenshots.clone()) {
+    ///     ui.ctx().copy_image((*screenshot.image).clone());
+    /// }
+    /// # });
+    /// ```
     pub fn copy_image(&self, image: crate::ColorImage) {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Take a screenshot of the next frame, or of a part of it.
+    ///
+    /// If `rect` is `Some`, only that part of the screen (in points) is kept.
+    /// The cropping is done on the captured pixels, so the result looks exactly like what was on screen.
+    ///
+    /// There is a latency of one frame: the integration captures the frame after it has been painted,
+    /// and sends it back as input, so the screenshot arrives in [`PlatformOutput::screenshots`] of the next frame.
+    /// You can read it in your ui code with [`Self::output`],
+    /// or from [`FullOutput::platform_output`] if you are writing an integration.
+    ///
+    /// This only works with integrations that support [`ViewportCommand::Screenshot`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("Export as PNG").clicked() {
+    ///     ui.ctx().request_screenshot(None);
+    /// }
+    /// for screenshot in ui.ctx().output(|o| o.screenshots.clone()) {
+    ///     // Save `screenshot.image` to disk…
+    /// }
+    /// # });
+    /// ```
+    pub fn request_screenshot(&self, rect: Option<Rect>) {
+        let user_data = crate::UserData::new(ScreenshotRequest { rect });
+        self.send_viewport_cmd(ViewportCommand::Screenshot(user_data));
+        self.request_repaint(); // so that we get the result
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -1586,19 +1815,73 @@ impl Context {
 
     /// Format the given shortcut in a human-readable way (e.g. `Ctrl+Shift+X`).
     ///
+    /// Uses the conventions of [`Self::os`], see [`KeyboardShortcut::format_for`].
+    /// On macOS, this falls back to names (e.g. `Shift+Cmd+X`)
+    /// if the font can't show the symbols.
+    ///
     /// Can be used to get the text for [`crate::Button::shortcut_text`].
     pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
         let os = self.os();
 
         let is_mac = os.is_mac();
 
-        if is_mac && self.can_show_modifier_symbols() {
-            shortcut.format(&ModifierNames::SYMBOLS, is_mac)
-        } else {
+        let formatted = shortcut.format_for(os);
+        if is_mac && !self.can_show_symbols(&formatted) {
             shortcut.format(&ModifierNames::NAMES, is_mac)
+        } else {
+            formatted
         }
     }
 
+    fn can_show_symbols(&self, text: &str) -> bool {
+        let font_id = TextStyle::Body.resolve(&self.style());
+        self.fonts(|f| f.lock().fonts.font(&font_id).has_glyphs(text))
+    }
+
+    /// Read-only access to the [`ShortcutRegistry`].
+    pub fn shortcuts<R>(&self, reader: impl FnOnce(&ShortcutRegistry) -> R) -> R {
+        self.data(|data| {
+            reader(
+                &data
+                    .get_temp::<ShortcutRegistry>(Id::NULL)
+                    .unwrap_or_default(),
+            )
+        })
+    }
+
+    /// Read-write access to the [`ShortcutRegistry`].
+    pub fn shortcuts_mut<R>(&self, writer: impl FnOnce(&mut ShortcutRegistry) -> R) -> R {
+        self.data_mut(|data| writer(data.get_temp_mut_or_default::<ShortcutRegistry>(Id::NULL)))
+    }
+
+    /// Bind `shortcut` to `action` in the [`ShortcutRegistry`].
+    ///
+    /// Registering the same action again replaces its shortcut,
+    /// so it is fine to call this every frame.
+    pub fn register_shortcut(&self, action: impl Into<String>, shortcut: KeyboardShortcut) {
+        self.shortcuts_mut(|registry| registry.insert(action, shortcut));
+    }
+
+    /// Was the shortcut of `action` in the [`ShortcutRegistry`] pressed this pass?
+    pub fn shortcut_triggered(&self, action: &str) -> bool {
+        self.shortcuts(|registry| registry.triggered(action))
+    }
+
+    /// The current layout of the windows, panels and collapsing headers, for saving it.
+    ///
+    /// See [`LayoutState`].
+    pub fn layout_snapshot(&self) -> LayoutState {
+        LayoutState::snapshot(self)
+    }
+
+    /// Restore a layout from [`Self::layout_snapshot`].
+    ///
+    /// Windows, panels and collapsing headers that haven't been shown yet
+    /// start out the way they are in `layout`.
+    pub fn apply_layout_snapshot(&self, layout: &LayoutState) {
+        layout.apply(self);
+    }
+
     /// The total number of completed frames.
     ///
     /// Starts at zero, and is incremented once at the end of each call to [`Self::run`].
@@ -1957,6 +2240,34 @@ impl Context {
         }
     }
 
+    /// Remove a font that was added with [`Self::set_fonts`] or [`Self::add_font`].
+    ///
+    /// The font is removed from all font families and from [`FontDefinitions::scripts`],
+    /// which fall back to their remaining fonts.
+    /// Text in a family without any fonts left is shown as replacement characters.
+    ///
+    /// All fonts are reloaded at the start of the next pass,
+    /// so the glyphs of the removed font no longer take up space in the font texture.
+    pub fn remove_font(&self, name: &str) {
+        self.memory_mut(|mem| mem.font_edits.push(FontEdit::Remove(name.to_owned())));
+    }
+
+    /// Move an installed font to the front or back of the fallback order of a [`FontFamily`].
+    ///
+    /// If the family does not use the font yet, it is added to it.
+    /// This lets you change the fallback order without rebuilding all the [`FontDefinitions`].
+    ///
+    /// The change will become active at the start of the next pass.
+    /// Names of fonts that are not installed are ignored.
+    pub fn set_font_priority(&self, family: FontFamily, name: &str, priority: FontPriority) {
+        self.memory_mut(|mem| {
+            mem.font_edits.push(FontEdit::SetPriority {
+                name: name.to_owned(),
+                family: InsertFontFamily { family, priority },
+            });
+        });
+    }
+
     /// Does the OS use dark or light mode?
     /// This is used when the theme preference is set to [`crate::ThemePreference::System`].
     pub fn system_theme(&self) -> Option<Theme> {
@@ -2218,6 +2529,71 @@ impl Context {
         TextureHandle::new(tex_mngr, tex_id)
     }
 
+    /// Paint some ui into a new texture, instead of onto the screen.
+    ///
+    /// `add_contents` is laid out in a [`Ui`] of the given `size` (in points),
+    /// and what it paints is sent to the backend in [`FullOutput::texture_renders`]
+    /// to be rendered into the returned texture before this frame is painted.
+    /// This is useful for thumbnails, previews, or for cross-fading between two screens.
+    ///
+    /// The contents are only painted: nothing in there can be hovered, clicked or dragged.
+    /// The texture is freed when the returned [`TextureHandle`] is dropped.
+    ///
+    /// Not all backends support this. Those that don't will leave the texture transparent.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let texture = ui.ctx().render_ui_to_texture("preview", egui::vec2(200.0, 100.0), |ui| {
+    ///     ui.heading("Hello");
+    ///     ui.label("This is rendered off-screen");
+    /// });
+    /// ui.image((texture.id(), egui::vec2(100.0, 50.0)));
+    /// # });
+    /// ```
+    pub fn render_ui_to_texture(
+        &self,
+        name: impl Into<String>,
+        size: Vec2,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> TextureHandle {
+        let name = name.into();
+        let pixels_per_point = self.pixels_per_point();
+        let size_in_pixels = [
+            (size.x * pixels_per_point).round().at_least(1.0) as usize,
+            (size.y * pixels_per_point).round().at_least(1.0) as usize,
+        ];
+
+        let id = Id::new("render_ui_to_texture").with(&name);
+        let texture = self.load_texture(
+            name,
+            ColorImage::filled(size_in_pixels, Color32::TRANSPARENT),
+            TextureOptions::LINEAR,
+        );
+
+        // This layer is not an `Area`, so nothing on it is interactive:
+        let layer_id = LayerId::new(Order::Background, id);
+        let mut ui = Ui::new(
+            self.clone(),
+            id,
+            UiBuilder::new()
+                .layer_id(layer_id)
+                .max_rect(Rect::from_min_size(Pos2::ZERO, size)),
+        );
+        add_contents(&mut ui);
+        drop(ui);
+
+        let shapes = self.graphics_mut(|graphics| graphics.entry(layer_id).take());
+        let render = RenderToTexture {
+            texture_id: texture.id(),
+            size_in_pixels,
+            pixels_per_point,
+            shapes,
+        };
+        self.write(|ctx| ctx.viewport().texture_renders.push(render));
+
+        texture
+    }
+
     /// Low-level texture manager.
     ///
     /// In general it is easier to use [`Self::load_texture`] and [`TextureHandle`].
@@ -2491,6 +2867,7 @@ impl ContextImpl {
         let shapes = viewport
             .graphics
             .drain(self.memory.areas().order(), &self.memory.to_global);
+        let texture_renders = std::mem::take(&mut viewport.texture_renders);
 
         let mut repaint_needed = false;
 
@@ -2616,6 +2993,7 @@ impl ContextImpl {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders,
         }
     }
 }
@@ -2638,7 +3016,9 @@ impl Context {
         // it takes to tessellate them, so it is not a worth optimization.
 
         self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
+            let mut tessellation_options = ctx.memory.options.tessellation_options;
+            tessellation_options.corner_smoothing =
+                ctx.memory.options.style().visuals.corner_smoothing;
             let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
                 fonts.texture_atlas()
             } else {
@@ -2964,6 +3344,8 @@ impl Context {
     }
 
     /// Like [`Self::animate_bool`] but allows you to control the easing function.
+    ///
+    /// See also [`Self::animate_bool_eased`].
     #[track_caller] // To track repaint cause
     pub fn animate_bool_with_easing(&self, id: Id, value: bool, easing: fn(f32) -> f32) -> f32 {
         let animation_time = self.style().animation_time;
@@ -3029,6 +3411,7 @@ impl Context {
                 animation_time,
                 id,
                 target_value,
+                emath::easing::linear,
             )
         });
         let animation_in_progress = animated_value != target_value;
@@ -3039,6 +3422,79 @@ impl Context {
         animated_value
     }
 
+    /// Like [`Self::animate_bool_with_time_and_easing`], but with an [`Easing`],
+    /// which can also be a [`Easing::Spring`].
+    ///
+    /// With a spring the value can overshoot, and so leave the `[0, 1]` range for a while.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let open = true;
+    /// let id = ui.id().with("panel");
+    /// let openness = ui.ctx().animate_bool_eased(id, open, 0.3, egui::Easing::SPRING);
+    /// # });
+    /// ```
+    #[track_caller] // To track repaint cause
+    pub fn animate_bool_eased(
+        &self,
+        id: Id,
+        target_value: bool,
+        animation_time: f32,
+        easing: Easing,
+    ) -> f32 {
+        match easing {
+            Easing::Spring { .. } => {
+                let target = if target_value { 1.0 } else { 0.0 };
+                self.animate_value_eased(id, target, animation_time, easing)
+            }
+            _ => self.animate_bool_with_time_and_easing(
+                id,
+                target_value,
+                animation_time,
+                easing.curve().unwrap_or(emath::easing::linear),
+            ),
+        }
+    }
+
+    /// Like [`Self::animate_value_with_time`], but with an [`Easing`].
+    ///
+    /// With [`Easing::Spring`] the animation time is ignored,
+    /// and the velocity is kept when the target changes mid-animation.
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_eased(
+        &self,
+        id: Id,
+        target_value: f32,
+        animation_time: f32,
+        easing: Easing,
+    ) -> f32 {
+        let animated_value = self.write(|ctx| {
+            let input = &ctx.viewports.entry(ctx.viewport_id()).or_default().input;
+            match easing {
+                Easing::Spring { stiffness, damping } => ctx.animation_manager.animate_spring(
+                    input,
+                    stiffness,
+                    damping,
+                    id,
+                    target_value,
+                ),
+                _ => ctx.animation_manager.animate_value(
+                    input,
+                    animation_time,
+                    id,
+                    target_value,
+                    easing.curve().unwrap_or(emath::easing::linear),
+                ),
+            }
+        });
+        let animation_in_progress = animated_value != target_value;
+        if animation_in_progress {
+            self.request_repaint();
+        }
+
+        animated_value
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
@@ -3586,6 +4042,27 @@ impl Context {
         }
     }
 
+    /// Stop loading the given image URI, if it is still in progress.
+    ///
+    /// Use this when an image is no longer needed before it has finished loading,
+    /// e.g. when the user has scrolled past it in a long gallery,
+    /// so that it doesn't compete with the images that are still visible.
+    ///
+    /// Unlike [`Self::forget_image`], images that have finished loading are kept.
+    /// See [`load::ImageLoader::cancel`].
+    pub fn cancel_load(&self, uri: &str) {
+        profiling::function_scope!();
+
+        let loaders = self.loaders();
+
+        for loader in loaders.bytes.lock().iter() {
+            loader.cancel(uri);
+        }
+        for loader in loaders.image.lock().iter() {
+            loader.cancel(uri);
+        }
+    }
+
     /// Release all memory and textures related to images used in [`Ui::image`] or [`crate::Image`].
     ///
     /// If you attempt to load any images again, they will be reloaded from scratch.
@@ -4041,6 +4518,30 @@ impl Context {
         });
     }
 
+    /// Let the widget with this id get all pointer input until the pointer is released,
+    /// no matter what is under the pointer.
+    ///
+    /// The widget is marked as dragged right away (see [`Self::set_dragged_id`]),
+    /// and stays dragged even if the pointer moves over other widgets.
+    /// No other widget is hovered, clicked or dragged meanwhile.
+    ///
+    /// The capture is released automatically when the pointer is released, or Escape is pressed.
+    /// Does nothing if no pointer button is down.
+    ///
+    /// See also [`crate::Response::set_pointer_capture`].
+    pub fn capture_pointer(&self, id: Id) {
+        if !self.input(|i| i.pointer.any_down()) {
+            return;
+        }
+        self.set_dragged_id(id);
+        self.write(|ctx| ctx.memory.interaction_mut().pointer_capture = Some(id));
+    }
+
+    /// The widget that has captured the pointer with [`Self::capture_pointer`], if any.
+    pub fn pointer_capture(&self) -> Option<Id> {
+        self.read(|ctx| ctx.memory.interaction().pointer_capture)
+    }
+
     /// Is something else being dragged?
     ///
     /// Returns true if we are dragging something, but not the given widget.
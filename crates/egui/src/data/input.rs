@@ -5,6 +5,7 @@ use epaint::ColorImage;
 use crate::{
     Key, Theme, ViewportId, ViewportIdMap,
     emath::{Pos2, Rect, Vec2},
+    os::OperatingSystem,
 };
 
 /// What the integrations provides to egui at the start of each frame.
@@ -395,6 +396,20 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The integration detected a "paste" event (e.g. Cmd+V) with an image in the clipboard,
+    /// e.g. a screenshot.
+    ///
+    /// Set by `egui-winit` (with its `clipboard` feature) when there is no text to paste.
+    /// Other backends never send this. See also [`crate::Response::pasted_image`].
+    PasteImage(std::sync::Arc<crate::ColorImage>),
+
+    /// The integration detected a "paste" event (e.g. Cmd+V) of files, e.g. copied in a file manager.
+    ///
+    /// Set by `egui-winit` when the pasted text is a list of `file://` URIs, one per line,
+    /// which is how most Linux file managers put files in the clipboard.
+    /// Other backends never send this. For files dropped into the app, see [`RawInput::dropped_files`].
+    PasteFiles(Vec<std::path::PathBuf>),
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -1047,9 +1062,10 @@ impl ModifierNames<'_> {
         };
 
         if is_mac {
+            // The order of the menus of macOS: ⌃⌥⇧⌘
             append_if(modifiers.ctrl, self.ctrl);
-            append_if(modifiers.shift, self.shift);
             append_if(modifiers.alt, self.mac_alt);
+            append_if(modifiers.shift, self.shift);
             append_if(modifiers.mac_cmd || modifiers.command, self.mac_cmd);
         } else {
             append_if(modifiers.ctrl || modifiers.command, self.ctrl);
@@ -1095,6 +1111,55 @@ impl KeyboardShortcut {
         }
         s
     }
+
+    /// Format the shortcut the way the menus of `os` do,
+    /// e.g. `⌥⇧⌘↑` on macOS and `Ctrl+Alt+Shift+Up` elsewhere.
+    ///
+    /// The macOS symbols need a font that has them,
+    /// which [`crate::Context::format_shortcut`] checks for you.
+    pub fn format_for(&self, os: OperatingSystem) -> String {
+        let is_mac = os.is_mac();
+        let names = if is_mac {
+            ModifierNames::SYMBOLS
+        } else {
+            ModifierNames::NAMES
+        };
+        let mut s = names.format(&self.modifiers, is_mac);
+        if !s.is_empty() {
+            s += names.concat;
+        }
+        s += if is_mac {
+            mac_key_symbol(self.logical_key)
+        } else {
+            key_name(self.logical_key)
+        };
+        s
+    }
+}
+
+/// How macOS menus show `key`.
+fn mac_key_symbol(key: Key) -> &'static str {
+    match key {
+        Key::ArrowDown => "↓",
+        Key::ArrowLeft => "←",
+        Key::ArrowRight => "→",
+        Key::ArrowUp => "↑",
+        Key::Backspace => "⌫",
+        Key::Delete => "⌦",
+        Key::Enter => "↩",
+        Key::Escape => "Esc",
+        Key::Tab => "⇥",
+        _ => key.symbol_or_name(),
+    }
+}
+
+/// How Windows and Linux menus show `key`.
+fn key_name(key: Key) -> &'static str {
+    match key {
+        Key::Delete => "Del",
+        Key::Escape => "Esc",
+        _ => key.name(),
+    }
 }
 
 #[test]
@@ -1110,6 +1175,27 @@ fn format_kb_shortcut() {
     );
     assert_eq!(cmd_shift_f.format(&ModifierNames::SYMBOLS, false), "⌃⇧F");
     assert_eq!(cmd_shift_f.format(&ModifierNames::SYMBOLS, true), "⇧⌘F");
+
+    let cmd_shift_p = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::P);
+    assert_eq!(
+        cmd_shift_p.format_for(OperatingSystem::Windows),
+        "Ctrl+Shift+P"
+    );
+    assert_eq!(cmd_shift_p.format_for(OperatingSystem::Nix), "Ctrl+Shift+P");
+    assert_eq!(cmd_shift_p.format_for(OperatingSystem::Mac), "⇧⌘P");
+
+    let all = KeyboardShortcut::new(
+        Modifiers::CTRL | Modifiers::ALT | Modifiers::SHIFT | Modifiers::MAC_CMD,
+        Key::ArrowUp,
+    );
+    assert_eq!(all.format_for(OperatingSystem::Mac), "⌃⌥⇧⌘↑");
+
+    let escape = KeyboardShortcut::new(Modifiers::NONE, Key::Escape);
+    assert_eq!(escape.format_for(OperatingSystem::Windows), "Esc");
+    assert_eq!(escape.format_for(OperatingSystem::Mac), "Esc");
+
+    let alt_down = KeyboardShortcut::new(Modifiers::ALT, Key::ArrowDown);
+    assert_eq!(alt_down.format_for(OperatingSystem::Nix), "Alt+Down");
 }
 
 // ----------------------------------------------------------------------------
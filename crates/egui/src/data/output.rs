@@ -33,6 +33,11 @@ pub struct FullOutput {
     /// It is up to the integration to spawn a native window for each viewport,
     /// and to close any window that no longer has a viewport in this map.
     pub viewport_output: ViewportIdMap<ViewportOutput>,
+
+    /// Shapes to paint into textures, instead of onto the screen.
+    ///
+    /// See [`RenderToTexture`] and [`crate::Context::render_ui_to_texture`].
+    pub texture_renders: Vec<RenderToTexture>,
 }
 
 impl FullOutput {
@@ -44,12 +49,14 @@ impl FullOutput {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
+        self.texture_renders.extend(texture_renders);
 
         for (id, new_viewport) in viewport_output {
             match self.viewport_output.entry(id) {
@@ -64,6 +71,31 @@ impl FullOutput {
     }
 }
 
+/// A request to paint some shapes into a texture, instead of onto the screen.
+///
+/// Created by [`crate::Context::render_ui_to_texture`].
+///
+/// The texture is already allocated (in [`FullOutput::textures_delta`]) with the right size.
+/// The backend should render [`Self::shapes`] into it _after_ applying [`crate::TexturesDelta::set`],
+/// but _before_ painting [`FullOutput::shapes`], which may show the texture.
+/// You can use [`crate::Context::tessellate`] with [`Self::pixels_per_point`] to turn the shapes into triangles.
+///
+/// Backends that do not support this will leave the texture transparent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderToTexture {
+    /// The texture to render into.
+    pub texture_id: epaint::TextureId,
+
+    /// The size of the texture, in physical pixels.
+    pub size_in_pixels: [usize; 2],
+
+    /// The number of physical pixels per point to tessellate the shapes with.
+    pub pixels_per_point: f32,
+
+    /// What to paint, with [`crate::Pos2::ZERO`] at the top left corner of the texture.
+    pub shapes: Vec<epaint::ClippedShape>,
+}
+
 /// Information about text being edited.
 ///
 /// Useful for IME.
@@ -91,6 +123,8 @@ pub enum OutputCommand {
     CopyText(String),
 
     /// Put this image to the system clipboard.
+    ///
+    /// Integrations that don't support copying images should log an error and ignore this.
     CopyImage(crate::ColorImage),
 
     /// Open this url in a browser.
@@ -162,6 +196,9 @@ pub struct PlatformOutput {
     /// If empty, there was never any calls.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub request_discard_reasons: Vec<RepaintCause>,
+
+    /// Screenshots requested with [`crate::Context::request_screenshot`] that arrived this frame.
+    pub screenshots: Vec<Screenshot>,
 }
 
 impl PlatformOutput {
@@ -199,6 +236,7 @@ impl PlatformOutput {
             accesskit_update,
             num_completed_passes,
             mut request_discard_reasons,
+            mut screenshots,
         } = newer;
 
         self.commands.append(&mut commands);
@@ -215,6 +253,7 @@ impl PlatformOutput {
         self.num_completed_passes += num_completed_passes;
         self.request_discard_reasons
             .append(&mut request_discard_reasons);
+        self.screenshots.append(&mut screenshots);
 
         #[cfg(feature = "accesskit")]
         {
@@ -237,6 +276,22 @@ impl PlatformOutput {
     }
 }
 
+/// A screenshot requested with [`crate::Context::request_screenshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Screenshot {
+    /// The viewport that was captured.
+    pub viewport_id: crate::ViewportId,
+
+    /// The captured region of the viewport, in points.
+    ///
+    /// This is the requested rectangle, clipped to the screen.
+    pub rect: crate::Rect,
+
+    /// The pixels of [`Self::rect`].
+    pub image: std::sync::Arc<crate::ColorImage>,
+}
+
 /// What URL to open, and how.
 ///
 /// Use with [`crate::Context::open_url`].
@@ -704,6 +759,7 @@ impl WidgetInfo {
             WidgetType::TextEdit => "text edit",
             WidgetType::Button => "button",
             WidgetType::Checkbox => "checkbox",
+            WidgetType::Switch => "switch",
             WidgetType::RadioButton => "radio",
             WidgetType::RadioGroup => "radio group",
             WidgetType::SelectableLabel => "selectable",
@@ -725,6 +781,8 @@ impl WidgetInfo {
             if *typ == WidgetType::Checkbox {
                 let state = if *selected { "checked" } else { "unchecked" };
                 description = format!("{state} {description}");
+            } else if *typ == WidgetType::Switch {
+                description += if *selected { " on" } else { " off" };
             } else {
                 description += if *selected { "selected" } else { "" };
             };
@@ -0,0 +1,235 @@
+//! Tools for debugging the performance of your app.
+//!
+//! See [`frame_time_overlay`].
+
+use crate::{
+    Align2, Area, Context, Frame, Id, Key, KeyboardShortcut, Modifiers, Order, Pos2, Sense, Shape,
+    Stroke, Ui, util::History, vec2,
+};
+
+/// A part of a frame that an integration can time, and report with [`report_phase`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FramePhase {
+    /// Gathering and processing input events.
+    Input,
+
+    /// Running the app code, i.e. the ui.
+    Update,
+
+    /// Tessellating the output shapes of egui into triangles.
+    Tessellation,
+}
+
+impl FramePhase {
+    pub const ALL: [Self; 3] = [Self::Input, Self::Update, Self::Tessellation];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Input => "Input",
+            Self::Update => "Update",
+            Self::Tessellation => "Tessellation",
+        }
+    }
+}
+
+/// Report how long a phase of the last frame took, in seconds.
+///
+/// egui has no clock of its own, so this is how the integration (or your app)
+/// can give the breakdown that [`frame_time_overlay`] shows.
+/// Report each phase once per frame.
+pub fn report_phase(ctx: &Context, phase: FramePhase, seconds: f32) {
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<FrameTimes>(Id::NULL).phases[phase as usize] = Some(seconds);
+    });
+}
+
+/// Show an overlay with the frame rate, and a graph of the recent frame times.
+///
+/// Call this every frame, e.g. at the end of your ui code.
+/// Press F12 to hide and show it.
+/// While it is shown, egui repaints continuously, so that the numbers reflect how fast your app can run.
+///
+/// The frame time is the time between the start of two frames.
+/// The phases of the frame are shown too if they are reported with [`report_phase`].
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// egui::debug::frame_time_overlay(ctx);
+/// # });
+/// ```
+///
+/// Use [`FrameTimeOverlay`] for more options.
+pub fn frame_time_overlay(ctx: &Context) {
+    FrameTimeOverlay::new().show(ctx);
+}
+
+/// An overlay with the frame rate, and a graph of the recent frame times.
+///
+/// See [`frame_time_overlay`].
+#[must_use = "You should call .show()"]
+pub struct FrameTimeOverlay {
+    toggle_shortcut: Option<KeyboardShortcut>,
+    corner: Align2,
+    max_frames: usize,
+}
+
+impl Default for FrameTimeOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameTimeOverlay {
+    pub fn new() -> Self {
+        Self {
+            toggle_shortcut: Some(KeyboardShortcut::new(Modifiers::NONE, Key::F12)),
+            corner: Align2::RIGHT_TOP,
+            max_frames: 120,
+        }
+    }
+
+    /// The shortcut that hides and shows the overlay, or `None` to always show it.
+    ///
+    /// Default: F12.
+    #[inline]
+    pub fn toggle_shortcut(mut self, toggle_shortcut: Option<KeyboardShortcut>) -> Self {
+        self.toggle_shortcut = toggle_shortcut;
+        self
+    }
+
+    /// Which corner of the screen to show the overlay in.
+    ///
+    /// Default: [`Align2::RIGHT_TOP`].
+    #[inline]
+    pub fn corner(mut self, corner: Align2) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// How many frames to keep track of.
+    ///
+    /// Default: 120.
+    #[inline]
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames.max(1);
+        self
+    }
+
+    pub fn show(self, ctx: &Context) {
+        let Self {
+            toggle_shortcut,
+            corner,
+            max_frames,
+        } = self;
+
+        let toggled = toggle_shortcut
+            .is_some_and(|shortcut| ctx.input_mut(|i| i.consume_shortcut(&shortcut)));
+        let (now, dt) = ctx.input(|i| (i.time, i.unstable_dt));
+        let pass_nr = ctx.cumulative_pass_nr();
+        let is_first_pass = ctx.current_pass_index() == 0;
+
+        let times = ctx.data_mut(|d| {
+            let times = d.get_temp_mut_or_default::<FrameTimes>(Id::NULL);
+            if toggled {
+                times.hidden = !times.hidden;
+            }
+            if times.frame_times.max_len() != max_frames {
+                times.frame_times = FrameTimes::history(max_frames);
+            }
+            // Record each frame only once, even if we are called several times.
+            // The first time we are called we don't know how long the last frame was.
+            if is_first_pass && times.last_pass_nr.is_some_and(|nr| nr != pass_nr) {
+                times.frame_times.add(now, dt);
+            }
+            times.last_pass_nr = Some(pass_nr);
+            times.clone()
+        });
+
+        if times.hidden && toggle_shortcut.is_some() {
+            return;
+        }
+        ctx.request_repaint();
+
+        let margin = ctx.style().spacing.window_margin.leftf();
+        let offset = -corner.to_sign() * margin;
+        Area::new(Id::new("egui::debug::frame_time_overlay"))
+            .order(Order::Debug)
+            .anchor(corner, offset)
+            .interactable(false)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| times.ui(ui));
+            });
+    }
+}
+
+/// The frame times, stored in [`crate::Memory::data`].
+#[derive(Clone)]
+struct FrameTimes {
+    /// Seconds from the start of one frame to the next.
+    frame_times: History<f32>,
+
+    /// The latest reported time of each [`FramePhase`].
+    phases: [Option<f32>; FramePhase::ALL.len()],
+
+    last_pass_nr: Option<u64>,
+    hidden: bool,
+}
+
+impl Default for FrameTimes {
+    fn default() -> Self {
+        Self {
+            frame_times: Self::history(FrameTimeOverlay::new().max_frames),
+            phases: Default::default(),
+            last_pass_nr: None,
+            hidden: false,
+        }
+    }
+}
+
+impl FrameTimes {
+    fn history(max_frames: usize) -> History<f32> {
+        History::new(0..max_frames, f32::INFINITY)
+    }
+
+    fn ui(&self, ui: &mut Ui) {
+        ui.style_mut().wrap_mode = Some(crate::TextWrapMode::Extend);
+        let history = &self.frame_times;
+
+        let latest = history.latest().unwrap_or_default();
+        let average = history.average().unwrap_or_default();
+        let max = history.values().fold(0.0, f32::max);
+        let fps = if 0.0 < average { 1.0 / average } else { 0.0 };
+
+        ui.monospace(format!("{fps:.1} fps"));
+        ui.monospace(format!("now {:.2} ms", 1e3 * latest));
+        ui.monospace(format!("avg {:.2} ms", 1e3 * average));
+        ui.monospace(format!("max {:.2} ms", 1e3 * max));
+
+        let (rect, _) = ui.allocate_exact_size(vec2(120.0, 30.0), Sense::hover());
+        let painter = ui.painter();
+        painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+        if 0.0 < max && 1 < history.len() {
+            let step = rect.width() / (history.max_len() - 1).max(1) as f32;
+            let left = rect.right() - step * (history.len() - 1) as f32;
+            let points: Vec<Pos2> = history
+                .values()
+                .enumerate()
+                .map(|(i, time)| {
+                    let x = left + step * i as f32;
+                    let y = rect.bottom() - rect.height() * time / max;
+                    Pos2::new(x, y)
+                })
+                .collect();
+            painter.add(Shape::line(
+                points,
+                Stroke::new(1.0, ui.visuals().text_color()),
+            ));
+        }
+
+        for phase in FramePhase::ALL {
+            if let Some(seconds) = self.phases[phase as usize] {
+                ui.monospace(format!("{}: {:.2} ms", phase.name(), 1e3 * seconds));
+            }
+        }
+    }
+}
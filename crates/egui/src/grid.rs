@@ -46,6 +46,16 @@ impl State {
         self.row_heights.get(row).copied()
     }
 
+    /// The width of `span` columns starting at `col`, including the spacing between them.
+    ///
+    /// Columns we don't know the width of yet are `min_col_width` wide.
+    fn span_width(&self, col: usize, span: usize, min_col_width: f32, x_spacing: f32) -> f32 {
+        (col..col + span)
+            .map(|col| self.col_width(col).unwrap_or(min_col_width))
+            .sum::<f32>()
+            + (span - 1) as f32 * x_spacing
+    }
+
     fn full_width(&self, x_spacing: f32) -> f32 {
         self.col_widths.iter().sum::<f32>()
             + (self.col_widths.len().at_least(1) - 1) as f32 * x_spacing
@@ -79,10 +89,17 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     color_picker: Option<ColorPickerFn>,
+    col_aligns: Vec<Align2>,
+
+    /// The widths needed by cells spanning multiple columns: `(col, span, width)`.
+    span_widths: Vec<(usize, usize, f32)>,
 
     // Cursor:
     col: usize,
     row: usize,
+
+    /// How many columns the next cell covers, see [`Ui::grid_span`].
+    span: usize,
 }
 
 impl GridLayout {
@@ -114,18 +131,21 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             color_picker: None,
+            col_aligns: Vec::new(),
+            span_widths: Vec::new(),
 
             col: 0,
             row: 0,
+            span: 1,
         }
     }
 }
 
 impl GridLayout {
-    fn prev_col_width(&self, col: usize) -> f32 {
+    /// The width of the current cell last frame, which may span several columns.
+    fn prev_cell_width(&self) -> f32 {
         self.prev_state
-            .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+            .span_width(self.col, self.span, self.min_cell_size.x, self.spacing.x)
     }
 
     fn prev_row_height(&self, row: usize) -> f32 {
@@ -138,16 +158,27 @@ impl GridLayout {
         self.max_cell_size.x.is_finite()
     }
 
+    /// Make the next cell cover `span` columns.
+    pub(crate) fn set_span(&mut self, span: usize) {
+        let columns_left = self.num_columns.map_or(usize::MAX, |num_columns| {
+            num_columns.saturating_sub(self.col)
+        });
+        self.span = span.at_most(columns_left).at_least(1);
+    }
+
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.col + self.span) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
             // so returning a big available width here can cause trouble.
             if self.is_first_frame {
-                self.curr_state
-                    .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                self.curr_state.span_width(
+                    self.col,
+                    self.span,
+                    self.min_cell_size.x,
+                    self.spacing.x,
+                )
             } else {
                 (self.initial_available.right() - region.cursor.left())
                     .at_most(self.max_cell_size.x)
@@ -155,17 +186,20 @@ impl GridLayout {
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
             self.max_cell_size.x
-        } else {
+        } else if self.prev_state.col_width(self.col).is_some() {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
-            self.prev_state
-                .col_width(self.col)
-                .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+            self.prev_cell_width()
+        } else {
+            self.curr_state
+                .span_width(self.col, self.span, self.min_cell_size.x, self.spacing.x)
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = width.max(
+            self.curr_state
+                .span_width(self.col, self.span, 0.0, self.spacing.x),
+        );
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -178,18 +212,23 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = if self.prev_state.col_width(self.col).is_some() {
+            self.prev_cell_width()
+        } else {
+            0.0
+        };
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size).round_ui()
     }
 
-    #[expect(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO(emilk): allow this alignment to be customized
-        Align2::LEFT_CENTER
-            .align_size_within_rect(size, frame)
-            .round_ui()
+        let align = self
+            .col_aligns
+            .get(self.col)
+            .copied()
+            .unwrap_or(Align2::LEFT_CENTER);
+        align.align_size_within_rect(size, frame).round_ui()
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -203,7 +242,7 @@ impl GridLayout {
             let debug_expand_height = self.style.debug.show_expand_height;
             if debug_expand_width || debug_expand_height {
                 let rect = widget_rect;
-                let too_wide = rect.width() > self.prev_col_width(self.col);
+                let too_wide = rect.width() > self.prev_cell_width();
                 let too_high = rect.height() > self.prev_row_height(self.row);
 
                 if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
@@ -227,13 +266,24 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        if self.span == 1 {
+            self.curr_state
+                .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        } else {
+            // Don't let a spanning cell widen just the first column it covers.
+            // We make room for it once we know the widths of all the columns, in `save`.
+            for col in self.col..self.col + self.span {
+                self.curr_state.set_min_col_width(col, self.min_cell_size.x);
+            }
+            self.span_widths
+                .push((self.col, self.span, widget_rect.width()));
+        }
         self.curr_state
             .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        cursor.min.x += self.prev_cell_width() + self.spacing.x;
+        self.col += self.span;
+        self.span = 1;
     }
 
     fn paint_row(&self, cursor: &Rect, painter: &Painter) {
@@ -265,12 +315,26 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y);
 
         self.col = 0;
+        self.span = 1;
         self.row += 1;
 
         self.paint_row(cursor, painter);
     }
 
-    pub(crate) fn save(&self) {
+    pub(crate) fn save(&mut self) {
+        // Widen the last column covered by a spanning cell, if the cell doesn't fit:
+        for &(col, span, width) in &self.span_widths {
+            let covered =
+                self.curr_state
+                    .span_width(col, span, self.min_cell_size.x, self.spacing.x);
+            if covered < width {
+                let last = col + span - 1;
+                let last_width = self.curr_state.col_width(last).unwrap_or(0.0);
+                self.curr_state
+                    .set_min_col_width(last, last_width + width - covered);
+            }
+        }
+
         // We need to always save state on the first frame, otherwise request_discard
         // would be called repeatedly (see #5132)
         if self.curr_state != self.prev_state || self.is_first_frame {
@@ -285,7 +349,9 @@ impl GridLayout {
 /// A simple grid layout.
 ///
 /// The cells are always laid out left to right, top-down.
-/// The contents of each cell will be aligned to the left and center.
+/// The contents of each cell will be aligned to the left and center,
+/// unless you change that with [`Grid::columns_align`].
+/// Use [`Ui::grid_span`] for a cell that covers several columns.
 ///
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
@@ -318,6 +384,7 @@ pub struct Grid {
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
+    col_aligns: Vec<Align2>,
 }
 
 impl Grid {
@@ -332,6 +399,7 @@ impl Grid {
             spacing: None,
             start_row: 0,
             color_picker: None,
+            col_aligns: Vec::new(),
         }
     }
 
@@ -398,6 +466,33 @@ impl Grid {
         self
     }
 
+    /// How to align the widgets within the cells of each column, starting with the first column.
+    ///
+    /// Columns past the end of this use [`Align2::LEFT_CENTER`], which is also the default.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let (mut name, mut age) = (String::new(), 42);
+    /// egui::Grid::new("form")
+    ///     .num_columns(2)
+    ///     .columns_align(&[egui::Align2::RIGHT_CENTER, egui::Align2::LEFT_CENTER])
+    ///     .show(ui, |ui| {
+    ///         ui.label("Name");
+    ///         ui.text_edit_singleline(&mut name);
+    ///         ui.end_row();
+    ///
+    ///         ui.label("Age");
+    ///         ui.add(egui::DragValue::new(&mut age));
+    ///         ui.end_row();
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn columns_align(mut self, col_aligns: &[Align2]) -> Self {
+        self.col_aligns = col_aligns.to_vec();
+        self
+    }
+
     /// Change which row number the grid starts on.
     /// This can be useful when you have a large [`crate::Grid`] inside of [`crate::ScrollArea::show_rows`].
     #[inline]
@@ -426,6 +521,7 @@ impl Grid {
             spacing,
             start_row,
             mut color_picker,
+            col_aligns,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -465,6 +561,7 @@ impl Grid {
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
                     spacing,
+                    col_aligns,
                     row: start_row,
                     ..GridLayout::new(ui, id, prev_state)
                 };
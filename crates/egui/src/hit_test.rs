@@ -43,6 +43,7 @@ pub fn hit_test(
     widgets: &WidgetRects,
     layer_order: &[LayerId],
     layer_to_global: &HashMap<LayerId, TSTransform>,
+    context_menus_when_disabled: &IdSet,
     pos: Pos2,
     search_radius: f32,
 ) -> WidgetHits {
@@ -130,7 +131,11 @@ pub fn hit_test(
     // the `enabled` flag everywhere:
     for w in &mut close {
         if !w.enabled {
-            w.sense -= Sense::CLICK;
+            if !context_menus_when_disabled.contains(&w.id) {
+                // Unless it wants a context menu. Then it also keeps the click from
+                // falling through to whatever is behind it.
+                w.sense -= Sense::CLICK;
+            }
             w.sense -= Sense::DRAG;
         }
     }
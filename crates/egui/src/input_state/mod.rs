@@ -2,7 +2,7 @@ mod touch_state;
 
 use crate::data::input::{
     Event, EventFilter, KeyboardShortcut, Modifiers, MouseWheelUnit, NUM_POINTER_BUTTONS,
-    PointerButton, RawInput, TouchDeviceId, ViewportInfo,
+    PointerButton, RawInput, TouchDeviceId, TouchPhase, ViewportInfo,
 };
 use crate::{
     emath::{NumExt as _, Pos2, Rect, Vec2, vec2},
@@ -38,10 +38,15 @@ pub struct InputOptions {
     /// This is to support "press and hold for context menu" on touch screens.
     pub max_click_duration: f64,
 
-    /// The new pointer press must come within this many seconds from previous pointer release
-    /// for double click (or when this value is doubled, triple click) to count.
+    /// A click must come within this many seconds from the previous click
+    /// for it to count as a double click.
+    /// The same goes for the third click of a triple click.
     pub max_double_click_delay: f64,
 
+    /// A click must be within this distance of the previous click
+    /// for it to count as a double (or triple) click.
+    pub max_multi_click_dist: f32,
+
     /// When this modifier is down, all scroll events are treated as zoom events.
     ///
     /// The default is CTRL/CMD, and it is STRONGLY recommended to NOT change this.
@@ -76,6 +81,7 @@ impl Default for InputOptions {
             max_click_dist: 6.0,
             max_click_duration: 0.8,
             max_double_click_delay: 0.3,
+            max_multi_click_dist: 6.0,
             zoom_modifier: Modifiers::COMMAND,
             horizontal_scroll_modifier: Modifiers::SHIFT,
             vertical_scroll_modifier: Modifiers::ALT,
@@ -92,6 +98,7 @@ impl InputOptions {
             max_click_dist,
             max_click_duration,
             max_double_click_delay,
+            max_multi_click_dist,
             zoom_modifier,
             horizontal_scroll_modifier,
             vertical_scroll_modifier,
@@ -143,6 +150,13 @@ impl InputOptions {
                 .on_hover_text("Max time interval for double click to count");
                 ui.end_row();
 
+                ui.label("Max multi-click distance");
+                ui.add(crate::DragValue::new(max_multi_click_dist).range(0.0..=f32::INFINITY))
+                    .on_hover_text(
+                        "How far apart two clicks can be and still count as a double click",
+                    );
+                ui.end_row();
+
                 ui.label("zoom_modifier");
                 zoom_modifier.ui(ui);
                 ui.end_row();
@@ -543,7 +557,7 @@ impl InputState {
         // the distances of the finger tips. It is therefore potentially more accurate than
         // `zoom_factor_delta` which is based on the `ctrl-scroll` event which, in turn, may be
         // synthesized from an original touch gesture.
-        self.multi_touch()
+        self.ongoing_multi_touch()
             .map_or(self.zoom_factor_delta, |touch| touch.zoom_delta)
     }
 
@@ -566,7 +580,7 @@ impl InputState {
         // the distances of the finger tips.  It is therefore potentially more accurate than
         // `zoom_factor_delta` which is based on the `ctrl-scroll` event which, in turn, may be
         // synthesized from an original touch gesture.
-        if let Some(multi_touch) = self.multi_touch() {
+        if let Some(multi_touch) = self.ongoing_multi_touch() {
             multi_touch.zoom_delta_2d
         } else {
             let mut zoom = Vec2::splat(self.zoom_factor_delta);
@@ -608,6 +622,7 @@ impl InputState {
         if self.pointer.wants_repaint()
             || self.unprocessed_scroll_delta.abs().max_elem() > 0.2
             || self.unprocessed_scroll_delta_for_zoom.abs() > 0.2
+            || self.touch_states.values().any(|t| t.is_smoothing())
             || !self.events.is_empty()
         {
             // Immediate repaint
@@ -756,6 +771,9 @@ impl InputState {
     /// Returns details about the currently ongoing multi-touch gesture, if any. Note that this
     /// method returns `None` for single-touch gestures (click, drag, …).
     ///
+    /// When the gesture ends, this returns it once more, with [`MultiTouchInfo::phase`] set to
+    /// [`TouchPhase::End`] and neutral deltas, so you know when to e.g. commit a pan-zoom.
+    ///
     /// ```
     /// # use egui::emath::Rot2;
     /// # egui::__run_test_ui(|ui| {
@@ -785,6 +803,12 @@ impl InputState {
         self.touch_states.values().find_map(|t| t.info())
     }
 
+    /// Like [`Self::multi_touch`], but `None` in the frame after the gesture ended.
+    fn ongoing_multi_touch(&self) -> Option<MultiTouchInfo> {
+        self.multi_touch()
+            .filter(|multi_touch| multi_touch.phase != TouchPhase::End)
+    }
+
     /// True if there currently are any fingers touching egui.
     pub fn any_touches(&self) -> bool {
         self.touch_states.values().any(|t| t.any_touches())
@@ -985,9 +1009,12 @@ pub struct PointerState {
     /// Used to check for double-clicks.
     last_click_time: f64,
 
-    /// When did the pointer get click two clicks ago?
-    /// Used to check for triple-clicks.
-    last_last_click_time: f64,
+    /// Where was the pointer last clicked?
+    /// Used to check for double- and triple-clicks.
+    last_click_pos: Option<Pos2>,
+
+    /// The [`Click::count`] of the last click.
+    last_click_count: u32,
 
     /// When was the pointer last moved?
     /// Used for things like showing hover ui/tooltip with a delay.
@@ -1019,7 +1046,8 @@ impl Default for PointerState {
             has_moved_too_much_for_a_click: false,
             started_decidedly_dragging: false,
             last_click_time: f64::NEG_INFINITY,
-            last_last_click_time: f64::NEG_INFINITY,
+            last_click_pos: None,
+            last_click_count: 0,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
             options: Default::default(),
@@ -1093,20 +1121,20 @@ impl PointerState {
                         let clicked = self.could_any_button_be_click();
 
                         let click = if clicked {
-                            let double_click =
+                            let soon_enough =
                                 (time - self.last_click_time) < self.options.max_double_click_delay;
-                            let triple_click = (time - self.last_last_click_time)
-                                < (self.options.max_double_click_delay * 2.0);
-                            let count = if triple_click {
-                                3
-                            } else if double_click {
-                                2
+                            let close_enough = self.last_click_pos.is_some_and(|last_pos| {
+                                last_pos.distance(pos) <= self.options.max_multi_click_dist
+                            });
+                            let count = if soon_enough && close_enough {
+                                (self.last_click_count + 1).at_most(3)
                             } else {
                                 1
                             };
 
-                            self.last_last_click_time = self.last_click_time;
                             self.last_click_time = time;
+                            self.last_click_pos = Some(pos);
+                            self.last_click_count = count;
 
                             Some(Click {
                                 pos,
@@ -1582,7 +1610,8 @@ impl PointerState {
             has_moved_too_much_for_a_click,
             started_decidedly_dragging,
             last_click_time,
-            last_last_click_time,
+            last_click_pos,
+            last_click_count,
             pointer_events,
             last_move_time,
             options: _,
@@ -1607,7 +1636,8 @@ impl PointerState {
             "started_decidedly_dragging: {started_decidedly_dragging}"
         ));
         ui.label(format!("last_click_time: {last_click_time:#?}"));
-        ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
+        ui.label(format!("last_click_pos: {last_click_pos:?}"));
+        ui.label(format!("last_click_count: {last_click_count}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
     }
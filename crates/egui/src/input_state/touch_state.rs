@@ -7,8 +7,44 @@ use crate::{
 };
 
 /// All you probably need to know about a multi-touch gesture.
+///
+/// A gesture starts when a second finger touches the surface, and ends when fewer than two
+/// fingers are left. Adding or removing fingers in between does not make the values jump.
+///
+/// For a pan-zoom canvas, apply the deltas of each frame to your transform,
+/// zooming around [`Self::center_pos`]:
+///
+/// ```
+/// # use egui::emath::TSTransform;
+/// # egui::__run_test_ui(|ui| {
+/// # let mut transform = TSTransform::IDENTITY;
+/// if let Some(touch) = ui.input(|i| i.multi_touch()) {
+///     let center = touch.center_pos.to_vec2();
+///     transform = TSTransform::from_translation(touch.translation_delta)
+///         * TSTransform::from_translation(center)
+///         * TSTransform::from_scaling(touch.zoom_delta)
+///         * TSTransform::from_translation(-center)
+///         * transform;
+/// }
+/// # });
+/// ```
+///
+/// If you'd rather compute your transform from the one you had when the gesture started,
+/// remember it when [`Self::phase`] is [`TouchPhase::Start`],
+/// and use [`Self::zoom`], [`Self::rotation`] and [`Self::translation`].
+///
+/// [`Self::zoom`] and [`Self::zoom_delta`] are smoothed over a few frames,
+/// so that the zoom doesn't shake with the jitter of the fingers.
+/// The other values follow the fingers as reported by the device.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct MultiTouchInfo {
+    /// [`TouchPhase::Start`] the first frame of a gesture, [`TouchPhase::End`] the frame after
+    /// it ended, and [`TouchPhase::Move`] in between.
+    ///
+    /// In the [`TouchPhase::End`] frame, all deltas are neutral,
+    /// and the totals are those of the last frame of the gesture.
+    pub phase: TouchPhase,
+
     /// Point in time when the gesture started.
     pub start_time: f64,
 
@@ -19,10 +55,10 @@ pub struct MultiTouchInfo {
     pub center_pos: Pos2,
 
     /// Number of touches (fingers) on the surface. Value is ≥ 2 since for a single touch no
-    /// [`MultiTouchInfo`] is created.
+    /// [`MultiTouchInfo`] is created, except for in the [`TouchPhase::End`] frame.
     pub num_touches: usize,
 
-    /// Proportional zoom factor (pinch gesture).
+    /// Proportional zoom factor (pinch gesture), smoothed.
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
@@ -54,6 +90,24 @@ pub struct MultiTouchInfo {
     /// and may depend on hardware and system settings.
     pub translation_delta: Vec2,
 
+    /// Proportional zoom factor since the start of the gesture, smoothed.
+    ///
+    /// This is the product of all [`Self::zoom_delta`]s of the gesture.
+    /// It follows the fingers with a time constant of [`ZOOM_SMOOTHING_TIME`] seconds.
+    pub zoom: f32,
+
+    /// Rotation in radians since the start of the gesture.
+    ///
+    /// This is the sum of all [`Self::rotation_delta`]s of the gesture,
+    /// so it is not limited to a single turn.
+    pub rotation: f32,
+
+    /// Movement of the average position of all touch points since the start of the gesture.
+    ///
+    /// This is the sum of all [`Self::translation_delta`]s of the gesture,
+    /// so unlike `center_pos - start_pos` it doesn't jump when a finger is added or removed.
+    pub translation: Vec2,
+
     /// Current force of the touch (average of the forces of the individual fingers). This is a
     /// value in the interval `[0.0 .. =1.0]`.
     ///
@@ -65,6 +119,12 @@ pub struct MultiTouchInfo {
     pub force: f32,
 }
 
+/// How quickly [`MultiTouchInfo::zoom`] follows the fingers.
+///
+/// This is the time constant of an exponential filter, in seconds:
+/// after this long, the smoothed zoom has moved about two thirds of the way to that of the fingers.
+pub const ZOOM_SMOOTHING_TIME: f32 = 0.05;
+
 /// The current state (for a specific touch device) of touch events and gestures.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -84,6 +144,9 @@ pub(crate) struct TouchState {
     /// If a gesture has been recognized (i.e. when exactly two fingers touch the surface), this
     /// holds state information
     gesture_state: Option<GestureState>,
+
+    /// The gesture that ended this frame, if any.
+    ended_gesture: Option<GestureState>,
 }
 
 #[derive(Clone, Debug)]
@@ -94,6 +157,77 @@ struct GestureState {
     pinch_type: PinchType,
     previous: Option<DynGestureState>,
     current: DynGestureState,
+
+    /// Did the gesture start this frame?
+    is_new: bool,
+
+    /// The deltas of all frames of the gesture, accumulated.
+    total_zoom: f32,
+    total_rotation: f32,
+    total_translation: Vec2,
+
+    /// [`Self::total_zoom`] after smoothing, this frame and the previous one.
+    smoothed_zoom: f32,
+    previous_smoothed_zoom: f32,
+
+    /// When the deltas were last accumulated.
+    time: f64,
+}
+
+impl GestureState {
+    fn raw_zoom_delta(&self) -> f32 {
+        // `previous` can be `None` when the number of simultaneous touches has just
+        // changed. In this case, we take `current` as `previous`, pretending that there
+        // was no change for the current frame.
+        let previous = self.previous.unwrap_or(self.current);
+        self.current.avg_distance / previous.avg_distance
+    }
+
+    fn zoom_delta_2d(&self) -> Vec2 {
+        let previous = self.previous.unwrap_or(self.current);
+        match self.pinch_type {
+            PinchType::Horizontal => Vec2::new(
+                self.current.avg_abs_distance2.x / previous.avg_abs_distance2.x,
+                1.0,
+            ),
+            PinchType::Vertical => Vec2::new(
+                1.0,
+                self.current.avg_abs_distance2.y / previous.avg_abs_distance2.y,
+            ),
+            PinchType::Proportional => Vec2::splat(self.raw_zoom_delta()),
+        }
+    }
+
+    fn rotation_delta(&self) -> f32 {
+        let previous = self.previous.unwrap_or(self.current);
+        normalized_angle(self.current.heading - previous.heading)
+    }
+
+    fn translation_delta(&self) -> Vec2 {
+        let previous = self.previous.unwrap_or(self.current);
+        self.current.avg_pos - previous.avg_pos
+    }
+
+    fn zoom_delta(&self) -> f32 {
+        self.smoothed_zoom / self.previous_smoothed_zoom
+    }
+
+    fn accumulate_deltas(&mut self, time: f64) {
+        let zoom_delta = self.raw_zoom_delta();
+        if zoom_delta.is_finite() {
+            // Not the case if all fingers were at the same spot.
+            self.total_zoom *= zoom_delta;
+        }
+        self.total_rotation += self.rotation_delta();
+        self.total_translation += self.translation_delta();
+
+        // Exponential smoothing, in log space so that zooming in and out are alike:
+        let dt = (time - self.time).max(0.0) as f32;
+        self.time = time;
+        let t = 1.0 - (-dt / ZOOM_SMOOTHING_TIME).exp();
+        self.previous_smoothed_zoom = self.smoothed_zoom;
+        self.smoothed_zoom *= (self.total_zoom / self.smoothed_zoom).powf(t);
+    }
 }
 
 /// Gesture data that can change over time
@@ -134,6 +268,7 @@ impl TouchState {
             device_id,
             active_touches: Default::default(),
             gesture_state: None,
+            ended_gesture: None,
         }
     }
 
@@ -178,6 +313,10 @@ impl TouchState {
                 state.previous = None;
             }
         }
+
+        if let Some(state) = &mut self.gesture_state {
+            state.accumulate_deltas(time);
+        }
     }
 
     /// Are there currently any fingers touching the surface?
@@ -185,49 +324,62 @@ impl TouchState {
         !self.active_touches.is_empty()
     }
 
-    pub fn info(&self) -> Option<MultiTouchInfo> {
-        self.gesture_state.as_ref().map(|state| {
-            // state.previous can be `None` when the number of simultaneous touches has just
-            // changed. In this case, we take `current` as `previous`, pretending that there
-            // was no change for the current frame.
-            let state_previous = state.previous.unwrap_or(state.current);
-
-            let zoom_delta = state.current.avg_distance / state_previous.avg_distance;
-
-            let zoom_delta_2d = match state.pinch_type {
-                PinchType::Horizontal => Vec2::new(
-                    state.current.avg_abs_distance2.x / state_previous.avg_abs_distance2.x,
-                    1.0,
-                ),
-                PinchType::Vertical => Vec2::new(
-                    1.0,
-                    state.current.avg_abs_distance2.y / state_previous.avg_abs_distance2.y,
-                ),
-                PinchType::Proportional => Vec2::splat(zoom_delta),
-            };
-
-            let center_pos = state.current.avg_pos;
+    /// Is the smoothed zoom still catching up with the fingers?
+    pub(crate) fn is_smoothing(&self) -> bool {
+        self.gesture_state
+            .as_ref()
+            .is_some_and(|state| (state.total_zoom / state.smoothed_zoom).ln().abs() > 1e-3)
+    }
 
-            MultiTouchInfo {
+    pub fn info(&self) -> Option<MultiTouchInfo> {
+        if let Some(state) = &self.gesture_state {
+            Some(MultiTouchInfo {
+                phase: if state.is_new {
+                    TouchPhase::Start
+                } else {
+                    TouchPhase::Move
+                },
                 start_time: state.start_time,
                 start_pos: state.start_pointer_pos,
                 num_touches: self.active_touches.len(),
-                zoom_delta,
-                zoom_delta_2d,
-                rotation_delta: normalized_angle(state.current.heading - state_previous.heading),
-                translation_delta: state.current.avg_pos - state_previous.avg_pos,
+                zoom_delta: state.zoom_delta(),
+                zoom_delta_2d: state.zoom_delta_2d(),
+                rotation_delta: state.rotation_delta(),
+                translation_delta: state.translation_delta(),
+                zoom: state.smoothed_zoom,
+                rotation: state.total_rotation,
+                translation: state.total_translation,
                 force: state.current.avg_force,
-                center_pos,
-            }
-        })
+                center_pos: state.current.avg_pos,
+            })
+        } else {
+            self.ended_gesture.as_ref().map(|state| MultiTouchInfo {
+                phase: TouchPhase::End,
+                start_time: state.start_time,
+                start_pos: state.start_pointer_pos,
+                num_touches: self.active_touches.len(),
+                zoom_delta: 1.0,
+                zoom_delta_2d: Vec2::splat(1.0),
+                rotation_delta: 0.0,
+                translation_delta: Vec2::ZERO,
+                zoom: state.smoothed_zoom,
+                rotation: state.total_rotation,
+                translation: state.total_translation,
+                force: 0.0,
+                center_pos: state.current.avg_pos,
+            })
+        }
     }
 
     fn update_gesture(&mut self, time: f64, pointer_pos: Option<Pos2>) {
+        self.ended_gesture = None;
+
         if let Some(dyn_state) = self.calc_dynamic_state() {
             if let Some(state) = &mut self.gesture_state {
                 // updating an ongoing gesture
                 state.previous = Some(state.current);
                 state.current = dyn_state;
+                state.is_new = false;
             } else if let Some(pointer_pos) = pointer_pos {
                 // starting a new gesture
                 self.gesture_state = Some(GestureState {
@@ -236,11 +388,18 @@ impl TouchState {
                     pinch_type: PinchType::classify(&self.active_touches),
                     previous: None,
                     current: dyn_state,
+                    is_new: true,
+                    total_zoom: 1.0,
+                    total_rotation: 0.0,
+                    total_translation: Vec2::ZERO,
+                    smoothed_zoom: 1.0,
+                    previous_smoothed_zoom: 1.0,
+                    time,
                 });
             }
         } else {
             // the end of a gesture (if there is any)
-            self.gesture_state = None;
+            self.ended_gesture = self.gesture_state.take();
         }
     }
 
@@ -350,3 +509,89 @@ impl PinchType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(id: u64, phase: TouchPhase, pos: Pos2) -> Event {
+        Event::Touch {
+            device_id: TouchDeviceId(0),
+            id: TouchId(id),
+            phase,
+            pos,
+            force: None,
+        }
+    }
+
+    fn pass(state: &mut TouchState, time: f64, events: Vec<Event>) -> Option<MultiTouchInfo> {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        state.begin_pass(time, &input, Some(Pos2::ZERO));
+        state.info()
+    }
+
+    #[test]
+    fn test_gesture_totals() {
+        let mut state = TouchState::new(TouchDeviceId(0));
+
+        let info = pass(
+            &mut state,
+            0.0,
+            vec![
+                touch(0, TouchPhase::Start, Pos2::new(0.0, 0.0)),
+                touch(1, TouchPhase::Start, Pos2::new(10.0, 0.0)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(info.phase, TouchPhase::Start);
+        assert_eq!(info.zoom, 1.0);
+
+        let info = pass(
+            &mut state,
+            0.1,
+            vec![touch(1, TouchPhase::Move, Pos2::new(20.0, 0.0))],
+        )
+        .unwrap();
+        assert_eq!(info.phase, TouchPhase::Move);
+        // The zoom is smoothed, it lags behind the fingers:
+        assert!(1.0 < info.zoom_delta && info.zoom_delta < 2.0);
+        assert_eq!(info.zoom, info.zoom_delta);
+        assert_eq!(info.translation, Vec2::new(5.0, 0.0));
+        let previous_zoom = info.zoom;
+
+        // Adding a finger far away moves the center, but must not make the totals jump:
+        let info = pass(
+            &mut state,
+            0.2,
+            vec![touch(2, TouchPhase::Start, Pos2::new(100.0, 100.0))],
+        )
+        .unwrap();
+        assert_eq!(info.num_touches, 3);
+        assert!(previous_zoom < info.zoom && info.zoom < 2.0);
+        assert!(
+            (info.zoom - 2.0).abs() < 0.05,
+            "Catches up with the fingers"
+        );
+        assert!((info.zoom - previous_zoom * info.zoom_delta).abs() < 1e-6);
+        assert_eq!(info.translation, Vec2::new(5.0, 0.0));
+        let previous_zoom = info.zoom;
+
+        let info = pass(
+            &mut state,
+            0.3,
+            vec![
+                touch(1, TouchPhase::End, Pos2::new(20.0, 0.0)),
+                touch(2, TouchPhase::End, Pos2::new(100.0, 100.0)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(info.phase, TouchPhase::End);
+        assert_eq!(info.zoom_delta, 1.0);
+        assert_eq!(info.zoom, previous_zoom);
+
+        assert!(pass(&mut state, 0.4, vec![]).is_none());
+    }
+}
@@ -138,6 +138,16 @@ pub(crate) fn interact(
         // Abort dragging on escape
         dragged = None;
         interaction.potential_drag_id = None;
+        interaction.pointer_capture = None;
+    }
+
+    if let Some(id) = interaction.pointer_capture {
+        // The widget that captured the pointer keeps it, no matter what is under the pointer:
+        if interaction.potential_click_id != Some(id) {
+            interaction.potential_click_id = None;
+        }
+        interaction.potential_drag_id = Some(id);
+        dragged = Some(id);
     }
 
     if input.is_long_touch() {
@@ -160,8 +170,9 @@ pub(crate) fn interact(
             PointerEvent::Moved(_) => {}
 
             PointerEvent::Pressed { .. } => {
-                // Maybe new click?
-                if interaction.potential_click_id.is_none() {
+                // Maybe new click? (not while the pointer is captured)
+                if interaction.potential_click_id.is_none() && interaction.pointer_capture.is_none()
+                {
                     interaction.potential_click_id = hits.click.map(|w| w.id);
                 }
 
@@ -183,6 +194,7 @@ pub(crate) fn interact(
 
                 interaction.potential_drag_id = None;
                 interaction.potential_click_id = None;
+                interaction.pointer_capture = None;
                 dragged = None;
             }
         }
@@ -216,6 +228,7 @@ pub(crate) fn interact(
     if !input.pointer.any_down() || input.pointer.latest_pos().is_none() {
         interaction.potential_click_id = None;
         interaction.potential_drag_id = None;
+        interaction.pointer_capture = None;
     }
 
     // ------------------------------------------------------------------------
@@ -146,6 +146,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 round_text_to_pixels,
                 round_line_segments_to_pixels,
                 round_rects_to_pixels,
+                corner_smoothing: _, // Set from `Visuals::corner_smoothing`
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
@@ -212,11 +213,13 @@ impl Widget for &memory::InteractionState {
         let memory::InteractionState {
             potential_click_id,
             potential_drag_id,
+            pointer_capture,
         } = self;
 
         ui.vertical(|ui| {
             ui.label(format!("potential_click_id: {potential_click_id:?}"));
             ui.label(format!("potential_drag_id: {potential_drag_id:?}"));
+            ui.label(format!("pointer_capture: {pointer_capture:?}"));
         })
         .response
     }
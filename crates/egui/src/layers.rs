@@ -168,6 +168,11 @@ impl PaintList {
         self.0[idx.0].shape = Shape::Noop;
     }
 
+    /// Remove all the shapes, returning them.
+    pub fn take(&mut self) -> Vec<ClippedShape> {
+        std::mem::take(&mut self.0)
+    }
+
     /// Mutate the shape at the given index, if any.
     pub fn mutate_shape(&mut self, idx: ShapeIdx, f: impl FnOnce(&mut ClippedShape)) {
         self.0.get_mut(idx.0).map(f);
@@ -0,0 +1,161 @@
+use crate::{
+    Context, Id, IdMap, Order, Pos2, Rect, Vec2, collapsing_header::CollapsingState,
+    containers::panel::PanelState, containers::resize, id::IdSet, util::IdTypeMap,
+};
+
+/// The layout of the ui: where the windows are, how large the panels are,
+/// and which collapsing headers are open.
+///
+/// Use it to save the layout of your app separately from the rest of [`crate::Memory`],
+/// e.g. to let the user pick between saved layouts, or to version the layout on its own:
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// let layout = ctx.layout_snapshot();
+/// // … save it to disk, and on the next start:
+/// ctx.apply_layout_snapshot(&layout);
+/// # });
+/// ```
+///
+/// Only covers the panels and collapsing headers that were shown or applied since the [`Context`] was created,
+/// and the areas of the current viewport.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LayoutState {
+    /// The left top position of each [`crate::Window`], and other [`crate::Area`]s in [`Order::Middle`].
+    pub areas: IdMap<Pos2>,
+
+    /// The size picked by the user for each [`crate::Window`] and other [`crate::Resize`] container.
+    pub sizes: IdMap<Vec2>,
+
+    /// Where each panel is, with its size.
+    pub panels: IdMap<Rect>,
+
+    /// Whether each [`crate::CollapsingHeader`] and collapsible [`crate::Window`] is open.
+    pub collapsing: IdMap<bool>,
+}
+
+/// The ids of the states in [`LayoutState`], remembered as they are stored.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LayoutIds {
+    sizes: IdSet,
+    panels: IdSet,
+    collapsing: IdSet,
+}
+
+impl LayoutIds {
+    fn get(data: &mut IdTypeMap) -> &mut Self {
+        data.get_temp_mut_or_default(Id::NULL)
+    }
+
+    pub(crate) fn remember_size(data: &mut IdTypeMap, id: Id) {
+        Self::get(data).sizes.insert(id);
+    }
+
+    pub(crate) fn remember_panel(data: &mut IdTypeMap, id: Id) {
+        Self::get(data).panels.insert(id);
+    }
+
+    pub(crate) fn remember_collapsing(data: &mut IdTypeMap, id: Id) {
+        Self::get(data).collapsing.insert(id);
+    }
+}
+
+impl LayoutState {
+    pub(crate) fn snapshot(ctx: &Context) -> Self {
+        let areas = ctx.memory(|mem| {
+            let areas = mem.areas();
+            areas
+                .order()
+                .iter()
+                .filter(|layer| layer.order == Order::Middle && !areas.is_sublayer(layer))
+                .filter_map(|layer| {
+                    let state = areas.get(layer.id)?;
+                    state.pivot_pos?;
+                    Some((layer.id, state.left_top_pos()))
+                })
+                .collect()
+        });
+
+        let LayoutIds {
+            sizes,
+            panels,
+            collapsing,
+        } = ctx.data_mut(|d| LayoutIds::get(d).clone());
+
+        Self {
+            areas,
+            sizes: sizes
+                .into_iter()
+                .filter_map(|id| Some((id, resize::State::load(ctx, id)?.desired_size)))
+                .collect(),
+            panels: panels
+                .into_iter()
+                .filter_map(|id| Some((id, PanelState::load(ctx, id)?.rect)))
+                .collect(),
+            collapsing: collapsing
+                .into_iter()
+                .filter_map(|id| Some((id, CollapsingState::load(ctx, id)?.is_open())))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn apply(&self, ctx: &Context) {
+        let Self {
+            areas,
+            sizes,
+            panels,
+            collapsing,
+        } = self;
+
+        ctx.memory_mut(|mem| {
+            for (&id, &pos) in areas {
+                mem.areas_mut().set_left_top_pos(id, pos);
+            }
+        });
+
+        for (&id, &size) in sizes {
+            let state = if let Some(mut state) = resize::State::load(ctx, id) {
+                state.requested_size = Some(size);
+                state
+            } else {
+                resize::State::new(size)
+            };
+            state.store(ctx, id);
+        }
+
+        for (&id, &rect) in panels {
+            PanelState { rect }.store(ctx, id);
+        }
+
+        for (&id, &open) in collapsing {
+            let mut state = CollapsingState::load_with_default_open(ctx, id, open);
+            state.set_open(open);
+            state.store(ctx);
+        }
+
+        ctx.request_repaint();
+    }
+}
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_layout_state_serde() {
+    let mut layout = LayoutState::default();
+    layout
+        .areas
+        .insert(Id::new("window"), crate::pos2(10.0, 20.0));
+    layout
+        .sizes
+        .insert(Id::new("resize"), crate::vec2(300.0, 200.0));
+    layout.panels.insert(
+        Id::new("panel"),
+        Rect::from_min_size(Pos2::ZERO, crate::vec2(150.0, 600.0)),
+    );
+    layout.collapsing.insert(Id::new("header"), true);
+
+    let serialized = ron::to_string(&layout).unwrap();
+    let deserialized: LayoutState = ron::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, layout);
+}
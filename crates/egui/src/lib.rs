@@ -410,6 +410,7 @@ pub mod cache;
 pub mod containers;
 mod context;
 mod data;
+pub mod debug;
 pub mod debug_text;
 mod drag_and_drop;
 pub(crate) mod grid;
@@ -421,6 +422,7 @@ mod interaction;
 pub mod introspection;
 pub mod layers;
 mod layout;
+mod layout_state;
 pub mod load;
 mod memory;
 #[deprecated = "Use `egui::containers::menu` instead"]
@@ -431,8 +433,10 @@ mod pass_state;
 pub(crate) mod placer;
 pub mod response;
 mod sense;
+mod shortcut_registry;
 pub mod style;
 pub mod text_selection;
+mod texture_atlas;
 mod ui;
 mod ui_builder;
 mod ui_stack;
@@ -462,11 +466,12 @@ pub use ecolor::hex_color;
 pub use ecolor::{Color32, Rgba};
 pub use emath::{
     Align, Align2, NumExt, Pos2, Rangef, Rect, RectAlign, Vec2, Vec2b, lerp, pos2, remap,
-    remap_clamp, vec2,
+    remap_clamp, snap_to_grid, vec2,
 };
 pub use epaint::{
-    ClippedPrimitive, ColorImage, CornerRadius, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId, mutex,
+    ClippedPrimitive, ColorImage, CornerRadius, Gradient, ImageData, LinePattern, Margin, Mesh,
+    PaintCallback, PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId,
+    mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
 };
@@ -474,12 +479,13 @@ pub use epaint::{
 pub mod text {
     pub use crate::text_selection::CCursorRange;
     pub use epaint::text::{
-        FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob, LayoutSection, TAB_SIZE,
-        TextFormat, TextWrapping, cursor::CCursor,
+        FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob, LayoutSection, Script,
+        TAB_SIZE, TextDirection, TextFormat, TextTruncation, TextWrapping, cursor::CCursor,
     };
 }
 
 pub use self::{
+    animation_manager::Easing,
     atomics::*,
     containers::{menu::MenuBar, *},
     context::{Context, RepaintCause, RequestRepaintInfo},
@@ -487,8 +493,8 @@ pub use self::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput, RenderToTexture,
+            Screenshot, UserAttentionType, WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
@@ -498,13 +504,18 @@ pub use self::{
     input_state::{InputOptions, InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
+    layout_state::LayoutState,
     load::SizeHint,
     memory::{Memory, Options, Theme, ThemePreference},
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
+    shortcut_registry::{
+        RegisteredShortcut, ShortcutRegistry, format_shortcut, show_shortcuts_window,
+    },
     style::{FontSelection, Spacing, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    texture_atlas::{AtlasKey, TextureAtlas},
     ui::Ui,
     ui_builder::UiBuilder,
     ui_stack::*,
@@ -653,6 +664,9 @@ pub enum WidgetType {
 
     Checkbox,
 
+    /// An on/off [`crate::ToggleSwitch`].
+    Switch,
+
     RadioButton,
 
     /// A group of radio buttons.
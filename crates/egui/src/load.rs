@@ -348,6 +348,16 @@ pub trait BytesLoader {
     /// so that all of them may be fully reloaded.
     fn forget_all(&self);
 
+    /// Stop loading the given `uri`, if it is still in progress.
+    ///
+    /// Unlike [`Self::forget`], this leaves anything that has finished loading in the cache.
+    /// Loading starts over if the `uri` is loaded again.
+    ///
+    /// The default implementation does nothing, i.e. the loading carries on.
+    fn cancel(&self, uri: &str) {
+        let _ = uri;
+    }
+
     /// Implementations may use this to perform work at the end of a frame,
     /// such as evicting unused entries from a cache.
     fn end_pass(&self, pass_index: u64) {
@@ -422,6 +432,19 @@ pub trait ImageLoader {
     /// so that all of them may be fully reloaded.
     fn forget_all(&self);
 
+    /// Stop loading the given `uri`, if it is still in progress.
+    ///
+    /// For example, a loader that decodes images on a background thread
+    /// should drop the decoding that hasn't been done yet, and not cache the result.
+    ///
+    /// Unlike [`Self::forget`], this leaves anything that has finished loading in the cache.
+    /// Loading starts over if the `uri` is loaded again.
+    ///
+    /// The default implementation does nothing, i.e. the loading carries on.
+    fn cancel(&self, uri: &str) {
+        let _ = uri;
+    }
+
     /// Implementations may use this to perform work at the end of a pass,
     /// such as evicting unused entries from a cache.
     fn end_pass(&self, pass_index: u64) {
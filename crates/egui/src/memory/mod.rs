@@ -83,6 +83,11 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) add_fonts: Vec<epaint::text::FontInsert>,
 
+    /// Changes to the installed fonts that will be applied at the start of the next frame,
+    /// after [`Self::add_fonts`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) font_edits: Vec<FontEdit>,
+
     // Current active viewport
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) viewport_id: ViewportId,
@@ -133,6 +138,7 @@ impl Default for Memory {
             popups: Default::default(),
             everything_is_visible: Default::default(),
             add_fonts: Default::default(),
+            font_edits: Default::default(),
         };
         slf.interactions.entry(slf.viewport_id).or_default();
         slf.areas.entry(slf.viewport_id).or_default();
@@ -140,6 +146,19 @@ impl Default for Memory {
     }
 }
 
+/// A change to the installed fonts, see [`Memory::font_edits`].
+#[derive(Clone, Debug)]
+pub(crate) enum FontEdit {
+    /// Remove the font with this name from all families.
+    Remove(String),
+
+    /// Move (or add) the font with this name to the front or back of a family.
+    SetPriority {
+        name: String,
+        family: epaint::text::InsertFontFamily,
+    },
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 enum FocusDirection {
     /// Select the widget closest above the current focused widget.
@@ -299,6 +318,26 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// Power-saving mode: while idle, wait at least this long between repaints.
+    ///
+    /// egui is idle when there was no input and nothing is animating,
+    /// i.e. no immediate repaint was requested the previous pass.
+    /// While idle, any [`crate::Context::request_repaint_after`] with a shorter delay
+    /// (e.g. from a blinking text cursor, or a dashboard polling for new data)
+    /// is postponed to this interval.
+    ///
+    /// Input and [`crate::Context::request_repaint`] still repaint right away,
+    /// and the next delayed repaint after that is back at full rate.
+    ///
+    /// This only changes [`crate::ViewportOutput::repaint_delay`].
+    /// Backends that repaint continuously, ignoring that delay, are unaffected
+    /// and need to limit their frame rate themselves.
+    ///
+    /// For instance, `Some(Duration::from_secs(1))` limits an idle UI to 1 Hz.
+    ///
+    /// Default is `None` (no power saving).
+    pub idle_repaint_interval: Option<std::time::Duration>,
 }
 
 impl Default for Options {
@@ -321,6 +360,7 @@ impl Default for Options {
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+            idle_repaint_interval: None,
         }
     }
 }
@@ -375,6 +415,7 @@ impl Options {
             warn_on_id_clash,
             input_options,
             reduce_texture_memory,
+            idle_repaint_interval,
         } = self;
 
         use crate::Widget as _;
@@ -401,6 +442,24 @@ impl Options {
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.horizontal(|ui| {
+                    let mut power_saving = idle_repaint_interval.is_some();
+                    ui.checkbox(&mut power_saving, "Power saving")
+                        .on_hover_text("Limit the repaint rate while idle");
+                    if power_saving {
+                        let mut interval = idle_repaint_interval.map_or(1.0, |d| d.as_secs_f32());
+                        ui.add(
+                            crate::DragValue::new(&mut interval)
+                                .speed(0.1)
+                                .range(0.1..=10.0)
+                                .suffix(" s"),
+                        );
+                        *idle_repaint_interval = Some(std::time::Duration::from_secs_f32(interval));
+                    } else {
+                        *idle_repaint_interval = None;
+                    }
+                });
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -458,6 +517,11 @@ pub(crate) struct InteractionState {
     /// as that can only happen after the mouse has moved a bit
     /// (at least if the widget is interesated in both clicks and drags).
     pub potential_drag_id: Option<Id>,
+
+    /// A widget that gets all pointer input until the pointer is released.
+    ///
+    /// See [`crate::Context::capture_pointer`].
+    pub pointer_capture: Option<Id>,
 }
 
 /// Keeps tracks of what widget has keyboard focus
@@ -889,6 +953,21 @@ impl Memory {
         }
     }
 
+    /// Give keyboard focus to the next widget interested in it, e.g. the first widget of a modal.
+    pub(crate) fn focus_next_interested(&mut self) {
+        let focus = self.focus_mut();
+        focus.focused_widget = None;
+        focus.give_to_next = true;
+    }
+
+    /// Give keyboard focus to a specific widget, or none at all,
+    /// even if it would otherwise go to the next widget interested in it.
+    pub(crate) fn set_focus(&mut self, id: Option<Id>) {
+        let focus = self.focus_mut();
+        focus.focused_widget = id.map(FocusWidget::new);
+        focus.give_to_next = false;
+    }
+
     /// Returns true if
     /// - this layer is the top-most modal layer or above it
     /// - there is no modal layer
@@ -1185,6 +1264,11 @@ impl Areas {
         }
     }
 
+    /// Move an area, even one that hasn't been shown yet.
+    pub(crate) fn set_left_top_pos(&mut self, id: Id, pos: Pos2) {
+        self.areas.entry(id).or_default().set_left_top_pos(pos);
+    }
+
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::AreaState) {
         self.visible_areas_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use emath::GuiRounding as _;
 use epaint::{
-    CircleShape, ClippedShape, CornerRadius, PathStroke, RectShape, Shape, Stroke, StrokeKind,
+    CircleShape, ClipPolygon, ClipShape, ClippedShape, CornerRadius, CubicBezierShape, Gradient,
+    LinePattern, Mesh, PathStroke, RectShape, Shape, Stroke, StrokeKind, TextShape,
     text::{Fonts, Galley, LayoutJob},
 };
 
@@ -32,6 +33,9 @@ pub struct Painter {
     /// This means nothing outside of this rectangle will be visible on screen.
     clip_rect: Rect,
 
+    /// If set, everything painted in this [`Painter`] is also clipped against this convex polygon.
+    clip_polygon: Option<Arc<ClipPolygon>>,
+
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
@@ -51,6 +55,7 @@ impl Painter {
             pixels_per_point,
             layer_id,
             clip_rect,
+            clip_polygon: None,
             fade_to_color: None,
             opacity_factor: 1.0,
         }
@@ -182,6 +187,29 @@ impl Painter {
         self.clip_rect = clip_rect;
     }
 
+    /// If set, everything painted in this [`Painter`] is also clipped against this convex polygon.
+    ///
+    /// See [`Self::clip_to_polygon`].
+    #[inline]
+    pub fn clip_polygon(&self) -> Option<&ClipPolygon> {
+        self.clip_polygon.as_deref()
+    }
+
+    /// Only paint inside of this convex polygon from now on, e.g. a rounded rectangle.
+    ///
+    /// If there already is a clip polygon, we clip to where they overlap.
+    /// This also shrinks the clip rect to the bounding rectangle of the polygon.
+    ///
+    /// This is more expensive than the clip rect alone, since the clipping happens when tessellating.
+    pub fn clip_to_polygon(&mut self, clip_polygon: ClipPolygon) {
+        let clip_polygon = match &self.clip_polygon {
+            Some(current) => current.intersect(&clip_polygon),
+            None => clip_polygon,
+        };
+        self.shrink_clip_rect(clip_polygon.bounding_rect());
+        self.clip_polygon = Some(Arc::new(clip_polygon));
+    }
+
     /// Useful for pixel-perfect rendering of lines that are one pixel wide (or any odd number of pixels).
     #[inline]
     pub fn round_to_pixel_center(&self, point: f32) -> f32 {
@@ -238,6 +266,11 @@ impl Painter {
         if self.opacity_factor < 1.0 {
             multiply_opacity(shape, self.opacity_factor);
         }
+        if let Some(clip_polygon) = &self.clip_polygon {
+            let clipped =
+                ClipShape::new(clip_polygon.clone(), std::mem::replace(shape, Shape::Noop));
+            *shape = clipped.into();
+        }
     }
 
     /// It is up to the caller to make sure there is room for this.
@@ -260,7 +293,8 @@ impl Painter {
         if self.fade_to_color == Some(Color32::TRANSPARENT) || self.opacity_factor == 0.0 {
             return;
         }
-        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 {
+        if self.fade_to_color.is_some() || self.opacity_factor < 1.0 || self.clip_polygon.is_some()
+        {
             let shapes = shapes.into_iter().map(|mut shape| {
                 self.transform_shape(&mut shape);
                 shape
@@ -409,6 +443,17 @@ impl Painter {
         })
     }
 
+    /// The outline of a circle, broken up into dashes (or dots).
+    pub fn circle_stroke_dashed(
+        &self,
+        center: Pos2,
+        radius: f32,
+        stroke: impl Into<Stroke>,
+        pattern: LinePattern,
+    ) -> ShapeIdx {
+        self.add(CircleShape::stroke(center, radius, stroke).dashed(pattern))
+    }
+
     /// See also [`Self::rect_filled`] and [`Self::rect_stroke`].
     pub fn rect(
         &self,
@@ -446,6 +491,145 @@ impl Painter {
         self.add(RectShape::stroke(rect, corner_radius, stroke, stroke_kind))
     }
 
+    /// The outline of a rectangle, broken up into dashes (or dots).
+    ///
+    /// The dashes follow the rounded corners.
+    /// Advance [`LinePattern::offset`] each frame for "marching ants":
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Color32, LinePattern, StrokeKind};
+    ///
+    /// let rect = ui.available_rect_before_wrap().shrink(8.0);
+    /// let time = ui.input(|i| i.time) as f32;
+    /// ui.painter().rect_stroke_dashed(
+    ///     rect,
+    ///     4.0,
+    ///     (1.0, Color32::WHITE),
+    ///     StrokeKind::Inside,
+    ///     LinePattern::dashed(4.0, 4.0).with_offset(16.0 * time),
+    /// );
+    /// ui.ctx().request_repaint();
+    /// # });
+    /// ```
+    pub fn rect_stroke_dashed(
+        &self,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        stroke: impl Into<Stroke>,
+        stroke_kind: StrokeKind,
+        pattern: LinePattern,
+    ) -> ShapeIdx {
+        self.add(RectShape::stroke(rect, corner_radius, stroke, stroke_kind).dashed(pattern))
+    }
+
+    /// Fills a rectangle with a [`Gradient`], stretched over `rect`.
+    ///
+    /// Use [`RectShape::with_gradient`] for a gradient together with a stroke.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let progress = 0.3;
+    /// use egui::{Color32, Gradient, Sense};
+    ///
+    /// let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 16.0), Sense::hover());
+    /// let filled = rect.with_max_x(egui::lerp(rect.x_range(), progress));
+    /// ui.painter().rect_gradient(
+    ///     filled,
+    ///     8.0,
+    ///     Gradient::horizontal(Color32::DARK_GREEN, Color32::LIGHT_GREEN),
+    /// );
+    /// # });
+    /// ```
+    pub fn rect_gradient(
+        &self,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        gradient: impl Into<Gradient>,
+    ) -> ShapeIdx {
+        self.add(
+            RectShape::filled(rect, corner_radius, Color32::TRANSPARENT).with_gradient(gradient),
+        )
+    }
+
+    /// Fills a convex polygon with a [`Gradient`],
+    /// stretched over the bounding rectangle of the `points`.
+    pub fn convex_polygon_gradient(&self, points: &[Pos2], gradient: &Gradient) -> ShapeIdx {
+        let feathering = self.ctx.tessellation_options(|options| {
+            if options.feathering {
+                options.feathering_size_in_pixels / self.pixels_per_point
+            } else {
+                0.0
+            }
+        });
+        let mut path = epaint::tessellator::Path::default();
+        path.add_line_loop(points);
+        let mut mesh = Mesh::default();
+        path.fill_with_gradient(feathering, gradient, Rect::from_points(points), &mut mesh);
+        self.add(Shape::mesh(mesh))
+    }
+
+    /// Paints the lines of a grid with cells of size `spacing`, covering `rect`.
+    ///
+    /// The grid lines go through the origin, so they stay put when `rect` moves,
+    /// e.g. when panning a canvas.
+    /// Only the lines within the clip rectangle are painted.
+    /// A zero spacing along an axis means no lines along that axis.
+    pub fn grid(&self, rect: Rect, spacing: Vec2, stroke: impl Into<Stroke>) {
+        let stroke = stroke.into();
+        let visible = rect.intersect(self.clip_rect());
+        if !visible.is_positive() || stroke.is_empty() {
+            return;
+        }
+        // The multiples of `spacing` within `range`:
+        let lines = |range: Rangef, spacing: f32| {
+            let (first, last) = if spacing > 0.0 {
+                (
+                    (range.min / spacing).ceil() as i64,
+                    (range.max / spacing).floor() as i64,
+                )
+            } else {
+                (1, 0)
+            };
+            (first..=last).map(move |i| i as f32 * spacing)
+        };
+        let mut shapes = Vec::new();
+        for x in lines(visible.x_range(), spacing.x) {
+            shapes.push(Shape::vline(x, visible.y_range(), stroke));
+        }
+        for y in lines(visible.y_range(), spacing.y) {
+            shapes.push(Shape::hline(visible.x_range(), y, stroke));
+        }
+        self.extend(shapes);
+    }
+
+    /// Paints a smooth curve from `from` to `to`, like the wires between the ports of a node graph.
+    ///
+    /// The curve leaves `from` in the direction of `from_dir`,
+    /// and arrives at `to` from the direction of `to_dir`.
+    /// Use [`CubicBezierShape::distance_to_curve`] to check if the pointer is over it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (from, to) = (egui::pos2(10.0, 10.0), egui::pos2(100.0, 60.0));
+    /// let stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+    /// ui.painter()
+    ///     .connection(from, to, egui::Vec2::RIGHT, egui::Vec2::LEFT, stroke);
+    /// # });
+    /// ```
+    pub fn connection(
+        &self,
+        from: Pos2,
+        to: Pos2,
+        from_dir: Vec2,
+        to_dir: Vec2,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(CubicBezierShape::connection(
+            from, to, from_dir, to_dir, stroke,
+        ))
+    }
+
     /// Show an arrow starting at `origin` and going in the direction of `vec`, with the length `vec.length()`.
     pub fn arrow(&self, origin: Pos2, vec: Vec2, stroke: impl Into<Stroke>) {
         use crate::emath::Rot2;
@@ -513,6 +697,46 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text, rotated by `angle` radians clockwise around `pos`.
+    ///
+    /// The `anchor` is the point of the unrotated text that ends up at `pos`.
+    /// For instance, the label of a vertical axis that reads from bottom to top
+    /// and is centered on `pos`:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let pos = ui.min_rect().left_center();
+    /// ui.painter().text_rotated(
+    ///     pos,
+    ///     egui::Align2::CENTER_CENTER,
+    ///     -std::f32::consts::FRAC_PI_2,
+    ///     "Temperature",
+    ///     egui::FontId::default(),
+    ///     ui.visuals().text_color(),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// Returns the bounding rectangle of the rotated text.
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_rotated(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        angle: f32,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+    ) -> Rect {
+        let galley = self.layout_no_wrap(text.to_string(), font_id, text_color);
+        let rect = anchor.anchor_size(pos, galley.size());
+        let text_shape =
+            TextShape::new(rect.min, galley, text_color).with_angle_and_anchor(angle, anchor);
+        let bounding_rect = text_shape.logical_bounding_rect();
+        self.add(text_shape);
+        bounding_rect
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
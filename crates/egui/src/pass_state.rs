@@ -41,6 +41,17 @@ pub struct PerLayerState {
     /// Only one widget per layer may show a tooltip.
     /// But if a tooltip contains a tooltip, you can show a tooltip on top of a tooltip.
     pub widget_with_tooltip: Option<Id>,
+
+    /// Don't report the widgets in this layer as hovered.
+    ///
+    /// Set while a menu is navigated with the keyboard,
+    /// so that the pointer doesn't highlight another entry than the keyboard.
+    pub ignore_hover: bool,
+
+    /// Store the [`crate::WidgetInfo`] of the widgets in this layer in [`crate::WidgetRects`].
+    ///
+    /// Set by menus, to find their entries by label when the user types.
+    pub store_widget_infos: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -231,6 +242,12 @@ pub struct PassState {
     /// Highlight these widgets the next pass.
     pub highlight_next_pass: IdSet,
 
+    /// Disabled widgets that still open their context menu on a secondary click,
+    /// from [`crate::Response::context_menu_enabled_when_disabled`].
+    ///
+    /// Used by the hit test of the next pass.
+    pub context_menus_when_disabled: IdSet,
+
     #[cfg(debug_assertions)]
     pub debug_rect: Option<DebugRect>,
 }
@@ -250,6 +267,7 @@ impl Default for PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
+            context_menus_when_disabled: Default::default(),
 
             #[cfg(debug_assertions)]
             debug_rect: None,
@@ -273,6 +291,7 @@ impl PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
+            context_menus_when_disabled,
 
             #[cfg(debug_assertions)]
             debug_rect,
@@ -299,6 +318,7 @@ impl PassState {
         }
 
         highlight_next_pass.clear();
+        context_menus_when_disabled.clear();
     }
 
     /// How much space is still available after panels has been added.
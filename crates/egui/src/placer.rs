@@ -31,6 +31,12 @@ impl Placer {
         }
     }
 
+    pub(crate) fn set_grid_span(&mut self, span: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_span(span);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn grid(&self) -> Option<&grid::GridLayout> {
         self.grid.as_ref()
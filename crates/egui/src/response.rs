@@ -338,6 +338,60 @@ impl Response {
         self.ctx.memory_mut(|mem| mem.surrender_focus(self.id));
     }
 
+    /// The image the user pasted into this widget this frame, if it has keyboard focus.
+    ///
+    /// Always `None` with backends that don't support pasting images,
+    /// see [`crate::Event::PasteImage`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut texture: Option<egui::TextureHandle> = None;
+    /// let response = ui.add(egui::Button::new("Paste a screenshot here").sense(egui::Sense::click()));
+    /// if let Some(image) = response.pasted_image() {
+    ///     texture = Some(ui.ctx().load_texture("screenshot", (*image).clone(), Default::default()));
+    /// }
+    /// # });
+    /// ```
+    pub fn pasted_image(&self) -> Option<Arc<crate::ColorImage>> {
+        if !self.has_focus() {
+            return None;
+        }
+        self.ctx.input(|i| {
+            i.events.iter().rev().find_map(|event| match event {
+                crate::Event::PasteImage(image) => Some(image.clone()),
+                _ => None,
+            })
+        })
+    }
+
+    /// The files the user pasted into this widget this frame, if it has keyboard focus.
+    ///
+    /// Always empty with backends that don't support pasting files,
+    /// see [`crate::Event::PasteFiles`].
+    pub fn pasted_files(&self) -> Vec<std::path::PathBuf> {
+        if !self.has_focus() {
+            return vec![];
+        }
+        self.ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|event| match event {
+                    crate::Event::PasteFiles(paths) => Some(paths.iter().cloned()),
+                    _ => None,
+                })
+                .flatten()
+                .collect()
+        })
+    }
+
+    /// Let this widget get all pointer input until the pointer is released,
+    /// no matter what is under the pointer.
+    ///
+    /// See [`Context::capture_pointer`].
+    pub fn set_pointer_capture(&self) {
+        self.ctx.capture_pointer(self.id);
+    }
+
     /// Did a drag on this widget begin this frame?
     ///
     /// This is only true if the widget sense drags.
@@ -410,6 +464,56 @@ impl Response {
         }
     }
 
+    /// If dragged by the button, how many points were we dragged and in what direction?
+    ///
+    /// This lets you map different buttons to different actions,
+    /// e.g. pan with the middle button and orbit with the secondary button.
+    ///
+    /// See [`Self::drag_delta`] and [`Self::dragged_by`].
+    #[inline]
+    pub fn drag_delta_by(&self, button: PointerButton) -> Vec2 {
+        if self.dragged_by(button) {
+            self.drag_delta()
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    /// If dragged, how far to move something at `pos` so that it stays on a grid.
+    ///
+    /// The grid has cells of size `grid`, and its lines go through `offset`
+    /// (see [`emath::snap_to_grid`]).
+    /// Movement that is too small to reach the next grid line is remembered,
+    /// so slow drags still get there.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut node_pos = egui::pos2(20.0, 20.0);
+    /// let rect = egui::Rect::from_min_size(node_pos, egui::vec2(40.0, 20.0));
+    /// let response = ui.interact(rect, ui.id().with("node"), egui::Sense::drag());
+    /// node_pos += response.drag_delta_snapped(node_pos, egui::vec2(10.0, 10.0), egui::Vec2::ZERO);
+    /// # });
+    /// ```
+    pub fn drag_delta_snapped(&self, pos: Pos2, grid: Vec2, offset: Vec2) -> Vec2 {
+        let remainder_id = self.id.with("drag_snap_remainder");
+        if !self.dragged() {
+            self.ctx.data_mut(|d| d.remove::<Vec2>(remainder_id));
+            return Vec2::ZERO;
+        }
+        let remainder = if self.drag_started() {
+            Vec2::ZERO
+        } else {
+            self.ctx
+                .data(|d| d.get_temp::<Vec2>(remainder_id))
+                .unwrap_or_default()
+        };
+        let target = pos + remainder + self.drag_delta();
+        let snapped = emath::snap_to_grid(target, grid, offset);
+        self.ctx
+            .data_mut(|d| d.insert_temp(remainder_id, target - snapped));
+        snapped - pos
+    }
+
     /// If dragged, how far did the mouse move?
     /// This will use raw mouse movement if provided by the integration, otherwise will fall back to [`Response::drag_delta`]
     /// Raw mouse movement is unaccelerated and unclamped by screen boundaries, and does not relate to any position on the screen.
@@ -582,6 +686,22 @@ impl Response {
     }
 
     /// Like `on_hover_ui`, but show the ui next to cursor.
+    ///
+    /// The tooltip follows the pointer while it moves over the widget,
+    /// flipping to the other side of the pointer near the edges of the screen.
+    /// This is useful for showing the value under the pointer in a plot or canvas:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(200.0, 100.0), egui::Sense::hover());
+    /// if let Some(pos) = response.hover_pos() {
+    ///     let x = (pos.x - rect.left()) / rect.width();
+    ///     response.on_hover_ui_at_pointer(|ui| {
+    ///         ui.label(format!("x = {x:.2}"));
+    ///     });
+    /// }
+    /// # });
+    /// ```
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_enabled(&self)
             .at_pointer()
@@ -642,6 +762,23 @@ impl Response {
         })
     }
 
+    /// Show a [`crate::RichTooltip`] when hovering, with its own delay and max width.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.button("Export").on_hover_tooltip(
+    ///     egui::RichTooltip::new("Export")
+    ///         .body("Save a copy of the document as a PDF.")
+    ///         .delay(0.6),
+    /// );
+    /// # });
+    /// ```
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_tooltip(self, tooltip: crate::RichTooltip) -> Self {
+        tooltip.show(&self);
+        self
+    }
+
     /// Highlight this widget, to make it look like it is hovered, even if it isn't.
     ///
     /// The highlight takes one frame to take effect if you call this after the widget has been fully rendered.
@@ -852,6 +989,7 @@ impl Response {
             }
             WidgetType::Image => Role::Image,
             WidgetType::Checkbox => Role::CheckBox,
+            WidgetType::Switch => Role::Switch,
             WidgetType::RadioButton => Role::RadioButton,
             WidgetType::RadioGroup => Role::RadioGroup,
             WidgetType::SelectableLabel => Role::Button,
@@ -941,6 +1079,36 @@ impl Response {
         Popup::context_menu(self).show(add_contents)
     }
 
+    /// Like [`Self::context_menu`], but also opens when the widget is disabled.
+    ///
+    /// Disabled widgets otherwise ignore all clicks. This only makes them sense the secondary click
+    /// (starting next frame), so they stay inert otherwise, but won't let the right-click
+    /// fall through to whatever is behind them.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.add_enabled(false, egui::Button::new("Save"));
+    /// response.context_menu_enabled_when_disabled(|ui| {
+    ///     if !response.enabled() {
+    ///         ui.label("Nothing to save");
+    ///     }
+    ///     if ui.button("Copy path").clicked() {
+    ///         ui.close();
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn context_menu_enabled_when_disabled(
+        &self,
+        add_contents: impl FnOnce(&mut Ui),
+    ) -> Option<InnerResponse<()>> {
+        if !self.enabled() {
+            self.ctx
+                .pass_state_mut(|fs| fs.context_menus_when_disabled.insert(self.id));
+        }
+        self.context_menu(add_contents)
+    }
+
     /// Returns whether a context menu is currently open for this widget.
     ///
     /// See [`Self::context_menu`].
@@ -0,0 +1,329 @@
+use std::sync::Arc;
+
+use crate::{
+    Align, Context, Grid, Id, InnerResponse, KeyboardShortcut, Layout, RichText, ScrollArea,
+    TextEdit, Window, os::OperatingSystem,
+};
+
+/// A keyboard shortcut bound to an action, see [`ShortcutRegistry`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredShortcut {
+    /// Names the action, e.g. `"Save"`. Unique within the registry.
+    pub action: String,
+
+    pub shortcut: KeyboardShortcut,
+
+    /// Groups related actions in [`show_shortcuts_window`], e.g. `"File"`.
+    pub category: Option<String>,
+}
+
+/// A central place for the keyboard shortcuts of an app.
+///
+/// Instead of calling [`crate::InputState::consume_shortcut`] all over the code,
+/// register each shortcut once, and check if its action was triggered where it is handled:
+///
+/// ```
+/// # use egui::{Key, KeyboardShortcut, Modifiers};
+/// # egui::__run_test_ctx(|ctx| {
+/// ctx.register_shortcut("Save", KeyboardShortcut::new(Modifiers::COMMAND, Key::S));
+/// ctx.register_shortcut(
+///     "Save as…",
+///     KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S),
+/// );
+///
+/// if ctx.shortcut_triggered("Save") {
+///     // save…
+/// }
+/// # });
+/// ```
+///
+/// At the start of each pass, the registered shortcuts that were pressed are consumed,
+/// starting with the ones with the most modifiers.
+/// That way `Cmd-Shift-S` triggers "Save as…" and not "Save".
+/// Shortcuts registered during a pass are handled from the next pass on.
+///
+/// The list of shortcuts can be shown to the user with [`show_shortcuts_window`],
+/// or in your own help overlay, see [`Self::shortcuts`].
+///
+/// Access it with [`Context::shortcuts`] and [`Context::shortcuts_mut`].
+#[derive(Clone, Debug, Default)]
+pub struct ShortcutRegistry {
+    /// In the order they were registered.
+    shortcuts: Vec<RegisteredShortcut>,
+
+    /// The actions triggered this pass.
+    triggered: Vec<String>,
+}
+
+impl ShortcutRegistry {
+    pub(crate) fn register(ctx: &Context) {
+        ctx.on_begin_pass("shortcut_registry_begin_pass", Arc::new(Self::begin_pass));
+    }
+
+    /// Consume the registered shortcuts that were pressed.
+    fn begin_pass(ctx: &Context) {
+        let (mut shortcuts, any_triggered) =
+            ctx.shortcuts(|registry| (registry.shortcuts.clone(), !registry.triggered.is_empty()));
+        if shortcuts.is_empty() && !any_triggered {
+            return;
+        }
+
+        // Most specific first, see `InputState::consume_shortcut`:
+        shortcuts.sort_by_key(|registered| std::cmp::Reverse(num_modifiers(&registered.shortcut)));
+        let triggered = ctx.input_mut(|i| {
+            shortcuts
+                .into_iter()
+                .filter(|registered| i.consume_shortcut(&registered.shortcut))
+                .map(|registered| registered.action)
+                .collect()
+        });
+        ctx.shortcuts_mut(|registry| registry.triggered = triggered);
+    }
+
+    /// Bind `shortcut` to `action`, replacing any previous binding of `action`.
+    ///
+    /// Returns the previous shortcut of `action`, if any.
+    ///
+    /// Other actions may already use the same shortcut, see [`Self::conflicts`].
+    /// With the `log` feature, this is also logged as a warning.
+    pub fn insert(
+        &mut self,
+        action: impl Into<String>,
+        shortcut: KeyboardShortcut,
+    ) -> Option<KeyboardShortcut> {
+        self.insert_registered(RegisteredShortcut {
+            action: action.into(),
+            shortcut,
+            category: None,
+        })
+    }
+
+    /// Like [`Self::insert`], but puts the action in a `category`, e.g. `"File"`.
+    pub fn insert_in(
+        &mut self,
+        category: impl Into<String>,
+        action: impl Into<String>,
+        shortcut: KeyboardShortcut,
+    ) -> Option<KeyboardShortcut> {
+        self.insert_registered(RegisteredShortcut {
+            action: action.into(),
+            shortcut,
+            category: Some(category.into()),
+        })
+    }
+
+    fn insert_registered(&mut self, new: RegisteredShortcut) -> Option<KeyboardShortcut> {
+        #[cfg(feature = "log")]
+        for other in &self.shortcuts {
+            if other.shortcut == new.shortcut && other.action != new.action {
+                log::warn!(
+                    "Shortcut {:?} of {:?} is already used by {:?}",
+                    new.shortcut,
+                    new.action,
+                    other.action
+                );
+            }
+        }
+
+        if let Some(registered) = self.shortcuts.iter_mut().find(|r| r.action == new.action) {
+            Some(std::mem::replace(registered, new).shortcut)
+        } else {
+            self.shortcuts.push(new);
+            None
+        }
+    }
+
+    /// Unbind the shortcut of `action`, returning it if there was one.
+    pub fn remove(&mut self, action: &str) -> Option<KeyboardShortcut> {
+        let index = self.shortcuts.iter().position(|r| r.action == action)?;
+        Some(self.shortcuts.remove(index).shortcut)
+    }
+
+    /// The shortcut bound to `action`, if any.
+    pub fn shortcut(&self, action: &str) -> Option<KeyboardShortcut> {
+        self.shortcuts
+            .iter()
+            .find(|r| r.action == action)
+            .map(|r| r.shortcut)
+    }
+
+    /// All registered shortcuts, in the order they were registered.
+    pub fn shortcuts(&self) -> &[RegisteredShortcut] {
+        &self.shortcuts
+    }
+
+    /// Pairs of actions that are bound to the same shortcut.
+    ///
+    /// Only one action of each pair can ever be triggered.
+    pub fn conflicts(&self) -> Vec<(&RegisteredShortcut, &RegisteredShortcut)> {
+        let mut conflicts = Vec::new();
+        for (i, a) in self.shortcuts.iter().enumerate() {
+            for b in &self.shortcuts[i + 1..] {
+                if a.shortcut == b.shortcut {
+                    conflicts.push((a, b));
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Was the shortcut of `action` pressed at the start of this pass?
+    pub fn triggered(&self, action: &str) -> bool {
+        self.triggered.iter().any(|triggered| triggered == action)
+    }
+}
+
+fn num_modifiers(shortcut: &KeyboardShortcut) -> usize {
+    let modifiers = shortcut.modifiers;
+    [
+        modifiers.alt,
+        modifiers.ctrl || modifiers.command,
+        modifiers.shift,
+        modifiers.mac_cmd,
+    ]
+    .into_iter()
+    .filter(|&pressed| pressed)
+    .count()
+}
+
+/// Format a shortcut the way it is written on `os`, e.g. `⇧⌘S` on macOS and `Ctrl+Shift+S` elsewhere.
+///
+/// The same as [`KeyboardShortcut::format_for`].
+/// The symbols need a font that has them,
+/// which [`Context::format_shortcut`] checks for you.
+pub fn format_shortcut(shortcut: &KeyboardShortcut, os: OperatingSystem) -> String {
+    shortcut.format_for(os)
+}
+
+/// Show a window listing all the shortcuts of `registry`, grouped by category.
+///
+/// The window has a search field to filter the shortcuts by action, category or keys.
+/// Show it for as long as you want it open, e.g. while the user toggles it with `?`:
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut show_help = true;
+/// if show_help {
+///     let registry = ctx.shortcuts(|registry| registry.clone());
+///     egui::show_shortcuts_window(ctx, &registry);
+/// }
+/// # });
+/// ```
+pub fn show_shortcuts_window(
+    ctx: &Context,
+    registry: &ShortcutRegistry,
+) -> Option<InnerResponse<Option<()>>> {
+    let search_id = Id::new("egui::shortcuts_window_search");
+    let mut search: String = ctx.data(|d| d.get_temp(search_id)).unwrap_or_default();
+
+    let response = Window::new("Keyboard shortcuts")
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.add(TextEdit::singleline(&mut search).hint_text("Search"));
+            ui.separator();
+
+            let needle = search.to_lowercase();
+            let formatted: Vec<String> = registry
+                .shortcuts()
+                .iter()
+                .map(|registered| ctx.format_shortcut(&registered.shortcut))
+                .collect();
+            let matches = |(registered, keys): &(&RegisteredShortcut, &String)| {
+                needle.is_empty()
+                    || registered.action.to_lowercase().contains(&needle)
+                    || keys.to_lowercase().contains(&needle)
+                    || registered
+                        .category
+                        .as_ref()
+                        .is_some_and(|category| category.to_lowercase().contains(&needle))
+            };
+
+            // In the order the categories were first registered:
+            let mut categories: Vec<Option<&str>> = Vec::new();
+            for registered in registry.shortcuts() {
+                let category = registered.category.as_deref();
+                if !categories.contains(&category) {
+                    categories.push(category);
+                }
+            }
+
+            ScrollArea::vertical().show(ui, |ui| {
+                let mut any_shown = false;
+                for category in categories {
+                    let shortcuts: Vec<_> = registry
+                        .shortcuts()
+                        .iter()
+                        .zip(&formatted)
+                        .filter(|(registered, _)| registered.category.as_deref() == category)
+                        .filter(matches)
+                        .collect();
+                    if shortcuts.is_empty() {
+                        continue;
+                    }
+                    any_shown = true;
+
+                    ui.label(RichText::new(category.unwrap_or("General")).strong());
+                    Grid::new(("egui::shortcuts_window_category", category))
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (registered, keys) in shortcuts {
+                                ui.label(&registered.action);
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    ui.label(RichText::new(keys).monospace());
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    ui.add_space(ui.spacing().item_spacing.y);
+                }
+                if !any_shown {
+                    ui.weak("No matching shortcuts");
+                }
+            });
+        });
+
+    ctx.data_mut(|d| d.insert_temp(search_id, search));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Key, Modifiers};
+
+    #[test]
+    fn test_shortcut_conflicts() {
+        let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+        let save_as = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+
+        let mut registry = ShortcutRegistry::default();
+        assert_eq!(registry.insert("Save", save), None);
+        assert_eq!(registry.insert("Save as", save_as), None);
+        assert!(registry.conflicts().is_empty());
+
+        assert_eq!(registry.insert("Sync", save), None);
+        let conflicts: Vec<_> = registry
+            .conflicts()
+            .into_iter()
+            .map(|(a, b)| (a.action.as_str(), b.action.as_str()))
+            .collect();
+        assert_eq!(conflicts, [("Save", "Sync")]);
+
+        assert_eq!(registry.insert("Sync", save_as), Some(save));
+        assert_eq!(registry.remove("Save as"), Some(save_as));
+        assert!(registry.conflicts().is_empty());
+        assert_eq!(registry.shortcuts().len(), 2);
+    }
+
+    #[test]
+    fn test_format_shortcut() {
+        let save_as = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+        assert_eq!(format_shortcut(&save_as, OperatingSystem::Mac), "⇧⌘S");
+        assert_eq!(
+            format_shortcut(&save_as, OperatingSystem::Windows),
+            "Ctrl+Shift+S"
+        );
+    }
+}
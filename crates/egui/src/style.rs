@@ -3,7 +3,10 @@
 #![allow(clippy::if_same_then_else)]
 
 use emath::Align;
-use epaint::{AlphaFromCoverage, CornerRadius, Shadow, Stroke, text::FontTweak};
+use epaint::{
+    AlphaFromCoverage, CornerRadius, Shadow, Stroke,
+    text::{FontTweak, TextDirection},
+};
 use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 
 use crate::{
@@ -14,22 +17,78 @@ use crate::{
     reset_button_with,
 };
 
-/// How to format numbers in e.g. a [`crate::DragValue`].
+/// How to format numbers in e.g. a [`crate::DragValue`], and how to parse them back when edited.
+///
+/// ```
+/// use egui::style::NumberFormatter;
+///
+/// let english = NumberFormatter::grouped(',', '.');
+/// assert_eq!(english.format(1234567.89, 2..=2), "1,234,567.89");
+/// assert_eq!(english.parse("1,234,567.89"), Some(1234567.89));
+///
+/// let german = NumberFormatter::grouped('.', ',');
+/// assert_eq!(german.format(1234567.89, 2..=2), "1.234.567,89");
+/// assert_eq!(german.parse("1.234.567,89"), Some(1234567.89));
+/// ```
 #[derive(Clone)]
-pub struct NumberFormatter(
-    Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
-);
+pub struct NumberFormatter {
+    formatter: Arc<dyn 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String>,
+    parser: Option<NumberParser>,
+}
+
+type NumberParser = Arc<dyn 'static + Sync + Send + Fn(&str) -> Option<f64>>;
 
 impl NumberFormatter {
     /// The first argument is the number to be formatted.
     /// The second argument is the range of the number of decimals to show.
     ///
     /// See [`Self::format`] for the meaning of the `decimals` argument.
+    ///
+    /// Text is parsed with the default egui parser,
+    /// unless you also set [`Self::with_parser`].
     #[inline]
     pub fn new(
         formatter: impl 'static + Sync + Send + Fn(f64, RangeInclusive<usize>) -> String,
     ) -> Self {
-        Self(Arc::new(formatter))
+        Self {
+            formatter: Arc::new(formatter),
+            parser: None,
+        }
+    }
+
+    /// How to parse text that the user entered back into a number.
+    ///
+    /// This should accept anything the formatter produces.
+    /// Return `None` for text that isn't a number.
+    #[inline]
+    pub fn with_parser(
+        mut self,
+        parser: impl 'static + Sync + Send + Fn(&str) -> Option<f64>,
+    ) -> Self {
+        self.parser = Some(Arc::new(parser));
+        self
+    }
+
+    /// Group the digits in thousands, e.g. `1,234,567.89` or `1.234.567,89`.
+    ///
+    /// When parsing, the `group_separator` is optional,
+    /// and the `decimal_separator` is the only accepted decimal separator.
+    pub fn grouped(group_separator: char, decimal_separator: char) -> Self {
+        debug_assert_ne!(
+            group_separator, decimal_separator,
+            "The group and decimal separators must differ"
+        );
+        Self::new(move |value, decimals| {
+            format_grouped(value, decimals, group_separator, decimal_separator)
+        })
+        .with_parser(move |text| {
+            let text: String = text
+                .chars()
+                .filter(|&c| c != group_separator)
+                .map(|c| if c == decimal_separator { '.' } else { c })
+                .collect();
+            crate::widgets::drag_value::default_parser(&text)
+        })
     }
 
     /// Format the given number with the given number of decimals.
@@ -42,10 +101,55 @@ impl NumberFormatter {
     /// more decimals will be shown, up to the given max.
     #[inline]
     pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
-        (self.0)(value, decimals)
+        (self.formatter)(value, decimals)
+    }
+
+    /// Parse text that the user entered, e.g. in a [`crate::DragValue`].
+    ///
+    /// Returns `None` if the text isn't a number.
+    #[inline]
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        match &self.parser {
+            Some(parser) => parser(text),
+            None => crate::widgets::drag_value::default_parser(text),
+        }
     }
 }
 
+/// See [`NumberFormatter::grouped`].
+fn format_grouped(
+    value: f64,
+    decimals: RangeInclusive<usize>,
+    group_separator: char,
+    decimal_separator: char,
+) -> String {
+    let text = emath::format_with_decimals_in_range(value, decimals);
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (text.as_str(), None),
+    };
+    let (sign, digits) = match integer.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", integer),
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return text; // inf or NaN
+    }
+
+    let mut grouped = sign.to_owned();
+    for (i, digit) in digits.chars().enumerate() {
+        if 0 < i && (digits.len() - i) % 3 == 0 {
+            grouped.push(group_separator);
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push(decimal_separator);
+        grouped.push_str(fraction);
+    }
+    grouped
+}
+
 impl std::fmt::Debug for NumberFormatter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("NumberFormatter")
@@ -55,7 +159,12 @@ impl std::fmt::Debug for NumberFormatter {
 impl PartialEq for NumberFormatter {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.0, &other.0)
+        Arc::ptr_eq(&self.formatter, &other.formatter)
+            && match (&self.parser, &other.parser) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
     }
 }
 
@@ -277,9 +386,10 @@ pub struct Style {
     /// The style to use for [`DragValue`] text.
     pub drag_value_text_style: TextStyle,
 
-    /// How to format numbers as strings, e.g. in a [`crate::DragValue`].
+    /// How to format numbers as strings, e.g. in a [`crate::DragValue`],
+    /// and how to parse them back when edited.
     ///
-    /// You can override this to e.g. add thousands separators.
+    /// You can override this to e.g. add thousands separators, see [`NumberFormatter::grouped`].
     #[cfg_attr(feature = "serde", serde(skip))]
     pub number_formatter: NumberFormatter,
 
@@ -302,6 +412,19 @@ pub struct Style {
     /// * `Some(mode)`: use the specified mode as default
     pub wrap_mode: Option<crate::TextWrapMode>,
 
+    /// The base direction of text and widgets.
+    ///
+    /// With [`TextDirection::RightToLeft`], e.g. for Arabic or Hebrew user interfaces:
+    /// * text is laid out right-to-left (see [`crate::text::LayoutJob::direction`]),
+    /// * [`crate::Ui::horizontal`] fills from the right,
+    /// * widgets like [`crate::Checkbox`] and [`crate::RadioButton`] are mirrored, with the box on the right.
+    ///
+    /// Set it for a part of your ui with [`crate::Ui::style_mut`].
+    ///
+    /// Default: [`TextDirection::Auto`], which uses the direction of the text,
+    /// and lays out widgets left-to-right.
+    pub text_direction: TextDirection,
+
     /// Sizes and distances between widgets
     pub spacing: Spacing,
 
@@ -566,6 +689,22 @@ pub struct ScrollStyle {
     /// This is only for floating scroll bars.
     /// Solid scroll bars are always opaque.
     pub interact_handle_opacity: f32,
+
+    /// The rounding of the scroll handle and the bar behind it.
+    ///
+    /// If `None`, the rounding of the widget [`Visuals::widgets`] is used.
+    pub corner_radius: Option<CornerRadius>,
+
+    /// The color of the scroll handle.
+    ///
+    /// If `None`, it is picked from the widget [`Visuals::widgets`],
+    /// based on [`Self::foreground_color`] and how the user is interacting with the bar.
+    pub handle_color: Option<Color32>,
+
+    /// The color of the bar behind the scroll handle.
+    ///
+    /// If `None`, [`Visuals::extreme_bg_color`] is used.
+    pub background_color: Option<Color32>,
 }
 
 impl Default for ScrollStyle {
@@ -595,6 +734,10 @@ impl ScrollStyle {
             dormant_handle_opacity: 0.0,
             active_handle_opacity: 0.6,
             interact_handle_opacity: 1.0,
+
+            corner_radius: None,
+            handle_color: None,
+            background_color: None,
         }
     }
 
@@ -675,6 +818,10 @@ impl ScrollStyle {
             dormant_handle_opacity,
             active_handle_opacity,
             interact_handle_opacity,
+
+            corner_radius,
+            handle_color,
+            background_color,
         } = self;
 
         ui.horizontal(|ui| {
@@ -713,6 +860,34 @@ impl ScrollStyle {
             ui.selectable_value(foreground_color, true, "Foreground");
         });
 
+        ui.horizontal(|ui| {
+            let mut custom = corner_radius.is_some();
+            ui.checkbox(&mut custom, "Custom rounding");
+            if custom {
+                let corner_radius =
+                    corner_radius.get_or_insert(ui.visuals().widgets.inactive.corner_radius);
+                ui.add(corner_radius);
+            } else {
+                *corner_radius = None;
+            }
+        });
+
+        for (color, label) in [
+            (handle_color, "Custom handle color"),
+            (background_color, "Custom bar color"),
+        ] {
+            ui.horizontal(|ui| {
+                let mut custom = color.is_some();
+                ui.checkbox(&mut custom, label);
+                if custom {
+                    let color = color.get_or_insert(ui.visuals().widgets.inactive.bg_fill);
+                    ui.color_edit_button_srgba(color);
+                } else {
+                    *color = None;
+                }
+            });
+        }
+
         if *floating {
             crate::Grid::new("opacity").show(ui, |ui| {
                 fn opacity_ui(ui: &mut Ui, opacity: &mut f32) {
@@ -761,6 +936,9 @@ pub struct ScrollAnimation {
 
     /// The min / max scroll duration.
     pub duration: Rangef,
+
+    /// How the speed changes over the course of the animation.
+    pub easing: ScrollEasing,
 }
 
 impl Default for ScrollAnimation {
@@ -768,6 +946,7 @@ impl Default for ScrollAnimation {
         Self {
             points_per_second: 1000.0,
             duration: Rangef::new(0.1, 0.3),
+            easing: ScrollEasing::default(),
         }
     }
 }
@@ -778,6 +957,7 @@ impl ScrollAnimation {
         Self {
             points_per_second,
             duration,
+            easing: ScrollEasing::default(),
         }
     }
 
@@ -786,6 +966,7 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(0.0, 0.0),
+            easing: ScrollEasing::default(),
         }
     }
 
@@ -794,9 +975,22 @@ impl ScrollAnimation {
         Self {
             points_per_second: f32::INFINITY,
             duration: Rangef::new(t, t),
+            easing: ScrollEasing::default(),
         }
     }
 
+    /// Change the [`ScrollEasing`].
+    #[inline]
+    pub fn with_easing(mut self, easing: ScrollEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// How long to take to scroll `distance` points.
+    pub fn duration_for(&self, distance: f32) -> f32 {
+        (distance.abs() / self.points_per_second).clamp(self.duration.min, self.duration.max)
+    }
+
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         crate::Grid::new("scroll_animation").show(ui, |ui| {
             ui.label("Scroll animation:");
@@ -825,10 +1019,55 @@ impl ScrollAnimation {
             );
             ui.label("seconds");
             ui.end_row();
+
+            ui.label("Easing:");
+            ui.horizontal(|ui| {
+                for easing in ScrollEasing::ALL {
+                    ui.selectable_value(&mut self.easing, easing, easing.name());
+                }
+            });
+            ui.end_row();
         });
     }
 }
 
+/// How a [`ScrollAnimation`] moves towards its target.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollEasing {
+    /// Constant speed.
+    Linear,
+
+    /// Start slow, speed up, then slow down before arriving.
+    #[default]
+    EaseInOut,
+
+    /// Start at full speed, then slow down before arriving.
+    EaseOut,
+}
+
+impl ScrollEasing {
+    pub const ALL: [Self; 3] = [Self::Linear, Self::EaseInOut, Self::EaseOut];
+
+    /// Map the time `t` (in `0..=1`) to how far along the animation is (in `0..=1`).
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseInOut => emath::ease_in_ease_out(t),
+            Self::EaseOut => emath::easing::cubic_out(t),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Linear => "Linear",
+            Self::EaseInOut => "Ease in-out",
+            Self::EaseOut => "Ease out",
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// How and when interaction happens.
@@ -869,6 +1108,22 @@ pub struct Interaction {
     /// The default is `true`, but text selection can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// How long in seconds a button with [`crate::Button::auto_repeat`] must be held
+    /// before it starts repeating.
+    pub button_repeat_delay: f32,
+
+    /// Seconds between the repeats of a held button with [`crate::Button::auto_repeat`].
+    pub button_repeat_interval: f32,
+
+    /// Should the edges of dragged windows snap to the edges of the screen and of other windows?
+    ///
+    /// Hold down Alt to move a window freely.
+    /// Can be overridden per window with [`crate::Window::snapping`].
+    pub window_snapping: bool,
+
+    /// How close in points an edge of a dragged window must come to another edge to snap to it.
+    pub window_snap_distance: f32,
 }
 
 /// Look and feel of the text cursor.
@@ -883,12 +1138,17 @@ pub struct TextCursorStyle {
     pub preview: bool,
 
     /// Should the cursor blink?
+    ///
+    /// Set to `false` for a solid cursor.
+    /// A solid cursor does not request any repaints, which saves power.
     pub blink: bool,
 
-    /// When blinking, this is how long the cursor is visible.
+    /// When blinking, this is how long the cursor is visible, in seconds.
     pub on_duration: f32,
 
-    /// When blinking, this is how long the cursor is invisible.
+    /// When blinking, this is how long the cursor is invisible, in seconds.
+    ///
+    /// If this is zero, the cursor is solid.
     pub off_duration: f32,
 }
 
@@ -904,6 +1164,14 @@ impl Default for TextCursorStyle {
     }
 }
 
+impl TextCursorStyle {
+    /// Does the cursor blink, i.e. is [`Self::blink`] set and both durations positive?
+    #[inline]
+    pub fn is_blinking(&self) -> bool {
+        self.blink && 0.0 < self.on_duration && 0.0 < self.off_duration
+    }
+}
+
 /// Controls the visual style (colors etc) of egui.
 ///
 /// You can change the visuals of a [`Ui`] with [`Ui::visuals_mut`]
@@ -991,11 +1259,26 @@ pub struct Visuals {
 
     pub menu_corner_radius: CornerRadius,
 
+    /// How smooth all rounded corners are,
+    /// from `0.0` (circular arcs, the default) to `1.0` (squircle-like).
+    ///
+    /// This applies to every rounded rectangle, e.g. in [`crate::Frame`], [`crate::Button`] and [`crate::Window`],
+    /// unless overridden with [`epaint::RectShape::corner_smoothing`].
+    /// See [`epaint::CornerSmoothing`].
+    ///
+    /// Only the global style counts (see [`crate::Context::set_style`]),
+    /// since it is read when the shapes are tessellated.
+    /// Changing it for a single [`crate::Ui`] has no effect.
+    pub corner_smoothing: f32,
+
     /// Panel background color
     pub panel_fill: Color32,
 
     pub popup_shadow: Shadow,
 
+    /// The color [`crate::Modal`] dims everything behind it with.
+    pub modal_backdrop_color: Color32,
+
     pub resize_corner_size: f32,
 
     /// How the text cursor acts.
@@ -1312,9 +1595,10 @@ impl Default for Style {
             override_text_valign: Some(Align::Center),
             text_styles: default_text_styles(),
             drag_value_text_style: TextStyle::Button,
-            number_formatter: NumberFormatter(Arc::new(emath::format_with_decimals_in_range)),
+            number_formatter: NumberFormatter::new(emath::format_with_decimals_in_range),
             wrap: None,
             wrap_mode: None,
+            text_direction: TextDirection::Auto,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
@@ -1368,6 +1652,10 @@ impl Default for Interaction {
             tooltip_grace_time: 0.2,
             selectable_labels: true,
             multi_widget_text_select: true,
+            button_repeat_delay: 0.4,
+            button_repeat_interval: 0.05,
+            window_snapping: false,
+            window_snap_distance: 8.0,
         }
     }
 }
@@ -1404,6 +1692,8 @@ impl Visuals {
 
             menu_corner_radius: CornerRadius::same(6),
 
+            corner_smoothing: 0.0,
+
             panel_fill: Color32::from_gray(27),
 
             popup_shadow: Shadow {
@@ -1413,6 +1703,8 @@ impl Visuals {
                 color: Color32::from_black_alpha(96),
             },
 
+            modal_backdrop_color: Color32::from_black_alpha(100),
+
             resize_corner_size: 12.0,
 
             text_cursor: Default::default(),
@@ -1623,6 +1915,7 @@ impl Style {
             number_formatter: _, // can't change callbacks in the UI
             wrap: _,
             wrap_mode,
+            text_direction,
             spacing,
             interaction,
             visuals,
@@ -1720,6 +2013,20 @@ impl Style {
                 });
             ui.end_row();
 
+            ui.label("Text direction");
+            crate::ComboBox::from_id_salt("text_direction")
+                .selected_text(format!("{text_direction:?}"))
+                .show_ui(ui, |ui| {
+                    for direction in [
+                        TextDirection::Auto,
+                        TextDirection::LeftToRight,
+                        TextDirection::RightToLeft,
+                    ] {
+                        ui.selectable_value(text_direction, direction, format!("{direction:?}"));
+                    }
+                });
+            ui.end_row();
+
             ui.label("Animation duration");
             ui.add(
                 DragValue::new(animation_time)
@@ -1909,6 +2216,10 @@ impl Interaction {
             tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            button_repeat_delay,
+            button_repeat_interval,
+            window_snapping,
+            window_snap_distance,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1951,6 +2262,33 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Button repeat delay").on_hover_text(
+                    "How long an auto-repeating button must be held before it starts repeating",
+                );
+                ui.add(
+                    DragValue::new(button_repeat_delay)
+                        .range(0.0..=2.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Button repeat interval")
+                    .on_hover_text("Time between the repeats of a held auto-repeating button");
+                ui.add(
+                    DragValue::new(button_repeat_interval)
+                        .range(0.01..=1.0)
+                        .speed(0.01)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Window snap distance").on_hover_text(
+                    "How close the edge of a dragged window must come to another edge to snap to it",
+                );
+                ui.add(DragValue::new(window_snap_distance).range(0.0..=32.0));
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -1965,6 +2303,9 @@ impl Interaction {
             }
         });
 
+        ui.checkbox(window_snapping, "Snap dragged windows to edges")
+            .on_hover_text("Hold down Alt to move a window freely");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset interaction settings"));
     }
 }
@@ -2095,10 +2436,14 @@ impl Visuals {
 
             menu_corner_radius,
 
+            corner_smoothing,
+
             panel_fill,
 
             popup_shadow,
 
+            modal_backdrop_color,
+
             resize_corner_size,
 
             text_cursor,
@@ -2271,6 +2616,10 @@ impl Visuals {
                     ui.label("Shadow");
                     ui.add(popup_shadow);
                     ui.end_row();
+
+                    ui.label("Modal backdrop");
+                    ui.color_edit_button_srgba(modal_backdrop_color);
+                    ui.end_row();
                 });
         });
 
@@ -2280,6 +2629,8 @@ impl Visuals {
         ui.collapsing("Misc", |ui| {
             ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
             ui.add(Slider::new(clip_rect_margin, 0.0..=20.0).text("clip_rect_margin"));
+            ui.add(Slider::new(corner_smoothing, 0.0..=1.0).text("corner_smoothing"))
+                .on_hover_text("How smooth rounded corners are: from circular arcs to squircles");
 
             ui.checkbox(button_frame, "Button has a frame");
             ui.checkbox(collapsing_header_frame, "Collapsing header has a frame");
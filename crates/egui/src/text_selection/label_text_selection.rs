@@ -3,8 +3,8 @@ use std::sync::Arc;
 use emath::TSTransform;
 
 use crate::{
-    Context, CursorIcon, Event, Galley, Id, LayerId, Pos2, Rect, Response, Ui, layers::ShapeIdx,
-    text::CCursor, text_selection::CCursorRange,
+    Context, CursorIcon, Event, Galley, Id, Key, LayerId, Modifiers, PointerButton, Pos2, Rect,
+    Response, Ui, layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
 };
 
 use super::{
@@ -96,6 +96,24 @@ pub struct LabelSelectionState {
     /// Have we reached the widget containing the secondary selection?
     has_reached_secondary: bool,
 
+    /// Copy the selection next pass, e.g. because "Copy" was clicked in a context menu.
+    copy_requested: bool,
+
+    /// The selection scope containing the primary cursor this frame.
+    selection_scope: Option<Id>,
+
+    /// The selection scope under the pointer this frame.
+    hovered_scope: Option<Id>,
+
+    /// Select all the text in this selection scope this pass, because of `Cmd+A`.
+    select_all_scope: Option<Id>,
+
+    /// Have we reached the first label of [`Self::select_all_scope`]?
+    has_started_select_all: bool,
+
+    /// Copy the selection this pass, as if there was a copy event.
+    copy_this_pass: bool,
+
     /// Accumulated text to copy.
     text_to_copy: String,
     last_copied_galley_rect: Option<Rect>,
@@ -116,6 +134,12 @@ impl Default for LabelSelectionState {
             is_dragging: Default::default(),
             has_reached_primary: Default::default(),
             has_reached_secondary: Default::default(),
+            copy_requested: Default::default(),
+            selection_scope: Default::default(),
+            hovered_scope: Default::default(),
+            select_all_scope: Default::default(),
+            has_started_select_all: Default::default(),
+            copy_this_pass: Default::default(),
             text_to_copy: Default::default(),
             last_copied_galley_rect: Default::default(),
             painted_selections: Default::default(),
@@ -124,6 +148,16 @@ impl Default for LabelSelectionState {
 }
 
 impl LabelSelectionState {
+    /// Tag a [`Ui`] with this to make it a selection scope:
+    /// pressing `Cmd+A` while the text selection is in it,
+    /// or while the pointer is over it, selects all the text in it.
+    ///
+    /// The text is selected across all the selectable labels in view,
+    /// the same as if the user dragged from the first to the last one.
+    ///
+    /// See [`crate::ScrollArea::text_selection_scope`] and [`crate::UiStackInfo::with_tag`].
+    pub const SELECTION_SCOPE_TAG: &'static str = "egui_text_selection_scope";
+
     pub(crate) fn register(ctx: &Context) {
         ctx.on_begin_pass("LabelSelectionState", std::sync::Arc::new(Self::begin_pass));
         ctx.on_end_pass("LabelSelectionState", std::sync::Arc::new(Self::end_pass));
@@ -156,6 +190,15 @@ impl LabelSelectionState {
         state.any_hovered = false;
         state.has_reached_primary = false;
         state.has_reached_secondary = false;
+        state.copy_this_pass = std::mem::take(&mut state.copy_requested);
+
+        let scope = state.selection_scope.take().or(state.hovered_scope.take());
+        state.has_started_select_all = false;
+        state.select_all_scope = scope.filter(|_| {
+            // Don't steal `Cmd+A` from a focused `TextEdit`:
+            !ctx.wants_keyboard_input()
+                && ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::A))
+        });
         state.text_to_copy.clear();
         state.last_copied_galley_rect = None;
         state.painted_selections.clear();
@@ -238,6 +281,21 @@ impl LabelSelectionState {
         self.selection = None;
     }
 
+    /// Copy the selected text to the clipboard at the start of the next pass,
+    /// just like pressing `Ctrl+C` would.
+    ///
+    /// Does nothing if there is no selection.
+    pub fn request_copy(&mut self) {
+        self.copy_requested = true;
+    }
+
+    /// Don't treat a click this pass as a click outside the selection.
+    ///
+    /// Used by popups (like the label context menu) that act on the selection.
+    pub(crate) fn keep_selection(&mut self) {
+        self.any_hovered = true;
+    }
+
     fn copy_text(&mut self, new_galley_rect: Rect, galley: &Galley, cursor_range: &CCursorRange) {
         let new_text = selected_text(galley, cursor_range);
         if new_text.is_empty() {
@@ -499,6 +557,35 @@ impl LabelSelectionState {
         }
     }
 
+    /// Extend the selection to all of this label, which is in the scope we select all of.
+    ///
+    /// The first label of the scope gets the secondary cursor, and the last one the primary.
+    fn select_all_in_scope(
+        &mut self,
+        response: &Response,
+        global_from_galley: TSTransform,
+        galley: &Galley,
+    ) {
+        let end = WidgetTextCursor::new(response.id, galley.end(), global_from_galley, galley);
+        match &mut self.selection {
+            Some(selection) if self.has_started_select_all => {
+                selection.primary = end;
+            }
+            _ => {
+                let begin =
+                    WidgetTextCursor::new(response.id, galley.begin(), global_from_galley, galley);
+                self.selection = Some(CurrentSelection {
+                    layer_id: response.layer_id,
+                    primary: end,
+                    secondary: begin,
+                });
+                self.has_started_select_all = true;
+            }
+        }
+        self.has_reached_primary = true;
+        self.has_reached_secondary = true;
+    }
+
     /// Returns the painted selections, if any.
     fn on_label(
         &mut self,
@@ -524,16 +611,36 @@ impl LabelSelectionState {
         }
 
         self.any_hovered |= response.hovered();
-        self.is_dragging |= response.is_pointer_button_down_on(); // we don't want the initial latency of drag vs click decision
+        // Right-clicking opens the context menu, and should neither select nor deselect:
+        let secondary_pressed = ui.input(|i| i.pointer.button_pressed(PointerButton::Secondary));
+        self.is_dragging |= response.is_pointer_button_down_on() && !secondary_pressed; // we don't want the initial latency of drag vs click decision
 
         let old_selection = self.selection;
 
+        let scope = selection_scope(ui);
+        if let Some((scope_id, scope_rect)) = scope {
+            if ui
+                .ctx()
+                .pointer_hover_pos()
+                .is_some_and(|pos| scope_rect.contains(pos))
+            {
+                self.hovered_scope = Some(scope_id);
+            }
+        }
+        let select_all = self.select_all_scope.is_some()
+            && self.select_all_scope == scope.map(|(scope_id, _)| scope_id);
+
         let mut cursor_state = self.cursor_for(ui, response, global_from_galley, galley);
 
         let old_range = cursor_state.range(galley);
 
+        if select_all {
+            self.select_all_in_scope(response, global_from_galley, galley);
+            cursor_state.set_char_range(Some(CCursorRange::select_all(galley)));
+        }
+
         if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-            if response.contains_pointer() {
+            if response.contains_pointer() && !secondary_pressed && !select_all {
                 let cursor_at_pointer =
                     galley.cursor_from_pos((galley_from_global * pointer_pos).to_vec2());
 
@@ -554,7 +661,7 @@ impl LabelSelectionState {
                 }
             }
 
-            if got_copy_event(ui.ctx()) {
+            if self.copy_this_pass || got_copy_event(ui.ctx()) {
                 self.copy_text(galley_rect, galley, &cursor_range);
             }
 
@@ -565,7 +672,7 @@ impl LabelSelectionState {
         let new_range = cursor_state.range(galley);
         let selection_changed = old_range != new_range;
 
-        if let (true, Some(range)) = (selection_changed, new_range) {
+        if let (true, Some(range), false) = (selection_changed, new_range, select_all) {
             // --------------
             // Store results:
 
@@ -632,6 +739,13 @@ impl LabelSelectionState {
             }
         }
 
+        if self
+            .selection
+            .is_some_and(|selection| selection.primary.widget_id == widget_id)
+        {
+            self.selection_scope = scope.map(|(scope_id, _)| scope_id);
+        }
+
         let cursor_range = cursor_state.range(galley);
 
         let mut new_vertex_indices = vec![];
@@ -659,6 +773,18 @@ impl LabelSelectionState {
     }
 }
 
+/// The id and visible rectangle of the innermost selection scope around `ui`, if any.
+///
+/// See [`LabelSelectionState::SELECTION_SCOPE_TAG`].
+fn selection_scope(ui: &Ui) -> Option<(Id, Rect)> {
+    let scope = ui.stack().iter().find(|frame| {
+        frame
+            .tags()
+            .contains(LabelSelectionState::SELECTION_SCOPE_TAG)
+    })?;
+    Some((scope.id, scope.max_rect.intersect(ui.clip_rect())))
+}
+
 fn got_copy_event(ctx: &Context) -> bool {
     ctx.input(|i| {
         i.events
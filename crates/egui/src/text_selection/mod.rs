@@ -6,8 +6,10 @@ pub mod accesskit_text;
 mod cursor_range;
 mod label_text_selection;
 pub mod text_cursor_state;
+mod text_search;
 pub mod visuals;
 
 pub use cursor_range::CCursorRange;
 pub use label_text_selection::LabelSelectionState;
 pub use text_cursor_state::TextCursorState;
+pub use text_search::{TextSearch, range_rects, scroll_to_range};
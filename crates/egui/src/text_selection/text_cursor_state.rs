@@ -175,6 +175,18 @@ fn select_line_at(text: &str, ccursor: CCursor) -> CCursorRange {
     }
 }
 
+/// Select the whole paragraph (the text between two `\n`) at the given cursor,
+/// including the trailing newline.
+pub(crate) fn select_paragraph_at(text: &str, ccursor: CCursor) -> CCursorRange {
+    let start = find_line_start(text, ccursor);
+    let end = text
+        .chars()
+        .skip(start.index)
+        .position(|c| c == '\n')
+        .map_or_else(|| text.chars().count(), |i| start.index + i + 1);
+    CCursorRange::two(start, CCursor::new(end))
+}
+
 pub fn ccursor_next_word(text: &str, ccursor: CCursor) -> CCursor {
     CCursor {
         index: next_word_boundary_char_index(text, ccursor.index),
@@ -321,7 +333,11 @@ pub fn cursor_rect(galley: &Galley, cursor: &CCursor, row_height: f32) -> Rect {
 
 #[cfg(test)]
 mod test {
-    use crate::text_selection::text_cursor_state::next_word_boundary_char_index;
+    use epaint::text::cursor::CCursor;
+
+    use crate::text_selection::text_cursor_state::{
+        next_word_boundary_char_index, select_paragraph_at,
+    };
 
     #[test]
     fn test_next_word_boundary_char_index() {
@@ -352,4 +368,17 @@ mod test {
         assert_eq!(next_word_boundary_char_index(text, 19), 20);
         assert_eq!(next_word_boundary_char_index(text, 20), 21);
     }
+
+    #[test]
+    fn test_select_paragraph_at() {
+        let text = "first\nsécond\n\nlast";
+        let range = |index| select_paragraph_at(text, CCursor::new(index)).as_sorted_char_range();
+        assert_eq!(range(0), 0..6);
+        assert_eq!(range(3), 0..6);
+        assert_eq!(range(6), 6..13);
+        assert_eq!(range(12), 6..13);
+        assert_eq!(range(13), 13..14);
+        assert_eq!(range(14), 14..18);
+        assert_eq!(range(18), 14..18);
+    }
 }
@@ -0,0 +1,241 @@
+//! Finding and highlighting text in a [`Galley`], e.g. for find-in-text in a log viewer.
+
+use epaint::{Galley, text::cursor::CCursor};
+
+use crate::{Align, Pos2, Rect, Ui, pos2};
+
+use super::{CCursorRange, text_cursor_state::is_word_char};
+
+/// A search term, and how to match it.
+///
+/// ```
+/// # use egui::text_selection::TextSearch;
+/// let search = TextSearch::new("error").whole_word(true);
+/// let matches = search.find_all("Error: errors are an error");
+/// assert_eq!(matches[0].as_sorted_char_range(), 0..5);
+/// assert_eq!(matches[1].as_sorted_char_range(), 21..26);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextSearch {
+    /// What to search for. Nothing matches an empty query.
+    pub query: String,
+
+    /// If false (default), `a` matches `A`.
+    pub case_sensitive: bool,
+
+    /// If true, only match whole words, i.e. not `error` in `errors`.
+    pub whole_word: bool,
+}
+
+impl TextSearch {
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Default::default()
+        }
+    }
+
+    /// If false (default), `a` matches `A`.
+    #[inline]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// If true, only match whole words.
+    #[inline]
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.whole_word = whole_word;
+        self
+    }
+
+    /// All the (non-overlapping) matches in the text, in order.
+    ///
+    /// The ranges are in characters (NOT bytes!), so they can be used with [`Galley`]s.
+    pub fn find_all(&self, text: &str) -> Vec<CCursorRange> {
+        let query: Vec<char> = self.query.chars().collect();
+        if query.is_empty() {
+            return vec![];
+        }
+        let text: Vec<char> = text.chars().collect();
+
+        let mut matches = vec![];
+        let mut start = 0;
+        while start + query.len() <= text.len() {
+            if self.matches_at(&text, start, &query) {
+                matches.push(CCursorRange::two(
+                    CCursor::new(start),
+                    CCursor::new(start + query.len()),
+                ));
+                start += query.len();
+            } else {
+                start += 1;
+            }
+        }
+        matches
+    }
+
+    /// The first match after `cursor`, wrapping around to the start of the text.
+    ///
+    /// Use this for a "next match" button, passing in the end of the current match.
+    pub fn find_next(&self, text: &str, cursor: CCursor) -> Option<CCursorRange> {
+        let matches = self.find_all(text);
+        matches
+            .iter()
+            .find(|range| cursor.index <= range.as_sorted_char_range().start)
+            .or_else(|| matches.first())
+            .copied()
+    }
+
+    /// The last match before `cursor`, wrapping around to the end of the text.
+    ///
+    /// Use this for a "previous match" button, passing in the start of the current match.
+    pub fn find_prev(&self, text: &str, cursor: CCursor) -> Option<CCursorRange> {
+        let matches = self.find_all(text);
+        matches
+            .iter()
+            .rev()
+            .find(|range| range.as_sorted_char_range().end <= cursor.index)
+            .or_else(|| matches.last())
+            .copied()
+    }
+
+    fn matches_at(&self, text: &[char], start: usize, query: &[char]) -> bool {
+        let end = start + query.len();
+        let chars_match = text[start..end].iter().zip(query).all(|(&a, &b)| {
+            a == b || (!self.case_sensitive && a.to_lowercase().eq(b.to_lowercase()))
+        });
+        let is_word_char_at = |i: Option<usize>| {
+            i.and_then(|i| text.get(i))
+                .is_some_and(|&c| is_word_char(c))
+        };
+        let at_word_boundaries = !self.whole_word
+            || !(is_word_char_at(start.checked_sub(1)) || is_word_char_at(Some(end)));
+        chars_match && at_word_boundaries
+    }
+}
+
+/// The rectangles covering a range of the galley, one per row, relative to the galley.
+///
+/// Use this to paint search highlights:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui::text_selection::{TextSearch, range_rects};
+/// let galley = ui.painter().layout_no_wrap(
+///     "warning: disk full".to_owned(),
+///     egui::FontId::monospace(12.0),
+///     ui.visuals().text_color(),
+/// );
+/// let pos = ui.cursor().min;
+/// for range in TextSearch::new("disk").find_all(galley.text()) {
+///     for rect in range_rects(&galley, &range) {
+///         let color = ui.visuals().selection.bg_fill;
+///         ui.painter().rect_filled(rect.translate(pos.to_vec2()), 0.0, color);
+///     }
+/// }
+/// ui.painter().galley(pos, galley, ui.visuals().text_color());
+/// # });
+/// ```
+pub fn range_rects(galley: &Galley, range: &CCursorRange) -> Vec<Rect> {
+    if range.is_empty() {
+        return vec![];
+    }
+
+    let [min, max] = range.sorted_cursors();
+    // A range starting at a row wrap starts on the next row:
+    let min = galley.layout_from_cursor(CCursor {
+        prefer_next_row: true,
+        ..min
+    });
+    let max = galley.layout_from_cursor(CCursor {
+        prefer_next_row: false,
+        ..max
+    });
+
+    (min.row..=max.row)
+        .map(|ri| {
+            let row = &galley.rows[ri];
+            let left = if ri == min.row {
+                row.x_offset(min.column)
+            } else {
+                0.0
+            };
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                row.size.x
+            };
+            Rect::from_min_max(pos2(left, 0.0), pos2(right, row.size.y))
+                .translate(row.pos.to_vec2())
+        })
+        .collect()
+}
+
+/// Scroll the enclosing [`crate::ScrollArea`] so that a range of a galley painted at `galley_pos`
+/// is centered in view, e.g. when jumping to the next search match.
+pub fn scroll_to_range(ui: &Ui, galley_pos: Pos2, galley: &Galley, range: &CCursorRange) {
+    let rect = range_rects(galley, range)
+        .into_iter()
+        .reduce(Rect::union)
+        .unwrap_or_else(|| galley.pos_from_cursor(range.primary));
+    ui.scroll_to_rect(rect.translate(galley_pos.to_vec2()), Some(Align::Center));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(search: &TextSearch, text: &str) -> Vec<(usize, usize)> {
+        search
+            .find_all(text)
+            .iter()
+            .map(|range| {
+                let range = range.as_sorted_char_range();
+                (range.start, range.end)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_all() {
+        let text = "Ünïcode ünïcode ÜNÏCODE_x";
+        assert_eq!(
+            ranges(&TextSearch::new("ünï"), text),
+            [(0, 3), (8, 11), (16, 19)]
+        );
+        assert_eq!(
+            ranges(&TextSearch::new("ünï").case_sensitive(true), text),
+            [(8, 11)]
+        );
+        assert_eq!(
+            ranges(&TextSearch::new("ünïcode").whole_word(true), text),
+            [(0, 7), (8, 15)]
+        );
+        assert_eq!(ranges(&TextSearch::new("aa"), "aaaaa"), [(0, 2), (2, 4)]);
+        assert!(TextSearch::new("").find_all(text).is_empty());
+    }
+
+    #[test]
+    fn test_find_next_and_prev() {
+        let search = TextSearch::new("ab");
+        let text = "ab ab ab";
+        let next = |index| {
+            search
+                .find_next(text, CCursor::new(index))
+                .map(|r| r.as_sorted_char_range())
+        };
+        let prev = |index| {
+            search
+                .find_prev(text, CCursor::new(index))
+                .map(|r| r.as_sorted_char_range())
+        };
+        assert_eq!(next(0), Some(0..2));
+        assert_eq!(next(2), Some(3..5));
+        assert_eq!(next(8), Some(0..2), "Wraps around");
+        assert_eq!(prev(3), Some(0..2));
+        assert_eq!(prev(0), Some(6..8), "Wraps around");
+        assert_eq!(TextSearch::new("x").find_next(text, CCursor::new(0)), None);
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{Galley, Painter, Rect, Ui, Visuals, pos2, vec2};
+use crate::{
+    Color32, Galley, Painter, Pos2, Rangef, Rect, Stroke, Ui, Visuals, epaint::text::Row, pos2,
+    vec2,
+};
 
 use super::CCursorRange;
 
@@ -33,65 +36,132 @@ pub fn paint_text_selection(
     for ri in min.row..=max.row {
         let row = Arc::make_mut(&mut galley.rows[ri].row);
 
-        let left = if ri == min.row {
-            row.x_offset(min.column)
+        let newline_size = if ri != max.row && row.ends_with_newline {
+            row.height() / 2.0 // visualize that we select the newline
         } else {
             0.0
         };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
+
+        let x_ranges = if row.has_rtl() {
+            // The selected characters may be spread out over the row:
+            let first_column = if ri == min.row { min.column } else { 0 };
+            let end_column = if ri == max.row {
+                max.column
+            } else {
+                row.glyphs.len()
+            };
+            let mut x_ranges = row.x_ranges(first_column..end_column);
+            if 0.0 < newline_size {
+                x_ranges.push(Rangef::new(row.size.x, row.size.x + newline_size));
+            }
+            x_ranges
         } else {
-            let newline_size = if row.ends_with_newline {
-                row.height() / 2.0 // visualize that we select the newline
+            let left = if ri == min.row {
+                row.x_offset(min.column)
             } else {
                 0.0
             };
-            row.size.x + newline_size
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                row.size.x + newline_size
+            };
+            vec![Rangef::new(left, right)]
         };
 
-        let rect = Rect::from_min_max(pos2(left, 0.0), pos2(right, row.size.y));
-        let mesh = &mut row.visuals.mesh;
-
-        // Time to insert the selection rectangle into the row mesh.
-        // It should be on top (after) of any background in the galley,
-        // but behind (before) any glyphs. The row visuals has this information:
-        let glyph_index_start = row.visuals.glyph_index_start;
-
-        // Start by appending the selection rectangle to end of the mesh, as two triangles (= 6 indices):
-        let num_indices_before = mesh.indices.len();
-        mesh.add_colored_rect(rect, color);
-        assert_eq!(
-            num_indices_before + 6,
-            mesh.indices.len(),
-            "We expect exactly 6 new indices"
-        );
-
-        // Copy out the new triangles:
-        let selection_triangles = [
-            mesh.indices[num_indices_before],
-            mesh.indices[num_indices_before + 1],
-            mesh.indices[num_indices_before + 2],
-            mesh.indices[num_indices_before + 3],
-            mesh.indices[num_indices_before + 4],
-            mesh.indices[num_indices_before + 5],
-        ];
-
-        // Move every old triangle forwards by 6 indices to make room for the new triangle:
-        for i in (glyph_index_start..num_indices_before).rev() {
-            mesh.indices.swap(i, i + 6);
+        for x_range in x_ranges {
+            let rect = Rect::from_x_y_ranges(x_range, 0.0..=row.size.y);
+            let selection_triangles = insert_selection_rect(row, rect, color);
+            if let Some(new_vertex_indices) = &mut new_vertex_indices {
+                new_vertex_indices.push(RowVertexIndices {
+                    row: ri,
+                    vertex_indices: selection_triangles,
+                });
+            }
         }
-        // Put the new triangle in place:
-        mesh.indices[glyph_index_start..glyph_index_start + 6]
-            .clone_from_slice(&selection_triangles);
+    }
+}
+
+/// Insert a selection rectangle into the row mesh, returning its indices.
+fn insert_selection_rect(row: &mut Row, rect: Rect, color: Color32) -> [u32; 6] {
+    let mesh = &mut row.visuals.mesh;
+
+    // Time to insert the selection rectangle into the row mesh.
+    // It should be on top (after) of any background in the galley,
+    // but behind (before) any glyphs. The row visuals has this information:
+    let glyph_index_start = row.visuals.glyph_index_start;
+
+    // Start by appending the selection rectangle to end of the mesh, as two triangles (= 6 indices):
+    let num_indices_before = mesh.indices.len();
+    mesh.add_colored_rect(rect, color);
+    assert_eq!(
+        num_indices_before + 6,
+        mesh.indices.len(),
+        "We expect exactly 6 new indices"
+    );
+
+    // Copy out the new triangles:
+    let selection_triangles = [
+        mesh.indices[num_indices_before],
+        mesh.indices[num_indices_before + 1],
+        mesh.indices[num_indices_before + 2],
+        mesh.indices[num_indices_before + 3],
+        mesh.indices[num_indices_before + 4],
+        mesh.indices[num_indices_before + 5],
+    ];
+
+    // Move every old triangle forwards by 6 indices to make room for the new triangle:
+    for i in (glyph_index_start..num_indices_before).rev() {
+        mesh.indices.swap(i, i + 6);
+    }
+    // Put the new triangle in place:
+    mesh.indices[glyph_index_start..glyph_index_start + 6].clone_from_slice(&selection_triangles);
+
+    row.visuals.mesh_bounds = mesh.calc_bounds();
+
+    selection_triangles
+}
+
+/// Underline the IME composition (preedit) text, i.e. the text that the input method
+/// has inserted but not yet committed.
+///
+/// `galley_pos` is where the galley is painted on screen.
+pub fn paint_ime_preedit(
+    painter: &Painter,
+    galley: &Galley,
+    galley_pos: Pos2,
+    preedit_range: &CCursorRange,
+    stroke: Stroke,
+) {
+    if preedit_range.is_empty() {
+        return;
+    }
 
-        row.visuals.mesh_bounds = mesh.calc_bounds();
+    let [min, max] = preedit_range.sorted_cursors();
+    let min = galley.layout_from_cursor(min);
+    let max = galley.layout_from_cursor(max);
 
-        if let Some(new_vertex_indices) = &mut new_vertex_indices {
-            new_vertex_indices.push(RowVertexIndices {
-                row: ri,
-                vertex_indices: selection_triangles,
-            });
+    for ri in min.row..=max.row {
+        let placed_row = &galley.rows[ri];
+        let row = &placed_row.row;
+
+        let left = if ri == min.row {
+            row.x_offset(min.column)
+        } else {
+            0.0
+        };
+        let right = if ri == max.row {
+            row.x_offset(max.column)
+        } else {
+            row.size.x
+        };
+        if right <= left {
+            continue;
         }
+
+        let y = galley_pos.y + placed_row.pos.y + row.size.y - 0.5 * stroke.width;
+        let x = galley_pos.x + placed_row.pos.x;
+        painter.line_segment([pos2(x + left, y), pos2(x + right, y)], stroke);
     }
 }
 
@@ -128,9 +198,10 @@ pub fn paint_text_cursor(
     primary_cursor_rect: Rect,
     time_since_last_interaction: f64,
 ) {
-    if ui.visuals().text_cursor.blink {
-        let on_duration = ui.visuals().text_cursor.on_duration;
-        let off_duration = ui.visuals().text_cursor.off_duration;
+    let text_cursor = &ui.visuals().text_cursor;
+    if text_cursor.is_blinking() {
+        let on_duration = text_cursor.on_duration;
+        let off_duration = text_cursor.off_duration;
         let total_duration = on_duration + off_duration;
 
         let time_in_cycle = (time_since_last_interaction % (total_duration as f64)) as f32;
@@ -146,6 +217,7 @@ pub fn paint_text_cursor(
 
         ui.ctx().request_repaint_after_secs(wake_in);
     } else {
+        // A solid cursor needs no repaints.
         paint_cursor_end(painter, ui.visuals(), primary_cursor_rect);
     }
 }
@@ -0,0 +1,351 @@
+use epaint::{Color32, ColorImage, TextureHandle, TextureId, textures::TextureOptions};
+
+use crate::{Context, Image, Rect, load::SizedTexture, pos2, vec2};
+
+/// Empty texels between the images, so that filtering doesn't bleed them into each other.
+const PADDING: usize = 1;
+
+/// Identifies an image in a [`TextureAtlas`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AtlasKey(usize);
+
+/// Where an image is in the atlas, in texels.
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    pos: [usize; 2],
+    size: [usize; 2],
+}
+
+/// Packs many small images, like a set of icons, into a single texture.
+///
+/// Shapes using the same texture can be tessellated into the same mesh,
+/// so this means fewer draw calls and less memory than a texture per image.
+///
+/// The atlas starts out small, grows as you insert images,
+/// and moves them all around (repacks) when it runs out of room.
+/// So always ask for the uv rectangle with [`Self::get`] or [`Self::image`]
+/// when painting, rather than remembering it.
+///
+/// Not to be confused with [`epaint::TextureAtlas`], which holds the glyphs of the fonts.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// // Create this once, and keep it in your app state:
+/// let mut atlas =
+///     egui::TextureAtlas::new(ui.ctx(), "icons", [512, 512], egui::TextureOptions::LINEAR);
+/// let icon = egui::ColorImage::filled([16, 16], egui::Color32::RED);
+/// if let Some(key) = atlas.insert(icon) {
+///     if let Some(image) = atlas.image(key) {
+///         ui.add(image);
+///     }
+/// }
+/// # });
+/// ```
+pub struct TextureAtlas {
+    handle: TextureHandle,
+    options: TextureOptions,
+    max_size: [usize; 2],
+
+    /// A copy of the texture, so we can repack it.
+    image: ColorImage,
+
+    /// Indexed by [`AtlasKey`]. `None` for removed images.
+    entries: Vec<Option<Entry>>,
+
+    shelf: Shelf,
+}
+
+impl TextureAtlas {
+    /// Allocate a new atlas texture.
+    ///
+    /// The texture never grows larger than `max_size`, nor [`crate::InputState::max_texture_side`].
+    pub fn new(
+        ctx: &Context,
+        name: impl Into<String>,
+        max_size: [usize; 2],
+        options: TextureOptions,
+    ) -> Self {
+        let max_texture_side = ctx.input(|i| i.max_texture_side);
+        let max_size = max_size.map(|side| side.clamp(1, max_texture_side));
+        let image = ColorImage::filled(
+            [max_size[0].min(128), max_size[1].min(32)],
+            Color32::TRANSPARENT,
+        );
+        Self {
+            handle: ctx.load_texture(name, image.clone(), options),
+            options,
+            max_size,
+            image,
+            entries: Vec::new(),
+            shelf: Shelf::default(),
+        }
+    }
+
+    /// The texture all the images are in.
+    #[inline]
+    pub fn texture_id(&self) -> TextureId {
+        self.handle.id()
+    }
+
+    /// The current size of the texture, in texels.
+    #[inline]
+    pub fn size(&self) -> [usize; 2] {
+        self.image.size
+    }
+
+    /// Number of images in the atlas.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add an image to the atlas, and upload it.
+    ///
+    /// Returns `None` if it doesn't fit, even after repacking everything into a texture of the max size.
+    pub fn insert(&mut self, image: ColorImage) -> Option<AtlasKey> {
+        let key = AtlasKey(self.entries.len());
+
+        let mut shelf = self.shelf;
+        let pos = shelf
+            .allocate(image.size, self.image.width())
+            .filter(|_| shelf.height() <= self.max_size[1]);
+
+        if let Some(pos) = pos {
+            self.shelf = shelf;
+            let grew = self.image.height() < shelf.height();
+            if grew {
+                let height = texture_height(shelf.height(), self.max_size[1]);
+                let mut grown =
+                    ColorImage::filled([self.image.width(), height], Color32::TRANSPARENT);
+                blit(&mut grown, [0, 0], &self.image);
+                self.image = grown;
+            }
+            blit(&mut self.image, pos, &image);
+            self.entries.push(Some(Entry {
+                pos,
+                size: image.size,
+            }));
+
+            if grew {
+                self.handle.set(self.image.clone(), self.options);
+            } else {
+                self.handle.set_partial(pos, image, self.options);
+            }
+        } else {
+            self.repack(Some(image))?;
+        }
+
+        Some(key)
+    }
+
+    /// Remove an image from the atlas.
+    ///
+    /// Its space is reclaimed the next time the atlas is repacked.
+    pub fn remove(&mut self, key: AtlasKey) {
+        if let Some(entry) = self.entries.get_mut(key.0) {
+            *entry = None;
+        }
+    }
+
+    /// The texture of the image, and where in it the image is, in normalized uv coordinates.
+    pub fn get(&self, key: AtlasKey) -> Option<(TextureId, Rect)> {
+        let entry = self.entries.get(key.0).copied().flatten()?;
+        let [width, height] = self.image.size.map(|side| side as f32);
+        let uv = Rect::from_min_size(
+            pos2(entry.pos[0] as f32 / width, entry.pos[1] as f32 / height),
+            vec2(entry.size[0] as f32 / width, entry.size[1] as f32 / height),
+        );
+        Some((self.texture_id(), uv))
+    }
+
+    /// An [`Image`] showing the image, with its size in texels.
+    pub fn image(&self, key: AtlasKey) -> Option<Image<'static>> {
+        let (texture_id, uv) = self.get(key)?;
+        let size = self.entries[key.0]?.size.map(|side| side as f32);
+        Some(Image::from_texture(SizedTexture::new(texture_id, size)).uv(uv))
+    }
+
+    /// Pack all the images, and `extra`, as tightly as we can,
+    /// widening the texture if we need to.
+    ///
+    /// Returns `None` and changes nothing if they don't fit in the max size.
+    fn repack(&mut self, extra: Option<ColorImage>) -> Option<()> {
+        let keys: Vec<usize> = (0..self.entries.len())
+            .filter(|&i| self.entries[i].is_some())
+            .collect();
+        let mut sizes: Vec<[usize; 2]> = keys
+            .iter()
+            .filter_map(|&i| self.entries[i].map(|entry| entry.size))
+            .collect();
+        sizes.extend(extra.as_ref().map(|image| image.size));
+
+        let mut width = self.image.width();
+        let (positions, shelf) = loop {
+            if let Some((positions, shelf)) = pack(&sizes, width) {
+                if shelf.height() <= self.max_size[1] {
+                    break (positions, shelf);
+                }
+            }
+            if self.max_size[0] <= width {
+                return None;
+            }
+            width = (2 * width).min(self.max_size[0]);
+        };
+
+        let height = texture_height(shelf.height(), self.max_size[1]);
+        let mut image = ColorImage::filled([width, height], Color32::TRANSPARENT);
+        for (&i, &pos) in keys.iter().zip(&positions) {
+            if let Some(entry) = &mut self.entries[i] {
+                blit(
+                    &mut image,
+                    pos,
+                    &self.image.region_by_pixels(entry.pos, entry.size),
+                );
+                entry.pos = pos;
+            }
+        }
+        if let Some(extra) = extra {
+            let pos = positions[keys.len()];
+            blit(&mut image, pos, &extra);
+            self.entries.push(Some(Entry {
+                pos,
+                size: extra.size,
+            }));
+        }
+
+        self.image = image;
+        self.shelf = shelf;
+        self.handle.set(self.image.clone(), self.options);
+        Some(())
+    }
+}
+
+/// Puts images in rows, left to right, top to bottom.
+#[derive(Clone, Copy, Debug, Default)]
+struct Shelf {
+    /// Where the next image goes.
+    cursor: [usize; 2],
+    row_height: usize,
+}
+
+impl Shelf {
+    fn allocate(&mut self, [w, h]: [usize; 2], width: usize) -> Option<[usize; 2]> {
+        if width < w {
+            return None;
+        }
+        if width < self.cursor[0] + w {
+            // New row:
+            self.cursor = [0, self.height() + PADDING];
+            self.row_height = 0;
+        }
+        let pos = self.cursor;
+        self.cursor[0] += w + PADDING;
+        self.row_height = self.row_height.max(h);
+        Some(pos)
+    }
+
+    /// The height used so far.
+    fn height(&self) -> usize {
+        self.cursor[1] + self.row_height
+    }
+}
+
+/// Pack images of these sizes into rows `width` wide, tallest first.
+///
+/// Returns where each image goes.
+fn pack(sizes: &[[usize; 2]], width: usize) -> Option<(Vec<[usize; 2]>, Shelf)> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(sizes[i][1]));
+
+    let mut shelf = Shelf::default();
+    let mut positions = vec![[0, 0]; sizes.len()];
+    for i in order {
+        positions[i] = shelf.allocate(sizes[i], width)?;
+    }
+    Some((positions, shelf))
+}
+
+/// Round up to a power of two, to not reallocate the texture for every new row.
+fn texture_height(required_height: usize, max_height: usize) -> usize {
+    required_height.next_power_of_two().min(max_height)
+}
+
+fn blit(target: &mut ColorImage, [x, y]: [usize; 2], source: &ColorImage) {
+    let [w, h] = source.size;
+    for row in 0..h {
+        let start = (y + row) * target.width() + x;
+        target.pixels[start..start + w].copy_from_slice(&source.pixels[row * w..(row + 1) * w]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(size: [usize; 2], color: Color32) -> ColorImage {
+        ColorImage::filled(size, color)
+    }
+
+    /// The texels of the image, read back from the atlas.
+    fn texels(atlas: &TextureAtlas, key: AtlasKey) -> Vec<Color32> {
+        let entry = atlas.entries[key.0].unwrap();
+        let (_, uv) = atlas.get(key).unwrap();
+        let [width, height] = atlas.size().map(|side| side as f32);
+        assert_eq!(uv.min.x * width, entry.pos[0] as f32);
+        assert_eq!(uv.min.y * height, entry.pos[1] as f32);
+        atlas.image.region_by_pixels(entry.pos, entry.size).pixels
+    }
+
+    #[test]
+    fn images_share_a_texture() {
+        let ctx = Context::default();
+        let mut atlas = TextureAtlas::new(&ctx, "icons", [256, 256], TextureOptions::LINEAR);
+        let red = atlas.insert(icon([16, 16], Color32::RED)).unwrap();
+        let blue = atlas.insert(icon([8, 24], Color32::BLUE)).unwrap();
+
+        let (red_texture, red_uv) = atlas.get(red).unwrap();
+        let (blue_texture, blue_uv) = atlas.get(blue).unwrap();
+        assert_eq!(red_texture, blue_texture);
+        assert!(!red_uv.intersects(blue_uv));
+        assert_eq!(texels(&atlas, red), vec![Color32::RED; 16 * 16]);
+        assert_eq!(texels(&atlas, blue), vec![Color32::BLUE; 8 * 24]);
+        assert_eq!(atlas.len(), 2);
+    }
+
+    #[test]
+    fn repacks_when_full() {
+        let ctx = Context::default();
+        let mut atlas = TextureAtlas::new(&ctx, "icons", [64, 64], TextureOptions::LINEAR);
+        let colors = [Color32::RED, Color32::GREEN, Color32::BLUE, Color32::YELLOW];
+        let keys: Vec<AtlasKey> = colors
+            .iter()
+            .map(|&color| atlas.insert(icon([31, 31], color)).unwrap())
+            .collect();
+        assert!(
+            atlas.insert(icon([31, 31], Color32::WHITE)).is_none(),
+            "Full"
+        );
+        assert!(
+            atlas.insert(icon([65, 1], Color32::WHITE)).is_none(),
+            "Too wide"
+        );
+
+        atlas.remove(keys[1]);
+        let white = atlas
+            .insert(icon([31, 31], Color32::WHITE))
+            .expect("Reuses the space of the removed image");
+        assert_eq!(atlas.size(), [64, 64]);
+        assert!(atlas.get(keys[1]).is_none());
+        for (key, color) in [
+            (keys[0], Color32::RED),
+            (keys[2], Color32::BLUE),
+            (keys[3], Color32::YELLOW),
+            (white, Color32::WHITE),
+        ] {
+            assert_eq!(texels(&atlas, key), vec![color; 31 * 31]);
+        }
+    }
+}
@@ -11,13 +11,13 @@ use crate::Stroke;
 use crate::containers::menu;
 use crate::{
     Align, Color32, Context, CursorIcon, DragAndDrop, Id, InnerResponse, InputState, IntoAtoms,
-    LayerId, Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText,
-    Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2,
-    WidgetRect, WidgetText,
+    KeyboardShortcut, LayerId, Memory, Order, Painter, PlatformOutput, PopupCloseBehavior, Pos2,
+    Rangef, Rect, Response, Rgba, RichText, Sense, Style, TextStyle, TextWrapMode, UiBuilder,
+    UiKind, UiStack, UiStackInfo, Vec2, WidgetRect, WidgetText,
     containers::{CollapsingHeader, CollapsingResponse, Frame},
     ecolor::Hsva,
     emath, epaint,
-    epaint::text::Fonts,
+    epaint::text::{Fonts, TextDirection},
     grid,
     layout::{Direction, Layout},
     pass_state,
@@ -27,7 +27,8 @@ use crate::{
     vec2, widgets,
     widgets::{
         Button, Checkbox, DragValue, Hyperlink, Image, ImageSource, Label, Link, RadioButton,
-        Separator, Spinner, TextEdit, Widget, color_picker,
+        RadioGroup, SegmentedControl, Separator, Spinner, TextEdit, ToggleSwitch, Widget,
+        color_picker,
     },
 };
 // ----------------------------------------------------------------------------
@@ -701,6 +702,19 @@ impl Ui {
         self.wrap_mode() == TextWrapMode::Wrap
     }
 
+    /// Should text and widgets be laid out right-to-left?
+    ///
+    /// This is determined by [`Style::text_direction`].
+    #[inline]
+    pub fn is_right_to_left(&self) -> bool {
+        self.style.text_direction == TextDirection::RightToLeft
+    }
+
+    /// Should horizontal layouts fill from the right?
+    fn prefer_right_to_left(&self) -> bool {
+        self.is_right_to_left() || self.placer.prefer_right_to_left()
+    }
+
     /// How to vertically align text
     #[inline]
     pub fn text_valign(&self) -> Align {
@@ -756,6 +770,26 @@ impl Ui {
         self.painter.set_clip_rect(clip_rect);
     }
 
+    /// Only paint inside of this convex polygon from now on, e.g. a rounded rectangle or a circle.
+    ///
+    /// This is in addition to the clip rect, which is shrunk to the bounding rectangle of the polygon.
+    /// Interaction is only limited by the clip rect.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = ui.available_rect_before_wrap();
+    /// ui.scope(|ui| {
+    ///     ui.clip_to_polygon(egui::epaint::ClipPolygon::rounded_rect(rect, 16.0));
+    ///     ui.label("Painted with rounded corners");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also: [`Painter::clip_to_polygon`].
+    pub fn clip_to_polygon(&mut self, clip_polygon: epaint::ClipPolygon) {
+        self.painter.clip_to_polygon(clip_polygon);
+    }
+
     /// Can be used for culling: if `false`, then no part of `rect` will be visible on screen.
     ///
     /// This is false if the whole `Ui` is invisible (see [`UiBuilder::invisible`])
@@ -2077,6 +2111,14 @@ impl Ui {
         Checkbox::new(checked, atoms).ui(self)
     }
 
+    /// Show an on/off switch without a label.
+    ///
+    /// Use [`ToggleSwitch`] to add a label or make it small.
+    #[inline]
+    pub fn toggle(&mut self, on: &mut bool) -> Response {
+        ToggleSwitch::without_text(on).ui(self)
+    }
+
     /// Acts like a checkbox, but looks like a [`Button::selectable`].
     ///
     /// Click to toggle to bool.
@@ -2132,6 +2174,62 @@ impl Ui {
         response
     }
 
+    /// Show a [`RadioGroup`]: one [`RadioButton`] per alternative,
+    /// acting as a single tab stop that is navigated with the arrow keys.
+    ///
+    /// The alternative equal to `*current_value` is selected.
+    /// If another one is picked, it is assigned to `*current_value`
+    /// and the returned response is marked as changed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// #[derive(PartialEq)]
+    /// enum Enum { First, Second, Third }
+    /// let mut my_enum = Enum::First;
+    ///
+    /// ui.horizontal(|ui| {
+    ///     ui.radio_group(
+    ///         &mut my_enum,
+    ///         [(Enum::First, "First"), (Enum::Second, "Second"), (Enum::Third, "Third")],
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    pub fn radio_group<'a, Value: PartialEq>(
+        &mut self,
+        current_value: &'a mut Value,
+        alternatives: impl IntoIterator<Item = (Value, impl IntoAtoms<'a>)>,
+    ) -> Response {
+        RadioGroup::new(current_value, alternatives).ui(self)
+    }
+
+    /// Show a [`SegmentedControl`]: a row of connected buttons, one per alternative,
+    /// acting as a single tab stop that is navigated with the arrow keys.
+    ///
+    /// The alternative equal to `*current_value` is selected.
+    /// If another one is picked, it is assigned to `*current_value`
+    /// and the returned response is marked as changed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// #[derive(PartialEq)]
+    /// enum Period { Day, Week, Month }
+    /// let mut period = Period::Day;
+    ///
+    /// ui.segmented_control(
+    ///     &mut period,
+    ///     [(Period::Day, "Day"), (Period::Week, "Week"), (Period::Month, "Month")],
+    /// );
+    /// # });
+    /// ```
+    pub fn segmented_control<'a, Value: PartialEq>(
+        &mut self,
+        current_value: &'a mut Value,
+        segments: impl IntoIterator<Item = (Value, impl IntoAtoms<'a>)>,
+    ) -> Response {
+        SegmentedControl::new(current_value, segments).ui(self)
+    }
+
     /// Show a label which can be selected or not.
     ///
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
@@ -2434,6 +2532,48 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Measure how much space some contents would use, without showing them.
+    ///
+    /// The contents are laid out in an invisible, disabled sizing-pass child `Ui`
+    /// (see [`UiBuilder::sizing_pass`]) spanning [`Self::available_rect_before_wrap`].
+    /// Nothing is painted, nothing can be interacted with,
+    /// and no space is allocated in `self`.
+    ///
+    /// This is useful for custom layouts, e.g. to right-align a row of buttons:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let add_buttons = |ui: &mut egui::Ui| {
+    ///     ui.horizontal(|ui| {
+    ///         let _ = ui.button("Cancel");
+    ///         let _ = ui.button("Ok");
+    ///     });
+    /// };
+    /// let width = ui.measure(add_buttons).x;
+    /// ui.horizontal(|ui| {
+    ///     ui.add_space(ui.available_width() - width);
+    ///     add_buttons(ui);
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Widgets with explicit ids will be seen twice in the same pass,
+    /// so you may need to disable [`crate::Options::warn_on_id_clash`]
+    /// or give them different ids while measuring.
+    pub fn measure(&mut self, add_contents: impl FnOnce(&mut Ui)) -> Vec2 {
+        let next_auto_id_salt = self.next_auto_id_salt;
+        let mut child_ui = self.new_child(
+            UiBuilder::new()
+                .id_salt("measure")
+                .sizing_pass()
+                .invisible(),
+        );
+        // Measuring should not affect the ids of the widgets that follow:
+        self.next_auto_id_salt = next_auto_id_salt;
+        add_contents(&mut child_ui);
+        child_ui.min_size()
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2562,7 +2702,7 @@ impl Ui {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let initial_size = self.available_size_before_wrap();
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -2577,7 +2717,7 @@ impl Ui {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let initial_size = self.available_size_before_wrap();
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -2618,7 +2758,7 @@ impl Ui {
             self.spacing().interact_size.y, // Assume there will be something interactive on the horizontal layout
         );
 
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -2752,6 +2892,38 @@ impl Ui {
         self.placer.set_row_height(height);
     }
 
+    /// Add a cell to a [`crate::Grid`] that covers `columns` columns, e.g. for a full-width description.
+    ///
+    /// A spanning cell doesn't make the first column it covers any wider;
+    /// if it doesn't fit, the last column it covers grows instead.
+    /// The contents are laid out top-down, so long text wraps to the width of the cell.
+    ///
+    /// Outside of a [`crate::Grid`] this is the same as [`Self::vertical`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut enabled = true;
+    /// egui::Grid::new("settings").num_columns(2).show(ui, |ui| {
+    ///     ui.label("Enabled");
+    ///     ui.checkbox(&mut enabled, "");
+    ///     ui.end_row();
+    ///
+    ///     ui.grid_span(2, |ui| {
+    ///         ui.weak("Turns the whole thing on or off.");
+    ///     });
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    pub fn grid_span<R>(
+        &mut self,
+        columns: usize,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.placer.set_grid_span(columns);
+        self.vertical(add_contents)
+    }
+
     /// Temporarily split a [`Ui`] into several columns.
     ///
     /// ```
@@ -2977,6 +3149,168 @@ impl Ui {
         (InnerResponse { inner, response }, payload)
     }
 
+    /// Show `len` items that the user can reorder by dragging them.
+    ///
+    /// While an item is dragged, a line shows where it would go.
+    /// If the list is in a [`crate::ScrollArea`],
+    /// it scrolls when the item is dragged close to its edges (see [`Self::dnd_auto_scroll`]).
+    /// The list can be horizontal too, e.g. in [`Self::horizontal`].
+    ///
+    /// Returns `(from, to)` on the frame the item is dropped:
+    /// the item at index `from` should be moved so that it ends up at index `to`.
+    ///
+    /// The `id` needs to be globally unique.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut fruits = vec!["Apple", "Banana", "Cherry"];
+    /// let moved = ui.dnd_reorderable_list(egui::Id::new("fruits"), fruits.len(), |ui, i| {
+    ///     ui.label(fruits[i]);
+    /// });
+    /// if let Some((from, to)) = moved {
+    ///     let fruit = fruits.remove(from);
+    ///     fruits.insert(to, fruit);
+    /// }
+    /// # });
+    /// ```
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_reorderable_list(
+        &mut self,
+        id: Id,
+        len: usize,
+        mut add_item: impl FnMut(&mut Self, usize),
+    ) -> Option<(usize, usize)> {
+        /// The payload of an item being dragged.
+        struct ListItem {
+            list: Id,
+            index: usize,
+        }
+
+        let item_rects: Vec<Rect> = (0..len)
+            .map(|index| {
+                self.dnd_drag_source(id.with(index), ListItem { list: id, index }, |ui| {
+                    add_item(ui, index);
+                })
+                .response
+                .rect
+            })
+            .collect();
+
+        let dragged =
+            DragAndDrop::payload::<ListItem>(self.ctx()).filter(|item| item.list == id)?;
+
+        self.dnd_auto_scroll(self.spacing().interact_size.y);
+        let insertion_index = self.dnd_insertion_indicator(&item_rects);
+
+        if !self.input(|i| i.pointer.any_released()) {
+            return None;
+        }
+        DragAndDrop::clear_payload(self.ctx());
+
+        let from = dragged.index;
+        let insertion_index = insertion_index?;
+        let to = if from < insertion_index {
+            insertion_index - 1
+        } else {
+            insertion_index
+        };
+        (from != to).then_some((from, to))
+    }
+
+    /// Paint a line between the items of a list, where whatever is being dragged would be dropped.
+    ///
+    /// The `item_rects` are the rectangles of the items, in order,
+    /// laid out in the main direction of [`Self::layout`].
+    ///
+    /// Returns where to insert the dropped item, from `0` (before the first item)
+    /// to `item_rects.len()` (after the last item).
+    /// Returns `None` if nothing is being dragged, or if the pointer isn't over the list.
+    ///
+    /// See also [`Self::dnd_reorderable_list`].
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_insertion_indicator(&self, item_rects: &[Rect]) -> Option<usize> {
+        if !DragAndDrop::has_any_payload(self.ctx()) {
+            return None;
+        }
+        let pointer = self.ctx().pointer_interact_pos()?;
+        let list_rect = item_rects
+            .iter()
+            .fold(Rect::NOTHING, |list_rect, rect| list_rect.union(*rect));
+        let hover_rect = list_rect
+            .expand2(self.spacing().item_spacing)
+            .intersect(self.clip_rect());
+        if !hover_rect.contains(pointer) {
+            return None;
+        }
+
+        // The axis the items are laid out along:
+        let d = usize::from(self.layout().main_dir().is_vertical());
+        let insertion_index = item_rects
+            .iter()
+            .filter(|rect| rect.center()[d] < pointer[d])
+            .count();
+
+        let position = match (
+            insertion_index.checked_sub(1).map(|i| item_rects[i]),
+            item_rects.get(insertion_index),
+        ) {
+            (Some(before), Some(after)) => 0.5 * (before.max[d] + after.min[d]),
+            (Some(before), None) => before.max[d] + 0.5 * self.spacing().item_spacing[d],
+            (None, Some(after)) => after.min[d] - 0.5 * self.spacing().item_spacing[d],
+            (None, None) => list_rect.min[d],
+        };
+        let stroke = Stroke::new(2.0, self.visuals().selection.bg_fill);
+        if d == 0 {
+            self.painter().vline(position, list_rect.y_range(), stroke);
+        } else {
+            self.painter().hline(list_rect.x_range(), position, stroke);
+        }
+
+        Some(insertion_index)
+    }
+
+    /// Scroll the enclosing [`crate::ScrollArea`] if something is being dragged
+    /// within `margin` points of its edges, so that you can drag it to parts of the area that are
+    /// scrolled out of view.
+    ///
+    /// The closer the pointer is to the edge, the faster it scrolls.
+    ///
+    /// See also [`Self::dnd_reorderable_list`].
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_auto_scroll(&self, margin: f32) {
+        /// Points per second, with the pointer at the very edge.
+        const MAX_SPEED: f32 = 1000.0;
+
+        if !DragAndDrop::has_any_payload(self.ctx()) || margin <= 0.0 {
+            return;
+        }
+        let Some(pointer) = self.ctx().pointer_interact_pos() else {
+            return;
+        };
+        let viewport = self.clip_rect();
+        if !viewport.expand(margin).contains(pointer) {
+            return;
+        }
+
+        let dt = self.input(|i| i.stable_dt).min(0.1);
+        let mut delta = Vec2::ZERO;
+        for d in 0..2 {
+            let to_min = pointer[d] - viewport.min[d];
+            let to_max = viewport.max[d] - pointer[d];
+            if to_min < margin {
+                // Reveal what is before the viewport:
+                delta[d] = (1.0 - to_min / margin).min(1.0) * MAX_SPEED * dt;
+            } else if to_max < margin {
+                delta[d] = -(1.0 - to_max / margin).min(1.0) * MAX_SPEED * dt;
+            }
+        }
+
+        if delta != Vec2::ZERO {
+            self.scroll_with_delta_animation(delta, style::ScrollAnimation::none());
+            self.ctx().request_repaint();
+        }
+    }
+
     /// Create a new Scope and transform its contents via a [`emath::TSTransform`].
     /// This only affects visuals, inputs will not be transformed. So this is mostly useful
     /// to create visual effects on interactions, e.g. scaling a button on hover / click.
@@ -3129,6 +3463,48 @@ impl Ui {
         };
         InnerResponse::new(inner.map(|i| i.inner), response)
     }
+
+    /// A menu button that shows its keyboard shortcut, and is clicked by pressing the shortcut.
+    ///
+    /// The shortcut is shown on the right, formatted with [`Context::format_shortcut`].
+    /// [`Response::clicked`] is `true` if the button was clicked or the shortcut was pressed.
+    /// The shortcut is then consumed, and the menu is closed
+    /// (unless its [`menu::MenuConfig::close_behavior`] says otherwise).
+    ///
+    /// A disabled item doesn't consume its shortcut.
+    ///
+    /// The shortcut is only seen while the item is shown, i.e. while its menu is open.
+    /// To handle it while the menu is closed, register it with [`Context::register_shortcut`] too.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui::{Key, KeyboardShortcut, Modifiers};
+    /// ui.menu_button("File", |ui| {
+    ///     let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    ///     if ui.menu_item("Save", save).clicked() {
+    ///         // save…
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn menu_item<'a>(
+        &mut self,
+        atoms: impl IntoAtoms<'a>,
+        shortcut: KeyboardShortcut,
+    ) -> Response {
+        let shortcut_text = self.ctx().format_shortcut(&shortcut);
+        let mut response = self.add(Button::new(atoms).shortcut_text(shortcut_text));
+
+        if self.is_enabled() && self.input_mut(|i| i.consume_shortcut(&shortcut)) {
+            response.flags |= crate::response::Flags::FAKE_PRIMARY_CLICKED;
+            if menu::is_in_menu(self)
+                && menu::MenuConfig::find(self).close_behavior == PopupCloseBehavior::CloseOnClick
+            {
+                self.close();
+            }
+        }
+        response
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -112,6 +112,23 @@ where
         }
     }
 
+    /// The current [`Settings`].
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Change the [`Settings`].
+    ///
+    /// If the new `max_undos` is lower than the current number of undo points,
+    /// the oldest undo points are dropped.
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.settings = settings;
+        while self.undos.len() > self.settings.max_undos {
+            self.undos.pop_front();
+        }
+        self.redos.truncate(self.settings.max_undos);
+    }
+
     /// Do we have an undo point different from the given state?
     pub fn has_undo(&self, current_state: &State) -> bool {
         match self.undos.len() {
@@ -161,9 +178,12 @@ where
     }
 
     /// Add an undo point if, and only if, there has been a change since the latest undo point.
+    ///
+    /// Adding a new undo point clears the redo history.
     pub fn add_undo(&mut self, current_state: &State) {
         if self.undos.back() != Some(current_state) {
             self.undos.push_back(current_state.clone());
+            self.redos.clear();
         }
         while self.undos.len() > self.settings.max_undos {
             self.undos.pop_front();
@@ -219,3 +239,38 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Settings, Undoer};
+
+    #[test]
+    fn redo_is_cleared_by_new_edits() {
+        let mut undoer = Undoer::default();
+        undoer.add_undo(&1);
+        undoer.add_undo(&2);
+
+        assert_eq!(undoer.undo(&2), Some(&1));
+        assert!(undoer.has_redo(&1));
+
+        undoer.feed_state(0.0, &3);
+        assert!(!undoer.has_redo(&3));
+        assert_eq!(undoer.redo(&3), None);
+    }
+
+    #[test]
+    fn lowering_max_undos_drops_oldest() {
+        let mut undoer = Undoer::default();
+        for i in 0..10 {
+            undoer.add_undo(&i);
+        }
+        undoer.set_settings(Settings {
+            max_undos: 3,
+            ..Default::default()
+        });
+
+        assert_eq!(undoer.undo(&9), Some(&8));
+        assert_eq!(undoer.undo(&8), Some(&7));
+        assert_eq!(undoer.undo(&7), None);
+    }
+}
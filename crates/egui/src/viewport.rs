@@ -1111,6 +1111,8 @@ pub enum ViewportCommand {
     /// Take a screenshot of the next frame after this.
     ///
     /// The results are returned in [`crate::Event::Screenshot`].
+    ///
+    /// See also [`crate::Context::request_screenshot`], which can also crop the screenshot.
     Screenshot(crate::UserData),
 
     /// Request cut of the current selection
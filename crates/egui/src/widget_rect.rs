@@ -77,7 +77,7 @@ pub struct WidgetRects {
 
     /// Info about some widgets.
     ///
-    /// Only filled in if the widget is interacted with,
+    /// Only filled in if the widget is interacted with, if it is in a menu,
     /// or if this is a debug build.
     infos: IdMap<WidgetInfo>,
 }
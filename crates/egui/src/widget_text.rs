@@ -5,7 +5,7 @@ use std::{borrow::Cow, sync::Arc};
 
 use crate::{
     Align, Color32, FontFamily, FontSelection, Galley, Style, TextStyle, TextWrapMode, Ui, Visuals,
-    text::{LayoutJob, TextWrapping},
+    text::{LayoutJob, TextTruncation, TextWrapping},
 };
 
 /// Text and optional style choices for it.
@@ -28,6 +28,7 @@ pub struct RichText {
     text: String,
     size: Option<f32>,
     extra_letter_spacing: f32,
+    extra_word_spacing: f32,
     line_height: Option<f32>,
     family: Option<FontFamily>,
     text_style: Option<TextStyle>,
@@ -39,8 +40,11 @@ pub struct RichText {
     weak: bool,
     strikethrough: bool,
     underline: bool,
+    stroke: crate::Stroke,
     italics: bool,
     raised: bool,
+    full_justify: bool,
+    truncation: TextTruncation,
 }
 
 impl Default for RichText {
@@ -49,6 +53,7 @@ impl Default for RichText {
             text: Default::default(),
             size: Default::default(),
             extra_letter_spacing: Default::default(),
+            extra_word_spacing: Default::default(),
             line_height: Default::default(),
             family: Default::default(),
             text_style: Default::default(),
@@ -60,8 +65,11 @@ impl Default for RichText {
             weak: Default::default(),
             strikethrough: Default::default(),
             underline: Default::default(),
+            stroke: Default::default(),
             italics: Default::default(),
             raised: Default::default(),
+            full_justify: Default::default(),
+            truncation: Default::default(),
         }
     }
 }
@@ -161,6 +169,15 @@ impl RichText {
         self
     }
 
+    /// Extra spacing after each space character, in points.
+    ///
+    /// Default: 0.0.
+    #[inline]
+    pub fn extra_word_spacing(mut self, extra_word_spacing: f32) -> Self {
+        self.extra_word_spacing = extra_word_spacing;
+        self
+    }
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
@@ -261,6 +278,15 @@ impl RichText {
         self
     }
 
+    /// Draw an outline around each character, e.g. to keep text readable on top of images.
+    ///
+    /// The outline is painted behind the characters.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<crate::Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
     /// Tilt the characters to the right.
     #[inline]
     pub fn italics(mut self) -> Self {
@@ -287,6 +313,37 @@ impl RichText {
         self
     }
 
+    /// Widen the spaces between words so that wrapped rows are flush with both edges.
+    ///
+    /// The last row of each paragraph is left as is.
+    /// See [`crate::text::LayoutJob::full_justify`].
+    ///
+    /// This is ignored by [`Self::append_to`].
+    #[inline]
+    pub fn full_justify(mut self, full_justify: bool) -> Self {
+        self.full_justify = full_justify;
+        self
+    }
+
+    /// Where to cut the text, and put the `…`, if it is truncated to a single row.
+    ///
+    /// Useful for file paths, where the end is more interesting than the middle:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let path = egui::RichText::new("~/projects/egui/crates/egui/src/lib.rs")
+    ///     .truncation(egui::text::TextTruncation::Middle);
+    /// ui.add(egui::Label::new(path).truncate());
+    /// # });
+    /// ```
+    ///
+    /// See [`crate::text::TextWrapping::truncation`].
+    /// This is ignored by [`Self::append_to`].
+    #[inline]
+    pub fn truncation(mut self, truncation: TextTruncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
     /// Fill-color behind the text.
     #[inline]
     pub fn background_color(mut self, background_color: impl Into<Color32>) -> Self {
@@ -369,8 +426,14 @@ impl RichText {
         fallback_font: FontSelection,
         default_valign: Align,
     ) -> LayoutJob {
+        let full_justify = self.full_justify;
+        let truncation = self.truncation;
         let (text, text_format) = self.into_text_and_format(style, fallback_font, default_valign);
-        LayoutJob::single_section(text, text_format)
+        let mut layout_job = LayoutJob::single_section(text, text_format);
+        layout_job.direction = style.text_direction;
+        layout_job.full_justify = full_justify;
+        layout_job.wrap.truncation = truncation;
+        layout_job
     }
 
     fn into_text_and_format(
@@ -385,6 +448,7 @@ impl RichText {
             text,
             size,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
             family,
             text_style,
@@ -396,8 +460,11 @@ impl RichText {
             weak: _,   // already used by `get_text_color`
             strikethrough,
             underline,
+            stroke,
             italics,
             raised,
+            full_justify: _, // used by `into_layout_job`
+            truncation: _,   // used by `into_layout_job`
         } = self;
 
         let line_color = text_color.unwrap_or_else(|| style.visuals.text_color());
@@ -448,12 +515,14 @@ impl RichText {
             crate::text::TextFormat {
                 font_id,
                 extra_letter_spacing,
+                extra_word_spacing,
                 line_height,
                 color: text_color,
                 background: background_color,
                 italics,
                 underline,
                 strikethrough,
+                stroke,
                 valign,
                 expand_bg,
             },
@@ -643,6 +712,12 @@ impl WidgetText {
         self.map_rich_text(|text| text.strikethrough())
     }
 
+    /// Prefer using [`RichText`] directly!
+    #[inline]
+    pub fn stroke(self, stroke: impl Into<crate::Stroke>) -> Self {
+        self.map_rich_text(|text| text.stroke(stroke))
+    }
+
     /// Prefer using [`RichText`] directly!
     #[inline]
     pub fn italics(self) -> Self {
@@ -696,15 +771,19 @@ impl WidgetText {
         default_valign: Align,
     ) -> Arc<LayoutJob> {
         match self {
-            Self::Text(text) => Arc::new(LayoutJob::simple_format(
-                text,
-                TextFormat {
-                    font_id: FontSelection::Default.resolve(style),
-                    color: crate::Color32::PLACEHOLDER,
-                    valign: default_valign,
-                    ..Default::default()
-                },
-            )),
+            Self::Text(text) => {
+                let mut layout_job = LayoutJob::simple_format(
+                    text,
+                    TextFormat {
+                        font_id: FontSelection::Default.resolve(style),
+                        color: crate::Color32::PLACEHOLDER,
+                        valign: default_valign,
+                        ..Default::default()
+                    },
+                );
+                layout_job.direction = style.text_direction;
+                Arc::new(layout_job)
+            }
             Self::RichText(text) => Arc::new(Arc::unwrap_or_clone(text).into_layout_job(
                 style,
                 fallback_font,
@@ -753,6 +832,7 @@ impl WidgetText {
                         ..Default::default()
                     },
                 );
+                layout_job.direction = style.text_direction;
                 layout_job.wrap = text_wrapping;
                 ctx.fonts(|f| f.layout_job(layout_job))
             }
@@ -762,7 +842,10 @@ impl WidgetText {
                     fallback_font,
                     default_valign,
                 );
-                layout_job.wrap = text_wrapping;
+                layout_job.wrap = TextWrapping {
+                    truncation: layout_job.wrap.truncation,
+                    ..text_wrapping
+                };
                 ctx.fonts(|f| f.layout_job(layout_job))
             }
             Self::LayoutJob(job) => {
@@ -1,7 +1,7 @@
 use crate::{
-    Atom, AtomExt as _, AtomKind, AtomLayout, AtomLayoutResponse, Color32, CornerRadius, Frame,
-    Image, IntoAtoms, NumExt as _, Response, Sense, Stroke, TextWrapMode, Ui, Vec2, Widget,
-    WidgetInfo, WidgetText, WidgetType,
+    Atom, AtomExt as _, AtomKind, AtomLayout, AtomLayoutResponse, Color32, CornerRadius, Event,
+    Frame, Image, IntoAtoms, Key, NumExt as _, Response, Sense, Stroke, TextWrapMode, Ui, Vec2,
+    Widget, WidgetInfo, WidgetText, WidgetType, response,
 };
 
 /// Clickable button with text.
@@ -35,6 +35,9 @@ pub struct Button<'a> {
     selected: bool,
     image_tint_follows_text_color: bool,
     limit_image_size: bool,
+    auto_repeat: bool,
+    repeat_delay: Option<f32>,
+    repeat_interval: Option<f32>,
 }
 
 impl<'a> Button<'a> {
@@ -51,6 +54,9 @@ impl<'a> Button<'a> {
             selected: false,
             image_tint_follows_text_color: false,
             limit_image_size: false,
+            auto_repeat: false,
+            repeat_delay: None,
+            repeat_interval: None,
         }
     }
 
@@ -247,6 +253,41 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Keep clicking while the button is held down, like the arrows of a spin box.
+    ///
+    /// [`Response::clicked`] is then `true` once when the button is pressed
+    /// (instead of when it is released),
+    /// and again every [`Self::repeat_interval`] after [`Self::repeat_delay`]
+    /// for as long as the button is held down with the pointer over it.
+    /// Holding down Space or Enter on a focused button repeats the same way.
+    ///
+    /// To keep track of long presses, the button then also senses drags.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn auto_repeat(mut self, auto_repeat: bool) -> Self {
+        self.auto_repeat = auto_repeat;
+        self
+    }
+
+    /// Seconds a button with [`Self::auto_repeat`] must be held before it starts repeating.
+    ///
+    /// Default: [`crate::style::Interaction::button_repeat_delay`].
+    #[inline]
+    pub fn repeat_delay(mut self, seconds: f32) -> Self {
+        self.repeat_delay = Some(seconds);
+        self
+    }
+
+    /// Seconds between the repeats of a held button with [`Self::auto_repeat`].
+    ///
+    /// Default: [`crate::style::Interaction::button_repeat_interval`].
+    #[inline]
+    pub fn repeat_interval(mut self, seconds: f32) -> Self {
+        self.repeat_interval = Some(seconds);
+        self
+    }
+
     /// Show the button and return a [`AtomLayoutResponse`] for painting custom contents.
     pub fn atom_ui(self, ui: &mut Ui) -> AtomLayoutResponse {
         let Button {
@@ -261,6 +302,9 @@ impl<'a> Button<'a> {
             selected,
             image_tint_follows_text_color,
             limit_image_size,
+            auto_repeat,
+            repeat_delay,
+            repeat_interval,
         } = self;
 
         if !small {
@@ -277,6 +321,11 @@ impl<'a> Button<'a> {
             });
         }
 
+        if auto_repeat {
+            // Otherwise the button is no longer held once the press is too long for a click:
+            layout.sense |= Sense::DRAG;
+        }
+
         let text = layout.text().map(String::from);
 
         let has_frame_margin = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -295,6 +344,13 @@ impl<'a> Button<'a> {
             .min_size(min_size)
             .allocate(ui);
 
+        if auto_repeat {
+            let interaction = &ui.style().interaction;
+            let delay = repeat_delay.unwrap_or(interaction.button_repeat_delay);
+            let interval = repeat_interval.unwrap_or(interaction.button_repeat_interval);
+            auto_repeat_clicks(ui, &mut prepared.response, delay, interval);
+        }
+
         let response = if ui.is_rect_visible(prepared.response.rect) {
             let visuals = ui.style().interact_selectable(&prepared.response, selected);
 
@@ -349,3 +405,58 @@ impl Widget for Button<'_> {
         self.atom_ui(ui).response
     }
 }
+
+/// Replace the clicks of `response` with the schedule of [`Button::auto_repeat`].
+fn auto_repeat_clicks(ui: &Ui, response: &mut Response, delay: f32, interval: f32) {
+    let is_activation_key = |key: &Key| matches!(key, Key::Space | Key::Enter);
+    let (pressed_by_pointer, pressed_by_key, key_down, now) = ui.input(|i| {
+        let pressed_by_key = i.events.iter().any(|event| {
+            matches!(event, Event::Key { key, pressed: true, repeat: false, .. } if is_activation_key(key))
+        });
+        let key_down = i.key_down(Key::Space) || i.key_down(Key::Enter);
+        (i.pointer.primary_pressed(), pressed_by_key, key_down, i.time)
+    });
+
+    // The regular clicks are replaced by ours:
+    if ui.input(|i| i.pointer.primary_clicked()) {
+        response.flags -= response::Flags::CLICKED;
+    }
+    if response.has_focus() && ui.input(|i| i.key_pressed(Key::Space) || i.key_pressed(Key::Enter))
+    {
+        response.flags -= response::Flags::FAKE_PRIMARY_CLICKED;
+    }
+
+    let held_by_pointer = response.is_pointer_button_down_on() && response.contains_pointer();
+    let held_by_key = response.has_focus() && key_down;
+
+    // When to click next, while held:
+    let id = response.id.with("auto_repeat");
+    let next_click: Option<f64> = ui.data(|d| d.get_temp(id));
+
+    if !held_by_pointer && !held_by_key {
+        if next_click.is_some() {
+            ui.data_mut(|d| d.remove::<f64>(id));
+        }
+        return;
+    }
+
+    let just_pressed = (held_by_pointer && pressed_by_pointer) || (held_by_key && pressed_by_key);
+    let next_click = match next_click {
+        _ if just_pressed => {
+            response.flags |= response::Flags::FAKE_PRIMARY_CLICKED;
+            now + delay as f64
+        }
+        Some(next_click) if next_click <= now => {
+            response.flags |= response::Flags::FAKE_PRIMARY_CLICKED;
+            now + interval as f64
+        }
+        Some(next_click) => next_click,
+        None => {
+            // Back on the button after having dragged off it:
+            now + interval as f64
+        }
+    };
+    ui.data_mut(|d| d.insert_temp(id, next_click));
+    ui.ctx()
+        .request_repaint_after_secs((next_click - now) as f32);
+}
@@ -16,19 +16,63 @@ use crate::{
 /// ui.add(egui::Checkbox::new(&mut my_bool, "Checked"));
 /// # });
 /// ```
+///
+/// Use [`Self::tri_state`] for a checkbox that can be neither checked nor unchecked,
+/// e.g. a parent of several other checkboxes.
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 pub struct Checkbox<'a> {
-    checked: &'a mut bool,
+    checked: CheckboxValue<'a>,
     atoms: Atoms<'a>,
     indeterminate: bool,
+    cycle_indeterminate: bool,
+}
+
+enum CheckboxValue<'a> {
+    Bool(&'a mut bool),
+
+    /// `None` is the indeterminate state.
+    TriState(&'a mut Option<bool>),
 }
 
 impl<'a> Checkbox<'a> {
     pub fn new(checked: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Self {
+        Self::from_value(CheckboxValue::Bool(checked), atoms)
+    }
+
+    /// A checkbox that is indeterminate when the value is `None`.
+    ///
+    /// Clicking an indeterminate checkbox checks it, and after that clicks toggle it as usual.
+    /// Each click marks the response as [`Response::changed`],
+    /// including the one going from `None` to `Some(true)`.
+    /// To let clicks go back to `None` too, use [`Self::cycle_indeterminate`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut children = [true, false, true];
+    /// let mut all = if children.iter().all(|&c| c) {
+    ///     Some(true)
+    /// } else if children.iter().all(|&c| !c) {
+    ///     Some(false)
+    /// } else {
+    ///     None
+    /// };
+    /// if ui.add(egui::Checkbox::tri_state(&mut all, "All")).changed() {
+    ///     if let Some(all) = all {
+    ///         children = [all; 3];
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn tri_state(checked: &'a mut Option<bool>, atoms: impl IntoAtoms<'a>) -> Self {
+        Self::from_value(CheckboxValue::TriState(checked), atoms)
+    }
+
+    fn from_value(checked: CheckboxValue<'a>, atoms: impl IntoAtoms<'a>) -> Self {
         Checkbox {
             checked,
             atoms: atoms.into_atoms(),
             indeterminate: false,
+            cycle_indeterminate: false,
         }
     }
 
@@ -45,14 +89,48 @@ impl<'a> Checkbox<'a> {
         self.indeterminate = indeterminate;
         self
     }
+
+    /// Let clicks on a [`Self::tri_state`] checkbox go from checked to unchecked to
+    /// indeterminate, and then back to checked.
+    ///
+    /// Default: `false`, i.e. only the code can make the checkbox indeterminate.
+    #[inline]
+    pub fn cycle_indeterminate(mut self, cycle_indeterminate: bool) -> Self {
+        self.cycle_indeterminate = cycle_indeterminate;
+        self
+    }
+}
+
+impl CheckboxValue<'_> {
+    /// `None` when indeterminate.
+    fn get(&self) -> Option<bool> {
+        match self {
+            Self::Bool(checked) => Some(**checked),
+            Self::TriState(checked) => **checked,
+        }
+    }
+
+    fn click(&mut self, cycle_indeterminate: bool) {
+        match self {
+            Self::Bool(checked) => **checked = !**checked,
+            Self::TriState(checked) => {
+                **checked = match **checked {
+                    Some(true) => Some(false),
+                    Some(false) if cycle_indeterminate => None,
+                    None | Some(false) => Some(true),
+                };
+            }
+        }
+    }
 }
 
 impl Widget for Checkbox<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
-            checked,
+            mut checked,
             mut atoms,
             indeterminate,
+            cycle_indeterminate,
         } = self;
 
         let spacing = &ui.spacing();
@@ -65,7 +143,11 @@ impl Widget for Checkbox<'_> {
         let mut icon_size = Vec2::splat(icon_width);
         icon_size.y = icon_size.y.at_least(min_size.y);
         let rect_id = Id::new("egui::checkbox");
-        atoms.push_left(Atom::custom(rect_id, icon_size));
+        if ui.is_right_to_left() {
+            atoms.push_right(Atom::custom(rect_id, icon_size));
+        } else {
+            atoms.push_left(Atom::custom(rect_id, icon_size));
+        }
 
         let text = atoms.text().map(String::from);
 
@@ -75,24 +157,22 @@ impl Widget for Checkbox<'_> {
             .allocate(ui);
 
         if prepared.response.clicked() {
-            *checked = !*checked;
+            checked.click(cycle_indeterminate);
             prepared.response.mark_changed();
         }
-        prepared.response.widget_info(|| {
-            if indeterminate {
-                WidgetInfo::labeled(
-                    WidgetType::Checkbox,
-                    ui.is_enabled(),
-                    text.as_deref().unwrap_or(""),
-                )
-            } else {
-                WidgetInfo::selected(
-                    WidgetType::Checkbox,
-                    ui.is_enabled(),
-                    *checked,
-                    text.as_deref().unwrap_or(""),
-                )
-            }
+        let checked = checked.get().filter(|_| !indeterminate);
+        prepared.response.widget_info(|| match checked {
+            None => WidgetInfo::labeled(
+                WidgetType::Checkbox,
+                ui.is_enabled(),
+                text.as_deref().unwrap_or(""),
+            ),
+            Some(checked) => WidgetInfo::selected(
+                WidgetType::Checkbox,
+                ui.is_enabled(),
+                checked,
+                text.as_deref().unwrap_or(""),
+            ),
         });
 
         if ui.is_rect_visible(prepared.response.rect) {
@@ -111,14 +191,14 @@ impl Widget for Checkbox<'_> {
                     epaint::StrokeKind::Inside,
                 ));
 
-                if indeterminate {
+                if checked.is_none() {
                     // Horizontal line:
                     ui.painter().add(Shape::hline(
                         small_icon_rect.x_range(),
                         small_icon_rect.center().y,
                         visuals.fg_stroke,
                     ));
-                } else if *checked {
+                } else if checked == Some(true) {
                     // Check mark:
                     ui.painter().add(Shape::line(
                         vec![
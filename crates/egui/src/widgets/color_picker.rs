@@ -1,13 +1,18 @@
 //! Color picker widgets.
 
+use std::sync::Arc;
+
 use crate::util::fixed_cache::FixedCache;
 use crate::{
-    Context, DragValue, Id, Painter, Popup, PopupCloseBehavior, Response, Sense, Ui, Widget as _,
-    WidgetInfo, WidgetType, epaint, lerp, remap_clamp,
+    Area, Context, CursorIcon, DragValue, Event, Id, Key, Modifiers, Order, Painter, Popup,
+    PopupCloseBehavior, Response, Sense, TextEdit, TextStyle, Ui, UserData, ViewportCommand,
+    Widget as _, WidgetInfo, WidgetType, epaint, lerp, remap_clamp,
 };
 use epaint::{
-    Mesh, Rect, Shape, Stroke, StrokeKind, Vec2,
-    ecolor::{Color32, Hsva, HsvaGamma, Rgba},
+    ColorImage, Mesh, Pos2, Rect, Shape, Stroke, StrokeKind, Vec2,
+    ecolor::{
+        Color32, Hsva, HsvaGamma, Oklcha, Rgba, linear_f32_from_gamma_u8, linear_f32_from_linear_u8,
+    },
     pos2, vec2,
 };
 
@@ -244,6 +249,8 @@ fn is_additive_alpha(a: f32) -> bool {
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Alpha {
     /// Set alpha to 1.0, and show no option for it.
+    ///
+    /// Any alpha typed or pasted into the hex field is ignored.
     Opaque,
 
     /// Only show normal blend options for alpha.
@@ -253,6 +260,26 @@ pub enum Alpha {
     BlendOrAdditive,
 }
 
+/// Which color space the color picker sliders edit in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum PickerMode {
+    /// Hue, saturation and value.
+    #[default]
+    Hsv,
+
+    /// Perceptual lightness, chroma and hue.
+    Oklch,
+}
+
+impl PickerMode {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hsv => "HSV",
+            Self::Oklch => "OKLCH",
+        }
+    }
+}
+
 fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     use crate::style::NumericColorSpace;
 
@@ -313,6 +340,21 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
     }
 
+    // The mode is shared by all color pickers:
+    let mode_id = Id::new("egui::color_picker_mode");
+    let mut mode: PickerMode = ui.data(|d| d.get_temp(mode_id)).unwrap_or_default();
+
+    ui.horizontal(|ui| {
+        hex_edit_ui(ui, hsvag, alpha_control);
+
+        // A combo box would close the popup the picker is usually shown in.
+        for value in [PickerMode::Hsv, PickerMode::Oklch] {
+            ui.selectable_value(&mut mode, value, value.label())
+                .on_hover_text("Which color space to edit in");
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(mode_id, mode));
+
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
     show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
 
@@ -336,28 +378,36 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
 
     let opaque = HsvaGamma { a: 1.0, ..*hsvag };
 
-    let HsvaGamma { h, s, v, a: _ } = hsvag;
+    match mode {
+        PickerMode::Hsv => {
+            let HsvaGamma { h, s, v, a: _ } = hsvag;
 
-    if false {
-        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into()).on_hover_text("Saturation");
-    }
+            if false {
+                color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into())
+                    .on_hover_text("Saturation");
+            }
 
-    if false {
-        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
-    }
+            if false {
+                color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
+            }
 
-    color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
+            color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
 
-    color_slider_1d(ui, h, |h| {
-        HsvaGamma {
-            h,
-            s: 1.0,
-            v: 1.0,
-            a: 1.0,
+            color_slider_1d(ui, h, |h| {
+                HsvaGamma {
+                    h,
+                    s: 1.0,
+                    v: 1.0,
+                    a: 1.0,
+                }
+                .into()
+            })
+            .on_hover_text("Hue");
         }
-        .into()
-    })
-    .on_hover_text("Hue");
+        PickerMode::Oklch => {
+            oklch_sliders_ui(ui, hsvag);
+        }
+    }
 
     let additive = is_additive_alpha(hsvag.a);
 
@@ -377,6 +427,172 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     }
 }
 
+/// Shows one slider each for the `OKLCH` lightness, chroma and hue of the color.
+fn oklch_sliders_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) {
+    let hsva = Hsva::from(*hsvag);
+
+    // To keep the hue and chroma of grays and clamped colors, we remember the last edit:
+    let cache_id = Id::new("egui::color_picker_oklch");
+    let srgba = hsva.to_srgba_unmultiplied();
+    let mut oklcha = ui
+        .data(|d| d.get_temp::<([u8; 4], Oklcha)>(cache_id))
+        .filter(|(cached_srgba, _)| *cached_srgba == srgba)
+        .map_or_else(
+            || {
+                let [r, g, b, _] = hsva.to_rgba_unmultiplied();
+                Oklcha::from_rgba_unmultiplied(r, g, b, 1.0)
+            },
+            |(_, oklcha)| oklcha,
+        );
+    let before = oklcha;
+
+    let opaque = Oklcha { a: 1.0, ..oklcha };
+    let max_chroma = Oklcha::MAX_SRGB_CHROMA;
+
+    color_slider_1d(ui, &mut oklcha.l, |l| Oklcha { l, ..opaque }.into())
+        .on_hover_text("Lightness");
+
+    let mut chroma = oklcha.c / max_chroma;
+    color_slider_1d(ui, &mut chroma, |chroma| {
+        Oklcha {
+            c: chroma * max_chroma,
+            ..opaque
+        }
+        .into()
+    })
+    .on_hover_text("Chroma");
+    if chroma != oklcha.c / max_chroma {
+        oklcha.c = chroma * max_chroma;
+    }
+
+    color_slider_1d(ui, &mut oklcha.h, |h| Oklcha { h, ..opaque }.into()).on_hover_text("Hue");
+
+    if !oklcha.is_in_srgb_gamut() {
+        ui.weak("Clamped to the sRGB gamut");
+    }
+
+    if oklcha != before {
+        let [r, g, b, _] = oklcha.to_rgba_unmultiplied();
+        let new_hsva = Hsva::from_rgba_unmultiplied(r, g, b, hsva.a);
+        *hsvag = HsvaGamma::from(new_hsva);
+        ui.data_mut(|d| d.insert_temp(cache_id, (new_hsva.to_srgba_unmultiplied(), oklcha)));
+    }
+}
+
+/// Shows a text field for editing the color as `#RRGGBBAA`,
+/// or as `#RRGGBB` when `Alpha::Opaque`.
+///
+/// The color is only updated when the text changes (by typing or pasting),
+/// so that the color does not drift when the user just looks at it.
+/// Text that isn't a valid color gets an error outline, and is not applied.
+///
+/// When `Alpha::Opaque`, the alpha of a 4 or 8 digit color is ignored,
+/// and the field warns about it on hover.
+///
+/// Pressing copy with nothing selected, or clicking the copy button,
+/// copies the current color as hex.
+fn hex_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+    let srgba = Hsva::from(*hsvag).to_srgba_unmultiplied();
+    let [r, g, b, a] = srgba;
+    let hex = if alpha == Alpha::Opaque {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    // Keep what the user typed while editing, even if it isn't a valid color (yet):
+    let id = ui.auto_id_with("hex");
+    let mut text = ui
+        .data(|d| d.get_temp::<String>(id))
+        .unwrap_or_else(|| hex.clone());
+
+    let output = TextEdit::singleline(&mut text)
+        .font(TextStyle::Monospace)
+        .desired_width(ui.spacing().interact_size.x * 2.0)
+        .show(ui);
+    let mut response = output.response;
+
+    let parsed = parse_hex_srgba(&text);
+    let ignored_alpha = alpha == Alpha::Opaque && parsed.is_some_and(|parsed| parsed[3] != 255);
+
+    if response.changed() {
+        if let Some(mut parsed) = parsed {
+            if alpha == Alpha::Opaque {
+                parsed[3] = a;
+            }
+            if parsed != srgba {
+                let additive_alpha = is_additive_alpha(hsvag.a).then_some(hsvag.a);
+                let [r, g, b, a] = parsed;
+                *hsvag = HsvaGamma::from(Hsva::from_rgba_unmultiplied(
+                    linear_f32_from_gamma_u8(r),
+                    linear_f32_from_gamma_u8(g),
+                    linear_f32_from_gamma_u8(b),
+                    linear_f32_from_linear_u8(a),
+                ));
+                if let Some(additive_alpha) = additive_alpha {
+                    // Don't edit the alpha:
+                    hsvag.a = additive_alpha;
+                }
+            }
+        }
+    }
+
+    if parsed.is_none() {
+        ui.painter().rect_stroke(
+            response.rect,
+            ui.visuals().widgets.inactive.corner_radius,
+            Stroke::new(1.0, ui.visuals().error_fg_color),
+            StrokeKind::Outside,
+        );
+        response = response.on_hover_text("Not a valid hex color");
+    } else if ignored_alpha {
+        response = response.on_hover_text("Alpha is ignored: this color is always opaque");
+    } else {
+        response = response.on_hover_text("Hex color");
+    }
+
+    if response.has_focus() {
+        let nothing_selected = output.cursor_range.is_none_or(|range| range.is_empty());
+        let copy = ui.input(|i| i.events.iter().any(|e| matches!(e, Event::Copy)));
+        if nothing_selected && copy {
+            ui.ctx().copy_text(hex.clone());
+        }
+        ui.data_mut(|d| d.insert_temp(id, text));
+    } else {
+        ui.data_mut(|d| d.remove::<String>(id));
+    }
+
+    if ui.small_button("🗐").on_hover_text("Copy hex").clicked() {
+        ui.ctx().copy_text(hex);
+    }
+}
+
+/// Parses `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, with or without the `#`.
+///
+/// The result is unmultiplied sRGBA.
+fn parse_hex_srgba(text: &str) -> Option<[u8; 4]> {
+    let text = text.trim();
+    let text = text.strip_prefix('#').unwrap_or(text);
+    let digits = text
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => return None,
+    };
+    Some([
+        channels[0],
+        channels[1],
+        channels[2],
+        channels.get(3).copied().unwrap_or(255),
+    ])
+}
+
 fn input_type_button_ui(ui: &mut Ui) {
     let mut input_type = ui.ctx().style().visuals.numeric_color_space;
     if input_type.toggle_button_ui(ui).changed() {
@@ -490,6 +706,15 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
 }
 
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
+    color_edit_button_hsva_with_palette(ui, hsva, alpha, &[])
+}
+
+fn color_edit_button_hsva_with_palette(
+    ui: &mut Ui,
+    hsva: &mut Hsva,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = Popup::is_id_open(ui.ctx(), popup_id);
     let mut button_response = color_button(ui, (*hsva).into(), open);
@@ -499,19 +724,299 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
 
     const COLOR_SLIDER_WIDTH: f32 = 275.0;
 
-    Popup::menu(&button_response)
+    let eyedropper_id = popup_id.with("eyedropper");
+    let eyedropping = if open {
+        eyedropper_overlay(ui.ctx(), eyedropper_id, hsva)
+    } else {
+        ui.data_mut(|d| d.remove::<EyedropperState>(eyedropper_id));
+        None
+    };
+    if eyedropping == Some(true) {
+        button_response.mark_changed();
+    }
+    let close_behavior = if eyedropping.is_some() {
+        // Clicking to pick a color should not close the popup:
+        PopupCloseBehavior::IgnoreClicks
+    } else {
+        PopupCloseBehavior::CloseOnClickOutside
+    };
+
+    let shown = Popup::menu(&button_response)
         .id(popup_id)
-        .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+        .close_behavior(close_behavior)
         .show(|ui| {
             ui.spacing_mut().slider_width = COLOR_SLIDER_WIDTH;
             if color_picker_hsva_2d(ui, hsva, alpha) {
                 button_response.mark_changed();
             }
+            if ui
+                .button("🔍")
+                .on_hover_text("Pick a color from the screen")
+                .clicked()
+            {
+                ui.data_mut(|d| d.insert_temp(eyedropper_id, EyedropperState::default()));
+            }
+            if swatches_ui(ui, hsva, palette) {
+                button_response.mark_changed();
+            }
         });
 
+    // Remember what color the popup was opened with, so we know if it changed when it closes:
+    let opened_with_id = popup_id.with("opened_with");
+    let opened_with = ui.data(|d| d.get_temp::<Color32>(opened_with_id));
+    match (shown.is_some(), opened_with) {
+        (true, None) => {
+            ui.data_mut(|d| d.insert_temp(opened_with_id, Color32::from(*hsva)));
+        }
+        (false, Some(opened_with)) => {
+            ui.data_mut(|d| d.remove::<Color32>(opened_with_id));
+            let color = Color32::from(*hsva);
+            if color != opened_with {
+                add_recent_color(ui.ctx(), color);
+            }
+        }
+        _ => {}
+    }
+
     button_response
 }
 
+/// While the eyedropper is active we keep the latest screenshot of the viewport.
+#[derive(Clone)]
+struct EyedropperState {
+    screenshot: Option<Arc<ColorImage>>,
+
+    /// When did we last ask for a screenshot?
+    last_request: f64,
+}
+
+impl Default for EyedropperState {
+    fn default() -> Self {
+        Self {
+            screenshot: None,
+            last_request: f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// While the eyedropper is active, this covers the whole viewport,
+/// shows a magnified view of the pixels under the pointer,
+/// and picks the color of the pixel that is clicked.
+/// Escape cancels.
+///
+/// The pixels come from screenshots requested with [`ViewportCommand::Screenshot`],
+/// so this only works on integrations that support those.
+///
+/// Returns `None` if the eyedropper is not active,
+/// else if a color was picked.
+fn eyedropper_overlay(ctx: &Context, id: Id, hsva: &mut Hsva) -> Option<bool> {
+    /// How often to refresh the screenshot, in seconds.
+    const REFRESH_INTERVAL: f64 = 0.25;
+
+    let mut state = ctx.data(|d| d.get_temp::<EyedropperState>(id))?;
+
+    if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+        ctx.data_mut(|d| d.remove::<EyedropperState>(id));
+        return Some(false);
+    }
+
+    let viewport_id = ctx.viewport_id();
+    let (time, screenshot) = ctx.input(|i| {
+        let screenshot = i.events.iter().rev().find_map(|event| match event {
+            Event::Screenshot {
+                viewport_id: screenshot_viewport_id,
+                image,
+                ..
+            } if *screenshot_viewport_id == viewport_id => Some(Arc::clone(image)),
+            _ => None,
+        });
+        (i.time, screenshot)
+    });
+    if screenshot.is_some() {
+        state.screenshot = screenshot;
+    }
+    if REFRESH_INTERVAL <= time - state.last_request {
+        ctx.send_viewport_cmd(ViewportCommand::Screenshot(UserData::default()));
+        state.last_request = time;
+    }
+    ctx.request_repaint_after_secs(REFRESH_INTERVAL as f32);
+
+    let pixels_per_point = ctx.pixels_per_point();
+    let screen_rect = ctx.screen_rect();
+    let response = Area::new(id)
+        .order(Order::Tooltip)
+        .fixed_pos(screen_rect.min)
+        .show(ctx, |ui| {
+            let response = ui.allocate_response(screen_rect.size(), Sense::click());
+            if let (Some(screenshot), Some(pos)) = (&state.screenshot, response.hover_pos()) {
+                magnifier_ui(ui, screenshot, pos, pixels_per_point);
+            }
+            response.on_hover_cursor(CursorIcon::Crosshair)
+        })
+        .inner;
+
+    if response.clicked() {
+        let picked = state
+            .screenshot
+            .as_ref()
+            .zip(response.interact_pointer_pos())
+            .and_then(|(screenshot, pos)| {
+                let [x, y] = pixel_at(pos, pixels_per_point);
+                screenshot_pixel(screenshot, x, y)
+            });
+        ctx.data_mut(|d| d.remove::<EyedropperState>(id));
+        if let Some(color) = picked {
+            *hsva = Hsva::from(color);
+            return Some(true);
+        }
+        return Some(false);
+    }
+
+    ctx.data_mut(|d| d.insert_temp(id, state));
+    Some(false)
+}
+
+/// The physical pixel under the given position, in points.
+fn pixel_at(pos: Pos2, pixels_per_point: f32) -> [i64; 2] {
+    [
+        (pos.x * pixels_per_point).floor() as i64,
+        (pos.y * pixels_per_point).floor() as i64,
+    ]
+}
+
+fn screenshot_pixel(screenshot: &ColorImage, x: i64, y: i64) -> Option<Color32> {
+    let x = usize::try_from(x).ok()?;
+    let y = usize::try_from(y).ok()?;
+    (x < screenshot.width() && y < screenshot.height()).then(|| screenshot[(x, y)])
+}
+
+/// Shows the pixels around `pos` blown up, next to the pointer.
+fn magnifier_ui(ui: &Ui, screenshot: &ColorImage, pos: Pos2, pixels_per_point: f32) {
+    /// How many pixels to show on each side of the center one.
+    const RADIUS: i64 = 4;
+    const CELL_SIZE: f32 = 8.0;
+    const OFFSET: f32 = 16.0;
+
+    let size = Vec2::splat((2 * RADIUS + 1) as f32 * CELL_SIZE);
+    let screen_rect = ui.ctx().screen_rect();
+    // Keep it on screen by flipping it to the other side of the pointer if needed:
+    let x = if pos.x + OFFSET + size.x <= screen_rect.right() {
+        pos.x + OFFSET
+    } else {
+        pos.x - OFFSET - size.x
+    };
+    let y = if pos.y + OFFSET + size.y <= screen_rect.bottom() {
+        pos.y + OFFSET
+    } else {
+        pos.y - OFFSET - size.y
+    };
+    let rect = Rect::from_min_size(pos2(x, y), size);
+
+    let painter = ui.painter();
+    let visuals = ui.visuals();
+    painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+    let [center_x, center_y] = pixel_at(pos, pixels_per_point);
+    for dy in -RADIUS..=RADIUS {
+        for dx in -RADIUS..=RADIUS {
+            if let Some(color) = screenshot_pixel(screenshot, center_x + dx, center_y + dy) {
+                let min = rect.min + CELL_SIZE * vec2((dx + RADIUS) as f32, (dy + RADIUS) as f32);
+                painter.rect_filled(Rect::from_min_size(min, Vec2::splat(CELL_SIZE)), 0.0, color);
+            }
+        }
+    }
+
+    if let Some(color) = screenshot_pixel(screenshot, center_x, center_y) {
+        let center = Rect::from_center_size(rect.center(), Vec2::splat(CELL_SIZE));
+        painter.rect_stroke(
+            center,
+            0.0,
+            (1.0, contrast_color(color)),
+            StrokeKind::Outside,
+        );
+    }
+    painter.rect_stroke(rect, 0.0, visuals.window_stroke, StrokeKind::Outside);
+}
+
+/// How many colors [`recent_colors`] remembers.
+const MAX_RECENT_COLORS: usize = 10;
+
+fn recent_colors_id() -> Id {
+    Id::new("egui::color_picker_recent_colors")
+}
+
+/// The colors most recently picked in a color picker popup, most recent first.
+///
+/// A color is recorded when a popup closes with a different color than it opened with.
+/// With the `persistence` feature, these are remembered across sessions.
+pub fn recent_colors(ctx: &Context) -> Vec<Color32> {
+    ctx.data_mut(|d| {
+        d.get_persisted::<Vec<Color32>>(recent_colors_id())
+            .unwrap_or_default()
+    })
+}
+
+fn add_recent_color(ctx: &Context, color: Color32) {
+    ctx.data_mut(|d| {
+        let recent = d.get_persisted_mut_or_default::<Vec<Color32>>(recent_colors_id());
+        recent.retain(|&recent| recent != color);
+        recent.insert(0, color);
+        recent.truncate(MAX_RECENT_COLORS);
+    });
+}
+
+/// Shows the palette, and the recently used colors.
+///
+/// Returns `true` if a swatch was clicked.
+fn swatches_ui(ui: &mut Ui, hsva: &mut Hsva, palette: &[Color32]) -> bool {
+    let recent = recent_colors(ui.ctx());
+    let mut clicked = None;
+
+    for (label, colors) in [("Palette", palette), ("Recent", recent.as_slice())] {
+        if colors.is_empty() {
+            continue;
+        }
+        ui.label(label);
+        ui.horizontal_wrapped(|ui| {
+            for &color in colors {
+                if swatch_ui(ui, color).clicked() {
+                    clicked = Some(color);
+                }
+            }
+        });
+    }
+
+    if let Some(color) = clicked {
+        *hsva = Hsva::from(color);
+        color_cache_set(ui.ctx(), color, *hsva);
+        true
+    } else {
+        false
+    }
+}
+
+/// A small clickable color, with its hex value on hover.
+fn swatch_ui(ui: &mut Ui, color: Color32) -> Response {
+    let size = Vec2::splat(ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    let hex = color.to_hex();
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), &hex));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let rect = rect.expand(visuals.expansion);
+        show_color_at(ui.painter(), color, rect.shrink(1.0));
+        ui.painter().rect_stroke(
+            rect,
+            visuals.corner_radius.at_most(2),
+            visuals.fg_stroke,
+            StrokeKind::Inside,
+        );
+    }
+
+    response.on_hover_text(hex)
+}
+
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
@@ -522,6 +1027,22 @@ pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -
     response
 }
 
+/// Like [`color_edit_button_srgba`], but the popup also offers the given palette.
+///
+/// Clicking a color in the palette selects it right away.
+pub fn color_edit_button_srgba_with_palette(
+    ui: &mut Ui,
+    srgba: &mut Color32,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> Response {
+    let mut hsva = color_cache_get(ui.ctx(), *srgba);
+    let response = color_edit_button_hsva_with_palette(ui, &mut hsva, alpha, palette);
+    *srgba = Color32::from(hsva);
+    color_cache_set(ui.ctx(), *srgba, hsva);
+    response
+}
+
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 /// The given color is in `sRGB` space.
@@ -4,7 +4,7 @@ use std::{cmp::Ordering, ops::RangeInclusive};
 
 use crate::{
     Button, CursorIcon, Id, Key, MINUS_CHAR_STR, Modifiers, NumExt as _, Response, RichText, Sense,
-    TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, text,
+    TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, style::NumberFormatter, text,
 };
 
 // ----------------------------------------------------------------------------
@@ -47,6 +47,7 @@ pub struct DragValue<'a> {
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
     update_while_editing: bool,
+    with_buttons: bool,
 }
 
 impl<'a> DragValue<'a> {
@@ -78,6 +79,7 @@ impl<'a> DragValue<'a> {
             custom_formatter: None,
             custom_parser: None,
             update_while_editing: true,
+            with_buttons: false,
         }
     }
 
@@ -415,6 +417,70 @@ impl<'a> DragValue<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Set `custom_formatter` and `custom_parser` to display and parse the value with units,
+    /// like "1.5 km" or "250 ms".
+    ///
+    /// `units` are pairs of a suffix and how many of the value's own unit it is,
+    /// e.g. `("km", 1000.0)` for a value in meters.
+    ///
+    /// If `display_unit` is the suffix of one of the `units`, the value is always shown in that unit.
+    /// If it is `None`, the largest unit that keeps the number at or above one is picked,
+    /// e.g. "1.5 km" rather than "1500 m".
+    ///
+    /// When editing, the user can type a number followed by any of the suffixes,
+    /// with or without whitespace in between, and the value is converted from that unit.
+    /// Suffixes are matched case-insensitively, unless that is ambiguous (like "m" and "M"),
+    /// and a decimal comma is accepted as well as a decimal point.
+    /// A number without a suffix is in the `display_unit`, or else the first of the `units`.
+    /// Unknown suffixes are rejected, keeping the old value.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut meters: f64 = 1500.0;
+    /// let units = [("m", 1.0), ("km", 1000.0), ("cm", 0.01)];
+    /// ui.add(egui::DragValue::new(&mut meters).units(&units, None)); // Shows "1.5 km"
+    /// # });
+    /// ```
+    pub fn units(self, units: &[(&str, f64)], display_unit: Option<&str>) -> Self {
+        let mut units: Vec<(String, f64)> = units
+            .iter()
+            .map(|&(suffix, multiplier)| (suffix.to_owned(), multiplier))
+            .collect();
+        let display_unit = display_unit
+            .and_then(|display_unit| units.iter().position(|(suffix, _)| suffix == display_unit));
+        if let Some(index) = display_unit {
+            // Numbers without a suffix are in the first unit:
+            let unit = units.remove(index);
+            units.insert(0, unit);
+        }
+        let fixed = display_unit.is_some();
+        let parser_units = units.clone();
+
+        self.custom_formatter(move |n, decimals| format_with_units(n, decimals, &units, fixed))
+            .custom_parser(move |s| parse_with_units(s, &parser_units))
+    }
+
+    /// Set `custom_formatter` and `custom_parser` from a [`NumberFormatter`],
+    /// e.g. to group the digits in thousands.
+    ///
+    /// The text the user enters is parsed with the same formatter,
+    /// so the displayed text can be edited as is.
+    ///
+    /// To do this for all widgets, set [`crate::Style::number_formatter`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut balance: f64 = 1234567.89;
+    /// use egui::style::NumberFormatter;
+    /// ui.add(egui::DragValue::new(&mut balance).fixed_decimals(2).number_formatter(NumberFormatter::grouped('.', ','))); // Shows "1.234.567,89"
+    /// # });
+    /// ```
+    pub fn number_formatter(self, number_formatter: NumberFormatter) -> Self {
+        let parser = number_formatter.clone();
+        self.custom_formatter(move |n, decimals| number_formatter.format(n, decimals))
+            .custom_parser(move |s| parser.parse(s))
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -424,10 +490,154 @@ impl<'a> DragValue<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Show small `−` and `+` buttons on either side of the value, for touch screens.
+    ///
+    /// Each press of a button changes the value by [`Self::speed`],
+    /// and holding a button down keeps changing it.
+    /// Hold down `Shift` for a tenth of the step, or `Ctrl`/`Cmd` for ten times the step.
+    /// A button is disabled when the value is at that end of the [`Self::range`].
+    ///
+    /// The returned [`Response`] covers the buttons too.
+    ///
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut quantity: u32 = 1;
+    /// ui.add(egui::DragValue::new(&mut quantity).range(1..=99).with_buttons(true));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn with_buttons(mut self, with_buttons: bool) -> Self {
+        self.with_buttons = with_buttons;
+        self
+    }
+
+    fn ui_with_buttons(mut self, ui: &mut Ui) -> Response {
+        ui.spacing_mut().item_spacing.x = ui.spacing().icon_spacing;
+
+        let range = self.range.clone();
+        let (min, max) = (
+            range.start().min(*range.end()),
+            range.start().max(*range.end()),
+        );
+
+        // Don't get stuck by stepping less than what can be stored, e.g. for integers:
+        let smallest_step = self
+            .max_decimals
+            .map_or(0.0, |max_decimals| 10.0_f64.powi(-(max_decimals as i32)));
+        let speed = match self.max_decimals {
+            Some(max_decimals) => emath::round_to_decimals(self.speed, max_decimals),
+            None => self.speed,
+        }
+        .at_least(smallest_step);
+
+        let modifiers = ui.input(|i| i.modifiers);
+        let step = if modifiers.shift_only() {
+            (speed / 10.0).at_least(smallest_step)
+        } else if modifiers.command {
+            speed * 10.0
+        } else {
+            speed
+        };
+        let decimals = auto_decimals(ui.input(|i| i.aim_radius()) as f64, step)
+            .min(self.max_decimals.unwrap_or(usize::MAX));
+        let step_value = |get_set_value: &mut GetSetValue<'_>, steps: f64| {
+            let value = get(get_set_value) + steps * step;
+            let value = emath::round_to_decimals(value, decimals);
+            set(get_set_value, clamp_value_to_range(value, range.clone()));
+        };
+
+        let mut get_set_value = std::mem::replace(&mut self.get_set_value, Box::new(|_| 0.0));
+
+        let decrement = step_button(ui, MINUS_CHAR_STR, min < get(&mut get_set_value));
+        let decrements = num_button_steps(ui, &decrement);
+        if 0 < decrements {
+            step_value(&mut get_set_value, -(decrements as f64));
+        }
+
+        let mut response = DragValue {
+            get_set_value: Box::new(&mut get_set_value),
+            with_buttons: false,
+            ..self
+        }
+        .ui(ui);
+
+        let increment = step_button(ui, "+", get(&mut get_set_value) < max);
+        let increments = num_button_steps(ui, &increment);
+        if 0 < increments {
+            step_value(&mut get_set_value, increments as f64);
+            // The value is shown before we knew about the click:
+            ui.ctx().request_repaint();
+        }
+
+        if 0 < decrements || 0 < increments {
+            response.mark_changed();
+        }
+        response | decrement | increment
+    }
+}
+
+/// How long a step button must be held before it starts repeating, in seconds.
+const STEP_REPEAT_DELAY: f64 = 0.5;
+
+/// How long between each repeated step while a step button is held, in seconds.
+const STEP_REPEAT_INTERVAL: f64 = 0.1;
+
+fn step_button(ui: &mut Ui, text: &str, enabled: bool) -> Response {
+    let size = ui.spacing().interact_size.y;
+    ui.add_enabled(
+        enabled,
+        Button::new(text)
+            .min_size(crate::vec2(size, size))
+            // The value is the tab stop, and can be changed with the arrow keys:
+            .sense(Sense::click() - Sense::FOCUSABLE),
+    )
+}
+
+/// How many steps a step button wants to take this frame.
+///
+/// It steps once when pressed, and then repeatedly while it is held down.
+fn num_button_steps(ui: &Ui, response: &Response) -> u32 {
+    let id = response.id;
+    // How many steps we have taken since the button was pressed:
+    let steps_taken = ui.data(|data| data.get_temp::<u32>(id));
+
+    if response.is_pointer_button_down_on() {
+        let held_for = ui.input(|i| {
+            i.pointer
+                .press_start_time()
+                .map_or(0.0, |press_start_time| i.time - press_start_time)
+        });
+        let num_repeats = if held_for < STEP_REPEAT_DELAY {
+            0
+        } else {
+            ((held_for - STEP_REPEAT_DELAY) / STEP_REPEAT_INTERVAL) as u32 + 1
+        };
+        let until_next_repeat =
+            STEP_REPEAT_DELAY + num_repeats as f64 * STEP_REPEAT_INTERVAL - held_for;
+        ui.ctx()
+            .request_repaint_after_secs(until_next_repeat.at_least(0.0) as f32);
+
+        let total_steps = 1 + num_repeats;
+        ui.data_mut(|data| data.insert_temp(id, total_steps));
+        total_steps - steps_taken.unwrap_or(0)
+    } else {
+        if steps_taken.is_some() {
+            ui.data_mut(|data| data.remove::<u32>(id));
+        }
+        // The press and release came in the same frame, or it was clicked with assistive technology:
+        u32::from(response.clicked() && steps_taken.is_none())
+    }
 }
 
 impl Widget for DragValue<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
+        if self.with_buttons {
+            return ui.horizontal(|ui| self.ui_with_buttons(ui)).inner;
+        }
+
         let Self {
             mut get_set_value,
             speed,
@@ -440,6 +650,7 @@ impl Widget for DragValue<'_> {
             custom_formatter,
             custom_parser,
             update_while_editing,
+            with_buttons: _,
         } = self;
 
         let shift = ui.input(|i| i.modifiers.shift_only());
@@ -465,8 +676,7 @@ impl Widget for DragValue<'_> {
         let mut value = old_value;
         let aim_rad = ui.input(|i| i.aim_radius() as f64);
 
-        let auto_decimals = (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize;
-        let auto_decimals = auto_decimals + is_slow_speed as usize;
+        let auto_decimals = auto_decimals(aim_rad, speed) + is_slow_speed as usize;
         let max_decimals = max_decimals
             .unwrap_or(auto_decimals + 2)
             .at_least(min_decimals);
@@ -540,7 +750,7 @@ impl Widget for DragValue<'_> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(ui, &custom_parser, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -582,7 +792,7 @@ impl Widget for DragValue<'_> {
                 response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
             };
             if update {
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(ui, &custom_parser, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -720,17 +930,22 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
+/// How many decimals are needed to show changes of `speed`.
+fn auto_decimals(aim_rad: f64, speed: f64) -> usize {
+    (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize
+}
+
+fn parse(ui: &Ui, custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
     match &custom_parser {
         Some(parser) => parser(value_text),
-        None => default_parser(value_text),
+        None => ui.style().number_formatter.parse(value_text),
     }
 }
 
 /// The default egui parser of numbers.
 ///
 /// It ignored whitespaces anywhere in the input, and treats the special minus character (U+2212) as a normal minus.
-fn default_parser(text: &str) -> Option<f64> {
+pub(crate) fn default_parser(text: &str) -> Option<f64> {
     let text: String = text
         .chars()
         // Ignore whitespace (trailing, leading, and thousands separators):
@@ -742,6 +957,82 @@ fn default_parser(text: &str) -> Option<f64> {
     text.parse().ok()
 }
 
+/// Format a value in the given units, see [`DragValue::units`].
+///
+/// If `fixed`, the first unit is always used.
+fn format_with_units(
+    value: f64,
+    decimals: RangeInclusive<usize>,
+    units: &[(String, f64)],
+    fixed: bool,
+) -> String {
+    let unit = if fixed || value == 0.0 || !value.is_finite() {
+        units.first()
+    } else {
+        // The largest unit that keeps the number at or above one, or else the smallest unit:
+        units
+            .iter()
+            .filter(|(_, multiplier)| *multiplier <= value.abs())
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .or_else(|| units.iter().min_by(|(_, a), (_, b)| a.total_cmp(b)))
+    };
+    let Some((suffix, multiplier)) = unit else {
+        return emath::format_with_decimals_in_range(value, decimals);
+    };
+
+    // Keep the same precision as in the value's own unit,
+    // but don't add trailing zeros when going to a larger unit:
+    let shift = multiplier.log10().round() as isize;
+    let max_decimals = decimals.end().saturating_add_signed(shift);
+    let min_decimals = decimals
+        .start()
+        .saturating_add_signed(shift.min(0))
+        .min(max_decimals);
+    let number =
+        emath::format_with_decimals_in_range(value / multiplier, min_decimals..=max_decimals);
+    format!("{number} {suffix}")
+}
+
+/// Parse a number followed by one of the unit suffixes, see [`DragValue::units`].
+///
+/// A number without a suffix is in the first unit.
+fn parse_with_units(text: &str, units: &[(String, f64)]) -> Option<f64> {
+    let text = text.trim();
+
+    // Prefer the longest suffix that matches exactly, then the longest that matches ignoring case:
+    let longest_match = |matches: &dyn Fn(&str) -> bool| {
+        units
+            .iter()
+            .filter(|(suffix, _)| !suffix.is_empty() && matches(suffix))
+            .max_by_key(|(suffix, _)| suffix.len())
+    };
+    let text_lowercase = text.to_lowercase();
+    let unit = longest_match(&|suffix| text.ends_with(suffix))
+        .map(|(suffix, multiplier)| (suffix.len(), *multiplier))
+        .or_else(|| {
+            longest_match(&|suffix| text_lowercase.ends_with(&suffix.to_lowercase()))
+                .filter(|(suffix, _)| text.is_char_boundary(text.len() - suffix.len()))
+                .map(|(suffix, multiplier)| (suffix.len(), *multiplier))
+        });
+
+    let (number, multiplier) = match unit {
+        Some((suffix_len, multiplier)) => (&text[..text.len() - suffix_len], multiplier),
+        None => (
+            text,
+            units.first().map_or(1.0, |(_, multiplier)| *multiplier),
+        ),
+    };
+
+    // Accept a decimal comma, unless it could be a thousands separator:
+    let number = if !number.contains('.') && number.matches(',').count() == 1 {
+        number.replace(',', ".")
+    } else {
+        number.to_owned()
+    };
+
+    default_parser(&number).map(|number| number * multiplier)
+}
+
 /// Clamp the given value with careful handling of negative zero, and other corner cases.
 pub(crate) fn clamp_value_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     let (mut min, mut max) = (*range.start(), *range.end());
@@ -798,6 +1089,46 @@ mod tests {
         total_assert_eq!(1.0_f64, clamp_value_to_range(-5.0, 5.0..=1.0));
     }
 
+    #[test]
+    fn test_parse_with_units() {
+        let units = |units: &[(&str, f64)]| -> Vec<(String, f64)> {
+            units
+                .iter()
+                .map(|&(suffix, multiplier)| (suffix.to_owned(), multiplier))
+                .collect()
+        };
+        let meters = units(&[("m", 1.0), ("km", 1000.0), ("cm", 0.01)]);
+        let parse = |text| super::parse_with_units(text, &meters);
+
+        assert_eq!(parse("2km"), Some(2000.0));
+        assert_eq!(parse("1500 m"), Some(1500.0));
+        assert_eq!(parse(" 2.5 KM "), Some(2500.0), "Suffixes ignore case");
+        assert_eq!(parse("1,5 km"), Some(1500.0), "Decimal comma");
+        assert_eq!(parse("-3 cm"), Some(-0.03));
+        assert_eq!(parse("7"), Some(7.0), "No suffix means the first unit");
+        assert_eq!(parse("7 ft"), None, "Unknown suffix");
+        assert_eq!(parse("km"), None);
+
+        let ambiguous = units(&[("m", 1e-3), ("M", 1e6)]);
+        assert_eq!(super::parse_with_units("2M", &ambiguous), Some(2e6));
+        assert_eq!(super::parse_with_units("2m", &ambiguous), Some(2e-3));
+    }
+
+    #[test]
+    fn test_format_with_units() {
+        let seconds: Vec<(String, f64)> = [("s", 1.0), ("ms", 0.001), ("min", 60.0)]
+            .iter()
+            .map(|&(suffix, multiplier)| (suffix.to_owned(), multiplier))
+            .collect();
+        let format = |value, fixed| super::format_with_units(value, 0..=3, &seconds, fixed);
+
+        assert_eq!(format(0.25, false), "250 ms");
+        assert_eq!(format(1.5, false), "1.5 s");
+        assert_eq!(format(90.0, false), "1.5 min");
+        assert_eq!(format(0.0, false), "0 s");
+        assert_eq!(format(90.0, true), "90 s");
+    }
+
     #[test]
     fn test_default_parser() {
         assert_eq!(super::default_parser("123"), Some(123.0));
@@ -93,6 +93,7 @@ pub struct Hyperlink {
     url: String,
     text: WidgetText,
     new_tab: bool,
+    context_menu: bool,
 }
 
 impl Hyperlink {
@@ -103,6 +104,7 @@ impl Hyperlink {
             url: url.clone(),
             text: url.into(),
             new_tab: false,
+            context_menu: true,
         }
     }
 
@@ -112,6 +114,7 @@ impl Hyperlink {
             url: url.to_string(),
             text: text.into(),
             new_tab: false,
+            context_menu: true,
         }
     }
 
@@ -121,11 +124,27 @@ impl Hyperlink {
         self.new_tab = new_tab;
         self
     }
+
+    /// Show a context menu with "Copy link" and "Open link" when right-clicked.
+    ///
+    /// Turn this off if you want to attach your own context menu to the returned [`Response`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = context_menu;
+        self
+    }
 }
 
 impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { url, text, new_tab } = self;
+        let Self {
+            url,
+            text,
+            new_tab,
+            context_menu,
+        } = self;
 
         let response = ui.add(Link::new(text));
 
@@ -141,6 +160,20 @@ impl Widget for Hyperlink {
             });
         }
 
+        if context_menu {
+            response.context_menu(|ui| {
+                if ui.button("Copy link").clicked() {
+                    ui.ctx().copy_text(url.clone());
+                }
+                if ui.button("Open link").clicked() {
+                    ui.ctx().open_url(crate::OpenUrl {
+                        url: url.clone(),
+                        new_tab,
+                    });
+                }
+            });
+        }
+
         if ui.style().url_in_tooltip {
             response.on_hover_text(url)
         } else {
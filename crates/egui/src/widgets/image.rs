@@ -2,15 +2,15 @@ use std::{borrow::Cow, slice::Iter, sync::Arc, time::Duration};
 
 use emath::{Align, Float as _, GuiRounding as _, NumExt as _, Rot2};
 use epaint::{
-    RectShape,
+    MarginF32, RectShape,
     text::{LayoutJob, TextFormat, TextWrapping},
 };
 
 use crate::{
-    Color32, Context, CornerRadius, Id, Mesh, Painter, Rect, Response, Sense, Shape, Spinner,
-    TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    Color32, Context, CornerRadius, Id, Margin, Mesh, Painter, Pos2, Rect, Response, Sense, Shape,
+    Spinner, TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
     load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
-    pos2,
+    pos2, vec2,
 };
 
 /// A widget which displays an image.
@@ -54,6 +54,10 @@ pub struct Image<'a> {
     image_options: ImageOptions,
     sense: Sense,
     size: ImageSize,
+
+    /// Region of the image to show, in texels.
+    crop: Option<Rect>,
+
     pub(crate) show_loading_spinner: Option<bool>,
     pub(crate) alt_text: Option<String>,
 }
@@ -80,6 +84,7 @@ impl<'a> Image<'a> {
                 image_options: Default::default(),
                 sense: Sense::hover(),
                 size,
+                crop: None,
                 show_loading_spinner: None,
                 alt_text: None,
             }
@@ -211,6 +216,44 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Only show this region of the image, e.g. one sprite of a sprite sheet.
+    ///
+    /// The region is in texels of the source image (or points, for SVGs),
+    /// with (0,0) being the top-left corner.
+    /// Once the size of the image is known, this is converted to a [`Self::uv`] range,
+    /// replacing any previously set one.
+    ///
+    /// The size of the region is used instead of the size of the whole image
+    /// when [sizing](Self::calc_size) the widget,
+    /// so [`Self::fit_to_exact_size`], [`Self::maintain_aspect_ratio`] etc.
+    /// all work on the region.
+    /// For [`ImageSource::Texture`], the default size becomes the size of the region,
+    /// unless you have already picked a different one.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// // Show the second 16x16 sprite of the top row, four times as large:
+    /// ui.add(
+    ///     egui::Image::new(egui::include_image!("../../assets/ferris.png"))
+    ///         .crop(egui::Rect::from_min_size(egui::pos2(16.0, 0.0), egui::Vec2::splat(16.0)))
+    ///         .fit_to_exact_size(egui::Vec2::splat(64.0))
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn crop(mut self, region: Rect) -> Self {
+        if let (ImageSource::Texture(texture), ImageFit::Exact(size)) =
+            (&self.source, self.size.fit)
+        {
+            if size == texture.size {
+                // Keep the default of showing the texture at its own size:
+                self.size.fit = ImageFit::Exact(region.size());
+            }
+        }
+        self.crop = Some(region);
+        self
+    }
+
     /// A solid color to put behind the image. Useful for transparent images.
     #[inline]
     pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
@@ -225,6 +268,33 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Scale the image as a nine-patch, e.g. for a skinned frame or button background.
+    ///
+    /// The margin is the size of the corners, in texels of the texture.
+    /// The corners are painted unscaled (one texel per point),
+    /// the edges are stretched along their length, and the center is stretched both ways.
+    /// If the image is painted smaller than its corners, the corners shrink to fit.
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Image::new(egui::include_image!("../../assets/ferris.png"))
+    ///         .nine_patch(egui::Margin::same(8))
+    ///         .maintain_aspect_ratio(false)
+    ///         .fit_to_exact_size(egui::vec2(200.0, 40.0))
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn nine_patch(mut self, margin: impl Into<Margin>) -> Self {
+        self.image_options.nine_patch = Some(margin.into());
+        self.image_options.corner_radius = CornerRadius::ZERO; // incompatible with nine-patch
+        self
+    }
+
     /// Rotate the image about an origin by some angle
     ///
     /// Positive angle is clockwise.
@@ -252,6 +322,7 @@ impl<'a> Image<'a> {
         self.image_options.corner_radius = corner_radius.into();
         if self.image_options.corner_radius != CornerRadius::ZERO {
             self.image_options.rotation = None; // incompatible with rounding
+            self.image_options.nine_patch = None; // incompatible with rounding
         }
         self
     }
@@ -297,13 +368,20 @@ impl<'a> Image<'a> {
     /// Returns the size the image will occupy in the final UI.
     #[inline]
     pub fn calc_size(&self, available_size: Vec2, image_source_size: Option<Vec2>) -> Vec2 {
-        let image_source_size = image_source_size.unwrap_or(Vec2::splat(24.0)); // Fallback for still-loading textures, or failure to load.
+        let image_source_size = match (self.crop, image_source_size) {
+            (Some(crop), Some(image_source_size)) => crop
+                .intersect(Rect::from_min_size(Pos2::ZERO, image_source_size))
+                .size()
+                .max(Vec2::ZERO),
+            (Some(crop), None) => crop.size(),
+            (None, image_source_size) => image_source_size.unwrap_or(Vec2::splat(24.0)), // Fallback for still-loading textures, or failure to load.
+        };
         self.size.calc_size(available_size, image_source_size)
     }
 
     pub fn load_and_calc_size(&self, ui: &Ui, available_size: Vec2) -> Option<Vec2> {
         let image_size = self.load_for_size(ui.ctx(), available_size).ok()?.size()?;
-        Some(self.size.calc_size(available_size, image_size))
+        Some(self.calc_size(available_size, Some(image_size)))
     }
 
     #[inline]
@@ -333,6 +411,23 @@ impl<'a> Image<'a> {
         &self.image_options
     }
 
+    /// The [`ImageOptions`] to paint with, once we know the size of the image.
+    ///
+    /// This converts any [`Self::crop`] region to a uv range.
+    pub(crate) fn image_options_for_size(&self, image_source_size: Option<Vec2>) -> ImageOptions {
+        let mut image_options = self.image_options.clone();
+        if let (Some(crop), Some(size)) = (self.crop, image_source_size) {
+            if size.x > 0.0 && size.y > 0.0 {
+                image_options.uv = Rect::from_min_max(
+                    (crop.min.to_vec2() / size).to_pos2(),
+                    (crop.max.to_vec2() / size).to_pos2(),
+                )
+                .intersect(Rect::from_min_max(Pos2::ZERO, pos2(1.0, 1.0)));
+            }
+        }
+        image_options
+    }
+
     #[inline]
     pub fn source(&'a self, ctx: &Context) -> ImageSource<'a> {
         match &self.source {
@@ -396,12 +491,14 @@ impl<'a> Image<'a> {
             },
         );
 
+        let image_options =
+            self.image_options_for_size(texture.as_ref().ok().and_then(|t| t.size()));
         paint_texture_load_result(
             ui,
             &texture,
             rect,
             self.show_loading_spinner,
-            &self.image_options,
+            &image_options,
             self.alt_text.as_deref(),
         );
     }
@@ -425,7 +522,7 @@ impl Widget for Image<'_> {
                 &tlr,
                 rect,
                 self.show_loading_spinner,
-                &self.image_options,
+                &self.image_options_for_size(image_source_size),
                 self.alt_text.as_deref(),
             );
         }
@@ -834,6 +931,11 @@ pub struct ImageOptions {
     /// Due to limitations in the current implementation,
     /// this will turn off any rotation of the image.
     pub corner_radius: CornerRadius,
+
+    /// Paint the image as a nine-patch with these corners, in texels of the texture.
+    ///
+    /// The corners are painted unscaled, the edges and center are stretched.
+    pub nine_patch: Option<Margin>,
 }
 
 impl Default for ImageOptions {
@@ -844,6 +946,7 @@ impl Default for ImageOptions {
             tint: Color32::WHITE,
             rotation: None,
             corner_radius: CornerRadius::ZERO,
+            nine_patch: None,
         }
     }
 }
@@ -862,6 +965,20 @@ pub fn paint_texture_at(
         ));
     }
 
+    if let Some(margin) = options.nine_patch {
+        debug_assert!(
+            options.corner_radius == CornerRadius::ZERO,
+            "Image had both rounding and nine-patch. Please pick only one"
+        );
+
+        let mut mesh = nine_patch_mesh(rect, options, margin, texture);
+        if let Some((rot, origin)) = options.rotation {
+            mesh.rotate(rot, rect.min + origin * rect.size());
+        }
+        painter.add(Shape::mesh(mesh));
+        return;
+    }
+
     match options.rotation {
         Some((rot, origin)) => {
             // TODO(emilk): implement this using `PathShape` (add texture support to it).
@@ -885,17 +1002,141 @@ pub fn paint_texture_at(
     }
 }
 
+/// Nine quads: unscaled corners, edges stretched along their length, and a stretched center.
+fn nine_patch_mesh(
+    rect: Rect,
+    options: &ImageOptions,
+    margin: Margin,
+    texture: &SizedTexture,
+) -> Mesh {
+    let margin = MarginF32::from(margin);
+
+    // Shrink the corners if they don't fit:
+    let scale = |size: f32, a: f32, b: f32| {
+        if a + b > size { size / (a + b) } else { 1.0 }
+    };
+    let scale = vec2(
+        scale(rect.width(), margin.left, margin.right),
+        scale(rect.height(), margin.top, margin.bottom),
+    );
+
+    let uv = options.uv;
+    let texels = texture.size.max(Vec2::splat(1.0));
+    let xs = [
+        rect.left(),
+        rect.left() + margin.left * scale.x,
+        rect.right() - margin.right * scale.x,
+        rect.right(),
+    ];
+    let ys = [
+        rect.top(),
+        rect.top() + margin.top * scale.y,
+        rect.bottom() - margin.bottom * scale.y,
+        rect.bottom(),
+    ];
+    let us = [
+        uv.left(),
+        uv.left() + margin.left / texels.x,
+        uv.right() - margin.right / texels.x,
+        uv.right(),
+    ];
+    let vs = [
+        uv.top(),
+        uv.top() + margin.top / texels.y,
+        uv.bottom() - margin.bottom / texels.y,
+        uv.bottom(),
+    ];
+
+    let mut mesh = Mesh::with_texture(texture.id);
+    for row in 0..3 {
+        for col in 0..3 {
+            mesh.add_rect_with_uv(
+                Rect::from_min_max(pos2(xs[col], ys[row]), pos2(xs[col + 1], ys[row + 1])),
+                Rect::from_min_max(pos2(us[col], vs[row]), pos2(us[col + 1], vs[row + 1])),
+                options.tint,
+            );
+        }
+    }
+    mesh
+}
+
+/// How many times an animated image is played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LoopCount {
+    /// Loop forever.
+    #[default]
+    Infinite,
+
+    /// Play the animation this many times, then stop on the last frame.
+    Finite(u32),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-/// Stores the durations between each frame of an animated image
-pub struct FrameDurations(Arc<Vec<Duration>>);
+/// Stores the durations between each frame of an animated image,
+/// and how many times to play it.
+pub struct FrameDurations {
+    durations: Arc<Vec<Duration>>,
+    loop_count: LoopCount,
+}
 
 impl FrameDurations {
+    /// The animation loops forever, unless you also set [`Self::with_loop_count`].
     pub fn new(durations: Vec<Duration>) -> Self {
-        Self(Arc::new(durations))
+        Self {
+            durations: Arc::new(durations),
+            loop_count: LoopCount::Infinite,
+        }
+    }
+
+    #[inline]
+    pub fn with_loop_count(mut self, loop_count: LoopCount) -> Self {
+        self.loop_count = loop_count;
+        self
     }
 
     pub fn all(&self) -> Iter<'_, Duration> {
-        self.0.iter()
+        self.durations.iter()
+    }
+
+    #[inline]
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// How long it takes to play the animation once.
+    pub fn total(&self) -> Duration {
+        self.all().sum()
+    }
+
+    /// Which frame to show `elapsed` time after the animation started,
+    /// and how long until the next frame.
+    ///
+    /// The time until the next frame is `None` once a finite animation has finished,
+    /// and it stays on its last frame.
+    pub fn frame_at(&self, elapsed: Duration) -> (usize, Option<Duration>) {
+        let total = self.total();
+        let Some(last_frame) = self.durations.len().checked_sub(1) else {
+            return (0, None);
+        };
+        if total.is_zero() {
+            return (last_frame, None);
+        }
+
+        let loops_done = elapsed.as_nanos() / total.as_nanos();
+        if let LoopCount::Finite(loop_count) = self.loop_count {
+            if u128::from(loop_count.max(1)) <= loops_done {
+                return (last_frame, None);
+            }
+        }
+
+        let mut time_in_loop = Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64);
+        for (index, duration) in self.all().enumerate() {
+            if time_in_loop < *duration {
+                return (index, Some(*duration - time_in_loop));
+            }
+            time_in_loop -= *duration;
+        }
+        (last_frame, None)
     }
 }
 
@@ -918,28 +1159,24 @@ pub fn decode_animated_image_uri(uri: &str) -> Result<(&str, usize), String> {
 }
 
 /// Calculates at which frame the animated image is
+///
+/// The animation starts playing the first time it is shown after it was loaded.
 fn animated_image_frame_index(ctx: &Context, uri: &str) -> usize {
-    let now = ctx.input(|input| Duration::from_secs_f64(input.time));
+    let now = ctx.input(|input| input.time);
 
     let durations: Option<FrameDurations> = ctx.data(|data| data.get_temp(Id::new(uri)));
 
     if let Some(durations) = durations {
-        let frames: Duration = durations.all().sum();
-        let pos_ms = now.as_millis() % frames.as_millis().max(1);
-
-        let mut cumulative_ms = 0;
-
-        for (index, duration) in durations.all().enumerate() {
-            cumulative_ms += duration.as_millis();
+        let start_time = ctx.data_mut(|data| {
+            *data.get_temp_mut_or_insert_with(Id::new(uri).with("animation_start_time"), || now)
+        });
+        let elapsed = Duration::from_secs_f64((now - start_time).max(0.0));
 
-            if pos_ms < cumulative_ms {
-                let ms_until_next_frame = cumulative_ms - pos_ms;
-                ctx.request_repaint_after(Duration::from_millis(ms_until_next_frame as u64));
-                return index;
-            }
+        let (index, until_next_frame) = durations.frame_at(elapsed);
+        if let Some(until_next_frame) = until_next_frame {
+            ctx.request_repaint_after(until_next_frame);
         }
-
-        0
+        index
     } else {
         0
     }
@@ -136,7 +136,7 @@ impl Widget for ImageButton<'_> {
                 .layout()
                 .align_size_within_rect(image_size, rect.shrink2(padding));
             // let image_rect = image_rect.expand2(expansion); // can make it blurry, so let's not
-            let image_options = self.image.image_options().clone();
+            let image_options = self.image.image_options_for_size(image_source_size);
 
             widgets::image::paint_texture_load_result(
                 ui,
@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use crate::{
     Align, Direction, FontSelection, Galley, Pos2, Response, Sense, Stroke, TextWrapMode, Ui,
-    Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2, text_selection::LabelSelectionState,
+    Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2, text::TextTruncation,
+    text_selection::LabelSelectionState,
 };
 
 /// Static text.
@@ -25,10 +26,14 @@ use crate::{
 pub struct Label {
     text: WidgetText,
     wrap_mode: Option<TextWrapMode>,
+    max_lines: Option<usize>,
     sense: Option<Sense>,
     selectable: Option<bool>,
     halign: Option<Align>,
     show_tooltip_when_elided: bool,
+    copy_context_menu: bool,
+    full_justify: bool,
+    truncation: Option<TextTruncation>,
 }
 
 impl Label {
@@ -36,10 +41,14 @@ impl Label {
         Self {
             text: text.into(),
             wrap_mode: None,
+            max_lines: None,
             sense: None,
             selectable: None,
             halign: None,
             show_tooltip_when_elided: true,
+            copy_context_menu: false,
+            full_justify: false,
+            truncation: None,
         }
     }
 
@@ -73,6 +82,25 @@ impl Label {
         self
     }
 
+    /// Truncate the text to a single row, cutting it at the given place.
+    ///
+    /// [`TextTruncation::Middle`] keeps both the start and the end of the text,
+    /// which is useful for file paths:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::text::TextTruncation;
+    /// ui.add(egui::Label::new("~/projects/egui/crates/egui/src/lib.rs").truncate_at(TextTruncation::Middle));
+    /// # });
+    /// ```
+    ///
+    /// This also sets [`Self::wrap_mode`] to [`TextWrapMode::Truncate`].
+    #[inline]
+    pub fn truncate_at(mut self, truncation: TextTruncation) -> Self {
+        self.wrap_mode = Some(TextWrapMode::Truncate);
+        self.truncation = Some(truncation);
+        self
+    }
+
     /// Set [`Self::wrap_mode`] to [`TextWrapMode::Extend`],
     /// disabling wrapping and truncating, and instead expanding the parent [`Ui`].
     #[inline]
@@ -81,6 +109,25 @@ impl Label {
         self
     }
 
+    /// Wrap the text, but show at most this many lines.
+    ///
+    /// If the text doesn't fit, the last line ends with `…`,
+    /// and the full text is shown on hover (see [`Self::show_tooltip_when_elided`]).
+    ///
+    /// This also sets [`Self::wrap_mode`] to [`TextWrapMode::Wrap`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("A long card title that may need more than two lines").max_lines(2));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.wrap_mode = Some(TextWrapMode::Wrap);
+        self.max_lines = Some(max_lines);
+        self
+    }
+
     /// Sets the horizontal alignment of the Label to the given `Align` value.
     #[inline]
     pub fn halign(mut self, align: Align) -> Self {
@@ -88,6 +135,23 @@ impl Label {
         self
     }
 
+    /// Widen the spaces between words so that wrapped rows are flush with both edges,
+    /// like the paragraphs of a book.
+    ///
+    /// The last row of each paragraph is left as is.
+    /// See [`crate::text::LayoutJob::full_justify`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("A long paragraph of text…").wrap().full_justify(true));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn full_justify(mut self, full_justify: bool) -> Self {
+        self.full_justify = full_justify;
+        self
+    }
+
     /// Can the user select the text with the mouse?
     ///
     /// Overrides [`crate::style::Interaction::selectable_labels`].
@@ -133,6 +197,24 @@ impl Label {
         self.show_tooltip_when_elided = show;
         self
     }
+
+    /// Show a context menu with a "Copy" button when the label is right-clicked.
+    ///
+    /// If there is any selected text (see [`Self::selectable`]) it is copied,
+    /// otherwise the whole text of the label is copied.
+    ///
+    /// By default, this is false, so that you can add your own context menu to the label.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("2024-03-01 12:00:00 INFO Started").copy_context_menu(true));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn copy_context_menu(mut self, copy_context_menu: bool) -> Self {
+        self.copy_context_menu = copy_context_menu;
+        self
+    }
 }
 
 impl Label {
@@ -168,6 +250,11 @@ impl Label {
             sense |= select_sense;
         }
 
+        if self.copy_context_menu {
+            // We need to sense secondary clicks to open the menu:
+            sense |= Sense::click() - Sense::FOCUSABLE;
+        }
+
         if let WidgetText::Galley(galley) = self.text {
             // If the user said "use this specific galley", then just use it:
             let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
@@ -188,6 +275,10 @@ impl Label {
 
         let available_width = ui.available_width();
 
+        if let Some(max_lines) = self.max_lines {
+            layout_job.wrap.max_rows = max_lines;
+        }
+
         let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
         if wrap_mode == TextWrapMode::Wrap
             && ui.layout().main_dir() == Direction::LeftToRight
@@ -208,6 +299,7 @@ impl Label {
             layout_job.first_row_min_height = cursor.height();
             layout_job.halign = Align::Min;
             layout_job.justify = false;
+            layout_job.full_justify = false;
             if let Some(first_section) = layout_job.sections.first_mut() {
                 first_section.leading_space = first_row_indentation;
             }
@@ -240,6 +332,9 @@ impl Label {
                     layout_job.wrap.max_width = available_width;
                     layout_job.wrap.max_rows = 1;
                     layout_job.wrap.break_anywhere = true;
+                    if let Some(truncation) = self.truncation {
+                        layout_job.wrap.truncation = truncation;
+                    }
                 }
             }
 
@@ -251,6 +346,7 @@ impl Label {
                 layout_job.halign = self.halign.unwrap_or(ui.layout().horizontal_placement());
                 layout_job.justify = ui.layout().horizontal_justify();
             };
+            layout_job.full_justify |= self.full_justify;
 
             let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
             let (rect, mut response) = ui.allocate_exact_size(galley.size(), sense);
@@ -274,11 +370,32 @@ impl Widget for Label {
 
         let selectable = self.selectable;
         let show_tooltip_when_elided = self.show_tooltip_when_elided;
+        let copy_context_menu = self.copy_context_menu;
 
         let (galley_pos, galley, mut response) = self.layout_in_ui(ui);
         response
             .widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
 
+        if copy_context_menu {
+            response.context_menu(|ui| {
+                let mut selection = LabelSelectionState::load(ui.ctx());
+                if ui.button("Copy").clicked() {
+                    if selection.has_selection() {
+                        selection.request_copy();
+                        ui.ctx().request_repaint();
+                    } else {
+                        ui.ctx().copy_text(galley.text().to_owned());
+                    }
+                    ui.close();
+                }
+                if ui.ui_contains_pointer() {
+                    // Clicking in this menu should not deselect the text we are about to copy:
+                    selection.keep_selection();
+                }
+                selection.store(ui.ctx());
+            });
+        }
+
         if ui.is_rect_visible(response.rect) {
             if show_tooltip_when_elided && galley.elided {
                 // Show the full (non-elided) text on hover:
@@ -16,11 +16,14 @@ mod image_button;
 mod label;
 mod progress_bar;
 mod radio_button;
+mod range_slider;
+mod segmented_control;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod toggle_switch;
 
 #[expect(deprecated)]
 pub use self::selected_label::SelectableLabel;
@@ -30,17 +33,20 @@ pub use self::{
     drag_value::DragValue,
     hyperlink::{Hyperlink, Link},
     image::{
-        FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource,
+        FrameDurations, Image, ImageFit, ImageOptions, ImageSize, ImageSource, LoopCount,
         decode_animated_image_uri, has_gif_magic_header, has_webp_header, paint_texture_at,
     },
     image_button::ImageButton,
     label::Label,
     progress_bar::ProgressBar,
-    radio_button::RadioButton,
+    radio_button::{RadioButton, RadioGroup},
+    range_slider::RangeSlider,
+    segmented_control::SegmentedControl,
     separator::Separator,
     slider::{Slider, SliderClamping, SliderOrientation},
-    spinner::Spinner,
+    spinner::{Spinner, SpinnerStyle},
     text_edit::{TextBuffer, TextEdit},
+    toggle_switch::ToggleSwitch,
 };
 
 // ----------------------------------------------------------------------------
@@ -10,6 +10,8 @@ enum ProgressBarText {
 
 /// A simple progress bar.
 ///
+/// Use [`Self::indeterminate`] when you don't know how much work is left.
+///
 /// See also: [`crate::Spinner`].
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 pub struct ProgressBar {
@@ -19,6 +21,7 @@ pub struct ProgressBar {
     text: Option<ProgressBarText>,
     fill: Option<Color32>,
     animate: bool,
+    indeterminate: bool,
     corner_radius: Option<CornerRadius>,
 }
 
@@ -32,10 +35,36 @@ impl ProgressBar {
             text: None,
             fill: None,
             animate: false,
+            indeterminate: false,
             corner_radius: None,
         }
     }
 
+    /// A progress bar for when the total amount of work is unknown.
+    ///
+    /// Instead of a fraction, a highlighted segment keeps sweeping across the bar.
+    /// This will cause the UI to be redrawn while the bar is visible.
+    ///
+    /// The bar has the same size as a [`Self::new`] one with the same options,
+    /// so you can switch between them as soon as you know the progress:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let progress: Option<f32> = None;
+    /// let bar = match progress {
+    ///     Some(progress) => egui::ProgressBar::new(progress).show_percentage(),
+    ///     None => egui::ProgressBar::indeterminate(),
+    /// };
+    /// ui.add(bar.desired_width(200.0));
+    /// # });
+    /// ```
+    pub fn indeterminate() -> Self {
+        Self {
+            indeterminate: true,
+            ..Self::new(0.0)
+        }
+    }
+
     /// The desired width of the bar. Will use all horizontal space if not set.
     #[inline]
     pub fn desired_width(mut self, desired_width: f32) -> Self {
@@ -65,6 +94,8 @@ impl ProgressBar {
     }
 
     /// Show the progress in percent on the progress bar.
+    ///
+    /// Not shown for an [`Self::indeterminate`] progress bar.
     #[inline]
     pub fn show_percentage(mut self) -> Self {
         self.text = Some(ProgressBarText::Percentage);
@@ -111,10 +142,11 @@ impl Widget for ProgressBar {
             text,
             fill,
             animate,
+            indeterminate,
             corner_radius,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress < 1.0 && !indeterminate;
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -128,13 +160,16 @@ impl Widget for ProgressBar {
             } else {
                 WidgetInfo::new(WidgetType::ProgressIndicator)
             };
-            info.value = Some((progress as f64 * 100.0).floor());
+            if !indeterminate {
+                info.value = Some((progress as f64 * 100.0).floor());
+            }
 
             info
         });
 
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            // Only animate while visible, so hidden bars don't keep the app busy:
+            if animate || indeterminate {
                 ui.ctx().request_repaint();
             }
 
@@ -146,9 +181,12 @@ impl Widget for ProgressBar {
                 .rect_filled(outer_rect, corner_radius, visuals.extreme_bg_color);
             let min_width =
                 2.0 * f32::max(corner_radius.sw as _, corner_radius.nw as _).at_most(half_height);
-            let filled_width = (outer_rect.width() * progress).at_least(min_width);
-            let inner_rect =
-                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()));
+            let inner_rect = if indeterminate {
+                indeterminate_segment(outer_rect, ui.input(|i| i.time), min_width)
+            } else {
+                let filled_width = (outer_rect.width() * progress).at_least(min_width);
+                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()))
+            };
 
             let (dark, bright) = (0.7, 1.0);
             let color_factor = if animate {
@@ -188,6 +226,7 @@ impl Widget for ProgressBar {
             if let Some(text_kind) = text {
                 let text = match text_kind {
                     ProgressBarText::Custom(text) => text,
+                    ProgressBarText::Percentage if indeterminate => WidgetText::default(),
                     ProgressBarText::Percentage => {
                         format!("{}%", (progress * 100.0) as usize).into()
                     }
@@ -212,3 +251,25 @@ impl Widget for ProgressBar {
         response
     }
 }
+
+/// The highlighted part of an indeterminate progress bar at the given time.
+fn indeterminate_segment(outer_rect: Rect, time: f64, min_width: f32) -> Rect {
+    /// Seconds for the segment to sweep across the bar once.
+    const PERIOD: f64 = 1.5;
+
+    let segment_width = (0.3 * outer_rect.width()).at_least(min_width);
+    let phase = (time / PERIOD).fract() as f32;
+    let center = lerp(
+        (outer_rect.left() - segment_width / 2.0)..=(outer_rect.right() + segment_width / 2.0),
+        phase,
+    );
+
+    // Keep the segment inside the bar, so the rounded ends look right:
+    let left = (center - segment_width / 2.0).at_least(outer_rect.left());
+    let right = (center + segment_width / 2.0).at_most(outer_rect.right());
+    Rect::from_x_y_ranges(
+        left..=right.at_least(left + min_width),
+        outer_rect.y_range(),
+    )
+    .intersect(outer_rect)
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Ui, Vec2, Widget,
-    WidgetInfo, WidgetType, epaint,
+    Atom, AtomLayout, Atoms, EventFilter, Id, IntoAtoms, Key, Layout, Modifiers, NumExt as _,
+    Response, Sense, Ui, UiBuilder, Vec2, Widget, WidgetInfo, WidgetType, epaint,
 };
 
 /// One out of several alternatives, either selected or not.
@@ -26,6 +26,12 @@ use crate::{
 pub struct RadioButton<'a> {
     checked: bool,
     atoms: Atoms<'a>,
+
+    /// Set by [`RadioGroup`], so it can move the focus between its buttons.
+    id: Option<Id>,
+
+    /// A [`RadioGroup`] is a single tab stop.
+    focusable: bool,
 }
 
 impl<'a> RadioButton<'a> {
@@ -33,13 +39,20 @@ impl<'a> RadioButton<'a> {
         Self {
             checked,
             atoms: atoms.into_atoms(),
+            id: None,
+            focusable: true,
         }
     }
 }
 
 impl Widget for RadioButton<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { checked, mut atoms } = self;
+        let Self {
+            checked,
+            mut atoms,
+            id,
+            focusable,
+        } = self;
 
         let spacing = &ui.spacing();
         let icon_width = spacing.icon_width;
@@ -51,14 +64,24 @@ impl Widget for RadioButton<'_> {
         let mut icon_size = Vec2::splat(icon_width);
         icon_size.y = icon_size.y.at_least(min_size.y);
         let rect_id = Id::new("egui::radio_button");
-        atoms.push_left(Atom::custom(rect_id, icon_size));
+        if ui.is_right_to_left() {
+            atoms.push_right(Atom::custom(rect_id, icon_size));
+        } else {
+            atoms.push_left(Atom::custom(rect_id, icon_size));
+        }
 
         let text = atoms.text().map(String::from);
 
-        let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
-            .min_size(min_size)
-            .allocate(ui);
+        let sense = if focusable {
+            Sense::click()
+        } else {
+            Sense::click() - Sense::FOCUSABLE
+        };
+        let mut layout = AtomLayout::new(atoms).sense(sense).min_size(min_size);
+        if let Some(id) = id {
+            layout = layout.id(id);
+        }
+        let mut prepared = layout.allocate(ui);
 
         prepared.response.widget_info(|| {
             WidgetInfo::selected(
@@ -104,3 +127,186 @@ impl Widget for RadioButton<'_> {
         }
     }
 }
+
+/// A group of [`RadioButton`]s for picking one out of several alternatives.
+///
+/// Unlike a series of [`Ui::radio_value`], the whole group is a single tab stop:
+/// pressing Tab focuses the selected button, and the arrow keys then move both the selection
+/// and the focus to the previous or next button.
+///
+/// Usually you'd use [`Ui::radio_group`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(PartialEq)]
+/// enum Enum { First, Second, Third }
+/// let mut my_enum = Enum::First;
+///
+/// ui.radio_group(
+///     &mut my_enum,
+///     [(Enum::First, "First"), (Enum::Second, "Second"), (Enum::Third, "Third")],
+/// );
+///
+/// // is equivalent to:
+///
+/// ui.add(egui::RadioGroup::new(
+///     &mut my_enum,
+///     [(Enum::First, "First"), (Enum::Second, "Second"), (Enum::Third, "Third")],
+/// ));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct RadioGroup<'a, Value> {
+    current_value: &'a mut Value,
+    alternatives: Vec<(Value, Atoms<'a>)>,
+    layout: Option<Layout>,
+}
+
+impl<'a, Value: PartialEq> RadioGroup<'a, Value> {
+    /// One button per alternative. The one equal to `*current_value` is selected.
+    pub fn new<Text: IntoAtoms<'a>>(
+        current_value: &'a mut Value,
+        alternatives: impl IntoIterator<Item = (Value, Text)>,
+    ) -> Self {
+        Self {
+            current_value,
+            alternatives: alternatives
+                .into_iter()
+                .map(|(value, atoms)| (value, atoms.into_atoms()))
+                .collect(),
+            layout: None,
+        }
+    }
+
+    /// Put the buttons next to each other.
+    ///
+    /// Default: follow the layout of the parent [`Ui`].
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.layout = Some(Layout::left_to_right(crate::Align::Center));
+        self
+    }
+
+    /// Put the buttons below each other.
+    ///
+    /// Default: follow the layout of the parent [`Ui`].
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.layout = Some(Layout::top_down(crate::Align::Min));
+        self
+    }
+}
+
+impl<Value: PartialEq> Widget for RadioGroup<'_, Value> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current_value,
+            alternatives,
+            layout,
+        } = self;
+
+        let mut ui_builder = UiBuilder::new();
+        if let Some(layout) = layout {
+            ui_builder = ui_builder.layout(layout);
+        }
+
+        let num_alternatives = alternatives.len();
+        let mut changed = false;
+
+        let mut response = ui
+            .scope_builder(ui_builder, |ui| {
+                let group_id = ui.unique_id();
+                let button_id = |index: usize| group_id.with(index);
+
+                let focused = (0..num_alternatives)
+                    .find(|&index| ui.memory(|mem| mem.has_focus(button_id(index))));
+                let selected = alternatives
+                    .iter()
+                    .position(|(value, _)| value == current_value);
+
+                // The arrow keys move the selection, rather than the focus:
+                let keyboard_selection = focused.and_then(|focused| {
+                    arrow_key_selection(ui, button_id, focused, selected, num_alternatives)
+                });
+
+                // Only one button is reachable with the Tab key:
+                let tab_stop = keyboard_selection.or(selected).unwrap_or(0);
+
+                // The group node must exist before the buttons can be its children:
+                #[cfg(feature = "accesskit")]
+                ui.ctx().accesskit_node_builder(group_id, |_| {});
+
+                ui.ctx().clone().with_accessibility_parent(group_id, || {
+                    for (index, (value, atoms)) in alternatives.into_iter().enumerate() {
+                        let checked = match keyboard_selection {
+                            Some(target) => index == target,
+                            None => value == *current_value,
+                        };
+
+                        let button = RadioButton {
+                            checked,
+                            atoms,
+                            id: Some(button_id(index)),
+                            focusable: index == tab_stop || Some(index) == focused,
+                        };
+                        let clicked = ui.add(button).clicked();
+
+                        if (clicked || keyboard_selection == Some(index)) && *current_value != value
+                        {
+                            *current_value = value;
+                            changed = true;
+                        }
+                    }
+                });
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::RadioGroup, ui.is_enabled(), ""));
+        response
+    }
+}
+
+/// Handle the arrow keys in a group of buttons that is a single tab stop,
+/// like a [`RadioGroup`], while the button with index `focused` has the focus.
+///
+/// Returns the index of the button that should be selected, and moves the focus to it.
+pub(crate) fn arrow_key_selection(
+    ui: &Ui,
+    button_id: impl Fn(usize) -> Id,
+    focused: usize,
+    selected: Option<usize>,
+    num_buttons: usize,
+) -> Option<usize> {
+    ui.memory_mut(|mem| {
+        mem.set_focus_lock_filter(
+            button_id(focused),
+            EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        );
+    });
+    let steps = ui.input_mut(|input| {
+        let mut steps = 0;
+        for key in [Key::ArrowLeft, Key::ArrowUp] {
+            steps -= input.count_and_consume_key(Modifiers::NONE, key) as isize;
+        }
+        for key in [Key::ArrowRight, Key::ArrowDown] {
+            steps += input.count_and_consume_key(Modifiers::NONE, key) as isize;
+        }
+        steps
+    });
+    if steps == 0 {
+        return None;
+    }
+    let target =
+        (selected.unwrap_or(focused) as isize + steps).rem_euclid(num_buttons as isize) as usize;
+    ui.memory_mut(|mem| mem.request_focus(button_id(target)));
+    // Let the new button set its focus lock filter before the next key press:
+    ui.ctx().request_repaint();
+    Some(target)
+}
@@ -0,0 +1,901 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::ops::RangeInclusive;
+
+use crate::{
+    Color32, DragValue, EventFilter, Id, Key, Label, NumExt as _, Rangef, Rect, Response, Sense,
+    TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath, lerp, remap_clamp,
+    style::HandleShape, vec2,
+};
+
+use super::drag_value::clamp_value_to_range;
+use super::slider::{
+    NumFormatter, NumParser, SliderClamping, SliderOrientation, SliderSpec, normalized_from_value,
+    value_from_normalized,
+};
+
+// ----------------------------------------------------------------------------
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetRange<'a> = Box<dyn 'a + FnMut(Option<(f64, f64)>) -> (f64, f64)>;
+
+/// One of the two handles of a [`RangeSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Handle {
+    Start,
+    End,
+}
+
+/// What is being dragged, remembered from when the drag started.
+#[derive(Clone, Copy, Debug)]
+enum DragTarget {
+    Handle(Handle),
+
+    /// The handles were on top of each other when the drag started,
+    /// so we pick one based on which way the pointer goes.
+    EitherHandle,
+
+    /// The span between the handles, moving both of them.
+    Span {
+        /// Normalized position of the pointer, relative to the start handle.
+        grab: f64,
+
+        /// Normalized distance from the start handle to the end handle.
+        width: f64,
+    },
+}
+
+/// Control a range of numbers with a slider that has two handles.
+///
+/// Each handle can be dragged on its own, and dragging the span between them moves both.
+/// Clicking the rail outside the span moves the closest handle.
+/// Each handle can also be focused with the keyboard, and moved with the arrow keys.
+///
+/// The handles cannot cross each other, unless you allow it with [`Self::allow_swap`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut price = 20.0..=80.0;
+/// ui.add(egui::RangeSlider::new(&mut price, 0.0..=100.0).text("Price"));
+/// # });
+/// ```
+///
+/// The default size is set by [`crate::style::Spacing::slider_width`].
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct RangeSlider<'a> {
+    get_set_range: GetSetRange<'a>,
+    range: RangeInclusive<f64>,
+    spec: SliderSpec,
+    clamping: SliderClamping,
+    smart_aim: bool,
+    show_value: bool,
+    orientation: SliderOrientation,
+    prefix: String,
+    suffix: String,
+    text: WidgetText,
+
+    /// Sets the minimal step of the widget values
+    step: Option<f64>,
+
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+    custom_parser: Option<NumParser<'a>>,
+    handle_shape: Option<HandleShape>,
+    allow_swap: bool,
+}
+
+impl<'a> RangeSlider<'a> {
+    /// Creates a new horizontal range slider.
+    ///
+    /// Both ends of the `value` given will be clamped to the `range`,
+    /// unless you change this behavior with [`Self::clamping`].
+    pub fn new<Num: emath::Numeric>(
+        value: &'a mut RangeInclusive<Num>,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<(f64, f64)>| {
+            if let Some((start, end)) = v {
+                *value = Num::from_f64(start)..=Num::from_f64(end);
+            }
+            (value.start().to_f64(), value.end().to_f64())
+        });
+
+        if Num::INTEGRAL { slf.integer() } else { slf }
+    }
+
+    /// Creates a new horizontal range slider, from two separate values.
+    ///
+    /// The `start` is kept less than or equal to the `end`.
+    pub fn from_values<Num: emath::Numeric>(
+        start: &'a mut Num,
+        end: &'a mut Num,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<(f64, f64)>| {
+            if let Some((new_start, new_end)) = v {
+                *start = Num::from_f64(new_start);
+                *end = Num::from_f64(new_end);
+            }
+            (start.to_f64(), end.to_f64())
+        });
+
+        if Num::INTEGRAL { slf.integer() } else { slf }
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_range: impl 'a + FnMut(Option<(f64, f64)>) -> (f64, f64),
+    ) -> Self {
+        Self {
+            get_set_range: Box::new(get_set_range),
+            range,
+            spec: SliderSpec {
+                logarithmic: false,
+                smallest_positive: 1e-6,
+                largest_finite: f64::INFINITY,
+            },
+            clamping: SliderClamping::default(),
+            smart_aim: true,
+            show_value: true,
+            orientation: SliderOrientation::Horizontal,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            step: None,
+            min_decimals: 0,
+            max_decimals: None,
+            custom_formatter: None,
+            custom_parser: None,
+            handle_shape: None,
+            allow_swap: false,
+        }
+    }
+
+    /// Control whether or not the slider shows the current values.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the numbers, e.g. "x: "
+    #[inline]
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the numbers, this can be e.g. a unit ("°" or " m")
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text next to the slider (e.g. explaining what the slider controls).
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    #[inline]
+    pub fn text_color(mut self, text_color: Color32) -> Self {
+        self.text = self.text.color(text_color);
+        self
+    }
+
+    /// Vertical or horizontal slider? The default is horizontal.
+    #[inline]
+    pub fn orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Make this a vertical slider.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SliderOrientation::Vertical;
+        self
+    }
+
+    /// Make this a logarithmic slider.
+    /// This is great for when the slider spans a huge range,
+    /// e.g. from one to a million.
+    /// The default is OFF.
+    #[inline]
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.spec.logarithmic = logarithmic;
+        self
+    }
+
+    /// For logarithmic sliders that includes zero:
+    /// what is the smallest positive value you want to be able to select?
+    /// The default is `1` for integer sliders and `1e-6` for real sliders.
+    #[inline]
+    pub fn smallest_positive(mut self, smallest_positive: f64) -> Self {
+        self.spec.smallest_positive = smallest_positive;
+        self
+    }
+
+    /// For logarithmic sliders, the largest positive value we are interested in
+    /// before the slider switches to `INFINITY`, if that is the higher end.
+    /// Default: INFINITY.
+    #[inline]
+    pub fn largest_finite(mut self, largest_finite: f64) -> Self {
+        self.spec.largest_finite = largest_finite;
+        self
+    }
+
+    /// Controls when the values will be clamped to the range.
+    ///
+    /// See [`crate::Slider::clamping`] for details.
+    ///
+    /// Default: [`SliderClamping::Always`].
+    #[inline]
+    pub fn clamping(mut self, clamping: SliderClamping) -> Self {
+        self.clamping = clamping;
+        self
+    }
+
+    /// Turn smart aim on/off. Default is ON.
+    /// There is almost no point in turning this off.
+    #[inline]
+    pub fn smart_aim(mut self, smart_aim: bool) -> Self {
+        self.smart_aim = smart_aim;
+        self
+    }
+
+    /// Sets the minimal change of the values.
+    ///
+    /// Value `0.0` effectively disables the feature.
+    ///
+    /// Default: `0.0` (disabled).
+    #[inline]
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step != 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Set a minimum number of decimals to display.
+    ///
+    /// See [`crate::Slider::min_decimals`].
+    #[inline]
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Set a maximum number of decimals to display.
+    ///
+    /// Values will also be rounded to this number of decimals.
+    #[inline]
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    #[inline]
+    pub fn max_decimals_opt(mut self, max_decimals: Option<usize>) -> Self {
+        self.max_decimals = max_decimals;
+        self
+    }
+
+    /// Set an exact number of decimals to display.
+    ///
+    /// Values will also be rounded to this number of decimals.
+    #[inline]
+    pub fn fixed_decimals(mut self, num_decimals: usize) -> Self {
+        self.min_decimals = num_decimals;
+        self.max_decimals = Some(num_decimals);
+        self
+    }
+
+    /// Change the shape of the handles.
+    ///
+    /// Behavior for each shape is the same as [`crate::Slider::handle_shape`].
+    #[inline]
+    pub fn handle_shape(mut self, handle_shape: HandleShape) -> Self {
+        self.handle_shape = Some(handle_shape);
+        self
+    }
+
+    /// Set custom formatter defining how numbers are converted into text.
+    ///
+    /// See [`crate::Slider::custom_formatter`].
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set custom parser defining how the text input is parsed into a number.
+    ///
+    /// See [`crate::Slider::custom_parser`].
+    #[inline]
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<f64>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
+
+    /// Can a handle be dragged past the other one?
+    ///
+    /// If `true`, the handles swap places when one is dragged past the other,
+    /// so that the start is always less than or equal to the end.
+    /// If `false`, a handle stops when it reaches the other one.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn allow_swap(mut self, allow_swap: bool) -> Self {
+        self.allow_swap = allow_swap;
+        self
+    }
+
+    /// Helper: equivalent to `self.precision(0).smallest_positive(1.0)`.
+    /// If you use [`Self::new`] with integers this is called for you,
+    /// but if you want to pick integer values with `f64`s, use this.
+    pub fn integer(self) -> Self {
+        self.fixed_decimals(0).smallest_positive(1.0).step_by(1.0)
+    }
+
+    fn clamp_value(&self, value: f64) -> f64 {
+        if self.clamping == SliderClamping::Always {
+            clamp_value_to_range(value, self.range.clone())
+        } else {
+            value
+        }
+    }
+
+    fn get_range(&mut self) -> (f64, f64) {
+        let (start, end) = (self.get_set_range)(None);
+        (self.clamp_value(start), self.clamp_value(end))
+    }
+
+    fn get_value(&mut self, handle: Handle) -> f64 {
+        let (start, end) = self.get_range();
+        match handle {
+            Handle::Start => start,
+            Handle::End => end,
+        }
+    }
+
+    fn round_value(&self, mut value: f64) -> f64 {
+        if self.clamping != SliderClamping::Never {
+            value = clamp_value_to_range(value, self.range.clone());
+        }
+
+        if let Some(step) = self.step {
+            let start = *self.range.start();
+            value = start + ((value - start) / step).round() * step;
+        }
+        if let Some(max_decimals) = self.max_decimals {
+            value = emath::round_to_decimals(value, max_decimals);
+        }
+        value
+    }
+
+    fn set_range(&mut self, start: f64, end: f64) {
+        let start = self.round_value(start);
+        let end = self.round_value(end);
+        (self.get_set_range)(Some((start, end)));
+    }
+
+    /// Move one handle to a new value, keeping the start before the end.
+    ///
+    /// Returns the handle that ends up at `value`,
+    /// which is the other one if the handles swapped places.
+    fn set_value(&mut self, handle: Handle, value: f64) -> Handle {
+        let (start, end) = self.get_range();
+        match handle {
+            Handle::Start if end < value => {
+                if self.allow_swap {
+                    self.set_range(end, value);
+                    return Handle::End;
+                }
+                self.set_range(end, end);
+            }
+            Handle::End if value < start => {
+                if self.allow_swap {
+                    self.set_range(value, start);
+                    return Handle::Start;
+                }
+                self.set_range(start, start);
+            }
+            Handle::Start => self.set_range(value, end),
+            Handle::End => self.set_range(start, value),
+        }
+        handle
+    }
+
+    fn normalized_from_position(position: f32, position_range: Rangef) -> f64 {
+        remap_clamp(position, position_range, 0.0..=1.0) as f64
+    }
+
+    fn value_from_position(&self, position: f32, position_range: Rangef) -> f64 {
+        let normalized = Self::normalized_from_position(position, position_range);
+        value_from_normalized(normalized, self.range.clone(), &self.spec)
+    }
+
+    fn position_from_value(&self, value: f64, position_range: Rangef) -> f32 {
+        let normalized = normalized_from_value(value, self.range.clone(), &self.spec);
+        lerp(position_range, normalized as f32)
+    }
+
+    /// The value under the pointer, using smart aim if enabled.
+    fn aimed_value(&self, ui: &Ui, position: f32, position_range: Rangef) -> f64 {
+        if self.smart_aim {
+            let aim_radius = ui.input(|i| i.aim_radius());
+            emath::smart_aim::best_in_range_f64(
+                self.value_from_position(position - aim_radius, position_range),
+                self.value_from_position(position + aim_radius, position_range),
+            )
+        } else {
+            self.value_from_position(position, position_range)
+        }
+    }
+}
+
+impl RangeSlider<'_> {
+    /// Where we can interact with a handle.
+    fn handle_rect(&self, center: crate::Pos2, radius: f32, handle_shape: HandleShape) -> Rect {
+        let half_size = match handle_shape {
+            HandleShape::Circle => Vec2::splat(radius),
+            HandleShape::Rect { aspect_ratio } => match self.orientation {
+                SliderOrientation::Horizontal => vec2(radius * aspect_ratio, radius),
+                SliderOrientation::Vertical => vec2(radius, radius * aspect_ratio),
+            },
+        };
+        Rect::from_center_size(center, 2.0 * half_size)
+    }
+
+    /// Handle dragging of the rail, the span, and the handles.
+    fn drag_ui(
+        &mut self,
+        ui: &Ui,
+        drag_id: Id,
+        [rail, span, start, end]: [&Response; 4],
+        position_range: Rangef,
+    ) {
+        let any_drag_started = [rail, span, start, end]
+            .iter()
+            .any(|response| response.drag_started());
+
+        if any_drag_started {
+            let (start_value, end_value) = self.get_range();
+            let start_position = self.position_from_value(start_value, position_range);
+            let end_position = self.position_from_value(end_value, position_range);
+            let pointer = [rail, span, start, end]
+                .iter()
+                .find_map(|response| response.interact_pointer_pos())
+                .map(|pos| self.orientation.pointer_position(pos));
+
+            let target = if span.drag_started() {
+                let start_normalized =
+                    Self::normalized_from_position(start_position, position_range);
+                let end_normalized = Self::normalized_from_position(end_position, position_range);
+                let pointer_normalized = pointer.map_or(start_normalized, |pointer| {
+                    Self::normalized_from_position(pointer, position_range)
+                });
+                DragTarget::Span {
+                    grab: pointer_normalized - start_normalized,
+                    width: end_normalized - start_normalized,
+                }
+            } else if (start_position - end_position).abs() < 1.0 {
+                DragTarget::EitherHandle
+            } else if start.drag_started() {
+                DragTarget::Handle(Handle::Start)
+            } else if end.drag_started() {
+                DragTarget::Handle(Handle::End)
+            } else {
+                // Pressed the rail: go with the closest handle.
+                let pointer = pointer.unwrap_or(start_position);
+                if (pointer - start_position).abs() <= (pointer - end_position).abs() {
+                    DragTarget::Handle(Handle::Start)
+                } else {
+                    DragTarget::Handle(Handle::End)
+                }
+            };
+            ui.data_mut(|data| data.insert_temp(drag_id, target));
+        }
+
+        let pointer_pos = [rail, span, start, end]
+            .iter()
+            .filter(|response| response.dragged())
+            .find_map(|response| response.interact_pointer_pos());
+
+        if let (Some(pointer_pos), Some(target)) = (
+            pointer_pos,
+            ui.data(|data| data.get_temp::<DragTarget>(drag_id)),
+        ) {
+            let position = self.orientation.pointer_position(pointer_pos);
+
+            let new_target = match target {
+                DragTarget::Handle(handle) => {
+                    let value = self.aimed_value(ui, position, position_range);
+                    DragTarget::Handle(self.set_value(handle, value))
+                }
+                DragTarget::EitherHandle => {
+                    let value = self.aimed_value(ui, position, position_range);
+                    let (start_value, end_value) = self.get_range();
+                    if value < start_value {
+                        DragTarget::Handle(self.set_value(Handle::Start, value))
+                    } else if end_value < value {
+                        DragTarget::Handle(self.set_value(Handle::End, value))
+                    } else {
+                        target
+                    }
+                }
+                DragTarget::Span { grab, width } => {
+                    let pointer_normalized =
+                        Self::normalized_from_position(position, position_range);
+                    let mut start_normalized = pointer_normalized - grab;
+                    let min = start_normalized.min(start_normalized + width);
+                    let max = start_normalized.max(start_normalized + width);
+                    if min < 0.0 {
+                        start_normalized -= min;
+                    } else if 1.0 < max {
+                        start_normalized -= max - 1.0;
+                    }
+                    let end_normalized = start_normalized + width;
+                    self.set_range(
+                        value_from_normalized(start_normalized, self.range.clone(), &self.spec),
+                        value_from_normalized(end_normalized, self.range.clone(), &self.spec),
+                    );
+                    target
+                }
+            };
+            ui.data_mut(|data| data.insert_temp(drag_id, new_target));
+        }
+    }
+
+    /// Move a focused handle with the keyboard (or accessibility actions).
+    fn keyboard_ui(
+        &mut self,
+        ui: &Ui,
+        handle: Handle,
+        response: &Response,
+        other_id: Id,
+        position_range: Rangef,
+    ) {
+        let mut decrement = 0usize;
+        let mut increment = 0usize;
+
+        if response.has_focus() {
+            ui.ctx().memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        // pressing arrows in the orientation of the
+                        // slider should not move focus to next widget
+                        horizontal_arrows: matches!(
+                            self.orientation,
+                            SliderOrientation::Horizontal
+                        ),
+                        vertical_arrows: matches!(self.orientation, SliderOrientation::Vertical),
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let (dec_key, inc_key) = match self.orientation {
+                SliderOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
+                // Note that this is for moving the slider position,
+                // so up = decrement y coordinate:
+                SliderOrientation::Vertical => (Key::ArrowUp, Key::ArrowDown),
+            };
+
+            ui.input(|input| {
+                decrement += input.num_presses(dec_key);
+                increment += input.num_presses(inc_key);
+            });
+        }
+
+        #[cfg(feature = "accesskit")]
+        {
+            use accesskit::Action;
+            ui.input(|input| {
+                decrement += input.num_accesskit_action_requests(response.id, Action::Decrement);
+                increment += input.num_accesskit_action_requests(response.id, Action::Increment);
+            });
+        }
+
+        let kb_step = increment as f32 - decrement as f32;
+        let mut new_handle = handle;
+
+        if kb_step != 0.0 {
+            let ui_point_per_step = 1.0; // move this many ui points for each kb_step
+            let prev_value = self.get_value(handle);
+            let prev_position = self.position_from_value(prev_value, position_range);
+            let new_position = prev_position + ui_point_per_step * kb_step;
+            let mut new_value = match self.step {
+                Some(step) => prev_value + (kb_step as f64 * step),
+                None if self.smart_aim => {
+                    let aim_radius = 0.49 * ui_point_per_step; // Chosen so we don't include `prev_value` in the search.
+                    emath::smart_aim::best_in_range_f64(
+                        self.value_from_position(new_position - aim_radius, position_range),
+                        self.value_from_position(new_position + aim_radius, position_range),
+                    )
+                }
+                _ => self.value_from_position(new_position, position_range),
+            };
+            if let Some(max_decimals) = self.max_decimals {
+                // `set_range` rounds, so ensure we reach at the least the next breakpoint.
+                let min_increment = 1.0 / (10.0_f64.powi(max_decimals as i32));
+                new_value = if new_value > prev_value {
+                    f64::max(new_value, prev_value + min_increment * 1.001)
+                } else if new_value < prev_value {
+                    f64::min(new_value, prev_value - min_increment * 1.001)
+                } else {
+                    new_value
+                };
+            }
+            new_handle = self.set_value(handle, new_value);
+        }
+
+        #[cfg(feature = "accesskit")]
+        {
+            use accesskit::{Action, ActionData};
+            let requests = ui.input(|input| {
+                input
+                    .accesskit_action_requests(response.id, Action::SetValue)
+                    .filter_map(|request| match request.data {
+                        Some(ActionData::NumericValue(new_value)) => Some(new_value),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            });
+            for new_value in requests {
+                new_handle = self.set_value(new_handle, new_value);
+            }
+        }
+
+        if new_handle != handle && response.has_focus() {
+            // The handles swapped places, so keep the focus on the value we are moving:
+            ui.memory_mut(|m| m.request_focus(other_id));
+        }
+    }
+
+    fn paint(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        (start_value, end_value): (f64, f64),
+        [start_response, end_response]: [&Response; 2],
+    ) {
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        let position_range = self.orientation.position_range(&rect, &handle_shape);
+        let widget_visuals = &ui.visuals().widgets;
+        let spacing = &ui.style().spacing;
+
+        let rail_radius = (spacing.slider_rail_height / 2.0).at_least(0.0);
+        let rail_rect = self.orientation.rail_rect(&rect, rail_radius);
+        let corner_radius = widget_visuals.inactive.corner_radius;
+
+        ui.painter()
+            .rect_filled(rail_rect, corner_radius, widget_visuals.inactive.bg_fill);
+
+        let start_center = self.orientation.marker_center(
+            self.position_from_value(start_value, position_range),
+            &rail_rect,
+        );
+        let end_center = self.orientation.marker_center(
+            self.position_from_value(end_value, position_range),
+            &rail_rect,
+        );
+
+        let mut span_rect = Rect::from_two_pos(start_center, end_center);
+        match self.orientation {
+            SliderOrientation::Horizontal => span_rect.set_height(rail_rect.height()),
+            SliderOrientation::Vertical => span_rect.set_width(rail_rect.width()),
+        }
+        ui.painter()
+            .rect_filled(span_rect, corner_radius, ui.visuals().selection.bg_fill);
+
+        let radius = self.orientation.handle_radius(&rect);
+        for (center, response) in [(start_center, start_response), (end_center, end_response)] {
+            let visuals = ui.style().interact(response);
+            self.orientation
+                .paint_handle(ui, center, radius, handle_shape, visuals);
+        }
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui, handle: Handle, position_range: Rangef) -> Response {
+        let value = self.get_value(handle);
+
+        // If [`DragValue`] is controlled from the keyboard and `step` is defined, set speed to `step`
+        let any_change = ui.input(|input| {
+            [
+                Key::ArrowUp,
+                Key::ArrowRight,
+                Key::ArrowDown,
+                Key::ArrowLeft,
+            ]
+            .into_iter()
+            .any(|key| input.key_pressed(key))
+        });
+        let speed = match self.step {
+            Some(step) if any_change => step,
+            _ => {
+                // delta(value) / delta(points)
+                let position = self.position_from_value(value, position_range);
+                self.value_from_position(position + 0.5, position_range)
+                    - self.value_from_position(position - 0.5, position_range)
+            }
+        };
+
+        let mut new_value = value;
+        let response = ui.add({
+            let mut dv = DragValue::new(&mut new_value)
+                .speed(speed)
+                .min_decimals(self.min_decimals)
+                .max_decimals_opt(self.max_decimals)
+                .suffix(self.suffix.clone())
+                .prefix(self.prefix.clone());
+
+            match self.clamping {
+                SliderClamping::Never => {}
+                SliderClamping::Edits => {
+                    dv = dv.range(self.range.clone()).clamp_existing_to_range(false);
+                }
+                SliderClamping::Always => {
+                    dv = dv.range(self.range.clone()).clamp_existing_to_range(true);
+                }
+            }
+
+            if let Some(fmt) = &self.custom_formatter {
+                dv = dv.custom_formatter(fmt);
+            };
+            if let Some(parser) = &self.custom_parser {
+                dv = dv.custom_parser(parser);
+            }
+            dv
+        });
+        if new_value != value {
+            self.set_value(handle, new_value);
+        }
+        response
+    }
+
+    fn add_contents(&mut self, ui: &mut Ui) -> Response {
+        let (old_start, old_end) = self.get_range();
+
+        if self.clamping == SliderClamping::Always {
+            self.set_range(old_start, old_end);
+        }
+
+        let thickness = ui
+            .text_style_height(&TextStyle::Body)
+            .at_least(ui.spacing().interact_size.y);
+        let desired_size = match self.orientation {
+            SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
+            SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
+        };
+
+        // Pressing the rail moves the closest handle, but only the handles can have focus:
+        let mut response = ui.allocate_response(desired_size, Sense::drag() - Sense::FOCUSABLE);
+        let rect = response.rect;
+        let id = response.id;
+
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        let position_range = self.orientation.position_range(&rect, &handle_shape);
+        let radius = self.orientation.handle_radius(&rect);
+
+        let (start_value, end_value) = self.get_range();
+        let start_center = self
+            .orientation
+            .marker_center(self.position_from_value(start_value, position_range), &rect);
+        let end_center = self
+            .orientation
+            .marker_center(self.position_from_value(end_value, position_range), &rect);
+        let mut span_rect = Rect::from_two_pos(start_center, end_center);
+        match self.orientation {
+            SliderOrientation::Horizontal => span_rect.set_height(rect.height()),
+            SliderOrientation::Vertical => span_rect.set_width(rect.width()),
+        }
+
+        // Added in this order so that the handles are on top of the span:
+        let start_id = id.with("start");
+        let end_id = id.with("end");
+        let span_response =
+            ui.interact(span_rect, id.with("span"), Sense::drag() - Sense::FOCUSABLE);
+        let start_response = ui.interact(
+            self.handle_rect(start_center, radius, handle_shape),
+            start_id,
+            Sense::drag(),
+        );
+        let end_response = ui.interact(
+            self.handle_rect(end_center, radius, handle_shape),
+            end_id,
+            Sense::drag(),
+        );
+
+        self.drag_ui(
+            ui,
+            id.with("drag"),
+            [&response, &span_response, &start_response, &end_response],
+            position_range,
+        );
+        let (mut start_response, mut end_response) = (start_response, end_response);
+        self.keyboard_ui(ui, Handle::Start, &start_response, end_id, position_range);
+        self.keyboard_ui(ui, Handle::End, &end_response, start_id, position_range);
+
+        let (start_value, end_value) = self.get_range();
+        if start_value != old_start {
+            start_response.mark_changed();
+        }
+        if end_value != old_end {
+            end_response.mark_changed();
+        }
+
+        if ui.is_rect_visible(rect) {
+            self.paint(
+                ui,
+                rect,
+                (start_value, end_value),
+                [&start_response, &end_response],
+            );
+        }
+
+        let text = self.text.text().to_owned();
+        for (response, value) in [(&start_response, start_value), (&end_response, end_value)] {
+            response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, &text));
+
+            #[cfg(feature = "accesskit")]
+            ui.ctx().accesskit_node_builder(response.id, |builder| {
+                use accesskit::Action;
+                builder.set_min_numeric_value(*self.range.start());
+                builder.set_max_numeric_value(*self.range.end());
+                if let Some(step) = self.step {
+                    builder.set_numeric_value_step(step);
+                }
+                builder.add_action(Action::SetValue);
+                builder.add_action(Action::Increment);
+                builder.add_action(Action::Decrement);
+            });
+        }
+
+        response = response
+            .union(span_response)
+            .union(start_response.clone())
+            .union(end_response.clone());
+
+        if self.show_value {
+            let start_value_response = self.value_ui(ui, Handle::Start, position_range);
+            let end_value_response = self.value_ui(ui, Handle::End, position_range);
+            response = response
+                .union(start_value_response)
+                .union(end_value_response);
+        }
+
+        if !self.text.is_empty() {
+            let label_response =
+                ui.add(Label::new(self.text.clone()).wrap_mode(TextWrapMode::Extend));
+            start_response.labelled_by(label_response.id);
+            end_response.labelled_by(label_response.id);
+        }
+
+        response
+    }
+}
+
+impl Widget for RangeSlider<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let inner_response = match self.orientation {
+            SliderOrientation::Horizontal => ui.horizontal(|ui| self.add_contents(ui)),
+            SliderOrientation::Vertical => ui.vertical(|ui| self.add_contents(ui)),
+        };
+
+        inner_response.inner | inner_response.response
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    Align, Align2, AtomLayout, Atoms, Frame, IntoAtoms, Layout, NumExt as _, Rect, Response, Sense,
+    StrokeKind, Ui, UiBuilder, Widget, WidgetInfo, WidgetType, vec2,
+    widgets::radio_button::arrow_key_selection,
+};
+
+/// A row of connected buttons for picking one out of several alternatives,
+/// like "Day | Week | Month".
+///
+/// The segments are shown as one pill, with the selected one highlighted.
+/// Like a [`crate::RadioGroup`], the whole control is a single tab stop,
+/// and the arrow keys move the selection while it has keyboard focus.
+///
+/// [`Response::changed`] is `true` when the user picked another value.
+///
+/// Usually you'd use [`Ui::segmented_control`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(PartialEq)]
+/// enum Period { Day, Week, Month }
+/// let mut period = Period::Day;
+///
+/// ui.add(
+///     egui::SegmentedControl::new(
+///         &mut period,
+///         [(Period::Day, "Day"), (Period::Week, "Week"), (Period::Month, "Month")],
+///     )
+///     .equal_widths(true),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct SegmentedControl<'a, Value> {
+    current_value: &'a mut Value,
+    segments: Vec<(Value, Atoms<'a>)>,
+    equal_widths: bool,
+}
+
+impl<'a, Value: PartialEq> SegmentedControl<'a, Value> {
+    /// One segment per alternative. The one equal to `*current_value` is selected.
+    ///
+    /// The segments can be text, images, or anything else that is [`IntoAtoms`].
+    pub fn new<Content: IntoAtoms<'a>>(
+        current_value: &'a mut Value,
+        segments: impl IntoIterator<Item = (Value, Content)>,
+    ) -> Self {
+        Self {
+            current_value,
+            segments: segments
+                .into_iter()
+                .map(|(value, atoms)| (value, atoms.into_atoms()))
+                .collect(),
+            equal_widths: false,
+        }
+    }
+
+    /// Make all segments as wide as the widest one.
+    ///
+    /// Default: `false`, i.e. each segment is as wide as its contents.
+    #[inline]
+    pub fn equal_widths(mut self, equal_widths: bool) -> Self {
+        self.equal_widths = equal_widths;
+        self
+    }
+}
+
+impl<Value: PartialEq> Widget for SegmentedControl<'_, Value> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current_value,
+            segments,
+            equal_widths,
+        } = self;
+
+        let num_segments = segments.len();
+        let mut changed = false;
+
+        let ui_builder = UiBuilder::new().layout(Layout::left_to_right(Align::Center));
+        let mut response = ui
+            .scope_builder(ui_builder, |ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+
+                let group_id = ui.unique_id();
+                let segment_id = |index: usize| group_id.with(index);
+                let width_id = group_id.with("segment_width");
+
+                let focused = (0..num_segments)
+                    .find(|&index| ui.memory(|mem| mem.has_focus(segment_id(index))));
+                let selected = segments
+                    .iter()
+                    .position(|(value, _)| value == current_value);
+
+                let keyboard_selection = focused.and_then(|focused| {
+                    arrow_key_selection(ui, segment_id, focused, selected, num_segments)
+                });
+
+                // Only one segment is reachable with the Tab key:
+                let tab_stop = keyboard_selection.or(selected).unwrap_or(0);
+
+                // The widest segment last frame:
+                let segment_width = if equal_widths {
+                    ui.data(|data| data.get_temp::<f32>(width_id))
+                } else {
+                    None
+                };
+                let mut widest: f32 = 0.0;
+
+                let button_padding = ui.spacing().button_padding;
+                let icon_spacing = ui.spacing().icon_spacing;
+                let corner_radius = ui.visuals().widgets.inactive.corner_radius;
+                let mut rects: Vec<Rect> = Vec::with_capacity(num_segments);
+
+                // The group node must exist before the segments can be its children:
+                #[cfg(feature = "accesskit")]
+                ui.ctx().accesskit_node_builder(group_id, |_| {});
+
+                ui.ctx().clone().with_accessibility_parent(group_id, || {
+                    for (index, (value, atoms)) in segments.into_iter().enumerate() {
+                        let is_selected = match keyboard_selection {
+                            Some(target) => index == target,
+                            None => value == *current_value,
+                        };
+
+                        let text = atoms.text().map(String::from);
+                        let sense = if index == tab_stop || Some(index) == focused {
+                            Sense::click()
+                        } else {
+                            Sense::click() - Sense::FOCUSABLE
+                        };
+                        let mut prepared = AtomLayout::new(atoms)
+                            .id(segment_id(index))
+                            .sense(sense)
+                            .frame(Frame::new().inner_margin(button_padding))
+                            .min_size(vec2(
+                                segment_width.unwrap_or_default(),
+                                ui.spacing().interact_size.y,
+                            ))
+                            .align2(Align2::CENTER_CENTER)
+                            .allocate(ui);
+
+                        // Without the `min_size`, so the segments can also get narrower:
+                        let num_atoms = prepared.sized_atoms.len();
+                        let natural_width = prepared
+                            .sized_atoms
+                            .iter()
+                            .map(|atom| atom.size.x)
+                            .sum::<f32>()
+                            + icon_spacing * num_atoms.saturating_sub(1) as f32
+                            + 2.0 * button_padding.x;
+                        widest = widest.max(natural_width);
+
+                        prepared.response.widget_info(|| {
+                            WidgetInfo::selected(
+                                WidgetType::RadioButton,
+                                ui.is_enabled(),
+                                is_selected,
+                                text.as_deref().unwrap_or(""),
+                            )
+                        });
+
+                        let segment_response = if ui.is_rect_visible(prepared.response.rect) {
+                            let visuals = ui
+                                .style()
+                                .interact_selectable(&prepared.response, is_selected);
+                            // Only the outer corners of the pill are rounded:
+                            let mut rounding = corner_radius;
+                            if index != 0 {
+                                rounding.nw = 0;
+                                rounding.sw = 0;
+                            }
+                            if index + 1 != num_segments {
+                                rounding.ne = 0;
+                                rounding.se = 0;
+                            }
+                            prepared.fallback_text_color = visuals.text_color();
+                            prepared.frame = prepared
+                                .frame
+                                .fill(visuals.weak_bg_fill)
+                                .corner_radius(rounding);
+                            prepared.paint(ui).response
+                        } else {
+                            prepared.response
+                        };
+                        rects.push(segment_response.rect);
+
+                        if (segment_response.clicked() || keyboard_selection == Some(index))
+                            && *current_value != value
+                        {
+                            *current_value = value;
+                            changed = true;
+                        }
+                    }
+                });
+
+                if equal_widths && segment_width != Some(widest) {
+                    ui.data_mut(|data| data.insert_temp(width_id, widest));
+                    ui.ctx().request_discard("SegmentedControl widths changed");
+                }
+
+                // Outline the pill, and separate the segments:
+                if let (Some(first), Some(last)) = (rects.first(), rects.last()) {
+                    let outline = first.union(*last);
+                    if ui.is_rect_visible(outline) {
+                        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                        let painter = ui.painter();
+                        for rect in &rects[1..] {
+                            painter.vline(
+                                rect.left(),
+                                rect.y_range().shrink(stroke.width.at_least(1.0)),
+                                stroke,
+                            );
+                        }
+                        painter.rect_stroke(outline, corner_radius, stroke, StrokeKind::Inside);
+                    }
+                }
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::RadioGroup, ui.is_enabled(), ""));
+        response
+    }
+}
@@ -1,19 +1,21 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use crate::{
-    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef, Rect,
-    Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath,
-    epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
+    Color32, DragValue, EventFilter, Galley, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef,
+    Rect, Response, Sense, Stroke, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo,
+    WidgetText, emath, epaint, lerp, pos2, remap, remap_clamp, style,
+    style::{HandleShape, NumberFormatter},
+    vec2,
 };
 
 use super::drag_value::clamp_value_to_range;
 
 // ----------------------------------------------------------------------------
 
-type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
-type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
+pub(super) type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
+pub(super) type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 
 // ----------------------------------------------------------------------------
 
@@ -32,17 +34,17 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 // ----------------------------------------------------------------------------
 
 #[derive(Clone)]
-struct SliderSpec {
-    logarithmic: bool,
+pub(super) struct SliderSpec {
+    pub(super) logarithmic: bool,
 
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
-    smallest_positive: f64,
+    pub(super) smallest_positive: f64,
 
     /// For logarithmic sliders, the largest positive value we are interested in
     /// before the slider switches to `INFINITY`, if that is the higher end.
     /// Default: INFINITY.
-    largest_finite: f64,
+    pub(super) largest_finite: f64,
 }
 
 /// Specifies the orientation of a [`Slider`].
@@ -53,6 +55,92 @@ pub enum SliderOrientation {
     Vertical,
 }
 
+impl SliderOrientation {
+    pub(super) fn marker_center(self, position_1d: f32, rail_rect: &Rect) -> Pos2 {
+        match self {
+            Self::Horizontal => pos2(position_1d, rail_rect.center().y),
+            Self::Vertical => pos2(rail_rect.center().x, position_1d),
+        }
+    }
+
+    pub(super) fn pointer_position(self, pointer_position_2d: Pos2) -> f32 {
+        match self {
+            Self::Horizontal => pointer_position_2d.x,
+            Self::Vertical => pointer_position_2d.y,
+        }
+    }
+
+    pub(super) fn position_range(self, rect: &Rect, handle_shape: &style::HandleShape) -> Rangef {
+        let handle_radius = self.handle_radius(rect);
+        let handle_radius = match handle_shape {
+            style::HandleShape::Circle => handle_radius,
+            style::HandleShape::Rect { aspect_ratio } => handle_radius * aspect_ratio,
+        };
+        match self {
+            Self::Horizontal => rect.x_range().shrink(handle_radius),
+            // The vertical case has to be flipped because the largest slider value maps to the
+            // lowest y value (which is at the top)
+            Self::Vertical => rect.y_range().shrink(handle_radius).flip(),
+        }
+    }
+
+    pub(super) fn rail_rect(self, rect: &Rect, radius: f32) -> Rect {
+        match self {
+            Self::Horizontal => Rect::from_min_max(
+                pos2(rect.left(), rect.center().y - radius),
+                pos2(rect.right(), rect.center().y + radius),
+            ),
+            Self::Vertical => Rect::from_min_max(
+                pos2(rect.center().x - radius, rect.top()),
+                pos2(rect.center().x + radius, rect.bottom()),
+            ),
+        }
+    }
+
+    pub(super) fn handle_radius(self, rect: &Rect) -> f32 {
+        let limit = match self {
+            Self::Horizontal => rect.height(),
+            Self::Vertical => rect.width(),
+        };
+        limit / 2.5
+    }
+
+    pub(super) fn paint_handle(
+        self,
+        ui: &Ui,
+        center: Pos2,
+        radius: f32,
+        handle_shape: HandleShape,
+        visuals: &style::WidgetVisuals,
+    ) {
+        match handle_shape {
+            style::HandleShape::Circle => {
+                ui.painter().add(epaint::CircleShape {
+                    center,
+                    radius: radius + visuals.expansion,
+                    fill: visuals.bg_fill,
+                    stroke: visuals.fg_stroke,
+                });
+            }
+            style::HandleShape::Rect { aspect_ratio } => {
+                let v = match self {
+                    Self::Horizontal => Vec2::new(radius * aspect_ratio, radius),
+                    Self::Vertical => Vec2::new(radius, radius * aspect_ratio),
+                };
+                let v = v + Vec2::splat(visuals.expansion);
+                let rect = Rect::from_center_size(center, 2.0 * v);
+                ui.painter().rect(
+                    rect,
+                    visuals.corner_radius,
+                    visuals.bg_fill,
+                    visuals.fg_stroke,
+                    epaint::StrokeKind::Inside,
+                );
+            }
+        }
+    }
+}
+
 /// Specifies how values in a [`Slider`] are clamped.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -118,6 +206,16 @@ pub struct Slider<'a> {
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
+    ticks: Vec<SliderTick>,
+    num_evenly_spaced_ticks: usize,
+
+    /// Snap to ticks within this many points.
+    snap_to_ticks: Option<f32>,
+}
+
+struct SliderTick {
+    value: f64,
+    label: Option<WidgetText>,
 }
 
 impl<'a> Slider<'a> {
@@ -165,6 +263,9 @@ impl<'a> Slider<'a> {
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
+            ticks: Vec::new(),
+            num_evenly_spaced_ticks: 0,
+            snap_to_ticks: None,
         }
     }
 
@@ -395,6 +496,63 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Draw tick marks on the rail at these values.
+    ///
+    /// Ticks outside of the range are ignored.
+    /// The ticks are placed correctly for [`Self::logarithmic`] sliders too.
+    ///
+    /// See also [`Self::tick`], [`Self::evenly_spaced_ticks`] and [`Self::snap_to_ticks`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut gain = 0.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut gain, -24.0..=12.0)
+    ///         .ticks(&[-12.0, 6.0])
+    ///         .tick(0.0, "0 dB")
+    ///         .snap_to_ticks(4.0),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn ticks(mut self, ticks: &[f64]) -> Self {
+        self.ticks
+            .extend(ticks.iter().map(|&value| SliderTick { value, label: None }));
+        self
+    }
+
+    /// Draw a tick mark on the rail at this value, with a label next to it.
+    #[inline]
+    pub fn tick(mut self, value: f64, label: impl Into<WidgetText>) -> Self {
+        self.ticks.push(SliderTick {
+            value,
+            label: Some(label.into()),
+        });
+        self
+    }
+
+    /// Draw this many tick marks, evenly spaced along the rail, including both ends.
+    ///
+    /// For [`Self::logarithmic`] sliders, the ticks are evenly spaced on screen,
+    /// not in value.
+    #[inline]
+    pub fn evenly_spaced_ticks(mut self, num_ticks: usize) -> Self {
+        self.num_evenly_spaced_ticks = num_ticks;
+        self
+    }
+
+    /// When dragging within `threshold` points of a tick, snap the value to that tick.
+    ///
+    /// Values between the ticks can still be picked freely.
+    /// With this set, the arrow keys move the value from one tick to the next.
+    ///
+    /// See [`Self::ticks`].
+    #[inline]
+    pub fn snap_to_ticks(mut self, threshold: f32) -> Self {
+        self.snap_to_ticks = Some(threshold);
+        self
+    }
+
     /// Set custom formatter defining how numbers are converted into text.
     ///
     /// A custom formatter takes a `f64` for the numeric value and a `RangeInclusive<usize>` representing
@@ -594,6 +752,27 @@ impl<'a> Slider<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Set `custom_formatter` and `custom_parser` from a [`NumberFormatter`],
+    /// e.g. to group the digits in thousands.
+    ///
+    /// The text the user enters is parsed with the same formatter,
+    /// so the displayed text can be edited as is.
+    ///
+    /// To do this for all widgets, set [`crate::Style::number_formatter`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut balance: f64 = 1234567.89;
+    /// use egui::style::NumberFormatter;
+    /// ui.add(egui::Slider::new(&mut balance, 0.0..=1e7).fixed_decimals(2).number_formatter(NumberFormatter::grouped('.', ','))); // Shows "1.234.567,89"
+    /// # });
+    /// ```
+    pub fn number_formatter(self, number_formatter: NumberFormatter) -> Self {
+        let parser = number_formatter.clone();
+        self.custom_formatter(move |n, decimals| number_formatter.format(n, decimals))
+            .custom_parser(move |s| parser.parse(s))
+    }
+
     /// Helper: equivalent to `self.precision(0).smallest_positive(1.0)`.
     /// If you use one of the integer constructors (e.g. `Slider::i32`) this is called for you,
     /// but if you want to have a slider for picking integer values in an `Slider::f64`, use this.
@@ -653,25 +832,120 @@ impl<'a> Slider<'a> {
 
 impl Slider<'_> {
     /// Just the slider, no text
-    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32) -> Response {
+    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32, label_space: f32) -> Response {
         let desired_size = match self.orientation {
-            SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
-            SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
+            SliderOrientation::Horizontal => {
+                vec2(ui.spacing().slider_width, thickness + label_space)
+            }
+            SliderOrientation::Vertical => vec2(thickness + label_space, ui.spacing().slider_width),
         };
         ui.allocate_response(desired_size, Sense::drag())
     }
 
+    /// The part of the slider without the tick labels,
+    /// which go below horizontal sliders and to the right of vertical ones.
+    fn rect_without_labels(&self, rect: Rect, label_space: f32) -> Rect {
+        let mut rect = rect;
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.max.y -= label_space,
+            SliderOrientation::Vertical => rect.max.x -= label_space,
+        }
+        rect
+    }
+
+    /// Lay out the tick labels, and return them with how much space they need.
+    fn tick_label_galleys(&self, ui: &Ui) -> (Vec<(f64, Arc<Galley>)>, f32) {
+        let galleys: Vec<(f64, Arc<Galley>)> = self
+            .ticks
+            .iter()
+            .filter(|tick| self.range().contains(&tick.value))
+            .filter_map(|tick| {
+                let galley = tick.label.clone()?.into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Small,
+                );
+                Some((tick.value, galley))
+            })
+            .collect();
+
+        let label_space = galleys
+            .iter()
+            .map(|(_, galley)| match self.orientation {
+                SliderOrientation::Horizontal => galley.size().y,
+                SliderOrientation::Vertical => galley.size().x + ui.spacing().item_spacing.x,
+            })
+            .fold(0.0, f32::max);
+
+        (galleys, label_space)
+    }
+
+    /// The values of all ticks within the range.
+    fn tick_values(&self) -> Vec<f64> {
+        let range = self.range();
+        let num_even = self.num_evenly_spaced_ticks;
+        let evenly_spaced = (0..num_even).map(|i| {
+            let normalized = if num_even == 1 {
+                0.5
+            } else {
+                i as f64 / (num_even - 1) as f64
+            };
+            value_from_normalized(normalized, range.clone(), &self.spec)
+        });
+        self.ticks
+            .iter()
+            .map(|tick| tick.value)
+            .chain(evenly_spaced)
+            .filter(|value| range.contains(value))
+            .collect()
+    }
+
+    /// The position of the tick closest to `position`, if any.
+    fn closest_tick_position(&self, position: f32, position_range: Rangef) -> Option<(f64, f32)> {
+        self.tick_values()
+            .into_iter()
+            .map(|value| (value, self.position_from_value(value, position_range)))
+            .min_by(|(_, a), (_, b)| (a - position).abs().total_cmp(&(b - position).abs()))
+    }
+
+    /// The value of the next tick after `position`, in the direction of `direction`.
+    fn next_tick_value(
+        &self,
+        position: f32,
+        direction: f32,
+        position_range: Rangef,
+    ) -> Option<f64> {
+        self.tick_values()
+            .into_iter()
+            .map(|value| {
+                let distance =
+                    direction * (self.position_from_value(value, position_range) - position);
+                (value, distance)
+            })
+            // Ignore ticks we are already at:
+            .filter(|(_, distance)| 0.5 < *distance)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(value, _)| value)
+    }
+
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
-        let rect = &response.rect;
+    fn slider_ui(
+        &mut self,
+        ui: &Ui,
+        response: &Response,
+        tick_labels: &[(f64, Arc<Galley>)],
+        label_space: f32,
+    ) {
+        let rect = &self.rect_without_labels(response.rect, label_space);
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
-        let position_range = self.position_range(rect, &handle_shape);
+        let position_range = self.orientation.position_range(rect, &handle_shape);
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
-            let position = self.pointer_position(pointer_position_2d);
-            let new_value = if self.smart_aim {
+            let position = self.orientation.pointer_position(pointer_position_2d);
+            let mut new_value = if self.smart_aim {
                 let aim_radius = ui.input(|i| i.aim_radius());
                 emath::smart_aim::best_in_range_f64(
                     self.value_from_position(position - aim_radius, position_range),
@@ -680,6 +954,15 @@ impl Slider<'_> {
             } else {
                 self.value_from_position(position, position_range)
             };
+            if let Some(threshold) = self.snap_to_ticks {
+                if let Some((tick_value, tick_position)) =
+                    self.closest_tick_position(position, position_range)
+                {
+                    if (tick_position - position).abs() <= threshold {
+                        new_value = tick_value;
+                    }
+                }
+            }
             self.set_value(new_value);
         }
 
@@ -727,7 +1010,25 @@ impl Slider<'_> {
 
         let kb_step = increment as f32 - decrement as f32;
 
-        if kb_step != 0.0 {
+        let mut kb_step_done = false;
+        if kb_step != 0.0 && self.snap_to_ticks.is_some() {
+            // Jump from tick to tick:
+            let direction = kb_step.signum();
+            let mut value = self.get_value();
+            for _ in 0..(kb_step.abs() as usize) {
+                let position = self.position_from_value(value, position_range);
+                if let Some(tick_value) = self.next_tick_value(position, direction, position_range)
+                {
+                    value = tick_value;
+                    kb_step_done = true;
+                }
+            }
+            if kb_step_done {
+                self.set_value(value);
+            }
+        }
+
+        if kb_step != 0.0 && !kb_step_done {
             let ui_point_per_step = 1.0; // move this many ui points for each kb_step
             let prev_value = self.get_value();
             let prev_position = self.position_from_value(prev_value, position_range);
@@ -780,14 +1081,14 @@ impl Slider<'_> {
             let spacing = &ui.style().spacing;
 
             let rail_radius = (spacing.slider_rail_height / 2.0).at_least(0.0);
-            let rail_rect = self.rail_rect(rect, rail_radius);
+            let rail_rect = self.orientation.rail_rect(rect, rail_radius);
             let corner_radius = widget_visuals.inactive.corner_radius;
 
             ui.painter()
                 .rect_filled(rail_rect, corner_radius, widget_visuals.inactive.bg_fill);
 
             let position_1d = self.position_from_value(value, position_range);
-            let center = self.marker_center(position_1d, &rail_rect);
+            let center = self.orientation.marker_center(position_1d, &rail_rect);
 
             // Decide if we should add trailing fill.
             let trailing_fill = self
@@ -815,88 +1116,68 @@ impl Slider<'_> {
                 );
             }
 
-            let radius = self.handle_radius(rect);
+            self.paint_ticks(ui, response.rect, &rail_rect, position_range, tick_labels);
+
+            let radius = self.orientation.handle_radius(rect);
 
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            match handle_shape {
-                style::HandleShape::Circle => {
-                    ui.painter().add(epaint::CircleShape {
-                        center,
-                        radius: radius + visuals.expansion,
-                        fill: visuals.bg_fill,
-                        stroke: visuals.fg_stroke,
-                    });
-                }
-                style::HandleShape::Rect { aspect_ratio } => {
-                    let v = match self.orientation {
-                        SliderOrientation::Horizontal => Vec2::new(radius * aspect_ratio, radius),
-                        SliderOrientation::Vertical => Vec2::new(radius, radius * aspect_ratio),
-                    };
-                    let v = v + Vec2::splat(visuals.expansion);
-                    let rect = Rect::from_center_size(center, 2.0 * v);
-                    ui.painter().rect(
-                        rect,
-                        visuals.corner_radius,
-                        visuals.bg_fill,
-                        visuals.fg_stroke,
-                        epaint::StrokeKind::Inside,
-                    );
-                }
-            }
+            self.orientation
+                .paint_handle(ui, center, radius, handle_shape, visuals);
         }
     }
 
-    fn marker_center(&self, position_1d: f32, rail_rect: &Rect) -> Pos2 {
-        match self.orientation {
-            SliderOrientation::Horizontal => pos2(position_1d, rail_rect.center().y),
-            SliderOrientation::Vertical => pos2(rail_rect.center().x, position_1d),
-        }
-    }
-
-    fn pointer_position(&self, pointer_position_2d: Pos2) -> f32 {
-        match self.orientation {
-            SliderOrientation::Horizontal => pointer_position_2d.x,
-            SliderOrientation::Vertical => pointer_position_2d.y,
-        }
-    }
-
-    fn position_range(&self, rect: &Rect, handle_shape: &style::HandleShape) -> Rangef {
-        let handle_radius = self.handle_radius(rect);
-        let handle_radius = match handle_shape {
-            style::HandleShape::Circle => handle_radius,
-            style::HandleShape::Rect { aspect_ratio } => handle_radius * aspect_ratio,
-        };
-        match self.orientation {
-            SliderOrientation::Horizontal => rect.x_range().shrink(handle_radius),
-            // The vertical case has to be flipped because the largest slider value maps to the
-            // lowest y value (which is at the top)
-            SliderOrientation::Vertical => rect.y_range().shrink(handle_radius).flip(),
+    fn paint_ticks(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        rail_rect: &Rect,
+        position_range: Rangef,
+        tick_labels: &[(f64, Arc<Galley>)],
+    ) {
+        let stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+        let tick_length = match self.orientation {
+            SliderOrientation::Horizontal => rail_rect.height(),
+            SliderOrientation::Vertical => rail_rect.width(),
+        } + 6.0;
+
+        for value in self.tick_values() {
+            let center = self
+                .orientation
+                .marker_center(self.position_from_value(value, position_range), rail_rect);
+            let half_tick = match self.orientation {
+                SliderOrientation::Horizontal => vec2(0.0, 0.5 * tick_length),
+                SliderOrientation::Vertical => vec2(0.5 * tick_length, 0.0),
+            };
+            ui.painter()
+                .line_segment([center - half_tick, center + half_tick], stroke);
         }
-    }
 
-    fn rail_rect(&self, rect: &Rect, radius: f32) -> Rect {
-        match self.orientation {
-            SliderOrientation::Horizontal => Rect::from_min_max(
-                pos2(rect.left(), rect.center().y - radius),
-                pos2(rect.right(), rect.center().y + radius),
-            ),
-            SliderOrientation::Vertical => Rect::from_min_max(
-                pos2(rect.center().x - radius, rect.top()),
-                pos2(rect.center().x + radius, rect.bottom()),
-            ),
+        for (value, galley) in tick_labels {
+            let center = self
+                .orientation
+                .marker_center(self.position_from_value(*value, position_range), rail_rect);
+            let size = galley.size();
+            let label_rect = match self.orientation {
+                SliderOrientation::Horizontal => {
+                    let x = (center.x - 0.5 * size.x)
+                        .at_most(rect.right() - size.x)
+                        .at_least(rect.left());
+                    Rect::from_min_size(pos2(x, rect.bottom() - size.y), size)
+                }
+                SliderOrientation::Vertical => {
+                    let y = (center.y - 0.5 * size.y)
+                        .at_most(rect.bottom() - size.y)
+                        .at_least(rect.top());
+                    Rect::from_min_size(pos2(rect.right() - size.x, y), size)
+                }
+            };
+            ui.painter()
+                .galley(label_rect.min, galley.clone(), stroke.color);
         }
     }
 
-    fn handle_radius(&self, rect: &Rect) -> f32 {
-        let limit = match self.orientation {
-            SliderOrientation::Horizontal => rect.height(),
-            SliderOrientation::Vertical => rect.width(),
-        };
-        limit / 2.5
-    }
-
     fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef) -> Response {
         // If [`DragValue`] is controlled from the keyboard and `step` is defined, set speed to `step`
         let change = ui.input(|input| {
@@ -969,8 +1250,9 @@ impl Slider<'_> {
         let thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
-        let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
+        let (tick_labels, label_space) = self.tick_label_galleys(ui);
+        let mut response = self.allocate_slider_space(ui, thickness, label_space);
+        self.slider_ui(ui, &response, &tick_labels, label_space);
 
         let value = self.get_value();
         if value != old_value {
@@ -1007,7 +1289,8 @@ impl Slider<'_> {
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
+            let slider_rect = self.rect_without_labels(response.rect, label_space);
+            let position_range = self.orientation.position_range(&slider_rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -1065,7 +1348,11 @@ const INFINITY: f64 = f64::INFINITY;
 /// give a scale that this many orders of magnitude in size.
 const INF_RANGE_MAGNITUDE: f64 = 10.0;
 
-fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(super) fn value_from_normalized(
+    normalized: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -1117,7 +1404,11 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
     }
 }
 
-fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(super) fn normalized_from_value(
+    value: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
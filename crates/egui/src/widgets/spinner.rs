@@ -1,19 +1,53 @@
-use epaint::{Color32, Pos2, Rect, Shape, Stroke, emath::lerp, vec2};
+use std::time::Duration;
+
+use epaint::{Color32, Pos2, Rect, Shape, Stroke, emath::lerp, pos2, vec2};
 
 use crate::{Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
 
+/// How a [`Spinner`] looks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SpinnerStyle {
+    /// An arc that spins around and grows and shrinks.
+    #[default]
+    Arc,
+
+    /// A ring of dots, fading out behind the brightest one.
+    Dots,
+
+    /// Three bars bouncing up and down.
+    Bars,
+}
+
 /// A spinner widget used to indicate loading.
 ///
+/// The animation pauses while the [`Ui`] is disabled.
+///
 /// See also: [`crate::ProgressBar`].
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
-#[derive(Default)]
 pub struct Spinner {
     /// Uses the style's `interact_size` if `None`.
     size: Option<f32>,
     color: Option<Color32>,
+    style: SpinnerStyle,
+    speed: f32,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self {
+            size: None,
+            color: None,
+            style: SpinnerStyle::default(),
+            speed: 1.0,
+        }
+    }
 }
 
 impl Spinner {
+    /// How often an animated spinner asks for a repaint.
+    const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
     /// Create a new spinner that uses the style's `interact_size` unless changed.
     pub fn new() -> Self {
         Self::default()
@@ -28,34 +62,51 @@ impl Spinner {
     }
 
     /// Sets the spinner's color.
+    ///
+    /// Default: [`crate::Visuals::strong_text_color`].
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         self.color = Some(color.into());
         self
     }
 
+    /// Sets how the spinner looks. Default: [`SpinnerStyle::Arc`].
+    #[inline]
+    pub fn style(mut self, style: SpinnerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Scales how fast the spinner animates, e.g. `2.0` for twice as fast.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
     /// Paint the spinner in the given rectangle.
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
-            ui.ctx().request_repaint(); // because it is animated
+            let time = if ui.is_enabled() {
+                // Bounded to the frame rate the animation needs:
+                ui.ctx().request_repaint_after(Self::FRAME_INTERVAL);
+                ui.input(|i| i.time) * self.speed as f64
+            } else {
+                // A static frame, chosen so that every style shows something:
+                1.0
+            };
 
             let color = self
                 .color
                 .unwrap_or_else(|| ui.visuals().strong_text_color());
-            let radius = (rect.height() / 2.0) - 2.0;
-            let n_points = (radius.round() as u32).clamp(8, 128);
-            let time = ui.input(|i| i.time);
-            let start_angle = time * std::f64::consts::TAU;
-            let end_angle = start_angle + 240f64.to_radians() * time.sin();
-            let points: Vec<Pos2> = (0..n_points)
-                .map(|i| {
-                    let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
-                    let (sin, cos) = angle.sin_cos();
-                    rect.center() + radius * vec2(cos as f32, sin as f32)
-                })
-                .collect();
-            ui.painter()
-                .add(Shape::line(points, Stroke::new(3.0, color)));
+            let shapes = match self.style {
+                SpinnerStyle::Arc => vec![arc(rect, time, color)],
+                SpinnerStyle::Dots => dots(rect, time, color),
+                SpinnerStyle::Bars => bars(rect, time, color),
+            };
+            ui.painter().extend(shapes);
         }
     }
 }
@@ -72,3 +123,53 @@ impl Widget for Spinner {
         response
     }
 }
+
+fn arc(rect: Rect, time: f64, color: Color32) -> Shape {
+    let radius = (rect.height() / 2.0) - 2.0;
+    let n_points = (radius.round() as u32).clamp(8, 128);
+    let start_angle = time * std::f64::consts::TAU;
+    let end_angle = start_angle + 240f64.to_radians() * time.sin();
+    let points: Vec<Pos2> = (0..n_points)
+        .map(|i| {
+            let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
+            let (sin, cos) = angle.sin_cos();
+            rect.center() + radius * vec2(cos as f32, sin as f32)
+        })
+        .collect();
+    Shape::line(points, Stroke::new(3.0, color))
+}
+
+fn dots(rect: Rect, time: f64, color: Color32) -> Vec<Shape> {
+    const N_DOTS: usize = 8;
+    let dot_radius = rect.height() / 10.0;
+    let ring_radius = rect.height() / 2.0 - dot_radius;
+    // The brightest dot goes around the ring once per second:
+    let lead = time.rem_euclid(1.0) as f32 * N_DOTS as f32;
+    (0..N_DOTS)
+        .map(|i| {
+            let angle = i as f32 / N_DOTS as f32 * std::f32::consts::TAU;
+            let center = rect.center() + ring_radius * vec2(angle.cos(), angle.sin());
+            let behind = (lead - i as f32).rem_euclid(N_DOTS as f32) / N_DOTS as f32;
+            let opacity = lerp(1.0..=0.15, behind);
+            Shape::circle_filled(center, dot_radius, color.gamma_multiply(opacity))
+        })
+        .collect()
+}
+
+fn bars(rect: Rect, time: f64, color: Color32) -> Vec<Shape> {
+    const N_BARS: usize = 3;
+    // Bars and the gaps between them are equally wide:
+    let bar_width = rect.width() / (2 * N_BARS - 1) as f32;
+    (0..N_BARS)
+        .map(|i| {
+            let phase = time * std::f64::consts::TAU - i as f64 * 0.8;
+            let height = lerp(0.3..=1.0, 0.5 + 0.5 * phase.sin() as f32) * rect.height();
+            let left = rect.left() + 2.0 * i as f32 * bar_width;
+            let bar = Rect::from_center_size(
+                pos2(left + bar_width / 2.0, rect.center().y),
+                vec2(bar_width, height),
+            );
+            Shape::rect_filled(bar, bar_width / 4.0, color)
+        })
+        .collect()
+}
@@ -7,17 +7,26 @@ use epaint::{
 };
 
 use crate::{
-    Align, Align2, Color32, Context, CursorIcon, Event, EventFilter, FontSelection, Id, ImeEvent,
-    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape, TextBuffer,
-    TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState, epaint,
+    Align, Align2, Color32, Context, CornerRadius, CursorIcon, Event, EventFilter, FontId,
+    FontSelection, Id, ImeEvent, Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Pos2,
+    Response, Sense, Shape, Stroke, TextBuffer, TextStyle, TextWrapMode, Ui, Vec2, Widget,
+    WidgetInfo, WidgetText, WidgetType, WidgetWithState, epaint,
     os::OperatingSystem,
     output::OutputEvent,
-    response, text_selection,
-    text_selection::{CCursorRange, text_cursor_state::cursor_rect, visuals::paint_text_selection},
+    pos2, response, text_selection,
+    text_selection::{
+        CCursorRange,
+        text_cursor_state::{cursor_rect, select_paragraph_at},
+        visuals::paint_text_selection,
+    },
     vec2,
 };
 
-use super::{TextEditOutput, TextEditState};
+use super::{
+    TextEditOutput, TextEditState,
+    text_buffer::truncate_to_limits,
+    virtualized::{LineIndex, WindowText, handle_document_events, window_lines},
+};
 
 type LayouterFn<'t> = &'t mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>;
 
@@ -75,6 +84,9 @@ pub struct TextEdit<'t> {
     text_color: Option<Color32>,
     layouter: Option<LayouterFn<'t>>,
     password: bool,
+    password_char: char,
+    password_reveal_button: bool,
+    password_reveal: PasswordReveal,
     frame: bool,
     margin: Margin,
     multiline: bool,
@@ -87,8 +99,22 @@ pub struct TextEdit<'t> {
     align: Align2,
     clip_text: bool,
     char_limit: usize,
+    byte_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    show_line_numbers: bool,
+    virtualized: bool,
+}
+
+/// How the [`TextEdit::password_reveal_button`] reveals the password.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PasswordReveal {
+    /// A click reveals the password, and another click hides it again.
+    #[default]
+    Toggle,
+
+    /// The password is only revealed while the button is held down.
+    Hold,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -128,6 +154,9 @@ impl<'t> TextEdit<'t> {
             text_color: None,
             layouter: None,
             password: false,
+            password_char: epaint::text::PASSWORD_REPLACEMENT_CHAR,
+            password_reveal_button: false,
+            password_reveal: PasswordReveal::default(),
             frame: true,
             margin: Margin::symmetric(4, 2),
             multiline: true,
@@ -146,8 +175,11 @@ impl<'t> TextEdit<'t> {
             align: Align2::LEFT_TOP,
             clip_text: false,
             char_limit: usize::MAX,
+            byte_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            show_line_numbers: false,
+            virtualized: false,
         }
     }
 
@@ -229,6 +261,49 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// The character that each letter of a [`Self::password`] is shown as.
+    ///
+    /// Default: `•`.
+    #[inline]
+    pub fn password_char(mut self, password_char: char) -> Self {
+        self.password_char = password_char;
+        self
+    }
+
+    /// Show an eye button at the end of a [`Self::password`] field, to reveal the text.
+    ///
+    /// The button can be clicked, or focused with the tab key and pressed with space.
+    /// While the text is revealed, it can also be copied.
+    /// See [`Self::password_reveal`] for how long the text stays revealed.
+    ///
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut password = String::new();
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut password)
+    ///         .password(true)
+    ///         .password_reveal_button(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn password_reveal_button(mut self, password_reveal_button: bool) -> Self {
+        self.password_reveal_button = password_reveal_button;
+        self
+    }
+
+    /// Does the [`Self::password_reveal_button`] reveal the text until it is clicked again,
+    /// or only while it is held down?
+    ///
+    /// Default: [`PasswordReveal::Toggle`].
+    #[inline]
+    pub fn password_reveal(mut self, password_reveal: PasswordReveal) -> Self {
+        self.password_reveal = password_reveal;
+        self
+    }
+
     /// Pick a [`crate::FontId`] or [`TextStyle`].
     #[inline]
     pub fn font(mut self, font_selection: impl Into<FontSelection>) -> Self {
@@ -355,15 +430,30 @@ impl<'t> TextEdit<'t> {
         self
     }
 
-    /// Sets the limit for the amount of characters can be entered
+    /// Sets the limit for the amount of characters can be entered.
+    ///
+    /// Text that is typed or pasted is truncated to fit, but never in the middle of a grapheme cluster
+    /// (so a multi-codepoint emoji is either inserted whole, or not at all).
+    /// An IME composition is allowed to exceed the limit until it is committed.
     ///
-    /// This only works for singleline [`TextEdit`]
+    /// Check [`TextEditOutput::truncated`] to see if the user input was truncated.
+    ///
+    /// The limit is only enforced on user input: text that is already longer is left as-is.
     #[inline]
     pub fn char_limit(mut self, limit: usize) -> Self {
         self.char_limit = limit;
         self
     }
 
+    /// Sets the limit for the length of the text in bytes (of UTF-8).
+    ///
+    /// This is enforced the same way as [`Self::char_limit`], and both limits can be combined.
+    #[inline]
+    pub fn byte_limit(mut self, limit: usize) -> Self {
+        self.byte_limit = limit;
+        self
+    }
+
     /// Set the horizontal align of the inner text.
     #[inline]
     pub fn horizontal_align(mut self, align: Align) -> Self {
@@ -396,6 +486,54 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// Show line numbers in a gutter to the left of the text. Default is `false`.
+    ///
+    /// There is one number per line of text (i.e. per `\n`), not per wrapped row.
+    /// The numbers use a monospace font of the same size as the text.
+    /// Clicking a line number selects that line.
+    ///
+    /// This only works for multiline [`TextEdit`].
+    #[inline]
+    pub fn show_line_numbers(mut self, show_line_numbers: bool) -> Self {
+        self.show_line_numbers = show_line_numbers;
+        self
+    }
+
+    /// Only lay out and paint the lines that are visible (plus a few more). Default is `false`.
+    ///
+    /// Use this for very large texts (e.g. log files of several megabytes) in a [`crate::ScrollArea`],
+    /// where laying out all of the text each frame would be too slow.
+    ///
+    /// This comes with some restrictions:
+    /// * The text is not wrapped, and each line is assumed to be one row of the font height.
+    /// * The width of the text is estimated from the longest line.
+    /// * The [`TextEditOutput::galley`] only contains the laid out lines,
+    ///   starting at [`TextEditOutput::galley_char_offset`].
+    /// * Undo and redo are not supported, because the undoer keeps a copy of the whole text
+    ///   for each undo point, which is what we want to avoid for a very large text.
+    ///
+    /// The custom [`Self::layouter`], if any, will be called with the laid out lines only.
+    ///
+    /// This only works for multiline [`TextEdit`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let log = "A very long log…\n".repeat(100);
+    /// egui::ScrollArea::both().show(ui, |ui| {
+    ///     ui.add(
+    ///         egui::TextEdit::multiline(&mut log.as_str())
+    ///             .code_editor()
+    ///             .virtualized(true),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn virtualized(mut self, virtualized: bool) -> Self {
+        self.virtualized = virtualized;
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -479,6 +617,9 @@ impl TextEdit<'_> {
             text_color,
             layouter,
             password,
+            password_char,
+            password_reveal_button,
+            password_reveal,
             frame: _,
             margin,
             multiline,
@@ -491,22 +632,76 @@ impl TextEdit<'_> {
             align,
             clip_text,
             char_limit,
+            byte_limit,
             return_key,
             background_color: _,
+            show_line_numbers,
+            virtualized,
         } = self;
 
+        let virtualized = virtualized && multiline;
+
+        // The password is shown if the reveal button says so, which we only know after the layout:
+        let masked = std::rc::Rc::new(std::cell::Cell::new(password));
+
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
             .unwrap_or_else(|| ui.visuals().widgets.inactive.text_color());
 
-        let prev_text = text.as_str().to_owned();
+        // A virtualized text edit can be huge, so there we only keep the laid out lines:
+        let mut prev_text = if virtualized {
+            String::new()
+        } else {
+            text.as_str().to_owned()
+        };
         let hint_text_str = hint_text.text().to_owned();
 
         let font_id = font_selection.resolve(ui.style());
         let row_height = ui.fonts(|f| f.row_height(&font_id));
+
+        // The id is needed before we allocate space, to find the lines of a virtualized text edit:
+        let id = id.unwrap_or_else(|| {
+            if let Some(id_salt) = id_salt {
+                ui.make_persistent_id(id_salt)
+            } else {
+                ui.next_auto_id() // Since we are only storing the cursor a persistent Id is not super important
+            }
+        });
+        let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
+
+        // Scanning a huge text for its lines is slow, so we keep them between frames,
+        // and update them as the text is edited.
+        // If the length changed, the text was replaced from the outside:
+        let mut line_index = virtualized.then(|| {
+            state
+                .line_index
+                .lock()
+                .take()
+                .filter(|lines| lines.num_bytes() == text.as_str().len())
+                .unwrap_or_else(|| LineIndex::new(text.as_str()))
+        });
+
+        let line_number_font_id = FontId::monospace(font_id.size);
+        let reveal_button_size = (password && password_reveal_button).then_some(row_height);
+        let reveal_width =
+            reveal_button_size.map_or(0.0, |size| size + ui.spacing().item_spacing.x);
+
+        let gutter_width = if show_line_numbers && multiline {
+            let num_lines = line_index.as_ref().map_or_else(
+                || text.as_str().matches('\n').count() + 1,
+                |lines| lines.num_lines(),
+            );
+            let num_digits = num_lines.to_string().len();
+            let digit_width = ui.fonts(|f| f.glyph_width(&line_number_font_id, '0'));
+            num_digits as f32 * digit_width + 2.0 * ui.spacing().item_spacing.x
+        } else {
+            0.0
+        };
+
         const MIN_WIDTH: f32 = 24.0; // Never make a [`TextEdit`] more narrow than this.
-        let available_width = (ui.available_width() - margin.sum().x).at_least(MIN_WIDTH);
+        let available_width = (ui.available_width() - margin.sum().x - gutter_width - reveal_width)
+            .at_least(MIN_WIDTH);
         let desired_width = desired_width.unwrap_or_else(|| ui.spacing().text_edit_width);
         let wrap_width = if ui.layout().horizontal_justify() {
             available_width
@@ -515,8 +710,10 @@ impl TextEdit<'_> {
         };
 
         let font_id_clone = font_id.clone();
+        let layouter_masked = masked.clone();
         let mut default_layouter = move |ui: &Ui, text: &dyn TextBuffer, wrap_width: f32| {
-            let text = mask_if_password(password, text.as_str());
+            let mask = layouter_masked.get().then_some(password_char);
+            let text = mask_if_password(mask, text.as_str());
             let layout_job = if multiline {
                 LayoutJob::simple(text, font_id_clone.clone(), text_color, wrap_width)
             } else {
@@ -527,27 +724,55 @@ impl TextEdit<'_> {
 
         let layouter = layouter.unwrap_or(&mut default_layouter);
 
-        let mut galley = layouter(ui, text, wrap_width);
+        // A virtualized text edit is laid out once we know what part of it is visible.
+        let galley = (!virtualized).then(|| layouter(ui, text, wrap_width));
+        let layout_width = if virtualized {
+            f32::INFINITY
+        } else {
+            wrap_width
+        };
+
+        let content_size = if let Some(lines) = &line_index {
+            // We haven't laid out all of the text, so we have to estimate its size:
+            let char_width = ui.fonts(|f| f.glyph_width(&font_id, '0'));
+            vec2(
+                lines.max_line_chars() as f32 * char_width,
+                lines.num_lines() as f32 * row_height,
+            )
+        } else {
+            galley.as_ref().map_or(Vec2::ZERO, |galley| galley.size())
+        };
 
         let desired_inner_width = if clip_text {
             wrap_width // visual clipping with scroll in singleline input.
         } else {
-            galley.size().x.max(wrap_width)
+            content_size.x.max(wrap_width)
         };
         let desired_height = (desired_height_rows.at_least(1) as f32) * row_height;
-        let desired_inner_size = vec2(desired_inner_width, galley.size().y.max(desired_height));
-        let desired_outer_size = (desired_inner_size + margin.sum()).at_least(min_size);
-        let (auto_id, outer_rect) = ui.allocate_space(desired_outer_size);
-        let rect = outer_rect - margin; // inner rect (excluding frame/margin).
-
-        let id = id.unwrap_or_else(|| {
-            if let Some(id_salt) = id_salt {
-                ui.make_persistent_id(id_salt)
+        let desired_inner_size = vec2(desired_inner_width, content_size.y.max(desired_height));
+        let desired_outer_size =
+            (desired_inner_size + margin.sum() + vec2(gutter_width + reveal_width, 0.0))
+                .at_least(min_size);
+        let (_, outer_rect) = ui.allocate_space(desired_outer_size);
+        let mut rect = outer_rect - margin; // inner rect (excluding frame/margin).
+        let gutter_rect = Rect::from_min_max(
+            outer_rect.min,
+            pos2(rect.left() + gutter_width, outer_rect.bottom()),
+        );
+        rect.min.x += gutter_width;
+        rect.max.x -= reveal_width;
+        let reveal_button_rect = reveal_button_size.map(|size| {
+            let column = Rect::from_x_y_ranges(
+                rect.right() + reveal_width - size..=rect.right() + reveal_width,
+                rect.y_range(),
+            );
+            let align = if multiline {
+                Align2::CENTER_TOP
             } else {
-                auto_id // Since we are only storing the cursor a persistent Id is not super important
-            }
+                Align2::CENTER_CENTER
+            };
+            align.align_size_within_rect(Vec2::splat(size), column)
         });
-        let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
 
         // On touch screens (e.g. mobile in `eframe` web), should
         // dragging select text, or scroll the enclosing [`ScrollArea`] (if any)?
@@ -566,13 +791,129 @@ impl TextEdit<'_> {
             Sense::hover()
         };
         let mut response = ui.interact(outer_rect, id, sense);
-        response.intrinsic_size = Some(Vec2::new(desired_width, desired_outer_size.y));
+        response.intrinsic_size = Some(Vec2::new(
+            desired_width + gutter_width + reveal_width,
+            desired_outer_size.y,
+        ));
+
+        // On top of the text edit, so it gets the clicks:
+        let reveal_button = reveal_button_rect
+            .map(|rect| ui.interact(rect, id.with("password_reveal"), Sense::click()));
+        if let Some(button) = &reveal_button {
+            let revealed = match password_reveal {
+                PasswordReveal::Toggle => {
+                    if button.clicked() {
+                        state.password_revealed = !state.password_revealed;
+                    }
+                    state.password_revealed
+                }
+                PasswordReveal::Hold => {
+                    button.is_pointer_button_down_on()
+                        || (button.has_focus()
+                            && ui.input(|i| i.key_down(Key::Space) || i.key_down(Key::Enter)))
+                }
+            };
+            masked.set(!revealed);
+        }
+        let masked = masked.get();
+        let password_mask = masked.then_some(password_char);
 
         // Don't sent `OutputEvent::Clicked` when a user presses the space bar
         response.flags -= response::Flags::FAKE_PRIMARY_CLICKED;
         let text_clip_rect = rect;
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
+        // For a virtualized text edit we lay out, paint and edit a window of lines around the visible ones,
+        // and convert the cursor between whole-text and window coordinates.
+        let mut virtual_window = None;
+        let mut pending_events = None;
+        let mut full_cursor_range = None;
+        let mut document_cursor_changed = false;
+        if let Some(lines) = &mut line_index {
+            let clip_rect = ui.clip_rect();
+            let line_at_y = |y: f32| {
+                ((y - rect.top()) / row_height)
+                    .at_least(0.0)
+                    .at_most(lines.num_lines() as f32) as usize
+            };
+            let visible_lines = line_at_y(clip_rect.top())..line_at_y(clip_rect.bottom()) + 1;
+            let window_for_cursor = |lines: &LineIndex, cursor_range: Option<&CCursorRange>| {
+                let cursor_line = cursor_range.map(|r| lines.line_of_char(r.primary.index));
+                lines.window(window_lines(lines, visible_lines.clone(), cursor_line))
+            };
+
+            // The text may have been changed from the outside without changing its length:
+            if !lines.matches(
+                text.as_str(),
+                &window_for_cursor(lines, state.cursor.char_range().as_ref()),
+            ) {
+                *lines = LineIndex::new(text.as_str());
+            }
+
+            let clamp = |lines: &LineIndex, cursor: CCursor| CCursor {
+                index: cursor.index.min(lines.num_chars()),
+                ..cursor
+            };
+            let mut cursor_range = state.cursor.char_range().map(|r| CCursorRange {
+                primary: clamp(lines, r.primary),
+                secondary: clamp(lines, r.secondary),
+                h_pos: r.h_pos,
+            });
+
+            let mut cursor_moves = false;
+            if interactive && ui.memory(|mem| mem.has_focus(id)) {
+                let mut focused_cursor_range = cursor_range.unwrap_or(if cursor_at_end {
+                    CCursorRange::one(CCursor::new(lines.num_chars()))
+                } else {
+                    CCursorRange::default()
+                });
+                let input_events = ui.input(|i| i.filtered_events(&event_filter));
+                let (input_events, changed) = handle_document_events(
+                    ui,
+                    input_events,
+                    text,
+                    lines,
+                    &mut focused_cursor_range,
+                    |lines, cursor_range| window_for_cursor(lines, Some(cursor_range)),
+                    masked,
+                    return_key,
+                );
+                if changed {
+                    response.mark_changed();
+                }
+                document_cursor_changed = cursor_range.is_some_and(|r| r != focused_cursor_range);
+                cursor_moves = document_cursor_changed || !input_events.is_empty();
+                cursor_range = Some(focused_cursor_range);
+                pending_events = Some(input_events);
+            }
+
+            let window = window_for_cursor(lines, cursor_range.as_ref().filter(|_| cursor_moves));
+            state
+                .cursor
+                .set_char_range(cursor_range.map(|r| window.range_to_local(r)));
+            state.ime_cursor_range = window.range_to_local(state.ime_cursor_range);
+            state.ime_preedit_range = state.ime_preedit_range.map(|r| window.range_to_local(r));
+            full_cursor_range = cursor_range;
+
+            // The window is only copied if it is edited, see below.
+            virtual_window = Some((window, None));
+        }
+
+        let mut galley = if let Some((window, window_text)) = &virtual_window {
+            match window_text {
+                Some(window_text) => layouter(ui, window_text, layout_width),
+                None => layouter(ui, &&text.as_str()[window.bytes.clone()], layout_width),
+            }
+        } else if masked != password {
+            // Revealed by the button:
+            layouter(ui, text, wrap_width)
+        } else {
+            galley.unwrap_or_else(|| layouter(ui, text, wrap_width))
+        };
+        let galley_offset = virtual_window.as_ref().map_or(Vec2::ZERO, |(window, _)| {
+            vec2(0.0, window.lines.start as f32 * row_height)
+        });
+
         if interactive {
             if let Some(pointer_pos) = response.interact_pointer_pos() {
                 if response.hovered() && text.is_mutable() {
@@ -582,27 +923,38 @@ impl TextEdit<'_> {
                 // TODO(emilk): drag selected text to either move or clone (ctrl on windows, alt on mac)
 
                 let singleline_offset = vec2(state.singleline_offset, 0.0);
-                let cursor_at_pointer =
-                    galley.cursor_from_pos(pointer_pos - rect.min + singleline_offset);
+                let cursor_at_pointer = galley
+                    .cursor_from_pos(pointer_pos - rect.min - galley_offset + singleline_offset);
 
                 if ui.visuals().text_cursor.preview
                     && response.hovered()
                     && ui.input(|i| i.pointer.is_moving())
                 {
                     // text cursor preview:
-                    let cursor_rect = TSTransform::from_translation(rect.min.to_vec2())
-                        * cursor_rect(&galley, &cursor_at_pointer, row_height);
+                    let cursor_rect =
+                        TSTransform::from_translation(rect.min.to_vec2() + galley_offset)
+                            * cursor_rect(&galley, &cursor_at_pointer, row_height);
                     text_selection::visuals::paint_cursor_end(&painter, ui.visuals(), cursor_rect);
                 }
 
                 let is_being_dragged = ui.ctx().is_being_dragged(response.id);
-                let did_interact = state.cursor.pointer_interaction(
-                    ui,
-                    &response,
-                    cursor_at_pointer,
-                    &galley,
-                    is_being_dragged,
-                );
+                let pressed_line_number = gutter_width > 0.0
+                    && gutter_rect.contains(pointer_pos)
+                    && response.hovered()
+                    && ui.input(|i| i.pointer.any_pressed());
+                let did_interact = if pressed_line_number {
+                    let line_range = select_paragraph_at(galley.text(), cursor_at_pointer);
+                    state.cursor.set_char_range(Some(line_range));
+                    true
+                } else {
+                    state.cursor.pointer_interaction(
+                        ui,
+                        &response,
+                        cursor_at_pointer,
+                        &galley,
+                        is_being_dragged,
+                    )
+                };
 
                 if did_interact || response.clicked() {
                     ui.memory_mut(|mem| mem.request_focus(response.id));
@@ -617,6 +969,7 @@ impl TextEdit<'_> {
         }
 
         let mut cursor_range = None;
+        let mut truncated = false;
         let prev_cursor_range = state.cursor.range(&galley);
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
@@ -627,32 +980,82 @@ impl TextEdit<'_> {
                 CCursorRange::default()
             };
 
-            let (changed, new_cursor_range) = events(
+            let input_events = pending_events
+                .take()
+                .unwrap_or_else(|| ui.input(|i| i.filtered_events(&event_filter)));
+
+            let (buffer, char_limit, byte_limit): (&mut dyn TextBuffer, _, _) =
+                match (&mut virtual_window, &line_index) {
+                    (Some((window, window_text)), Some(lines)) => {
+                        // We edit a copy of the window, and splice it back into the whole text below:
+                        let window_text = window_text.insert(WindowText {
+                            text: text.as_str()[window.bytes.clone()].to_owned(),
+                            is_mutable: text.is_mutable(),
+                        });
+                        if !input_events.is_empty() {
+                            prev_text.clone_from(&window_text.text);
+                        }
+
+                        // The limits are for the whole text:
+                        let chars_outside = lines.num_chars() - window.chars.len();
+                        let bytes_outside = text.as_str().len() - window.bytes.len();
+                        (
+                            window_text,
+                            char_limit.saturating_sub(chars_outside),
+                            byte_limit.saturating_sub(bytes_outside),
+                        )
+                    }
+                    _ => (&mut *text, char_limit, byte_limit),
+                };
+
+            let (changed, new_cursor_range, did_truncate) = events(
                 ui,
                 &mut state,
-                text,
+                buffer,
                 &mut galley,
                 layouter,
                 id,
-                wrap_width,
+                layout_width,
                 multiline,
-                password,
+                masked,
                 default_cursor_range,
                 char_limit,
-                event_filter,
+                byte_limit,
+                input_events,
+                !virtualized, // The undoer would copy the whole text
                 return_key,
             );
 
+            if changed {
+                if let (Some((window, Some(window_text))), Some(lines)) =
+                    (&virtual_window, &mut line_index)
+                {
+                    // Splice the edited lines back into the whole text:
+                    text.delete_char_range(window.chars.clone());
+                    text.insert_text(&window_text.text, window.chars.start);
+                    lines.replace(
+                        window.chars.clone(),
+                        window.bytes.clone(),
+                        &window_text.text,
+                    );
+                }
+            }
+
             if changed {
                 response.mark_changed();
             }
+            truncated = did_truncate;
             cursor_range = Some(new_cursor_range);
         }
 
-        let mut galley_pos = align
-            .align_size_within_rect(galley.size(), rect)
-            .intersect(rect) // limit pos to the response rect area
-            .min;
+        let mut galley_pos = if virtual_window.is_some() {
+            rect.min + galley_offset
+        } else {
+            align
+                .align_size_within_rect(galley.size(), rect)
+                .intersect(rect) // limit pos to the response rect area
+                .min
+        };
         let align_offset = rect.left() - galley_pos.x;
 
         // Visual clipping for singleline text editor with text larger than width
@@ -686,15 +1089,27 @@ impl TextEdit<'_> {
         let selection_changed = if let (Some(cursor_range), Some(prev_cursor_range)) =
             (cursor_range, prev_cursor_range)
         {
-            prev_cursor_range != cursor_range
+            document_cursor_changed || prev_cursor_range != cursor_range
         } else {
             false
         };
 
+        // The cursor of a virtualized text edit can be outside the laid out lines (if it was scrolled away from),
+        // in which case it is clamped to them, and should not be painted.
+        let cursor_in_window = match (&virtual_window, state.cursor.char_range()) {
+            (Some((window, _)), Some(cursor_range)) => {
+                window
+                    .range_to_full(cursor_range, full_cursor_range)
+                    .primary
+                    == window.to_full(cursor_range.primary)
+            }
+            _ => true,
+        };
+
         if ui.is_rect_visible(rect) {
             if text.as_str().is_empty() && !hint_text.is_empty() {
                 let hint_text_color = ui.visuals().weak_text_color();
-                let hint_text_font_id = hint_text_font.unwrap_or(font_id.into());
+                let hint_text_font_id = hint_text_font.unwrap_or(font_id.clone().into());
                 let galley = if multiline {
                     hint_text.into_galley(
                         ui,
@@ -719,7 +1134,9 @@ impl TextEdit<'_> {
 
             let has_focus = ui.memory(|mem| mem.has_focus(id));
 
-            if has_focus {
+            let ime_preedit_range = state.ime_preedit_range.filter(|_| state.ime_enabled);
+
+            if has_focus && ime_preedit_range.is_none() {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     // Add text selection rectangles to the galley:
                     paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
@@ -730,7 +1147,12 @@ impl TextEdit<'_> {
                 // Allocate additional space if edits were made this frame that changed the size. This is important so that,
                 // if there's a ScrollArea, it can properly scroll to the cursor.
                 // Condition `!clip_text` is important to avoid breaking layout for `TextEdit::singleline` (PR #5640)
-                let extra_size = galley.size() - rect.size();
+                let content_size = if let Some(lines) = &line_index {
+                    vec2(galley.size().x, lines.num_lines() as f32 * row_height)
+                } else {
+                    galley.size()
+                };
+                let extra_size = content_size - rect.size();
                 if extra_size.x > 0.0 || extra_size.y > 0.0 {
                     ui.allocate_rect(
                         Rect::from_min_size(outer_rect.max, extra_size),
@@ -741,7 +1163,38 @@ impl TextEdit<'_> {
 
             painter.galley(galley_pos, galley.clone(), text_color);
 
+            if gutter_width > 0.0 {
+                let current_row = (has_focus && cursor_in_window)
+                    .then(|| state.cursor.range(&galley))
+                    .flatten()
+                    .map(|cursor_range| galley.layout_from_cursor(cursor_range.primary).row);
+                paint_line_numbers(
+                    ui,
+                    &galley,
+                    galley_pos,
+                    gutter_rect,
+                    &line_number_font_id,
+                    current_row,
+                    virtual_window
+                        .as_ref()
+                        .map_or(0, |(window, _)| window.lines.start),
+                );
+            }
+
             if has_focus {
+                if let Some(preedit_range) = &ime_preedit_range {
+                    // The composition text is not committed yet, so we underline it instead of selecting it:
+                    text_selection::visuals::paint_ime_preedit(
+                        &painter,
+                        &galley,
+                        galley_pos,
+                        preedit_range,
+                        Stroke::new(1.0, text_color),
+                    );
+                }
+            }
+
+            if has_focus && cursor_in_window {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
                     let primary_cursor_rect =
                         cursor_rect(&galley, &cursor_range.primary, row_height)
@@ -778,10 +1231,17 @@ impl TextEdit<'_> {
                             .layer_transform_to_global(ui.layer_id())
                             .unwrap_or_default();
 
+                        // Keep the candidate window anchored at the start of the composition text,
+                        // so that it doesn't jump around while the user is typing:
+                        let ime_cursor_rect = ime_preedit_range.map_or(primary_cursor_rect, |r| {
+                            cursor_rect(&galley, &r.sorted_cursors()[0], row_height)
+                                .translate(galley_pos.to_vec2())
+                        });
+
                         ui.ctx().output_mut(|o| {
                             o.ime = Some(crate::output::IMEOutput {
                                 rect: to_global * rect,
-                                cursor_rect: to_global * primary_cursor_rect,
+                                cursor_rect: to_global * ime_cursor_rect,
                             });
                         });
                     }
@@ -789,9 +1249,41 @@ impl TextEdit<'_> {
             }
         }
 
+        if let Some(button) = &reveal_button {
+            let revealed = !masked;
+            button.widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::Button,
+                    ui.is_enabled(),
+                    revealed,
+                    "Show password",
+                )
+            });
+            if ui.is_rect_visible(button.rect) {
+                let visuals = ui.style().interact_selectable(button, revealed);
+                if revealed || button.hovered() || button.has_focus() {
+                    ui.painter().rect(
+                        button.rect.expand(visuals.expansion),
+                        visuals.corner_radius,
+                        visuals.weak_bg_fill,
+                        visuals.bg_stroke,
+                        StrokeKind::Inside,
+                    );
+                }
+                ui.painter().text(
+                    button.rect.center(),
+                    Align2::CENTER_CENTER,
+                    "👁",
+                    font_id.clone(),
+                    visuals.text_color(),
+                );
+            }
+        }
+
         // Ensures correct IME behavior when the text input area gains or loses focus.
         if state.ime_enabled && (response.gained_focus() || response.lost_focus()) {
             state.ime_enabled = false;
+            state.ime_preedit_range = None;
             if let Some(mut ccursor_range) = state.cursor.char_range() {
                 ccursor_range.secondary.index = ccursor_range.primary.index;
                 state.cursor.set_char_range(Some(ccursor_range));
@@ -799,14 +1291,38 @@ impl TextEdit<'_> {
             ui.input_mut(|i| i.events.retain(|e| !matches!(e, Event::Ime(_))));
         }
 
+        // Back to whole-text coordinates:
+        let mut galley_char_offset = 0;
+        if let Some((window, _)) = &virtual_window {
+            state.cursor.set_char_range(
+                state
+                    .cursor
+                    .char_range()
+                    .map(|r| window.range_to_full(r, full_cursor_range)),
+            );
+            state.ime_cursor_range = window.range_to_full(state.ime_cursor_range, None);
+            state.ime_preedit_range = state
+                .ime_preedit_range
+                .map(|r| window.range_to_full(r, None));
+            galley_char_offset = window.chars.start;
+        }
+
+        *state.line_index.lock() = line_index;
         state.clone().store(ui.ctx(), id);
 
+        // For a virtualized text edit we only report the laid out lines, for the same reason we only lay them out:
+        let text_for_info = match &virtual_window {
+            Some((_, Some(window_text))) => window_text.text.as_str(),
+            Some((window, None)) => &text.as_str()[window.bytes.clone()],
+            None => text.as_str(),
+        };
+
         if response.changed() {
             response.widget_info(|| {
                 WidgetInfo::text_edit(
                     ui.is_enabled(),
-                    mask_if_password(password, prev_text.as_str()),
-                    mask_if_password(password, text.as_str()),
+                    mask_if_password(password_mask, prev_text.as_str()),
+                    mask_if_password(password_mask, text_for_info),
                     hint_text_str.as_str(),
                 )
             });
@@ -816,15 +1332,15 @@ impl TextEdit<'_> {
             let info = WidgetInfo::text_selection_changed(
                 ui.is_enabled(),
                 char_range,
-                mask_if_password(password, text.as_str()),
+                mask_if_password(password_mask, text_for_info),
             );
             response.output_event(OutputEvent::TextSelectionChanged(info));
         } else {
             response.widget_info(|| {
                 WidgetInfo::text_edit(
                     ui.is_enabled(),
-                    mask_if_password(password, prev_text.as_str()),
-                    mask_if_password(password, text.as_str()),
+                    mask_if_password(password_mask, prev_text.as_str()),
+                    mask_if_password(password_mask, text_for_info),
                     hint_text_str.as_str(),
                 )
             });
@@ -854,24 +1370,89 @@ impl TextEdit<'_> {
             response,
             galley,
             galley_pos,
+            galley_char_offset,
             text_clip_rect,
+            cursor_range: match &virtual_window {
+                Some((window, _)) => {
+                    cursor_range.map(|r| window.range_to_full(r, full_cursor_range))
+                }
+                None => cursor_range,
+            },
             state,
-            cursor_range,
+            truncated,
         }
     }
 }
 
-fn mask_if_password(is_password: bool, text: &str) -> String {
-    fn mask_password(text: &str) -> String {
-        std::iter::repeat_n(
-            epaint::text::PASSWORD_REPLACEMENT_CHAR,
-            text.chars().count(),
-        )
-        .collect::<String>()
+/// Paint the line number of each logical line (not each wrapped row) of the galley,
+/// right-aligned in the gutter.
+fn paint_line_numbers(
+    ui: &Ui,
+    galley: &Galley,
+    galley_pos: Pos2,
+    gutter_rect: Rect,
+    font_id: &FontId,
+    current_row: Option<usize>,
+    first_line: usize,
+) {
+    let painter = ui.painter_at(gutter_rect);
+    let visuals = ui.visuals();
+
+    let corner_radius = visuals.widgets.inactive.corner_radius;
+    painter.rect_filled(
+        gutter_rect,
+        CornerRadius {
+            ne: 0,
+            se: 0,
+            ..corner_radius
+        },
+        visuals.faint_bg_color,
+    );
+
+    // Which line (not row) the cursor is on:
+    let current_line = current_row.map(|current_row| {
+        first_line
+            + galley.rows[..current_row]
+                .iter()
+                .filter(|row| row.ends_with_newline)
+                .count()
+    });
+
+    let right = gutter_rect.right() - ui.spacing().item_spacing.x;
+    let clip_rect = painter.clip_rect();
+    let mut line = first_line;
+    let mut starts_new_line = true;
+    for placed_row in &galley.rows {
+        if starts_new_line {
+            let row_rect = placed_row.rect().translate(galley_pos.to_vec2());
+            if clip_rect.y_range().intersects(row_rect.y_range()) {
+                let color = if current_line == Some(line) {
+                    visuals.strong_text_color()
+                } else {
+                    visuals.weak_text_color()
+                };
+                painter.text(
+                    pos2(right, row_rect.center().y),
+                    Align2::RIGHT_CENTER,
+                    (line + 1).to_string(),
+                    font_id.clone(),
+                    color,
+                );
+            }
+        }
+        if placed_row.ends_with_newline {
+            line += 1;
+            starts_new_line = true;
+        } else {
+            starts_new_line = false;
+        }
     }
+}
 
-    if is_password {
-        mask_password(text)
+/// Replace each character with `mask`, if any.
+fn mask_if_password(mask: Option<char>, text: &str) -> String {
+    if let Some(mask) = mask {
+        std::iter::repeat_n(mask, text.chars().count()).collect()
     } else {
         text.to_owned()
     }
@@ -880,7 +1461,7 @@ fn mask_if_password(is_password: bool, text: &str) -> String {
 // ----------------------------------------------------------------------------
 
 /// Check for (keyboard) events to edit the cursor and/or text.
-#[expect(clippy::too_many_arguments)]
+#[expect(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 fn events(
     ui: &crate::Ui,
     state: &mut TextEditState,
@@ -893,19 +1474,23 @@ fn events(
     password: bool,
     default_cursor_range: CCursorRange,
     char_limit: usize,
-    event_filter: EventFilter,
+    byte_limit: usize,
+    mut events: Vec<Event>,
+    undo: bool,
     return_key: Option<KeyboardShortcut>,
-) -> (bool, CCursorRange) {
+) -> (bool, CCursorRange, bool) {
     let os = ui.ctx().os();
 
     let mut cursor_range = state.cursor.range(galley).unwrap_or(default_cursor_range);
 
     // We feed state to the undoer both before and after handling input
     // so that the undoer creates automatic saves even when there are no events for a while.
-    state.undoer.lock().feed_state(
-        ui.input(|i| i.time),
-        &(cursor_range, text.as_str().to_owned()),
-    );
+    if undo {
+        state.undoer.lock().feed_state(
+            ui.input(|i| i.time),
+            &(cursor_range, text.as_str().to_owned()),
+        );
+    }
 
     let copy_if_not_password = |ui: &Ui, text: String| {
         if !password {
@@ -914,8 +1499,16 @@ fn events(
     };
 
     let mut any_change = false;
-
-    let mut events = ui.input(|i| i.filtered_events(&event_filter));
+    let mut truncated = false;
+
+    let mut insert_text =
+        |text: &mut dyn TextBuffer, ccursor: &mut CCursor, text_to_insert: &str| {
+            let fitting = truncate_to_limits(text.as_str(), text_to_insert, char_limit, byte_limit);
+            ccursor.index += text.insert_text(fitting, ccursor.index);
+            if fitting.len() < text_to_insert.len() {
+                truncated = true;
+            }
+        };
 
     if state.ime_enabled {
         remove_ime_incompatible_events(&mut events);
@@ -948,7 +1541,7 @@ fn events(
                 if !text_to_insert.is_empty() {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    insert_text(text, &mut ccursor, text_to_insert);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -960,7 +1553,7 @@ fn events(
                 if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    insert_text(text, &mut ccursor, text_to_insert);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -978,7 +1571,7 @@ fn events(
                     // TODO(emilk): support removing indentation over a selection?
                     text.decrease_indentation(&mut ccursor);
                 } else {
-                    text.insert_text_at(&mut ccursor, "\t", char_limit);
+                    insert_text(text, &mut ccursor, "\t");
                 }
                 Some(CCursorRange::one(ccursor))
             }
@@ -993,7 +1586,7 @@ fn events(
             {
                 if multiline {
                     let mut ccursor = text.delete_selected(&cursor_range);
-                    text.insert_text_at(&mut ccursor, "\n", char_limit);
+                    insert_text(text, &mut ccursor, "\n");
                     // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -1007,9 +1600,10 @@ fn events(
                 pressed: true,
                 modifiers,
                 ..
-            } if (modifiers.matches_logically(Modifiers::COMMAND) && *key == Key::Y)
-                || (modifiers.matches_logically(Modifiers::SHIFT | Modifiers::COMMAND)
-                    && *key == Key::Z) =>
+            } if undo
+                && ((modifiers.matches_logically(Modifiers::COMMAND) && *key == Key::Y)
+                    || (modifiers.matches_logically(Modifiers::SHIFT | Modifiers::COMMAND)
+                        && *key == Key::Z)) =>
             {
                 if let Some((redo_ccursor_range, redo_txt)) = state
                     .undoer
@@ -1028,7 +1622,7 @@ fn events(
                 pressed: true,
                 modifiers,
                 ..
-            } if modifiers.matches_logically(Modifiers::COMMAND) => {
+            } if undo && modifiers.matches_logically(Modifiers::COMMAND) => {
                 if let Some((undo_ccursor_range, undo_txt)) = state
                     .undoer
                     .lock()
@@ -1063,9 +1657,13 @@ fn events(
                         let mut ccursor = text.delete_selected(&cursor_range);
                         let start_cursor = ccursor;
                         if !text_mark.is_empty() {
-                            text.insert_text_at(&mut ccursor, text_mark, char_limit);
+                            // The limits are enforced once the composition is committed,
+                            // so that we don't cut it off while the user is still typing:
+                            ccursor.index += text.insert_text(text_mark, ccursor.index);
                         }
                         state.ime_cursor_range = cursor_range;
+                        state.ime_preedit_range = (!text_mark.is_empty())
+                            .then(|| CCursorRange::two(start_cursor, ccursor));
                         Some(CCursorRange::two(start_cursor, ccursor))
                     }
                 }
@@ -1074,13 +1672,14 @@ fn events(
                         None
                     } else {
                         state.ime_enabled = false;
+                        state.ime_preedit_range = None;
 
                         if !prediction.is_empty()
                             && cursor_range.secondary.index
                                 == state.ime_cursor_range.secondary.index
                         {
                             let mut ccursor = text.delete_selected(&cursor_range);
-                            text.insert_text_at(&mut ccursor, prediction, char_limit);
+                            insert_text(text, &mut ccursor, prediction);
                             Some(CCursorRange::one(ccursor))
                         } else {
                             let ccursor = cursor_range.primary;
@@ -1090,6 +1689,7 @@ fn events(
                 }
                 ImeEvent::Disabled => {
                     state.ime_enabled = false;
+                    state.ime_preedit_range = None;
                     None
                 }
             },
@@ -1110,12 +1710,14 @@ fn events(
 
     state.cursor.set_char_range(Some(cursor_range));
 
-    state.undoer.lock().feed_state(
-        ui.input(|i| i.time),
-        &(cursor_range, text.as_str().to_owned()),
-    );
+    if undo {
+        state.undoer.lock().feed_state(
+            ui.input(|i| i.time),
+            &(cursor_range, text.as_str().to_owned()),
+        );
+    }
 
-    (any_change, cursor_range)
+    (any_change, cursor_range, truncated)
 }
 
 // ----------------------------------------------------------------------------
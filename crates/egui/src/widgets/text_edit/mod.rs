@@ -2,8 +2,12 @@ mod builder;
 mod output;
 mod state;
 mod text_buffer;
+mod virtualized;
 
 pub use {
-    crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
-    state::TextEditState, text_buffer::TextBuffer,
+    crate::text_selection::TextCursorState,
+    builder::{PasswordReveal, TextEdit},
+    output::TextEditOutput,
+    state::TextEditState,
+    text_buffer::TextBuffer,
 };
@@ -13,6 +13,12 @@ pub struct TextEditOutput {
     /// Where the text in [`Self::galley`] ended up on the screen.
     pub galley_pos: crate::Pos2,
 
+    /// The index of the first character of [`Self::galley`] in the text.
+    ///
+    /// This is always zero, except for a [`crate::TextEdit::virtualized`] text edit,
+    /// where the galley only contains the lines that were laid out.
+    pub galley_char_offset: usize,
+
     /// The text was clipped to this rectangle when painted.
     pub text_clip_rect: crate::Rect,
 
@@ -21,6 +27,12 @@ pub struct TextEditOutput {
 
     /// Where the text cursor is.
     pub cursor_range: Option<CCursorRange>,
+
+    /// Was some user input (typed, pasted or committed by an IME) cut off this frame
+    /// because of [`crate::TextEdit::char_limit`] or [`crate::TextEdit::byte_limit`]?
+    ///
+    /// Useful for e.g. flashing a warning.
+    pub truncated: bool,
 }
 
 // TODO(emilk): add `output.paint` and `output.store` and split out that code from `TextEdit::show`.
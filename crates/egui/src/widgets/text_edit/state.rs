@@ -3,10 +3,13 @@ use std::sync::Arc;
 use crate::mutex::Mutex;
 
 use crate::{
-    Context, Id,
+    Context, Id, TextBuffer,
     text_selection::{CCursorRange, TextCursorState},
+    util::undoer,
 };
 
+use super::virtualized::LineIndex;
+
 pub type TextEditUndoer = crate::util::undoer::Undoer<(CCursorRange, String)>;
 
 /// The text edit state stored between frames.
@@ -49,6 +52,10 @@ pub struct TextEditState {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) ime_cursor_range: CCursorRange,
 
+    // The not-yet-committed IME composition (preedit) text, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) ime_preedit_range: Option<CCursorRange>,
+
     // Visual offset when editing singleline text bigger than the width.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) singleline_offset: f32,
@@ -57,6 +64,16 @@ pub struct TextEditState {
     /// Used to pause the cursor animation when typing.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) last_interaction_time: f64,
+
+    /// Toggled with the [`crate::TextEdit::password_reveal_button`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) password_revealed: bool,
+
+    /// The lines of a [`crate::TextEdit::virtualized`] text, updated as it is edited.
+    ///
+    /// Wrapped in Arc for cheaper clones.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) line_index: Arc<Mutex<Option<LineIndex>>>,
 }
 
 impl TextEditState {
@@ -77,7 +94,116 @@ impl TextEditState {
         *self.undoer.lock() = undoer;
     }
 
+    /// Forget all undo/redo history.
+    ///
+    /// Call this after changing the text programmatically if that change should not be undoable,
+    /// e.g. after loading a new document.
+    /// The next time the [`crate::TextEdit`] is shown, its text becomes the new starting point.
     pub fn clear_undoer(&mut self) {
-        self.set_undoer(TextEditUndoer::default());
+        let settings = self.undoer.lock().settings().clone();
+        self.set_undoer(TextEditUndoer::with_settings(settings));
+    }
+
+    /// Is there anything to undo for the given text?
+    ///
+    /// Useful for e.g. greying out an "Undo" menu item.
+    pub fn can_undo(&self, text: &dyn TextBuffer) -> bool {
+        self.undoer.lock().has_undo(&self.undo_state(text))
+    }
+
+    /// Is there anything to redo for the given text?
+    pub fn can_redo(&self, text: &dyn TextBuffer) -> bool {
+        self.undoer.lock().has_redo(&self.undo_state(text))
+    }
+
+    /// Undo the latest change to `text`, same as pressing `Cmd/Ctrl+Z` in the [`crate::TextEdit`].
+    ///
+    /// Returns `true` if anything was undone.
+    /// Remember to [`Self::store`] the state afterwards.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// let output = egui::TextEdit::multiline(&mut text).show(ui);
+    /// let mut state = output.state;
+    /// if ui
+    ///     .add_enabled(state.can_undo(&text), egui::Button::new("Undo"))
+    ///     .clicked()
+    /// {
+    ///     state.undo(&mut text);
+    ///     state.store(ui.ctx(), output.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn undo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text);
+        let undo_state = self.undoer.lock().undo(&current_state).cloned();
+        self.apply_undo_state(text, undo_state)
+    }
+
+    /// Redo the latest undone change to `text`, same as pressing `Cmd/Ctrl+Shift+Z` in the [`crate::TextEdit`].
+    ///
+    /// Returns `true` if anything was redone.
+    /// Remember to [`Self::store`] the state afterwards.
+    pub fn redo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text);
+        let redo_state = self.undoer.lock().redo(&current_state).cloned();
+        self.apply_undo_state(text, redo_state)
+    }
+
+    /// Create an undo point for the current text right away.
+    ///
+    /// Call this after changing the text programmatically if the user should be able to undo that change
+    /// as a single step, regardless of [`undoer::Settings::stable_time`].
+    #[expect(clippy::needless_pass_by_ref_mut)] // Intentionally hide interiority of mutability
+    pub fn add_undo_point(&mut self, text: &dyn TextBuffer) {
+        let current_state = self.undo_state(text);
+        self.undoer.lock().add_undo(&current_state);
+    }
+
+    /// Set the maximum number of undo steps to keep. Default: `100`.
+    pub fn set_undo_limit(&mut self, max_undos: usize) {
+        let settings = undoer::Settings {
+            max_undos,
+            ..self.undoer.lock().settings().clone()
+        };
+        self.set_undo_settings(settings);
+    }
+
+    /// Configure the undo history depth, and how consecutive edits are merged into one undo step.
+    ///
+    /// Edits that are made within [`undoer::Settings::stable_time`] seconds of each other are merged.
+    /// Set it to `0.0` to create an undo point for every frame with a change.
+    #[expect(clippy::needless_pass_by_ref_mut)] // Intentionally hide interiority of mutability
+    pub fn set_undo_settings(&mut self, settings: undoer::Settings) {
+        self.undoer.lock().set_settings(settings);
+    }
+
+    fn undo_state(&self, text: &dyn TextBuffer) -> (CCursorRange, String) {
+        (
+            self.cursor.char_range().unwrap_or_default(),
+            text.as_str().to_owned(),
+        )
+    }
+
+    fn apply_undo_state(
+        &mut self,
+        text: &mut dyn TextBuffer,
+        state: Option<(CCursorRange, String)>,
+    ) -> bool {
+        if let Some((cursor_range, new_text)) = state {
+            text.replace_with(&new_text);
+            self.cursor.set_char_range(Some(cursor_range));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The range of the IME composition (preedit) text that is currently being typed, if any.
+    ///
+    /// This text is part of the buffer, but has not yet been committed by the input method.
+    pub fn ime_preedit_range(&self) -> Option<CCursorRange> {
+        self.ime_preedit_range
     }
 }
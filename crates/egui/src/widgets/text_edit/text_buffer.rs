@@ -5,6 +5,8 @@ use epaint::{
     text::{TAB_SIZE, cursor::CCursor},
 };
 
+use unicode_segmentation::UnicodeSegmentation as _;
+
 use crate::{
     text::CCursorRange,
     text_selection::text_cursor_state::{
@@ -70,21 +72,12 @@ pub trait TextBuffer {
         s
     }
 
+    /// Insert as much of `text_to_insert` as fits within `char_limit`.
+    ///
+    /// The text is never cut in the middle of a grapheme cluster (e.g. a multi-codepoint emoji).
     fn insert_text_at(&mut self, ccursor: &mut CCursor, text_to_insert: &str, char_limit: usize) {
-        if char_limit < usize::MAX {
-            let mut new_string = text_to_insert;
-            // Avoid subtract with overflow panic
-            let cutoff = char_limit.saturating_sub(self.as_str().chars().count());
-
-            new_string = match new_string.char_indices().nth(cutoff) {
-                None => new_string,
-                Some((idx, _)) => &new_string[..idx],
-            };
-
-            ccursor.index += self.insert_text(new_string, ccursor.index);
-        } else {
-            ccursor.index += self.insert_text(text_to_insert, ccursor.index);
-        }
+        let new_string = truncate_to_limits(self.as_str(), text_to_insert, char_limit, usize::MAX);
+        ccursor.index += self.insert_text(new_string, ccursor.index);
     }
 
     fn decrease_indentation(&mut self, ccursor: &mut CCursor) {
@@ -211,6 +204,38 @@ pub trait TextBuffer {
     fn type_id(&self) -> std::any::TypeId;
 }
 
+/// The longest prefix of `text_to_insert` that can be added to `existing_text`
+/// without it exceeding `char_limit` characters or `byte_limit` bytes (of UTF-8).
+///
+/// The text is only ever cut between grapheme clusters,
+/// so that e.g. an emoji or a letter with combining marks is either inserted whole, or not at all.
+pub(crate) fn truncate_to_limits<'a>(
+    existing_text: &str,
+    text_to_insert: &'a str,
+    char_limit: usize,
+    byte_limit: usize,
+) -> &'a str {
+    if char_limit == usize::MAX && byte_limit == usize::MAX {
+        return text_to_insert;
+    }
+
+    let mut chars_left = char_limit.saturating_sub(existing_text.chars().count());
+    let mut bytes_left = byte_limit.saturating_sub(existing_text.len());
+    let mut end = 0;
+
+    for (byte_index, grapheme) in text_to_insert.grapheme_indices(true) {
+        let num_chars = grapheme.chars().count();
+        if chars_left < num_chars || bytes_left < grapheme.len() {
+            break;
+        }
+        chars_left -= num_chars;
+        bytes_left -= grapheme.len();
+        end = byte_index + grapheme.len();
+    }
+
+    &text_to_insert[..end]
+}
+
 impl TextBuffer for String {
     fn is_mutable(&self) -> bool {
         true
@@ -315,3 +340,39 @@ impl TextBuffer for &str {
         std::any::TypeId::of::<&str>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_to_limits;
+
+    #[test]
+    fn test_truncate_to_char_limit() {
+        assert_eq!(truncate_to_limits("abc", "def", 5, usize::MAX), "de");
+        assert_eq!(truncate_to_limits("abcdef", "g", 5, usize::MAX), "");
+        assert_eq!(truncate_to_limits("", "abc", usize::MAX, usize::MAX), "abc");
+
+        // "e" followed by a combining acute accent is two chars, but one grapheme:
+        let e_acute = "e\u{301}";
+        assert_eq!(truncate_to_limits("abc", e_acute, 4, usize::MAX), "");
+        assert_eq!(truncate_to_limits("abc", e_acute, 5, usize::MAX), e_acute);
+    }
+
+    #[test]
+    fn test_truncate_to_byte_limit() {
+        // 'ä' is two bytes in UTF-8:
+        assert_eq!(truncate_to_limits("", "aäb", usize::MAX, 2), "a");
+        assert_eq!(truncate_to_limits("", "aäb", usize::MAX, 3), "aä");
+
+        // A family emoji is several code points joined by zero-width joiners:
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(
+            truncate_to_limits("", family, usize::MAX, family.len() - 1),
+            ""
+        );
+        assert_eq!(truncate_to_limits("x", family, 3, usize::MAX), "");
+        assert_eq!(
+            truncate_to_limits("x", &format!("{family}y"), usize::MAX, 1 + family.len()),
+            family
+        );
+    }
+}
@@ -0,0 +1,640 @@
+//! Support for [`crate::TextEdit::virtualized`]: only laying out the lines of a (large) text
+//! that are close to being visible.
+//!
+//! The text edit keeps its cursor in whole-text coordinates,
+//! but lays out, paints and edits a [`TextWindow`] of a few lines around what is visible.
+//! Edits to the window are then spliced back into the whole text.
+//!
+//! The [`LineIndex`] is kept in the [`super::TextEditState`] between frames, and updated on edits,
+//! so we only scan the whole text when it is first shown, or changed from the outside.
+//! Each line of the window is laid out and cached individually by [`epaint::text::Fonts`],
+//! so editing or scrolling only lays out the lines that changed or came into view.
+
+use std::ops::Range;
+
+use epaint::text::cursor::CCursor;
+
+use crate::{
+    Event, ImeEvent, Key, KeyboardShortcut, Modifiers, TextBuffer, Ui, os::OperatingSystem,
+    text_selection::CCursorRange,
+};
+
+/// How many lines to lay out above and below the visible ones (or the cursor).
+const MARGIN_LINES: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct LineStart {
+    byte: usize,
+    char: usize,
+}
+
+/// Where each line (separated by `\n`) of a text starts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct LineIndex {
+    line_starts: Vec<LineStart>,
+    num_bytes: usize,
+    num_chars: usize,
+    max_line_chars: usize,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        profiling::function_scope!();
+
+        let mut line_starts = Vec::new();
+        let mut max_line_chars = 0;
+        let mut start = LineStart { byte: 0, char: 0 };
+        for line in text.split('\n') {
+            line_starts.push(start);
+            let line_chars = line.chars().count();
+            max_line_chars = max_line_chars.max(line_chars);
+            start.byte += line.len() + 1;
+            start.char += line_chars + 1;
+        }
+
+        Self {
+            line_starts,
+            num_bytes: text.len(),
+            num_chars: start.char - 1,
+            max_line_chars,
+        }
+    }
+
+    /// Does this index (still) match the text?
+    ///
+    /// This only looks at the text of the given window, so it is cheap,
+    /// but together with [`Self::num_bytes`] it catches most changes made from the outside.
+    pub fn matches(&self, text: &str, window: &TextWindow) -> bool {
+        let Some(window_text) = text.get(window.bytes.clone()) else {
+            return false;
+        };
+        let bytes = text.as_bytes();
+        self.num_bytes == text.len()
+            && (window.bytes.start == 0 || bytes[window.bytes.start - 1] == b'\n')
+            && bytes.get(window.bytes.end).is_none_or(|&b| b == b'\n')
+            && window_text.matches('\n').count() + 1 == window.lines.len()
+            && window_text.chars().count() == window.chars.len()
+    }
+
+    /// Update the index after the characters `old_chars` (the bytes `old_bytes`)
+    /// of the text were replaced with `new_text`.
+    ///
+    /// This only scans `new_text`, and not the whole text.
+    pub fn replace(&mut self, old_chars: Range<usize>, old_bytes: Range<usize>, new_text: &str) {
+        profiling::function_scope!();
+
+        let mut new_starts = Vec::new();
+        let mut start = LineStart {
+            byte: old_bytes.start,
+            char: old_chars.start,
+        };
+        for (i, line) in new_text.split('\n').enumerate() {
+            if 0 < i {
+                new_starts.push(start);
+            }
+            start.byte += line.len() + 1;
+            start.char += line.chars().count() + 1;
+        }
+        let new_num_bytes = start.byte - 1 - old_bytes.start;
+        let new_num_chars = start.char - 1 - old_chars.start;
+
+        // The lines that started after one of the replaced newlines are gone,
+        // and the ones after the replaced text are moved:
+        let first = self
+            .line_starts
+            .partition_point(|start| start.char <= old_chars.start);
+        let last = self
+            .line_starts
+            .partition_point(|start| start.char <= old_chars.end);
+        let num_new_starts = new_starts.len();
+        self.line_starts.splice(first..last, new_starts);
+        for start in &mut self.line_starts[first + num_new_starts..] {
+            start.byte = start.byte - old_bytes.len() + new_num_bytes;
+            start.char = start.char - old_chars.len() + new_num_chars;
+        }
+        self.num_bytes = self.num_bytes - old_bytes.len() + new_num_bytes;
+        self.num_chars = self.num_chars - old_chars.len() + new_num_chars;
+
+        let line_ends = (self.line_starts.iter().skip(1).map(|start| start.char - 1))
+            .chain(std::iter::once(self.num_chars));
+        self.max_line_chars = (self.line_starts.iter().zip(line_ends))
+            .map(|(start, end)| end - start.char)
+            .max()
+            .unwrap_or(0);
+    }
+
+    /// Always at least one.
+    pub fn num_lines(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn num_chars(&self) -> usize {
+        self.num_chars
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        self.num_bytes
+    }
+
+    /// The number of characters in the longest line, excluding the newline.
+    pub fn max_line_chars(&self) -> usize {
+        self.max_line_chars
+    }
+
+    /// Which line is the character at this index on?
+    pub fn line_of_char(&self, char_index: usize) -> usize {
+        self.line_starts
+            .partition_point(|start| start.char <= char_index)
+            .saturating_sub(1)
+    }
+
+    /// The byte offset of the character at this index.
+    ///
+    /// This only scans the line the character is on.
+    /// Returns `None` if that line doesn't start where the index says it does,
+    /// i.e. the text was changed from the outside without changing its length,
+    /// somewhere [`Self::matches`] didn't look.
+    pub fn byte_of_char(&self, text: &str, char_index: usize) -> Option<usize> {
+        let start = self.line_starts[self.line_of_char(char_index)];
+        if start.byte != 0 && text.as_bytes().get(start.byte - 1) != Some(&b'\n') {
+            return None;
+        }
+        let line = text.get(start.byte..)?;
+        Some(
+            line.char_indices()
+                .nth(char_index - start.char)
+                .map_or(text.len(), |(byte, _)| start.byte + byte),
+        )
+    }
+
+    /// The given lines, excluding the newline at the end of the last one.
+    ///
+    /// The range is clamped to the text, but always contains at least one line.
+    pub fn window(&self, lines: Range<usize>) -> TextWindow {
+        let end = lines.end.clamp(1, self.num_lines());
+        let start = lines.start.min(end - 1);
+
+        let first = self.line_starts[start];
+        let (end_byte, end_char) = match self.line_starts.get(end) {
+            Some(next) => (next.byte - 1, next.char - 1),
+            None => (self.num_bytes, self.num_chars),
+        };
+
+        TextWindow {
+            lines: start..end,
+            bytes: first.byte..end_byte,
+            chars: first.char..end_char,
+        }
+    }
+}
+
+/// A range of whole lines of a text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TextWindow {
+    pub lines: Range<usize>,
+    pub bytes: Range<usize>,
+    pub chars: Range<usize>,
+}
+
+impl TextWindow {
+    /// Is the cursor inside the window (including at its very end)?
+    pub fn contains(&self, ccursor: CCursor) -> bool {
+        self.chars.start <= ccursor.index && ccursor.index <= self.chars.end
+    }
+
+    pub fn contains_range(&self, range: &CCursorRange) -> bool {
+        self.contains(range.primary) && self.contains(range.secondary)
+    }
+
+    /// From whole-text coordinates to window coordinates, clamping to the window.
+    pub fn to_local(&self, ccursor: CCursor) -> CCursor {
+        CCursor {
+            index: ccursor.index.clamp(self.chars.start, self.chars.end) - self.chars.start,
+            ..ccursor
+        }
+    }
+
+    /// From window coordinates to whole-text coordinates.
+    pub fn to_full(&self, ccursor: CCursor) -> CCursor {
+        CCursor {
+            index: ccursor.index + self.chars.start,
+            ..ccursor
+        }
+    }
+
+    pub fn range_to_local(&self, range: CCursorRange) -> CCursorRange {
+        CCursorRange {
+            primary: self.to_local(range.primary),
+            secondary: self.to_local(range.secondary),
+            h_pos: range.h_pos,
+        }
+    }
+
+    /// From window coordinates to whole-text coordinates.
+    ///
+    /// `before` is the whole-text range that [`Self::range_to_local`] was called with.
+    /// An end of it that was outside of the window and has not been moved is kept as-is,
+    /// so that a selection reaching outside the window survives.
+    pub fn range_to_full(&self, range: CCursorRange, before: Option<CCursorRange>) -> CCursorRange {
+        let to_full = |local: CCursor, before: Option<CCursor>| match before {
+            Some(before) if !self.contains(before) && self.to_local(before) == local => before,
+            _ => self.to_full(local),
+        };
+        CCursorRange {
+            primary: to_full(range.primary, before.map(|r| r.primary)),
+            secondary: to_full(range.secondary, before.map(|r| r.secondary)),
+            h_pos: range.h_pos,
+        }
+    }
+}
+
+/// Which lines to lay out.
+///
+/// That is the visible lines plus a margin, and the lines around the cursor if we are about to
+/// handle keyboard input, so that moving the cursor up or down works.
+pub(crate) fn window_lines(
+    lines: &LineIndex,
+    visible_lines: Range<usize>,
+    cursor_line: Option<usize>,
+) -> Range<usize> {
+    let around = |lines: Range<usize>| {
+        lines.start.saturating_sub(MARGIN_LINES)..lines.end.saturating_add(MARGIN_LINES)
+    };
+
+    let visible_window = around(visible_lines);
+    let Some(cursor_line) = cursor_line else {
+        return visible_window;
+    };
+    let cursor_window = around(cursor_line..cursor_line + 1);
+
+    let union = visible_window.start.min(cursor_window.start)
+        ..visible_window
+            .end
+            .max(cursor_window.end)
+            .min(lines.num_lines());
+    if union.len() <= 2 * visible_window.len() + cursor_window.len() {
+        union
+    } else {
+        // The cursor is far away from what is visible.
+        // We will scroll to it as soon as it moves, so for now we only lay out around it:
+        cursor_window
+    }
+}
+
+/// The text of a [`TextWindow`], which is edited in place of the whole text.
+pub(crate) struct WindowText {
+    pub text: String,
+    pub is_mutable: bool,
+}
+
+impl TextBuffer for WindowText {
+    fn is_mutable(&self) -> bool {
+        self.is_mutable
+    }
+
+    fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    fn insert_text(&mut self, text: &str, char_index: usize) -> usize {
+        if self.is_mutable {
+            self.text.insert_text(text, char_index)
+        } else {
+            0
+        }
+    }
+
+    fn delete_char_range(&mut self, char_range: Range<usize>) {
+        if self.is_mutable {
+            self.text.delete_char_range(char_range);
+        }
+    }
+
+    fn type_id(&self) -> std::any::TypeId {
+        std::any::TypeId::of::<Self>()
+    }
+}
+
+/// Handle the events that need the whole text, and not just the window of it that is laid out.
+///
+/// These are the events that move the cursor to the start or end of the text,
+/// and events that copy, replace or move the selection when it reaches outside the window.
+///
+/// The events are handled in order until we reach one that can be handled by the window.
+/// That and all following events are returned, together with whether the text was changed.
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn handle_document_events(
+    ui: &Ui,
+    events: Vec<Event>,
+    text: &mut dyn TextBuffer,
+    lines: &mut LineIndex,
+    cursor_range: &mut CCursorRange,
+    window_for_cursor: impl Fn(&LineIndex, &CCursorRange) -> TextWindow,
+    password: bool,
+    return_key: Option<KeyboardShortcut>,
+) -> (Vec<Event>, bool) {
+    let os = ui.ctx().os();
+    let num_bytes = text.as_str().len();
+
+    let mut events = events.into_iter();
+    let mut remaining = Vec::new();
+    for event in events.by_ref() {
+        let window = window_for_cursor(lines, cursor_range);
+        let handled = handle_document_event(
+            ui,
+            os,
+            &event,
+            text,
+            lines,
+            cursor_range,
+            &window,
+            password,
+            return_key,
+        );
+        if !handled {
+            remaining.push(event);
+            break;
+        }
+    }
+    remaining.extend(events);
+
+    // We only ever delete text here:
+    let changed = text.as_str().len() != num_bytes;
+    (remaining, changed)
+}
+
+/// Returns `true` if the event was handled.
+#[expect(clippy::too_many_arguments)]
+fn handle_document_event(
+    ui: &Ui,
+    os: OperatingSystem,
+    event: &Event,
+    text: &mut dyn TextBuffer,
+    lines: &mut LineIndex,
+    cursor_range: &mut CCursorRange,
+    window: &TextWindow,
+    password: bool,
+    return_key: Option<KeyboardShortcut>,
+) -> bool {
+    if let Event::Key {
+        key,
+        pressed: true,
+        modifiers,
+        ..
+    } = event
+    {
+        if *key == Key::A && modifiers.command {
+            *cursor_range = CCursorRange::two(CCursor::new(0), CCursor::new(lines.num_chars()));
+            return true;
+        }
+
+        if let Some(ccursor) = move_to_start_or_end(os, *key, modifiers, lines.num_chars()) {
+            cursor_range.primary = ccursor;
+            cursor_range.h_pos = None;
+            if !modifiers.shift {
+                cursor_range.secondary = ccursor;
+            }
+            return true;
+        }
+    }
+
+    if window.contains_range(cursor_range) {
+        return false; // The window can handle it.
+    }
+
+    // The selection reaches outside the window:
+    let [min, max] = cursor_range.sorted_cursors();
+
+    match event {
+        Event::Key {
+            key: key @ (Key::ArrowLeft | Key::ArrowRight),
+            pressed: true,
+            modifiers,
+            ..
+        } if modifiers.is_none() && !cursor_range.is_empty() => {
+            *cursor_range = CCursorRange::one(if *key == Key::ArrowLeft { min } else { max });
+            true
+        }
+
+        Event::Copy | Event::Cut => {
+            if !cursor_range.is_empty() && !password {
+                ui.ctx()
+                    .copy_text(cursor_range.slice_str(text.as_str()).to_owned());
+            }
+            if matches!(event, Event::Cut) {
+                delete_selection(text, lines, cursor_range);
+            }
+            true
+        }
+
+        Event::Key {
+            key: Key::Backspace,
+            pressed: true,
+            ..
+        } => {
+            delete_selection(text, lines, cursor_range);
+            true
+        }
+        Event::Key {
+            key: Key::Delete,
+            pressed: true,
+            modifiers,
+            ..
+        } if !modifiers.shift || os != OperatingSystem::Windows => {
+            delete_selection(text, lines, cursor_range);
+            true
+        }
+        Event::Key {
+            key: Key::W,
+            pressed: true,
+            modifiers,
+            ..
+        } if modifiers.ctrl => {
+            delete_selection(text, lines, cursor_range);
+            true
+        }
+
+        // These replace the selection with something, which the window can then do:
+        Event::Text(new_text) | Event::Paste(new_text)
+            if !new_text.is_empty() && new_text != "\n" && new_text != "\r" =>
+        {
+            delete_selection(text, lines, cursor_range);
+            false
+        }
+        Event::Ime(ImeEvent::Preedit(text_mark)) if text_mark != "\n" && text_mark != "\r" => {
+            delete_selection(text, lines, cursor_range);
+            false
+        }
+        Event::Key {
+            key: Key::Tab,
+            pressed: true,
+            ..
+        } => {
+            delete_selection(text, lines, cursor_range);
+            false
+        }
+        Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } if return_key.is_some_and(|return_key| {
+            *key == return_key.logical_key && modifiers.matches_logically(return_key.modifiers)
+        }) =>
+        {
+            delete_selection(text, lines, cursor_range);
+            false
+        }
+
+        _ => false,
+    }
+}
+
+/// Keyboard shortcuts for moving to the start or end of the whole text.
+///
+/// Keep in sync with `move_single_cursor` in `cursor_range.rs`.
+fn move_to_start_or_end(
+    os: OperatingSystem,
+    key: Key,
+    modifiers: &Modifiers,
+    num_chars: usize,
+) -> Option<CCursor> {
+    if os == OperatingSystem::Mac && modifiers.ctrl && !modifiers.shift {
+        return None;
+    }
+    match key {
+        Key::ArrowUp if modifiers.command => Some(CCursor::new(0)),
+        Key::Home if modifiers.ctrl => Some(CCursor::new(0)),
+        Key::ArrowDown if modifiers.command => Some(CCursor::new(num_chars)),
+        Key::End if modifiers.ctrl => Some(CCursor::new(num_chars)),
+        _ => None,
+    }
+}
+
+fn delete_selection(
+    text: &mut dyn TextBuffer,
+    lines: &mut LineIndex,
+    cursor_range: &mut CCursorRange,
+) {
+    if text.is_mutable() && !cursor_range.is_empty() {
+        let [min, max] = cursor_range.sorted_cursors();
+        let byte_range = |lines: &LineIndex| {
+            Some(
+                lines.byte_of_char(text.as_str(), min.index)?
+                    ..lines.byte_of_char(text.as_str(), max.index)?,
+            )
+        };
+        let bytes = byte_range(lines).unwrap_or_else(|| {
+            *lines = LineIndex::new(text.as_str());
+            byte_range(lines).expect("A new index matches the text")
+        });
+        *cursor_range = CCursorRange::one(text.delete_selected(cursor_range));
+        lines.replace(min.index..max.index, bytes, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_index() {
+        let lines = LineIndex::new("ab\n\nåäö\n");
+        assert_eq!(lines.num_lines(), 4);
+        assert_eq!(lines.num_chars(), 8);
+        assert_eq!(lines.max_line_chars(), 3);
+
+        assert_eq!(lines.line_of_char(0), 0);
+        assert_eq!(lines.line_of_char(2), 0);
+        assert_eq!(lines.line_of_char(3), 1);
+        assert_eq!(lines.line_of_char(4), 2);
+        assert_eq!(lines.line_of_char(8), 3);
+
+        let window = lines.window(1..3);
+        assert_eq!(window.lines, 1..3);
+        assert_eq!(window.chars, 3..7);
+        assert_eq!(&"ab\n\nåäö\n"[window.bytes], "\nåäö");
+
+        // Clamped, but never empty:
+        assert_eq!(lines.window(2..100).lines, 2..4);
+        assert_eq!(lines.window(100..200).lines, 3..4);
+        assert_eq!(LineIndex::new("").window(0..0).chars, 0..0);
+    }
+
+    #[test]
+    fn test_line_index_replace() {
+        let mut text = "ab\n\nåäö\nlast".to_owned();
+        let mut lines = LineIndex::new(&text);
+
+        let mut replace = |text: &mut String, chars: Range<usize>, new_text: &str| {
+            let bytes = lines.byte_of_char(text, chars.start).unwrap()
+                ..lines.byte_of_char(text, chars.end).unwrap();
+            text.replace_range(bytes.clone(), new_text);
+            lines.replace(chars, bytes, new_text);
+            assert_eq!(
+                lines,
+                LineIndex::new(text),
+                "After replacing with {new_text:?}"
+            );
+        };
+
+        replace(&mut text, 1..1, "x"); // "axb\n\nåäö\nlast"
+        replace(&mut text, 3..5, ""); // Join lines
+        replace(&mut text, 4..6, "ü\n\nö"); // Split lines
+        let num_chars = text.chars().count();
+        replace(&mut text, 0..num_chars, "");
+        replace(&mut text, 0..0, "new\ntext\n");
+        assert_eq!(text, "new\ntext\n");
+    }
+
+    #[test]
+    fn test_line_index_matches() {
+        let text = "ab\n\nåäö\nlast";
+        let lines = LineIndex::new(text);
+        let window = lines.window(1..3);
+        assert!(lines.matches(text, &window));
+        assert!(!lines.matches("ab\nxåäö\nlast", &window), "Moved newline");
+        assert!(
+            !lines.matches("ab\n\nåäöx\nlast", &window),
+            "Different length"
+        );
+        assert!(
+            !lines.matches("ab\n\naaaaaa\nlast", &window),
+            "Different characters"
+        );
+    }
+
+    #[test]
+    fn test_byte_of_char_in_changed_text() {
+        let lines = LineIndex::new("a\nbcd");
+        assert_eq!(lines.byte_of_char("a\nbcd", 3), Some(3));
+        // Same length, but the second line now starts in the middle of "å":
+        assert_eq!(lines.byte_of_char("aå\nd", 3), None);
+        // …or after something else than a newline:
+        assert_eq!(lines.byte_of_char("abc\nd", 3), None);
+    }
+
+    #[test]
+    fn test_window_keeps_selection_outside() {
+        let lines = LineIndex::new("a\nb\nc\nd");
+        let window = lines.window(1..3); // "b\nc"
+
+        let before = CCursorRange::two(CCursor::new(0), CCursor::new(3));
+        let local = window.range_to_local(before);
+        assert_eq!(local, CCursorRange::two(CCursor::new(0), CCursor::new(1)));
+
+        // Extending the selection inside the window keeps the end outside of it:
+        let after = CCursorRange::two(CCursor::new(0), CCursor::new(3));
+        assert_eq!(
+            window.range_to_full(after, Some(before)),
+            CCursorRange::two(CCursor::new(0), CCursor::new(5))
+        );
+
+        // Moving both ends discards the outside:
+        let after = CCursorRange::one(CCursor::new(2));
+        assert_eq!(
+            window.range_to_full(after, Some(before)),
+            CCursorRange::one(CCursor::new(4))
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType, emath, epaint, pos2, vec2,
+};
+
+/// An on/off switch with a sliding knob, with an optional text label.
+///
+/// It works like a [`crate::Checkbox`]: click it, or press space while it has keyboard focus,
+/// to flip the boolean.
+///
+/// Usually you'd use [`Ui::toggle`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut dark_mode = true;
+/// // These are equivalent:
+/// ui.toggle(&mut dark_mode);
+/// ui.add(egui::ToggleSwitch::without_text(&mut dark_mode));
+///
+/// // With a label in front:
+/// ui.add(egui::ToggleSwitch::new(&mut dark_mode, "Dark mode").text_on_left(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct ToggleSwitch<'a> {
+    on: &'a mut bool,
+    atoms: Atoms<'a>,
+    text_on_left: bool,
+    small: bool,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(on: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Self {
+        Self {
+            on,
+            atoms: atoms.into_atoms(),
+            text_on_left: false,
+            small: false,
+        }
+    }
+
+    pub fn without_text(on: &'a mut bool) -> Self {
+        Self::new(on, ())
+    }
+
+    /// Put the text before the switch instead of after it.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn text_on_left(mut self, text_on_left: bool) -> Self {
+        self.text_on_left = text_on_left;
+        self
+    }
+
+    /// Make this a small switch, for dense UIs.
+    #[inline]
+    pub fn small(mut self) -> Self {
+        self.small = true;
+        self
+    }
+}
+
+impl Widget for ToggleSwitch<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let ToggleSwitch {
+            on,
+            mut atoms,
+            text_on_left,
+            small,
+        } = self;
+
+        let spacing = ui.spacing();
+        let height = if small {
+            spacing.icon_width
+        } else {
+            spacing.interact_size.y
+        };
+        let switch_size = vec2(2.0 * height, height);
+        let min_size = Vec2::splat(height.at_least(spacing.interact_size.y));
+
+        let switch_id = Id::new("egui::toggle_switch");
+        let switch = Atom::custom(switch_id, switch_size);
+        if text_on_left {
+            atoms.push_right(switch);
+        } else {
+            atoms.push_left(switch);
+        }
+
+        let text = atoms.text().map(String::from);
+
+        let mut prepared = AtomLayout::new(atoms)
+            .sense(Sense::click())
+            .min_size(min_size)
+            .allocate(ui);
+
+        if prepared.response.clicked() {
+            *on = !*on;
+            prepared.response.mark_changed();
+        }
+        prepared.response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Switch,
+                ui.is_enabled(),
+                *on,
+                text.as_deref().unwrap_or(""),
+            )
+        });
+
+        if ui.is_rect_visible(prepared.response.rect) {
+            let how_on = ui.ctx().animate_bool_responsive(prepared.response.id, *on);
+            let visuals = ui.style().interact_selectable(&prepared.response, *on);
+            prepared.fallback_text_color = ui.style().interact(&prepared.response).text_color();
+            let response = prepared.paint(ui);
+
+            if let Some(rect) = response.rect(switch_id) {
+                let rect = rect.expand(visuals.expansion);
+                let radius = 0.5 * rect.height();
+                ui.painter().add(epaint::RectShape::new(
+                    rect,
+                    radius,
+                    visuals.bg_fill,
+                    visuals.bg_stroke,
+                    epaint::StrokeKind::Inside,
+                ));
+
+                let knob_x = emath::lerp((rect.left() + radius)..=(rect.right() - radius), how_on);
+                let knob_center = pos2(knob_x, rect.center().y);
+                ui.painter().circle(
+                    knob_center,
+                    0.75 * radius,
+                    visuals.bg_fill,
+                    visuals.fg_stroke,
+                );
+            }
+            response.response
+        } else {
+            prepared.response
+        }
+    }
+}
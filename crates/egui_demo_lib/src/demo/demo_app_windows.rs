@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use super::About;
+use super::{About, DemoRegistry};
 use crate::Demo;
 use crate::View as _;
 use crate::is_mobile;
@@ -31,24 +31,32 @@ impl DemoGroup {
     pub fn checkboxes(&mut self, ui: &mut Ui, open: &mut BTreeSet<String>) {
         let Self { demos } = self;
         for demo in demos {
-            if demo.is_enabled(ui.ctx()) {
-                let mut is_open = open.contains(demo.name());
-                ui.toggle_value(&mut is_open, demo.name());
-                set_open(open, demo.name(), is_open);
-            }
+            demo_checkbox(ui, demo.as_ref(), open);
         }
     }
 
     pub fn windows(&mut self, ctx: &Context, open: &mut BTreeSet<String>) {
         let Self { demos } = self;
         for demo in demos {
-            let mut is_open = open.contains(demo.name());
-            demo.show(ctx, &mut is_open);
-            set_open(open, demo.name(), is_open);
+            demo_window(ctx, demo.as_mut(), open);
         }
     }
 }
 
+fn demo_checkbox(ui: &mut Ui, demo: &dyn Demo, open: &mut BTreeSet<String>) {
+    if demo.is_enabled(ui.ctx()) {
+        let mut is_open = open.contains(demo.name());
+        ui.toggle_value(&mut is_open, demo.name());
+        set_open(open, demo.name(), is_open);
+    }
+}
+
+fn demo_window(ctx: &Context, demo: &mut dyn Demo, open: &mut BTreeSet<String>) {
+    let mut is_open = open.contains(demo.name());
+    demo.show(ctx, &mut is_open);
+    set_open(open, demo.name(), is_open);
+}
+
 fn set_open(open: &mut BTreeSet<String>, key: &'static str, is_open: bool) {
     if is_open {
         if !open.contains(key) {
@@ -65,6 +73,7 @@ pub struct DemoGroups {
     about: About,
     demos: DemoGroup,
     tests: DemoGroup,
+    registry: DemoRegistry,
 }
 
 impl Default for DemoGroups {
@@ -77,6 +86,7 @@ impl Default for DemoGroups {
                 Box::<super::code_example::CodeExample>::default(),
                 Box::<super::dancing_strings::DancingStrings>::default(),
                 Box::<super::drag_and_drop::DragAndDropDemo>::default(),
+                Box::<super::easing::EasingDemo>::default(),
                 Box::<super::extra_viewport::ExtraViewport>::default(),
                 Box::<super::font_book::FontBook>::default(),
                 Box::<super::frame_demo::FrameDemo>::default(),
@@ -114,6 +124,7 @@ impl Default for DemoGroups {
                 Box::<super::tests::TessellationTest>::default(),
                 Box::<super::tests::WindowResizeTest>::default(),
             ]),
+            registry: DemoRegistry::default(),
         }
     }
 }
@@ -124,6 +135,7 @@ impl DemoGroups {
             about,
             demos,
             tests,
+            registry,
         } = self;
 
         {
@@ -133,6 +145,12 @@ impl DemoGroups {
         }
         ui.separator();
         demos.checkboxes(ui, open);
+        if !registry.is_empty() {
+            ui.separator();
+            for demo in registry.iter() {
+                demo_checkbox(ui, demo, open);
+            }
+        }
         ui.separator();
         tests.checkboxes(ui, open);
     }
@@ -142,6 +160,7 @@ impl DemoGroups {
             about,
             demos,
             tests,
+            registry,
         } = self;
         {
             let mut is_open = open.contains(about.name());
@@ -149,6 +168,9 @@ impl DemoGroups {
             set_open(open, about.name(), is_open);
         }
         demos.windows(ctx, open);
+        for demo in registry.iter_mut() {
+            demo_window(ctx, demo, open);
+        }
         tests.windows(ctx, open);
     }
 }
@@ -203,6 +225,20 @@ impl DemoWindows {
         }
     }
 
+    /// Demos from other crates, shown after the built-in demos.
+    ///
+    /// The registry is not persisted, so register your demos again after restoring [`Self`].
+    pub fn registry(&self) -> &DemoRegistry {
+        &self.groups.registry
+    }
+
+    /// Register demos from other crates, shown after the built-in demos.
+    ///
+    /// See [`DemoRegistry`] for an example.
+    pub fn registry_mut(&mut self) -> &mut DemoRegistry {
+        &mut self.groups.registry
+    }
+
     fn about_is_open(&self) -> bool {
         self.open.contains(About::default().name())
     }
@@ -381,6 +417,7 @@ mod tests {
             demos,
             tests,
             about: _,
+            registry: _,
         } = DemoGroups::default();
         let demos = demos + tests;
 
@@ -420,6 +457,50 @@ mod tests {
         }
     }
 
+    struct Greeter;
+
+    impl crate::DemoWidget for Greeter {
+        fn name(&self) -> &'static str {
+            "Greeter"
+        }
+
+        fn show(&mut self, ui: &mut egui::Ui) {
+            ui.label("Hello from a registered demo");
+        }
+    }
+
+    #[test]
+    fn registered_demos_are_listed_and_shown() {
+        let mut demo_windows = crate::DemoWindows::default();
+        demo_windows.registry_mut().register(Greeter);
+        assert_eq!(
+            demo_windows
+                .registry()
+                .iter()
+                .map(|demo| demo.name())
+                .collect::<Vec<_>>(),
+            ["Greeter"]
+        );
+
+        // Tall enough to show the whole demo list:
+        let mut harness = Harness::builder()
+            .with_size(egui::vec2(1200.0, 2000.0))
+            .build_state(
+                |ctx, demo_windows: &mut crate::DemoWindows| demo_windows.ui(ctx),
+                demo_windows,
+            );
+        harness.run();
+        assert!(
+            harness
+                .query_by_label("Hello from a registered demo")
+                .is_none()
+        );
+
+        harness.get_by_label("Greeter").click();
+        harness.run();
+        harness.get_by_label("Hello from a registered demo");
+    }
+
     fn remove_leading_emoji(full_name: &str) -> &str {
         if let Some((start, name)) = full_name.split_once(' ') {
             if start.len() <= 4 && start.bytes().next().is_some_and(|byte| byte >= 128) {
@@ -0,0 +1,78 @@
+use super::{Demo, DemoWidget};
+
+/// Demos registered by other crates, e.g. to show off their own widgets in the demo app.
+///
+/// Each demo gets a toggle in the demo list of [`super::DemoWindows`],
+/// after the built-in demos.
+///
+/// ```
+/// struct Greeter;
+///
+/// impl egui_demo_lib::DemoWidget for Greeter {
+///     fn name(&self) -> &'static str {
+///         "👋 Greeter"
+///     }
+///
+///     fn show(&mut self, ui: &mut egui::Ui) {
+///         ui.label("Hello from another crate!");
+///     }
+/// }
+///
+/// let mut demo_windows = egui_demo_lib::DemoWindows::default();
+/// demo_windows.registry_mut().register(Greeter);
+/// ```
+#[derive(Default)]
+pub struct DemoRegistry {
+    demos: Vec<Box<dyn Demo>>,
+}
+
+impl DemoRegistry {
+    /// Add a widget demo, shown in its own window.
+    ///
+    /// The [`DemoWidget::name`] is also used to remember if the window is open,
+    /// so it should be unique.
+    pub fn register(&mut self, widget: impl DemoWidget + 'static) -> &mut Self {
+        self.register_demo(Box::new(WidgetWindow(widget)))
+    }
+
+    /// Add a demo that shows its own windows.
+    pub fn register_demo(&mut self, demo: Box<dyn Demo>) -> &mut Self {
+        self.demos.push(demo);
+        self
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.demos.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.demos.is_empty()
+    }
+
+    /// The registered demos, in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &(dyn Demo + 'static)> {
+        self.demos.iter().map(|demo| demo.as_ref())
+    }
+
+    /// The registered demos, in the order they were registered.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut (dyn Demo + 'static)> {
+        self.demos.iter_mut().map(|demo| demo.as_mut())
+    }
+}
+
+/// Shows a [`DemoWidget`] in a window.
+struct WidgetWindow<W>(W);
+
+impl<W: DemoWidget> Demo for WidgetWindow<W> {
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .show(ctx, |ui| self.0.show(ui));
+    }
+}
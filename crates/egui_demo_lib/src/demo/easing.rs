@@ -0,0 +1,119 @@
+use egui::{Easing, Id, Sense, Stroke, Ui, emath, lerp, vec2};
+
+/// Compares the animation curves of [`Easing`].
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EasingDemo {
+    on: bool,
+    animation_time: f32,
+    stiffness: f32,
+    damping: f32,
+}
+
+impl Default for EasingDemo {
+    fn default() -> Self {
+        Self {
+            on: false,
+            animation_time: 0.5,
+            stiffness: 200.0,
+            damping: 22.0,
+        }
+    }
+}
+
+impl crate::Demo for EasingDemo {
+    fn name(&self) -> &'static str {
+        "〰 Easing"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        use crate::View as _;
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| self.ui(ui));
+    }
+}
+
+impl crate::View for EasingDemo {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add(crate::egui_github_link_file!());
+        });
+
+        ui.label("Click the button to animate every curve at once.");
+
+        egui::Grid::new("easing_settings")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Animation time");
+                ui.add(egui::Slider::new(&mut self.animation_time, 0.1..=2.0).suffix(" s"));
+                ui.end_row();
+
+                ui.label("Spring stiffness");
+                ui.add(egui::Slider::new(&mut self.stiffness, 10.0..=1000.0).logarithmic(true));
+                ui.end_row();
+
+                ui.label("Spring damping");
+                ui.add(egui::Slider::new(&mut self.damping, 1.0..=60.0));
+                ui.end_row();
+            });
+
+        let label = if self.on { "Slide back" } else { "Slide over" };
+        if ui.button(label).clicked() {
+            self.on = !self.on;
+        }
+
+        ui.separator();
+
+        let easings = [
+            ("Linear", Easing::Linear),
+            ("Ease in", Easing::EaseIn),
+            ("Ease out", Easing::EaseOut),
+            ("Ease in-out", Easing::EaseInOut),
+            ("Back out", Easing::Curve(emath::easing::back_out)),
+            ("Bounce out", Easing::Curve(emath::easing::bounce_out)),
+            (
+                "Spring",
+                Easing::Spring {
+                    stiffness: self.stiffness,
+                    damping: self.damping,
+                },
+            ),
+        ];
+
+        egui::Grid::new("easing_curves")
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (name, easing) in easings {
+                    ui.label(name);
+                    let t = ui.ctx().animate_bool_eased(
+                        Id::new("easing_demo").with(name),
+                        self.on,
+                        self.animation_time,
+                        easing,
+                    );
+                    track_ui(ui, t);
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+/// A dot on a horizontal track, at `t` of the way across.
+fn track_ui(ui: &mut Ui, t: f32) {
+    let size = vec2(200.0, ui.spacing().interact_size.y);
+    let (rect, _response) = ui.allocate_exact_size(size, Sense::hover());
+    let radius = 0.4 * rect.height();
+    let track = rect.shrink2(vec2(radius, 0.0));
+
+    let visuals = ui.visuals();
+    ui.painter().line_segment(
+        [track.left_center(), track.right_center()],
+        Stroke::new(1.0, visuals.weak_text_color()),
+    );
+    let center = egui::pos2(lerp(track.x_range(), t), track.center().y);
+    ui.painter()
+        .circle_filled(center, radius, visuals.selection.bg_fill);
+}
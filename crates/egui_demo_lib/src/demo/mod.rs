@@ -9,7 +9,9 @@ pub mod code_editor;
 pub mod code_example;
 pub mod dancing_strings;
 pub mod demo_app_windows;
+pub mod demo_registry;
 pub mod drag_and_drop;
+pub mod easing;
 pub mod extra_viewport;
 pub mod font_book;
 pub mod frame_demo;
@@ -39,8 +41,8 @@ pub mod widget_gallery;
 pub mod window_options;
 
 pub use {
-    about::About, demo_app_windows::DemoWindows, misc_demo_window::MiscDemoWindow,
-    widget_gallery::WidgetGallery,
+    about::About, demo_app_windows::DemoWindows, demo_registry::DemoRegistry,
+    misc_demo_window::MiscDemoWindow, widget_gallery::WidgetGallery,
 };
 
 // ----------------------------------------------------------------------------
@@ -63,3 +65,12 @@ pub trait Demo {
     /// Show windows, etc
     fn show(&mut self, ctx: &egui::Context, open: &mut bool);
 }
+
+/// A widget demo from another crate, see [`DemoRegistry`].
+pub trait DemoWidget {
+    /// `&'static` so we can also use it as a key to store open/close state.
+    fn name(&self) -> &'static str;
+
+    /// Show the contents of the demo window.
+    fn show(&mut self, ui: &mut egui::Ui);
+}
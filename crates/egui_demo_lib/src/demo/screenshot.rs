@@ -69,7 +69,10 @@ impl crate::View for Screenshot {
             ));
         }
 
-        if let Some((_, texture)) = &self.image {
+        if let Some((image, texture)) = &self.image {
+            if ui.button("📋 Copy to clipboard").clicked() {
+                ui.ctx().copy_image((**image).clone());
+            }
             Image::new(texture).shrink_to_fit().ui(ui);
         } else {
             ui.group(|ui| {
@@ -288,11 +288,13 @@ fn rect_shape_ui(ui: &mut egui::Ui, shape: &mut RectShape) {
     let RectShape {
         rect,
         corner_radius,
+        corner_smoothing: _,
         fill,
         stroke,
         stroke_kind,
         blur_width,
         round_to_pixels,
+        feathering: _,
         brush: _,
     } = shape;
 
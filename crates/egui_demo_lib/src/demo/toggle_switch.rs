@@ -1,5 +1,7 @@
 //! Source code example of how to create your own widget.
 //! This is meant to be read as a tutorial, hence the plethora of comments.
+//!
+//! If you just want a toggle switch, use [`egui::ToggleSwitch`] or [`egui::Ui::toggle`].
 
 /// iOS-style toggle switch:
 ///
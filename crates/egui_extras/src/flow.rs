@@ -0,0 +1,167 @@
+//! Left-to-right layout of variably-sized items, wrapping to a new line when they don't fit.
+
+use egui::{Align, Id, InnerResponse, Layout, Pos2, Rect, Sense, Ui, UiBuilder, Vec2, vec2};
+
+/// Lays out items left-to-right, and starts a new row when the next item does not fit.
+///
+/// Unlike [`Ui::horizontal_wrapped`], each item is its own [`Ui`],
+/// so an item can contain any number of widgets, and can be as tall as it likes.
+/// The height of each row is the height of the tallest item in it.
+///
+/// To know where to wrap, the sizes of the items are remembered from the previous frame.
+/// The first frame is therefore a hidden sizing pass.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let tags = ["rust", "gui", "immediate mode", "egui"];
+/// egui_extras::FlowLayout::new("tags")
+///     .gap(egui::vec2(4.0, 4.0))
+///     .show(ui, |flow| {
+///         for tag in tags {
+///             flow.item(|ui| ui.button(tag));
+///         }
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct FlowLayout {
+    id_salt: Id,
+    gap: Option<Vec2>,
+}
+
+impl FlowLayout {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            gap: None,
+        }
+    }
+
+    /// Horizontal and vertical space between items.
+    ///
+    /// Default: [`egui::Spacing::item_spacing`].
+    #[inline]
+    pub fn gap(mut self, gap: impl Into<Vec2>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
+    /// Add the items with [`FlowUi::item`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_items: impl FnOnce(&mut FlowUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self { id_salt, gap } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let prev_sizes: Option<Vec<Vec2>> = ui.data_mut(|d| d.get_temp(id));
+        let is_first_frame = prev_sizes.is_none();
+
+        let mut ui_builder = UiBuilder::new();
+        if is_first_frame {
+            if ui.is_visible() {
+                // Cover up the glitchy initial frame, where we don't know where to wrap:
+                ui.ctx().request_discard("new FlowLayout");
+            }
+            ui_builder = ui_builder.sizing_pass().invisible();
+        }
+
+        ui.scope_builder(ui_builder, |ui| {
+            let mut flow = FlowUi {
+                gap: gap.unwrap_or_else(|| ui.spacing().item_spacing),
+                top_left: ui.cursor().min,
+                max_width: ui.available_width(),
+                ui,
+                prev_sizes: prev_sizes.clone().unwrap_or_default(),
+                sizes: Vec::new(),
+                cursor: Vec2::ZERO,
+                row_height: 0.0,
+                content_width: 0.0,
+            };
+            let inner = add_items(&mut flow);
+
+            let FlowUi {
+                ui,
+                top_left,
+                sizes,
+                cursor,
+                row_height,
+                content_width,
+                ..
+            } = flow;
+
+            // Store the state on the first frame even if there are no items,
+            // or we would keep requesting discards.
+            if is_first_frame || prev_sizes.as_ref() != Some(&sizes) {
+                ui.data_mut(|d| d.insert_temp(id, sizes));
+                ui.ctx().request_repaint();
+            }
+
+            let size = vec2(content_width, cursor.y + row_height);
+            let response = ui.allocate_rect(Rect::from_min_size(top_left, size), Sense::hover());
+            InnerResponse::new(inner, response)
+        })
+        .inner
+    }
+}
+
+/// Add items to a [`FlowLayout`].
+pub struct FlowUi<'a> {
+    ui: &'a mut Ui,
+    gap: Vec2,
+    top_left: Pos2,
+    max_width: f32,
+
+    /// Sizes of the items last frame.
+    prev_sizes: Vec<Vec2>,
+
+    /// Sizes of the items this frame.
+    sizes: Vec<Vec2>,
+
+    /// Where the next item goes, relative to `top_left`.
+    cursor: Vec2,
+
+    /// Height of the tallest item in the current row.
+    row_height: f32,
+
+    content_width: f32,
+}
+
+impl FlowUi<'_> {
+    /// Add an item to the flow, with its own [`Ui`].
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let index = self.sizes.len();
+
+        // Wrap before the item if we know from last frame that it won't fit:
+        if let Some(prev_size) = self.prev_sizes.get(index) {
+            if 0.0 < self.cursor.x && self.max_width < self.cursor.x + prev_size.x {
+                self.cursor = vec2(0.0, self.cursor.y + self.row_height + self.gap.y);
+                self.row_height = 0.0;
+            }
+        }
+
+        // Give the item the full width, so it doesn't wrap its contents just because
+        // it is at the end of a row. If it is too wide, it will be wrapped next frame.
+        let max_rect = Rect::from_min_size(
+            self.top_left + self.cursor,
+            vec2(self.max_width, f32::INFINITY),
+        );
+        let response = self.ui.scope_builder(
+            UiBuilder::new()
+                .id_salt(index)
+                .max_rect(max_rect)
+                .layout(Layout::top_down(Align::Min)),
+            add_contents,
+        );
+
+        let size = response.response.rect.size();
+        self.sizes.push(size);
+        self.cursor.x += size.x;
+        self.content_width = self.content_width.max(self.cursor.x);
+        self.cursor.x += self.gap.x;
+        self.row_height = self.row_height.max(size.y);
+
+        response
+    }
+}
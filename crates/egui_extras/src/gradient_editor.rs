@@ -0,0 +1,317 @@
+//! Editing of multi-stop color gradients.
+
+use egui::{
+    Color32, Mesh, Popup, PopupCloseBehavior, Pos2, Rect, RectAlign, Response, Sense,
+    SetOpenCommand, Shape, Stroke, StrokeKind, Ui, Widget, WidgetInfo, WidgetType,
+    color_picker::{Alpha, color_picker_color32, show_color_at},
+    lerp, pos2, remap_clamp, vec2,
+};
+
+/// Edit a color gradient made of `(position, color)` stops, e.g. for heatmaps or theme ramps.
+///
+/// The gradient is shown as a bar, with a marker below it for each stop:
+/// * Drag a marker to move the stop.
+/// * Click a marker to edit its color.
+/// * Double-click the bar to add a stop.
+/// * Drag a marker away from the bar to remove the stop.
+///
+/// The stops are kept sorted by position, and the positions are clamped to `[0, 1]`.
+/// There is always at least one stop left.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui::Color32;
+/// let mut stops = vec![(0.0, Color32::BLUE), (0.5, Color32::YELLOW), (1.0, Color32::RED)];
+/// if ui.add(egui_extras::GradientEditor::new(&mut stops)).changed() {
+///     // Recolor your heatmap…
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct GradientEditor<'a> {
+    stops: &'a mut Vec<(f32, Color32)>,
+    width: Option<f32>,
+    height: Option<f32>,
+    alpha: Alpha,
+}
+
+impl<'a> GradientEditor<'a> {
+    pub fn new(stops: &'a mut Vec<(f32, Color32)>) -> Self {
+        Self {
+            stops,
+            width: None,
+            height: None,
+            alpha: Alpha::BlendOrAdditive,
+        }
+    }
+
+    /// Width of the gradient bar.
+    ///
+    /// Default: [`egui::style::Spacing::slider_width`].
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the gradient bar, not including the stop markers below it.
+    ///
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// What alpha options the color picker of a stop shows.
+    ///
+    /// Default: [`Alpha::BlendOrAdditive`].
+    #[inline]
+    pub fn alpha(mut self, alpha: Alpha) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+/// Remembered between frames.
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// The stop being dragged.
+    dragged: Option<usize>,
+
+    /// The stop whose color picker is open.
+    editing: Option<usize>,
+}
+
+impl Widget for GradientEditor<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            stops,
+            width,
+            height,
+            alpha,
+        } = self;
+
+        let mut changed = sanitize(stops);
+        if stops.is_empty() {
+            stops.push((0.0, Color32::WHITE));
+            changed = true;
+        }
+
+        let width = width.unwrap_or_else(|| ui.spacing().slider_width);
+        let bar_height = height.unwrap_or_else(|| ui.spacing().interact_size.y);
+        let marker_size = ui.spacing().interact_size.y;
+
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(width, bar_height + marker_size), Sense::hover());
+        let bar_rect = Rect::from_min_size(rect.min, vec2(width, bar_height));
+        let markers_rect = Rect::from_x_y_ranges(rect.x_range(), bar_rect.bottom()..=rect.bottom());
+
+        let id = response.id;
+        let bar_response = ui.interact(bar_rect, id.with("bar"), Sense::click());
+        let markers_response = ui.interact(markers_rect, id.with("stops"), Sense::click_and_drag());
+
+        let mut state: State = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        state.dragged = state.dragged.filter(|&index| index < stops.len());
+        state.editing = state.editing.filter(|&index| index < stops.len());
+
+        let x_from_t = |t: f32| lerp(bar_rect.x_range(), t);
+        let t_from_x = |x: f32| remap_clamp(x, bar_rect.x_range(), 0.0..=1.0);
+        let stop_at = |stops: &[(f32, Color32)], pos: Pos2| -> Option<usize> {
+            // The closest stop, if the pointer is on its marker:
+            stops
+                .iter()
+                .enumerate()
+                .map(|(index, (t, _))| (index, (x_from_t(*t) - pos.x).abs()))
+                .filter(|(_, distance)| *distance <= marker_size / 2.0)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(index, _)| index)
+        };
+
+        // Dragged far enough from the bar, the stop is removed on release:
+        let remove_rect = rect.expand(2.0 * marker_size);
+        let mut removing = false;
+
+        if markers_response.drag_started() {
+            // The pointer may already have moved away from the marker it was pressed on:
+            state.dragged = ui
+                .input(|i| i.pointer.press_origin())
+                .and_then(|pos| stop_at(stops, pos));
+        }
+        if let (Some(index), Some(pos)) = (state.dragged, markers_response.interact_pointer_pos()) {
+            removing = stops.len() > 1 && !remove_rect.contains(pos);
+            let t = t_from_x(pos.x);
+            if stops[index].0 != t {
+                let stop = (t, stops.remove(index).1);
+                let new_index = stops.partition_point(|(other, _)| *other <= t);
+                stops.insert(new_index, stop);
+                state.dragged = Some(new_index);
+                if state.editing == Some(index) {
+                    state.editing = Some(new_index);
+                }
+                changed = true;
+            }
+        }
+        if markers_response.drag_stopped() {
+            if let Some(index) = state.dragged.take() {
+                if removing {
+                    stops.remove(index);
+                    state.editing = None;
+                    removing = false;
+                    changed = true;
+                }
+            }
+        }
+
+        if markers_response.clicked() {
+            state.editing = markers_response
+                .interact_pointer_pos()
+                .and_then(|pos| stop_at(stops, pos));
+        }
+
+        if bar_response.double_clicked() {
+            if let Some(pos) = bar_response.interact_pointer_pos() {
+                let t = t_from_x(pos.x);
+                let new_index = stops.partition_point(|(other, _)| *other <= t);
+                stops.insert(new_index, (t, color_at(stops, t)));
+                changed = true;
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            paint_gradient(ui, bar_rect, stops);
+
+            let visuals = ui.style().interact(&markers_response);
+            for (index, (t, color)) in stops.iter().enumerate() {
+                let is_active = state.dragged == Some(index) || state.editing == Some(index);
+                let stroke = if is_active {
+                    ui.visuals().selection.stroke
+                } else {
+                    visuals.fg_stroke
+                };
+                let x = x_from_t(*t);
+                let swatch = marker_swatch(markers_rect, x, marker_size);
+
+                let mut painter = ui.painter().clone();
+                if removing && state.dragged == Some(index) {
+                    // Show that the stop will be removed:
+                    painter.multiply_opacity(0.3);
+                }
+                painter.add(Shape::convex_polygon(
+                    vec![
+                        pos2(x, markers_rect.top()),
+                        pos2(swatch.right(), swatch.top()),
+                        pos2(swatch.left(), swatch.top()),
+                    ],
+                    stroke.color,
+                    Stroke::NONE,
+                ));
+                show_color_at(&painter, *color, swatch);
+                painter.rect_stroke(swatch, 2.0, stroke, StrokeKind::Outside);
+            }
+        }
+
+        // The color picker of the clicked stop:
+        let popup_id = id.with("popup");
+        if let Some(index) = state.editing {
+            let swatch = marker_swatch(markers_rect, x_from_t(stops[index].0), marker_size);
+            let opened_now = markers_response.clicked();
+            let popup = Popup::from_response(&markers_response)
+                .id(popup_id)
+                .anchor(swatch)
+                .align(RectAlign::BOTTOM_START)
+                .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                .open_memory(opened_now.then_some(SetOpenCommand::Bool(true)))
+                .show(|ui| {
+                    ui.spacing_mut().slider_width = 275.0;
+                    color_picker_color32(ui, &mut stops[index].1, alpha)
+                });
+            if let Some(popup) = popup {
+                changed |= popup.inner;
+            } else {
+                state.editing = None;
+            }
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        response |= bar_response;
+        response |= markers_response;
+        if changed {
+            response.mark_changed();
+        }
+        response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), "Gradient"));
+        response
+    }
+}
+
+/// Clamp the positions to `[0, 1]` and sort the stops by them.
+///
+/// Returns `true` if anything changed.
+fn sanitize(stops: &mut [(f32, Color32)]) -> bool {
+    let mut changed = false;
+    for (t, _) in stops.iter_mut() {
+        let clamped = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        if *t != clamped {
+            *t = clamped;
+            changed = true;
+        }
+    }
+    if !stops.is_sorted_by(|(a, _), (b, _)| a <= b) {
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        changed = true;
+    }
+    changed
+}
+
+/// The color of the gradient at `t`, for sorted stops.
+fn color_at(stops: &[(f32, Color32)], t: f32) -> Color32 {
+    let after = stops.partition_point(|(other, _)| *other <= t);
+    match (
+        after.checked_sub(1).map(|before| stops[before]),
+        stops.get(after),
+    ) {
+        (Some((t0, c0)), Some(&(t1, c1))) => {
+            c0.lerp_to_gamma(c1, remap_clamp(t, t0..=t1, 0.0..=1.0))
+        }
+        (Some((_, color)), None) | (None, Some(&(_, color))) => color,
+        (None, None) => Color32::TRANSPARENT,
+    }
+}
+
+fn paint_gradient(ui: &Ui, rect: Rect, stops: &[(f32, Color32)]) {
+    // One column of vertices at each stop, and at both ends:
+    let mut ts = vec![0.0];
+    ts.extend(stops.iter().map(|(t, _)| *t));
+    ts.push(1.0);
+
+    let mut mesh = Mesh::default();
+    for (i, &t) in ts.iter().enumerate() {
+        let color = color_at(stops, t);
+        let x = lerp(rect.x_range(), t);
+        mesh.colored_vertex(pos2(x, rect.top()), color);
+        mesh.colored_vertex(pos2(x, rect.bottom()), color);
+        if 0 < i {
+            let i = 2 * i as u32;
+            mesh.add_triangle(i - 2, i - 1, i);
+            mesh.add_triangle(i - 1, i, i + 1);
+        }
+    }
+    ui.painter().add(Shape::mesh(mesh));
+    ui.painter().rect_stroke(
+        rect,
+        0.0,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+        StrokeKind::Inside,
+    );
+}
+
+/// The colored square of the marker of a stop at `x`.
+fn marker_swatch(markers_rect: Rect, x: f32, marker_size: f32) -> Rect {
+    let size = 0.6 * marker_size;
+    Rect::from_min_size(
+        pos2(x - size / 2.0, markers_rect.bottom() - size),
+        vec2(size, size),
+    )
+}
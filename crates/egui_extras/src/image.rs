@@ -63,15 +63,17 @@ pub fn load_svg_bytes_with_size(
     size_hint: SizeHint,
     options: &resvg::usvg::Options<'_>,
 ) -> Result<egui::ColorImage, String> {
-    use egui::Vec2;
-    use resvg::{
-        tiny_skia::Pixmap,
-        usvg::{Transform, Tree},
-    };
-
     profiling::function_scope!();
 
-    let rtree = Tree::from_data(svg_bytes, options).map_err(|err| err.to_string())?;
+    let rtree = resvg::usvg::Tree::from_data(svg_bytes, options).map_err(|err| err.to_string())?;
+    let [w, h] = svg_size_in_pixels(&rtree, size_hint);
+    rasterize_svg_tree(&rtree, [w, h])
+}
+
+/// How many pixels to rasterize the SVG into, to fill the `size_hint`.
+#[cfg(feature = "svg")]
+pub(crate) fn svg_size_in_pixels(rtree: &resvg::usvg::Tree, size_hint: SizeHint) -> [u32; 2] {
+    use egui::Vec2;
 
     let source_size = Vec2::new(rtree.size().width(), rtree.size().height());
 
@@ -99,13 +101,27 @@ pub fn load_svg_bytes_with_size(
     };
 
     let scaled_size = scaled_size.round();
-    let (w, h) = (scaled_size.x as u32, scaled_size.y as u32);
+    [scaled_size.x as u32, scaled_size.y as u32]
+}
+
+/// Rasterize a parsed SVG into an image of `[w, h]` pixels.
+#[cfg(feature = "svg")]
+pub(crate) fn rasterize_svg_tree(
+    rtree: &resvg::usvg::Tree,
+    [w, h]: [u32; 2],
+) -> Result<egui::ColorImage, String> {
+    use egui::Vec2;
+    use resvg::{tiny_skia::Pixmap, usvg::Transform};
+
+    profiling::function_scope!();
+
+    let source_size = Vec2::new(rtree.size().width(), rtree.size().height());
 
     let mut pixmap =
         Pixmap::new(w, h).ok_or_else(|| format!("Failed to create SVG Pixmap of size {w}x{h}"))?;
 
     resvg::render(
-        &rtree,
+        rtree,
         Transform::from_scale(w as f32 / source_size.x, h as f32 / source_size.y),
         &mut pixmap.as_mut(),
     );
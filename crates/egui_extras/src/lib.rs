@@ -14,23 +14,43 @@ mod datepicker;
 
 pub mod syntax_highlighting;
 
+mod flow;
+mod gradient_editor;
 #[doc(hidden)]
 pub mod image;
 mod layout;
+mod list_view;
 mod loaders;
+mod masonry;
 mod sizing;
+mod sparkline;
 mod strip;
+mod tab_bar;
 mod table;
+mod toasts;
+mod tree_view;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+pub use crate::flow::{FlowLayout, FlowUi};
+pub use crate::gradient_editor::GradientEditor;
 pub(crate) use crate::layout::StripLayout;
+pub use crate::list_view::{ListView, ListViewResponse};
+pub use crate::masonry::{MasonryLayout, MasonryUi};
 pub use crate::sizing::Size;
+pub use crate::sparkline::Sparkline;
 pub use crate::strip::*;
+pub use crate::tab_bar::{TabBar, TabBarResponse};
 pub use crate::table::*;
+pub use crate::toasts::{Toast, ToastKind, Toasts};
+pub use crate::tree_view::{TreeNode, TreeView, TreeViewResponse, TreeViewState};
 
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+pub use loaders::DiskCacheLoader;
 pub use loaders::install_image_loaders;
+#[cfg(feature = "http")]
+pub use loaders::set_http_headers;
 
 // ---------------------------------------------------------------------------
 
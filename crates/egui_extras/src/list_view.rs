@@ -0,0 +1,347 @@
+//! A virtualized list of items of different heights, e.g. chat messages or a feed.
+//!
+//! Only the visible items are laid out. The heights of the others are remembered from
+//! when they were last shown, or estimated from the average of the known ones.
+
+use std::ops::Range;
+
+use egui::{Align, Id, NumExt as _, Rect, ScrollArea, Ui, UiBuilder, pos2, vec2};
+
+/// Where the top of the viewport is, relative to an item.
+#[derive(Clone, Copy, Debug)]
+struct Anchor {
+    index: usize,
+
+    /// From the top of the item to the top of the viewport.
+    offset: f32,
+}
+
+/// Stored in [`egui::Memory`] between frames.
+#[derive(Clone, Debug, Default)]
+struct ListViewState {
+    /// The measured height of each item, if it has been shown.
+    heights: Vec<Option<f32>>,
+
+    /// The heights were measured at this revision and width.
+    revision: u64,
+    width: f32,
+
+    /// Keeps the visible items in place when the heights of the ones above them change.
+    anchor: Option<Anchor>,
+
+    /// The scroll offset at the end of the last frame.
+    scroll_offset: f32,
+    viewport_height: Option<f32>,
+    at_bottom: bool,
+
+    /// A [`ListView::scroll_to_item`] that needs another frame, to measure the item.
+    scroll_to: Option<(usize, Align)>,
+}
+
+/// What happened in a [`ListView`] this frame.
+pub struct ListViewResponse {
+    /// Where the list was shown.
+    pub inner_rect: Rect,
+
+    /// The items that were shown.
+    pub visible: Range<usize>,
+
+    /// Is the list scrolled all the way to the end?
+    pub at_bottom: bool,
+}
+
+/// A vertically scrolling list, where the items can have different heights.
+///
+/// Like [`ScrollArea::show_rows`], only the visible items are laid out, but they don't need to
+/// have the same height. This uses the height of each item from the last time it was shown,
+/// and the average height for the ones that haven't been shown yet.
+///
+/// The measured heights are remembered by index, so tell the list when they change:
+/// * Bump [`Self::revision`] when the heights of existing items may have changed.
+/// * Call [`Self::prepended`] when you insert items at the start, e.g. when loading older messages.
+///   Appending items needs nothing.
+///
+/// The items that are visible stay where they are when the heights of the ones above change,
+/// so loading older history or measuring new items doesn't make the contents jump.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let messages = vec![String::from("Hello")];
+/// egui_extras::ListView::new("chat")
+///     .stick_to_bottom(true)
+///     .show(ui, messages.len(), |ui, i| {
+///         ui.label(&messages[i]);
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ListView {
+    id_salt: Id,
+    estimated_item_height: Option<f32>,
+    max_height: f32,
+    stick_to_bottom: bool,
+    revision: u64,
+    prepended: usize,
+    scroll_to: Option<(usize, Align)>,
+}
+
+impl ListView {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            estimated_item_height: None,
+            max_height: f32::INFINITY,
+            stick_to_bottom: false,
+            revision: 0,
+            prepended: 0,
+            scroll_to: None,
+        }
+    }
+
+    /// The height of items before any of them have been shown.
+    ///
+    /// After that, the average height of the shown items is used.
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn estimated_item_height(mut self, height: f32) -> Self {
+        self.estimated_item_height = Some(height);
+        self
+    }
+
+    /// The list will never be taller than this, and will scroll if needed.
+    ///
+    /// Default: fill the available height.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Stay scrolled to the end when new items are added, unless the user has scrolled up.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn stick_to_bottom(mut self, stick: bool) -> Self {
+        self.stick_to_bottom = stick;
+        self
+    }
+
+    /// Change this to forget all the measured heights, e.g. when the items have been edited.
+    ///
+    /// The heights are also forgotten when the width of the list changes.
+    #[inline]
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.revision = revision;
+        self
+    }
+
+    /// Call this in the frame that `count` items were inserted at the start of the list.
+    ///
+    /// The remembered heights are moved along with the items,
+    /// and the visible items stay where they are.
+    #[inline]
+    pub fn prepended(mut self, count: usize) -> Self {
+        self.prepended = count;
+        self
+    }
+
+    /// Scroll so that the item with this index is shown at the top, center or bottom.
+    #[inline]
+    pub fn scroll_to_item(mut self, index: usize, align: Align) -> Self {
+        self.scroll_to = Some((index, align));
+        self
+    }
+
+    /// Show the list.
+    ///
+    /// `add_item` is called for each visible item, with a top-down [`Ui`] as wide as the list.
+    /// Each item gets its own [`Id`] based on its index.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        len: usize,
+        mut add_item: impl FnMut(&mut Ui, usize),
+    ) -> ListViewResponse {
+        let id = ui.make_persistent_id(self.id_salt);
+        let mut state: ListViewState = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        let width = ui.available_width();
+        let spacing = ui.spacing().item_spacing.y;
+        let default_height = self
+            .estimated_item_height
+            .unwrap_or_else(|| ui.spacing().interact_size.y);
+
+        if 0 < self.prepended {
+            state
+                .heights
+                .splice(0..0, std::iter::repeat_n(None, self.prepended));
+            if let Some(anchor) = &mut state.anchor {
+                anchor.index += self.prepended;
+            }
+            if let Some((index, _)) = &mut state.scroll_to {
+                *index += self.prepended;
+            }
+        }
+        if state.revision != self.revision || 0.5 < (state.width - width).abs() {
+            state.heights.clear();
+            state.revision = self.revision;
+            state.width = width;
+        }
+        state.heights.resize(len, None);
+        if self.scroll_to.is_some() {
+            state.scroll_to = self.scroll_to;
+        }
+        state.scroll_to = state.scroll_to.filter(|&(index, _)| index < len);
+
+        let estimate = estimate_height(&state.heights, default_height);
+        let mut tops = item_tops(&state.heights, estimate, spacing);
+        let viewport_height = state
+            .viewport_height
+            .unwrap_or_else(|| ui.available_height().at_most(self.max_height));
+        let max_offset = (content_height(&tops, spacing) - viewport_height).at_least(0.0);
+
+        let scrolling_to_item = state.scroll_to.is_some();
+        let mut item_was_measured = false;
+        let mut offset = None;
+        if let Some((index, align)) = state.scroll_to {
+            let measured = state.heights[index];
+            item_was_measured = measured.is_some();
+            let height = measured.unwrap_or(estimate);
+            let target = match align {
+                Align::Min => tops[index],
+                Align::Center => tops[index] + 0.5 * (height - viewport_height),
+                Align::Max => tops[index] + height - viewport_height,
+            };
+            offset = Some(target.clamp(0.0, max_offset));
+        } else if let Some(anchor) = state.anchor {
+            // When pinned to the bottom, that is what stays in place instead:
+            let pinned = self.stick_to_bottom && state.at_bottom;
+            if !pinned && anchor.index < len {
+                let target = tops[anchor.index] + anchor.offset;
+                if 0.5 < (target - state.scroll_offset).abs() {
+                    offset = Some(target.clamp(0.0, max_offset));
+                }
+            }
+        }
+
+        let mut scroll_area = ScrollArea::vertical()
+            .id_salt(id.with("scroll"))
+            .auto_shrink([false, true])
+            .max_height(self.max_height)
+            // Sticking would undo scrolling to an item:
+            .stick_to_bottom(self.stick_to_bottom && !scrolling_to_item);
+        if let Some(offset) = offset {
+            scroll_area = scroll_area.vertical_scroll_offset(offset);
+        }
+
+        let heights = &mut state.heights;
+        let tops = &mut tops;
+        let output = scroll_area.show_viewport(ui, |ui, viewport| {
+            let top = ui.max_rect().top();
+            let first = tops[1..].partition_point(|&t| t <= viewport.min.y).min(len);
+
+            // Lay out the visible items after each other, using the heights they have now:
+            let mut y = tops[first];
+            let mut index = first;
+            let mut measured_new_heights = false;
+            while index < len && y < viewport.max.y {
+                tops[index] = y;
+                let rect = Rect::from_min_size(
+                    pos2(ui.max_rect().left(), top + y),
+                    vec2(ui.max_rect().width(), f32::INFINITY),
+                );
+                let height = ui
+                    .scope_builder(UiBuilder::new().id_salt(index).max_rect(rect), |ui| {
+                        add_item(ui, index);
+                    })
+                    .response
+                    .rect
+                    .height();
+                // Far down a long list, the rounding errors of `f32` vary with the position:
+                if heights[index].is_none_or(|old| 0.5 < (old - height).abs()) {
+                    heights[index] = Some(height);
+                    measured_new_heights = true;
+                }
+                y += height + spacing;
+                index += 1;
+            }
+
+            // Move the items below along with the ones we just measured, and make room for them:
+            let shift = y - tops[index];
+            for t in &mut tops[index..] {
+                *t += shift;
+            }
+            ui.expand_to_include_y(top + content_height(tops, spacing));
+
+            (first..index, measured_new_heights, viewport.min.y)
+        });
+        let (visible, measured_new_heights, viewport_top) = output.inner;
+
+        if item_was_measured && !measured_new_heights {
+            // Nothing could have moved it this time:
+            state.scroll_to = None;
+        }
+
+        // Remember where the viewport is relative to the items as they were shown,
+        // so it stays there even if the estimated heights of the ones above change:
+        let scroll_offset = output.state.offset.y;
+        state.anchor = (0 < len).then(|| {
+            let index = tops[1..]
+                .partition_point(|&t| t <= scroll_offset)
+                .at_most(len - 1);
+            Anchor {
+                index,
+                offset: scroll_offset - tops[index],
+            }
+        });
+        state.scroll_offset = scroll_offset;
+        state.viewport_height = Some(output.inner_rect.height());
+        let max_offset = output.content_size.y - output.inner_rect.height();
+        state.at_bottom = max_offset - 0.5 <= scroll_offset;
+
+        // Sticking to the bottom moves the contents after they were laid out.
+        // New heights only change the items that aren't shown, so they can wait.
+        let moved = 0.5 < (viewport_top - scroll_offset).abs();
+        if moved || state.scroll_to.is_some() {
+            ui.ctx().request_repaint();
+        }
+
+        let at_bottom = state.at_bottom;
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        ListViewResponse {
+            inner_rect: output.inner_rect,
+            visible,
+            at_bottom,
+        }
+    }
+}
+
+/// The average of the measured heights, or `default` if there are none.
+fn estimate_height(heights: &[Option<f32>], default: f32) -> f32 {
+    let (sum, count) = heights
+        .iter()
+        .flatten()
+        .fold((0.0, 0), |(sum, count), height| (sum + height, count + 1));
+    if 0 < count {
+        sum / count as f32
+    } else {
+        default
+    }
+}
+
+/// Where each item starts, relative to the top of the list, and then where an item after the last one would start.
+fn item_tops(heights: &[Option<f32>], estimate: f32, spacing: f32) -> Vec<f32> {
+    let mut tops = Vec::with_capacity(heights.len() + 1);
+    let mut y = 0.0;
+    tops.push(y);
+    for height in heights {
+        y += height.unwrap_or(estimate) + spacing;
+        tops.push(y);
+    }
+    tops
+}
+
+fn content_height(tops: &[f32], spacing: f32) -> f32 {
+    let last = tops.last().copied().unwrap_or_default();
+    if 1 < tops.len() { last - spacing } else { last }
+}
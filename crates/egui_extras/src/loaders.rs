@@ -40,6 +40,8 @@
 ///
 /// The `http` loader is a [`BytesLoader`][`egui::load::BytesLoader`].
 /// It will attempt to load `http://` and `https://` URIs, and infer the content type from the `Content-Type` header.
+/// Use `set_http_headers` to send extra headers, e.g. for authorization.
+/// To keep the downloads around after the app restarts, also add a `DiskCacheLoader`.
 ///
 /// The `image` loader is an [`ImageLoader`][`egui::load::ImageLoader`].
 /// It will attempt to load any URI with any extension other than `svg`.
@@ -107,12 +109,51 @@ pub fn install_image_loaders(ctx: &egui::Context) {
     let _ = ctx;
 }
 
+/// Send extra headers with the requests of the `http` loader,
+/// e.g. to load images from an endpoint that requires authorization.
+///
+/// `headers` is called with the URI of each request, so you can pick the headers by origin,
+/// and avoid leaking credentials to other servers:
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let token = "secret";
+/// let token = token.to_owned();
+/// egui_extras::set_http_headers(&ctx, move |uri| {
+///     if uri.starts_with("https://dashboard.example.com/") {
+///         vec![("Authorization".to_owned(), format!("Bearer {token}"))]
+///     } else {
+///         vec![]
+///     }
+/// });
+/// ```
+///
+/// ⚠ The loaded bytes are cached by URI only, regardless of the headers they were loaded with.
+/// So changing the headers (e.g. when a token expires) doesn't reload anything that has already been loaded,
+/// or is being loaded. Use [`egui::Context::forget_image`] or [`egui::Context::forget_all_images`] for that.
+///
+/// The callback is kept in the [`egui::Memory`] of the context, and is called from the ui thread.
+/// Calling this again replaces it.
+#[cfg(feature = "http")]
+pub fn set_http_headers(
+    ctx: &egui::Context,
+    headers: impl Fn(&str) -> Vec<(String, String)> + Send + Sync + 'static,
+) {
+    let headers = self::ehttp_loader::HttpHeaders(std::sync::Arc::new(headers));
+    ctx.data_mut(|data| data.insert_temp(egui::Id::NULL, headers));
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod file_loader;
 
 #[cfg(feature = "http")]
 mod ehttp_loader;
 
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+mod disk_cache_loader;
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+pub use self::disk_cache_loader::DiskCacheLoader;
+
 #[cfg(feature = "gif")]
 mod gif_loader;
 #[cfg(feature = "image")]
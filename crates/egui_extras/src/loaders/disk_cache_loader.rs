@@ -0,0 +1,483 @@
+use ahash::HashMap;
+use egui::{
+    load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
+    mutex::Mutex,
+};
+use std::{
+    fs,
+    io::{self, BufRead as _, Read as _},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::SystemTime,
+};
+
+use super::ehttp_loader::EhttpLoader;
+
+#[derive(Clone)]
+struct File {
+    bytes: Arc<[u8]>,
+    mime: Option<String>,
+}
+
+enum Entry {
+    /// Being read from disk.
+    Reading,
+
+    /// Not on disk, so it is downloaded.
+    Downloading,
+
+    Ready(File),
+}
+
+/// A `http(s)://` loader that keeps the downloaded bytes in a directory on disk,
+/// so they don't need to be downloaded again after the app restarts.
+///
+/// The cached files are named after a hash of the URI, and are checked before going to the network.
+/// Like with `file://` URIs, the files are read on a background thread.
+/// When the files take up more than [`Self::max_bytes`],
+/// the ones that were used the longest time ago are deleted.
+///
+/// Several instances of the app can share the same directory:
+/// files are written to a temporary file first, and then renamed into place,
+/// so a file is never read while it is only partially written.
+///
+/// Add it after [`crate::install_image_loaders`], so it is tried before the `http` loader:
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// egui_extras::install_image_loaders(&ctx);
+/// ctx.add_bytes_loader(std::sync::Arc::new(
+///     egui_extras::DiskCacheLoader::new("cache/images").max_bytes(50_000_000),
+/// ));
+/// ```
+///
+/// [`egui::Context::forget_image`] and [`egui::Context::forget_all_images`] only release the memory,
+/// the next load reads the file again.
+/// Use [`Self::remove`] or [`Self::clear`] to download something again.
+pub struct DiskCacheLoader {
+    dir: PathBuf,
+    max_bytes: u64,
+    network: EhttpLoader,
+
+    /// What we read from disk or downloaded.
+    cache: Arc<Mutex<HashMap<String, Entry>>>,
+
+    /// How much the files in [`Self::dir`] take up, as far as we know.
+    ///
+    /// `None` until the directory has been scanned.
+    /// Kept up to date as we write files, so it needs to be scanned only when it's time to evict.
+    disk_bytes: Arc<Mutex<Option<u64>>>,
+}
+
+impl DiskCacheLoader {
+    pub const ID: &'static str = egui::generate_loader_id!(DiskCacheLoader);
+
+    /// Cache the downloads in `dir`, which is created if it doesn't exist.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes: 100_000_000,
+            network: EhttpLoader::default(),
+            cache: Default::default(),
+            disk_bytes: Default::default(),
+        }
+    }
+
+    /// The most the cached files may take up on disk, in bytes.
+    ///
+    /// Default: 100 MB.
+    #[inline]
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Delete the cached file for `uri`, so it is downloaded again the next time it is loaded.
+    pub fn remove(&self, uri: &str) {
+        self.forget(uri);
+        if let Err(err) = fs::remove_file(path(&self.dir, uri)) {
+            if err.kind() != io::ErrorKind::NotFound {
+                log::warn!("Failed to remove {uri:?} from the disk cache: {err}");
+            }
+        }
+        *self.disk_bytes.lock() = None;
+    }
+
+    /// Delete all the cached files.
+    pub fn clear(&self) {
+        self.forget_all();
+        for file in cached_files(&self.dir) {
+            let _ = fs::remove_file(file.path);
+        }
+        *self.disk_bytes.lock() = Some(0);
+    }
+}
+
+const PROTOCOLS: &[&str] = &["http://", "https://"];
+
+const EXTENSION: &str = "bin";
+
+/// Where the bytes of `uri` are cached.
+///
+/// The file name is the 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the URI,
+/// which is the same on every platform and in every version, so the files can be found again.
+fn path(dir: &Path, uri: &str) -> PathBuf {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    let hash = uri.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    });
+    dir.join(format!("{hash:016x}.{EXTENSION}"))
+}
+
+/// Each file starts with the full URI, in case of hash collisions, and the mime type.
+fn read(dir: &Path, uri: &str) -> io::Result<Option<File>> {
+    let path = path(dir, uri);
+    let mut reader = match fs::File::open(&path) {
+        Ok(file) => io::BufReader::new(file),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    if line.strip_suffix('\n') != Some(uri) {
+        return Ok(None);
+    }
+    line.clear();
+    reader.read_line(&mut line)?;
+    let Some(mime) = line.strip_suffix('\n') else {
+        return Ok(None);
+    };
+    let mime = (!mime.is_empty()).then(|| mime.to_owned());
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes)?;
+
+    // The least recently used files are evicted first:
+    if let Err(err) = fs::File::options()
+        .append(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(SystemTime::now()))
+    {
+        log::debug!("Failed to touch {path:?}: {err}");
+    }
+
+    Ok(Some(File {
+        bytes: bytes.into(),
+        mime,
+    }))
+}
+
+/// Returns the size of the file.
+fn write(dir: &Path, uri: &str, file: &File) -> io::Result<u64> {
+    // Unique, so simultaneous writes of the same URI (e.g. from other instances of the app)
+    // each have a file of their own:
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let temp = dir.join(format!(
+        "{}-{}.tmp",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    fs::create_dir_all(dir)?;
+    let mut contents = Vec::with_capacity(uri.len() + file.bytes.len() + 64);
+    contents.extend_from_slice(uri.as_bytes());
+    contents.push(b'\n');
+    contents.extend_from_slice(file.mime.as_deref().unwrap_or_default().as_bytes());
+    contents.push(b'\n');
+    contents.extend_from_slice(&file.bytes);
+    fs::write(&temp, &contents)?;
+
+    // Renaming replaces the file in one go, so readers get either the old or the new one:
+    fs::rename(&temp, path(dir, uri)).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })?;
+    Ok(contents.len() as u64)
+}
+
+struct CachedFile {
+    path: PathBuf,
+    len: u64,
+    used: SystemTime,
+}
+
+fn cached_files(dir: &Path) -> Vec<CachedFile> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == EXTENSION))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some(CachedFile {
+                path: entry.path(),
+                len: metadata.len(),
+                used: metadata.modified().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Delete the least recently used files until the rest fit in `max_bytes`.
+///
+/// Returns how much the rest take up.
+fn evict(dir: &Path, max_bytes: u64) -> u64 {
+    let mut files = cached_files(dir);
+    let mut total: u64 = files.iter().map(|file| file.len).sum();
+    files.sort_by_key(|file| file.used);
+    for file in files {
+        if total <= max_bytes {
+            break;
+        }
+        // Another instance of the app may have deleted it already:
+        let _ = fs::remove_file(&file.path);
+        total = total.saturating_sub(file.len);
+    }
+    total
+}
+
+/// Write `file` to the disk cache, and make room for it if needed.
+fn store(dir: &Path, max_bytes: u64, disk_bytes: &Mutex<Option<u64>>, uri: &str, file: &File) {
+    let len = match write(dir, uri, file) {
+        Ok(len) => len,
+        Err(err) => {
+            log::warn!("Failed to write {uri:?} to the disk cache: {err}");
+            return;
+        }
+    };
+
+    let mut disk_bytes = disk_bytes.lock();
+    // Overestimates if the file was already there, which at worst means an early scan:
+    let total = disk_bytes.map(|total| total + len);
+    *disk_bytes = match total {
+        Some(total) if total <= max_bytes => Some(total),
+        _ => Some(evict(dir, max_bytes)),
+    };
+}
+
+impl BytesLoader for DiskCacheLoader {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn load(&self, ctx: &egui::Context, uri: &str) -> BytesLoadResult {
+        if !PROTOCOLS.iter().any(|protocol| uri.starts_with(protocol)) {
+            return Err(LoadError::NotSupported);
+        }
+
+        let mut cache = self.cache.lock();
+        match cache.get(uri) {
+            Some(Entry::Ready(file)) => {
+                return Ok(BytesPoll::Ready {
+                    size: None,
+                    bytes: Bytes::Shared(file.bytes.clone()),
+                    mime: file.mime.clone(),
+                });
+            }
+            Some(Entry::Reading) => return Ok(BytesPoll::Pending { size: None }),
+            Some(Entry::Downloading) => {}
+            None => {
+                log::trace!("started reading {uri:?} from the disk cache");
+                cache.insert(uri.to_owned(), Entry::Reading);
+                drop(cache);
+
+                // Spawn a thread to read the file, so that we don't block the render for too long.
+                thread::Builder::new()
+                    .name(format!("egui_extras::DiskCacheLoader::read({uri:?})"))
+                    .spawn({
+                        let ctx = ctx.clone();
+                        let cache = self.cache.clone();
+                        let dir = self.dir.clone();
+                        let uri = uri.to_owned();
+                        move || {
+                            let entry = match read(&dir, &uri) {
+                                Ok(Some(file)) => {
+                                    log::trace!("loaded {uri:?} from the disk cache");
+                                    Entry::Ready(file)
+                                }
+                                Ok(None) => Entry::Downloading,
+                                Err(err) => {
+                                    log::warn!("Failed to read {uri:?} from the disk cache: {err}");
+                                    Entry::Downloading
+                                }
+                            };
+                            if let Some(cached) = cache.lock().get_mut(&uri) {
+                                *cached = entry;
+                                // Either show it, or start downloading it:
+                                ctx.request_repaint();
+                            } else {
+                                log::trace!("Canceled reading {uri:?} from the disk cache");
+                            }
+                        }
+                    })
+                    .expect("failed to spawn thread");
+
+                return Ok(BytesPoll::Pending { size: None });
+            }
+        }
+
+        let result = self.network.load(ctx, uri);
+        if let Ok(BytesPoll::Ready { bytes, mime, .. }) = &result {
+            let file = File {
+                bytes: bytes.as_ref().into(),
+                mime: mime.clone(),
+            };
+            cache.insert(uri.to_owned(), Entry::Ready(file.clone()));
+            // We have our own copy now:
+            self.network.forget(uri);
+
+            let dir = self.dir.clone();
+            let max_bytes = self.max_bytes;
+            let disk_bytes = self.disk_bytes.clone();
+            let uri = uri.to_owned();
+            thread::Builder::new()
+                .name(format!("egui_extras::DiskCacheLoader::write({uri:?})"))
+                .spawn(move || store(&dir, max_bytes, &disk_bytes, &uri, &file))
+                .expect("failed to spawn thread");
+        }
+        result
+    }
+
+    fn forget(&self, uri: &str) {
+        let _ = self.cache.lock().remove(uri);
+        self.network.forget(uri);
+    }
+
+    fn forget_all(&self) {
+        self.cache.lock().clear();
+        self.network.forget_all();
+    }
+
+    fn cancel(&self, uri: &str) {
+        let mut cache = self.cache.lock();
+        if cache
+            .get(uri)
+            .is_some_and(|entry| !matches!(entry, Entry::Ready(_)))
+        {
+            cache.remove(uri);
+        }
+        self.network.cancel(uri);
+    }
+
+    fn byte_size(&self) -> usize {
+        let cached: usize = self
+            .cache
+            .lock()
+            .values()
+            .map(|entry| match entry {
+                Entry::Ready(file) => file.bytes.len() + file.mime.as_ref().map_or(0, |m| m.len()),
+                Entry::Reading | Entry::Downloading => 0,
+            })
+            .sum();
+        cached + self.network.byte_size()
+    }
+
+    fn has_pending(&self) -> bool {
+        self.cache
+            .lock()
+            .values()
+            .any(|entry| matches!(entry, Entry::Reading))
+            || self.network.has_pending()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::disallowed_methods)] // Ok for tests
+
+    use super::*;
+    use std::time::Duration;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "egui_extras_disk_cache_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn file(bytes: &[u8], mime: Option<&str>) -> File {
+        File {
+            bytes: bytes.into(),
+            mime: mime.map(String::from),
+        }
+    }
+
+    #[test]
+    fn serves_cached_files_without_the_network() {
+        let dir = temp_dir("serves");
+        let uri = "https://example.com/image.png";
+        write(&dir, uri, &file(b"\x89PNG\n", Some("image/png"))).unwrap();
+
+        let ctx = egui::Context::default();
+        let loader = DiskCacheLoader::new(&dir);
+        let mut result = loader.load(&ctx, uri);
+        for _ in 0..100 {
+            if !loader.has_pending() {
+                result = loader.load(&ctx, uri);
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let Ok(BytesPoll::Ready { bytes, mime, .. }) = result else {
+            panic!("Expected {uri:?} to be read from disk");
+        };
+        assert_eq!(bytes.as_ref(), b"\x89PNG\n");
+        assert_eq!(mime.as_deref(), Some("image/png"));
+
+        assert!(
+            read(&dir, "https://example.com/other.png")
+                .unwrap()
+                .is_none()
+        );
+        loader.remove(uri);
+        assert!(read(&dir, uri).unwrap().is_none(), "Removed from disk");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn file_names_are_stable() {
+        // Changing these would lose everything that is cached on disk:
+        assert_eq!(
+            path(Path::new("cache"), "a"),
+            Path::new("cache").join("af63dc4c8601ec8c.bin")
+        );
+        assert_eq!(
+            path(Path::new("cache"), ""),
+            Path::new("cache").join("cbf29ce484222325.bin")
+        );
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_files() {
+        let dir = temp_dir("evicts");
+        let uris = ["https://a.com/1", "https://a.com/2", "https://a.com/3"];
+        let start = SystemTime::now() - Duration::from_secs(60);
+        for (i, uri) in uris.into_iter().enumerate() {
+            write(&dir, uri, &file(&[0; 100], None)).unwrap();
+            fs::File::options()
+                .append(true)
+                .open(path(&dir, uri))
+                .unwrap()
+                .set_modified(start + Duration::from_secs(i as u64))
+                .unwrap();
+        }
+
+        // Reading the oldest one makes it the most recently used:
+        assert!(read(&dir, uris[0]).unwrap().is_some());
+        assert_eq!(evict(&dir, 1_000), 3 * (100 + 17), "All fit");
+        assert!(uris.iter().all(|uri| path(&dir, uri).exists()));
+
+        evict(&dir, 250);
+        assert!(path(&dir, uris[0]).exists());
+        assert!(!path(&dir, uris[1]).exists(), "Evicted");
+        assert!(path(&dir, uris[2]).exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
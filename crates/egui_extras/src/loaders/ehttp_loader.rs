@@ -1,5 +1,6 @@
 use ahash::HashMap;
 use egui::{
+    Id,
     load::{Bytes, BytesLoadResult, BytesLoader, BytesPoll, LoadError},
     mutex::Mutex,
 };
@@ -39,6 +40,23 @@ impl File {
 
 type Entry = Poll<Result<File, String>>;
 
+type HeadersFn = dyn Fn(&str) -> Vec<(String, String)> + Send + Sync;
+
+/// Set with [`crate::set_http_headers`], and stored in the [`egui::Memory`] of the context.
+#[derive(Clone)]
+pub(crate) struct HttpHeaders(pub(crate) Arc<HeadersFn>);
+
+/// A GET request for `uri`, with the headers set with [`crate::set_http_headers`].
+fn request(ctx: &egui::Context, uri: &str) -> ehttp::Request {
+    let mut request = ehttp::Request::get(uri);
+    if let Some(HttpHeaders(headers)) = ctx.data(|data| data.get_temp::<HttpHeaders>(Id::NULL)) {
+        for (name, value) in headers(uri) {
+            request.headers.insert(name, value);
+        }
+    }
+    request
+}
+
 #[derive(Default)]
 pub struct EhttpLoader {
     cache: Arc<Mutex<HashMap<String, Entry>>>,
@@ -82,7 +100,7 @@ impl BytesLoader for EhttpLoader {
             cache.insert(uri.clone(), Poll::Pending);
             drop(cache);
 
-            ehttp::fetch(ehttp::Request::get(uri.clone()), {
+            ehttp::fetch(request(ctx, &uri), {
                 let ctx = ctx.clone();
                 let cache = self.cache.clone();
                 move |response| {
@@ -94,9 +112,14 @@ impl BytesLoader for EhttpLoader {
                             Err(format!("Failed to load {uri:?}"))
                         }
                     };
-                    log::trace!("finished loading {uri:?}");
-                    cache.lock().insert(uri, Poll::Ready(result));
-                    ctx.request_repaint();
+                    let mut cache = cache.lock();
+                    if let Some(entry @ Poll::Pending) = cache.get_mut(&uri) {
+                        *entry = Poll::Ready(result);
+                        ctx.request_repaint();
+                        log::trace!("finished loading {uri:?}");
+                    } else {
+                        log::trace!("Canceled loading {uri:?}");
+                    }
                 }
             });
 
@@ -112,6 +135,13 @@ impl BytesLoader for EhttpLoader {
         self.cache.lock().clear();
     }
 
+    fn cancel(&self, uri: &str) {
+        let mut cache = self.cache.lock();
+        if cache.get(uri).is_some_and(|entry| entry.is_pending()) {
+            cache.remove(uri);
+        }
+    }
+
     fn byte_size(&self) -> usize {
         self.cache
             .lock()
@@ -130,3 +160,37 @@ impl BytesLoader for EhttpLoader {
         self.cache.lock().values().any(|entry| entry.is_pending())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_have_the_configured_headers() {
+        let ctx = egui::Context::default();
+        assert!(
+            request(&ctx, "https://example.com/a.png")
+                .headers
+                .get("Authorization")
+                .is_none()
+        );
+
+        crate::set_http_headers(&ctx, |uri| {
+            if uri.starts_with("https://intranet.example.com/") {
+                vec![("Authorization".to_owned(), "Bearer secret".to_owned())]
+            } else {
+                vec![]
+            }
+        });
+        let protected = request(&ctx, "https://intranet.example.com/a.png");
+        assert_eq!(
+            protected.headers.get("authorization"),
+            Some("Bearer secret")
+        );
+        let public = request(&ctx, "https://example.com/a.png");
+        assert!(
+            public.headers.get("Authorization").is_none(),
+            "Only for that origin"
+        );
+    }
+}
@@ -120,6 +120,13 @@ impl BytesLoader for FileLoader {
         self.cache.lock().clear();
     }
 
+    fn cancel(&self, uri: &str) {
+        let mut cache = self.cache.lock();
+        if cache.get(uri).is_some_and(|entry| entry.is_pending()) {
+            cache.remove(uri);
+        }
+    }
+
     fn byte_size(&self) -> usize {
         self.cache
             .lock()
@@ -1,6 +1,6 @@
 use ahash::HashMap;
 use egui::{
-    ColorImage, FrameDurations, Id, decode_animated_image_uri, has_gif_magic_header,
+    ColorImage, FrameDurations, Id, LoopCount, decode_animated_image_uri, has_gif_magic_header,
     load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
     mutex::Mutex,
 };
@@ -25,7 +25,11 @@ impl AnimatedImage {
             let img = frame.buffer();
             let pixels = img.as_flat_samples();
 
-            let delay: Duration = frame.delay().into();
+            let mut delay: Duration = frame.delay().into();
+            if delay <= Duration::from_millis(10) {
+                // Like browsers do, since many gifs rely on it:
+                delay = Duration::from_millis(100);
+            }
             images.push(Arc::new(ColorImage::from_rgba_unmultiplied(
                 [img.width() as usize, img.height() as usize],
                 pixels.as_slice(),
@@ -34,11 +38,32 @@ impl AnimatedImage {
         }
         Ok(Self {
             frames: images,
-            frame_durations: FrameDurations::new(durations),
+            frame_durations: FrameDurations::new(durations).with_loop_count(gif_loop_count(data)),
         })
     }
 }
 
+/// Read the loop count from the `NETSCAPE2.0` application extension.
+///
+/// It counts the repetitions after the first time the gif is played, with 0 meaning forever.
+/// Without it, the gif is played once, like in browsers.
+fn gif_loop_count(data: &[u8]) -> LoopCount {
+    for identifier in [b"NETSCAPE2.0", b"ANIMEXTS1.0"] {
+        let position = data
+            .windows(identifier.len())
+            .position(|window| window == identifier);
+        if let Some(position) = position {
+            if let [3, 1, low, high, ..] = data[position + identifier.len()..] {
+                return match u16::from_le_bytes([low, high]) {
+                    0 => LoopCount::Infinite,
+                    repetitions => LoopCount::Finite(u32::from(repetitions) + 1),
+                };
+            }
+        }
+    }
+    LoopCount::Finite(1)
+}
+
 impl AnimatedImage {
     pub fn byte_len(&self) -> usize {
         size_of::<Self>()
@@ -131,3 +156,79 @@ impl ImageLoader for GifLoader {
             .sum()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{
+        Delay, Frame, RgbaImage,
+        codecs::gif::{GifEncoder, Repeat},
+    };
+
+    fn encode_gif(delays_ms: &[u32], repeat: Option<Repeat>) -> Vec<u8> {
+        let mut bytes = vec![];
+        {
+            let mut encoder = GifEncoder::new(&mut bytes);
+            if let Some(repeat) = repeat {
+                encoder.set_repeat(repeat).unwrap();
+            }
+            let frames = delays_ms.iter().map(|&ms| {
+                Frame::from_parts(
+                    RgbaImage::new(2, 2),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(ms, 1),
+                )
+            });
+            encoder.encode_frames(frames).unwrap();
+        }
+        bytes
+    }
+
+    fn frame_durations(bytes: Vec<u8>) -> FrameDurations {
+        let ctx = egui::Context::default();
+        ctx.include_bytes("bytes://test.gif", bytes);
+        let loader = GifLoader::default();
+        assert!(matches!(
+            loader.load(&ctx, "bytes://test.gif#0", SizeHint::default()),
+            Ok(ImagePoll::Ready { .. })
+        ));
+        ctx.data(|data| data.get_temp(Id::new("bytes://test.gif")))
+            .expect("The loader stores the frame durations")
+    }
+
+    #[test]
+    fn frames_have_their_own_delays() {
+        let durations = frame_durations(encode_gif(&[30, 70, 200], Some(Repeat::Infinite)));
+        assert_eq!(
+            durations.all().copied().collect::<Vec<_>>(),
+            [30, 70, 200].map(Duration::from_millis)
+        );
+        assert_eq!(durations.loop_count(), LoopCount::Infinite);
+
+        let at = |ms| durations.frame_at(Duration::from_millis(ms));
+        assert_eq!(at(0), (0, Some(Duration::from_millis(30))));
+        assert_eq!(at(50), (1, Some(Duration::from_millis(50))));
+        assert_eq!(at(100), (2, Some(Duration::from_millis(200))));
+        assert_eq!(at(310), (0, Some(Duration::from_millis(20))), "Loops");
+    }
+
+    #[test]
+    fn finite_loops_stop_on_the_last_frame() {
+        let durations = frame_durations(encode_gif(&[50, 50], Some(Repeat::Finite(1))));
+        assert_eq!(durations.loop_count(), LoopCount::Finite(2), "Repeats once");
+
+        let at = |ms| durations.frame_at(Duration::from_millis(ms));
+        assert_eq!(at(150), (1, Some(Duration::from_millis(50))));
+        assert_eq!(at(200), (1, None));
+        assert_eq!(at(10_000), (1, None));
+
+        let durations = frame_durations(encode_gif(&[0, 50], None));
+        assert_eq!(durations.loop_count(), LoopCount::Finite(1));
+        assert_eq!(
+            durations.all().next(),
+            Some(&Duration::from_millis(100)),
+            "Zero delays are shown like in browsers"
+        );
+    }
+}
@@ -96,18 +96,24 @@ impl ImageLoader for ImageCrateLoader {
                     let uri = uri.clone();
                     let bytes = bytes.clone();
                     move || {
+                        if !matches!(cache.lock().get(&uri), Some(Poll::Pending)) {
+                            log::trace!("ImageLoader - cancelled loading {uri:?} before it started");
+                            return;
+                        }
+
                         log::trace!("ImageLoader - started loading {uri:?}");
                         let result = crate::image::load_image_bytes(&bytes)
                             .map(Arc::new)
                             .map_err(|err| err.to_string());
                         log::trace!("ImageLoader - finished loading {uri:?}");
-                        let prev = cache.lock().insert(uri, Poll::Ready(result));
-                        debug_assert!(
-                            matches!(prev, Some(Poll::Pending)),
-                            "Expected previous state to be Pending"
-                        );
 
-                        ctx.request_repaint();
+                        let mut cache = cache.lock();
+                        if let Some(entry @ Poll::Pending) = cache.get_mut(&uri) {
+                            *entry = Poll::Ready(result);
+                            ctx.request_repaint();
+                        } else {
+                            log::trace!("ImageLoader - cancelled loading {uri:?}\nNote: This can happen if `forget_image` or `cancel_load` is called while the image is still loading.");
+                        }
                     }
                 })
                 .expect("failed to spawn thread");
@@ -169,6 +175,15 @@ impl ImageLoader for ImageCrateLoader {
         self.cache.lock().clear();
     }
 
+    fn cancel(&self, uri: &str) {
+        let uri = decode_animated_image_uri(uri).map_or(uri, |(uri, _frame_index)| uri);
+        let mut cache = self.cache.lock();
+        if matches!(cache.get(uri), Some(Poll::Pending)) {
+            // The decoding thread sees this, and drops its work:
+            cache.remove(uri);
+        }
+    }
+
     fn byte_size(&self) -> usize {
         self.cache
             .lock()
@@ -198,4 +213,22 @@ mod tests {
         assert!(is_supported_uri("file://test"));
         assert!(!is_supported_uri("test.svg"));
     }
+
+    #[test]
+    fn cancel_only_drops_pending_decodes() {
+        let loader = ImageCrateLoader::default();
+        {
+            let mut cache = loader.cache.lock();
+            cache.insert("pending.png".to_owned(), Poll::Pending);
+            cache.insert("failed.png".to_owned(), Poll::Ready(Err("Oops".to_owned())));
+        }
+        assert!(loader.has_pending());
+
+        loader.cancel("pending.png#0");
+        loader.cancel("failed.png");
+        assert!(!loader.has_pending());
+        let cache = loader.cache.lock();
+        assert!(!cache.contains_key("pending.png"));
+        assert!(cache.contains_key("failed.png"), "Finished loads are kept");
+    }
 }
@@ -19,9 +19,17 @@ struct Entry {
     result: Result<Arc<ColorImage>, String>,
 }
 
+/// An SVG is parsed once, and then rasterized once per size it is shown at.
+struct Svg {
+    tree: Result<Arc<resvg::usvg::Tree>, String>,
+
+    /// Keyed by size in pixels, so that size hints that result in the same size share an image.
+    images: HashMap<[u32; 2], Entry>,
+}
+
 pub struct SvgLoader {
     pass_index: AtomicU64,
-    cache: Mutex<HashMap<String, HashMap<SizeHint, Entry>>>,
+    cache: Mutex<HashMap<String, Svg>>,
     options: resvg::usvg::Options<'static>,
 }
 
@@ -61,40 +69,44 @@ impl ImageLoader for SvgLoader {
         }
 
         let mut cache = self.cache.lock();
-        let bucket = cache.entry(uri.to_owned()).or_default();
-
-        if let Some(entry) = bucket.get(&size_hint) {
-            entry
-                .last_used
-                .store(self.pass_index.load(Relaxed), Relaxed);
-            match entry.result.clone() {
-                Ok(image) => Ok(ImagePoll::Ready { image }),
-                Err(err) => Err(LoadError::Loading(err)),
-            }
+
+        let svg = if let Some(svg) = cache.get_mut(uri) {
+            svg
         } else {
             match ctx.try_load_bytes(uri) {
                 Ok(BytesPoll::Ready { bytes, .. }) => {
-                    log::trace!("Started loading {uri:?}");
-                    let result =
-                        crate::image::load_svg_bytes_with_size(&bytes, size_hint, &self.options)
-                            .map(Arc::new);
-
-                    log::trace!("Finished loading {uri:?}");
-                    bucket.insert(
-                        size_hint,
-                        Entry {
-                            last_used: AtomicU64::new(self.pass_index.load(Relaxed)),
-                            result: result.clone(),
-                        },
-                    );
-                    match result {
-                        Ok(image) => Ok(ImagePoll::Ready { image }),
-                        Err(err) => Err(LoadError::Loading(err)),
-                    }
+                    log::trace!("Started parsing {uri:?}");
+                    let tree = resvg::usvg::Tree::from_data(&bytes, &self.options)
+                        .map(Arc::new)
+                        .map_err(|err| err.to_string());
+                    log::trace!("Finished parsing {uri:?}");
+                    cache.entry(uri.to_owned()).or_insert(Svg {
+                        tree,
+                        images: Default::default(),
+                    })
                 }
-                Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
-                Err(err) => Err(err),
+                Ok(BytesPoll::Pending { size }) => return Ok(ImagePoll::Pending { size }),
+                Err(err) => return Err(err),
+            }
+        };
+
+        let tree = svg.tree.clone().map_err(LoadError::Loading)?;
+
+        // Rasterize again at every new size, so that the image stays crisp when zoomed:
+        let size = crate::image::svg_size_in_pixels(&tree, size_hint);
+        let entry = svg.images.entry(size).or_insert_with(|| {
+            log::trace!("Rasterizing {uri:?} at {size:?}");
+            Entry {
+                last_used: AtomicU64::new(0),
+                result: crate::image::rasterize_svg_tree(&tree, size).map(Arc::new),
             }
+        });
+        entry
+            .last_used
+            .store(self.pass_index.load(Relaxed), Relaxed);
+        match entry.result.clone() {
+            Ok(image) => Ok(ImagePoll::Ready { image }),
+            Err(err) => Err(LoadError::Loading(err)),
         }
     }
 
@@ -110,7 +122,7 @@ impl ImageLoader for SvgLoader {
         self.cache
             .lock()
             .values()
-            .flat_map(|bucket| bucket.values())
+            .flat_map(|svg| svg.images.values())
             .map(|entry| match &entry.result {
                 Ok(image) => image.pixels.len() * size_of::<egui::Color32>(),
                 Err(err) => err.len(),
@@ -121,16 +133,16 @@ impl ImageLoader for SvgLoader {
     fn end_pass(&self, pass_index: u64) {
         self.pass_index.store(pass_index, Relaxed);
         let mut cache = self.cache.lock();
-        cache.retain(|_key, bucket| {
-            if 2 <= bucket.len() {
+        for svg in cache.values_mut() {
+            if 2 <= svg.images.len() {
                 // There are multiple images of the same URI (e.g. SVGs of different scales).
                 // This could be because someone has an SVG in a resizable container,
                 // and so we get a lot of different sizes of it.
                 // This could wast RAM, so we remove the ones that are not used in this frame.
-                bucket.retain(|_, texture| pass_index <= texture.last_used.load(Relaxed) + 1);
+                svg.images
+                    .retain(|_, texture| pass_index <= texture.last_used.load(Relaxed) + 1);
             }
-            !bucket.is_empty()
-        });
+        }
     }
 }
 
@@ -148,4 +160,33 @@ mod tests {
         assert!(!is_supported("file://test"));
         assert!(is_supported("test.svg"));
     }
+
+    #[test]
+    fn rasterizes_once_per_size() {
+        let ctx = egui::Context::default();
+        ctx.include_bytes(
+            "bytes://square.svg",
+            br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">
+                <rect width="10" height="10" fill="red"/>
+            </svg>"#,
+        );
+        let loader = SvgLoader::default();
+        let load = |size_hint| match loader.load(&ctx, "bytes://square.svg", size_hint) {
+            Ok(ImagePoll::Ready { image }) => image,
+            _ => panic!("Failed to load the svg"),
+        };
+
+        let by_width = load(SizeHint::Width(20));
+        let by_height = load(SizeHint::Height(20));
+        assert_eq!(by_width.size, [20, 20]);
+        assert!(
+            Arc::ptr_eq(&by_width, &by_height),
+            "Same size, same rasterization"
+        );
+
+        let zoomed = load(SizeHint::Width(40));
+        assert_eq!(zoomed.size, [40, 40], "Crisp when zoomed");
+        assert_eq!(zoomed.source_size, egui::vec2(10.0, 10.0));
+        assert_eq!(loader.cache.lock()["bytes://square.svg"].images.len(), 2);
+    }
 }
@@ -1,6 +1,6 @@
 use ahash::HashMap;
 use egui::{
-    ColorImage, FrameDurations, Id, decode_animated_image_uri, has_webp_header,
+    ColorImage, FrameDurations, Id, LoopCount, decode_animated_image_uri, has_webp_header,
     load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
     mutex::Mutex,
 };
@@ -44,7 +44,8 @@ impl WebP {
             }
             Ok(Self::Animated(AnimatedImage {
                 frames: images,
-                frame_durations: FrameDurations::new(durations),
+                frame_durations: FrameDurations::new(durations)
+                    .with_loop_count(webp_loop_count(data)),
             }))
         } else {
             // color_type() of WebPDecoder only returns Rgb8/Rgba8 variants of ColorType
@@ -89,6 +90,28 @@ impl WebP {
     }
 }
 
+/// Read the loop count from the `ANIM` chunk, with 0 meaning forever.
+fn webp_loop_count(data: &[u8]) -> LoopCount {
+    // Skip the "RIFF", file size and "WEBP":
+    let mut offset = 12;
+    while let Some(header) = data.get(offset..offset + 8) {
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        if &header[..4] == b"ANIM" {
+            // After the background color:
+            if let Some(&[low, high]) = data.get(offset + 12..offset + 14) {
+                return match u16::from_le_bytes([low, high]) {
+                    0 => LoopCount::Infinite,
+                    loop_count => LoopCount::Finite(u32::from(loop_count)),
+                };
+            }
+            break;
+        }
+        // Chunks are padded to an even size:
+        offset = offset.saturating_add(8 + size + size % 2);
+    }
+    LoopCount::Infinite
+}
+
 #[derive(Debug, Clone)]
 pub struct AnimatedImage {
     frames: Vec<Arc<ColorImage>>,
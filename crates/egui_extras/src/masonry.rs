@@ -0,0 +1,184 @@
+//! Pinterest-style layout, where each item goes into the currently shortest column.
+
+use egui::{Align, InnerResponse, Layout, Pos2, Rect, Sense, Ui, UiBuilder, Vec2, vec2};
+
+/// Lays out items in equally wide columns, putting each item in the shortest column.
+///
+/// This packs items of different heights (like image cards) with no gaps.
+///
+/// The number of columns is either set with [`Self::columns`],
+/// or as many as fit the available width with [`Self::min_column_width`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui_extras::MasonryLayout::new()
+///     .min_column_width(120.0)
+///     .show(ui, |masonry| {
+///         for i in 0..10 {
+///             masonry.item(|ui| {
+///                 ui.group(|ui| {
+///                     ui.set_min_height(20.0 * (i % 4 + 1) as f32);
+///                     ui.label(format!("Card {i}"));
+///                 });
+///             });
+///         }
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct MasonryLayout {
+    columns: Option<usize>,
+    min_column_width: f32,
+    gap: Option<Vec2>,
+}
+
+impl Default for MasonryLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MasonryLayout {
+    pub fn new() -> Self {
+        Self {
+            columns: None,
+            min_column_width: 150.0,
+            gap: None,
+        }
+    }
+
+    /// Use exactly this many columns, regardless of the available width.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Use as many columns as fit the available width, with each column at least this wide.
+    ///
+    /// Ignored if [`Self::columns`] is set. Default: 150.
+    #[inline]
+    pub fn min_column_width(mut self, min_column_width: f32) -> Self {
+        self.min_column_width = min_column_width;
+        self
+    }
+
+    /// Horizontal space between columns, and vertical space between items.
+    ///
+    /// Default: [`egui::Spacing::item_spacing`].
+    #[inline]
+    pub fn gap(mut self, gap: impl Into<Vec2>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
+    /// Add the items with [`MasonryUi::item`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_items: impl FnOnce(&mut MasonryUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            columns,
+            min_column_width,
+            gap,
+        } = self;
+
+        let gap = gap.unwrap_or_else(|| ui.spacing().item_spacing);
+        let mut available_width = ui.available_width();
+        let num_columns = columns.unwrap_or_else(|| {
+            let column_pitch = min_column_width + gap.x;
+            if 0.0 < column_pitch && available_width.is_finite() {
+                let num_columns = ((available_width + gap.x) / column_pitch).floor() as usize;
+                // No narrower than a point, even with a tiny `min_column_width`:
+                num_columns.min(available_width as usize)
+            } else {
+                1
+            }
+        });
+        let num_columns = num_columns.max(1);
+        if !available_width.is_finite() {
+            // E.g. in a horizontal `ScrollArea`, so give each column its minimum width:
+            available_width = num_columns as f32 * (min_column_width + gap.x) - gap.x;
+        }
+        let column_width =
+            ((available_width - gap.x * (num_columns - 1) as f32) / num_columns as f32).max(0.0);
+
+        ui.scope(|ui| {
+            let mut masonry = MasonryUi {
+                top_left: ui.cursor().min,
+                ui,
+                gap,
+                column_width,
+                column_heights: vec![0.0; num_columns],
+                num_items: 0,
+            };
+            let inner = add_items(&mut masonry);
+
+            let MasonryUi {
+                ui,
+                top_left,
+                column_heights,
+                ..
+            } = masonry;
+
+            let height = column_heights.iter().copied().fold(0.0, f32::max);
+            let size = vec2(available_width, (height - gap.y).max(0.0));
+            let response = ui.allocate_rect(Rect::from_min_size(top_left, size), Sense::hover());
+            InnerResponse::new(inner, response)
+        })
+        .inner
+    }
+}
+
+/// Add items to a [`MasonryLayout`].
+pub struct MasonryUi<'a> {
+    ui: &'a mut Ui,
+    top_left: Pos2,
+    gap: Vec2,
+    column_width: f32,
+
+    /// Height of each column so far, including the gap after the last item.
+    column_heights: Vec<f32>,
+
+    num_items: usize,
+}
+
+impl MasonryUi<'_> {
+    /// The width of each column, which is also the width of each item.
+    pub fn column_width(&self) -> f32 {
+        self.column_width
+    }
+
+    /// Add an item to the shortest column, with its own [`Ui`].
+    pub fn item<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let (column, &y) = self
+            .column_heights
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .expect("There is always at least one column");
+
+        let x = column as f32 * (self.column_width + self.gap.x);
+        let max_rect = Rect::from_min_size(
+            self.top_left + vec2(x, y),
+            vec2(self.column_width, f32::INFINITY),
+        );
+        let column_width = self.column_width;
+        let response = self.ui.scope_builder(
+            UiBuilder::new()
+                .id_salt(self.num_items)
+                .max_rect(max_rect)
+                .layout(Layout::top_down(Align::Min)),
+            |ui| {
+                ui.set_width(column_width);
+                add_contents(ui)
+            },
+        );
+
+        self.column_heights[column] += response.response.rect.height() + self.gap.y;
+        self.num_items += 1;
+
+        response
+    }
+}
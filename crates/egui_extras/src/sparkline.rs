@@ -0,0 +1,222 @@
+//! Tiny inline line charts, for showing trends at a glance.
+
+use egui::{
+    Color32, Mesh, Pos2, Response, Sense, Shape, Stroke, Ui, Widget, pos2, remap, remap_clamp, vec2,
+};
+
+/// A small line chart without axes or labels, e.g. for a table cell.
+///
+/// The values are scaled so that the smallest one is at the bottom of the chart,
+/// and the largest one at the top.
+/// Non-finite values (like `NaN`) leave a gap in the line.
+///
+/// Hovering the chart shows the value closest to the pointer.
+///
+/// For proper plots, use the `egui_plot` crate.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let requests_per_second = [12.0, 15.5, 9.0, 22.0, 18.0, 25.5];
+/// ui.add(egui_extras::Sparkline::new(&requests_per_second).fill(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    width: Option<f32>,
+    height: Option<f32>,
+    stroke: Option<Stroke>,
+    fill: bool,
+    highlight_last: bool,
+    show_hover: bool,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            width: None,
+            height: None,
+            stroke: None,
+            fill: false,
+            highlight_last: true,
+            show_hover: true,
+        }
+    }
+
+    /// Width of the chart.
+    ///
+    /// Default: all of the available width.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the chart.
+    ///
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// The stroke of the line.
+    ///
+    /// Default: the stroke used for text of non-interactive widgets.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Fill the area under the line with a faded line color. Default: `false`.
+    #[inline]
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Mark the last value with a dot. Default: `true`.
+    #[inline]
+    pub fn highlight_last(mut self, highlight_last: bool) -> Self {
+        self.highlight_last = highlight_last;
+        self
+    }
+
+    /// Show the value closest to the pointer when hovered. Default: `true`.
+    #[inline]
+    pub fn show_hover(mut self, show_hover: bool) -> Self {
+        self.show_hover = show_hover;
+        self
+    }
+}
+
+impl Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            width,
+            height,
+            stroke,
+            fill,
+            highlight_last,
+            show_hover,
+        } = self;
+
+        let size = vec2(
+            width.unwrap_or_else(|| ui.available_width()),
+            height.unwrap_or_else(|| ui.spacing().interact_size.y),
+        );
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        let stroke = stroke.unwrap_or(ui.visuals().widgets.noninteractive.fg_stroke);
+        // Leave room for the line and the dots at the edges:
+        let chart_rect = rect.shrink(stroke.width + 1.0);
+
+        let (min, max) = values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        let point = |index: usize, value: f32| -> Option<Pos2> {
+            if !value.is_finite() {
+                return None;
+            }
+            let x = if values.len() == 1 {
+                chart_rect.center().x
+            } else {
+                remap(
+                    index as f32,
+                    0.0..=(values.len() - 1) as f32,
+                    chart_rect.x_range(),
+                )
+            };
+            let y = if min == max {
+                chart_rect.center().y
+            } else {
+                remap(value, min..=max, chart_rect.bottom()..=chart_rect.top())
+            };
+            Some(pos2(x, y))
+        };
+        let points: Vec<Option<Pos2>> = values
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| point(index, value))
+            .collect();
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter_at(rect);
+
+            if fill {
+                painter.add(fill_under(
+                    &points,
+                    chart_rect.bottom(),
+                    stroke.color.gamma_multiply(0.25),
+                ));
+            }
+
+            // Each run of finite values is its own line:
+            for run in points.split(|point| point.is_none()) {
+                let run: Vec<Pos2> = run.iter().flatten().copied().collect();
+                match run.as_slice() {
+                    [] => {}
+                    [single] => {
+                        painter.circle_filled(*single, 0.5 * stroke.width, stroke.color);
+                    }
+                    _ => {
+                        painter.add(Shape::line(run, stroke));
+                    }
+                }
+            }
+
+            if highlight_last {
+                if let Some(Some(last)) = points.last() {
+                    painter.circle_filled(*last, stroke.width + 1.0, stroke.color);
+                }
+            }
+        }
+
+        let hovered_index = response.hover_pos().filter(|_| show_hover).and_then(|pos| {
+            let last_index = values.len().checked_sub(1)?;
+            let t = remap_clamp(pos.x, chart_rect.x_range(), 0.0..=1.0);
+            Some((t * last_index as f32).round() as usize)
+        });
+
+        if let Some(index) = hovered_index {
+            if let Some(point) = points[index] {
+                ui.painter().vline(
+                    point.x,
+                    rect.y_range(),
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+                ui.painter()
+                    .circle_filled(point, stroke.width + 1.0, stroke.color);
+                return response.on_hover_text_at_pointer(values[index].to_string());
+            }
+        }
+
+        response
+    }
+}
+
+/// A mesh of the area between the line and the bottom of the chart.
+fn fill_under(points: &[Option<Pos2>], bottom: f32, color: Color32) -> Mesh {
+    let mut mesh = Mesh::default();
+    for segment in points.windows(2) {
+        let [Some(a), Some(b)] = [segment[0], segment[1]] else {
+            continue;
+        };
+        let index = mesh.vertices.len() as u32;
+        mesh.colored_vertex(a, color);
+        mesh.colored_vertex(b, color);
+        mesh.colored_vertex(pos2(b.x, bottom), color);
+        mesh.colored_vertex(pos2(a.x, bottom), color);
+        mesh.add_triangle(index, index + 1, index + 2);
+        mesh.add_triangle(index, index + 2, index + 3);
+    }
+    mesh
+}
@@ -0,0 +1,420 @@
+//! A row of tabs for switching between panels, without a full docking framework.
+
+use egui::{
+    Align, Color32, CornerRadius, CursorIcon, Id, Layout, PointerButton, Rect, Response,
+    ScrollArea, Sense, Stroke, StrokeKind, TextStyle, TextWrapMode, Ui, Vec2, WidgetInfo,
+    WidgetText, WidgetType, pos2, scroll_area::ScrollBarVisibility, vec2,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+struct TabBarState {
+    /// The tab that is currently being dragged.
+    dragged: Option<Drag>,
+
+    /// Did the tabs overflow the available width last frame?
+    overflowing: bool,
+
+    /// The active tab was picked from the dropdown, so we should scroll to it.
+    scroll_to_active: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// Where the tab was when the drag started.
+    from: usize,
+
+    /// Where the tab is now.
+    to: usize,
+}
+
+impl Drag {
+    /// Where the tab that is now at `index` was when the drag started.
+    ///
+    /// The tabs are identified by that, so that they keep their [`Id`] while the tabs are reordered,
+    /// and egui knows which one is being dragged.
+    fn index_before(drag: Option<Self>, index: usize) -> usize {
+        let Some(Self { from, to }) = drag else {
+            return index;
+        };
+        if index == to {
+            from
+        } else if from <= index && index < to {
+            index + 1
+        } else if to < index && index <= from {
+            index - 1
+        } else {
+            index
+        }
+    }
+}
+
+impl TabBarState {
+    fn load(ctx: &egui::Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_temp(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &egui::Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
+/// What happened in a [`TabBar`] this frame.
+pub struct TabBarResponse<T> {
+    /// Covers the whole bar.
+    pub response: Response,
+
+    /// Tabs that the user closed this frame.
+    ///
+    /// They have already been removed from the list of tabs.
+    pub closed: Vec<T>,
+
+    /// Did the active tab change this frame?
+    ///
+    /// This is also `true` if the active tab was closed or moved.
+    pub active_changed: bool,
+
+    /// Were the tabs reordered this frame?
+    pub reordered: bool,
+}
+
+/// A row of clickable tabs, of which one is active.
+///
+/// The tabs can be closed (with the `×` button or with a middle-click) and reordered by dragging.
+/// If there are too many tabs to fit, the bar can be scrolled,
+/// and a dropdown listing all tabs is shown at the end.
+///
+/// The contents of the active tab is up to you to show.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tabs = vec!["First".to_owned(), "Second".to_owned()];
+/// # let mut active = 0;
+/// egui_extras::TabBar::new("documents").show(ui, &mut tabs, &mut active, |tab| tab.into());
+/// if let Some(tab) = tabs.get(active) {
+///     ui.label(format!("Contents of {tab}"));
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TabBar {
+    id_salt: Id,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl TabBar {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            closable: true,
+            reorderable: true,
+        }
+    }
+
+    /// Show a close button on each tab, and close tabs on middle-click. Default: `true`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Allow reordering the tabs by dragging them. Default: `true`.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show the tabs.
+    ///
+    /// `active` is the index of the active tab in `tabs`, and is kept up-to-date
+    /// as tabs are clicked, closed or reordered.
+    pub fn show<T>(
+        self,
+        ui: &mut Ui,
+        tabs: &mut Vec<T>,
+        active: &mut usize,
+        mut tab_title: impl FnMut(&T) -> WidgetText,
+    ) -> TabBarResponse<T> {
+        let Self {
+            id_salt,
+            closable,
+            reorderable,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state = TabBarState::load(ui.ctx(), id);
+        if state
+            .dragged
+            .is_some_and(|drag| tabs.len() <= drag.from.max(drag.to))
+        {
+            // Tabs were removed during the drag, so the dragged tab may be gone:
+            state.dragged = None;
+        }
+        let prev_active = *active;
+
+        let mut closed = Vec::new();
+        let mut reordered = false;
+
+        let height = ui.spacing().interact_size.y + ui.spacing().button_padding.y;
+        let dropdown_width = if state.overflowing {
+            ui.spacing().interact_size.y
+        } else {
+            0.0
+        };
+
+        let bar_response = ui.allocate_ui_with_layout(
+            vec2(ui.available_width(), height),
+            Layout::left_to_right(Align::Max),
+            |ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+
+                let scroll_width = ui.available_width() - dropdown_width;
+                let scroll_output = ScrollArea::horizontal()
+                    .id_salt(id.with("scroll"))
+                    .max_width(scroll_width)
+                    .auto_shrink([true, true])
+                    .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.spacing_mut().item_spacing.x = 2.0;
+
+                            let mut tab_rects = Vec::with_capacity(tabs.len());
+                            let mut close_index = None;
+
+                            for (index, tab) in tabs.iter().enumerate() {
+                                let is_active = index == *active;
+                                let tab_id = id.with(Drag::index_before(state.dragged, index));
+                                let tab_response =
+                                    tab_ui(ui, tab_id, tab_title(tab), is_active, closable, height);
+
+                                if tab_response.close_clicked
+                                    || (closable
+                                        && tab_response.response.clicked_by(PointerButton::Middle))
+                                {
+                                    close_index = Some(index);
+                                } else if tab_response.response.clicked()
+                                    || tab_response.response.drag_started()
+                                {
+                                    *active = index;
+                                }
+                                if reorderable && tab_response.response.drag_started() {
+                                    state.dragged = Some(Drag {
+                                        from: index,
+                                        to: index,
+                                    });
+                                }
+
+                                tab_rects.push(tab_response.response.rect);
+                            }
+
+                            if let Some(drag) = &mut state.dragged {
+                                if ui.input(|i| i.pointer.primary_down()) {
+                                    ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                                    if let Some(pointer_x) =
+                                        ui.input(|i| i.pointer.interact_pos()).map(|p| p.x)
+                                    {
+                                        let target = drop_index(&tab_rects, drag.to, pointer_x);
+                                        if target != drag.to {
+                                            move_tab(tabs, active, drag.to, target);
+                                            drag.to = target;
+                                            reordered = true;
+                                        }
+                                    }
+                                } else {
+                                    state.dragged = None;
+                                }
+                            }
+
+                            if let Some(index) = close_index {
+                                closed.push(tabs.remove(index));
+                                if index < *active || (index == *active && index == tabs.len()) {
+                                    *active = active.saturating_sub(1);
+                                }
+                                state.dragged = None;
+                            }
+
+                            if state.scroll_to_active {
+                                if let Some(rect) = tab_rects.get(*active) {
+                                    ui.scroll_to_rect(*rect, None);
+                                }
+                                state.scroll_to_active = false;
+                            }
+                        });
+                    });
+
+                let overflowing = scroll_width < scroll_output.content_size.x;
+                if overflowing != state.overflowing {
+                    // Show or hide the dropdown right away:
+                    state.overflowing = overflowing;
+                    ui.ctx().request_repaint();
+                }
+
+                if state.overflowing {
+                    let response = ui
+                        .menu_button("⏷", |ui| {
+                            for (index, tab) in tabs.iter().enumerate() {
+                                if ui
+                                    .selectable_label(index == *active, tab_title(tab))
+                                    .clicked()
+                                {
+                                    *active = index;
+                                    state.scroll_to_active = true;
+                                    ui.ctx().request_repaint();
+                                }
+                            }
+                        })
+                        .response;
+                    response.on_hover_text("Show all tabs");
+                }
+            },
+        );
+
+        let response = bar_response.response;
+        ui.painter().hline(
+            response.rect.x_range(),
+            response.rect.bottom(),
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        );
+
+        state.store(ui.ctx(), id);
+
+        TabBarResponse {
+            response,
+            closed,
+            active_changed: *active != prev_active,
+            reordered,
+        }
+    }
+}
+
+struct TabResponse {
+    response: Response,
+    close_clicked: bool,
+}
+
+fn tab_ui(
+    ui: &mut Ui,
+    id: Id,
+    title: WidgetText,
+    is_active: bool,
+    closable: bool,
+    height: f32,
+) -> TabResponse {
+    let padding = ui.spacing().button_padding;
+    let close_size = if closable {
+        ui.spacing().icon_width
+    } else {
+        0.0
+    };
+
+    let galley = title.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    );
+    let close_spacing = if closable { padding.x } else { 0.0 };
+    let width = 2.0 * padding.x + galley.size().x + close_spacing + close_size;
+
+    let (rect, _) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+    let response = ui.interact(rect, id, Sense::click_and_drag());
+    response.widget_info(|| {
+        WidgetInfo::selected(
+            WidgetType::Button,
+            ui.is_enabled(),
+            is_active,
+            galley.text(),
+        )
+    });
+
+    let close_rect = Rect::from_center_size(
+        pos2(rect.right() - padding.x - 0.5 * close_size, rect.center().y),
+        Vec2::splat(close_size),
+    );
+    let close_response = closable.then(|| {
+        let close_response = ui.interact(close_rect, id.with("close"), Sense::click());
+        close_response
+            .widget_info(|| WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Close tab"));
+        close_response
+    });
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.visuals();
+        let widget_visuals = ui.style().interact(&response);
+        let corner_radius = CornerRadius {
+            se: 0,
+            sw: 0,
+            ..widget_visuals.corner_radius
+        };
+
+        let (fill, stroke) = if is_active {
+            (visuals.panel_fill, visuals.widgets.noninteractive.bg_stroke)
+        } else if response.hovered() {
+            (visuals.widgets.hovered.weak_bg_fill, Stroke::NONE)
+        } else {
+            (visuals.widgets.inactive.weak_bg_fill, Stroke::NONE)
+        };
+        ui.painter()
+            .rect(rect, corner_radius, fill, stroke, StrokeKind::Inside);
+
+        let text_color = if is_active {
+            visuals.strong_text_color()
+        } else {
+            widget_visuals.text_color()
+        };
+        let text_pos = pos2(
+            rect.left() + padding.x,
+            rect.center().y - 0.5 * galley.size().y,
+        );
+        ui.painter().galley(text_pos, galley, text_color);
+
+        if let Some(close_response) = &close_response {
+            // Only show the close button on the active or hovered tab, to reduce clutter:
+            if is_active || response.hovered() || close_response.hovered() {
+                let close_visuals = ui.style().interact(close_response);
+                let stroke = if close_response.hovered() {
+                    close_visuals.fg_stroke
+                } else {
+                    Stroke::new(close_visuals.fg_stroke.width, Color32::GRAY)
+                };
+                let x_rect = close_rect.shrink(2.0).expand(close_visuals.expansion);
+                ui.painter() // paints \
+                    .line_segment([x_rect.left_top(), x_rect.right_bottom()], stroke);
+                ui.painter() // paints /
+                    .line_segment([x_rect.right_top(), x_rect.left_bottom()], stroke);
+            }
+        }
+    }
+
+    TabResponse {
+        response,
+        close_clicked: close_response.is_some_and(|r| r.clicked()),
+    }
+}
+
+/// Where should the dragged tab go, given the pointer position?
+fn drop_index(tab_rects: &[Rect], dragged: usize, pointer_x: f32) -> usize {
+    let mut target = dragged;
+    while 0 < target && pointer_x < tab_rects[target - 1].center().x {
+        target -= 1;
+    }
+    while target + 1 < tab_rects.len() && tab_rects[target + 1].center().x < pointer_x {
+        target += 1;
+    }
+    target
+}
+
+/// Move the tab at `from` to `to`, keeping `active` pointing at the same tab.
+fn move_tab<T>(tabs: &mut Vec<T>, active: &mut usize, from: usize, to: usize) {
+    let tab = tabs.remove(from);
+    tabs.insert(to, tab);
+
+    if *active == from {
+        *active = to;
+    } else if from < *active && *active <= to {
+        *active -= 1;
+    } else if to <= *active && *active < from {
+        *active += 1;
+    }
+}
@@ -0,0 +1,340 @@
+//! Short-lived notifications stacked in a corner of the screen.
+
+use egui::{
+    Align, Align2, Area, Color32, Context, Frame, Id, Label, Order, RichText, Sense, Vec2,
+    WidgetText, vec2,
+};
+
+/// How long it takes a toast to fade out, in seconds.
+const FADE_OUT_TIME: f32 = 0.3;
+
+/// How long it takes the stack to reflow when a toast disappears, in seconds.
+const REFLOW_TIME: f32 = 0.2;
+
+/// What kind of message a [`Toast`] is.
+///
+/// This decides the icon and color of the toast.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "ℹ",
+            Self::Success => "✔",
+            Self::Warning => "⚠",
+            Self::Error => "🗙",
+        }
+    }
+
+    fn color(self, visuals: &egui::Visuals) -> Color32 {
+        match self {
+            Self::Info => visuals.hyperlink_color,
+            Self::Success => {
+                if visuals.dark_mode {
+                    Color32::LIGHT_GREEN
+                } else {
+                    Color32::DARK_GREEN
+                }
+            }
+            Self::Warning => visuals.warn_fg_color,
+            Self::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// A single notification, to be added to [`Toasts`].
+///
+/// ```
+/// # let mut toasts = egui_extras::Toasts::new();
+/// use egui_extras::Toast;
+/// toasts.add(Toast::warning("Disk almost full").duration(Some(10.0)));
+/// toasts.add(Toast::error("Connection lost").duration(None)); // stays until clicked
+/// ```
+#[must_use = "You should add the toast with `Toasts::add`"]
+#[derive(Clone, Debug)]
+pub struct Toast {
+    kind: ToastKind,
+    text: WidgetText,
+    duration: Option<f32>,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, text: impl Into<WidgetText>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            duration: Some(4.0),
+        }
+    }
+
+    pub fn info(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Info, text)
+    }
+
+    pub fn success(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Success, text)
+    }
+
+    pub fn warning(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Warning, text)
+    }
+
+    pub fn error(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Error, text)
+    }
+
+    /// How many seconds to show the toast for, including the fade-out.
+    ///
+    /// `None` means the toast stays until it is clicked.
+    /// The time doesn't run out while the toast is hovered.
+    ///
+    /// Default: 4 seconds.
+    #[inline]
+    pub fn duration(mut self, duration: Option<f32>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+struct ToastState {
+    toast: Toast,
+
+    /// Unique among all toasts of a [`Toasts`], used to identify its [`Area`].
+    id: u64,
+
+    /// Seconds left until the toast is gone, or `None` if it never expires.
+    remaining: Option<f32>,
+
+    /// Has the toast been shown yet?
+    shown: bool,
+}
+
+impl ToastState {
+    fn opacity(&self) -> f32 {
+        self.remaining
+            .map_or(1.0, |remaining| (remaining / FADE_OUT_TIME).clamp(0.0, 1.0))
+    }
+}
+
+/// A stack of short-lived notifications ("toasts") in a corner of the screen.
+///
+/// Each toast fades out once its time is up, or when it is clicked.
+/// The oldest toast is closest to the corner, and the rest of the stack
+/// moves in to fill the gap when a toast disappears.
+///
+/// Keep the [`Toasts`] around in your app state, add toasts to it whenever something happens,
+/// and call [`Self::show`] once every frame:
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut toasts = egui_extras::Toasts::new();
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     if ui.button("Save").clicked() {
+///         toasts.success("Saved!");
+///     }
+/// });
+/// toasts.show(ctx);
+/// # });
+/// ```
+pub struct Toasts {
+    id: Id,
+    anchor: Align2,
+    offset: Vec2,
+    max_width: f32,
+    toasts: Vec<ToastState>,
+    next_id: u64,
+    last_time: Option<f64>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new("egui_extras_toasts"),
+            anchor: Align2::RIGHT_BOTTOM,
+            offset: Vec2::splat(8.0),
+            max_width: 320.0,
+            toasts: Vec::new(),
+            next_id: 0,
+            last_time: None,
+        }
+    }
+
+    /// Use a different id, if you want to show more than one [`Toasts`] at the same time.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = Id::new(id_salt);
+        self
+    }
+
+    /// Which corner (or edge) of the screen to show the toasts in.
+    ///
+    /// Toasts stack upwards from the bottom, and downwards otherwise.
+    ///
+    /// Default: [`Align2::RIGHT_BOTTOM`].
+    #[inline]
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Distance from the edges of the screen. Default: 8 points.
+    #[inline]
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Text in toasts wider than this is wrapped. Default: 320 points.
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Add a toast to the stack.
+    pub fn add(&mut self, toast: Toast) {
+        self.toasts.push(ToastState {
+            remaining: toast.duration.map(|duration| duration.max(FADE_OUT_TIME)),
+            toast,
+            id: self.next_id,
+            shown: false,
+        });
+        self.next_id += 1;
+    }
+
+    /// Add a [`ToastKind::Info`] toast with the default duration.
+    pub fn info(&mut self, text: impl Into<WidgetText>) {
+        self.add(Toast::info(text));
+    }
+
+    /// Add a [`ToastKind::Success`] toast with the default duration.
+    pub fn success(&mut self, text: impl Into<WidgetText>) {
+        self.add(Toast::success(text));
+    }
+
+    /// Add a [`ToastKind::Warning`] toast with the default duration.
+    pub fn warning(&mut self, text: impl Into<WidgetText>) {
+        self.add(Toast::warning(text));
+    }
+
+    /// Add a [`ToastKind::Error`] toast with the default duration.
+    pub fn error(&mut self, text: impl Into<WidgetText>) {
+        self.add(Toast::error(text));
+    }
+
+    /// Number of toasts currently shown, including ones that are fading out.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Remove all toasts immediately.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Show all toasts, and remove the ones that have expired.
+    ///
+    /// Call this once every frame, after your panels and windows.
+    pub fn show(&mut self, ctx: &Context) {
+        let Self {
+            id,
+            anchor,
+            offset,
+            max_width,
+            toasts,
+            next_id: _,
+            last_time,
+        } = self;
+
+        let now = ctx.input(|i| i.time);
+        let dt = last_time.map_or(0.0, |last_time| (now - last_time) as f32);
+
+        let stack_upwards = anchor.y() == Align::Max;
+        let corner = anchor.pos_in_rect(&ctx.screen_rect().shrink2(*offset));
+        let spacing = ctx.style().spacing.item_spacing.y;
+
+        let mut y = 0.0;
+        for toast in toasts.iter_mut() {
+            let area_id = id.with(toast.id);
+            // Glide into place when a toast further down the stack disappears:
+            let y_offset = ctx.animate_value_with_time(area_id.with("y"), y, REFLOW_TIME);
+            let pos = if stack_upwards {
+                corner - vec2(0.0, y_offset)
+            } else {
+                corner + vec2(0.0, y_offset)
+            };
+
+            let opacity = toast.opacity();
+            let response = Area::new(area_id)
+                .order(Order::Foreground)
+                .pivot(*anchor)
+                .fixed_pos(pos)
+                .sense(Sense::click())
+                .show(ctx, |ui| {
+                    ui.multiply_opacity(opacity);
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        ui.set_max_width(*max_width);
+                        ui.horizontal(|ui| {
+                            let kind = toast.toast.kind;
+                            ui.label(RichText::new(kind.icon()).color(kind.color(ui.visuals())));
+                            ui.add(
+                                Label::new(toast.toast.text.clone())
+                                    .selectable(false)
+                                    .wrap(),
+                            );
+                        });
+                    });
+                })
+                .response;
+
+            if let Some(remaining) = &mut toast.remaining {
+                // Hovering pauses the toast, unless it is already fading out:
+                let paused = response.contains_pointer() && FADE_OUT_TIME < *remaining;
+                if toast.shown && !paused {
+                    *remaining -= dt;
+                }
+            }
+            if response.clicked() {
+                let remaining = toast.remaining.get_or_insert(FADE_OUT_TIME);
+                *remaining = remaining.min(FADE_OUT_TIME);
+            }
+            toast.shown = true;
+
+            y += response.rect.height() + spacing;
+        }
+
+        toasts.retain(|toast| toast.remaining.is_none_or(|remaining| 0.0 < remaining));
+
+        if let Some(remaining) = toasts
+            .iter()
+            .filter_map(|toast| toast.remaining)
+            .reduce(f32::min)
+        {
+            if remaining <= FADE_OUT_TIME {
+                ctx.request_repaint();
+            } else {
+                ctx.request_repaint_after_secs(remaining - FADE_OUT_TIME);
+            }
+        }
+
+        // Don't count the time without any toasts against the next one:
+        *last_time = (!toasts.is_empty()).then_some(now);
+    }
+}
@@ -0,0 +1,497 @@
+//! A tree view for large hierarchies, e.g. a file explorer or a scene graph.
+//!
+//! Only the visible rows are laid out, so it stays fast even with hundreds of thousands of nodes.
+
+use egui::{
+    Align, EventFilter, Id, Key, Layout, Modifiers, NumExt as _, Rect, Response, ScrollArea, Sense,
+    Ui, UiBuilder, collapsing_header::paint_default_icon, pos2, vec2,
+};
+
+/// One node of a [`TreeView`].
+///
+/// The nodes are given to [`TreeView::show`] flattened in depth-first order,
+/// i.e. each node is directly followed by all of its descendants.
+#[derive(Clone, Debug)]
+pub struct TreeNode<T> {
+    /// Uniquely identifies the node within the tree.
+    ///
+    /// Used to remember if the node is open and/or selected.
+    pub id: Id,
+
+    /// How deep in the hierarchy the node is. Root nodes are at depth `0`.
+    pub depth: usize,
+
+    /// Should the node have a button for expanding/collapsing it?
+    pub has_children: bool,
+
+    /// Your data, passed back to you when the row is shown.
+    pub item: T,
+}
+
+impl<T> TreeNode<T> {
+    /// A leaf node. Use [`Self::has_children`] for nodes that can be expanded.
+    pub fn new(id: Id, depth: usize, item: T) -> Self {
+        Self {
+            id,
+            depth,
+            has_children: false,
+            item,
+        }
+    }
+
+    /// Should the node have a button for expanding/collapsing it?
+    #[inline]
+    pub fn has_children(mut self, has_children: bool) -> Self {
+        self.has_children = has_children;
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which nodes of a [`TreeView`] are open, and which one is selected.
+///
+/// Stored in [`egui::Memory`] between frames.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TreeViewState {
+    open: ahash::HashSet<Id>,
+    selected: Option<Id>,
+}
+
+impl TreeViewState {
+    /// Load the state of the tree with the given id, if it was stored before.
+    ///
+    /// See also [`TreeView::load_state`].
+    pub fn load(ctx: &egui::Context, id: Id) -> Option<Self> {
+        #[cfg(feature = "serde")]
+        return ctx.data_mut(|d| d.get_persisted(id));
+        #[cfg(not(feature = "serde"))]
+        return ctx.data_mut(|d| d.get_temp(id));
+    }
+
+    /// Store the state of the tree with the given id.
+    ///
+    /// With the `serde` feature it is persisted, so the same nodes are open after a restart.
+    pub fn store(self, ctx: &egui::Context, id: Id) {
+        #[cfg(feature = "serde")]
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+        #[cfg(not(feature = "serde"))]
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// Is the given node expanded?
+    ///
+    /// You can use this to skip iterating over the children of closed nodes.
+    pub fn is_open(&self, node: Id) -> bool {
+        self.open.contains(&node)
+    }
+
+    pub fn set_open(&mut self, node: Id, open: bool) {
+        if open {
+            self.open.insert(node);
+        } else {
+            self.open.remove(&node);
+        }
+    }
+
+    pub fn toggle(&mut self, node: Id) {
+        let open = self.is_open(node);
+        self.set_open(node, !open);
+    }
+
+    /// The currently selected node, if any.
+    pub fn selected(&self) -> Option<Id> {
+        self.selected
+    }
+
+    pub fn set_selected(&mut self, node: Option<Id>) {
+        self.selected = node;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What happened in a [`TreeView`] this frame.
+pub struct TreeViewResponse {
+    /// Covers the whole tree. Has focus when the tree is keyboard-navigable.
+    pub response: Response,
+
+    /// The selected node, if any.
+    pub selected: Option<Id>,
+
+    /// Did the user change the selection this frame?
+    pub selection_changed: bool,
+
+    /// A node that the user opened or closed this frame.
+    pub toggled: Option<Id>,
+}
+
+/// A tree of nodes that can be expanded, collapsed and selected.
+///
+/// Only the visible rows are shown, using [`ScrollArea::show_rows`],
+/// so all rows have the same height.
+///
+/// When the tree has keyboard focus (click on any row):
+/// * `↑`/`↓` moves the selection
+/// * `→` opens the selected node, or moves to its first child
+/// * `←` closes the selected node, or moves to its parent
+/// * `Enter`/`Space` toggles the selected node
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{TreeNode, TreeView};
+///
+/// struct Entry { name: String, children: Vec<Entry> }
+/// # let root = Entry { name: "root".into(), children: vec![] };
+///
+/// fn flatten<'a>(
+///     entry: &'a Entry,
+///     depth: usize,
+///     state: &egui_extras::TreeViewState,
+///     out: &mut Vec<TreeNode<&'a Entry>>,
+/// ) {
+///     let id = egui::Id::new(&entry.name);
+///     out.push(TreeNode::new(id, depth, entry).has_children(!entry.children.is_empty()));
+///     if state.is_open(id) {
+///         for child in &entry.children {
+///             flatten(child, depth + 1, state, out);
+///         }
+///     }
+/// }
+///
+/// let tree = TreeView::new("file_tree");
+/// let mut nodes = Vec::new();
+/// flatten(&root, 0, &tree.load_state(ui), &mut nodes);
+/// tree.show(ui, nodes, |ui, node| {
+///     ui.label(&node.item.name);
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TreeView {
+    id_salt: Id,
+    row_height: Option<f32>,
+    indent: Option<f32>,
+    indent_guides: bool,
+    max_height: f32,
+}
+
+impl TreeView {
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            row_height: None,
+            indent: None,
+            indent_guides: true,
+            max_height: f32::INFINITY,
+        }
+    }
+
+    /// Height of each row. Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// How much to indent each level of the hierarchy. Default: [`egui::style::Spacing::indent`].
+    #[inline]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Draw vertical lines showing the indentation levels. Default: `true`.
+    #[inline]
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = indent_guides;
+        self
+    }
+
+    /// The tree will never be taller than this, and will scroll if needed.
+    ///
+    /// Default: fill the available height.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    fn id(&self, ui: &Ui) -> Id {
+        ui.make_persistent_id(self.id_salt)
+    }
+
+    /// Load the stored state, e.g. to know which nodes are open when flattening the tree.
+    pub fn load_state(&self, ui: &Ui) -> TreeViewState {
+        TreeViewState::load(ui.ctx(), self.id(ui)).unwrap_or_default()
+    }
+
+    /// Show the tree.
+    ///
+    /// `nodes` should contain at least all nodes that are visible, in depth-first order.
+    /// Descendants of closed nodes are skipped, so it is fine (but slower) to pass in the whole tree.
+    ///
+    /// `add_row` is called for each visible row, with a left-to-right [`Ui`] to the right of the expand button.
+    pub fn show<T>(
+        self,
+        ui: &mut Ui,
+        nodes: impl IntoIterator<Item = TreeNode<T>>,
+        mut add_row: impl FnMut(&mut Ui, &TreeNode<T>),
+    ) -> TreeViewResponse {
+        let id = self.id(ui);
+        let mut state = TreeViewState::load(ui.ctx(), id).unwrap_or_default();
+        let prev_selected = state.selected;
+
+        let mut visible = Vec::new();
+        let mut hide_deeper_than = None;
+        for node in nodes {
+            if let Some(depth) = hide_deeper_than {
+                if depth < node.depth {
+                    continue;
+                }
+                hide_deeper_than = None;
+            }
+            if node.has_children && !state.is_open(node.id) {
+                hide_deeper_than = Some(node.depth);
+            }
+            visible.push(node);
+        }
+
+        let row_height = self
+            .row_height
+            .unwrap_or_else(|| ui.spacing().interact_size.y);
+        let indent = self.indent.unwrap_or_else(|| ui.spacing().indent);
+        let row_height_with_spacing = row_height + ui.spacing().item_spacing.y;
+
+        let mut toggled = None;
+        let mut scroll_to_selected = false;
+
+        if ui.memory(|mem| mem.has_focus(id)) {
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            if let Some(key_toggled) =
+                keyboard_navigation(ui, &mut state, &mut visible, &mut scroll_to_selected)
+            {
+                toggled = Some(key_toggled);
+            }
+        }
+
+        let selected_index = state
+            .selected
+            .and_then(|selected| visible.iter().position(|node| node.id == selected));
+
+        let scroll_output = ScrollArea::vertical()
+            .id_salt(id.with("scroll"))
+            .auto_shrink([false, true])
+            .max_height(self.max_height)
+            .show_rows(ui, row_height, visible.len(), |ui, row_range| {
+                if scroll_to_selected {
+                    if let Some(index) = selected_index {
+                        // The row may be outside of `row_range`, so we compute its rect:
+                        let top = ui.max_rect().top()
+                            + (index as f32 - row_range.start as f32) * row_height_with_spacing;
+                        let rect =
+                            Rect::from_x_y_ranges(ui.max_rect().x_range(), top..=top + row_height);
+                        ui.scroll_to_rect(rect, None);
+                    }
+                }
+
+                for node in &visible[row_range] {
+                    let (row_rect, _) = ui.allocate_exact_size(
+                        vec2(ui.available_width(), row_height),
+                        Sense::hover(),
+                    );
+                    let row_id = id.with(node.id);
+                    let row_response = ui.interact(row_rect, row_id, Sense::click());
+                    let is_selected = state.selected == Some(node.id);
+
+                    if ui.is_rect_visible(row_rect) {
+                        let visuals = ui.visuals();
+                        if is_selected {
+                            ui.painter()
+                                .rect_filled(row_rect, 0.0, visuals.selection.bg_fill);
+                        } else if row_response.hovered() {
+                            ui.painter().rect_filled(
+                                row_rect,
+                                0.0,
+                                visuals.widgets.hovered.weak_bg_fill,
+                            );
+                        }
+
+                        if self.indent_guides {
+                            let stroke = visuals.widgets.noninteractive.bg_stroke;
+                            let y_range =
+                                row_rect.expand(0.5 * ui.spacing().item_spacing.y).y_range();
+                            for level in 0..node.depth {
+                                let x = row_rect.left() + (level as f32 + 0.5) * indent;
+                                ui.painter().vline(x, y_range, stroke);
+                            }
+                        }
+                    }
+
+                    let button_rect = Rect::from_min_size(
+                        pos2(row_rect.left() + node.depth as f32 * indent, row_rect.top()),
+                        vec2(indent, row_height),
+                    );
+
+                    if node.has_children {
+                        let button_response =
+                            ui.interact(button_rect, row_id.with("toggle"), Sense::click());
+                        if button_response.clicked() {
+                            state.toggle(node.id);
+                            toggled = Some(node.id);
+                        }
+                        let (mut icon_rect, _) = ui.spacing().icon_rectangles(button_rect);
+                        icon_rect.set_center(button_rect.center());
+                        let openness = if state.is_open(node.id) { 1.0 } else { 0.0 };
+                        paint_default_icon(ui, openness, &button_response.with_new_rect(icon_rect));
+                    }
+
+                    let content_rect = Rect::from_min_max(
+                        pos2(button_rect.right(), row_rect.top()),
+                        row_rect
+                            .max
+                            .max(pos2(button_rect.right(), row_rect.bottom())),
+                    );
+                    ui.scope_builder(
+                        UiBuilder::new()
+                            .max_rect(content_rect)
+                            .layout(Layout::left_to_right(Align::Center)),
+                        |ui| {
+                            ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                            add_row(ui, node);
+                        },
+                    );
+
+                    if row_response.clicked() {
+                        state.selected = Some(node.id);
+                        ui.memory_mut(|mem| mem.request_focus(id));
+                    }
+                    if row_response.double_clicked() && node.has_children {
+                        state.toggle(node.id);
+                        toggled = Some(node.id);
+                    }
+                }
+            });
+
+        let response = ui.interact(
+            scroll_output.inner_rect,
+            id,
+            Sense::focusable_noninteractive(),
+        );
+
+        if toggled.is_some() {
+            // Newly opened children show up next frame:
+            ui.ctx().request_repaint();
+        }
+
+        let selected = state.selected;
+        state.store(ui.ctx(), id);
+
+        TreeViewResponse {
+            response,
+            selected,
+            selection_changed: selected != prev_selected,
+            toggled,
+        }
+    }
+}
+
+/// Returns the node that was opened or closed, if any.
+fn keyboard_navigation<T>(
+    ui: &Ui,
+    state: &mut TreeViewState,
+    visible: &mut Vec<TreeNode<T>>,
+    scroll_to_selected: &mut bool,
+) -> Option<Id> {
+    if visible.is_empty() {
+        return None;
+    }
+
+    let mut index = state
+        .selected
+        .and_then(|selected| visible.iter().position(|node| node.id == selected));
+    let mut toggled = None;
+
+    let (up, down, home, end, left, right, toggle) = ui.input_mut(|i| {
+        (
+            i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp),
+            i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(Modifiers::NONE, Key::Home),
+            i.consume_key(Modifiers::NONE, Key::End),
+            i.consume_key(Modifiers::NONE, Key::ArrowLeft),
+            i.consume_key(Modifiers::NONE, Key::ArrowRight),
+            i.consume_key(Modifiers::NONE, Key::Enter)
+                || i.consume_key(Modifiers::NONE, Key::Space),
+        )
+    });
+
+    let last = visible.len() - 1;
+    if down > 0 {
+        index = Some(index.map_or(0, |i| (i + down).at_most(last)));
+    }
+    if up > 0 {
+        index = Some(index.map_or(0, |i| i.saturating_sub(up)));
+    }
+    if home {
+        index = Some(0);
+    }
+    if end {
+        index = Some(last);
+    }
+
+    if let Some(i) = index {
+        let node = &visible[i];
+        let depth = node.depth;
+        let is_open = state.is_open(node.id);
+
+        if right {
+            if node.has_children && !is_open {
+                toggled = Some(node.id);
+            } else if visible
+                .get(i + 1)
+                .is_some_and(|next| node.depth < next.depth)
+            {
+                index = Some(i + 1);
+            }
+        } else if left {
+            if node.has_children && is_open {
+                toggled = Some(node.id);
+            } else if let Some(parent) = visible[..i].iter().rposition(|n| n.depth < node.depth) {
+                index = Some(parent);
+            }
+        } else if toggle && node.has_children {
+            toggled = Some(node.id);
+        }
+
+        if let Some(toggled) = toggled {
+            state.toggle(toggled);
+            if !state.is_open(toggled) {
+                // Hide the descendants right away, so that the rows below don't jump next frame:
+                let num_descendants = visible[i + 1..]
+                    .iter()
+                    .take_while(|n| depth < n.depth)
+                    .count();
+                visible.drain(i + 1..i + 1 + num_descendants);
+            }
+        }
+    }
+
+    let new_selected = index.map(|i| visible[i].id);
+    if new_selected != state.selected {
+        state.selected = new_selected;
+        *scroll_to_selected = true;
+    }
+
+    toggled
+}
@@ -75,6 +75,7 @@ impl EguiGlow {
             shapes,
             pixels_per_point,
             viewport_output,
+            texture_renders: _, // Not yet supported by this backend
         } = self.egui_ctx.run(raw_input, run_ui);
 
         if viewport_output.len() > 1 {
@@ -29,7 +29,7 @@ pub use node::*;
 pub use renderer::*;
 
 use egui::style::ScrollAnimation;
-use egui::{Key, Modifiers, Pos2, Rect, RepaintCause, Vec2, ViewportId};
+use egui::{Key, Modifiers, PointerButton, Pos2, Rect, RepaintCause, Vec2, ViewportId};
 use kittest::Queryable;
 
 #[derive(Debug, Clone)]
@@ -556,6 +556,65 @@ impl<'a, State> Harness<'a, State> {
         self.key_combination_modifiers(modifiers, &[key]);
     }
 
+    /// Move the pointer to `pos` and press `button` there, in the next frame.
+    ///
+    /// In contrast to [`Node::click`], this doesn't need a node, and the events are
+    /// not queued one frame each: they all go into the [`egui::RawInput`] of the next frame.
+    pub fn pointer_down(&mut self, pos: Pos2, button: PointerButton) {
+        self.pointer_button(pos, button, true);
+    }
+
+    /// Move the pointer to `pos` and release `button` there, in the next frame.
+    pub fn pointer_up(&mut self, pos: Pos2, button: PointerButton) {
+        self.pointer_button(pos, button, false);
+    }
+
+    fn pointer_button(&mut self, pos: Pos2, button: PointerButton, pressed: bool) {
+        self.input.events.push(egui::Event::PointerMoved(pos));
+        self.input.events.push(egui::Event::PointerButton {
+            pos,
+            button,
+            pressed,
+            modifiers: Modifiers::default(),
+        });
+    }
+
+    /// Click at `pos` with the primary button: press and release it in the next frame.
+    pub fn click_at(&mut self, pos: Pos2) {
+        self.pointer_down(pos, PointerButton::Primary);
+        self.pointer_up(pos, PointerButton::Primary);
+    }
+
+    /// Move the pointer from `from` to `to` in a few steps, running a frame for each.
+    pub fn move_pointer(&mut self, from: Pos2, to: Pos2) {
+        for step in 1..=4 {
+            let pos = from.lerp(to, step as f32 / 4.0);
+            self.input.events.push(egui::Event::PointerMoved(pos));
+            self.step();
+        }
+    }
+
+    /// Drag from `from` to `to` with the primary button.
+    ///
+    /// See [`Self::drag_button`].
+    pub fn drag(&mut self, from: Pos2, to: Pos2) {
+        self.drag_button(PointerButton::Primary, from, to);
+    }
+
+    /// Drag from `from` to `to` with `button`.
+    ///
+    /// This presses the button at `from` and runs a frame, moves the pointer to `to`
+    /// with [`Self::move_pointer`], and releases the button there in the next frame.
+    /// Call e.g. [`Self::run`] afterwards to see the result of the drag.
+    ///
+    /// To hold the button at `to` instead, use [`Self::pointer_down`] and [`Self::move_pointer`].
+    pub fn drag_button(&mut self, button: PointerButton, from: Pos2, to: Pos2) {
+        self.pointer_down(from, button);
+        self.step();
+        self.move_pointer(from, to);
+        self.pointer_up(to, button);
+    }
+
     /// Render the last output to an image.
     ///
     /// # Errors
@@ -56,6 +56,27 @@ fn test_modifiers() {
     assert!(state.cmd_y_pressed, "Cmd+Y wasn't pressed");
 }
 
+#[test]
+fn test_drag() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (delta, stopped): &mut (Vec2, bool)| {
+            let response = ui.allocate_response(Vec2::splat(100.0), egui::Sense::drag());
+            *delta += response.drag_delta();
+            *stopped |= response.drag_stopped();
+        },
+        (Vec2::ZERO, false),
+    );
+
+    harness.drag(egui::pos2(20.0, 20.0), egui::pos2(60.0, 40.0));
+    assert_eq!(harness.state().0, Vec2::new(40.0, 20.0));
+    assert!(
+        !harness.state().1,
+        "The button is released in the next frame"
+    );
+    harness.run();
+    assert!(harness.state().1, "The drag wasn't stopped");
+}
+
 #[test]
 fn should_wait_for_images() {
     let mut harness = Harness::builder()
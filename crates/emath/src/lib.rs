@@ -379,6 +379,51 @@ fn test_normalized_angle() {
 
 // ----------------------------------------------------------------------------
 
+/// Round `pos` to the closest point on a grid with cells of size `grid`,
+/// where the grid lines go through `offset`.
+///
+/// A zero (or negative) cell size on an axis means no snapping along that axis.
+///
+/// ```
+/// # use emath::{pos2, snap_to_grid, vec2};
+/// let snapped = snap_to_grid(pos2(23.0, 36.0), vec2(10.0, 10.0), vec2(0.0, 5.0));
+/// assert_eq!(snapped, pos2(20.0, 35.0));
+/// ```
+pub fn snap_to_grid(pos: Pos2, grid: Vec2, offset: Vec2) -> Pos2 {
+    let snap = |value: f32, cell: f32, offset: f32| {
+        if cell > 0.0 {
+            ((value - offset) / cell).round() * cell + offset
+        } else {
+            value
+        }
+    };
+    Pos2::new(snap(pos.x, grid.x, offset.x), snap(pos.y, grid.y, offset.y))
+}
+
+#[test]
+fn test_snap_to_grid() {
+    let grid = Vec2::new(8.0, 16.0);
+    assert_eq!(
+        snap_to_grid(Pos2::new(3.0, 9.0), grid, Vec2::ZERO),
+        Pos2::new(0.0, 16.0)
+    );
+    assert_eq!(
+        snap_to_grid(Pos2::new(-5.0, -7.0), grid, Vec2::ZERO),
+        Pos2::new(-8.0, 0.0)
+    );
+    assert_eq!(
+        snap_to_grid(Pos2::new(3.0, 9.0), grid, Vec2::new(2.0, 2.0)),
+        Pos2::new(2.0, 2.0)
+    );
+    assert_eq!(
+        snap_to_grid(Pos2::new(3.0, 9.0), Vec2::new(0.0, 16.0), Vec2::ZERO),
+        Pos2::new(3.0, 16.0),
+        "No snapping along x"
+    );
+}
+
+// ----------------------------------------------------------------------------
+
 /// Calculate a lerp-factor for exponential smoothing using a time step.
 ///
 /// * `exponential_smooth_factor(0.90, 1.0, dt)`: reach 90% in 1.0 seconds
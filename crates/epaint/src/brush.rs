@@ -1,7 +1,7 @@
-use crate::{Rect, TextureId};
+use crate::{Gradient, Rect, TextureId};
 
-/// Controls texturing of a [`crate::RectShape`].
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Controls texturing and gradient fills of a [`crate::RectShape`].
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Brush {
     /// If the rect should be filled with a texture, which one?
@@ -16,4 +16,10 @@ pub struct Brush {
     ///
     /// Use [`Rect::ZERO`] to turn off texturing.
     pub uv: Rect,
+
+    /// Fill with a gradient instead of [`crate::RectShape::fill`], if any.
+    ///
+    /// The gradient is stretched over [`crate::RectShape::rect`].
+    /// If set, the texture is not used.
+    pub fill_gradient: Option<Gradient>,
 }
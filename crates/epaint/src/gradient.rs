@@ -0,0 +1,272 @@
+use crate::{Color32, Pos2, Rect, Vec2, vec2};
+
+/// A color gradient, for filling shapes with e.g. [`crate::RectShape::with_gradient`].
+///
+/// The gradient is stretched over a rectangle, usually the one of the shape being filled.
+/// The colors are blended in gamma space, just like the vertex colors of a [`crate::Mesh`].
+///
+/// ```
+/// # use epaint::{Color32, Gradient};
+/// let header = Gradient::vertical(Color32::LIGHT_BLUE, Color32::DARK_BLUE);
+/// let rainbow = Gradient::linear_stops(
+///     0.0,
+///     [(0.0, Color32::RED), (0.5, Color32::GREEN), (1.0, Color32::BLUE)],
+/// );
+/// let glow = Gradient::radial(Color32::WHITE, Color32::TRANSPARENT);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Gradient {
+    /// The color changes along a straight line.
+    Linear {
+        /// The direction of the gradient, in radians.
+        ///
+        /// `0` goes from left to right, and `TAU / 4` from top to bottom.
+        angle: f32,
+
+        /// The colors at positions along the gradient, in increasing order.
+        ///
+        /// Position `0` is at the corner furthest back, and `1` at the corner furthest ahead,
+        /// so that the gradient covers the whole rectangle at any angle (just like in CSS).
+        stops: Vec<(f32, Color32)>,
+    },
+
+    /// A color for each corner, blended in between.
+    ///
+    /// In the order left-top, right-top, left-bottom, right-bottom.
+    Corners([Color32; 4]),
+
+    /// The color changes with the distance from a center.
+    Radial {
+        /// The center, relative to the rectangle: `(0.5, 0.5)` is in its middle.
+        center: Vec2,
+
+        /// The colors at distances from the center, in increasing order.
+        ///
+        /// Distance `1` is half the width of the rectangle away horizontally,
+        /// and half its height vertically, so a centered gradient reaches `1`
+        /// at the middle of each side.
+        stops: Vec<(f32, Color32)>,
+    },
+}
+
+impl Gradient {
+    /// From `start` to `end`, in the direction of `angle` (in radians, see [`Self::Linear`]).
+    pub fn linear(angle: f32, start: impl Into<Color32>, end: impl Into<Color32>) -> Self {
+        Self::linear_stops(angle, [(0.0, start.into()), (1.0, end.into())])
+    }
+
+    /// From `left` to `right`.
+    pub fn horizontal(left: impl Into<Color32>, right: impl Into<Color32>) -> Self {
+        Self::linear(0.0, left, right)
+    }
+
+    /// From `top` to `bottom`.
+    pub fn vertical(top: impl Into<Color32>, bottom: impl Into<Color32>) -> Self {
+        Self::linear(std::f32::consts::TAU / 4.0, top, bottom)
+    }
+
+    /// A linear gradient with any number of stops, see [`Self::Linear`].
+    pub fn linear_stops(angle: f32, stops: impl IntoIterator<Item = (f32, Color32)>) -> Self {
+        Self::Linear {
+            angle,
+            stops: sorted(stops),
+        }
+    }
+
+    /// One color per corner.
+    pub fn corners(
+        left_top: impl Into<Color32>,
+        right_top: impl Into<Color32>,
+        left_bottom: impl Into<Color32>,
+        right_bottom: impl Into<Color32>,
+    ) -> Self {
+        Self::Corners([
+            left_top.into(),
+            right_top.into(),
+            left_bottom.into(),
+            right_bottom.into(),
+        ])
+    }
+
+    /// From `center` in the middle of the rectangle to `edge` at the middle of its sides.
+    pub fn radial(center: impl Into<Color32>, edge: impl Into<Color32>) -> Self {
+        Self::radial_stops(vec2(0.5, 0.5), [(0.0, center.into()), (1.0, edge.into())])
+    }
+
+    /// A radial gradient with any number of stops, see [`Self::Radial`].
+    pub fn radial_stops(center: Vec2, stops: impl IntoIterator<Item = (f32, Color32)>) -> Self {
+        Self::Radial {
+            center,
+            stops: sorted(stops),
+        }
+    }
+
+    /// Are all the colors transparent?
+    pub fn is_transparent(&self) -> bool {
+        match self {
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } => stops
+                .iter()
+                .all(|(_, color)| *color == Color32::TRANSPARENT),
+            Self::Corners(colors) => colors.iter().all(|color| *color == Color32::TRANSPARENT),
+        }
+    }
+
+    /// The color at `pos`, with the gradient stretched over `rect`.
+    pub fn color_at(&self, rect: Rect, pos: Pos2) -> Color32 {
+        match self {
+            Self::Linear { angle, stops } => {
+                let dir = Vec2::angled(*angle);
+                let (start, length) = linear_extent(rect, dir);
+                let t = (pos.to_vec2().dot(dir) - start) / length;
+                color_at_stops(stops, t)
+            }
+            Self::Corners([left_top, right_top, left_bottom, right_bottom]) => {
+                let t = ((pos - rect.min) / rect.size()).clamp(Vec2::ZERO, Vec2::splat(1.0));
+                let top = left_top.lerp_to_gamma(*right_top, t.x);
+                let bottom = left_bottom.lerp_to_gamma(*right_bottom, t.x);
+                top.lerp_to_gamma(bottom, t.y)
+            }
+            Self::Radial { center, stops } => {
+                let center = rect.min + *center * rect.size();
+                let t = ((pos - center) / (0.5 * rect.size())).length();
+                color_at_stops(stops, t)
+            }
+        }
+    }
+
+    /// Where to cut a shape filled with this gradient over `rect`,
+    /// so that it is well approximated by blending vertex colors across each piece.
+    ///
+    /// Each of the two directions comes with the values of `dir.dot(pos)` to cut at.
+    pub(crate) fn cuts(&self, rect: Rect) -> [(Vec2, Vec<f32>); 2] {
+        /// Roughly how large the pieces are when the gradient isn't linear.
+        const CELL_SIZE: f32 = 8.0;
+
+        /// The most pieces along each axis.
+        const MAX_CELLS: f32 = 32.0;
+
+        let grid = |min: f32, size: f32| {
+            let cells = (size / CELL_SIZE).ceil().clamp(1.0, MAX_CELLS) as usize;
+            (1..cells)
+                .map(|i| min + size * i as f32 / cells as f32)
+                .collect()
+        };
+
+        match self {
+            Self::Linear { angle, stops } => {
+                // Only the stops bend the gradient:
+                let dir = Vec2::angled(*angle);
+                let (start, length) = linear_extent(rect, dir);
+                let cuts = stops
+                    .iter()
+                    .filter(|(t, _)| 0.0 < *t && *t < 1.0)
+                    .map(|(t, _)| start + t * length)
+                    .collect();
+                [(dir, cuts), (dir.rot90(), vec![])]
+            }
+            Self::Corners(_) | Self::Radial { .. } => [
+                (Vec2::X, grid(rect.min.x, rect.width())),
+                (Vec2::Y, grid(rect.min.y, rect.height())),
+            ],
+        }
+    }
+
+    /// Change all the colors of the gradient.
+    pub(crate) fn adjust_colors(&mut self, adjust_color: impl Fn(&mut Color32)) {
+        match self {
+            Self::Linear { stops, .. } | Self::Radial { stops, .. } => {
+                for (_, color) in stops {
+                    adjust_color(color);
+                }
+            }
+            Self::Corners(colors) => {
+                for color in colors {
+                    adjust_color(color);
+                }
+            }
+        }
+    }
+}
+
+fn sorted(stops: impl IntoIterator<Item = (f32, Color32)>) -> Vec<(f32, Color32)> {
+    let mut stops: Vec<_> = stops.into_iter().collect();
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+    stops
+}
+
+/// Where a linear gradient in direction `dir` starts, and how long it is,
+/// measured along `dir`.
+fn linear_extent(rect: Rect, dir: Vec2) -> (f32, f32) {
+    let length = (rect.width() * dir.x.abs() + rect.height() * dir.y.abs()).max(f32::EPSILON);
+    let start = rect.center().to_vec2().dot(dir) - 0.5 * length;
+    (start, length)
+}
+
+fn color_at_stops(stops: &[(f32, Color32)], t: f32) -> Color32 {
+    let Some(&(first_t, first_color)) = stops.first() else {
+        return Color32::TRANSPARENT;
+    };
+    if t <= first_t {
+        return first_color;
+    }
+    for pair in stops.windows(2) {
+        let [(t0, c0), (t1, c1)] = [pair[0], pair[1]];
+        if t <= t1 {
+            return if t0 < t1 {
+                c0.lerp_to_gamma(c1, (t - t0) / (t1 - t0))
+            } else {
+                c1
+            };
+        }
+    }
+    stops[stops.len() - 1].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pos2, vec2};
+
+    #[test]
+    fn linear_covers_the_rect_at_any_angle() {
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), vec2(100.0, 50.0));
+        let gradient = Gradient::horizontal(Color32::BLACK, Color32::WHITE);
+        assert_eq!(gradient.color_at(rect, rect.left_center()), Color32::BLACK);
+        assert_eq!(gradient.color_at(rect, rect.right_top()), Color32::WHITE);
+        assert_eq!(
+            gradient.color_at(rect, rect.center()),
+            Color32::from_gray(128)
+        );
+
+        let diagonal = Gradient::linear(0.3, Color32::BLACK, Color32::WHITE);
+        assert_eq!(diagonal.color_at(rect, rect.left_top()), Color32::BLACK);
+        assert_eq!(diagonal.color_at(rect, rect.right_bottom()), Color32::WHITE);
+    }
+
+    #[test]
+    fn stops_are_sorted_and_clamped() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let gradient = Gradient::linear_stops(
+            0.0,
+            [
+                (0.75, Color32::BLUE),
+                (0.25, Color32::RED),
+                (0.5, Color32::GREEN),
+            ],
+        );
+        assert_eq!(gradient.color_at(rect, pos2(0.0, 0.0)), Color32::RED);
+        assert_eq!(gradient.color_at(rect, pos2(50.0, 0.0)), Color32::GREEN);
+        assert_eq!(gradient.color_at(rect, pos2(100.0, 0.0)), Color32::BLUE);
+        assert_eq!(gradient.cuts(rect)[0].1, vec![25.0, 50.0, 75.0]);
+    }
+
+    #[test]
+    fn radial_reaches_the_sides() {
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+        let gradient = Gradient::radial(Color32::WHITE, Color32::BLACK);
+        assert_eq!(gradient.color_at(rect, rect.center()), Color32::WHITE);
+        assert_eq!(gradient.color_at(rect, rect.center_top()), Color32::BLACK);
+        assert_eq!(gradient.color_at(rect, rect.right_center()), Color32::BLACK);
+    }
+}
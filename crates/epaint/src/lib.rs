@@ -27,6 +27,7 @@ mod brush;
 pub mod color;
 mod corner_radius;
 mod corner_radius_f32;
+mod gradient;
 pub mod image;
 mod margin;
 mod margin_f32;
@@ -50,17 +51,19 @@ pub use self::{
     color::ColorMode,
     corner_radius::CornerRadius,
     corner_radius_f32::CornerRadiusF32,
+    gradient::Gradient,
     image::{AlphaFromCoverage, ColorImage, ImageData, ImageDelta},
     margin::Margin,
     margin_f32::*,
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
-        QuadraticBezierShape, RectShape, Shape, TextShape,
+        CircleShape, ClipPolygon, ClipShape, CornerSmoothing, CubicBezierShape, DashedShape,
+        EllipseShape, PaintCallback, PaintCallbackInfo, PathShape, QuadraticBezierShape, RectShape,
+        Shape, TextShape,
     },
     stats::PaintStats,
-    stroke::{PathStroke, Stroke, StrokeKind},
+    stroke::{LinePattern, PathStroke, Stroke, StrokeKind},
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
     texture_atlas::TextureAtlas,
@@ -57,19 +57,32 @@ pub fn adjust_colors(
             radius: _,
             fill,
             stroke,
-        })
-        | Shape::Rect(RectShape {
+        }) => {
+            adjust_color(fill);
+            adjust_color(&mut stroke.color);
+        }
+
+        Shape::Rect(RectShape {
             rect: _,
             corner_radius: _,
+            corner_smoothing: _,
             fill,
             stroke,
             stroke_kind: _,
             round_to_pixels: _,
+            feathering: _,
             blur_width: _,
-            brush: _,
+            brush,
         }) => {
             adjust_color(fill);
             adjust_color(&mut stroke.color);
+            if let Some(brush) = brush {
+                if brush.fill_gradient.is_some() {
+                    if let Some(fill_gradient) = &mut Arc::make_mut(brush).fill_gradient {
+                        fill_gradient.adjust_colors(adjust_color);
+                    }
+                }
+            }
         }
 
         Shape::Text(TextShape {
@@ -113,6 +126,14 @@ pub fn adjust_colors(
         Shape::Callback(_) => {
             // Can't tint user callback code
         }
+
+        Shape::Clip(clip_shape) => {
+            adjust_colors(&mut clip_shape.shape, adjust_color);
+        }
+
+        Shape::Dashed(dashed_shape) => {
+            adjust_colors(&mut dashed_shape.shape, adjust_color);
+        }
     }
 }
 
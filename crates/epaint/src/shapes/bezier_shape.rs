@@ -4,7 +4,7 @@
 use std::ops::Range;
 
 use crate::{Color32, PathShape, PathStroke, Shape};
-use emath::{Pos2, Rect, RectTransform};
+use emath::{Pos2, Rect, RectTransform, Vec2};
 
 // ----------------------------------------------------------------------------
 
@@ -42,6 +42,53 @@ impl CubicBezierShape {
         }
     }
 
+    /// A smooth curve from `from` to `to`, like the wires between the ports of a node graph.
+    ///
+    /// The curve leaves `from` in the direction of `from_dir`,
+    /// and arrives at `to` from the direction of `to_dir`.
+    /// For instance, a wire from an output port on the right side of one node
+    /// to an input port on the left side of another uses
+    /// `from_dir = Vec2::RIGHT` and `to_dir = Vec2::LEFT`.
+    pub fn connection(
+        from: Pos2,
+        to: Pos2,
+        from_dir: Vec2,
+        to_dir: Vec2,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        // How far out the control points are.
+        // Never too short, so that the curve stays smooth when the ports are close,
+        // or when `to` is behind `from`:
+        let reach = (0.5 * from.distance(to)).max(25.0);
+        Self::from_points_stroke(
+            [
+                from,
+                from + reach * from_dir.normalized(),
+                to + reach * to_dir.normalized(),
+                to,
+            ],
+            false,
+            Color32::TRANSPARENT,
+            stroke,
+        )
+    }
+
+    /// The shortest distance from `pos` to the curve.
+    ///
+    /// Use this for hit-testing, e.g. to hover and select the wires of a node graph.
+    pub fn distance_to_curve(&self, pos: Pos2) -> f32 {
+        // Plenty precise for hit-testing:
+        let tolerance = 0.1;
+
+        let mut prev = self.points[0];
+        let mut distance_sq = prev.distance_sq(pos);
+        self.for_each_flattened_with_t(tolerance, &mut |point, _t| {
+            distance_sq = distance_sq.min(distance_sq_to_segment(pos, [prev, point]));
+            prev = point;
+        });
+        distance_sq.sqrt()
+    }
+
     /// Transform the curve with the given transform.
     pub fn transform(&self, transform: &RectTransform) -> Self {
         let mut points = [Pos2::default(); 4];
@@ -764,6 +811,16 @@ fn cubic_for_each_local_extremum<F: FnMut(f32)>(p0: f32, p1: f32, p2: f32, p3: f
     }
 }
 
+fn distance_sq_to_segment(pos: Pos2, [a, b]: [Pos2; 2]) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_sq() > 0.0 {
+        ((pos - a).dot(ab) / ab.length_sq()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    pos.distance_sq(a + t * ab)
+}
+
 #[cfg(test)]
 mod tests {
     use emath::pos2;
@@ -1130,4 +1187,47 @@ mod tests {
 
         assert_eq!(result.len(), 223);
     }
+
+    #[test]
+    fn test_connection() {
+        let from = pos2(0.0, 0.0);
+        let to = pos2(200.0, 100.0);
+        let curve = CubicBezierShape::connection(
+            from,
+            to,
+            Vec2::RIGHT,
+            Vec2::LEFT,
+            crate::Stroke::new(1.0, Color32::WHITE),
+        );
+        assert_eq!(curve.points[0], from);
+        assert_eq!(curve.points[3], to);
+
+        // Leaves and arrives horizontally:
+        assert_eq!(curve.points[1].y, from.y);
+        assert!(from.x < curve.points[1].x);
+        assert_eq!(curve.points[2].y, to.y);
+        assert!(curve.points[2].x < to.x);
+
+        // An S-curve through the middle:
+        assert!(curve.sample(0.5).distance(pos2(100.0, 50.0)) < 1e-3);
+    }
+
+    #[test]
+    fn test_distance_to_curve() {
+        let curve = CubicBezierShape::connection(
+            pos2(0.0, 0.0),
+            pos2(200.0, 100.0),
+            Vec2::RIGHT,
+            Vec2::LEFT,
+            crate::Stroke::new(1.0, Color32::WHITE),
+        );
+
+        for t in [0.0, 0.1, 0.5, 0.77, 1.0] {
+            let on_curve = curve.sample(t);
+            assert!(curve.distance_to_curve(on_curve) < 0.1, "t = {t}");
+        }
+        assert!((curve.distance_to_curve(pos2(-10.0, 0.0)) - 10.0).abs() < 1e-3);
+        assert!((curve.distance_to_curve(pos2(200.0, 120.0)) - 20.0).abs() < 1e-3);
+        assert!(20.0 < curve.distance_to_curve(pos2(200.0, 0.0)));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Color32, Pos2, Rect, Shape, Stroke, Vec2};
+use crate::{Color32, DashedShape, LinePattern, Pos2, Rect, Shape, Stroke, Vec2};
 
 /// How to paint a circle.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -31,6 +31,12 @@ impl CircleShape {
         }
     }
 
+    /// Break up the stroke into the dashes of the given pattern.
+    #[inline]
+    pub fn dashed(self, pattern: LinePattern) -> DashedShape {
+        DashedShape::new(pattern, self)
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     pub fn visual_bounding_rect(&self) -> Rect {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use emath::{Pos2, Rect, TSTransform, Vec2, lerp, pos2};
+
+use crate::{CornerRadiusF32, Mesh, Shape, Vertex, tessellator::path};
+
+/// A convex region to clip shapes to, e.g. a rounded rectangle or a circle.
+///
+/// Unlike the clip rectangle, this is applied when tessellating,
+/// by cutting the triangles of the [`Mesh`] along the edges of the polygon.
+/// The edges are anti-aliased (feathered) like the edges of other shapes.
+///
+/// See [`ClipShape`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ClipPolygon {
+    /// Clockwise on screen, so the inside is to the right of every edge.
+    points: Vec<Pos2>,
+}
+
+impl ClipPolygon {
+    /// The points must form a convex polygon. They can be in either winding order.
+    pub fn new(mut points: Vec<Pos2>) -> Self {
+        points.dedup_by(|a, b| a.distance_sq(*b) < 1e-8);
+        while 1 < points.len() && points[0].distance_sq(points[points.len() - 1]) < 1e-8 {
+            points.pop();
+        }
+
+        let twice_area: f32 = (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum();
+        if twice_area < 0.0 {
+            points.reverse();
+        }
+
+        Self { points }
+    }
+
+    pub fn rect(rect: Rect) -> Self {
+        Self::rounded_rect(rect, 0.0)
+    }
+
+    pub fn rounded_rect(rect: Rect, corner_radius: impl Into<CornerRadiusF32>) -> Self {
+        let mut points = vec![];
+        path::rounded_rectangle(&mut points, rect, corner_radius.into());
+        Self::new(points)
+    }
+
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::rounded_rect(
+            Rect::from_center_size(center, Vec2::splat(2.0 * radius)),
+            radius,
+        )
+    }
+
+    /// The corners of the polygon, clockwise on screen.
+    #[inline]
+    pub fn points(&self) -> &[Pos2] {
+        &self.points
+    }
+
+    pub fn bounding_rect(&self) -> Rect {
+        Rect::from_points(&self.points)
+    }
+
+    /// How far inside the polygon `pos` is. Negative outside of it.
+    pub fn signed_distance(&self, pos: Pos2) -> f32 {
+        self.edges()
+            .map(|edge| edge.distance(pos))
+            .min_by(f32::total_cmp)
+            .unwrap_or(f32::NEG_INFINITY)
+    }
+
+    pub fn contains(&self, pos: Pos2) -> bool {
+        0.0 <= self.signed_distance(pos)
+    }
+
+    /// The part of `self` that is also inside `other`.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut polygon: Vec<Vertex> = self
+            .points
+            .iter()
+            .map(|&pos| Vertex {
+                pos,
+                ..Default::default()
+            })
+            .collect();
+        for edge in other.edges() {
+            polygon = clip_by_plane(&polygon, |pos| edge.distance(pos));
+        }
+        Self::new(polygon.iter().map(|v| v.pos).collect())
+    }
+
+    pub fn transform(&mut self, transform: TSTransform) {
+        for p in &mut self.points {
+            *p = transform * *p;
+        }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        (0..self.points.len()).filter_map(|i| {
+            let (a, b) = (self.points[i], self.points[(i + 1) % self.points.len()]);
+            let dir = (b - a).normalized();
+            dir.is_finite().then_some(Edge { point: a, dir })
+        })
+    }
+
+    /// Remove everything outside of the polygon from the mesh.
+    ///
+    /// Triangles crossing an edge are cut along it, with a `feathering` wide
+    /// anti-aliased fringe centered on the edge.
+    pub fn clip_mesh(&self, mesh: &mut Mesh, feathering: f32) {
+        let half_feathering = 0.5 * feathering.max(0.0);
+        let is_inside = |pos| half_feathering <= self.signed_distance(pos);
+        if mesh.vertices.iter().all(|v| is_inside(v.pos)) {
+            return;
+        }
+
+        let edges: Vec<Edge> = self.edges().collect();
+        let input = std::mem::replace(mesh, Mesh::with_texture(mesh.texture_id));
+        let mut copied_vertices = vec![None; input.vertices.len()];
+
+        for triangle in input.triangles() {
+            let vertices = triangle.map(|i| input.vertices[i as usize]);
+
+            if vertices.iter().all(|v| is_inside(v.pos)) {
+                let [a, b, c] = triangle.map(|i| {
+                    *copied_vertices[i as usize].get_or_insert_with(|| {
+                        mesh.vertices.push(input.vertices[i as usize]);
+                        mesh.vertices.len() as u32 - 1
+                    })
+                });
+                mesh.add_triangle(a, b, c);
+                continue;
+            }
+
+            let is_outside_an_edge = edges.iter().any(|edge| {
+                vertices
+                    .iter()
+                    .all(|v| edge.distance(v.pos) < -half_feathering)
+            });
+            if is_outside_an_edge {
+                continue;
+            }
+
+            let mut pieces = vec![vertices.to_vec()];
+            for edge in &edges {
+                let outer = |pos| edge.distance(pos) + half_feathering;
+                let inner = |pos| edge.distance(pos) - half_feathering;
+                pieces = pieces
+                    .iter()
+                    .map(|piece| clip_by_plane(piece, outer))
+                    .flat_map(|piece| {
+                        // Split along the inner side of the fringe too,
+                        // so that the coverage is linear within each piece:
+                        let crosses_inner_side = 0.0 < half_feathering
+                            && piece.iter().any(|v| inner(v.pos) < 0.0)
+                            && piece.iter().any(|v| 0.0 < inner(v.pos));
+                        if crosses_inner_side {
+                            vec![
+                                clip_by_plane(&piece, inner),
+                                clip_by_plane(&piece, |pos| -inner(pos)),
+                            ]
+                        } else {
+                            vec![piece]
+                        }
+                    })
+                    .filter(|piece| 3 <= piece.len())
+                    .collect();
+            }
+
+            for piece in pieces {
+                let first = mesh.vertices.len() as u32;
+                mesh.vertices.extend(piece.iter().map(|&v| {
+                    let coverage = if 0.0 < half_feathering {
+                        let distance = self.signed_distance(v.pos);
+                        (0.5 + 0.5 * distance / half_feathering).clamp(0.0, 1.0)
+                    } else {
+                        1.0
+                    };
+                    Vertex {
+                        color: v.color.gamma_multiply(coverage),
+                        ..v
+                    }
+                }));
+                for i in 2..piece.len() as u32 {
+                    mesh.add_triangle(first, first + i - 1, first + i);
+                }
+            }
+        }
+    }
+}
+
+/// The edge of a [`ClipPolygon`], going from `point` in the direction `dir`.
+#[derive(Clone, Copy)]
+struct Edge {
+    point: Pos2,
+    dir: Vec2,
+}
+
+impl Edge {
+    /// How far to the right of the edge `pos` is, i.e. how far inside the polygon.
+    fn distance(self, pos: Pos2) -> f32 {
+        let delta = pos - self.point;
+        self.dir.x * delta.y - self.dir.y * delta.x
+    }
+}
+
+/// The part of a convex polygon where `distance` is positive.
+fn clip_by_plane(polygon: &[Vertex], distance: impl Fn(Pos2) -> f32) -> Vec<Vertex> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (da, db) = (distance(a.pos), distance(b.pos));
+        if 0.0 <= da {
+            clipped.push(a);
+        }
+        if (0.0 <= da) != (0.0 <= db) {
+            let t = da / (da - db);
+            clipped.push(Vertex {
+                pos: pos2(lerp(a.pos.x..=b.pos.x, t), lerp(a.pos.y..=b.pos.y, t)),
+                uv: pos2(lerp(a.uv.x..=b.uv.x, t), lerp(a.uv.y..=b.uv.y, t)),
+                color: a.color.lerp_to_gamma(b.color, t),
+            });
+        }
+    }
+    clipped
+}
+
+/// A [`Shape`] that is only painted inside of a convex [`ClipPolygon`],
+/// e.g. to give an image rounded corners.
+///
+/// This is in addition to the clip rectangle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipShape {
+    /// Only the parts of [`Self::shape`] inside of this are painted.
+    pub clip: Arc<ClipPolygon>,
+
+    pub shape: Box<Shape>,
+}
+
+impl ClipShape {
+    #[inline]
+    pub fn new(clip: impl Into<Arc<ClipPolygon>>, shape: impl Into<Shape>) -> Self {
+        Self {
+            clip: clip.into(),
+            shape: Box::new(shape.into()),
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke widths)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.shape
+            .visual_bounding_rect()
+            .intersect(self.clip.bounding_rect())
+    }
+}
+
+impl From<ClipShape> for Shape {
+    #[inline(always)]
+    fn from(shape: ClipShape) -> Self {
+        Self::Clip(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color32, vec2};
+
+    fn clipped_square(clip: &ClipPolygon, feathering: f32) -> Mesh {
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+            Color32::WHITE,
+        );
+        clip.clip_mesh(&mut mesh, feathering);
+        assert!(mesh.is_valid());
+        mesh
+    }
+
+    fn assert_rect_near(a: Rect, b: Rect) {
+        assert!(
+            a.min.distance(b.min) < 1e-3 && a.max.distance(b.max) < 1e-3,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn clip_to_circle() {
+        let clip = ClipPolygon::circle(pos2(50.0, 50.0), 30.0);
+        let mesh = clipped_square(&clip, 1.0);
+
+        for v in &mesh.vertices {
+            let distance = clip.signed_distance(v.pos);
+            assert!(-0.5 - 1e-3 <= distance, "{v:?} is outside");
+            if 0.5 + 1e-3 < distance {
+                assert_eq!(v.color, Color32::WHITE);
+            }
+        }
+        assert!(
+            mesh.vertices
+                .iter()
+                .any(|v| v.color == Color32::TRANSPARENT),
+            "The edge is feathered"
+        );
+        // The fringe is centered on the edge:
+        assert_rect_near(mesh.calc_bounds(), clip.bounding_rect().expand(0.5));
+    }
+
+    #[test]
+    fn clip_without_feathering() {
+        let clip = ClipPolygon::rounded_rect(
+            Rect::from_min_max(pos2(-10.0, 20.0), pos2(60.0, 150.0)),
+            8.0,
+        );
+        let mesh = clipped_square(&clip, 0.0);
+        assert!(mesh.vertices.iter().all(|v| v.color == Color32::WHITE));
+        assert_rect_near(
+            mesh.calc_bounds(),
+            Rect::from_min_max(pos2(0.0, 20.0), pos2(60.0, 100.0)),
+        );
+
+        let outside = ClipPolygon::rect(Rect::from_min_size(pos2(200.0, 0.0), vec2(10.0, 10.0)));
+        assert!(clipped_square(&outside, 1.0).is_empty());
+    }
+
+    #[test]
+    fn intersection_of_polygons() {
+        let a = ClipPolygon::rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)));
+        let b = ClipPolygon::new(vec![pos2(-5.0, -5.0), pos2(15.0, -5.0), pos2(-5.0, 15.0)]);
+        let both = a.intersect(&b);
+        assert!(both.contains(pos2(2.0, 2.0)));
+        assert!(!both.contains(pos2(8.0, 8.0)), "Outside of b");
+        assert!(!both.contains(pos2(-1.0, 1.0)), "Outside of a");
+        assert_eq!(
+            both.bounding_rect(),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0))
+        );
+    }
+}
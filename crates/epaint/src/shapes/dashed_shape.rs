@@ -0,0 +1,38 @@
+use crate::{LinePattern, Rect, Shape};
+
+/// A [`Shape`] with its outline broken up into dashes (or dots).
+///
+/// The dashes follow the outline of [`crate::RectShape`], [`crate::CircleShape`] and [`crate::PathShape`],
+/// including around rounded corners.
+/// The fill is painted as normal. Other shapes are painted as if they were not dashed.
+///
+/// For polylines that you want to split up yourself, see [`Shape::dashed_line`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DashedShape {
+    pub pattern: LinePattern,
+
+    pub shape: Box<Shape>,
+}
+
+impl DashedShape {
+    #[inline]
+    pub fn new(pattern: LinePattern, shape: impl Into<Shape>) -> Self {
+        Self {
+            pattern,
+            shape: Box::new(shape.into()),
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke widths)
+    #[inline]
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.shape.visual_bounding_rect()
+    }
+}
+
+impl From<DashedShape> for Shape {
+    #[inline(always)]
+    fn from(shape: DashedShape) -> Self {
+        Self::Dashed(shape)
+    }
+}
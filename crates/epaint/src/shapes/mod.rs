@@ -1,5 +1,7 @@
 mod bezier_shape;
 mod circle_shape;
+mod clip_shape;
+mod dashed_shape;
 mod ellipse_shape;
 mod paint_callback;
 mod path_shape;
@@ -10,10 +12,12 @@ mod text_shape;
 pub use self::{
     bezier_shape::{CubicBezierShape, QuadraticBezierShape},
     circle_shape::CircleShape,
+    clip_shape::{ClipPolygon, ClipShape},
+    dashed_shape::DashedShape,
     ellipse_shape::EllipseShape,
     paint_callback::{PaintCallback, PaintCallbackInfo},
     path_shape::PathShape,
-    rect_shape::RectShape,
+    rect_shape::{CornerSmoothing, RectShape},
     shape::Shape,
     text_shape::TextShape,
 };
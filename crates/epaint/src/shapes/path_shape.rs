@@ -62,6 +62,12 @@ impl PathShape {
         }
     }
 
+    /// Break up the stroke into the dashes of the given pattern.
+    #[inline]
+    pub fn dashed(self, pattern: LinePattern) -> DashedShape {
+        DashedShape::new(pattern, self)
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{num::NonZeroU8, sync::Arc};
 
 use crate::*;
 
@@ -20,6 +20,11 @@ pub struct RectShape {
     /// so the rounding will in this case specify the outer corner radius.
     pub corner_radius: CornerRadius,
 
+    /// How smooth the rounded corners are.
+    ///
+    /// If `None`, [`crate::TessellationOptions::corner_smoothing`] will be used.
+    pub corner_smoothing: Option<CornerSmoothing>,
+
     /// How to fill the rectangle.
     pub fill: Color32,
 
@@ -41,6 +46,14 @@ pub struct RectShape {
     /// If `None`, [`crate::TessellationOptions::round_rects_to_pixels`] will be used.
     pub round_to_pixels: Option<bool>,
 
+    /// Anti-alias the edges with feathering?
+    ///
+    /// Turn this off for crisp, pixel-aligned rectangles,
+    /// e.g. together with [`Self::round_to_pixels`].
+    ///
+    /// If `None`, [`crate::TessellationOptions::feathering`] will be used.
+    pub feathering: Option<bool>,
+
     /// If larger than zero, the edges of the rectangle
     /// (for both fill and stroke) will be blurred.
     ///
@@ -49,7 +62,7 @@ pub struct RectShape {
     /// The blur is currently implemented using a simple linear blur in sRGBA gamma space.
     pub blur_width: f32,
 
-    /// Controls texturing, if any.
+    /// Controls texturing and gradient fills, if any.
     ///
     /// Since most rectangles do not have a texture, this is optional and in an `Arc`,
     /// so that [`RectShape`] is kept small..
@@ -82,10 +95,12 @@ impl RectShape {
         Self {
             rect,
             corner_radius: corner_radius.into(),
+            corner_smoothing: None,
             fill: fill_color.into(),
             stroke: stroke.into(),
             stroke_kind,
             round_to_pixels: None,
+            feathering: None,
             blur_width: 0.0,
             brush: Default::default(),
         }
@@ -124,6 +139,15 @@ impl RectShape {
         self
     }
 
+    /// How smooth the rounded corners are, from `0.0` (circular arcs) to `1.0` (squircle-like).
+    ///
+    /// See [`CornerSmoothing`].
+    #[inline]
+    pub fn with_corner_smoothing(mut self, corner_smoothing: impl Into<CornerSmoothing>) -> Self {
+        self.corner_smoothing = Some(corner_smoothing.into());
+        self
+    }
+
     /// Snap the rectangle to pixels?
     ///
     /// Rounding produces sharper rectangles.
@@ -135,6 +159,18 @@ impl RectShape {
         self
     }
 
+    /// Anti-alias the edges with feathering?
+    ///
+    /// Turn this off for crisp, pixel-aligned rectangles,
+    /// e.g. together with [`Self::with_round_to_pixels`].
+    ///
+    /// If `None`, [`crate::TessellationOptions::feathering`] will be used.
+    #[inline]
+    pub fn with_feathering(mut self, feathering: bool) -> Self {
+        self.feathering = Some(feathering);
+        self
+    }
+
     /// If larger than zero, the edges of the rectangle
     /// (for both fill and stroke) will be blurred.
     ///
@@ -153,14 +189,39 @@ impl RectShape {
         self.brush = Some(Arc::new(Brush {
             fill_texture_id,
             uv,
+            fill_gradient: None,
         }));
         self
     }
 
+    /// Fill the rectangle with a gradient instead of [`Self::fill`].
+    ///
+    /// The gradient is stretched over [`Self::rect`].
+    /// It can't be combined with a texture.
+    #[inline]
+    pub fn with_gradient(mut self, gradient: impl Into<Gradient>) -> Self {
+        self.brush = Some(Arc::new(Brush {
+            fill_texture_id: TextureId::default(),
+            uv: Rect::ZERO,
+            fill_gradient: Some(gradient.into()),
+        }));
+        self
+    }
+
+    /// Break up the stroke into the dashes of the given pattern.
+    #[inline]
+    pub fn dashed(self, pattern: LinePattern) -> DashedShape {
+        DashedShape::new(pattern, self)
+    }
+
     /// The visual bounding rectangle (includes stroke width)
     #[inline]
     pub fn visual_bounding_rect(&self) -> Rect {
-        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+        let has_fill = match self.fill_gradient() {
+            Some(gradient) => !gradient.is_transparent(),
+            None => self.fill != Color32::TRANSPARENT,
+        };
+        if !has_fill && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
             let expand = match self.stroke_kind {
@@ -180,6 +241,78 @@ impl RectShape {
             .as_ref()
             .map_or_else(TextureId::default, |brush| brush.fill_texture_id)
     }
+
+    /// The gradient to fill this rectangle with, if any.
+    pub fn fill_gradient(&self) -> Option<&Gradient> {
+        self.brush.as_ref()?.fill_gradient.as_ref()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How smooth the rounded corners of a [`RectShape`] are.
+///
+/// At `0.0` each corner is a circular arc, which meets the straight edges with a sudden change in curvature.
+/// Higher values spread the bend further along the edges, up to `1.0`
+/// where each corner is a [superellipse](https://en.wikipedia.org/wiki/Superellipse) twice the length of the corner radius,
+/// similar to the "smooth corners" of iOS and many design tools.
+/// The corners cut in by roughly the same amount regardless of the smoothing.
+///
+/// Stored in a single byte, to keep [`RectShape`] small.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CornerSmoothing(NonZeroU8);
+
+impl CornerSmoothing {
+    /// Circular arcs.
+    pub const CIRCULAR: Self = Self(NonZeroU8::MIN);
+
+    /// Squircle-like corners.
+    pub const SMOOTH: Self = Self(NonZeroU8::MAX);
+
+    /// The smoothing is clamped to `[0, 1]`.
+    #[inline]
+    pub fn new(smoothing: f32) -> Self {
+        let steps = (smoothing.clamp(0.0, 1.0) * 254.0).round() as u8;
+        Self(NonZeroU8::MIN.saturating_add(steps))
+    }
+
+    /// In `[0, 1]`.
+    #[inline]
+    pub fn get(self) -> f32 {
+        (self.0.get() - 1) as f32 / 254.0
+    }
+}
+
+impl Default for CornerSmoothing {
+    #[inline]
+    fn default() -> Self {
+        Self::CIRCULAR
+    }
+}
+
+impl From<f32> for CornerSmoothing {
+    #[inline]
+    fn from(smoothing: f32) -> Self {
+        Self::new(smoothing)
+    }
+}
+
+impl std::fmt::Debug for CornerSmoothing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CornerSmoothing({})", self.get())
+    }
+}
+
+#[test]
+fn corner_smoothing_round_trip() {
+    assert_eq!(CornerSmoothing::new(0.0), CornerSmoothing::CIRCULAR);
+    assert_eq!(CornerSmoothing::new(1.0), CornerSmoothing::SMOOTH);
+    assert_eq!(CornerSmoothing::new(2.0), CornerSmoothing::SMOOTH);
+    assert_eq!(CornerSmoothing::new(f32::NAN), CornerSmoothing::CIRCULAR);
+    assert_eq!(CornerSmoothing::CIRCULAR.get(), 0.0);
+    assert_eq!(CornerSmoothing::SMOOTH.get(), 1.0);
+    assert!((CornerSmoothing::new(0.6).get() - 0.6).abs() < 0.5 / 254.0);
 }
 
 impl From<RectShape> for Shape {
@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    CircleShape, ClipShape, CubicBezierShape, DashedShape, EllipseShape, PaintCallback, PathShape,
+    QuadraticBezierShape, RectShape, TextShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -68,6 +68,16 @@ pub enum Shape {
 
     /// Backend-specific painting.
     Callback(PaintCallback),
+
+    /// Another shape, only painted inside of a convex polygon, e.g. a rounded rectangle.
+    ///
+    /// This is in addition to the clip rectangle, and more expensive,
+    /// since the clipping happens when tessellating.
+    /// [`Shape::Callback`]s are not clipped to the polygon.
+    Clip(ClipShape),
+
+    /// Another shape, with its outline broken up into dashes or dots.
+    Dashed(DashedShape),
 }
 
 #[test]
@@ -383,6 +393,8 @@ impl Shape {
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
+            Self::Clip(clip_shape) => clip_shape.visual_bounding_rect(),
+            Self::Dashed(dashed_shape) => dashed_shape.visual_bounding_rect(),
         }
     }
 }
@@ -391,7 +403,11 @@ impl Shape {
 impl Shape {
     #[inline(always)]
     pub fn texture_id(&self) -> crate::TextureId {
-        if let Self::Mesh(mesh) = self {
+        if let Self::Clip(clip_shape) = self {
+            clip_shape.shape.texture_id()
+        } else if let Self::Dashed(dashed_shape) = self {
+            dashed_shape.shape.texture_id()
+        } else if let Self::Mesh(mesh) = self {
             mesh.texture_id
         } else if let Self::Rect(rect_shape) = self {
             rect_shape.fill_texture_id()
@@ -477,6 +493,17 @@ impl Shape {
             Self::Callback(shape) => {
                 shape.rect = transform * shape.rect;
             }
+            Self::Clip(clip_shape) => {
+                Arc::make_mut(&mut clip_shape.clip).transform(transform);
+                clip_shape.shape.transform(transform);
+            }
+            Self::Dashed(dashed_shape) => {
+                let pattern = &mut dashed_shape.pattern;
+                pattern.dash_length *= transform.scaling;
+                pattern.gap_length *= transform.scaling;
+                pattern.offset *= transform.scaling;
+                dashed_shape.shape.transform(transform);
+            }
         }
     }
 }
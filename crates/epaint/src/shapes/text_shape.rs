@@ -67,6 +67,18 @@ impl TextShape {
             .translate(self.pos.to_vec2())
     }
 
+    /// The bounding rectangle of the [`Galley::rect`] after rotating it by [`Self::angle`].
+    ///
+    /// Unlike [`Self::visual_bounding_rect`] this includes the full row heights,
+    /// which makes it better suited for layout.
+    #[inline]
+    pub fn logical_bounding_rect(&self) -> Rect {
+        self.galley
+            .rect
+            .rotate_bb(Rot2::from_angle(self.angle))
+            .translate(self.pos.to_vec2())
+    }
+
     #[inline]
     pub fn with_underline(mut self, underline: Stroke) -> Self {
         self.underline = underline;
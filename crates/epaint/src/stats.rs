@@ -226,6 +226,12 @@ impl PaintStats {
             Shape::Callback(_) => {
                 self.num_callbacks += 1;
             }
+            Shape::Clip(clip_shape) => {
+                self.add(&clip_shape.shape);
+            }
+            Shape::Dashed(dashed_shape) => {
+                self.add(&dashed_shape.shape);
+            }
         }
     }
 
@@ -111,6 +111,174 @@ pub enum StrokeKind {
     Outside,
 }
 
+/// Breaks up a stroke into dashes, measured along the length of the outline.
+///
+/// Used by [`crate::DashedShape`].
+///
+/// Advance [`Self::offset`] each frame for an animated "marching ants" effect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LinePattern {
+    /// Length of each dash, in points.
+    pub dash_length: f32,
+
+    /// Length of the gap between two dashes, in points.
+    ///
+    /// If this is zero, the stroke is solid.
+    pub gap_length: f32,
+
+    /// How far into the pattern the outline starts, in points.
+    pub offset: f32,
+}
+
+impl LinePattern {
+    #[inline]
+    pub fn dashed(dash_length: f32, gap_length: f32) -> Self {
+        Self {
+            dash_length,
+            gap_length,
+            offset: 0.0,
+        }
+    }
+
+    /// Square dots of the given size, with equally sized gaps between them.
+    ///
+    /// Use the stroke width as `size` for square dots.
+    #[inline]
+    pub fn dotted(size: f32) -> Self {
+        Self::dashed(size, size)
+    }
+
+    #[inline]
+    pub fn with_offset(self, offset: f32) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// The length of one dash plus one gap.
+    #[inline]
+    pub fn period(&self) -> f32 {
+        self.dash_length + self.gap_length
+    }
+
+    /// Split a line through `points` into the dashes of this pattern.
+    ///
+    /// If `closed`, the line returns to the first point, and the dashes wrap around the seam
+    /// so that a dash (rather than a gap) begins where the pattern repeats.
+    ///
+    /// Each dash is appended to `out` as a line through two or more points.
+    pub fn split_line(&self, points: &[Pos2], closed: bool, out: &mut Vec<Vec<Pos2>>) {
+        if points.len() < 2 || self.dash_length <= 0.0 {
+            return;
+        }
+
+        if self.gap_length <= 0.0 {
+            let mut line = points.to_vec();
+            if closed {
+                line.push(points[0]);
+            }
+            out.push(line);
+            return;
+        }
+
+        if !closed {
+            split_open_line(points, self.offset, *self, out);
+            return;
+        }
+
+        // Rotate the loop so it starts where a dash starts:
+        let n = points.len();
+        let length: f32 = (0..n)
+            .map(|i| points[i].distance(points[(i + 1) % n]))
+            .sum();
+        if length <= 0.0 {
+            return;
+        }
+        let mut start = (-self.offset).rem_euclid(self.period());
+        if length <= start {
+            start = 0.0; // No dash starts on this loop
+        }
+
+        let mut line = Vec::with_capacity(n + 2);
+        let mut distance = 0.0;
+        for i in 0..n {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let segment_length = a.distance(b);
+            if start <= distance + segment_length || i + 1 == n {
+                let t = if 0.0 < segment_length {
+                    ((start - distance) / segment_length).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let seam = a + t * (b - a);
+                line.push(seam);
+                line.extend((1..=n).map(|j| points[(i + j) % n]));
+                line.push(seam);
+                break;
+            }
+            distance += segment_length;
+        }
+
+        split_open_line(&line, start + self.offset, *self, out);
+    }
+}
+
+/// Split an open line into dashes, starting `phase` points into the pattern.
+fn split_open_line(points: &[Pos2], phase: f32, pattern: LinePattern, out: &mut Vec<Vec<Pos2>>) {
+    let LinePattern {
+        dash_length,
+        gap_length,
+        ..
+    } = pattern;
+
+    let phase = phase.rem_euclid(pattern.period());
+    let mut in_dash = phase < dash_length;
+    let mut remaining = if in_dash {
+        dash_length - phase
+    } else {
+        pattern.period() - phase
+    };
+    let mut dash = Vec::new();
+    if in_dash {
+        dash.push(points[0]);
+    }
+
+    let push_point = |dash: &mut Vec<Pos2>, point: Pos2| {
+        if dash.last() != Some(&point) {
+            dash.push(point);
+        }
+    };
+
+    for window in points.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        let segment_length = a.distance(b);
+        let mut t = 0.0;
+        while remaining <= segment_length - t {
+            t += remaining;
+            let point = a + (t / segment_length) * (b - a);
+            if in_dash {
+                push_point(&mut dash, point);
+                let finished = std::mem::take(&mut dash);
+                if 2 <= finished.len() {
+                    out.push(finished);
+                }
+                remaining = gap_length;
+            } else {
+                dash.push(point);
+                remaining = dash_length;
+            }
+            in_dash = !in_dash;
+        }
+        remaining -= segment_length - t;
+        if in_dash {
+            push_point(&mut dash, b);
+        }
+    }
+
+    if in_dash && 2 <= dash.len() {
+        out.push(dash);
+    }
+}
+
 /// Describes the width and color of paths. The color can either be solid or provided by a callback. For more information, see [`ColorMode`]
 ///
 /// The default stroke is the same as [`Stroke::NONE`].
@@ -120,6 +288,14 @@ pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
     pub kind: StrokeKind,
+
+    /// Anti-alias the path (both stroke and fill) with feathering?
+    ///
+    /// Turn this off for crisp hairlines along pixel boundaries.
+    ///
+    /// If `None`, [`TessellationOptions::feathering`](`crate::tessellator::TessellationOptions::feathering`) will be used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub feathering: Option<bool>,
 }
 
 impl Default for PathStroke {
@@ -135,6 +311,7 @@ impl PathStroke {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
         kind: StrokeKind::Middle,
+        feathering: None,
     };
 
     #[inline]
@@ -143,6 +320,7 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
             kind: StrokeKind::Middle,
+            feathering: None,
         }
     }
 
@@ -158,6 +336,7 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
             kind: StrokeKind::Middle,
+            feathering: None,
         }
     }
 
@@ -193,6 +372,17 @@ impl PathStroke {
         }
     }
 
+    /// Anti-alias the path with feathering?
+    ///
+    /// If `None`, [`TessellationOptions::feathering`](`crate::tessellator::TessellationOptions::feathering`) will be used.
+    #[inline]
+    pub fn with_feathering(self, feathering: bool) -> Self {
+        Self {
+            feathering: Some(feathering),
+            ..self
+        }
+    }
+
     /// True if width is zero or color is solid and transparent
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -220,6 +410,7 @@ impl From<Stroke> for PathStroke {
                 width: value.width,
                 color: ColorMode::Solid(value.color),
                 kind: StrokeKind::Middle,
+                feathering: None,
             }
         }
     }
@@ -242,3 +433,86 @@ fn test_is_nearest_integer_odd() {
     assert!(is_nearest_integer_odd(3.0));
     assert!(is_nearest_integer_odd(3.4));
 }
+
+#[test]
+fn test_line_pattern_open_line() {
+    use emath::pos2;
+
+    let mut dashes = vec![];
+    LinePattern::dashed(4.0, 2.0).split_line(
+        &[pos2(0.0, 0.0), pos2(5.0, 0.0), pos2(5.0, 10.0)],
+        false,
+        &mut dashes,
+    );
+    assert_eq!(
+        dashes,
+        vec![
+            vec![pos2(0.0, 0.0), pos2(4.0, 0.0)],
+            vec![pos2(5.0, 1.0), pos2(5.0, 5.0)],
+            vec![pos2(5.0, 7.0), pos2(5.0, 10.0)],
+        ]
+    );
+
+    dashes.clear();
+    LinePattern::dashed(4.0, 2.0).with_offset(5.0).split_line(
+        &[pos2(0.0, 0.0), pos2(10.0, 0.0)],
+        false,
+        &mut dashes,
+    );
+    assert_eq!(
+        dashes,
+        vec![
+            vec![pos2(1.0, 0.0), pos2(5.0, 0.0)],
+            vec![pos2(7.0, 0.0), pos2(10.0, 0.0)],
+        ]
+    );
+}
+
+#[test]
+fn test_line_pattern_closed_line() {
+    use emath::pos2;
+
+    let square = [
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(10.0, 10.0),
+        pos2(0.0, 10.0),
+    ];
+
+    // The dash crossing the corners follows the outline:
+    let mut dashes = vec![];
+    LinePattern::dashed(6.0, 4.0)
+        .with_offset(-2.0)
+        .split_line(&square, true, &mut dashes);
+    assert_eq!(dashes.len(), 4);
+    assert_eq!(
+        dashes[0],
+        vec![pos2(2.0, 0.0), pos2(8.0, 0.0)],
+        "The first dash starts where the pattern does"
+    );
+    assert_eq!(
+        dashes[3],
+        vec![pos2(0.0, 8.0), pos2(0.0, 2.0)],
+        "The last dash ends before the seam"
+    );
+
+    // Marching ants: shifting the offset by a whole period changes nothing.
+    let mut shifted = vec![];
+    LinePattern::dashed(6.0, 4.0)
+        .with_offset(8.0)
+        .split_line(&square, true, &mut shifted);
+    assert_eq!(shifted, dashes);
+
+    // A dash crossing the seam is not split in two:
+    dashes.clear();
+    LinePattern::dashed(6.0, 4.0)
+        .with_offset(3.0)
+        .split_line(&square, true, &mut dashes);
+    assert_eq!(dashes.len(), 4);
+    assert!(
+        dashes
+            .iter()
+            .any(|dash| dash.contains(&pos2(0.0, 0.0)) && 2 < dash.len()),
+        "{dashes:?}"
+    );
+}
@@ -8,10 +8,11 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
-    StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
-    stroke::PathStroke, texture_atlas::PreparedDisc,
+    CircleShape, ClipShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32,
+    CornerSmoothing, CubicBezierShape, DashedShape, EllipseShape, Gradient, LinePattern, Mesh,
+    PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke, StrokeKind, TextShape,
+    TextureId, Vertex, WHITE_UV, color::ColorMode, emath, stroke::PathStroke,
+    texture_atlas::PreparedDisc,
 };
 
 // ----------------------------------------------------------------------------
@@ -530,15 +531,46 @@ impl Path {
     ) {
         fill_closed_path_with_uv(feathering, &mut self.0, color, texture_id, uv_from_pos, out);
     }
+
+    /// Like [`Self::fill`] but with a gradient, stretched over `rect`.
+    ///
+    /// The path must be convex.
+    /// Gradients that don't change linearly (several stops, corners, radial)
+    /// are approximated by cutting the area into smaller pieces.
+    pub fn fill_with_gradient(
+        &mut self,
+        feathering: f32,
+        gradient: &Gradient,
+        rect: Rect,
+        out: &mut Mesh,
+    ) {
+        fill_closed_path_with_gradient(feathering, &mut self.0, gradient, rect, out);
+    }
 }
 
 pub mod path {
     //! Helpers for constructing paths
     use crate::CornerRadiusF32;
-    use emath::{Pos2, Rect, pos2};
+    use emath::{Pos2, Rect, Vec2, pos2, vec2};
 
     /// overwrites existing points
     pub fn rounded_rectangle(path: &mut Vec<Pos2>, rect: Rect, cr: CornerRadiusF32) {
+        smooth_rounded_rectangle(path, rect, cr, 0.0);
+    }
+
+    /// Like [`rounded_rectangle`], but with squircle-like corners.
+    ///
+    /// `smoothing` goes from `0.0` (circular arcs, same as [`rounded_rectangle`])
+    /// to `1.0`, where each corner bends over twice its radius.
+    /// See [`crate::CornerSmoothing`].
+    ///
+    /// overwrites existing points
+    pub fn smooth_rounded_rectangle(
+        path: &mut Vec<Pos2>,
+        rect: Rect,
+        cr: CornerRadiusF32,
+        smoothing: f32,
+    ) {
         path.clear();
 
         let min = rect.min;
@@ -553,31 +585,44 @@ pub mod path {
             path.push(pos2(max.x, max.y)); // right bottom
             path.push(pos2(min.x, max.y)); // left bottom
         } else {
+            // How far along the edges each corner reaches.
+            // If there isn't enough room, the corners get less smooth.
+            let smoothing = if smoothing > 0.0 {
+                smoothing.min(1.0)
+            } else {
+                0.0 // also handles NaN
+            };
+            let len = (cr * (1.0 + smoothing)).at_most(0.5 * rect.size().min_elem());
+
             // We need to avoid duplicated vertices, because that leads to visual artifacts later.
             // Duplicated vertices can happen when one side is all rounding, with no straight edge between.
             let eps = f32::EPSILON * rect.size().max_elem();
 
-            add_circle_quadrant(path, pos2(max.x - cr.se, max.y - cr.se), cr.se, 0.0); // south east
+            let center = pos2(max.x - len.se, max.y - len.se);
+            add_smooth_corner_quadrant(path, center, cr.se, len.se, 0.0); // south east
 
-            if rect.width() <= cr.se + cr.sw + eps {
+            if rect.width() <= len.se + len.sw + eps {
                 path.pop(); // avoid duplicated vertex
             }
 
-            add_circle_quadrant(path, pos2(min.x + cr.sw, max.y - cr.sw), cr.sw, 1.0); // south west
+            let center = pos2(min.x + len.sw, max.y - len.sw);
+            add_smooth_corner_quadrant(path, center, cr.sw, len.sw, 1.0); // south west
 
-            if rect.height() <= cr.sw + cr.nw + eps {
+            if rect.height() <= len.sw + len.nw + eps {
                 path.pop(); // avoid duplicated vertex
             }
 
-            add_circle_quadrant(path, pos2(min.x + cr.nw, min.y + cr.nw), cr.nw, 2.0); // north west
+            let center = pos2(min.x + len.nw, min.y + len.nw);
+            add_smooth_corner_quadrant(path, center, cr.nw, len.nw, 2.0); // north west
 
-            if rect.width() <= cr.nw + cr.ne + eps {
+            if rect.width() <= len.nw + len.ne + eps {
                 path.pop(); // avoid duplicated vertex
             }
 
-            add_circle_quadrant(path, pos2(max.x - cr.ne, min.y + cr.ne), cr.ne, 3.0); // north east
+            let center = pos2(max.x - len.ne, min.y + len.ne);
+            add_smooth_corner_quadrant(path, center, cr.ne, len.ne, 3.0); // north east
 
-            if rect.height() <= cr.ne + cr.se + eps {
+            if rect.height() <= len.ne + len.se + eps {
                 path.pop(); // avoid duplicated vertex
             }
         }
@@ -602,33 +647,71 @@ pub mod path {
     //   - quadrant 3: right top
     // * angle 4 * TAU / 4 = right
     pub fn add_circle_quadrant(path: &mut Vec<Pos2>, center: Pos2, radius: f32, quadrant: f32) {
+        if radius <= 0.0 {
+            path.push(center);
+        } else {
+            let quadrant_vertices = circle_quadrant_vertices(radius, quadrant);
+            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+        }
+    }
+
+    /// Add one quadrant of a squircle-like corner with the given `radius`,
+    /// that bends over `length` along each edge.
+    ///
+    /// `center` is `length` away from both edges,
+    /// and the quadrants are the same as in [`add_circle_quadrant`].
+    /// If `length <= radius`, this is the same as [`add_circle_quadrant`].
+    pub fn add_smooth_corner_quadrant(
+        path: &mut Vec<Pos2>,
+        center: Pos2,
+        radius: f32,
+        length: f32,
+        quadrant: f32,
+    ) {
+        if radius <= 0.0 || length <= radius {
+            add_circle_quadrant(path, center, radius, quadrant);
+            return;
+        }
+
+        // We use a superellipse |x|^n + |y|^n = 1 with n > 2,
+        // which has zero curvature where it meets the edges, so there is no sudden change in curvature.
+        // We pick n so that the middle of the corner is cut in as much as a circular arc of `radius`,
+        // which makes the corner look about as round as it would without smoothing.
+        let cut = (1.0 - std::f32::consts::FRAC_1_SQRT_2) * radius / length;
+        let power = -2.0 * (1.0 - cut).log2(); // = 2/n
+        let superellipse = |t: f32| t.signum() * t.abs().powf(power);
+
+        // Same number of vertices as for the circular arc:
+        let quadrant_vertices = circle_quadrant_vertices(radius, quadrant);
+        path.extend(
+            quadrant_vertices
+                .iter()
+                .map(|&n| center + length * vec2(superellipse(n.x), superellipse(n.y))),
+        );
+    }
+
+    /// The unit circle vertices of one quadrant, with a resolution fitting the radius.
+    fn circle_quadrant_vertices(radius: f32, quadrant: f32) -> &'static [Vec2] {
         use super::precomputed_vertices::{CIRCLE_8, CIRCLE_16, CIRCLE_32, CIRCLE_64, CIRCLE_128};
 
         // These cutoffs are based on a high-dpi display. TODO(emilk): use pixels_per_point here?
         // same cutoffs as in add_circle
 
-        if radius <= 0.0 {
-            path.push(center);
-        } else if radius <= 2.0 {
+        if radius <= 2.0 {
             let offset = quadrant as usize * 2;
-            let quadrant_vertices = &CIRCLE_8[offset..=offset + 2];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_8[offset..=offset + 2]
         } else if radius <= 5.0 {
             let offset = quadrant as usize * 4;
-            let quadrant_vertices = &CIRCLE_16[offset..=offset + 4];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_16[offset..=offset + 4]
         } else if radius < 18.0 {
             let offset = quadrant as usize * 8;
-            let quadrant_vertices = &CIRCLE_32[offset..=offset + 8];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_32[offset..=offset + 8]
         } else if radius < 50.0 {
             let offset = quadrant as usize * 16;
-            let quadrant_vertices = &CIRCLE_64[offset..=offset + 16];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_64[offset..=offset + 16]
         } else {
             let offset = quadrant as usize * 32;
-            let quadrant_vertices = &CIRCLE_128[offset..=offset + 32];
-            path.extend(quadrant_vertices.iter().map(|&n| center + radius * n));
+            &CIRCLE_128[offset..=offset + 32]
         }
     }
 
@@ -663,6 +746,9 @@ pub struct TessellationOptions {
     ///
     /// This setting does not affect text.
     ///
+    /// You can override this per shape with [`crate::RectShape::feathering`]
+    /// and [`crate::PathStroke::feathering`].
+    ///
     /// Default: `true`.
     pub feathering: bool,
 
@@ -697,6 +783,13 @@ pub struct TessellationOptions {
     /// You can override this with [`crate::RectShape::round_to_pixels`].
     pub round_rects_to_pixels: bool,
 
+    /// How smooth the rounded corners of rectangles are,
+    /// from `0.0` (circular arcs, the default) to `1.0` (squircle-like).
+    ///
+    /// You can override this with [`crate::RectShape::corner_smoothing`].
+    /// See [`crate::CornerSmoothing`].
+    pub corner_smoothing: f32,
+
     /// Output the clip rectangles to be painted.
     pub debug_paint_clip_rects: bool,
 
@@ -732,6 +825,7 @@ impl Default for TessellationOptions {
             round_text_to_pixels: true,
             round_line_segments_to_pixels: true,
             round_rects_to_pixels: true,
+            corner_smoothing: 0.0,
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
@@ -903,6 +997,172 @@ fn fill_closed_path_with_uv(
     }
 }
 
+/// Like [`fill_closed_path`] but with a gradient, stretched over `rect`.
+///
+/// The area is cut into pieces along [`Gradient::cuts`],
+/// and each piece gets vertex colors from [`Gradient::color_at`].
+fn fill_closed_path_with_gradient(
+    feathering: f32,
+    path: &mut [PathPoint],
+    gradient: &Gradient,
+    rect: Rect,
+    out: &mut Mesh,
+) {
+    if gradient.is_transparent() {
+        return;
+    }
+
+    let n = path.len();
+    if n < 3 {
+        return;
+    }
+
+    if cw_signed_area(path) < 0.0 {
+        // Wrong winding order - fix:
+        path.reverse();
+        for point in &mut *path {
+            point.normal = -point.normal;
+        }
+    }
+
+    let cuts = gradient.cuts(rect);
+
+    // The area within the feathering, with extra points where the edges cross the cuts,
+    // so that the pieces and the feathering share the same vertices.
+    let inner = |i: usize| path[i % n].pos - 0.5 * feathering * path[i % n].normal;
+    let mut polygon = Vec::with_capacity(2 * n);
+    let mut corner_indices = Vec::with_capacity(n);
+    let mut crossings = vec![];
+    for i in 0..n {
+        let (a, b) = (inner(i), inner(i + 1));
+        corner_indices.push(polygon.len());
+        polygon.push(a);
+
+        crossings.clear();
+        for (dir, values) in &cuts {
+            let (da, db) = (a.to_vec2().dot(*dir), b.to_vec2().dot(*dir));
+            for &value in values {
+                let (sa, sb) = (da - value, db - value);
+                if (sa < -CUT_EPSILON && CUT_EPSILON < sb)
+                    || (CUT_EPSILON < sa && sb < -CUT_EPSILON)
+                {
+                    crossings.push((sa / (sa - sb), cut_point(a, b, sa, sb)));
+                }
+            }
+        }
+        crossings.sort_by(|x, y| x.0.total_cmp(&y.0));
+        polygon.extend(crossings.iter().map(|(_, point)| *point));
+    }
+
+    // The fill, one convex piece at a time:
+    let [(dir_a, cuts_a), (dir_b, cuts_b)] = &cuts;
+    let mut pieces = vec![];
+    let mut strips = vec![];
+    split_convex_along(polygon.clone(), *dir_a, cuts_a, &mut strips);
+    for strip in strips {
+        split_convex_along(strip, *dir_b, cuts_b, &mut pieces);
+    }
+    for piece in &pieces {
+        if piece.len() < 3 {
+            continue;
+        }
+        let idx = out.vertices.len() as u32;
+        out.reserve_vertices(piece.len());
+        out.reserve_triangles(piece.len() - 2);
+        for &pos in piece {
+            out.colored_vertex(pos, gradient.color_at(rect, pos));
+        }
+        for i in 2..piece.len() as u32 {
+            out.add_triangle(idx, idx + i - 1, idx + i);
+        }
+    }
+
+    if 0.0 < feathering {
+        // The feathering, from the inner points to transparent outer corners:
+        let idx_inner = out.vertices.len() as u32;
+        for &pos in &polygon {
+            out.colored_vertex(pos, gradient.color_at(rect, pos));
+        }
+        let idx_outer = out.vertices.len() as u32;
+        for point in &*path {
+            out.colored_vertex(
+                point.pos + 0.5 * feathering * point.normal,
+                Color32::TRANSPARENT,
+            );
+        }
+
+        let num_inner = polygon.len() as u32;
+        for i0 in 0..n {
+            let i1 = (i0 + 1) % n;
+            let (start, end) = (corner_indices[i0] as u32, corner_indices[i1] as u32);
+            let end = if end <= start { end + num_inner } else { end };
+            let outer0 = idx_outer + i0 as u32;
+            let outer1 = idx_outer + i1 as u32;
+            for k in start..end {
+                out.add_triangle(
+                    idx_inner + k % num_inner,
+                    idx_inner + (k + 1) % num_inner,
+                    outer1,
+                );
+            }
+            out.add_triangle(idx_inner + start, outer1, outer0);
+        }
+    }
+}
+
+/// Points closer than this to a cut are considered to lie on it.
+const CUT_EPSILON: f32 = 1e-3;
+
+/// Where the line from `a` to `b` crosses a cut,
+/// given their signed distances `sa` and `sb` to it.
+///
+/// Gives the same result when `a` and `b` are swapped,
+/// so that neighboring pieces share the exact same vertices.
+fn cut_point(a: Pos2, b: Pos2, sa: f32, sb: f32) -> Pos2 {
+    let (a, b, sa, sb) = if (a.x, a.y) <= (b.x, b.y) {
+        (a, b, sa, sb)
+    } else {
+        (b, a, sb, sa)
+    };
+    a + (b - a) * (sa / (sa - sb))
+}
+
+/// Cut the convex `polygon` where `dir.dot(pos)` equals each of the (increasing) `values`,
+/// and push the pieces to `out`.
+fn split_convex_along(mut polygon: Vec<Pos2>, dir: Vec2, values: &[f32], out: &mut Vec<Vec<Pos2>>) {
+    for &value in values {
+        if polygon.len() < 3 {
+            return;
+        }
+        let side = |pos: Pos2| pos.to_vec2().dot(dir) - value;
+
+        let mut before = vec![];
+        let mut after = vec![];
+        for (i, &a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            let (sa, sb) = (side(a), side(b));
+            if sa <= CUT_EPSILON {
+                before.push(a);
+            }
+            if -CUT_EPSILON <= sa {
+                after.push(a);
+            }
+            if (sa < -CUT_EPSILON && CUT_EPSILON < sb) || (CUT_EPSILON < sa && sb < -CUT_EPSILON) {
+                let point = cut_point(a, b, sa, sb);
+                before.push(point);
+                after.push(point);
+            }
+        }
+        if 3 <= before.len() {
+            out.push(before);
+        }
+        polygon = after;
+    }
+    if 3 <= polygon.len() {
+        out.push(polygon);
+    }
+}
+
 /// Tessellate the given path as a stroke with thickness.
 fn stroke_path(
     feathering: f32,
@@ -1289,6 +1549,86 @@ fn stroke_and_fill_path(
     }
 }
 
+/// Modify `rect` and `corner_radius` so that they represent the OUTER border of a stroke.
+fn outer_border(
+    rect: &mut Rect,
+    corner_radius: &mut CornerRadiusF32,
+    stroke_kind: StrokeKind,
+    stroke_width: f32,
+) {
+    let original_cr = *corner_radius;
+
+    match stroke_kind {
+        StrokeKind::Inside => {}
+        StrokeKind::Middle => {
+            *rect = rect.expand(stroke_width / 2.0);
+            *corner_radius += stroke_width / 2.0;
+        }
+        StrokeKind::Outside => {
+            *rect = rect.expand(stroke_width);
+            *corner_radius += stroke_width;
+        }
+    }
+
+    // A small corner_radius is incompatible with a wide stroke,
+    // because the small bend will be extruded inwards and cross itself.
+    // There are two ways to solve this (wile maintaining constant stroke width):
+    // either we increase the corner_radius, or we set it to zero.
+    // We choose the former: if the user asks for _any_ corner_radius, they should get it.
+
+    let min_inside_cr = 0.1; // Large enough to avoid numerical issues
+    let min_outside_cr = stroke_width + min_inside_cr;
+
+    let extra_cr_tweak = 0.4; // Otherwise is doesn't _feels_  enough.
+
+    if original_cr.nw == 0.0 {
+        corner_radius.nw = 0.0;
+    } else {
+        corner_radius.nw += extra_cr_tweak;
+        corner_radius.nw = corner_radius.nw.at_least(min_outside_cr);
+    }
+    if original_cr.ne == 0.0 {
+        corner_radius.ne = 0.0;
+    } else {
+        corner_radius.ne += extra_cr_tweak;
+        corner_radius.ne = corner_radius.ne.at_least(min_outside_cr);
+    }
+    if original_cr.sw == 0.0 {
+        corner_radius.sw = 0.0;
+    } else {
+        corner_radius.sw += extra_cr_tweak;
+        corner_radius.sw = corner_radius.sw.at_least(min_outside_cr);
+    }
+    if original_cr.se == 0.0 {
+        corner_radius.se = 0.0;
+    } else {
+        corner_radius.se += extra_cr_tweak;
+        corner_radius.se = corner_radius.se.at_least(min_outside_cr);
+    }
+}
+
+/// Move both ends of `line` up to `amount` towards each other along its first and last segment.
+///
+/// The ends stay apart, and on their segments.
+fn pull_in_ends(line: &mut [Pos2], amount: f32) {
+    let n = line.len();
+    if n < 2 {
+        return;
+    }
+    let max_fraction = if n == 2 { 0.25 } else { 0.5 };
+    let pull = |end: Pos2, next: Pos2| {
+        let length = end.distance(next);
+        if length <= 0.0 {
+            end
+        } else {
+            end + (next - end) * (amount.min(max_fraction * length) / length)
+        }
+    };
+    let (first, last) = (pull(line[0], line[1]), pull(line[n - 1], line[n - 2]));
+    line[0] = first;
+    line[n - 1] = last;
+}
+
 fn mul_color(color: Color32, factor: f32) -> Color32 {
     // The fast gamma-space multiply also happens to be perceptually better.
     // Win-win!
@@ -1368,6 +1708,53 @@ impl Tessellator {
             return; // skip empty clip rectangles
         }
 
+        let shape = match shape {
+            Shape::Clip(ClipShape { clip, shape }) => match *shape {
+                Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        let shape = ClipShape::new(clip.clone(), shape).into();
+                        self.tessellate_clipped_shape(
+                            ClippedShape { clip_rect, shape },
+                            out_primitives,
+                        );
+                    }
+                    return;
+                }
+                Shape::Clip(inner) => {
+                    let clip = clip.intersect(&inner.clip);
+                    let shape = ClipShape::new(clip, *inner.shape).into();
+                    self.tessellate_clipped_shape(
+                        ClippedShape { clip_rect, shape },
+                        out_primitives,
+                    );
+                    return;
+                }
+                Shape::Callback(callback) => Shape::Callback(callback),
+                shape => Shape::Clip(ClipShape {
+                    clip,
+                    shape: Box::new(shape),
+                }),
+            },
+            Shape::Dashed(DashedShape { pattern, shape }) => match *shape {
+                Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        let shape = DashedShape::new(pattern, shape).into();
+                        self.tessellate_clipped_shape(
+                            ClippedShape { clip_rect, shape },
+                            out_primitives,
+                        );
+                    }
+                    return;
+                }
+                Shape::Callback(callback) => Shape::Callback(callback),
+                shape => Shape::Dashed(DashedShape {
+                    pattern,
+                    shape: Box::new(shape),
+                }),
+            },
+            shape => shape,
+        };
+
         if let Shape::Vec(shapes) = shape {
             for shape in shapes {
                 self.tessellate_clipped_shape(ClippedShape { clip_rect, shape }, out_primitives);
@@ -1476,6 +1863,20 @@ impl Tessellator {
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
+            Shape::Clip(ClipShape { clip, shape }) => {
+                if self.options.coarse_tessellation_culling
+                    && !self.clip_rect.intersects(clip.bounding_rect())
+                {
+                    return;
+                }
+                let mut mesh = Mesh::with_texture(out.texture_id);
+                self.tessellate_shape(*shape, &mut mesh);
+                clip.clip_mesh(&mut mesh, self.feathering);
+                out.append(mesh);
+            }
+            Shape::Dashed(dashed_shape) => {
+                self.tessellate_dashed(dashed_shape, out);
+            }
         }
     }
 
@@ -1729,6 +2130,7 @@ impl Tessellator {
             fill,
             stroke,
         } = path_shape;
+        let feathering = self.feathering_for(stroke.feathering);
 
         self.scratchpad_path.clear();
 
@@ -1736,7 +2138,7 @@ impl Tessellator {
             self.scratchpad_path.add_line_loop(points);
 
             self.scratchpad_path
-                .fill_and_stroke(self.feathering, *fill, stroke, out);
+                .fill_and_stroke(feathering, *fill, stroke, out);
         } else {
             debug_assert_eq!(
                 *fill,
@@ -1747,10 +2149,26 @@ impl Tessellator {
             self.scratchpad_path.add_open_points(points);
 
             self.scratchpad_path
-                .stroke(self.feathering, PathType::Open, stroke, out);
+                .stroke(feathering, PathType::Open, stroke, out);
+        }
+    }
+
+    /// The feathering (in points) for a shape that may turn it on or off,
+    /// see e.g. [`PathStroke::feathering`].
+    fn feathering_for(&self, feathering: Option<bool>) -> f32 {
+        match feathering {
+            None => self.feathering,
+            Some(true) => self.options.feathering_size_in_pixels / self.pixels_per_point,
+            Some(false) => 0.0,
         }
     }
 
+    /// The corner smoothing for a shape that may override it,
+    /// see [`RectShape::corner_smoothing`].
+    fn corner_smoothing_for(&self, corner_smoothing: Option<CornerSmoothing>) -> f32 {
+        corner_smoothing.map_or(self.options.corner_smoothing, CornerSmoothing::get)
+    }
+
     /// Tessellate a single [`Rect`] into a [`Mesh`].
     ///
     /// * `rect`: the rectangle to tessellate.
@@ -1762,21 +2180,31 @@ impl Tessellator {
             return;
         }
 
-        let brush = rect_shape.brush.as_ref();
+        let mut fill_gradient = rect_shape.fill_gradient();
+        let brush = rect_shape
+            .brush
+            .as_ref()
+            .filter(|brush| brush.fill_gradient.is_none());
         let RectShape {
             mut rect,
             corner_radius,
+            corner_smoothing,
             mut fill,
             mut stroke,
             mut stroke_kind,
             round_to_pixels,
+            feathering,
             mut blur_width,
             brush: _, // brush is extracted on its own, because it is not Copy
         } = *rect_shape;
 
         let mut corner_radius = CornerRadiusF32::from(corner_radius);
+        let corner_smoothing = self.corner_smoothing_for(corner_smoothing);
         let round_to_pixels = round_to_pixels.unwrap_or(self.options.round_rects_to_pixels);
 
+        let old_feathering = self.feathering;
+        self.feathering = self.feathering_for(feathering);
+
         if stroke.width == 0.0 {
             stroke.color = Color32::TRANSPARENT;
         }
@@ -1802,27 +2230,32 @@ impl Tessellator {
                 // We blend so that if the stroke is semi-transparent,
                 // the fill still shines through.
                 fill = stroke.color;
+                fill_gradient = None;
 
                 stroke = Stroke::NONE;
             }
         }
 
-        if stroke.is_empty() && out.texture_id == TextureId::default() {
+        if stroke.is_empty() && out.texture_id == TextureId::default() && fill_gradient.is_none() {
             // Approximate thin rectangles with line segments.
             // This is important so that thin rectangles look good.
             if rect.width() <= 2.0 * self.feathering {
-                return self.tessellate_line_segment(
+                self.tessellate_line_segment(
                     [rect.center_top(), rect.center_bottom()],
                     (rect.width(), fill),
                     out,
                 );
+                self.feathering = old_feathering; // restore
+                return;
             }
             if rect.height() <= 2.0 * self.feathering {
-                return self.tessellate_line_segment(
+                self.tessellate_line_segment(
                     [rect.left_center(), rect.right_center()],
                     (rect.height(), fill),
                     out,
                 );
+                self.feathering = old_feathering; // restore
+                return;
             }
         }
 
@@ -1861,8 +2294,6 @@ impl Tessellator {
             }
         }
 
-        let old_feathering = self.feathering;
-
         if self.feathering < blur_width {
             // We accomplish the blur by using a larger-than-normal feathering.
             // Feathering is usually used to make the edges of a shape softer for anti-aliasing.
@@ -1878,67 +2309,23 @@ impl Tessellator {
             self.feathering = self.feathering.max(blur_width);
         }
 
-        {
-            // Modify `rect` so that it represents the OUTER border
-            // We do this because `path::rounded_rectangle` uses the
-            // corner radius to pick the fidelity/resolution of the corner.
-
-            let original_cr = corner_radius;
-
-            match stroke_kind {
-                StrokeKind::Inside => {}
-                StrokeKind::Middle => {
-                    rect = rect.expand(stroke.width / 2.0);
-                    corner_radius += stroke.width / 2.0;
-                }
-                StrokeKind::Outside => {
-                    rect = rect.expand(stroke.width);
-                    corner_radius += stroke.width;
-                }
-            }
-
-            stroke_kind = StrokeKind::Inside;
-
-            // A small corner_radius is incompatible with a wide stroke,
-            // because the small bend will be extruded inwards and cross itself.
-            // There are two ways to solve this (wile maintaining constant stroke width):
-            // either we increase the corner_radius, or we set it to zero.
-            // We choose the former: if the user asks for _any_ corner_radius, they should get it.
-
-            let min_inside_cr = 0.1; // Large enough to avoid numerical issues
-            let min_outside_cr = stroke.width + min_inside_cr;
+        // The gradient is stretched over the rectangle before the stroke is accounted for:
+        let gradient_rect = rect;
 
-            let extra_cr_tweak = 0.4; // Otherwise is doesn't _feels_  enough.
-
-            if original_cr.nw == 0.0 {
-                corner_radius.nw = 0.0;
-            } else {
-                corner_radius.nw += extra_cr_tweak;
-                corner_radius.nw = corner_radius.nw.at_least(min_outside_cr);
-            }
-            if original_cr.ne == 0.0 {
-                corner_radius.ne = 0.0;
-            } else {
-                corner_radius.ne += extra_cr_tweak;
-                corner_radius.ne = corner_radius.ne.at_least(min_outside_cr);
-            }
-            if original_cr.sw == 0.0 {
-                corner_radius.sw = 0.0;
-            } else {
-                corner_radius.sw += extra_cr_tweak;
-                corner_radius.sw = corner_radius.sw.at_least(min_outside_cr);
-            }
-            if original_cr.se == 0.0 {
-                corner_radius.se = 0.0;
-            } else {
-                corner_radius.se += extra_cr_tweak;
-                corner_radius.se = corner_radius.se.at_least(min_outside_cr);
-            }
-        }
+        // Modify `rect` so that it represents the OUTER border
+        // We do this because `path::rounded_rectangle` uses the
+        // corner radius to pick the fidelity/resolution of the corner.
+        outer_border(&mut rect, &mut corner_radius, stroke_kind, stroke.width);
+        stroke_kind = StrokeKind::Inside;
 
         let path = &mut self.scratchpad_path;
         path.clear();
-        path::rounded_rectangle(&mut self.scratchpad_points, rect, corner_radius);
+        path::smooth_rounded_rectangle(
+            &mut self.scratchpad_points,
+            rect,
+            corner_radius,
+            corner_smoothing,
+        );
         path.add_line_loop(&self.scratchpad_points);
 
         let path_stroke = PathStroke::from(stroke).with_kind(stroke_kind);
@@ -1956,6 +2343,7 @@ impl Tessellator {
                 let crate::Brush {
                     fill_texture_id,
                     uv,
+                    fill_gradient: _,
                 } = **brush;
                 let uv_from_pos = |p: Pos2| {
                     pos2(
@@ -1966,6 +2354,12 @@ impl Tessellator {
                 path.fill_with_uv(self.feathering, fill, fill_texture_id, uv_from_pos, out);
             }
 
+            if !stroke.is_empty() {
+                path.stroke_closed(self.feathering, &path_stroke, out);
+            }
+        } else if let Some(fill_gradient) = fill_gradient {
+            path.fill_with_gradient(self.feathering, fill_gradient, gradient_rect, out);
+
             if !stroke.is_empty() {
                 path.stroke_closed(self.feathering, &path_stroke, out);
             }
@@ -1977,6 +2371,207 @@ impl Tessellator {
         self.feathering = old_feathering; // restore
     }
 
+    /// Tessellate a [`DashedShape`] into a [`Mesh`].
+    ///
+    /// The fill is tessellated as usual, and the stroke is split into dashes
+    /// along the centerline of the stroke.
+    ///
+    /// * `dashed_shape`: the shape to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_dashed(&mut self, dashed_shape: DashedShape, out: &mut Mesh) {
+        let DashedShape { pattern, shape } = dashed_shape;
+
+        if pattern.gap_length <= 0.0 {
+            // A solid stroke:
+            self.tessellate_shape(*shape, out);
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !shape.visual_bounding_rect().intersects(self.clip_rect)
+        {
+            return;
+        }
+
+        match *shape {
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.tessellate_dashed(DashedShape::new(pattern, shape), out);
+                }
+            }
+            Shape::Rect(mut rect_shape) => {
+                let stroke = std::mem::replace(&mut rect_shape.stroke, Stroke::NONE);
+                self.tessellate_rect(&rect_shape, out);
+                if stroke.is_empty() {
+                    return;
+                }
+
+                let outline = self.rect_stroke_centerline(&rect_shape, stroke);
+                let feathering = self.feathering_for(rect_shape.feathering);
+                self.tessellate_dashes(&outline, true, pattern, feathering, stroke.into(), out);
+            }
+            Shape::Circle(mut circle_shape) => {
+                let stroke = std::mem::replace(&mut circle_shape.stroke, Stroke::NONE);
+                if circle_shape.fill != Color32::TRANSPARENT {
+                    self.tessellate_circle(circle_shape, out);
+                }
+                if stroke.is_empty() || circle_shape.radius <= 0.0 {
+                    return;
+                }
+
+                // The stroke of a circle is on the outside:
+                let CircleShape { center, radius, .. } = circle_shape;
+                self.scratchpad_path.clear();
+                self.scratchpad_path
+                    .add_circle(center, radius + stroke.width / 2.0);
+                let outline: Vec<Pos2> = self.scratchpad_path.0.iter().map(|p| p.pos).collect();
+                let feathering = self.feathering;
+                self.tessellate_dashes(&outline, true, pattern, feathering, stroke.into(), out);
+            }
+            Shape::Path(mut path_shape) => {
+                if path_shape.points.len() < 2 {
+                    return;
+                }
+
+                let no_stroke = PathStroke {
+                    feathering: path_shape.stroke.feathering,
+                    ..PathStroke::NONE
+                };
+                let stroke = std::mem::replace(&mut path_shape.stroke, no_stroke);
+                if path_shape.closed {
+                    self.tessellate_path(&path_shape, out);
+                }
+                if stroke.is_empty() {
+                    return;
+                }
+
+                let outline = self.path_stroke_centerline(&path_shape, &stroke);
+                let feathering = self.feathering_for(stroke.feathering);
+                self.tessellate_dashes(
+                    &outline,
+                    path_shape.closed,
+                    pattern,
+                    feathering,
+                    stroke,
+                    out,
+                );
+            }
+            shape => self.tessellate_shape(shape, out),
+        }
+    }
+
+    /// The line through the middle of the stroke of a [`RectShape`],
+    /// taking [`RectShape::stroke_kind`] and pixel rounding into account.
+    fn rect_stroke_centerline(&mut self, rect_shape: &RectShape, stroke: Stroke) -> Vec<Pos2> {
+        let mut rect = rect_shape.rect;
+        rect.min = rect.min.at_least(pos2(-1e7, -1e7));
+        rect.max = rect.max.at_most(pos2(1e7, 1e7));
+
+        let round_to_pixels = rect_shape
+            .round_to_pixels
+            .unwrap_or(self.options.round_rects_to_pixels);
+        if round_to_pixels {
+            // Same as in `tessellate_rect`:
+            match rect_shape.stroke_kind {
+                StrokeKind::Inside | StrokeKind::Outside => {
+                    rect = rect.round_to_pixels(self.pixels_per_point);
+                }
+                StrokeKind::Middle => {
+                    stroke.round_rect_to_pixel(self.pixels_per_point, &mut rect);
+                }
+            }
+        }
+
+        // Same as in `tessellate_rect`, which strokes the inside of the outer border:
+        let mut corner_radius = CornerRadiusF32::from(rect_shape.corner_radius);
+        outer_border(
+            &mut rect,
+            &mut corner_radius,
+            rect_shape.stroke_kind,
+            stroke.width,
+        );
+
+        let smoothing = self.corner_smoothing_for(rect_shape.corner_smoothing);
+        path::smooth_rounded_rectangle(&mut self.scratchpad_points, rect, corner_radius, smoothing);
+
+        let path = &mut self.scratchpad_path;
+        path.clear();
+        path.add_line_loop(&self.scratchpad_points);
+        path.0
+            .iter()
+            .map(|point| point.pos - 0.5 * stroke.width * point.normal)
+            .collect()
+    }
+
+    /// The line through the middle of the stroke of a [`PathShape`],
+    /// taking [`PathStroke::kind`] into account.
+    fn path_stroke_centerline(&mut self, path_shape: &PathShape, stroke: &PathStroke) -> Vec<Pos2> {
+        let offset = match stroke.kind {
+            StrokeKind::Inside => -stroke.width / 2.0,
+            StrokeKind::Middle => return path_shape.points.clone(),
+            StrokeKind::Outside => stroke.width / 2.0,
+        };
+
+        let path = &mut self.scratchpad_path;
+        path.clear();
+        if path_shape.closed {
+            path.add_line_loop(&path_shape.points);
+            if path_shape.fill != Color32::TRANSPARENT && cw_signed_area(&path.0) < 0.0 {
+                // The winding order will be fixed when filling, flipping the normals:
+                for point in &mut path.0 {
+                    point.normal = -point.normal;
+                }
+            }
+        } else {
+            path.add_open_points(&path_shape.points);
+        }
+        path.0
+            .iter()
+            .map(|point| point.pos + offset * point.normal)
+            .collect()
+    }
+
+    /// Stroke the dashes of `pattern` along a line through the middle of the stroke.
+    fn tessellate_dashes(
+        &mut self,
+        outline: &[Pos2],
+        closed: bool,
+        pattern: LinePattern,
+        feathering: f32,
+        stroke: PathStroke,
+        out: &mut Mesh,
+    ) {
+        let stroke = stroke.middle();
+
+        // The anti-aliased line caps stick out `feathering` past the ends of each dash,
+        // which on a bend is away from the outline.
+        // Instead we split the outline into dashes that are that much longer,
+        // and pull in their ends so that the caps end where the longer dashes end.
+        // Lines thinner than the feathering have no caps.
+        let thin_line = stroke.width <= 0.9 * feathering;
+        let cap_length = if !thin_line && feathering < pattern.gap_length {
+            feathering
+        } else {
+            0.0
+        };
+        let pattern = LinePattern {
+            dash_length: pattern.dash_length + cap_length,
+            gap_length: pattern.gap_length - cap_length,
+            offset: pattern.offset + 0.5 * cap_length,
+        };
+
+        let mut dashes = Vec::new();
+        pattern.split_line(outline, closed, &mut dashes);
+        for mut dash in dashes {
+            if 0.0 < cap_length {
+                pull_in_ends(&mut dash, cap_length);
+            }
+            self.scratchpad_path.clear();
+            self.scratchpad_path.add_open_points(&dash);
+            self.scratchpad_path.stroke_open(feathering, &stroke, out);
+        }
+    }
+
     /// Tessellate a single [`TextShape`] into a [`Mesh`].
     /// * `text_shape`: the text to tessellate.
     /// * `out`: triangles are appended to this.
@@ -2170,13 +2765,14 @@ impl Tessellator {
         if points.len() < 2 {
             return;
         }
+        let feathering = self.feathering_for(stroke.feathering);
 
         self.scratchpad_path.clear();
         if closed {
             self.scratchpad_path.add_line_loop(points);
 
             self.scratchpad_path
-                .fill_and_stroke(self.feathering, fill, stroke, out);
+                .fill_and_stroke(feathering, fill, stroke, out);
         } else {
             debug_assert_eq!(
                 fill,
@@ -2187,7 +2783,7 @@ impl Tessellator {
             self.scratchpad_path.add_open_points(points);
 
             self.scratchpad_path
-                .stroke(self.feathering, PathType::Open, stroke, out);
+                .stroke(feathering, PathType::Open, stroke, out);
         }
     }
 }
@@ -2272,6 +2868,10 @@ impl Tessellator {
 
                 Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
 
+                Shape::Clip(clip_shape) => should_parallelize(&clip_shape.shape),
+
+                Shape::Dashed(dashed_shape) => should_parallelize(&dashed_shape.shape),
+
                 Shape::Noop
                 | Shape::Text(_)
                 | Shape::Circle(_)
@@ -2411,3 +3011,197 @@ fn path_bounding_box() {
         );
     }
 }
+
+#[test]
+fn gradient_fill_covers_the_rounded_rect() {
+    use crate::*;
+
+    /// The area covered by the mesh, with the feathering only counting partially.
+    fn coverage(mesh: &Mesh) -> f32 {
+        mesh.indices
+            .chunks(3)
+            .map(|triangle| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+                let area = 0.5
+                    * ((b.pos - a.pos).x * (c.pos - a.pos).y
+                        - (c.pos - a.pos).x * (b.pos - a.pos).y)
+                        .abs();
+                let opacity = [a, b, c]
+                    .map(|vertex| vertex.color.a() as f32 / 255.0)
+                    .iter()
+                    .sum::<f32>()
+                    / 3.0;
+                area * opacity
+            })
+            .sum()
+    }
+
+    let rect = Rect::from_min_size(pos2(10.0, 20.0), vec2(200.0, 100.0));
+    let gradients = [
+        Gradient::horizontal(Color32::RED, Color32::BLUE),
+        Gradient::linear_stops(
+            0.5,
+            [
+                (0.0, Color32::RED),
+                (0.3, Color32::GREEN),
+                (1.0, Color32::BLUE),
+            ],
+        ),
+        Gradient::corners(Color32::RED, Color32::GREEN, Color32::BLUE, Color32::WHITE),
+        Gradient::radial(Color32::WHITE, Color32::BLACK),
+    ];
+
+    for gradient in gradients {
+        for (corner_radius, feathering) in [(0, false), (0, true), (20, true)] {
+            let shape = RectShape::filled(rect, corner_radius, Color32::TRANSPARENT)
+                .with_gradient(gradient.clone())
+                .with_feathering(feathering);
+            let mut mesh = Mesh::default();
+            Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+                .tessellate_rect(&shape, &mut mesh);
+
+            let corner_area = (4.0 - std::f32::consts::PI) * (corner_radius as f32).powi(2);
+            let expected_area = rect.area() - corner_area;
+            assert!(
+                (coverage(&mesh) - expected_area).abs() < 0.005 * expected_area,
+                "{gradient:?} with radius {corner_radius}: area {} vs. {expected_area}",
+                coverage(&mesh)
+            );
+
+            for vertex in &mesh.vertices {
+                if vertex.color != Color32::TRANSPARENT {
+                    assert_eq!(
+                        vertex.color,
+                        gradient.color_at(rect, vertex.pos),
+                        "{gradient:?}"
+                    );
+                    let corner_distance = (vertex.pos - rect.min).length();
+                    assert!(
+                        corner_radius == 0 || 5.0 < corner_distance,
+                        "{gradient:?}: the rounding is filled at {:?}",
+                        vertex.pos
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn dashed_outlines() {
+    use crate::*;
+
+    fn tessellate(shape: impl Into<Shape>) -> Mesh {
+        let mut mesh = Mesh::default();
+        Tessellator::new(1.0, Default::default(), [1024, 1024], vec![])
+            .tessellate_shape(shape.into(), &mut mesh);
+        mesh
+    }
+
+    fn assert_rect_near(a: Rect, b: Rect, tolerance: f32, what: &str) {
+        assert!(
+            a.min.distance(b.min) < tolerance && a.max.distance(b.max) < tolerance,
+            "{what}: {a:?} != {b:?}"
+        );
+    }
+
+    let rect = Rect::from_min_size(pos2(10.0, 20.0), vec2(200.0, 100.0));
+    let stroke = Stroke::new(2.0, Color32::WHITE);
+
+    for stroke_kind in [StrokeKind::Inside, StrokeKind::Middle, StrokeKind::Outside] {
+        let shape = RectShape::new(rect, 12.0, Color32::RED, stroke, stroke_kind);
+
+        assert_eq!(
+            tessellate(shape.clone().dashed(LinePattern::dashed(4.0, 0.0))),
+            tessellate(shape.clone()),
+            "A pattern without gaps is the same as a solid stroke"
+        );
+
+        let solid = tessellate(shape.clone());
+        let dashed = tessellate(shape.clone().dashed(LinePattern::dashed(4.0, 4.0)));
+        assert!(dashed.is_valid());
+        assert_rect_near(
+            solid.calc_bounds(),
+            dashed.calc_bounds(),
+            0.01,
+            &format!("{stroke_kind:?}: the dashes follow the outline"),
+        );
+
+        let num_dashes = |mesh: &Mesh| {
+            mesh.vertices
+                .iter()
+                .filter(|vertex| vertex.color == Color32::WHITE)
+                .count()
+        };
+        assert!(
+            num_dashes(&solid) < num_dashes(&dashed),
+            "{stroke_kind:?}: every dash gets its own vertices"
+        );
+    }
+
+    let circle = CircleShape::stroke(pos2(50.0, 50.0), 20.0, stroke);
+    let dashed = tessellate(circle.dashed(LinePattern::dotted(2.0)));
+    assert!(dashed.is_valid());
+    assert_rect_near(
+        dashed.calc_bounds(),
+        tessellate(circle).calc_bounds(),
+        0.2,
+        "The dots are on the outside of the circle",
+    );
+}
+
+#[test]
+fn smooth_rounded_rectangle() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(200.0, 100.0));
+    let cr = CornerRadiusF32::same(20.0);
+
+    let mut circular = vec![];
+    path::rounded_rectangle(&mut circular, rect, cr);
+    let mut unsmoothed = vec![];
+    path::smooth_rounded_rectangle(&mut unsmoothed, rect, cr, 0.0);
+    assert_eq!(circular, unsmoothed);
+
+    let mut smooth = vec![];
+    path::smooth_rounded_rectangle(&mut smooth, rect, cr, 1.0);
+    assert_eq!(smooth.len(), circular.len(), "Same number of vertices");
+
+    for p in &smooth {
+        assert!(rect.expand(1e-3).contains(*p), "{p:?} is outside {rect:?}");
+    }
+
+    // The corner reaches twice as far along the edges:
+    let top_left_x = |points: &[Pos2]| {
+        points
+            .iter()
+            .filter(|p| p.y == rect.min.y)
+            .map(|p| p.x)
+            .fold(f32::INFINITY, f32::min)
+    };
+    assert_eq!(top_left_x(&circular), 20.0);
+    assert!((top_left_x(&smooth) - 40.0).abs() < 1e-3);
+
+    // …but cuts in about as much at the middle of the corner:
+    let corner_cut = |points: &[Pos2]| {
+        points
+            .iter()
+            .filter(|p| p.x < rect.center().x && p.y < rect.center().y)
+            .map(|p| p.x - rect.min.x + p.y - rect.min.y)
+            .fold(f32::INFINITY, f32::min)
+    };
+    assert!(
+        (corner_cut(&smooth) - corner_cut(&circular)).abs() < 1.0,
+        "{} vs {}",
+        corner_cut(&smooth),
+        corner_cut(&circular)
+    );
+
+    // With too little room, the corners become less smooth:
+    let small = Rect::from_min_size(Pos2::ZERO, vec2(40.0, 40.0));
+    let mut squeezed = vec![];
+    path::smooth_rounded_rectangle(&mut squeezed, small, cr, 1.0);
+    let mut circle = vec![];
+    path::rounded_rectangle(&mut circle, small, cr);
+    assert_eq!(squeezed, circle, "No room to smooth a circle");
+}
@@ -6,7 +6,7 @@ use emath::{GuiRounding as _, Vec2, vec2};
 use crate::{
     TextureAtlas,
     mutex::{Mutex, RwLock},
-    text::FontTweak,
+    text::{FontTweak, Script},
 };
 
 // ----------------------------------------------------------------------------
@@ -79,6 +79,9 @@ pub struct FontImpl {
     ascent: f32,
     pixels_per_point: f32,
     glyph_info_cache: RwLock<ahash::HashMap<char, GlyphInfo>>, // TODO(emilk): standard Mutex
+
+    /// Keyed by glyph and outline width in quarter pixels.
+    glyph_outline_cache: RwLock<ahash::HashMap<(ab_glyph::GlyphId, u32), UvRect>>,
     atlas: Arc<Mutex<TextureAtlas>>,
 }
 
@@ -135,6 +138,7 @@ impl FontImpl {
             ascent: ascent + baseline_offset,
             pixels_per_point,
             glyph_info_cache: Default::default(),
+            glyph_outline_cache: Default::default(),
             atlas,
         }
     }
@@ -319,6 +323,144 @@ impl FontImpl {
             uv_rect,
         }
     }
+
+    /// The glyph grown by `width_in_points` in all directions,
+    /// to be painted behind the glyph as an outline.
+    ///
+    /// See [`crate::text::TextFormat::stroke`].
+    pub(crate) fn glyph_outline(
+        &self,
+        glyph_id: ab_glyph::GlyphId,
+        width_in_points: f32,
+    ) -> UvRect {
+        // Wider outlines are slow to compute, and take up a lot of the font atlas:
+        const MAX_WIDTH_IN_PIXELS: f32 = 16.0;
+        let width_in_pixels = (width_in_points * self.pixels_per_point).min(MAX_WIDTH_IN_PIXELS);
+
+        // Quarter pixels is plenty of precision, and keeps the cache small:
+        let quarter_pixels = (4.0 * width_in_pixels).round() as u32;
+        if glyph_id.0 == 0 || quarter_pixels == 0 {
+            return UvRect::default();
+        }
+
+        let key = (glyph_id, quarter_pixels);
+        if let Some(uv_rect) = self.glyph_outline_cache.read().get(&key) {
+            return *uv_rect;
+        }
+
+        let uv_rect = self.allocate_glyph_outline(glyph_id, quarter_pixels as f32 / 4.0);
+        self.glyph_outline_cache.write().insert(key, uv_rect);
+        uv_rect
+    }
+
+    fn allocate_glyph_outline(&self, glyph_id: ab_glyph::GlyphId, radius_in_pixels: f32) -> UvRect {
+        use ab_glyph::Font as _;
+
+        let glyph = glyph_id.with_scale_and_position(
+            self.scale_in_pixels as f32,
+            ab_glyph::Point { x: 0.0, y: 0.0 },
+        );
+        let Some(glyph) = self.ab_glyph_font.outline_glyph(glyph) else {
+            return UvRect::default();
+        };
+
+        let bb = glyph.px_bounds();
+        let glyph_width = bb.width() as usize;
+        let glyph_height = bb.height() as usize;
+        if glyph_width == 0 || glyph_height == 0 {
+            return UvRect::default();
+        }
+
+        // Leave room for the outline around the glyph:
+        let padding = radius_in_pixels.ceil() as usize;
+        let width = glyph_width + 2 * padding;
+        let height = glyph_height + 2 * padding;
+        if self.atlas.lock().size()[0] < width {
+            return UvRect::default(); // Doesn't fit, so no outline
+        }
+
+        let mut coverage = vec![0.0; width * height];
+        glyph.draw(|x, y, v| {
+            let (x, y) = (x as usize, y as usize);
+            if x < glyph_width && y < glyph_height {
+                coverage[(y + padding) * width + x + padding] = v;
+            }
+        });
+        let coverage = dilate_coverage(&coverage, width, radius_in_pixels);
+
+        let glyph_pos = {
+            let atlas = &mut self.atlas.lock();
+            let text_alpha_from_coverage = atlas.text_alpha_from_coverage;
+            let (glyph_pos, image) = atlas.allocate((width, height));
+            for (i, &v) in coverage.iter().enumerate() {
+                if 0.0 < v {
+                    let px = glyph_pos.0 + i % width;
+                    let py = glyph_pos.1 + i / width;
+                    image[(px, py)] = text_alpha_from_coverage.color_from_coverage(v);
+                }
+            }
+            glyph_pos
+        };
+
+        let offset_in_pixels = vec2(bb.min.x, bb.min.y) - Vec2::splat(padding as f32);
+        UvRect {
+            offset: offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y,
+            size: vec2(width as f32, height as f32) / self.pixels_per_point,
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [(glyph_pos.0 + width) as u16, (glyph_pos.1 + height) as u16],
+        }
+    }
+}
+
+/// Grow the shape in a coverage bitmap (with the given row width) by `radius` pixels,
+/// keeping the edges anti-aliased.
+fn dilate_coverage(coverage: &[f32], width: usize, radius: f32) -> Vec<f32> {
+    let height = coverage.len() / width;
+
+    // A disc with soft edges.
+    // Moving a pixel a whole `radius` keeps all of it, and then it fades out over one pixel:
+    let r = radius.ceil() as isize;
+    let mut disc = vec![];
+    for dy in -r..=r {
+        for dx in -r..=r {
+            let distance = ((dx * dx + dy * dy) as f32).sqrt();
+            let weight = (radius + 1.0 - distance).clamp(0.0, 1.0);
+            if 0.0 < weight {
+                disc.push((dx, dy, weight));
+            }
+        }
+    }
+
+    let mut dilated = vec![0.0; coverage.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let mut value: f32 = 0.0;
+            for &(dx, dy, weight) in &disc {
+                let (sx, sy) = (x as isize + dx, y as isize + dy);
+                if 0 <= sx && sx < width as isize && 0 <= sy && sy < height as isize {
+                    value = value.max(weight * coverage[sy as usize * width + sx as usize]);
+                }
+            }
+            dilated[y * width + x] = value;
+        }
+    }
+    dilated
+}
+
+#[test]
+fn test_dilate_coverage() {
+    // A single pixel in the middle of a 7x7 bitmap:
+    let mut coverage = vec![0.0; 49];
+    coverage[3 * 7 + 3] = 1.0;
+
+    let dilated = dilate_coverage(&coverage, 7, 2.0);
+    let at = |x: usize, y: usize| dilated[y * 7 + x];
+    assert_eq!(at(3, 3), 1.0);
+    assert_eq!(at(5, 3), 1.0, "Two pixels to the right");
+    assert_eq!(at(3, 1), 1.0, "Two pixels up");
+    assert!(0.0 < at(5, 5) && at(5, 5) < 1.0, "Soft in the corner");
+    assert_eq!(at(0, 0), 0.0);
+    assert_eq!(at(6, 3), 0.0, "Three pixels away");
 }
 
 type FontIndex = usize;
@@ -326,8 +468,15 @@ type FontIndex = usize;
 // TODO(emilk): rename?
 /// Wrapper over multiple [`FontImpl`] (e.g. a primary + fallbacks for emojis)
 pub struct Font {
+    /// The fonts of the family, followed by any fonts only used for [`Self::script_fonts`].
     fonts: Vec<Arc<FontImpl>>,
 
+    /// How many of [`Self::fonts`] belong to the family.
+    num_family_fonts: usize,
+
+    /// Fonts to try before the family fonts, for the characters of a script.
+    script_fonts: Vec<(Script, Vec<FontIndex>)>,
+
     /// Lazily calculated.
     characters: Option<BTreeMap<char, Vec<String>>>,
 
@@ -338,10 +487,34 @@ pub struct Font {
 }
 
 impl Font {
-    pub fn new(fonts: Vec<Arc<FontImpl>>) -> Self {
+    pub fn new(
+        mut fonts: Vec<Arc<FontImpl>>,
+        script_fonts: Vec<(Script, Vec<Arc<FontImpl>>)>,
+    ) -> Self {
+        let num_family_fonts = fonts.len();
+        let script_fonts = script_fonts
+            .into_iter()
+            .map(|(script, script_fonts)| {
+                let indices = script_fonts
+                    .into_iter()
+                    .map(|font| {
+                        if let Some(index) = fonts.iter().position(|f| Arc::ptr_eq(f, &font)) {
+                            index
+                        } else {
+                            fonts.push(font);
+                            fonts.len() - 1
+                        }
+                    })
+                    .collect();
+                (script, indices)
+            })
+            .collect();
+
         if fonts.is_empty() {
             return Self {
                 fonts,
+                num_family_fonts,
+                script_fonts,
                 characters: None,
                 replacement_glyph: Default::default(),
                 pixels_per_point: 1.0,
@@ -355,6 +528,8 @@ impl Font {
 
         let mut slf = Self {
             fonts,
+            num_family_fonts,
+            script_fonts,
             characters: None,
             replacement_glyph: Default::default(),
             pixels_per_point,
@@ -401,9 +576,17 @@ impl Font {
     pub fn characters(&mut self) -> &BTreeMap<char, Vec<String>> {
         self.characters.get_or_insert_with(|| {
             let mut characters: BTreeMap<char, Vec<String>> = Default::default();
-            for font in &self.fonts {
+            for (font_index, font) in self.fonts.iter().enumerate() {
+                let is_family_font = font_index < self.num_family_fonts;
                 for chr in font.characters() {
-                    characters.entry(chr).or_default().push(font.name.clone());
+                    let is_script_font = || {
+                        self.script_fonts.iter().any(|(script, indices)| {
+                            indices.contains(&font_index) && script.contains(chr)
+                        })
+                    };
+                    if is_family_font || is_script_font() {
+                        characters.entry(chr).or_default().push(font.name.clone());
+                    }
                 }
             }
             characters
@@ -476,8 +659,15 @@ impl Font {
     }
 
     fn glyph_info_no_cache_or_fallback(&mut self, c: char) -> Option<(FontIndex, GlyphInfo)> {
-        for (font_index, font_impl) in self.fonts.iter().enumerate() {
-            if let Some(glyph_info) = font_impl.glyph_info(c) {
+        let script_font_indices = self
+            .script_fonts
+            .iter()
+            .filter(|(script, _)| script.contains(c))
+            .flat_map(|(_, indices)| indices.iter().copied());
+        let family_font_indices = 0..self.num_family_fonts;
+
+        for font_index in script_font_indices.chain(family_font_indices) {
+            if let Some(glyph_info) = self.fonts[font_index].glyph_info(c) {
                 self.glyph_info_cache.insert(c, (font_index, glyph_info));
                 return Some((font_index, glyph_info));
             }
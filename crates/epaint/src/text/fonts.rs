@@ -106,6 +106,113 @@ impl std::fmt::Display for FontFamily {
 
 // ----------------------------------------------------------------------------
 
+/// A writing system, used to pick fonts for a character before the generic fallback.
+///
+/// See [`FontDefinitions::scripts`].
+///
+/// Each script covers the most commonly used Unicode blocks of that writing system.
+/// Characters shared between scripts (digits, punctuation, spaces, …) belong to no script.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Script {
+    /// Latin letters, including accented ones (but not ASCII digits or punctuation).
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+
+    /// Japanese Hiragana and Katakana.
+    Kana,
+
+    /// Chinese characters, also used in Japanese (Kanji) and Korean (Hanja).
+    Han,
+
+    /// Emoji and other pictographic symbols.
+    Emoji,
+}
+
+impl Script {
+    /// All scripts, in the order they are checked.
+    pub const ALL: [Self; 11] = [
+        Self::Latin,
+        Self::Greek,
+        Self::Cyrillic,
+        Self::Hebrew,
+        Self::Arabic,
+        Self::Devanagari,
+        Self::Thai,
+        Self::Hangul,
+        Self::Kana,
+        Self::Han,
+        Self::Emoji,
+    ];
+
+    /// The ranges of code points that belong to this script.
+    pub fn ranges(self) -> &'static [std::ops::RangeInclusive<char>] {
+        match self {
+            Self::Latin => &[
+                'A'..='Z',
+                'a'..='z',
+                '\u{00C0}'..='\u{024F}',
+                '\u{1E00}'..='\u{1EFF}',
+            ],
+            Self::Greek => &['\u{0370}'..='\u{03FF}', '\u{1F00}'..='\u{1FFF}'],
+            Self::Cyrillic => &['\u{0400}'..='\u{052F}'],
+            Self::Hebrew => &['\u{0590}'..='\u{05FF}'],
+            Self::Arabic => &[
+                '\u{0600}'..='\u{06FF}',
+                '\u{0750}'..='\u{077F}',
+                '\u{08A0}'..='\u{08FF}',
+                '\u{FB50}'..='\u{FDFF}',
+                '\u{FE70}'..='\u{FEFC}',
+            ],
+            Self::Devanagari => &['\u{0900}'..='\u{097F}'],
+            Self::Thai => &['\u{0E00}'..='\u{0E7F}'],
+            Self::Hangul => &[
+                '\u{1100}'..='\u{11FF}',
+                '\u{3130}'..='\u{318F}',
+                '\u{AC00}'..='\u{D7AF}',
+            ],
+            Self::Kana => &[
+                '\u{3040}'..='\u{30FF}',
+                '\u{31F0}'..='\u{31FF}',
+                '\u{FF66}'..='\u{FF9F}',
+            ],
+            Self::Han => &[
+                '\u{2E80}'..='\u{2FDF}',
+                '\u{3005}'..='\u{3007}',
+                '\u{3400}'..='\u{4DBF}',
+                '\u{4E00}'..='\u{9FFF}',
+                '\u{F900}'..='\u{FAFF}',
+                '\u{20000}'..='\u{3134F}',
+            ],
+            Self::Emoji => &['\u{2600}'..='\u{27BF}', '\u{1F300}'..='\u{1FAFF}'],
+        }
+    }
+
+    /// Does this script contain the given character?
+    pub fn contains(self, c: char) -> bool {
+        self.ranges().iter().any(|range| range.contains(&c))
+    }
+
+    /// Which script does this character belong to, if any?
+    pub fn of(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|script| script.contains(c))
+    }
+}
+
+impl std::fmt::Display for Script {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A `.ttf` or `.otf` file and a font face index.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -261,6 +368,24 @@ pub struct FontDefinitions {
     /// the first font and then move to the second, and so on.
     /// So the first font is the primary, and then comes a list of fallbacks in order of priority.
     pub families: BTreeMap<FontFamily, Vec<String>>,
+
+    /// Which fonts (names) to try first for the characters of a [`Script`], in any [`FontFamily`].
+    ///
+    /// The list should be a list of keys into [`Self::font_data`].
+    /// If none of these fonts has a glyph for a character,
+    /// the fonts of the [`FontFamily`] are tried as usual.
+    ///
+    /// This lets you mix e.g. Latin, Chinese and Arabic text in one label
+    /// without carefully ordering all fonts in each family:
+    /// ```
+    /// # use epaint::text::{FontDefinitions, Script};
+    /// let mut fonts = FontDefinitions::default();
+    /// // (Also add "NotoSansSC" to `fonts.font_data`.)
+    /// fonts.scripts.insert(Script::Han, vec!["NotoSansSC".to_owned()]);
+    /// ```
+    ///
+    /// Empty by default.
+    pub scripts: BTreeMap<Script, Vec<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -372,6 +497,7 @@ impl Default for FontDefinitions {
         Self {
             font_data,
             families,
+            scripts: Default::default(),
         }
     }
 }
@@ -386,6 +512,7 @@ impl FontDefinitions {
         Self {
             font_data: Default::default(),
             families,
+            scripts: Default::default(),
         }
     }
 
@@ -544,6 +671,17 @@ impl Fonts {
         self.lock().fonts.has_glyphs(font_id, s)
     }
 
+    /// The characters in this text that none of the fonts in the [`FontFamily`] have a glyph for,
+    /// in order of first appearance and without duplicates.
+    ///
+    /// Control characters (like `\n`) are ignored.
+    /// The coverage does not depend on [`FontId::size`].
+    ///
+    /// Use this to decide if you need to load more fonts before showing some text.
+    pub fn missing_glyphs(&self, font_id: &FontId, s: &str) -> Vec<char> {
+        self.lock().fonts.missing_glyphs(font_id, s)
+    }
+
     /// Height of one row of text in points.
     ///
     /// Returns a value rounded to [`emath::GUI_ROUNDING`].
@@ -717,7 +855,21 @@ impl FontsImpl {
                     .map(|font_name| self.font_impl_cache.font_impl(size, font_name))
                     .collect();
 
-                Font::new(fonts)
+                let script_fonts = self
+                    .definitions
+                    .scripts
+                    .iter()
+                    .filter(|(_, font_names)| !font_names.is_empty())
+                    .map(|(script, font_names)| {
+                        let fonts = font_names
+                            .iter()
+                            .map(|font_name| self.font_impl_cache.font_impl(size, font_name))
+                            .collect();
+                        (*script, fonts)
+                    })
+                    .collect();
+
+                Font::new(fonts, script_fonts)
             })
     }
 
@@ -736,6 +888,20 @@ impl FontsImpl {
         self.font(font_id).has_glyphs(s)
     }
 
+    /// The characters in this text that we have no glyph for.
+    ///
+    /// See [`Fonts::missing_glyphs`].
+    pub fn missing_glyphs(&mut self, font_id: &FontId, s: &str) -> Vec<char> {
+        let font = self.font(font_id);
+        let mut missing: Vec<char> = vec![];
+        for c in s.chars() {
+            if !c.is_control() && !missing.contains(&c) && !font.has_glyph(c) {
+                missing.push(c);
+            }
+        }
+        missing
+    }
+
     /// Height of one row of text in points.
     ///
     /// Returns a value rounded to [`emath::GUI_ROUNDING`].
@@ -903,6 +1069,8 @@ impl GalleyCache {
                 break_on_newline: job.break_on_newline,
                 halign: job.halign,
                 justify: job.justify,
+                full_justify: job.full_justify,
+                direction: job.direction,
                 first_row_min_height: if is_first_paragraph {
                     job.first_row_min_height
                 } else {
@@ -1165,6 +1333,76 @@ mod tests {
         ]
     }
 
+    #[test]
+    fn test_missing_glyphs() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let font_id = FontId::default();
+        assert_eq!(fonts.missing_glyphs(&font_id, "Hello, world!\n"), vec![]);
+        assert_eq!(
+            fonts.missing_glyphs(&font_id, "漢字 and 漢"),
+            vec!['漢', '字'],
+            "The default fonts have no CJK glyphs"
+        );
+    }
+
+    #[test]
+    fn test_script_of() {
+        assert_eq!(Script::of('a'), Some(Script::Latin));
+        assert_eq!(Script::of('é'), Some(Script::Latin));
+        assert_eq!(Script::of('Ж'), Some(Script::Cyrillic));
+        assert_eq!(Script::of('م'), Some(Script::Arabic));
+        assert_eq!(Script::of('か'), Some(Script::Kana));
+        assert_eq!(Script::of('漢'), Some(Script::Han));
+        assert_eq!(Script::of('😀'), Some(Script::Emoji));
+        assert_eq!(Script::of('1'), None, "Digits are shared by all scripts");
+        assert_eq!(Script::of(' '), None);
+    }
+
+    #[test]
+    fn test_script_fonts() {
+        let font_id = FontId::proportional(14.0);
+        let mut plain = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut definitions = FontDefinitions::default();
+        definitions
+            .scripts
+            .insert(Script::Latin, vec!["Hack".to_owned()]);
+        definitions
+            .scripts
+            .insert(Script::Han, vec!["Hack".to_owned()]);
+        let mut with_scripts = FontsImpl::new(1.0, 1024, AlphaFromCoverage::default(), definitions);
+
+        assert_ne!(
+            plain.glyph_width(&font_id, 'i'),
+            plain.glyph_width(&font_id, 'm')
+        );
+        assert_eq!(
+            with_scripts.glyph_width(&font_id, 'i'),
+            with_scripts.glyph_width(&font_id, 'm'),
+            "Latin letters should use the monospace script font"
+        );
+        assert_eq!(
+            with_scripts.glyph_width(&font_id, '1'),
+            plain.glyph_width(&font_id, '1'),
+            "Characters outside the script should use the family fonts"
+        );
+        assert_eq!(
+            with_scripts.missing_glyphs(&font_id, "漢 and 😀"),
+            vec!['漢'],
+            "Only characters that no font covers are missing"
+        );
+    }
+
     #[test]
     fn test_split_paragraphs() {
         for pixels_per_point in [1.0, 2.0_f32.sqrt(), 2.0] {
@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2, pos2, vec2};
 
-use crate::{Color32, Mesh, Stroke, Vertex, stroke::PathStroke, text::font::Font};
+use crate::{
+    Color32, Mesh, Stroke, Vertex,
+    stroke::PathStroke,
+    text::font::{Font, FontImpl, UvRect},
+};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals};
+use super::{
+    FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals, TextDirection,
+    TextFormat, TextTruncation,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -93,23 +100,33 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         layout_section(fonts, &job, section_index as u32, section, &mut paragraphs);
     }
 
+    for paragraph in &mut paragraphs {
+        resolve_bidi_levels(job.direction, paragraph);
+    }
+
     let point_scale = PointScale::new(fonts.pixels_per_point());
 
     let intrinsic_size = calculate_intrinsic_size(point_scale, &job, &paragraphs);
 
     let mut elided = false;
-    let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
-    if elided {
-        if let Some(last_placed) = rows.last_mut() {
-            let last_row = Arc::make_mut(&mut last_placed.row);
-            replace_last_glyph_with_overflow_character(fonts, &job, last_row);
-            if let Some(last) = last_row.glyphs.last() {
-                last_row.size.x = last.max_x();
+    let mut rows = if let Some(row) = elide_start_or_middle(fonts, &job, &paragraphs) {
+        elided = true;
+        vec![row]
+    } else {
+        let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
+        if elided {
+            if let Some(last_placed) = rows.last_mut() {
+                let last_row = Arc::make_mut(&mut last_placed.row);
+                replace_last_glyph_with_overflow_character(fonts, &job, last_row);
+                if let Some(last) = last_row.glyphs.last() {
+                    last_row.size.x = last.max_x();
+                }
             }
         }
-    }
+        rows
+    };
 
-    let justify = job.justify && job.wrap.max_width.is_finite();
+    let justify = (job.justify || job.full_justify) && job.wrap.max_width.is_finite();
 
     if justify || job.halign != Align::LEFT {
         let num_rows = rows.len();
@@ -122,12 +139,19 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
                 job.halign,
                 job.wrap.max_width,
                 justify_row,
+                job.full_justify,
             );
         }
     }
 
+    for placed_row in &mut rows {
+        if placed_row.has_rtl() {
+            reorder_bidi_row(point_scale, Arc::make_mut(&mut placed_row.row));
+        }
+    }
+
     // Calculate the Y positions and tessellate the text:
-    galley_from_rows(point_scale, job, rows, elided, intrinsic_size)
+    galley_from_rows(fonts, point_scale, job, rows, elided, intrinsic_size)
 }
 
 // Ignores the Y coordinate.
@@ -148,7 +172,6 @@ fn layout_section(
         .format
         .line_height
         .unwrap_or_else(|| font.row_height());
-    let extra_letter_spacing = section.format.extra_letter_spacing;
 
     let mut paragraph = out_paragraphs.last_mut().unwrap();
     if paragraph.glyphs.is_empty() {
@@ -157,42 +180,83 @@ fn layout_section(
 
     paragraph.cursor_x += leading_space;
 
-    let mut last_glyph_id = None;
+    let mut last_glyph = None;
 
     for chr in job.text[byte_range.clone()].chars() {
         if job.break_on_newline && chr == '\n' {
             out_paragraphs.push(Paragraph::from_section_index(section_index));
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = line_height; // TODO(emilk): replace this hack with actually including `\n` in the glyphs?
+            last_glyph = None;
         } else {
             let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
             if let Some(font_impl) = font_impl {
-                if let Some(last_glyph_id) = last_glyph_id {
-                    paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
-                    paragraph.cursor_x += extra_letter_spacing;
+                if let Some((last_glyph_id, last_glyph_x)) = last_glyph {
+                    // The distance to the start of the last glyph, after pixel rounding:
+                    let last_advance_width = paragraph.cursor_x - last_glyph_x;
+                    let kerning = font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    paragraph.cursor_x += kerning;
+                    paragraph.cursor_x += format.letter_spacing_after(last_advance_width, kerning);
                 }
             }
 
+            let advance_width = format.spaced_advance_width(chr, glyph_info.advance_width);
+
             paragraph.glyphs.push(Glyph {
                 chr,
                 pos: pos2(paragraph.cursor_x, f32::NAN),
-                advance_width: glyph_info.advance_width,
+                advance_width,
                 line_height,
                 font_impl_height: font_impl.map_or(0.0, |f| f.row_height()),
                 font_impl_ascent: font_impl.map_or(0.0, |f| f.ascent()),
                 font_height: font.row_height(),
                 font_ascent: font.ascent(),
                 uv_rect: glyph_info.uv_rect,
+                bidi_level: 0, // resolved later, for the whole paragraph
                 section_index,
             });
 
-            paragraph.cursor_x += glyph_info.advance_width;
+            let glyph_x = paragraph.cursor_x;
+            paragraph.cursor_x += advance_width;
             paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
-            last_glyph_id = Some(glyph_info.id);
+            last_glyph = Some((glyph_info.id, glyph_x));
         }
     }
 }
 
+/// Resolve the [`Glyph::bidi_level`] of each glyph in the paragraph
+/// using the Unicode Bidirectional Algorithm.
+fn resolve_bidi_levels(direction: TextDirection, paragraph: &mut Paragraph) {
+    use unicode_bidi::{BidiClass, BidiInfo, Level, bidi_class};
+
+    let base_level = match direction {
+        TextDirection::Auto => None,
+        TextDirection::LeftToRight => Some(Level::ltr()),
+        TextDirection::RightToLeft => Some(Level::rtl()),
+    };
+
+    let any_rtl = paragraph.glyphs.iter().any(|glyph| {
+        matches!(
+            bidi_class(glyph.chr),
+            BidiClass::R
+                | BidiClass::AL
+                | BidiClass::RLE
+                | BidiClass::RLO
+                | BidiClass::RLI
+                | BidiClass::FSI
+        )
+    });
+    if !any_rtl && base_level != Some(Level::rtl()) {
+        return; // Fast path: everything is left-to-right, i.e. level 0.
+    }
+
+    let text: String = paragraph.glyphs.iter().map(|glyph| glyph.chr).collect();
+    let bidi_info = BidiInfo::new(&text, base_level);
+    for (glyph, (byte_index, _)) in paragraph.glyphs.iter_mut().zip(text.char_indices()) {
+        glyph.bidi_level = bidi_info.levels[byte_index].number();
+    }
+}
+
 /// Calculate the intrinsic size of the text.
 ///
 /// The result is eventually passed to `Response::intrinsic_size`.
@@ -393,6 +457,136 @@ fn line_break(
     }
 }
 
+/// Truncate a single paragraph that doesn't fit on one row
+/// at the start or in the middle, according to [`super::TextWrapping::truncation`].
+///
+/// Returns `None` if the text fits, or should be elided at the end as usual.
+///
+/// Called before we have any Y coordinates.
+fn elide_start_or_middle(
+    fonts: &mut FontsImpl,
+    job: &LayoutJob,
+    paragraphs: &[Paragraph],
+) -> Option<PlacedRow> {
+    if job.wrap.truncation == TextTruncation::End || job.wrap.max_rows != 1 {
+        return None;
+    }
+    let [paragraph] = paragraphs else {
+        return None;
+    };
+    let glyphs = &paragraph.glyphs;
+    let (first, last) = (glyphs.first()?, glyphs.last()?);
+    let wrap_width = job.effective_wrap_width();
+    if last.max_x() - first.pos.x <= wrap_width {
+        return None;
+    }
+
+    let overflow_glyph = |fonts: &mut FontsImpl, neighbor: &Glyph| {
+        job.wrap.overflow_character.map(|overflow_character| {
+            let section = &job.sections[neighbor.section_index as usize];
+            let font = fonts.font(&section.format.font_id);
+            let line_height = section
+                .format
+                .line_height
+                .unwrap_or_else(|| font.row_height());
+            let (font_impl, glyph_info) = font.font_impl_and_glyph_info(overflow_character);
+            Glyph {
+                chr: overflow_character,
+                pos: pos2(0.0, f32::NAN),
+                advance_width: glyph_info.advance_width,
+                line_height,
+                font_impl_height: font_impl.map_or(0.0, |f| f.row_height()),
+                font_impl_ascent: font_impl.map_or(0.0, |f| f.ascent()),
+                font_height: font.row_height(),
+                font_ascent: font.ascent(),
+                uv_rect: glyph_info.uv_rect,
+                bidi_level: neighbor.bidi_level,
+                section_index: neighbor.section_index,
+            }
+        })
+    };
+
+    // The widths of the first `n` and the last `n` glyphs:
+    let prefix_width = |n: usize| {
+        if n == 0 {
+            0.0
+        } else {
+            glyphs[n - 1].max_x() - first.pos.x
+        }
+    };
+    let suffix_width = |n: usize| {
+        if n == 0 {
+            0.0
+        } else {
+            last.max_x() - glyphs[glyphs.len() - n].pos.x
+        }
+    };
+
+    let overflow_width = overflow_glyph(fonts, first).map_or(0.0, |glyph| glyph.advance_width);
+    let available_width = wrap_width - overflow_width;
+
+    // Take as much as fits from the start (for `Middle`), then from the end.
+    // The glyphs are kept whole, so multibyte characters are never split:
+    let mut num_prefix = 0;
+    if job.wrap.truncation == TextTruncation::Middle {
+        while num_prefix < glyphs.len() && prefix_width(num_prefix + 1) <= available_width / 2.0 {
+            num_prefix += 1;
+        }
+    }
+    let mut num_suffix = 0;
+    while num_prefix + num_suffix < glyphs.len()
+        && prefix_width(num_prefix) + suffix_width(num_suffix + 1) <= available_width
+    {
+        num_suffix += 1;
+    }
+
+    let mut row_glyphs: Vec<Glyph> = glyphs[..num_prefix]
+        .iter()
+        .map(|glyph| Glyph {
+            pos: pos2(glyph.pos.x - first.pos.x, glyph.pos.y),
+            ..*glyph
+        })
+        .collect();
+    let mut x = prefix_width(num_prefix);
+
+    let neighbor = if num_prefix > 0 {
+        &glyphs[num_prefix - 1]
+    } else {
+        glyphs.get(glyphs.len() - num_suffix).unwrap_or(first)
+    };
+    if let Some(mut overflow) = overflow_glyph(fonts, neighbor) {
+        overflow.pos.x = x;
+        x += overflow.advance_width;
+        row_glyphs.push(overflow);
+    }
+
+    let suffix = &glyphs[glyphs.len() - num_suffix..];
+    if let Some(suffix_start) = suffix.first() {
+        let dx = x - suffix_start.pos.x;
+        row_glyphs.extend(suffix.iter().map(|glyph| Glyph {
+            pos: pos2(glyph.pos.x + dx, glyph.pos.y),
+            ..*glyph
+        }));
+    }
+
+    // Only empty if there is no overflow character and nothing fits:
+    let size = row_glyphs
+        .last()
+        .map_or(vec2(0.0, first.line_height), |glyph| {
+            vec2(glyph.max_x(), 0.0)
+        });
+    Some(PlacedRow {
+        pos: pos2(0.0, f32::NAN),
+        row: Arc::new(Row {
+            section_index_at_start: paragraph.section_index_at_start,
+            glyphs: row_glyphs,
+            visuals: Default::default(),
+            size,
+            ends_with_newline: false,
+        }),
+    })
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -423,6 +617,7 @@ fn replace_last_glyph_with_overflow_character(
     // We always try to just append the character first:
     if let Some(last_glyph) = row.glyphs.last() {
         let section_index = last_glyph.section_index;
+        let bidi_level = last_glyph.bidi_level;
         let section = &job.sections[section_index as usize];
         let font = fonts.font(&section.format.font_id);
         let line_height = row_height(section, font);
@@ -435,10 +630,13 @@ fn replace_last_glyph_with_overflow_character(
 
         {
             // Kerning:
-            x += section.format.extra_letter_spacing;
-            if let Some(font_impl) = font_impl {
-                x += font_impl.pair_kerning(last_glyph_info.id, replacement_glyph_info.id);
-            }
+            let kerning = font_impl.map_or(0.0, |font_impl| {
+                font_impl.pair_kerning(last_glyph_info.id, replacement_glyph_info.id)
+            });
+            x += kerning;
+            x += section
+                .format
+                .letter_spacing_after(last_glyph.advance_width, kerning);
         }
 
         row.glyphs.push(Glyph {
@@ -451,6 +649,7 @@ fn replace_last_glyph_with_overflow_character(
             font_height: font.row_height(),
             font_ascent: font.ascent(),
             uv_rect: replacement_glyph_info.uv_rect,
+            bidi_level,
             section_index,
         });
     } else {
@@ -473,6 +672,7 @@ fn replace_last_glyph_with_overflow_character(
             font_height: font.row_height(),
             font_ascent: font.ascent(),
             uv_rect: replacement_glyph_info.uv_rect,
+            bidi_level: 0,
             section_index,
         });
     }
@@ -497,18 +697,23 @@ fn replace_last_glyph_with_overflow_character(
         };
 
         let section = &job.sections[last_glyph.section_index as usize];
-        let extra_letter_spacing = section.format.extra_letter_spacing;
         let font = fonts.font(&section.format.font_id);
 
         if let Some(prev_glyph) = prev_glyph {
             let prev_glyph_id = font.font_impl_and_glyph_info(prev_glyph.chr).1.id;
+            let kerning_and_spacing = |font_impl: Option<&FontImpl>, glyph_id| {
+                let kerning = font_impl.map_or(0.0, |font_impl| {
+                    font_impl.pair_kerning(prev_glyph_id, glyph_id)
+                });
+                kerning
+                    + section
+                        .format
+                        .letter_spacing_after(prev_glyph.advance_width, kerning)
+            };
 
             // Undo kerning with previous glyph:
             let (font_impl, glyph_info) = font.font_impl_and_glyph_info(last_glyph.chr);
-            last_glyph.pos.x -= extra_letter_spacing;
-            if let Some(font_impl) = font_impl {
-                last_glyph.pos.x -= font_impl.pair_kerning(prev_glyph_id, glyph_info.id);
-            }
+            last_glyph.pos.x -= kerning_and_spacing(font_impl, glyph_info.id);
 
             // Replace the glyph:
             last_glyph.chr = overflow_character;
@@ -519,10 +724,7 @@ fn replace_last_glyph_with_overflow_character(
             last_glyph.uv_rect = glyph_info.uv_rect;
 
             // Reapply kerning:
-            last_glyph.pos.x += extra_letter_spacing;
-            if let Some(font_impl) = font_impl {
-                last_glyph.pos.x += font_impl.pair_kerning(prev_glyph_id, glyph_info.id);
-            }
+            last_glyph.pos.x += kerning_and_spacing(font_impl, glyph_info.id);
 
             // Check if we're within width budget:
             if row_width(row) <= job.effective_wrap_width() || row.glyphs.len() == 1 {
@@ -553,6 +755,7 @@ fn halign_and_justify_row(
     halign: Align,
     wrap_width: f32,
     justify: bool,
+    full_justify: bool,
 ) {
     let row = Arc::make_mut(&mut placed_row.row);
 
@@ -586,11 +789,14 @@ fn halign_and_justify_row(
     let original_max_x = row.glyphs[glyph_range.1 - 1].logical_rect().max.x;
     let original_width = original_max_x - original_min_x;
 
-    let target_width = if justify && num_glyphs_in_range > 1 {
-        wrap_width
-    } else {
-        original_width
-    };
+    let num_spaces_in_range = row.glyphs[glyph_range.0..glyph_range.1]
+        .iter()
+        .filter(|glyph| glyph.chr.is_whitespace())
+        .count();
+
+    // Full justification only widens spaces, so a single long word is left as is:
+    let stretch = justify && num_glyphs_in_range > 1 && (!full_justify || num_spaces_in_range > 0);
+    let target_width = if stretch { wrap_width } else { original_width };
 
     let (target_min_x, target_max_x) = match halign {
         Align::LEFT => (0.0, target_width),
@@ -598,12 +804,7 @@ fn halign_and_justify_row(
         Align::RIGHT => (-target_width, 0.0),
     };
 
-    let num_spaces_in_range = row.glyphs[glyph_range.0..glyph_range.1]
-        .iter()
-        .filter(|glyph| glyph.chr.is_whitespace())
-        .count();
-
-    let mut extra_x_per_glyph = if num_glyphs_in_range == 1 {
+    let mut extra_x_per_glyph = if num_glyphs_in_range == 1 || full_justify {
         0.0
     } else {
         (target_width - original_width) / (num_glyphs_in_range as f32 - 1.0)
@@ -611,7 +812,12 @@ fn halign_and_justify_row(
     extra_x_per_glyph = extra_x_per_glyph.at_least(0.0); // Don't contract
 
     let mut extra_x_per_space = 0.0;
-    if 0 < num_spaces_in_range && num_spaces_in_range < num_glyphs_in_range {
+    if full_justify {
+        if 0 < num_spaces_in_range {
+            extra_x_per_space =
+                ((target_width - original_width) / (num_spaces_in_range as f32)).at_least(0.0);
+        }
+    } else if 0 < num_spaces_in_range && num_spaces_in_range < num_glyphs_in_range {
         // Add an integral number of pixels between each glyph,
         // and add the balance to the spaces:
 
@@ -626,12 +832,21 @@ fn halign_and_justify_row(
     placed_row.pos.x = point_scale.round_to_pixel(target_min_x);
     let mut translate_x = -original_min_x - extra_x_per_glyph * glyph_range.0 as f32;
 
-    for glyph in &mut row.glyphs {
+    for (i, glyph) in row.glyphs.iter_mut().enumerate() {
         glyph.pos.x += translate_x;
         glyph.pos.x = point_scale.round_to_pixel(glyph.pos.x);
         translate_x += extra_x_per_glyph;
         if glyph.chr.is_whitespace() {
-            translate_x += extra_x_per_space;
+            if full_justify {
+                if glyph_range.0 <= i && i < glyph_range.1 {
+                    // Widen the space itself, so that selections, cursors and underlines
+                    // cover the whole gap between the words:
+                    glyph.advance_width += extra_x_per_space;
+                    translate_x += extra_x_per_space;
+                }
+            } else {
+                translate_x += extra_x_per_space;
+            }
         }
     }
 
@@ -639,8 +854,72 @@ fn halign_and_justify_row(
     row.size.x = target_max_x - target_min_x;
 }
 
+/// Move the glyphs of a row with right-to-left text to their visual positions.
+///
+/// The glyphs stay in logical order in [`Row::glyphs`], so that cursors still index characters.
+fn reorder_bidi_row(point_scale: PointScale, row: &mut Row) {
+    let glyphs = &mut row.glyphs;
+    let Some(first) = glyphs.first() else {
+        return;
+    };
+    let min_x = first.pos.x;
+
+    // Keep the spacing (kerning, justification, …) between the glyphs it was between:
+    let spacing_after: Vec<f32> = (0..glyphs.len())
+        .map(|i| {
+            let spacing = if glyphs[i].is_rtl() {
+                i.checked_sub(1)
+                    .map(|prev| glyphs[i].pos.x - glyphs[prev].max_x())
+            } else {
+                glyphs.get(i + 1).map(|next| next.pos.x - glyphs[i].max_x())
+            };
+            spacing.unwrap_or_default()
+        })
+        .collect();
+
+    let levels: Vec<u8> = glyphs.iter().map(|glyph| glyph.bidi_level).collect();
+
+    let mut x = min_x;
+    for i in visual_order(&levels) {
+        glyphs[i].pos.x = point_scale.round_to_pixel(x);
+        x += glyphs[i].advance_width + spacing_after[i];
+    }
+}
+
+/// The logical indices of characters with the given bidi levels, in visual order from left to right.
+///
+/// This is rule L2 of the Unicode Bidirectional Algorithm.
+fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+
+    let max_level = levels.iter().copied().max().unwrap_or(0);
+    let Some(min_odd_level) = levels.iter().copied().filter(|level| level % 2 == 1).min() else {
+        return order; // All left-to-right
+    };
+
+    // From the highest level down to the lowest odd level,
+    // reverse every run of characters at that level or higher:
+    for level in (min_odd_level..=max_level).rev() {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let run_start = i;
+            while i < order.len() && level <= levels[order[i]] {
+                i += 1;
+            }
+            order[run_start..i].reverse();
+        }
+    }
+
+    order
+}
+
 /// Calculate the Y positions and tessellate the text.
 fn galley_from_rows(
+    fonts: &mut FontsImpl,
     point_scale: PointScale,
     job: Arc<LayoutJob>,
     mut rows: Vec<PlacedRow>,
@@ -694,7 +973,7 @@ fn galley_from_rows(
         rect |= placed_row.rect();
 
         let row = Arc::make_mut(&mut placed_row.row);
-        row.visuals = tessellate_row(point_scale, &job, &format_summary, row);
+        row.visuals = tessellate_row(fonts, point_scale, &job, &format_summary, row);
 
         mesh_bounds |= row.visuals.mesh_bounds.translate(placed_row.pos.to_vec2());
         num_vertices += row.visuals.mesh.vertices.len();
@@ -730,6 +1009,7 @@ struct FormatSummary {
     any_background: bool,
     any_underline: bool,
     any_strikethrough: bool,
+    any_stroke: bool,
 }
 
 fn format_summary(job: &LayoutJob) -> FormatSummary {
@@ -738,11 +1018,13 @@ fn format_summary(job: &LayoutJob) -> FormatSummary {
         format_summary.any_background |= section.format.background != Color32::TRANSPARENT;
         format_summary.any_underline |= section.format.underline != Stroke::NONE;
         format_summary.any_strikethrough |= section.format.strikethrough != Stroke::NONE;
+        format_summary.any_stroke |= !section.format.stroke.is_empty();
     }
     format_summary
 }
 
 fn tessellate_row(
+    fonts: &mut FontsImpl,
     point_scale: PointScale,
     job: &LayoutJob,
     format_summary: &FormatSummary,
@@ -762,6 +1044,13 @@ fn tessellate_row(
     }
 
     let glyph_index_start = mesh.indices.len();
+
+    if format_summary.any_stroke {
+        // Behind the glyphs, and not part of `glyph_vertex_range`,
+        // so that it keeps its color when the text color is overridden:
+        tessellate_glyph_outlines(fonts, point_scale, job, row, &mut mesh);
+    }
+
     let glyph_vertex_start = mesh.vertices.len();
     tessellate_glyphs(point_scale, job, row, &mut mesh);
     let glyph_vertex_end = mesh.vertices.len();
@@ -845,51 +1134,105 @@ fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh:
     for glyph in &row.glyphs {
         let uv_rect = glyph.uv_rect;
         if !uv_rect.is_nothing() {
-            let mut left_top = glyph.pos + uv_rect.offset;
-            left_top.x = point_scale.round_to_pixel(left_top.x);
-            left_top.y = point_scale.round_to_pixel(left_top.y);
-
-            let rect = Rect::from_min_max(left_top, left_top + uv_rect.size);
-            let uv = Rect::from_min_max(
-                pos2(uv_rect.min[0] as f32, uv_rect.min[1] as f32),
-                pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
-            );
-
             let format = &job.sections[glyph.section_index as usize].format;
+            let rect = glyph_rect(point_scale, glyph.pos, uv_rect);
+            let italics_bottom = rect.bottom();
+            add_glyph_rect(mesh, rect, uv_rect, format.color, format, italics_bottom);
+        }
+    }
+}
 
-            let color = format.color;
+/// Paint the outlines of glyphs that have a [`TextFormat::stroke`].
+fn tessellate_glyph_outlines(
+    fonts: &mut FontsImpl,
+    point_scale: PointScale,
+    job: &LayoutJob,
+    row: &Row,
+    mesh: &mut Mesh,
+) {
+    for glyph in &row.glyphs {
+        let format = &job.sections[glyph.section_index as usize].format;
+        if format.stroke.is_empty() || glyph.uv_rect.is_nothing() {
+            continue;
+        }
 
-            if format.italics {
-                let idx = mesh.vertices.len() as u32;
-                mesh.add_triangle(idx, idx + 1, idx + 2);
-                mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+        let (font_impl, glyph_info) = fonts
+            .font(&format.font_id)
+            .font_impl_and_glyph_info(glyph.chr);
+        let Some(font_impl) = font_impl else {
+            continue;
+        };
+        let uv_rect = font_impl.glyph_outline(glyph_info.id, format.stroke.width);
+        if !uv_rect.is_nothing() {
+            let rect = glyph_rect(point_scale, glyph.pos, uv_rect);
+            // Slant the outline exactly like the glyph:
+            let italics_bottom = glyph_rect(point_scale, glyph.pos, glyph.uv_rect).bottom();
+            add_glyph_rect(
+                mesh,
+                rect,
+                uv_rect,
+                format.stroke.color,
+                format,
+                italics_bottom,
+            );
+        }
+    }
+}
 
-                let top_offset = rect.height() * 0.25 * Vec2::X;
+/// Where to paint a glyph, snapped to the pixel grid.
+fn glyph_rect(point_scale: PointScale, glyph_pos: Pos2, uv_rect: UvRect) -> Rect {
+    let mut left_top = glyph_pos + uv_rect.offset;
+    left_top.x = point_scale.round_to_pixel(left_top.x);
+    left_top.y = point_scale.round_to_pixel(left_top.y);
+    Rect::from_min_max(left_top, left_top + uv_rect.size)
+}
 
-                mesh.vertices.push(Vertex {
-                    pos: rect.left_top() + top_offset,
-                    uv: uv.left_top(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.right_top() + top_offset,
-                    uv: uv.right_top(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.left_bottom(),
-                    uv: uv.left_bottom(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.right_bottom(),
-                    uv: uv.right_bottom(),
-                    color,
-                });
-            } else {
-                mesh.add_rect_with_uv(rect, uv, color);
-            }
-        }
+/// Add a textured glyph rectangle.
+///
+/// If [`TextFormat::italics`], it is slanted so that it leans right above `italics_bottom`.
+fn add_glyph_rect(
+    mesh: &mut Mesh,
+    rect: Rect,
+    uv_rect: UvRect,
+    color: Color32,
+    format: &TextFormat,
+    italics_bottom: f32,
+) {
+    let uv = Rect::from_min_max(
+        pos2(uv_rect.min[0] as f32, uv_rect.min[1] as f32),
+        pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
+    );
+
+    if format.italics {
+        let idx = mesh.vertices.len() as u32;
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+
+        let top_offset = (italics_bottom - rect.top()) * 0.25 * Vec2::X;
+        let bottom_offset = (italics_bottom - rect.bottom()) * 0.25 * Vec2::X;
+
+        mesh.vertices.push(Vertex {
+            pos: rect.left_top() + top_offset,
+            uv: uv.left_top(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.right_top() + top_offset,
+            uv: uv.right_top(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.left_bottom() + bottom_offset,
+            uv: uv.left_bottom(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.right_bottom() + bottom_offset,
+            uv: uv.right_bottom(),
+            color,
+        });
+    } else {
+        mesh.add_rect_with_uv(rect, uv, color);
     }
 }
 
@@ -1271,4 +1614,264 @@ mod tests {
             "Unexpected intrinsic size"
         );
     }
+
+    #[test]
+    fn test_stroke_is_painted_behind_glyphs() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut layout = |stroke: Stroke| {
+            let text_format = TextFormat {
+                stroke,
+                ..Default::default()
+            };
+            let job = LayoutJob::single_section("Hi".into(), text_format);
+            super::layout(&mut fonts, job.into())
+        };
+
+        let plain = layout(Stroke::NONE);
+        let outlined = layout(Stroke::new(2.0, Color32::BLACK));
+
+        let plain_visuals = &plain.rows[0].visuals;
+        let outlined_visuals = &outlined.rows[0].visuals;
+        assert_eq!(
+            outlined_visuals.mesh.vertices.len(),
+            2 * plain_visuals.mesh.vertices.len(),
+            "Expected one outline quad per glyph"
+        );
+        assert_eq!(
+            outlined_visuals.glyph_vertex_range.len(),
+            plain_visuals.glyph_vertex_range.len(),
+            "The outline should not be recolored with the text"
+        );
+        assert_eq!(outlined_visuals.glyph_vertex_range.start, 4 * 2);
+        assert!(
+            outlined_visuals
+                .mesh_bounds
+                .contains_rect(plain_visuals.mesh_bounds),
+            "The outline should extend around the glyphs"
+        );
+    }
+
+    #[test]
+    fn test_letter_and_word_spacing() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut layout = |extra_letter_spacing: f32, extra_word_spacing: f32| {
+            let text_format = TextFormat {
+                extra_letter_spacing,
+                extra_word_spacing,
+                ..Default::default()
+            };
+            let job = LayoutJob::single_section("ab cd".into(), text_format);
+            super::layout(&mut fonts, job.into())
+        };
+
+        let plain = layout(0.0, 0.0);
+        let spaced = layout(2.0, 10.0);
+        assert_eq!(
+            spaced.size().x,
+            plain.size().x + 4.0 * 2.0 + 10.0,
+            "Four gaps between letters, and one space"
+        );
+
+        // The extra space is part of the space glyph, so the cursor math sees it:
+        let space = &spaced.rows[0].glyphs[2];
+        assert_eq!(space.chr, ' ');
+        let next = &spaced.rows[0].glyphs[3];
+        assert!(
+            (next.pos.x - (space.max_x() + 2.0)).abs() < 1.0,
+            "Only pixel rounding and letter spacing between the space and the next letter"
+        );
+
+        let tight = layout(-1000.0, -1000.0);
+        let glyphs = &tight.rows[0].glyphs;
+        assert!(
+            glyphs.windows(2).all(|w| w[0].pos.x <= w[1].pos.x),
+            "Letters never start before the previous one"
+        );
+        assert_eq!(glyphs[2].advance_width, 0.0);
+    }
+
+    #[test]
+    fn test_full_justify() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let max_width = 80.0;
+
+        let mut layout = |text: &str, full_justify: bool| {
+            let mut job = LayoutJob::single_section(text.into(), TextFormat::default());
+            job.wrap.max_width = max_width;
+            job.full_justify = full_justify;
+            super::layout(&mut fonts, job.into())
+        };
+
+        let text = "aa b cc dd e ff gg h ii jj k ll\nmm n oo pp q rr ss t uu";
+        let plain = layout(text, false);
+        let justified = layout(text, true);
+        assert_eq!(plain.rows.len(), justified.rows.len());
+        assert!(plain.rows.len() > 3);
+
+        let num_rows = justified.rows.len();
+        for (i, (plain_row, row)) in plain.rows.iter().zip(&justified.rows).enumerate() {
+            let glyphs = &row.row.glyphs;
+            if row.ends_with_newline || i + 1 == num_rows {
+                let plain_glyphs = &plain_row.row.glyphs;
+                assert!(
+                    glyphs.iter().zip(plain_glyphs).all(|(glyph, plain)| {
+                        (glyph.pos.x - plain.pos.x).abs() <= 0.5
+                            && glyph.advance_width == plain.advance_width
+                    }),
+                    "The last row of a paragraph is not stretched"
+                );
+                continue;
+            }
+
+            let last_letter = glyphs.iter().rev().find(|g| !g.chr.is_whitespace());
+            let right_edge = last_letter.unwrap().max_x();
+            assert!(
+                (right_edge - max_width).abs() < 1.0,
+                "Row {i} should be flush with both edges, but ends at {right_edge}"
+            );
+            assert!(
+                glyphs
+                    .windows(2)
+                    .all(|w| (w[1].pos.x - w[0].max_x()).abs() < 1.5),
+                "The extra space is part of the space glyphs"
+            );
+        }
+
+        let long_word = "abcdefghijklmnopqrstuvwxyzabcdefghijklmnopqrstuvwxyz";
+        let long_word = layout(long_word, true);
+        assert!(long_word.rows.len() > 1);
+        assert!(
+            long_word.rows.iter().all(|row| row.size.x < max_width),
+            "Rows without spaces are not stretched"
+        );
+    }
+
+    #[test]
+    fn test_truncate_start_and_middle() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let max_width = 100.0;
+
+        let mut layout = |text: &str, truncation: TextTruncation, max_width: f32| {
+            let mut job = LayoutJob::single_section(text.into(), TextFormat::default());
+            job.wrap = TextWrapping {
+                truncation,
+                ..TextWrapping::truncate_at_width(max_width)
+            };
+            super::layout(&mut fonts, job.into())
+        };
+
+        let path = "~/projects/egui/crates/egui/src/main.rs";
+        let shown_text = |galley: &Galley| galley.rows[0].row.text();
+
+        let end = layout(path, TextTruncation::End, max_width);
+        assert!(end.elided);
+        let shown = shown_text(&end);
+        assert!(
+            shown.starts_with("~/pro") && shown.ends_with('…'),
+            "{shown}"
+        );
+
+        let middle = layout(path, TextTruncation::Middle, max_width);
+        assert!(middle.elided);
+        assert_eq!(middle.rows.len(), 1);
+        assert!(middle.size().x <= max_width, "{}", middle.size().x);
+        let shown = shown_text(&middle);
+        let (head, tail) = shown.split_once('…').unwrap();
+        assert!(!head.is_empty() && path.starts_with(head), "{shown}");
+        assert!(!tail.is_empty() && path.ends_with(tail), "{shown}");
+        assert_eq!(middle.text(), path, "The full text is kept in the job");
+
+        let start = layout(path, TextTruncation::Start, max_width);
+        assert!(start.elided);
+        assert!(start.size().x <= max_width, "{}", start.size().x);
+        let shown = shown_text(&start);
+        let tail = shown.strip_prefix('…').unwrap();
+        assert!(tail.len() > 3 && path.ends_with(tail), "{shown}");
+
+        let short = layout("main.rs", TextTruncation::Middle, max_width);
+        assert!(!short.elided);
+        assert_eq!(shown_text(&short), "main.rs");
+
+        let tiny = layout(path, TextTruncation::Middle, 1.0);
+        assert_eq!(shown_text(&tiny), "…");
+    }
+
+    #[test]
+    fn test_visual_order() {
+        assert_eq!(visual_order(&[]), Vec::<usize>::new());
+        assert_eq!(visual_order(&[0, 0, 0]), vec![0, 1, 2]);
+        assert_eq!(visual_order(&[0, 0, 0, 1, 1]), vec![0, 1, 2, 4, 3]);
+        assert_eq!(visual_order(&[1, 1, 1, 2, 2]), vec![3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_bidi_layout() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut layout = |text: &str, direction: TextDirection| {
+            let mut job = LayoutJob::single_section(text.into(), TextFormat::default());
+            job.direction = direction;
+            super::layout(&mut fonts, job.into())
+        };
+
+        // An English sentence ending with a Hebrew word:
+        let galley = layout("ab אב", TextDirection::Auto);
+        let row = &galley.rows[0];
+        let x = |i: usize| row.glyphs[i].pos.x;
+        assert!(row.has_rtl());
+        assert!(x(0) < x(1) && x(1) < x(2) && x(2) < x(4) && x(4) < x(3));
+        assert_eq!(
+            row.x_offset(3),
+            row.glyphs[3].max_x(),
+            "The cursor before a right-to-left character is on its right side"
+        );
+        assert_eq!(row.char_at(row.glyphs[3].max_x() - 0.1), 3);
+        assert_eq!(
+            row.x_ranges(1..4).len(),
+            2,
+            "Selecting 'b', ' ' and 'א' skips over 'ב'"
+        );
+
+        // Auto-detected as right-to-left, because the first strong character is Hebrew:
+        let galley = layout("אב ab", TextDirection::Auto);
+        let row = &galley.rows[0];
+        let x = |i: usize| row.glyphs[i].pos.x;
+        assert!(x(3) < x(4) && x(4) < x(2) && x(2) < x(1) && x(1) < x(0));
+
+        // Forced left-to-right:
+        let galley = layout("אב ab", TextDirection::LeftToRight);
+        let row = &galley.rows[0];
+        let x = |i: usize| row.glyphs[i].pos.x;
+        assert!(x(1) < x(0) && x(0) < x(2) && x(2) < x(3) && x(3) < x(4));
+
+        let galley = layout("ab", TextDirection::Auto);
+        assert!(!galley.rows[0].has_rtl());
+    }
 }
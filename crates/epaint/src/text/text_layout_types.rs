@@ -9,7 +9,9 @@ use super::{
     font::UvRect,
 };
 use crate::{Color32, FontId, Mesh, Stroke};
-use emath::{Align, GuiRounding as _, NumExt as _, OrderedFloat, Pos2, Rect, Vec2, pos2, vec2};
+use emath::{
+    Align, GuiRounding as _, NumExt as _, OrderedFloat, Pos2, Rangef, Rect, Vec2, pos2, vec2,
+};
 
 /// Describes the task of laying out text.
 ///
@@ -78,6 +80,21 @@ pub struct LayoutJob {
     /// Justify text so that word-wrapped rows fill the whole [`TextWrapping::max_width`].
     pub justify: bool,
 
+    /// Justify text so that both edges of word-wrapped rows are flush with the [`TextWrapping::max_width`],
+    /// by widening the spaces between words.
+    ///
+    /// Unlike [`Self::justify`], the letters are never spread apart,
+    /// so rows without any spaces (e.g. a single long word) are not stretched.
+    /// The last row of each paragraph is not stretched either.
+    ///
+    /// Default: `false`.
+    pub full_justify: bool,
+
+    /// The base direction of each paragraph, for mixing left-to-right and right-to-left text.
+    ///
+    /// Default: [`TextDirection::Auto`].
+    pub direction: TextDirection,
+
     /// Round output sizes using [`emath::GuiRounding`], to avoid rounding errors in layout code.
     pub round_output_to_gui: bool,
 }
@@ -93,6 +110,8 @@ impl Default for LayoutJob {
             break_on_newline: true,
             halign: Align::LEFT,
             justify: false,
+            full_justify: false,
+            direction: TextDirection::Auto,
             round_output_to_gui: true,
         }
     }
@@ -216,6 +235,8 @@ impl std::hash::Hash for LayoutJob {
             break_on_newline,
             halign,
             justify,
+            full_justify,
+            direction,
             round_output_to_gui,
         } = self;
 
@@ -226,12 +247,37 @@ impl std::hash::Hash for LayoutJob {
         break_on_newline.hash(state);
         halign.hash(state);
         justify.hash(state);
+        full_justify.hash(state);
+        direction.hash(state);
         round_output_to_gui.hash(state);
     }
 }
 
 // ----------------------------------------------------------------------------
 
+/// The base direction of a paragraph of text.
+///
+/// Text is laid out using the Unicode Bidirectional Algorithm,
+/// so that e.g. an Arabic name in an English sentence is shown right-to-left.
+/// The base direction decides the order of such runs,
+/// and the direction of neutral characters (spaces, punctuation) between them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextDirection {
+    /// Use the direction of the first strong (e.g. Latin or Hebrew) character of each paragraph,
+    /// or left-to-right if there is none.
+    #[default]
+    Auto,
+
+    /// Every paragraph is left-to-right.
+    LeftToRight,
+
+    /// Every paragraph is right-to-left.
+    RightToLeft,
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct LayoutSection {
@@ -270,9 +316,21 @@ pub struct TextFormat {
     ///
     /// Default: 0.0.
     ///
+    /// Negative values tighten the text, but never so much that a letter
+    /// starts before the previous one.
+    ///
     /// For even text it is recommended you round this to an even number of _pixels_.
     pub extra_letter_spacing: f32,
 
+    /// Extra spacing after each space character, in points.
+    ///
+    /// Default: 0.0.
+    ///
+    /// This widens the space itself, so it is included in selections
+    /// and in the hit-testing of the [`super::Galley`].
+    /// Negative values are clamped so that a space is never less than zero wide.
+    pub extra_word_spacing: f32,
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
@@ -298,6 +356,14 @@ pub struct TextFormat {
 
     pub strikethrough: Stroke,
 
+    /// An outline around the glyphs, painted behind them.
+    ///
+    /// The outline reaches [`Stroke::width`] points outside the glyphs, up to 16 pixels.
+    /// This keeps e.g. white text legible on top of images and maps.
+    ///
+    /// Default: [`Stroke::NONE`].
+    pub stroke: Stroke,
+
     /// If you use a small font and [`Align::TOP`] you
     /// can get the effect of raised text.
     ///
@@ -316,6 +382,7 @@ impl Default for TextFormat {
         Self {
             font_id: FontId::default(),
             extra_letter_spacing: 0.0,
+            extra_word_spacing: 0.0,
             line_height: None,
             color: Color32::GRAY,
             background: Color32::TRANSPARENT,
@@ -323,6 +390,7 @@ impl Default for TextFormat {
             italics: false,
             underline: Stroke::NONE,
             strikethrough: Stroke::NONE,
+            stroke: Stroke::NONE,
             valign: Align::BOTTOM,
         }
     }
@@ -334,6 +402,7 @@ impl std::hash::Hash for TextFormat {
         let Self {
             font_id,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
             color,
             background,
@@ -341,10 +410,12 @@ impl std::hash::Hash for TextFormat {
             italics,
             underline,
             strikethrough,
+            stroke,
             valign,
         } = self;
         font_id.hash(state);
         emath::OrderedFloat(*extra_letter_spacing).hash(state);
+        emath::OrderedFloat(*extra_word_spacing).hash(state);
         if let Some(line_height) = *line_height {
             emath::OrderedFloat(line_height).hash(state);
         }
@@ -354,6 +425,7 @@ impl std::hash::Hash for TextFormat {
         italics.hash(state);
         underline.hash(state);
         strikethrough.hash(state);
+        stroke.hash(state);
         valign.hash(state);
     }
 }
@@ -367,6 +439,25 @@ impl TextFormat {
             ..Default::default()
         }
     }
+
+    /// The [`Self::extra_letter_spacing`] to add between two glyphs,
+    /// where the next one starts `advance_width + kerning` after the previous one.
+    ///
+    /// Clamped so that the next glyph never starts before the previous one.
+    #[inline]
+    pub(crate) fn letter_spacing_after(&self, advance_width: f32, kerning: f32) -> f32 {
+        self.extra_letter_spacing.max(-(advance_width + kerning))
+    }
+
+    /// The advance width of `chr`, including any [`Self::extra_word_spacing`].
+    #[inline]
+    pub(crate) fn spaced_advance_width(&self, chr: char, advance_width: f32) -> f32 {
+        if chr == ' ' {
+            (advance_width + self.extra_word_spacing).max(0.0)
+        } else {
+            advance_width
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -389,6 +480,25 @@ pub enum TextWrapMode {
     Truncate,
 }
 
+/// Where to elide text that is truncated to a single row.
+///
+/// See [`TextWrapping::truncation`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextTruncation {
+    /// Keep the start of the text: `"~/projects/egui/cr…"`.
+    #[default]
+    End,
+
+    /// Keep both the start and the end of the text: `"~/projects/…/main.rs"`.
+    ///
+    /// Useful for file paths and long identifiers.
+    Middle,
+
+    /// Keep the end of the text: `"…/src/main.rs"`.
+    Start,
+}
+
 /// Controls the text wrapping and elision of a [`LayoutJob`].
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -434,6 +544,14 @@ pub struct TextWrapping {
     ///
     /// If not set, no character will be used (but the text will still be elided).
     pub overflow_character: Option<char>,
+
+    /// Where to elide text that doesn't fit.
+    ///
+    /// [`TextTruncation::Middle`] and [`TextTruncation::Start`] only apply to a single paragraph
+    /// with [`Self::max_rows`] set to `1`. Other text is always elided at the end.
+    ///
+    /// Default: [`TextTruncation::End`].
+    pub truncation: TextTruncation,
 }
 
 impl std::hash::Hash for TextWrapping {
@@ -444,11 +562,13 @@ impl std::hash::Hash for TextWrapping {
             max_rows,
             break_anywhere,
             overflow_character,
+            truncation,
         } = self;
         emath::OrderedFloat(*max_width).hash(state);
         max_rows.hash(state);
         break_anywhere.hash(state);
         overflow_character.hash(state);
+        truncation.hash(state);
     }
 }
 
@@ -459,6 +579,7 @@ impl Default for TextWrapping {
             max_rows: usize::MAX,
             break_anywhere: false,
             overflow_character: Some('…'),
+            truncation: TextTruncation::End,
         }
     }
 }
@@ -640,15 +761,15 @@ pub struct RowVisuals {
     /// Does NOT include leading or trailing whitespace glyphs!!
     pub mesh_bounds: Rect,
 
-    /// The number of triangle indices added before the first glyph triangle.
+    /// The number of triangle indices added before the first glyph triangle (or glyph outline).
     ///
     /// This can be used to insert more triangles after the background but before the glyphs,
     /// i.e. for text selection visualization.
     pub glyph_index_start: usize,
 
-    /// The range of vertices in the mesh that contain glyphs (as opposed to background, underlines, strikethorugh, etc).
+    /// The range of vertices in the mesh that contain glyphs (as opposed to background, glyph outlines, underlines, strikethorugh, etc).
     ///
-    /// The glyph vertices comes after backgrounds (if any), but before any underlines and strikethrough.
+    /// The glyph vertices comes after backgrounds and glyph outlines (if any), but before any underlines and strikethrough.
     pub glyph_vertex_range: Range<usize>,
 }
 
@@ -697,6 +818,11 @@ pub struct Glyph {
     /// Position and size of the glyph in the font texture, in texels.
     pub uv_rect: UvRect,
 
+    /// The [bidirectional embedding level](https://www.unicode.org/reports/tr9/#BD2) of this character.
+    ///
+    /// Even levels are left-to-right, odd levels are right-to-left.
+    pub bidi_level: u8,
+
     /// Index into [`LayoutJob::sections`]. Decides color etc.
     ///
     /// Only used during layout, then set to an invalid value in order to
@@ -716,6 +842,12 @@ impl Glyph {
         self.pos.x + self.advance_width
     }
 
+    /// Is this character part of right-to-left text?
+    #[inline]
+    pub fn is_rtl(&self) -> bool {
+        self.bidi_level % 2 == 1
+    }
+
     /// Same y range for all characters with the same [`TextFormat`].
     #[inline]
     pub fn logical_rect(&self) -> Rect {
@@ -746,6 +878,10 @@ impl Row {
     /// Closest char at the desired x coordinate in row-relative coordinates.
     /// Returns something in the range `[0, char_count_excluding_newline()]`.
     pub fn char_at(&self, desired_x: f32) -> usize {
+        if self.has_rtl() {
+            return self.bidi_char_at(desired_x);
+        }
+
         for (i, glyph) in self.glyphs.iter().enumerate() {
             if desired_x < glyph.logical_rect().center().x {
                 return i;
@@ -754,14 +890,83 @@ impl Row {
         self.char_count_excluding_newline()
     }
 
+    /// [`Self::char_at`] for rows where the glyphs are not in visual order.
+    fn bidi_char_at(&self, desired_x: f32) -> usize {
+        let distance = |glyph: &Glyph| {
+            if desired_x < glyph.pos.x {
+                glyph.pos.x - desired_x
+            } else {
+                (desired_x - glyph.max_x()).at_least(0.0)
+            }
+        };
+        let closest = self
+            .glyphs
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)));
+        let Some((i, glyph)) = closest else {
+            return 0;
+        };
+
+        // The cursor goes before the character when clicking on its leading half:
+        let on_left_half = desired_x < glyph.logical_rect().center().x;
+        if on_left_half == glyph.is_rtl() {
+            i + 1
+        } else {
+            i
+        }
+    }
+
+    /// The x position of the cursor before the character at `column`.
+    ///
+    /// For right-to-left text, that is the right side of the character.
     pub fn x_offset(&self, column: usize) -> f32 {
         if let Some(glyph) = self.glyphs.get(column) {
-            glyph.pos.x
+            if glyph.is_rtl() {
+                glyph.max_x()
+            } else {
+                glyph.pos.x
+            }
+        } else if let Some(last) = self.glyphs.last().filter(|glyph| glyph.is_rtl()) {
+            // At the end of right-to-left text:
+            last.pos.x
         } else {
             self.size.x
         }
     }
 
+    /// Does this row contain any right-to-left text?
+    ///
+    /// If so, the glyphs are not in visual (left-to-right) order.
+    #[inline]
+    pub fn has_rtl(&self) -> bool {
+        self.glyphs.iter().any(Glyph::is_rtl)
+    }
+
+    /// The horizontal ranges covered by the characters in the given column range,
+    /// from left to right.
+    ///
+    /// When mixing left-to-right and right-to-left text,
+    /// a range of characters can be split up into several pieces on screen.
+    pub fn x_ranges(&self, columns: Range<usize>) -> Vec<Rangef> {
+        let mut visual_order: Vec<(usize, &Glyph)> = self.glyphs.iter().enumerate().collect();
+        visual_order.sort_by(|(_, a), (_, b)| a.pos.x.total_cmp(&b.pos.x));
+
+        let mut x_ranges: Vec<Rangef> = vec![];
+        let mut prev_included = false;
+        for (column, glyph) in visual_order {
+            let included = columns.contains(&column);
+            if included {
+                match x_ranges.last_mut() {
+                    Some(x_range) if prev_included => x_range.max = glyph.max_x(),
+                    _ => x_ranges.push(Rangef::new(glyph.pos.x, glyph.max_x())),
+                }
+            }
+            prev_included = included;
+        }
+        x_ranges
+    }
+
     #[inline]
     pub fn height(&self) -> f32 {
         self.size.y
@@ -0,0 +1,120 @@
+use egui::{Easing, Id};
+use egui_kittest::Harness;
+
+const STEP: f32 = 1.0 / 60.0;
+
+#[derive(Default)]
+struct State {
+    on: bool,
+    target: f32,
+    easings: Vec<Easing>,
+    values: Vec<f32>,
+}
+
+fn harness(easings: Vec<Easing>) -> Harness<'static, State> {
+    let state = State {
+        easings,
+        ..Default::default()
+    };
+    Harness::builder().with_step_dt(STEP).build_ui_state(
+        |ui, state: &mut State| {
+            state.values = state
+                .easings
+                .iter()
+                .enumerate()
+                .map(|(i, easing)| {
+                    let id = Id::new(i);
+                    let openness = ui.ctx().animate_bool_eased(id, state.on, 0.5, *easing);
+                    let value =
+                        ui.ctx()
+                            .animate_value_eased(id.with("value"), state.target, 0.5, *easing);
+                    openness + value
+                })
+                .collect();
+        },
+        state,
+    )
+}
+
+#[test]
+fn curves_differ_mid_animation() {
+    let mut harness = harness(vec![Easing::EaseIn, Easing::Linear, Easing::EaseOut]);
+    harness.state_mut().on = true;
+    for _ in 0..10 {
+        harness.step();
+    }
+
+    let values = &harness.state().values;
+    assert!(
+        values[0] < values[1] && values[1] < values[2],
+        "Ease-in lags and ease-out leads: {values:?}"
+    );
+
+    for _ in 0..30 {
+        harness.step();
+    }
+    assert_eq!(harness.state().values, [1.0, 1.0, 1.0], "All done in time");
+}
+
+#[test]
+fn eased_values_retarget_from_where_they_are() {
+    let mut harness = harness(vec![Easing::EaseInOut]);
+    harness.state_mut().target = 10.0;
+    for _ in 0..15 {
+        harness.step();
+    }
+    let before = harness.state().values[0];
+    assert!(0.0 < before && before < 10.0, "Mid-animation: {before}");
+
+    harness.state_mut().target = 0.0;
+    harness.step();
+    let after = harness.state().values[0];
+    assert!((before - after).abs() < 1.0, "No jump: {before} -> {after}");
+
+    for _ in 0..60 {
+        harness.step();
+    }
+    assert_eq!(harness.state().values, [0.0]);
+}
+
+#[test]
+fn springs_overshoot_then_settle() {
+    let mut harness = harness(vec![Easing::Spring {
+        stiffness: 200.0,
+        damping: 10.0,
+    }]);
+    harness.state_mut().target = 100.0;
+
+    let mut max = 0.0_f32;
+    for _ in 0..60 {
+        harness.step();
+        max = max.max(harness.state().values[0]);
+    }
+    assert!(100.0 < max, "An underdamped spring overshoots: {max}");
+
+    for _ in 0..240 {
+        harness.step();
+    }
+    assert_eq!(
+        harness.state().values,
+        [100.0],
+        "Comes to rest on the target"
+    );
+}
+
+#[test]
+fn springs_keep_their_velocity_when_retargeted() {
+    let mut harness = harness(vec![Easing::SPRING]);
+    harness.state_mut().target = 100.0;
+    for _ in 0..5 {
+        harness.step();
+    }
+    let before = harness.state().values[0];
+
+    harness.state_mut().target = 0.0;
+    harness.step();
+    assert!(
+        before < harness.state().values[0],
+        "Still moving up after the target flips"
+    );
+}
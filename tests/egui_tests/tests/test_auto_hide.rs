@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use egui::containers::panel::AutoHide;
+use egui::{CentralPanel, Event, Key, Pos2, Rect, TopBottomPanel, pos2};
+use egui_kittest::Harness;
+
+/// Where the toolbar and the central panel are, and how often "Play" was clicked.
+#[derive(Default)]
+struct State {
+    toolbar: Option<Rect>,
+    play: Option<Rect>,
+    central: Option<Rect>,
+    played: usize,
+}
+
+fn harness() -> Harness<'static, State> {
+    let harness = Harness::builder().with_step_dt(0.1).build_state(
+        |ctx, state: &mut State| {
+            let toolbar = TopBottomPanel::top("toolbar")
+                .auto_hide(Some(AutoHide {
+                    delay: Duration::from_secs(1),
+                    reveal_margin: 8.0,
+                }))
+                .show(ctx, |ui| {
+                    let play = ui.button("Play");
+                    if play.clicked() {
+                        state.played += 1;
+                    }
+                    state.play = Some(play.rect);
+                });
+            state.toolbar = Some(toolbar.response.rect);
+            state.central = Some(CentralPanel::default().show(ctx, |_ui| {}).response.rect);
+        },
+        State::default(),
+    );
+    // The harness turns animations off:
+    harness.ctx.style_mut(|s| s.animation_time = 0.2);
+    harness
+}
+
+fn move_pointer(harness: &mut Harness<'_, State>, pos: Pos2) {
+    harness.input_mut().events.push(Event::PointerMoved(pos));
+}
+
+fn click(harness: &mut Harness<'_, State>, pos: Pos2) {
+    harness.click_at(pos);
+    harness.step();
+}
+
+fn toolbar_height(harness: &Harness<'_, State>) -> f32 {
+    harness.state().toolbar.unwrap().height()
+}
+
+fn wait(harness: &mut Harness<'_, State>, seconds: f32) {
+    harness.run_steps((seconds / 0.1).round() as usize);
+}
+
+#[test]
+fn toolbar_hides_when_the_pointer_is_away() {
+    let mut harness = harness();
+    harness.step();
+    let height = toolbar_height(&harness);
+    let play = harness.state().play.unwrap().center();
+    assert!(0.0 < height);
+
+    move_pointer(&mut harness, pos2(400.0, 300.0));
+    wait(&mut harness, 0.5);
+    assert_eq!(toolbar_height(&harness), height, "Not yet");
+
+    let heights: Vec<f32> = (0..10)
+        .map(|_| {
+            harness.step();
+            toolbar_height(&harness)
+        })
+        .collect();
+    assert!(
+        heights.iter().any(|&h| 0.0 < h && h < height),
+        "Slides away: {heights:?}"
+    );
+    assert_eq!(toolbar_height(&harness), 0.0);
+    assert_eq!(
+        harness.state().central.unwrap().top(),
+        0.0,
+        "The central panel gets the space"
+    );
+
+    click(&mut harness, play);
+    assert_eq!(
+        harness.state().played,
+        0,
+        "A hidden toolbar can't be clicked"
+    );
+}
+
+#[test]
+fn toolbar_reveals_at_the_screen_edge() {
+    let mut harness = harness();
+    harness.step();
+    let height = toolbar_height(&harness);
+    let play = harness.state().play.unwrap().center();
+    move_pointer(&mut harness, pos2(400.0, 300.0));
+    wait(&mut harness, 2.0);
+    assert_eq!(toolbar_height(&harness), 0.0);
+
+    move_pointer(&mut harness, pos2(400.0, 2.0));
+    wait(&mut harness, 0.5);
+    assert_eq!(toolbar_height(&harness), height);
+    assert_eq!(harness.state().central.unwrap().top(), height);
+
+    // Staying over the toolbar keeps it shown:
+    click(&mut harness, play);
+    wait(&mut harness, 2.0);
+    assert_eq!(harness.state().played, 1);
+    assert_eq!(toolbar_height(&harness), height);
+}
+
+#[test]
+fn keyboard_focus_reveals_the_toolbar() {
+    let mut harness = harness();
+    harness.step();
+    let height = toolbar_height(&harness);
+    move_pointer(&mut harness, pos2(400.0, 300.0));
+    wait(&mut harness, 2.0);
+    assert_eq!(toolbar_height(&harness), 0.0);
+
+    harness.key_press(Key::Tab);
+    wait(&mut harness, 0.5);
+    assert_eq!(toolbar_height(&harness), height);
+
+    harness.key_press(Key::Enter);
+    harness.step();
+    assert_eq!(harness.state().played, 1);
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use egui::{Button, Color32, ColorImage, Event, Key, Modifiers, PointerButton, Rect, vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// The number of clicks, and where the button is.
+type State = (usize, Rect);
+
+fn repeat_harness() -> Harness<'static, State> {
+    Harness::builder().with_step_dt(0.1).build_ui_state(
+        |ui, (clicks, rect): &mut State| {
+            let response = ui.add(
+                Button::new("+")
+                    .auto_repeat(true)
+                    .repeat_delay(0.35)
+                    .repeat_interval(0.15),
+            );
+            *clicks += response.clicked() as usize;
+            *rect = response.rect;
+        },
+        (0, Rect::NOTHING),
+    )
+}
+
+fn step_times(harness: &mut Harness<'_, State>, steps: usize) {
+    for _ in 0..steps {
+        harness.step();
+    }
+}
+
+#[test]
+fn holding_a_button_repeats_its_clicks() {
+    let mut harness = repeat_harness();
+    harness.step();
+    let center = harness.state().1.center();
+
+    harness.pointer_down(center, PointerButton::Primary);
+    harness.step();
+    assert_eq!(harness.state().0, 1, "The press clicks right away");
+    assert!(
+        harness.ctx.has_requested_repaint(),
+        "A held button needs to keep running"
+    );
+
+    step_times(&mut harness, 3);
+    assert_eq!(harness.state().0, 1, "Nothing during the delay");
+
+    step_times(&mut harness, 6);
+    assert_eq!(
+        harness.state().0,
+        4,
+        "One click every interval after the delay"
+    );
+
+    // Long after the press would have been too long for a click:
+    step_times(&mut harness, 10);
+    assert_eq!(harness.state().0, 9);
+
+    harness.pointer_up(center, PointerButton::Primary);
+    harness.step();
+    assert_eq!(harness.state().0, 9, "Releasing doesn't click again");
+    step_times(&mut harness, 4);
+    assert_eq!(harness.state().0, 9);
+}
+
+#[test]
+fn dragging_off_a_held_button_stops_repeating() {
+    let mut harness = repeat_harness();
+    harness.step();
+    let rect = harness.state().1;
+
+    harness.pointer_down(rect.center(), PointerButton::Primary);
+    harness.step();
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(rect.right_bottom() + vec2(50.0, 50.0)));
+    step_times(&mut harness, 10);
+    assert_eq!(harness.state().0, 1);
+}
+
+#[test]
+fn holding_space_on_a_focused_button_repeats() {
+    let mut harness = repeat_harness();
+    harness.get_by_label("+").focus();
+    harness.step();
+
+    harness.input_mut().events.push(Event::Key {
+        key: Key::Space,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    });
+    harness.step();
+    assert_eq!(harness.state().0, 1);
+
+    step_times(&mut harness, 9);
+    assert_eq!(harness.state().0, 4);
+    harness.input_mut().events.push(Event::Key {
+        key: Key::Space,
+        physical_key: None,
+        pressed: false,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    });
+    step_times(&mut harness, 4);
+    assert_eq!(harness.state().0, 4);
+}
+
+#[test]
+fn buttons_without_auto_repeat_click_on_release() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (clicks, rect): &mut State| {
+            let response = ui.button("Ok");
+            *clicks += response.clicked() as usize;
+            *rect = response.rect;
+        },
+        (0, Rect::NOTHING),
+    );
+    harness.step();
+    let center = harness.state().1.center();
+
+    harness.pointer_down(center, PointerButton::Primary);
+    harness.step();
+    assert_eq!(harness.state().0, 0);
+
+    harness.pointer_up(center, PointerButton::Primary);
+    harness.step();
+    assert_eq!(harness.state().0, 1);
+}
+
+#[test]
+fn focused_button_receives_pasted_images() {
+    let mut harness = Harness::new_ui_state(
+        |ui, pasted: &mut Vec<[usize; 2]>| {
+            for label in ["Image field", "Other"] {
+                let response = ui.button(label);
+                if let Some(image) = response.pasted_image() {
+                    pasted.push(image.size);
+                }
+            }
+        },
+        vec![],
+    );
+    let image = Arc::new(ColorImage::filled([4, 3], Color32::RED));
+    harness
+        .input_mut()
+        .events
+        .push(Event::PasteImage(image.clone()));
+    harness.step();
+    assert!(harness.state().is_empty(), "Nothing has focus");
+
+    harness.get_by_label("Image field").focus();
+    harness.step();
+    harness.input_mut().events.push(Event::PasteImage(image));
+    harness.step();
+    assert_eq!(harness.state(), &[[4, 3]]);
+}
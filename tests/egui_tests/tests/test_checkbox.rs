@@ -0,0 +1,66 @@
+use egui::Checkbox;
+use egui::accesskit::Toggled;
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+struct State {
+    checked: Option<bool>,
+    cycle_indeterminate: bool,
+    changes: usize,
+}
+
+fn tri_state_harness(checked: Option<bool>, cycle_indeterminate: bool) -> Harness<'static, State> {
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let response = ui.add(
+                Checkbox::tri_state(&mut state.checked, "All")
+                    .cycle_indeterminate(state.cycle_indeterminate),
+            );
+            if response.changed() {
+                state.changes += 1;
+            }
+        },
+        State {
+            checked,
+            cycle_indeterminate,
+            changes: 0,
+        },
+    )
+}
+
+fn click(harness: &mut Harness<'_, State>) -> Option<Toggled> {
+    harness.get_by_label("All").click();
+    harness.run();
+    harness.get_by_label("All").accesskit_node().toggled()
+}
+
+#[test]
+fn tri_state_checkbox_leaves_indeterminate_on_click() {
+    let mut harness = tri_state_harness(None, false);
+    assert_eq!(
+        harness.get_by_label("All").accesskit_node().toggled(),
+        Some(Toggled::Mixed)
+    );
+
+    assert_eq!(click(&mut harness), Some(Toggled::True));
+    assert_eq!(harness.state().checked, Some(true));
+    assert_eq!(
+        harness.state().changes,
+        1,
+        "Leaving indeterminate is a change"
+    );
+
+    assert_eq!(click(&mut harness), Some(Toggled::False));
+    assert_eq!(click(&mut harness), Some(Toggled::True));
+    assert_eq!(harness.state().changes, 3, "Every click is a change");
+}
+
+#[test]
+fn tri_state_checkbox_can_cycle_through_indeterminate() {
+    let mut harness = tri_state_harness(Some(true), true);
+
+    assert_eq!(click(&mut harness), Some(Toggled::False));
+    assert_eq!(click(&mut harness), Some(Toggled::Mixed));
+    assert_eq!(harness.state().checked, None);
+    assert_eq!(click(&mut harness), Some(Toggled::True));
+}
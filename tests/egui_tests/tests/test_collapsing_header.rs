@@ -0,0 +1,164 @@
+use egui::collapsing_header::CollapsingState;
+use egui::{Id, Ui};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[derive(Default)]
+struct State {
+    open: Option<bool>,
+    all_open: bool,
+}
+
+fn tree_id() -> Id {
+    Id::new("tree")
+}
+
+fn tree(ui: &mut Ui) {
+    ui.collapsing("Video", |ui| {
+        ui.collapsing("Advanced", |ui| ui.label("Video details"));
+    });
+    ui.collapsing("Audio", |ui| {
+        ui.collapsing("Devices", |ui| ui.label("Audio details"));
+    });
+}
+
+fn tree_harness() -> Harness<'static, State> {
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            if let Some(open) = state.open.take() {
+                CollapsingState::set_open_recursive(ui.ctx(), tree_id(), open);
+            }
+            state.all_open = CollapsingState::is_open_recursive(ui.ctx(), tree_id());
+            CollapsingState::show_scope(ui, tree_id(), tree);
+        },
+        State::default(),
+    )
+}
+
+fn is_shown(harness: &Harness<'_, State>, label: &str) -> bool {
+    harness.query_by_label(label).is_some()
+}
+
+#[test]
+fn expand_and_collapse_all() {
+    let mut harness = tree_harness();
+    harness.run();
+    assert!(!is_shown(&harness, "Advanced"));
+    assert!(!harness.state().all_open);
+
+    harness.state_mut().open = Some(true);
+    harness.run();
+    assert!(
+        is_shown(&harness, "Video details") && is_shown(&harness, "Audio details"),
+        "Headers that were never shown are opened too"
+    );
+    assert!(harness.state().all_open);
+
+    harness.state_mut().open = Some(false);
+    harness.run();
+    assert!(!is_shown(&harness, "Advanced"));
+    assert!(!is_shown(&harness, "Devices"));
+    assert!(!harness.state().all_open);
+
+    // The inner headers were closed too:
+    harness.get_by_label("Video").click();
+    harness.run();
+    assert!(is_shown(&harness, "Advanced"));
+    assert!(!is_shown(&harness, "Video details"));
+}
+
+#[test]
+fn headers_can_be_toggled_after_expand_all() {
+    let mut harness = tree_harness();
+    harness.state_mut().open = Some(true);
+    harness.run();
+
+    harness.get_by_label("Advanced").click();
+    harness.run();
+    assert!(!is_shown(&harness, "Video details"));
+    assert!(is_shown(&harness, "Audio details"));
+    assert!(!harness.state().all_open);
+}
+
+#[test]
+fn a_header_is_a_scope_for_its_body() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (open, video): &mut (Option<bool>, Id)| {
+            if let Some(open) = open.take() {
+                CollapsingState::set_open_recursive(ui.ctx(), *video, open);
+            }
+            *video = ui
+                .collapsing("Video", |ui| {
+                    ui.collapsing("Advanced", |ui| ui.label("Video details"));
+                })
+                .header_response
+                .id;
+            ui.collapsing("Audio", |ui| {
+                ui.collapsing("Devices", |ui| ui.label("Audio details"));
+            });
+        },
+        (None, Id::NULL),
+    );
+    harness.run();
+
+    harness.state_mut().0 = Some(true);
+    harness.run();
+    assert!(harness.query_by_label("Video details").is_some());
+    assert!(
+        harness.query_by_label("Devices").is_none(),
+        "Other headers are left alone"
+    );
+}
+
+/// Openness of the header, and the top of the label below it, for each pass.
+type Frames = Vec<(f32, f32)>;
+
+fn animated_harness(header: fn() -> egui::CollapsingHeader) -> Harness<'static, Frames> {
+    Harness::builder().with_step_dt(0.1).build_ui_state(
+        move |ui, frames: &mut Frames| {
+            let response = header().show(ui, |ui| {
+                for i in 0..5 {
+                    ui.label(format!("Setting {i}"));
+                }
+            });
+            let below = ui.label("Below");
+            frames.push((response.openness, below.rect.top()));
+        },
+        Frames::new(),
+    )
+}
+
+#[test]
+fn animation_time_slides_the_body_open() {
+    let mut harness =
+        animated_harness(|| egui::CollapsingHeader::new("Settings").animation_time(0.4));
+    harness.get_by_label("Settings").click();
+    harness.state_mut().clear();
+    for _ in 0..6 {
+        harness.step();
+    }
+
+    let frames = harness.state().clone();
+    let openness: Vec<f32> = frames.iter().map(|(openness, _)| *openness).collect();
+    assert!(
+        openness.windows(2).all(|w| w[0] <= w[1]),
+        "Only opens: {openness:?}"
+    );
+    assert!(
+        openness.iter().filter(|&&o| 0.0 < o && o < 1.0).count() == 3,
+        "Takes 0.4 s at a steady pace: {openness:?}"
+    );
+    assert!(
+        frames.windows(2).all(|w| w[0].1 <= w[1].1),
+        "The body never jumps back: {frames:?}"
+    );
+    assert!(harness.query_by_label("Setting 4").is_some());
+}
+
+#[test]
+fn unanimated_header_opens_right_away() {
+    let mut harness = animated_harness(|| egui::CollapsingHeader::new("Settings").animated(false));
+    harness.get_by_label("Settings").click();
+    harness.step();
+    assert_eq!(harness.state().last().unwrap().0, 1.0);
+}
@@ -0,0 +1,333 @@
+use egui::color_picker::{
+    Alpha, color_edit_button_srgba_with_palette, color_picker_color32, recent_colors,
+};
+use std::sync::Arc;
+
+use egui::{
+    Color32, ColorImage, Event, Key, Modifiers, OutputCommand, Pos2, UserData, ViewportCommand,
+    ViewportId, accesskit::Role, pos2,
+};
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+fn color_picker_harness(color: Color32, alpha: Alpha) -> Harness<'static, Color32> {
+    Harness::new_ui_state(
+        move |ui, color: &mut Color32| {
+            color_picker_color32(ui, color, alpha);
+        },
+        color,
+    )
+}
+
+fn hex_text(harness: &Harness<'_, Color32>) -> String {
+    harness
+        .get_by_role(Role::TextInput)
+        .accesskit_node()
+        .value()
+        .unwrap_or_default()
+}
+
+/// Replace all of the text of the hex field.
+fn type_hex(harness: &mut Harness<'_, Color32>, text: &str) {
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::A);
+    harness.run();
+    harness
+        .input_mut()
+        .events
+        .push(Event::Text(text.to_owned()));
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+}
+
+/// Replace all of the text of the hex field with pasted text.
+fn paste_hex(harness: &mut Harness<'_, Color32>, text: &str) {
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::A);
+    harness.run();
+    harness
+        .input_mut()
+        .events
+        .push(Event::Paste(text.to_owned()));
+    harness.run();
+}
+
+fn copied_text(harness: &Harness<'_, Color32>) -> Vec<String> {
+    harness
+        .output()
+        .platform_output
+        .commands
+        .iter()
+        .filter_map(|command| match command {
+            OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn hex_field_shows_color() {
+    let harness = color_picker_harness(Color32::from_rgb(0x33, 0x66, 0x99), Alpha::OnlyBlend);
+    assert_eq!(hex_text(&harness), "#336699ff");
+
+    let harness = color_picker_harness(Color32::from_rgb(0x33, 0x66, 0x99), Alpha::Opaque);
+    assert_eq!(hex_text(&harness), "#336699");
+}
+
+#[test]
+fn hex_field_round_trips() {
+    let mut harness = color_picker_harness(Color32::WHITE, Alpha::OnlyBlend);
+
+    type_hex(&mut harness, "#12ab34cc");
+    assert_eq!(
+        *harness.state(),
+        Color32::from_rgba_unmultiplied(0x12, 0xab, 0x34, 0xcc)
+    );
+    assert_eq!(
+        hex_text(&harness),
+        "#12ab34cc",
+        "The color should not drift"
+    );
+
+    harness.run_steps(10);
+    assert_eq!(
+        hex_text(&harness),
+        "#12ab34cc",
+        "The color should not drift"
+    );
+}
+
+#[test]
+fn hex_field_ignores_invalid_text() {
+    let mut harness = color_picker_harness(Color32::RED, Alpha::OnlyBlend);
+
+    type_hex(&mut harness, "#12ab3");
+    assert_eq!(*harness.state(), Color32::RED);
+    assert_eq!(hex_text(&harness), "#ff0000ff", "Reverts when done editing");
+}
+
+#[test]
+fn oklch_mode_keeps_color() {
+    let color = Color32::from_rgba_unmultiplied(0x12, 0xab, 0x34, 0xcc);
+    let mut harness = color_picker_harness(color, Alpha::OnlyBlend);
+
+    harness.get_by_label("OKLCH").click();
+    harness.run();
+
+    harness.run_steps(10);
+    assert_eq!(
+        *harness.state(),
+        color,
+        "Switching mode should not change the color"
+    );
+}
+
+#[test]
+fn hex_field_updates_live_on_paste() {
+    let mut harness = color_picker_harness(Color32::WHITE, Alpha::OnlyBlend);
+
+    paste_hex(&mut harness, "12ab34");
+    assert_eq!(
+        *harness.state(),
+        Color32::from_rgb(0x12, 0xab, 0x34),
+        "Applied while still focused, without the #"
+    );
+
+    paste_hex(&mut harness, "#f80");
+    assert_eq!(*harness.state(), Color32::from_rgb(0xff, 0x88, 0x00));
+}
+
+#[test]
+fn opaque_hex_field_ignores_pasted_alpha() {
+    let mut harness = color_picker_harness(Color32::WHITE, Alpha::Opaque);
+
+    paste_hex(&mut harness, "#12ab3480");
+    assert_eq!(*harness.state(), Color32::from_rgb(0x12, 0xab, 0x34));
+
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(hex_text(&harness), "#12ab34");
+}
+
+#[test]
+fn hex_field_copies_the_color() {
+    let mut harness = color_picker_harness(Color32::from_rgb(0x33, 0x66, 0x99), Alpha::OnlyBlend);
+
+    harness.get_by_label("🗐").click();
+    harness.step();
+    assert_eq!(copied_text(&harness), ["#336699ff"]);
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.input_mut().events.push(Event::Copy);
+    harness.step();
+    assert_eq!(
+        copied_text(&harness),
+        ["#336699ff"],
+        "Copy with nothing selected copies everything"
+    );
+}
+
+const PALETTE: [Color32; 2] = [
+    Color32::from_rgb(0xff, 0x88, 0x00),
+    Color32::from_rgb(0x00, 0x88, 0xff),
+];
+
+fn color_button_harness(color: Color32) -> Harness<'static, Color32> {
+    Harness::new_ui_state(
+        |ui, color: &mut Color32| {
+            color_edit_button_srgba_with_palette(ui, color, Alpha::OnlyBlend, &PALETTE);
+        },
+        color,
+    )
+}
+
+fn open_popup(harness: &mut Harness<'_, Color32>) {
+    harness.get_by_role(Role::ColorWell).click();
+    harness.run();
+}
+
+fn close_popup(harness: &mut Harness<'_, Color32>) {
+    harness.key_press(Key::Escape);
+    harness.run();
+}
+
+#[test]
+fn palette_swatches_pick_right_away() {
+    let mut harness = color_button_harness(Color32::WHITE);
+    open_popup(&mut harness);
+
+    harness.get_by_label("#ff8800ff").click();
+    harness.run();
+    assert_eq!(*harness.state(), PALETTE[0]);
+    assert_eq!(hex_text(&harness), "#ff8800ff");
+}
+
+#[test]
+fn recent_colors_are_recorded_when_the_popup_closes() {
+    let mut harness = color_button_harness(Color32::WHITE);
+
+    open_popup(&mut harness);
+    type_hex(&mut harness, "#123456");
+    type_hex(&mut harness, "#654321");
+    assert!(
+        recent_colors(&harness.ctx).is_empty(),
+        "Nothing is recorded while editing"
+    );
+    close_popup(&mut harness);
+    assert_eq!(
+        recent_colors(&harness.ctx),
+        [Color32::from_rgb(0x65, 0x43, 0x21)]
+    );
+
+    open_popup(&mut harness);
+    close_popup(&mut harness);
+    assert_eq!(
+        recent_colors(&harness.ctx).len(),
+        1,
+        "Nothing changed, so nothing is recorded"
+    );
+
+    open_popup(&mut harness);
+    harness.get_by_label("#ff8800ff").click();
+    harness.run();
+    close_popup(&mut harness);
+    open_popup(&mut harness);
+    harness.get_by_label("#654321ff").click();
+    harness.run();
+    close_popup(&mut harness);
+    assert_eq!(
+        recent_colors(&harness.ctx),
+        [Color32::from_rgb(0x65, 0x43, 0x21), PALETTE[0]],
+        "Most recent first, without duplicates"
+    );
+}
+
+/// A screenshot that is red on the left and blue on the right of `x = 240` pixels.
+fn hidpi_screenshot() -> Event {
+    let size = [800, 600];
+    let pixels = (0..size[0] * size[1])
+        .map(|i| {
+            if i % size[0] < 240 {
+                Color32::RED
+            } else {
+                Color32::BLUE
+            }
+        })
+        .collect();
+    Event::Screenshot {
+        viewport_id: ViewportId::ROOT,
+        user_data: UserData::default(),
+        image: Arc::new(ColorImage::new(size, pixels)),
+    }
+}
+
+fn eyedropper_harness() -> Harness<'static, Color32> {
+    let mut harness = Harness::new_ui_state(
+        |ui, color: &mut Color32| {
+            color_edit_button_srgba_with_palette(ui, color, Alpha::Opaque, &[]);
+        },
+        Color32::WHITE,
+    );
+    open_popup(&mut harness);
+    harness.get_by_label("🔍").click();
+    harness.step();
+    harness.step();
+    assert!(
+        harness.output().viewport_output[&ViewportId::ROOT]
+            .commands
+            .iter()
+            .any(|command| matches!(command, ViewportCommand::Screenshot(_))),
+        "The eyedropper asks for a screenshot"
+    );
+
+    // Clicking nodes doesn't account for this, so we only zoom in now:
+    harness.set_pixels_per_point(2.0);
+    harness.input_mut().events.push(hidpi_screenshot());
+    harness.step();
+    harness
+}
+
+fn click_at(harness: &mut Harness<'_, Color32>, pos: Pos2) {
+    harness.click_at(pos);
+    // The eyedropper keeps asking for screenshots, so we can't wait for it to settle:
+    harness.run_steps(2);
+}
+
+#[test]
+fn eyedropper_picks_the_pixel_under_the_pointer() {
+    let mut harness = eyedropper_harness();
+
+    // 150 points is 300 pixels, which is on the blue side:
+    click_at(&mut harness, pos2(150.0, 100.0));
+    assert_eq!(*harness.state(), Color32::BLUE);
+    assert!(
+        harness.query_by_label("🔍").is_some(),
+        "The popup stays open"
+    );
+
+    click_at(&mut harness, pos2(380.0, 280.0));
+    assert_eq!(*harness.state(), Color32::BLUE);
+    assert!(
+        harness.query_by_label("🔍").is_none(),
+        "The eyedropper is done after one pick, so this click closes the popup"
+    );
+}
+
+#[test]
+fn escape_cancels_the_eyedropper() {
+    let mut harness = eyedropper_harness();
+
+    harness.key_press(Key::Escape);
+    harness.run_steps(2);
+    assert!(
+        harness.query_by_label("🔍").is_some(),
+        "Only the eyedropper is closed, not the popup"
+    );
+
+    click_at(&mut harness, pos2(380.0, 280.0));
+    assert_eq!(*harness.state(), Color32::WHITE);
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use egui::{ComboBox, Key, accesskit::Role};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+const FRUITS: [&str; 5] = ["Apple", "Banana", "Cherry", "Grape", "Pineapple"];
+
+fn fruit_harness() -> Harness<'static, usize> {
+    Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            ComboBox::from_label("Fruit").searchable(true).show_index(
+                ui,
+                selected,
+                FRUITS.len(),
+                |i| FRUITS[i],
+            );
+        },
+        0,
+    )
+}
+
+#[test]
+fn searchable_combo_box_filters_and_selects_with_keyboard() {
+    let mut harness = fruit_harness();
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+
+    // The search field is focused when the popup opens:
+    harness.get_by_role(Role::TextInput).type_text("APP");
+    harness.run();
+    assert!(harness.query_by_label("Banana").is_none());
+    assert!(harness.query_by_label("Pineapple").is_some());
+
+    harness.key_press(Key::ArrowDown);
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+
+    assert_eq!(*harness.state(), 4);
+    assert!(
+        harness.query_by_role(Role::TextInput).is_none(),
+        "Popup closed"
+    );
+}
+
+#[test]
+fn searchable_combo_box_escape_keeps_selection_and_resets_search() {
+    let mut harness = fruit_harness();
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).type_text("grape");
+    harness.run();
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert_eq!(*harness.state(), 0);
+    assert!(
+        harness.query_by_role(Role::TextInput).is_none(),
+        "Popup closed"
+    );
+
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+    assert!(harness.query_by_label("Banana").is_some());
+}
+
+#[test]
+fn searchable_combo_box_with_selectable_value() {
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut &str| {
+            ComboBox::from_label("Fruit")
+                .selected_text(*selected)
+                .searchable(true)
+                .show_filtered(ui, |ui, filter| {
+                    for fruit in FRUITS {
+                        filter.selectable_value(ui, selected, fruit, fruit);
+                    }
+                });
+        },
+        "Apple",
+    );
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).type_text("err");
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+
+    assert_eq!(*harness.state(), "Cherry");
+}
+
+fn multi_harness() -> Harness<'static, (HashSet<usize>, usize)> {
+    Harness::new_ui_state(
+        |ui, (selected, num_changes): &mut (HashSet<usize>, usize)| {
+            let response =
+                ComboBox::from_label("Fruits")
+                    .show_multi_index(ui, selected, FRUITS.len(), |i| FRUITS[i]);
+            if response.changed() {
+                *num_changes += 1;
+            }
+        },
+        (HashSet::new(), 0),
+    )
+}
+
+#[test]
+fn multi_combo_box_toggles_without_closing() {
+    let mut harness = multi_harness();
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruits")
+        .click();
+    harness.run();
+
+    harness.get_by_label("Banana").click();
+    harness.run();
+    harness.get_by_label("Grape").click();
+    harness.run();
+    assert!(
+        harness.query_by_label("Apple").is_some(),
+        "Popup still open"
+    );
+    assert_eq!(harness.state().0, HashSet::from([1, 3]));
+    assert_eq!(harness.state().1, 2);
+
+    harness.get_by_label("Clear all").click();
+    harness.run();
+    assert!(harness.state().0.is_empty());
+    assert_eq!(harness.state().1, 3);
+
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert!(harness.query_by_label("Apple").is_none(), "Popup closed");
+    assert_eq!(harness.state().1, 3);
+}
+
+#[test]
+fn multi_combo_box_keyboard() {
+    let mut harness = multi_harness();
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruits")
+        .click();
+    harness.run();
+
+    // The first item is focused when the popup opens:
+    harness.key_press(Key::Space);
+    harness.run();
+    harness.key_press(Key::ArrowDown);
+    harness.run();
+    harness.key_press(Key::Space);
+    harness.run();
+    assert_eq!(harness.state().0, HashSet::from([0, 1]));
+    assert_eq!(harness.state().1, 2);
+}
+
+#[test]
+fn combo_box_is_navigated_with_the_keyboard() {
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            ComboBox::from_label("Fruit").show_index(ui, selected, 3, |i| FRUITS[i]);
+        },
+        0,
+    );
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .focus();
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+    harness.key_press(Key::ArrowDown);
+    harness.run();
+    harness.key_press(Key::ArrowDown);
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+
+    assert_eq!(*harness.state(), 2);
+    assert!(harness.query_by_label("Banana").is_none(), "Closed");
+}
@@ -0,0 +1,131 @@
+use egui::{Id, PointerButton, Pos2, Rect, ScrollArea, Ui, Vec2, pos2, vec2};
+use egui_kittest::Harness;
+
+/// The items, where they were shown, and the last move.
+#[derive(Default)]
+struct List {
+    items: Vec<usize>,
+    rects: Vec<Rect>,
+    moved: Option<(usize, usize)>,
+}
+
+fn show_list(ui: &mut Ui, list: &mut List) {
+    list.rects.clear();
+    let List {
+        items,
+        rects,
+        moved,
+    } = list;
+    let result = ui.dnd_reorderable_list(Id::new("list"), items.len(), |ui, i| {
+        rects.push(ui.label(format!("Item {}", items[i])).rect);
+    });
+    if let Some((from, to)) = result {
+        let item = items.remove(from);
+        items.insert(to, item);
+        *moved = result;
+    }
+}
+
+/// Press at `from`, move to `to`, and wait there for `hold` frames.
+fn drag_and_hold<State>(harness: &mut Harness<'_, State>, from: Pos2, to: Pos2, hold: usize) {
+    harness.pointer_down(from, PointerButton::Primary);
+    harness.step();
+    harness.move_pointer(from, to);
+    harness.run_steps(hold);
+}
+
+fn release<State>(harness: &mut Harness<'_, State>, pos: Pos2) {
+    harness.pointer_up(pos, PointerButton::Primary);
+    harness.run();
+}
+
+fn list(len: usize) -> List {
+    List {
+        items: (0..len).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn dragging_moves_items() {
+    let mut harness = Harness::new_ui_state(show_list, list(4));
+    harness.run();
+
+    let rects = harness.state().rects.clone();
+    // Between the items 2 and 3:
+    let to = pos2(rects[0].center().x, rects[3].top());
+    drag_and_hold(&mut harness, rects[0].center(), to, 1);
+    release(&mut harness, to);
+    assert_eq!(harness.state().moved, Some((0, 2)));
+    assert_eq!(harness.state().items, [1, 2, 0, 3]);
+
+    let rects = harness.state().rects.clone();
+    let to = pos2(rects[0].center().x, rects[0].top());
+    drag_and_hold(&mut harness, rects[3].center(), to, 1);
+    release(&mut harness, to);
+    assert_eq!(harness.state().moved, Some((3, 0)));
+    assert_eq!(harness.state().items, [3, 1, 2, 0]);
+}
+
+#[test]
+fn horizontal_lists_move_items_sideways() {
+    let mut harness = Harness::new_ui_state(
+        |ui, list: &mut List| {
+            ui.horizontal(|ui| show_list(ui, list));
+        },
+        list(3),
+    );
+    harness.run();
+
+    let rects = harness.state().rects.clone();
+    let to = pos2(rects[2].right() + 2.0, rects[2].center().y);
+    drag_and_hold(&mut harness, rects[0].center(), to, 1);
+    release(&mut harness, to);
+    assert_eq!(harness.state().items, [1, 2, 0]);
+}
+
+#[test]
+fn dropping_outside_the_list_does_nothing() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(300.0, 300.0))
+        .build_ui_state(show_list, list(3));
+    harness.run();
+
+    let from = harness.state().rects[0].center();
+    let to = pos2(from.x, 280.0);
+    drag_and_hold(&mut harness, from, to, 1);
+    release(&mut harness, to);
+    assert_eq!(harness.state().moved, None);
+    assert_eq!(harness.state().items, [0, 1, 2]);
+}
+
+#[test]
+fn dragging_near_the_edge_scrolls() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(300.0, 300.0))
+        .with_step_dt(0.05)
+        .build_ui_state(
+            |ui, (list, offset): &mut (List, Vec2)| {
+                *offset = ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| show_list(ui, list))
+                    .state
+                    .offset;
+            },
+            (list(50), Vec2::ZERO),
+        );
+    harness.run();
+    assert_eq!(harness.state().1, Vec2::ZERO);
+
+    let from = harness.state().0.rects[0].center();
+    // Near the bottom of the scroll area:
+    let to = pos2(from.x, 195.0);
+    drag_and_hold(&mut harness, from, to, 10);
+    let scrolled = harness.state().1.y;
+    assert!(20.0 < scrolled, "Scrolled down by {scrolled}");
+
+    release(&mut harness, to);
+    let (from, to) = harness.state().0.moved.expect("Dropped in the list");
+    assert_eq!(from, 0);
+    assert!(10 < to, "Dropped further down the list, at {to}");
+}
@@ -0,0 +1,119 @@
+use egui::{Event, PointerButton, Pos2, Rect, Sense, Vec2, vec2};
+use egui_kittest::Harness;
+
+const BUTTONS: [PointerButton; 3] = [
+    PointerButton::Primary,
+    PointerButton::Secondary,
+    PointerButton::Middle,
+];
+
+#[derive(Default)]
+struct State {
+    rect: Option<Rect>,
+    started: Vec<PointerButton>,
+    stopped: Vec<PointerButton>,
+    dragged: Vec<PointerButton>,
+    delta: [Vec2; 3],
+}
+
+/// A canvas that records which buttons drag it.
+fn harness(sense: Sense) -> Harness<'static, State> {
+    // Short steps, so that holding still doesn't count as a long press:
+    Harness::builder().with_step_dt(0.02).build_ui_state(
+        move |ui, state: &mut State| {
+            let response = ui.allocate_response(vec2(200.0, 200.0), sense);
+            state.rect = Some(response.rect);
+            for (i, button) in BUTTONS.into_iter().enumerate() {
+                if response.drag_started_by(button) {
+                    state.started.push(button);
+                }
+                if response.drag_stopped_by(button) {
+                    state.stopped.push(button);
+                }
+                if response.dragged_by(button) && !state.dragged.contains(&button) {
+                    state.dragged.push(button);
+                }
+                state.delta[i] += response.drag_delta_by(button);
+            }
+        },
+        State::default(),
+    )
+}
+
+fn drag(harness: &mut Harness<'_, State>, button: PointerButton, delta: Vec2) {
+    let start = harness.state().rect.unwrap().center();
+    harness.drag_button(button, start, start + delta);
+    harness.step();
+    harness.step();
+}
+
+#[test]
+fn drags_are_told_apart_by_button() {
+    for (i, button) in BUTTONS.into_iter().enumerate() {
+        let mut harness = harness(Sense::click_and_drag());
+        drag(&mut harness, button, vec2(40.0, 20.0));
+
+        let state = harness.state();
+        assert_eq!(state.started, [button], "{button:?}");
+        assert_eq!(state.stopped, [button], "{button:?}");
+        assert_eq!(state.dragged, [button], "{button:?}");
+        for (j, delta) in state.delta.iter().enumerate() {
+            let expected = if i == j { vec2(40.0, 20.0) } else { Vec2::ZERO };
+            assert_eq!(*delta, expected, "{button:?} drag, {:?} delta", BUTTONS[j]);
+        }
+    }
+}
+
+#[test]
+fn drag_only_sense() {
+    for button in BUTTONS {
+        let mut harness = harness(Sense::drag());
+        drag(&mut harness, button, vec2(0.0, 30.0));
+
+        let state = harness.state();
+        assert_eq!(state.started, [button], "{button:?}");
+        assert_eq!(state.stopped, [button], "{button:?}");
+        assert_eq!(state.dragged, [button], "{button:?}");
+    }
+}
+
+#[test]
+fn no_drag_without_moving() {
+    let mut harness = harness(Sense::click_and_drag());
+    drag(&mut harness, PointerButton::Middle, Vec2::ZERO);
+    assert!(harness.state().dragged.is_empty());
+    assert_eq!(harness.state().delta, [Vec2::ZERO; 3]);
+}
+
+#[test]
+fn slow_drags_snap_to_the_grid() {
+    let mut harness = Harness::builder().with_step_dt(0.02).build_ui_state(
+        |ui, pos: &mut Pos2| {
+            let rect = Rect::from_min_size(*pos, vec2(40.0, 40.0));
+            let response = ui.interact(rect, ui.id().with("node"), Sense::drag());
+            *pos += response.drag_delta_snapped(*pos, vec2(10.0, 10.0), Vec2::ZERO);
+        },
+        Pos2::new(20.0, 20.0),
+    );
+
+    let start = Pos2::new(30.0, 30.0);
+    harness.pointer_down(start, PointerButton::Primary);
+    harness.step();
+    let mut positions = Vec::new();
+    for step in 1..=12 {
+        let pointer = start + vec2(3.0, 1.0) * step as f32;
+        harness
+            .input_mut()
+            .events
+            .push(Event::PointerMoved(pointer));
+        harness.step();
+        positions.push(*harness.state());
+    }
+
+    for pos in &positions {
+        assert_eq!(pos.x % 10.0, 0.0, "{pos:?} is on the grid");
+        assert_eq!(pos.y % 10.0, 0.0, "{pos:?} is on the grid");
+    }
+    // Moved 36 by 12 in steps much smaller than the grid:
+    assert_eq!(*harness.state(), Pos2::new(60.0, 30.0));
+}
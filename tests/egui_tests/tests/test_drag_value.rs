@@ -0,0 +1,175 @@
+use egui::{DragValue, Event, Key, accesskit::Role, style::NumberFormatter};
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+const UNITS: [(&str, f64); 3] = [("m", 1.0), ("km", 1000.0), ("cm", 0.01)];
+
+fn distance_harness(meters: f64) -> Harness<'static, f64> {
+    Harness::new_ui_state(
+        |ui, meters: &mut f64| {
+            ui.add(DragValue::new(meters).units(&UNITS, None));
+        },
+        meters,
+    )
+}
+
+/// Replace all of the text of the focused [`DragValue`].
+fn type_text(harness: &mut Harness<'_, f64>, text: &str) {
+    harness.key_press(Key::Tab);
+    harness.run();
+    // The text is selected when the text edit gains focus:
+    harness
+        .input_mut()
+        .events
+        .push(Event::Text(text.to_owned()));
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+}
+
+#[test]
+fn drag_value_shows_nicest_unit() {
+    let harness = distance_harness(1500.0);
+    harness.get_by_value("1.5 km");
+
+    let harness = distance_harness(0.25);
+    harness.get_by_value("25 cm");
+}
+
+#[test]
+fn drag_value_parses_units() {
+    let mut harness = distance_harness(1500.0);
+    type_text(&mut harness, "2km");
+    assert_eq!(*harness.state(), 2000.0);
+
+    type_text(&mut harness, "30 CM");
+    assert_eq!(*harness.state(), 0.3);
+}
+
+#[test]
+fn drag_value_rejects_unknown_units() {
+    let mut harness = distance_harness(1500.0);
+    type_text(&mut harness, "3 ft");
+    assert_eq!(*harness.state(), 1500.0);
+    assert!(harness.query_by_role(Role::TextInput).is_none());
+    harness.get_by_value("1.5 km");
+}
+
+fn balance_harness(balance: f64, number_formatter: NumberFormatter) -> Harness<'static, f64> {
+    Harness::new_ui_state(
+        move |ui, balance: &mut f64| {
+            ui.add(
+                DragValue::new(balance)
+                    .fixed_decimals(2)
+                    .number_formatter(number_formatter.clone()),
+            );
+        },
+        balance,
+    )
+}
+
+#[test]
+fn drag_value_groups_thousands() {
+    let harness = balance_harness(1234567.89, NumberFormatter::grouped(',', '.'));
+    harness.get_by_value("1,234,567.89");
+
+    let harness = balance_harness(-1234.5, NumberFormatter::grouped('.', ','));
+    harness.get_by_value("-1.234,50");
+
+    let harness = balance_harness(999.0, NumberFormatter::grouped(',', '.'));
+    harness.get_by_value("999.00");
+}
+
+#[test]
+fn drag_value_parses_what_it_shows() {
+    let mut harness = balance_harness(0.0, NumberFormatter::grouped('.', ','));
+    type_text(&mut harness, "1.234.567,89");
+    assert_eq!(*harness.state(), 1234567.89);
+
+    type_text(&mut harness, "42,5");
+    assert_eq!(*harness.state(), 42.5, "Grouping is optional");
+
+    type_text(&mut harness, "12,3,4");
+    assert_eq!(*harness.state(), 42.5, "Not a number");
+}
+
+#[test]
+fn style_number_formatter_parses_edits() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.ctx().style_mut(|style| {
+                style.number_formatter = NumberFormatter::grouped('\'', '.');
+            });
+            ui.add(DragValue::new(value).fixed_decimals(0));
+        },
+        1000.0,
+    );
+    harness.get_by_value("1'000");
+    type_text(&mut harness, "2'500");
+    assert_eq!(*harness.state(), 2500.0);
+}
+
+fn stepper_harness(quantity: i32) -> Harness<'static, i32> {
+    Harness::builder().with_step_dt(0.05).build_ui_state(
+        |ui, quantity: &mut i32| {
+            ui.add(DragValue::new(quantity).range(0..=10).with_buttons(true));
+        },
+        quantity,
+    )
+}
+
+#[test]
+fn drag_value_buttons_step_the_value() {
+    let mut harness = stepper_harness(5);
+    harness.get_by_label("+").click();
+    harness.run();
+    assert_eq!(*harness.state(), 6);
+    harness.get_by_value("6");
+
+    harness.get_by_label("−").click();
+    harness.run();
+    harness.get_by_label("−").click();
+    harness.run();
+    assert_eq!(*harness.state(), 4);
+
+    harness
+        .get_by_label("+")
+        .click_modifiers(egui::Modifiers::COMMAND);
+    harness.run();
+    assert_eq!(*harness.state(), 10, "Ten steps, clamped to the range");
+}
+
+#[test]
+fn drag_value_buttons_are_disabled_at_the_bounds() {
+    let mut harness = stepper_harness(10);
+    assert!(harness.get_by_label("+").accesskit_node().is_disabled());
+    assert!(!harness.get_by_label("−").accesskit_node().is_disabled());
+
+    harness.get_by_label("+").click();
+    harness.run();
+    assert_eq!(*harness.state(), 10);
+
+    *harness.state_mut() = 0;
+    harness.run();
+    assert!(harness.get_by_label("−").accesskit_node().is_disabled());
+}
+
+#[test]
+fn drag_value_buttons_repeat_while_held() {
+    let mut harness = stepper_harness(0);
+    let center = harness.get_by_label("+").rect().center();
+    harness.pointer_down(center, egui::PointerButton::Primary);
+    harness.step();
+    assert_eq!(*harness.state(), 1, "Steps right away");
+
+    for _ in 0..16 {
+        harness.step();
+    }
+    let held = *harness.state();
+    assert!(1 < held, "Repeats after a while: {held}");
+
+    harness.pointer_up(center, egui::PointerButton::Primary);
+    harness.step();
+    harness.step();
+    assert_eq!(*harness.state(), held, "Releasing doesn't step again");
+}
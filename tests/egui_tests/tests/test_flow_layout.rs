@@ -0,0 +1,70 @@
+use egui_extras::{FlowLayout, MasonryLayout};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[test]
+fn flow_layout_wraps_items() {
+    let harness = Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .build_ui(|ui| {
+            FlowLayout::new("tags").show(ui, |flow| {
+                for tag in ["first tag", "second tag", "third tag", "fourth tag"] {
+                    flow.item(|ui| ui.button(tag));
+                }
+            });
+        });
+
+    let first = harness.get_by_label("first tag").rect();
+    let second = harness.get_by_label("second tag").rect();
+    let third = harness.get_by_label("third tag").rect();
+    assert_eq!(first.min.y, second.min.y, "Both fit on the first row");
+    assert!(first.max.x < second.min.x);
+    assert!(second.max.y < third.min.y, "Wrapped to the next row");
+    assert_eq!(first.min.x, third.min.x);
+}
+
+#[test]
+fn masonry_layout_fills_shortest_column() {
+    let harness = Harness::builder()
+        .with_size(egui::vec2(300.0, 400.0))
+        .build_ui(|ui| {
+            MasonryLayout::new().columns(2).show(ui, |masonry| {
+                for (i, height) in [100.0, 20.0, 20.0, 20.0].into_iter().enumerate() {
+                    masonry.item(|ui| {
+                        ui.set_min_height(height);
+                        ui.label(format!("Card {i}"));
+                    });
+                }
+            });
+        });
+
+    let card = |i: usize| harness.get_by_label(&format!("Card {i}")).rect();
+    assert!(
+        card(0).min.x < card(1).min.x,
+        "Second card in second column"
+    );
+    // The first card is tall, so the rest all go into the second column:
+    assert_eq!(card(1).min.x, card(2).min.x);
+    assert_eq!(card(1).min.x, card(3).min.x);
+    assert!(card(2).min.y < card(3).min.y);
+}
+
+#[test]
+fn masonry_layout_survives_degenerate_widths() {
+    let mut harness = Harness::new_ui(|ui| {
+        // Zero column width and gap:
+        MasonryLayout::new()
+            .min_column_width(0.0)
+            .gap(egui::Vec2::ZERO)
+            .show(ui, |masonry| masonry.item(|ui| ui.label("Narrow")));
+
+        // Infinite available width:
+        egui::ScrollArea::horizontal().show(ui, |ui| {
+            ui.set_max_width(f32::INFINITY);
+            MasonryLayout::new().show(ui, |masonry| masonry.item(|ui| ui.label("Wide")));
+        });
+    });
+    harness.run();
+    harness.get_by_label("Narrow");
+    harness.get_by_label("Wide");
+}
@@ -0,0 +1,82 @@
+use egui::{Color32, Context, FontFamily, FontId, RawInput, epaint::text::FontPriority};
+
+fn ctx_after_pass() -> Context {
+    let ctx = Context::default();
+    run_pass(&ctx);
+    ctx
+}
+
+fn run_pass(ctx: &Context) {
+    let _ = ctx.run(RawInput::default(), |_ctx| {});
+}
+
+fn family_fonts(ctx: &Context, family: &FontFamily) -> Vec<String> {
+    ctx.fonts(|fonts| fonts.lock().fonts.definitions().families[family].clone())
+}
+
+#[test]
+fn removed_font_falls_back_to_remaining_fonts() {
+    let ctx = ctx_after_pass();
+    let name = "Ubuntu-Light";
+    assert!(family_fonts(&ctx, &FontFamily::Proportional).contains(&name.to_owned()));
+    assert!(family_fonts(&ctx, &FontFamily::Monospace).contains(&name.to_owned()));
+
+    ctx.remove_font(name);
+    run_pass(&ctx);
+
+    ctx.fonts(|fonts| {
+        let fonts = &fonts.lock().fonts;
+        let definitions = fonts.definitions();
+        assert!(!definitions.font_data.contains_key(name));
+        assert!(
+            definitions
+                .families
+                .values()
+                .all(|family| !family.contains(&name.to_owned()))
+        );
+    });
+
+    // Monospace still has its own primary font:
+    let missing = ctx.fonts(|fonts| fonts.missing_glyphs(&FontId::monospace(14.0), "abc"));
+    assert!(missing.is_empty(), "{missing:?}");
+}
+
+#[test]
+fn removing_all_fonts_of_a_family_does_not_panic() {
+    let ctx = ctx_after_pass();
+    for name in family_fonts(&ctx, &FontFamily::Monospace) {
+        ctx.remove_font(&name);
+    }
+    run_pass(&ctx);
+    assert!(family_fonts(&ctx, &FontFamily::Monospace).is_empty());
+
+    let galley = ctx.fonts(|fonts| {
+        fonts.layout_no_wrap("code".to_owned(), FontId::monospace(14.0), Color32::WHITE)
+    });
+    assert_eq!(galley.rows[0].glyphs.len(), 4);
+}
+
+#[test]
+fn font_priority_can_be_changed() {
+    let ctx = ctx_after_pass();
+    let fonts = family_fonts(&ctx, &FontFamily::Proportional);
+    let last = fonts.last().unwrap().clone();
+
+    ctx.set_font_priority(FontFamily::Proportional, &last, FontPriority::Highest);
+    run_pass(&ctx);
+    let reordered = family_fonts(&ctx, &FontFamily::Proportional);
+    assert_eq!(reordered[0], last);
+    assert_eq!(
+        reordered.len(),
+        fonts.len(),
+        "The font is moved, not duplicated"
+    );
+
+    ctx.set_font_priority(
+        FontFamily::Proportional,
+        "no such font",
+        FontPriority::Highest,
+    );
+    run_pass(&ctx);
+    assert_eq!(family_fonts(&ctx, &FontFamily::Proportional), reordered);
+}
@@ -0,0 +1,62 @@
+use egui::debug::{FramePhase, FrameTimeOverlay};
+use egui::{Key, Modifiers};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn harness(app: impl FnMut(&egui::Context) + 'static) -> Harness<'static> {
+    Harness::builder().with_step_dt(0.02).build(app)
+}
+
+#[test]
+fn shows_frame_rate() {
+    let mut harness = harness(egui::debug::frame_time_overlay);
+    harness.run_steps(5);
+    assert!(harness.query_by_label("50.0 fps").is_some());
+    assert!(harness.query_by_label("max 20.00 ms").is_some());
+}
+
+#[test]
+fn shows_reported_phases() {
+    let mut harness = harness(|ctx| {
+        egui::debug::report_phase(ctx, FramePhase::Tessellation, 0.003);
+        egui::debug::frame_time_overlay(ctx);
+    });
+    harness.run_steps(2);
+    assert!(harness.query_by_label("Tessellation: 3.00 ms").is_some());
+    assert!(
+        harness.query_by_label_contains("Input").is_none(),
+        "Only reported phases are shown"
+    );
+}
+
+#[test]
+fn toggle_with_key() {
+    let mut harness = harness(egui::debug::frame_time_overlay);
+    harness.run_steps(2);
+    assert!(harness.query_by_label_contains("fps").is_some());
+
+    harness.key_press(Key::F12);
+    harness.run();
+    assert!(harness.query_by_label_contains("fps").is_none());
+
+    harness.key_press(Key::F12);
+    harness.run_steps(2);
+    assert!(harness.query_by_label_contains("fps").is_some());
+}
+
+#[test]
+fn custom_toggle_shortcut() {
+    let mut harness = harness(|ctx| {
+        FrameTimeOverlay::new()
+            .toggle_shortcut(Some(egui::KeyboardShortcut::new(Modifiers::NONE, Key::F1)))
+            .show(ctx);
+    });
+    harness.run_steps(2);
+    harness.key_press(Key::F12);
+    harness.run_steps(2);
+    assert!(harness.query_by_label_contains("fps").is_some());
+
+    harness.key_press(Key::F1);
+    harness.run();
+    assert!(harness.query_by_label_contains("fps").is_none());
+}
@@ -0,0 +1,131 @@
+use egui::accesskit::Role;
+use egui::{Color32, Pos2, Rect, lerp, pos2, vec2};
+use egui_extras::GradientEditor;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+type Stops = Vec<(f32, Color32)>;
+
+fn gradient_harness(stops: Stops) -> Harness<'static, (Stops, Rect)> {
+    Harness::new_ui_state(
+        |ui, (stops, rect): &mut (Stops, Rect)| {
+            *rect = ui.add(GradientEditor::new(stops).width(200.0)).rect;
+        },
+        (stops, Rect::NOTHING),
+    )
+}
+
+fn positions(harness: &Harness<'_, (Stops, Rect)>) -> Vec<f32> {
+    harness.state().0.iter().map(|(t, _)| *t).collect()
+}
+
+/// The point on the marker of a stop at `t`.
+fn marker_pos(harness: &Harness<'_, (Stops, Rect)>, t: f32) -> Pos2 {
+    let rect = harness.state().1;
+    pos2(lerp(rect.x_range(), t), rect.bottom() - 4.0)
+}
+
+#[test]
+fn stops_are_sorted_and_clamped() {
+    let mut harness = gradient_harness(vec![
+        (1.5, Color32::RED),
+        (0.5, Color32::GREEN),
+        (-1.0, Color32::BLUE),
+    ]);
+    harness.run();
+    assert_eq!(positions(&harness), [0.0, 0.5, 1.0]);
+    assert_eq!(harness.state().0[0].1, Color32::BLUE);
+}
+
+#[test]
+fn double_clicking_the_bar_adds_a_stop() {
+    let mut harness = gradient_harness(vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)]);
+    harness.run();
+
+    let rect = harness.state().1;
+    let pos = pos2(lerp(rect.x_range(), 0.25), rect.top() + 4.0);
+    for _ in 0..2 {
+        harness.click_at(pos);
+        harness.step();
+    }
+    harness.run();
+
+    let stops = &harness.state().0;
+    assert_eq!(stops.len(), 3);
+    assert!((stops[1].0 - 0.25).abs() < 0.01, "{stops:?}");
+    let gray = stops[1].1;
+    assert!(
+        Color32::BLACK.r() < gray.r() && gray.r() < Color32::WHITE.r(),
+        "The new stop takes the color of the gradient: {gray:?}"
+    );
+}
+
+#[test]
+fn dragging_a_stop_past_another_keeps_them_sorted() {
+    let mut harness = gradient_harness(vec![
+        (0.0, Color32::BLACK),
+        (0.25, Color32::RED),
+        (0.5, Color32::GREEN),
+        (1.0, Color32::WHITE),
+    ]);
+    harness.run();
+
+    let from = marker_pos(&harness, 0.25);
+    let to = marker_pos(&harness, 0.75);
+    harness.drag(from, to);
+    harness.run();
+
+    let stops = &harness.state().0;
+    assert_eq!(stops.len(), 4);
+    assert!(stops.is_sorted_by(|(a, _), (b, _)| a <= b), "{stops:?}");
+    assert_eq!(stops[1].1, Color32::GREEN);
+    assert_eq!(stops[2].1, Color32::RED);
+    assert!((stops[2].0 - 0.75).abs() < 0.01, "{stops:?}");
+}
+
+#[test]
+fn dragging_a_stop_off_removes_it() {
+    let mut harness = gradient_harness(vec![
+        (0.0, Color32::BLACK),
+        (0.5, Color32::RED),
+        (1.0, Color32::WHITE),
+    ]);
+    harness.run();
+
+    let from = marker_pos(&harness, 0.5);
+    harness.drag(from, from + vec2(0.0, 100.0));
+    harness.run();
+    assert_eq!(positions(&harness), [0.0, 1.0]);
+
+    // The last stop stays:
+    let mut harness = gradient_harness(vec![(0.5, Color32::RED)]);
+    harness.run();
+    let from = marker_pos(&harness, 0.5);
+    harness.drag(from, from + vec2(0.0, 100.0));
+    harness.run();
+    assert_eq!(harness.state().0.len(), 1);
+}
+
+#[test]
+fn clicking_a_stop_opens_its_color_picker() {
+    let mut harness = gradient_harness(vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)]);
+    harness.run();
+    assert_eq!(harness.query_all_by_role(Role::TextInput).count(), 0);
+
+    let pos = marker_pos(&harness, 1.0) - vec2(2.0, 0.0);
+    harness.click_at(pos);
+    harness.run();
+    assert!(
+        harness.query_all_by_role(Role::TextInput).count() > 0,
+        "The color picker should be open"
+    );
+
+    let outside = harness.state().1.left_top() + vec2(60.0, 120.0);
+    harness.click_at(outside);
+    harness.run();
+    assert_eq!(
+        harness.query_all_by_role(Role::TextInput).count(),
+        0,
+        "Clicking outside closes the color picker"
+    );
+}
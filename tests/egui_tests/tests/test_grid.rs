@@ -0,0 +1,100 @@
+use egui::{Align2, Grid, Rect, Sense, Ui, vec2};
+use egui_kittest::Harness;
+
+/// Runs `add_contents` in a grid until it settles, and returns the rects it recorded.
+fn grid_rects(grid: fn() -> Grid, add_contents: fn(&mut Ui, &mut Vec<Rect>)) -> Vec<Rect> {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, rects: &mut Vec<Rect>| {
+            rects.clear();
+            grid().show(ui, |ui| add_contents(ui, rects));
+        },
+        Vec::new(),
+    );
+    harness.run();
+    harness.state().clone()
+}
+
+#[test]
+fn spanning_cells_cover_several_columns() {
+    let rects = grid_rects(
+        || Grid::new("grid").num_columns(3),
+        |ui, rects| {
+            rects.push(ui.label("First").rect);
+            rects.push(ui.label("Second").rect);
+            rects.push(ui.label("Third").rect);
+            ui.end_row();
+
+            rects.push(ui.grid_span(2, |ui| ui.label("Both")).response.rect);
+            rects.push(ui.label("Last").rect);
+            ui.end_row();
+        },
+    );
+    let [first, _second, third, both, last] = rects[..] else {
+        panic!("{rects:?}");
+    };
+    assert_eq!(both.left(), first.left());
+    assert_eq!(
+        last.left(),
+        third.left(),
+        "The next cell skips both columns"
+    );
+}
+
+#[test]
+fn wide_spanning_cells_only_widen_the_last_column_they_cover() {
+    fn form(ui: &mut Ui, rects: &mut Vec<Rect>, spanning: bool) {
+        ui.label("Name");
+        rects.push(ui.label("Value").rect);
+        ui.end_row();
+
+        if spanning {
+            let spanned = ui.grid_span(2, |ui| {
+                ui.allocate_exact_size(vec2(300.0, 10.0), Sense::hover());
+            });
+            rects.push(spanned.response.rect);
+            ui.end_row();
+        }
+    }
+
+    let without = grid_rects(
+        || Grid::new("grid").num_columns(2),
+        |ui, rects| form(ui, rects, false),
+    );
+    let with = grid_rects(
+        || Grid::new("grid").num_columns(2),
+        |ui, rects| form(ui, rects, true),
+    );
+    assert_eq!(
+        with[0].left(),
+        without[0].left(),
+        "The first column keeps its width"
+    );
+    assert!(300.0 <= with[1].width(), "{:?}", with[1]);
+}
+
+#[test]
+fn columns_can_be_right_aligned() {
+    let rects = grid_rects(
+        || {
+            Grid::new("grid")
+                .num_columns(2)
+                .columns_align(&[Align2::RIGHT_CENTER])
+        },
+        |ui, rects| {
+            rects.push(ui.label("Name").rect);
+            rects.push(ui.label("Short").rect);
+            ui.end_row();
+
+            rects.push(ui.label("A much longer name").rect);
+            rects.push(ui.label("Value").rect);
+            ui.end_row();
+        },
+    );
+    assert_eq!(rects[0].right(), rects[2].right());
+    assert!(rects[2].left() < rects[0].left());
+    assert_eq!(
+        rects[1].left(),
+        rects[3].left(),
+        "Other columns stay left-aligned"
+    );
+}
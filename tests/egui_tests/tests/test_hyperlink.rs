@@ -0,0 +1,72 @@
+use egui::{Hyperlink, OpenUrl, OutputCommand, PointerButton, accesskit::Role};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+const URL: &str = "https://github.com/emilk/egui";
+
+fn hyperlink_harness(context_menu: bool) -> Harness<'static> {
+    Harness::new_ui(move |ui| {
+        ui.add(Hyperlink::from_label_and_url("egui repository", URL).context_menu(context_menu));
+    })
+}
+
+fn commands(harness: &Harness<'_>) -> Vec<OutputCommand> {
+    harness.output().platform_output.commands.clone()
+}
+
+#[test]
+fn copy_link_copies_the_url() {
+    let mut harness = hyperlink_harness(true);
+    harness.get_by_label("egui repository").click_secondary();
+    harness.run();
+
+    harness
+        .get_by_role_and_label(Role::Button, "Copy link")
+        .click();
+    harness.step();
+    assert_eq!(
+        commands(&harness),
+        [OutputCommand::CopyText(URL.to_owned())]
+    );
+}
+
+#[test]
+fn open_link_opens_the_url() {
+    let mut harness = hyperlink_harness(true);
+    harness.get_by_label("egui repository").click_secondary();
+    harness.run();
+
+    harness
+        .get_by_role_and_label(Role::Button, "Open link")
+        .click();
+    harness.step();
+    assert_eq!(
+        commands(&harness),
+        [OutputCommand::OpenUrl(OpenUrl::same_tab(URL))]
+    );
+}
+
+#[test]
+fn middle_click_opens_in_new_tab() {
+    let mut harness = hyperlink_harness(true);
+    harness
+        .get_by_label("egui repository")
+        .click_button(PointerButton::Middle);
+    harness.step();
+    assert_eq!(
+        commands(&harness),
+        [OutputCommand::OpenUrl(OpenUrl::new_tab(URL))]
+    );
+}
+
+#[test]
+fn context_menu_can_be_turned_off() {
+    let mut harness = hyperlink_harness(false);
+    harness.get_by_label("egui repository").click_secondary();
+    harness.run();
+    assert!(
+        harness
+            .query_by_role_and_label(Role::Button, "Copy link")
+            .is_none()
+    );
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use egui::{Event, ViewportId, pos2};
+use egui_kittest::Harness;
+
+/// A dashboard that polls for new data ten times a second.
+///
+/// It never stops asking for repaints, so [`Harness::run`] would never settle: step it instead.
+fn polling_harness(idle_repaint_interval: Option<Duration>) -> Harness<'static> {
+    // Egui subtracts the predicted frame time from repaint delays, so keep it short:
+    let harness = Harness::builder().with_step_dt(1.0 / 60.0).build_ui(|ui| {
+        ui.label("Dashboard");
+        ui.ctx().request_repaint_after(Duration::from_millis(100));
+    });
+    harness
+        .ctx
+        .options_mut(|o| o.idle_repaint_interval = idle_repaint_interval);
+    harness
+}
+
+fn repaint_delay(harness: &Harness<'_>) -> Duration {
+    harness.output().viewport_output[&ViewportId::ROOT].repaint_delay
+}
+
+#[test]
+fn idle_repaints_are_postponed() {
+    let mut harness = polling_harness(None);
+    harness.run_steps(3);
+    let delay = repaint_delay(&harness);
+    assert!(delay <= Duration::from_millis(100), "{delay:?}");
+
+    let mut harness = polling_harness(Some(Duration::from_secs(1)));
+    harness.run_steps(3);
+    let delay = repaint_delay(&harness);
+    assert!(Duration::from_millis(900) < delay, "{delay:?}");
+}
+
+#[test]
+fn input_returns_to_full_rate() {
+    let mut harness = polling_harness(Some(Duration::from_secs(1)));
+    harness.run_steps(3);
+    let delay = repaint_delay(&harness);
+    assert!(Duration::from_millis(900) < delay, "Idle: {delay:?}");
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(10.0, 10.0)));
+    harness.step();
+    let delay = repaint_delay(&harness);
+    assert!(
+        delay <= Duration::from_millis(100),
+        "After input: {delay:?}"
+    );
+
+    harness.run_steps(3);
+    let delay = repaint_delay(&harness);
+    assert!(Duration::from_millis(900) < delay, "Idle again: {delay:?}");
+}
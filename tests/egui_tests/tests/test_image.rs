@@ -0,0 +1,127 @@
+use egui::{
+    Image, Margin, Mesh, Pos2, Rect, Shape, TextureId, Vec2, accesskit::Role, epaint::ClippedShape,
+    load::SizedTexture, pos2, vec2,
+};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+const SPRITE_SHEET: SizedTexture = SizedTexture {
+    id: TextureId::User(42),
+    size: Vec2::new(64.0, 32.0),
+};
+
+fn image_harness(image: Image<'static>) -> Harness<'static> {
+    Harness::new_ui(move |ui| {
+        ui.add(image.clone());
+    })
+}
+
+/// The uv rectangle used to paint the sprite sheet.
+fn painted_uv(harness: &Harness<'_>) -> Option<Rect> {
+    harness
+        .output()
+        .shapes
+        .iter()
+        .find_map(|ClippedShape { shape, .. }| match shape {
+            Shape::Rect(rect) => rect
+                .brush
+                .as_ref()
+                .filter(|brush| brush.fill_texture_id == SPRITE_SHEET.id)
+                .map(|brush| brush.uv),
+            _ => None,
+        })
+}
+
+#[test]
+fn cropped_texture_is_sized_like_the_region() {
+    let crop = Rect::from_min_size(pos2(16.0, 0.0), vec2(16.0, 16.0));
+    let harness = image_harness(Image::from_texture(SPRITE_SHEET).crop(crop));
+
+    assert_eq!(
+        harness.get_by_role(Role::Image).rect().size(),
+        vec2(16.0, 16.0)
+    );
+    assert_eq!(
+        painted_uv(&harness),
+        Some(Rect::from_min_max(pos2(0.25, 0.0), pos2(0.5, 0.5)))
+    );
+}
+
+#[test]
+fn cropped_image_keeps_aspect_ratio_of_region() {
+    let crop = Rect::from_min_size(pos2(0.0, 16.0), vec2(32.0, 16.0));
+    let harness = image_harness(
+        Image::from_texture(SPRITE_SHEET)
+            .crop(crop)
+            .fit_to_exact_size(vec2(64.0, 64.0)),
+    );
+
+    assert_eq!(
+        harness.get_by_role(Role::Image).rect().size(),
+        vec2(64.0, 32.0)
+    );
+    assert_eq!(
+        painted_uv(&harness),
+        Some(Rect::from_min_max(pos2(0.0, 0.5), pos2(0.5, 1.0)))
+    );
+}
+
+/// The mesh painting the sprite sheet, if any.
+fn painted_mesh(harness: &Harness<'_>) -> Option<Mesh> {
+    harness
+        .output()
+        .shapes
+        .iter()
+        .find_map(|ClippedShape { shape, .. }| match shape {
+            Shape::Mesh(mesh) if mesh.texture_id == SPRITE_SHEET.id => Some((**mesh).clone()),
+            _ => None,
+        })
+}
+
+/// The screen position of the vertex with this uv.
+fn vertex_pos(mesh: &Mesh, uv: Pos2) -> Option<Pos2> {
+    mesh.vertices
+        .iter()
+        .find(|vertex| vertex.uv == uv)
+        .map(|vertex| vertex.pos)
+}
+
+#[test]
+fn nine_patch_keeps_corners_unscaled() {
+    let harness = image_harness(
+        Image::from_texture(SPRITE_SHEET)
+            .nine_patch(Margin::same(8))
+            .maintain_aspect_ratio(false)
+            .fit_to_exact_size(vec2(200.0, 100.0)),
+    );
+    let rect = harness.get_by_role(Role::Image).rect();
+    assert_eq!(rect.size(), vec2(200.0, 100.0));
+
+    let mesh = painted_mesh(&harness).expect("The nine-patch should be painted as a mesh");
+    assert_eq!(mesh.vertices.len(), 9 * 4, "Nine quads");
+    assert_eq!(
+        vertex_pos(&mesh, pos2(8.0 / 64.0, 8.0 / 32.0)),
+        Some(rect.min + vec2(8.0, 8.0))
+    );
+    assert_eq!(
+        vertex_pos(&mesh, pos2(56.0 / 64.0, 24.0 / 32.0)),
+        Some(rect.max - vec2(8.0, 8.0))
+    );
+}
+
+#[test]
+fn nine_patch_shrinks_corners_that_do_not_fit() {
+    let harness = image_harness(
+        Image::from_texture(SPRITE_SHEET)
+            .nine_patch(Margin::same(8))
+            .maintain_aspect_ratio(false)
+            .fit_to_exact_size(vec2(8.0, 32.0)),
+    );
+    let rect = harness.get_by_role(Role::Image).rect();
+
+    let mesh = painted_mesh(&harness).expect("The nine-patch should be painted as a mesh");
+    assert_eq!(
+        vertex_pos(&mesh, pos2(8.0 / 64.0, 8.0 / 32.0)),
+        Some(rect.min + vec2(4.0, 8.0))
+    );
+}
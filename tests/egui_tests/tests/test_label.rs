@@ -0,0 +1,225 @@
+use egui::{
+    Event, Key, Label, Modifiers, OutputCommand, RichText, ScrollArea, WidgetText, accesskit::Role,
+};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn copied_text(harness: &Harness<'_>) -> Option<String> {
+    harness
+        .output()
+        .platform_output
+        .commands
+        .iter()
+        .find_map(|command| match command {
+            OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        })
+}
+
+#[test]
+fn copy_context_menu_copies_whole_label() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.add(Label::new("Hello log line").copy_context_menu(true));
+    });
+
+    harness.get_by_label("Hello log line").click_secondary();
+    harness.run();
+
+    harness.get_by_role_and_label(Role::Button, "Copy").click();
+    harness.step();
+    assert_eq!(copied_text(&harness).as_deref(), Some("Hello log line"));
+
+    harness.run();
+    assert!(
+        harness
+            .query_by_role_and_label(Role::Button, "Copy")
+            .is_none(),
+        "The menu should close after copying"
+    );
+}
+
+#[test]
+fn copy_context_menu_copies_selection() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.add(Label::new("Alpha").selectable(true));
+        ui.add(Label::new("Beta").selectable(true).copy_context_menu(true));
+    });
+
+    // Double-click to select a word:
+    let label = harness.get_by_label("Alpha");
+    label.click();
+    label.click();
+    harness.run();
+
+    // Right-clicking a label should not change the selection…
+    harness.get_by_label("Beta").click_secondary();
+    harness.run();
+
+    // …so we copy the selected text, not the text of the clicked label.
+    harness.get_by_role_and_label(Role::Button, "Copy").click();
+    harness.step(); // Requests the copy…
+    harness.step(); // …which happens in the next pass.
+    assert_eq!(copied_text(&harness).as_deref(), Some("Alpha"));
+}
+
+fn chat_harness(text_selection_scope: bool) -> Harness<'static> {
+    Harness::new_ui(move |ui| {
+        ui.label("Outside");
+        ScrollArea::vertical()
+            .text_selection_scope(text_selection_scope)
+            .show(ui, |ui| {
+                ui.heading("Alice");
+                ui.label("Hello there");
+                ui.label("How are you?");
+            });
+    })
+}
+
+fn select_all_and_copy(harness: &mut Harness<'_>) {
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::A);
+    harness.run();
+    harness.input_mut().events.push(Event::Copy);
+    harness.step();
+}
+
+#[test]
+fn select_all_in_selection_scope() {
+    let mut harness = chat_harness(true);
+    harness.get_by_label("Hello there").hover();
+    harness.run();
+
+    select_all_and_copy(&mut harness);
+    assert_eq!(
+        copied_text(&harness).as_deref(),
+        Some("Alice\nHello there\nHow are you?")
+    );
+}
+
+#[test]
+fn select_all_follows_selection_into_scope() {
+    let mut harness = chat_harness(true);
+
+    // Double-click to select a word:
+    let label = harness.get_by_label("How are you?");
+    label.click();
+    label.click();
+    harness.run();
+
+    // Move the pointer away from the scroll area:
+    harness.get_by_label("Outside").hover();
+    harness.run();
+
+    select_all_and_copy(&mut harness);
+    assert_eq!(
+        copied_text(&harness).as_deref(),
+        Some("Alice\nHello there\nHow are you?")
+    );
+}
+
+#[test]
+fn select_all_without_selection_scope_stays_in_label() {
+    let mut harness = chat_harness(false);
+
+    let label = harness.get_by_label("How are you?");
+    label.click();
+    label.click();
+    harness.run();
+
+    select_all_and_copy(&mut harness);
+    assert_eq!(copied_text(&harness).as_deref(), Some("How are you?"));
+}
+
+const LONG_TEXT: &str = "This is a long title, that needs way more than two lines to fit";
+
+/// The rows and shown text of the galley of a label limited to two lines.
+fn max_lines_harness(text: &'static str) -> Harness<'static, (usize, String)> {
+    Harness::new_ui_state(
+        move |ui, (num_rows, galley_text): &mut (usize, String)| {
+            ui.set_width(120.0);
+            let (_, galley, response) = Label::new(text).max_lines(2).layout_in_ui(ui);
+            *num_rows = galley.rows.len();
+            // What is shown, which isn't the same as `galley.text()` when elided:
+            *galley_text = galley
+                .rows
+                .iter()
+                .flat_map(|row| row.glyphs.iter().map(|glyph| glyph.chr))
+                .collect();
+            ui.painter()
+                .galley(response.rect.min, galley, ui.visuals().text_color());
+        },
+        (0, String::new()),
+    )
+}
+
+#[test]
+fn max_lines_wraps_then_ellipsizes() {
+    let harness = max_lines_harness(LONG_TEXT);
+    let (num_rows, galley_text) = harness.state();
+    assert_eq!(*num_rows, 2);
+    assert!(galley_text.ends_with('…'), "{galley_text:?}");
+    assert!(
+        galley_text.starts_with("This is a long"),
+        "Wraps on words: {galley_text:?}"
+    );
+
+    let harness = max_lines_harness("Short title");
+    assert_eq!(*harness.state(), (1, "Short title".to_owned()));
+}
+
+#[test]
+fn max_lines_shows_full_text_on_hover() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.set_width(120.0);
+        ui.add(Label::new(LONG_TEXT).max_lines(2));
+    });
+
+    harness.get_by_label(LONG_TEXT).hover();
+    harness.run_steps(30);
+    assert_eq!(
+        harness.get_all_by_label(LONG_TEXT).count(),
+        2,
+        "The label, and its tooltip"
+    );
+}
+
+/// The right edge of the last letter of each row of a wrapped label, 120 points wide.
+fn row_ends(text: impl Into<WidgetText>, full_justify: bool) -> Vec<f32> {
+    let text = text.into();
+    let harness = Harness::new_ui_state(
+        move |ui, row_ends: &mut Vec<f32>| {
+            ui.set_width(120.0);
+            let label = Label::new(text.clone()).wrap().full_justify(full_justify);
+            let (_, galley, _) = label.layout_in_ui(ui);
+            *row_ends = galley
+                .rows
+                .iter()
+                .map(|row| {
+                    let last_letter = row.glyphs.iter().rev().find(|g| !g.chr.is_whitespace());
+                    row.pos.x + last_letter.map_or(0.0, |g| g.max_x())
+                })
+                .collect();
+        },
+        Vec::new(),
+    );
+    harness.state().clone()
+}
+
+#[test]
+fn full_justify_makes_rows_flush_with_both_edges() {
+    let plain = row_ends(LONG_TEXT, false);
+    let justified = row_ends(LONG_TEXT, true);
+    assert_eq!(plain.len(), justified.len());
+    assert!(3 <= justified.len(), "{justified:?}");
+
+    let (last, rows) = justified.split_last().unwrap();
+    for justified in rows {
+        assert!((justified - 120.0).abs() < 1.0, "Flush: {justified}");
+    }
+    assert_eq!(*last, *plain.last().unwrap(), "The last row is left as is");
+
+    assert_eq!(
+        row_ends(RichText::new(LONG_TEXT).full_justify(true), false),
+        justified,
+        "Same with RichText"
+    );
+}
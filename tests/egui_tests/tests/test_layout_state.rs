@@ -0,0 +1,69 @@
+use egui::{Context, Id, Pos2, Rect, SidePanel, Vec2, Window, pos2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// Shows a panel and two windows, starting out at `offset`.
+fn app(offset: f32) -> impl FnMut(&Context) {
+    move |ctx| {
+        SidePanel::left("panel")
+            .default_width(100.0 + offset)
+            .show(ctx, |ui| ui.label("Panel"));
+        Window::new("First")
+            .default_pos(pos2(20.0, 20.0) + Vec2::splat(offset))
+            .show(ctx, |ui| {
+                ui.collapsing("Details", |ui| ui.label("Some details"));
+            });
+        Window::new("Second")
+            .default_pos(pos2(150.0, 150.0) + Vec2::splat(offset))
+            .show(ctx, |ui| ui.label("Second window"));
+    }
+}
+
+fn harness(offset: f32) -> Harness<'static> {
+    Harness::builder()
+        .with_size(egui::vec2(800.0, 600.0))
+        .build(app(offset))
+}
+
+fn window_pos(harness: &Harness<'_>, title: &str) -> Pos2 {
+    harness
+        .ctx
+        .memory(|mem| mem.area_rect(Id::new(title)))
+        .expect("The window was shown")
+        .min
+}
+
+fn panel_rect(harness: &Harness<'_>) -> Rect {
+    egui::containers::panel::PanelState::load(&harness.ctx, Id::new("panel"))
+        .unwrap()
+        .rect
+}
+
+#[test]
+fn layout_snapshot_round_trips_windows() {
+    let mut original = harness(0.0);
+    original.get_by_label("Details").click();
+    original.run();
+    assert!(original.query_by_label("Some details").is_some());
+    let layout = original.ctx.layout_snapshot();
+    assert!(layout.areas.contains_key(&Id::new("First")));
+    assert!(layout.areas.contains_key(&Id::new("Second")));
+
+    let mut restored = harness(40.0);
+    restored.ctx.apply_layout_snapshot(&layout);
+    restored.run();
+
+    for title in ["First", "Second"] {
+        assert_eq!(
+            window_pos(&restored, title),
+            window_pos(&original, title),
+            "{title} is where it was"
+        );
+    }
+    assert_eq!(panel_rect(&restored).width(), panel_rect(&original).width());
+    assert!(
+        restored.query_by_label("Some details").is_some(),
+        "The collapsing header is open"
+    );
+    assert_eq!(restored.ctx.layout_snapshot(), layout);
+}
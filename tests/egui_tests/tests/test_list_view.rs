@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use egui::{Align, Event, Rect, Sense, vec2};
+use egui_extras::ListView;
+use egui_kittest::Harness;
+
+/// Items are identified by a key, so we can follow them when prepending.
+struct State {
+    keys: Vec<usize>,
+    rects: HashMap<usize, Rect>,
+    inner_rect: Rect,
+    at_bottom: bool,
+    stick_to_bottom: bool,
+    prepended: usize,
+    scroll_to: Option<(usize, Align)>,
+}
+
+fn item_height(key: usize) -> f32 {
+    10.0 + (key % 5) as f32 * 15.0
+}
+
+fn harness(
+    keys: impl IntoIterator<Item = usize>,
+    stick_to_bottom: bool,
+) -> Harness<'static, State> {
+    Harness::builder()
+        .with_size(vec2(200.0, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let mut list = ListView::new("list")
+                    .max_height(200.0)
+                    .stick_to_bottom(state.stick_to_bottom)
+                    .prepended(std::mem::take(&mut state.prepended));
+                if let Some((index, align)) = state.scroll_to.take() {
+                    list = list.scroll_to_item(index, align);
+                }
+                state.rects.clear();
+                let State { keys, rects, .. } = state;
+                let response = list.show(ui, keys.len(), |ui, i| {
+                    let key = keys[i];
+                    let (rect, _) =
+                        ui.allocate_exact_size(vec2(100.0, item_height(key)), Sense::hover());
+                    rects.insert(key, rect);
+                });
+                state.inner_rect = response.inner_rect;
+                state.at_bottom = response.at_bottom;
+            },
+            State {
+                keys: keys.into_iter().collect(),
+                rects: HashMap::new(),
+                inner_rect: Rect::NOTHING,
+                at_bottom: false,
+                stick_to_bottom,
+                prepended: 0,
+                scroll_to: None,
+            },
+        )
+}
+
+fn is_visible(state: &State, key: usize) -> bool {
+    state
+        .rects
+        .get(&key)
+        .is_some_and(|rect| state.inner_rect.intersects(*rect))
+}
+
+#[test]
+fn only_visible_items_are_shown() {
+    let mut harness = harness(0..10_000, false);
+    harness.run();
+
+    let state = harness.state();
+    assert!(state.rects.len() < 20, "Showed {} items", state.rects.len());
+    let mut rects: Vec<(usize, Rect)> = state.rects.iter().map(|(&k, &r)| (k, r)).collect();
+    rects.sort_by_key(|(key, _)| *key);
+    for ((key, rect), (_, next)) in rects.iter().zip(&rects[1..]) {
+        assert_eq!(rect.height(), item_height(*key));
+        assert!(rect.bottom() < next.top(), "Items don't overlap");
+    }
+}
+
+#[test]
+fn scroll_to_item_aligns_it() {
+    let mut harness = harness(0..10_000, false);
+    harness.run();
+
+    for (index, align) in [
+        (5_000, Align::Min),
+        (7_003, Align::Center),
+        (42, Align::Max),
+    ] {
+        harness.state_mut().scroll_to = Some((index, align));
+        harness.run();
+        let state = harness.state();
+        let rect = state.rects[&index];
+        let (actual, expected) = match align {
+            Align::Min => (rect.top(), state.inner_rect.top()),
+            Align::Center => (rect.center().y, state.inner_rect.center().y),
+            Align::Max => (rect.bottom(), state.inner_rect.bottom()),
+        };
+        assert!(
+            (actual - expected).abs() < 1.0,
+            "Item {index} is at {actual}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn sticks_to_bottom_until_scrolled_up() {
+    let mut harness = harness(0..100, true);
+    harness.run();
+    assert!(harness.state().at_bottom);
+    assert!(is_visible(harness.state(), 99));
+
+    harness.state_mut().keys.extend(100..110);
+    harness.run();
+    assert!(harness.state().at_bottom);
+    assert!(is_visible(harness.state(), 109), "Follows new items");
+
+    let center = harness.state().inner_rect.center();
+    harness.input_mut().events.push(Event::PointerMoved(center));
+    harness.input_mut().events.push(Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: vec2(0.0, 100.0),
+        modifiers: Default::default(),
+    });
+    harness.run();
+    assert!(!harness.state().at_bottom);
+
+    harness.state_mut().keys.extend(110..120);
+    harness.run();
+    assert!(!harness.state().at_bottom);
+    assert!(
+        !is_visible(harness.state(), 119),
+        "Stays where the user scrolled"
+    );
+}
+
+#[test]
+fn prepending_does_not_move_visible_items() {
+    let mut harness = harness(1_000..1_100, false);
+    harness.run();
+    harness.state_mut().scroll_to = Some((50, Align::Min));
+    harness.run();
+    let before = harness.state().rects[&1_050];
+
+    for batch in 0..3 {
+        let older = (900 - 100 * batch..1_000 - 100 * batch).collect::<Vec<_>>();
+        let state = harness.state_mut();
+        state.prepended = older.len();
+        state.keys.splice(0..0, older);
+        harness.run();
+        assert_eq!(harness.state().rects[&1_050], before, "Batch {batch}");
+    }
+
+    // The older items are all there, above:
+    harness.state_mut().scroll_to = Some((0, Align::Min));
+    harness.run();
+    let state = harness.state();
+    assert_eq!(state.rects[&700].top(), state.inner_rect.top());
+}
@@ -0,0 +1,73 @@
+use egui::{Rect, Ui, Vec2};
+use egui_kittest::Harness;
+
+fn buttons(ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        let _ = ui.button("Cancel");
+        let _ = ui.button("Ok");
+    });
+}
+
+#[test]
+fn measure_matches_shown_size() {
+    let mut measured = Vec2::ZERO;
+    let mut shown = Rect::NOTHING;
+    let mut cursor_before = Rect::NOTHING;
+    let mut cursor_after = Rect::NOTHING;
+
+    Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .build_ui(|ui| {
+            cursor_before = ui.cursor();
+            measured = ui.measure(buttons);
+            cursor_after = ui.cursor();
+            shown = ui.scope(buttons).response.rect;
+        })
+        .run();
+
+    assert_eq!(measured, shown.size());
+    assert_eq!(cursor_before, cursor_after, "Measuring allocates no space");
+}
+
+#[test]
+fn measure_wraps_to_available_width() {
+    let text = "Some fairly long text that will need to wrap onto several lines";
+    let mut narrow = Vec2::ZERO;
+    let mut wide = Vec2::ZERO;
+
+    Harness::builder()
+        .with_size(Vec2::new(600.0, 400.0))
+        .build_ui(|ui| {
+            wide = ui.measure(|ui| {
+                ui.label(text);
+            });
+            ui.allocate_ui(Vec2::new(100.0, 400.0), |ui| {
+                narrow = ui.measure(|ui| {
+                    ui.label(text);
+                });
+            });
+        })
+        .run();
+
+    assert!(narrow.x <= 100.0, "{narrow:?}");
+    assert!(narrow.y > wide.y, "{narrow:?} vs {wide:?}");
+}
+
+#[test]
+fn measure_paints_nothing() {
+    let shape_count = |measure: bool| {
+        let mut harness = Harness::new_ui(move |ui| {
+            if measure {
+                ui.measure(buttons);
+            }
+        });
+        harness.run();
+        harness.output().shapes.len()
+    };
+
+    assert_eq!(
+        shape_count(true),
+        shape_count(false),
+        "Measured contents are not painted"
+    );
+}
@@ -0,0 +1,359 @@
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+
+#[derive(Default)]
+struct State {
+    enabled: bool,
+    saved: usize,
+
+    /// Was the shortcut still there after the menu?
+    leftover_shortcut: bool,
+}
+
+fn menu_harness(enabled: bool) -> Harness<'static, State> {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut State| {
+            ui.menu_button("File", |ui| {
+                ui.add_enabled_ui(state.enabled, |ui| {
+                    if ui.menu_item("Save", SAVE).clicked() {
+                        state.saved += 1;
+                    }
+                });
+            });
+            state.leftover_shortcut |= ui.input_mut(|i| i.consume_shortcut(&SAVE));
+        },
+        State {
+            enabled,
+            ..Default::default()
+        },
+    );
+    harness.get_by_label("File").click();
+    harness.run();
+    harness
+}
+
+fn is_menu_open(harness: &Harness<'_, State>) -> bool {
+    harness.query_by_label_contains("Save").is_some()
+}
+
+#[test]
+fn menu_item_shows_its_shortcut() {
+    let harness = menu_harness(true);
+    let text = harness.ctx.format_shortcut(&SAVE);
+    assert!(
+        harness.query_by_label_contains(&text).is_some(),
+        "The item shows {text:?}"
+    );
+}
+
+#[test]
+fn menu_item_is_clicked_by_its_shortcut() {
+    let mut harness = menu_harness(true);
+    assert!(is_menu_open(&harness));
+
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::S);
+    harness.run();
+    assert_eq!(harness.state().saved, 1);
+    assert!(
+        !harness.state().leftover_shortcut,
+        "The item consumes the shortcut"
+    );
+    assert!(
+        !is_menu_open(&harness),
+        "Triggering the item closes the menu"
+    );
+}
+
+#[test]
+fn menu_item_is_clicked_by_the_pointer() {
+    let mut harness = menu_harness(true);
+    harness.get_by_label_contains("Save").click();
+    harness.run();
+    assert_eq!(harness.state().saved, 1);
+    assert!(!is_menu_open(&harness));
+}
+
+#[test]
+fn disabled_menu_item_ignores_its_shortcut() {
+    let mut harness = menu_harness(false);
+    assert!(is_menu_open(&harness));
+
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::S);
+    harness.run();
+    assert_eq!(harness.state().saved, 0);
+    assert!(
+        harness.state().leftover_shortcut,
+        "A disabled item leaves the shortcut to others"
+    );
+    assert!(is_menu_open(&harness));
+}
+
+#[derive(Default)]
+struct NavState {
+    /// The entries that were highlighted / hovered in the last pass.
+    highlighted: Vec<&'static str>,
+    hovered: Vec<&'static str>,
+    clicked: Vec<&'static str>,
+}
+
+impl NavState {
+    fn entry(&mut self, ui: &mut egui::Ui, name: &'static str) {
+        let response = ui.button(name);
+        self.record(name, &response);
+    }
+
+    fn record(&mut self, name: &'static str, response: &egui::Response) {
+        if response.highlighted() {
+            self.highlighted.push(name);
+        }
+        if response.hovered() {
+            self.hovered.push(name);
+        }
+        if response.clicked() {
+            self.clicked.push(name);
+        }
+    }
+}
+
+fn nav_harness() -> Harness<'static, NavState> {
+    Harness::new_ui_state(
+        |ui, state: &mut NavState| {
+            state.highlighted.clear();
+            state.hovered.clear();
+            ui.menu_button("Edit", |ui| {
+                state.entry(ui, "Undo");
+                state.entry(ui, "Redo");
+                ui.separator();
+                ui.add_enabled(false, egui::Button::new("Cut"));
+                state.entry(ui, "Copy");
+                state.entry(ui, "Paste");
+                let (response, _) =
+                    egui::containers::menu::SubMenuButton::new("Find").ui(ui, |ui| {
+                        state.entry(ui, "Find next");
+                        state.entry(ui, "Replace");
+                    });
+                state.record("Find", &response);
+            });
+        },
+        NavState::default(),
+    )
+}
+
+/// Focus the menu button and open the menu with Enter.
+fn open_with_keyboard(harness: &mut Harness<'_, NavState>) {
+    harness.get_by_label("Edit").focus();
+    harness.run();
+    press(harness, Key::Enter);
+}
+
+fn press(harness: &mut Harness<'_, NavState>, key: Key) {
+    harness.key_press(key);
+    harness.run();
+}
+
+fn type_text(harness: &mut Harness<'_, NavState>, text: &str) {
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::Text(text.to_owned()));
+    harness.run();
+}
+
+#[test]
+fn opening_with_the_keyboard_highlights_the_first_entry() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+    assert_eq!(harness.state().highlighted, ["Undo"]);
+}
+
+#[test]
+fn arrows_skip_separators_and_disabled_entries() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+
+    press(&mut harness, Key::ArrowDown);
+    press(&mut harness, Key::ArrowDown);
+    assert_eq!(harness.state().highlighted, ["Copy"]);
+    press(&mut harness, Key::ArrowUp);
+    assert_eq!(harness.state().highlighted, ["Redo"]);
+    press(&mut harness, Key::ArrowUp);
+    press(&mut harness, Key::ArrowUp);
+    assert_eq!(harness.state().highlighted, ["Find"], "Wraps around");
+}
+
+#[test]
+fn enter_activates_and_gives_focus_back() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+
+    press(&mut harness, Key::ArrowDown);
+    press(&mut harness, Key::Enter);
+    assert_eq!(harness.state().clicked, ["Redo"]);
+    assert!(harness.query_by_label("Undo").is_none(), "The menu closed");
+    assert!(harness.get_by_label("Edit").accesskit_node().is_focused());
+}
+
+#[test]
+fn right_and_left_enter_and_leave_sub_menus() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+
+    press(&mut harness, Key::ArrowUp);
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(harness.state().highlighted, ["Find next"]);
+    press(&mut harness, Key::ArrowDown);
+    assert_eq!(harness.state().highlighted, ["Replace"]);
+
+    press(&mut harness, Key::ArrowLeft);
+    assert!(harness.query_by_label("Replace").is_none());
+    assert_eq!(harness.state().highlighted, ["Find"]);
+
+    press(&mut harness, Key::Enter);
+    assert_eq!(harness.state().highlighted, ["Find next"]);
+    press(&mut harness, Key::Enter);
+    assert_eq!(harness.state().clicked, ["Find next"]);
+    assert!(harness.query_by_label("Undo").is_none(), "All menus closed");
+}
+
+#[test]
+fn escape_closes_one_level() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+    press(&mut harness, Key::ArrowUp);
+    press(&mut harness, Key::ArrowRight);
+    assert!(harness.query_by_label("Replace").is_some());
+
+    press(&mut harness, Key::Escape);
+    assert!(harness.query_by_label("Replace").is_none());
+    assert!(
+        harness.query_by_label("Undo").is_some(),
+        "The parent stays open"
+    );
+
+    press(&mut harness, Key::Escape);
+    assert!(harness.query_by_label("Undo").is_none());
+    assert!(harness.get_by_label("Edit").accesskit_node().is_focused());
+}
+
+#[test]
+fn typing_highlights_the_next_match() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+
+    type_text(&mut harness, "p");
+    assert_eq!(harness.state().highlighted, ["Paste"]);
+    type_text(&mut harness, "F");
+    assert_eq!(harness.state().highlighted, ["Find"]);
+    type_text(&mut harness, "r");
+    assert_eq!(harness.state().highlighted, ["Redo"], "Wraps around");
+}
+
+#[test]
+fn keyboard_and_pointer_highlight_are_exclusive() {
+    let mut harness = nav_harness();
+    harness.get_by_label("Edit").click();
+    harness.run();
+    harness.get_by_label("Copy").hover();
+    harness.run();
+    assert_eq!(harness.state().hovered, ["Copy"]);
+    assert!(harness.state().highlighted.is_empty());
+
+    press(&mut harness, Key::ArrowDown);
+    assert_eq!(
+        harness.state().highlighted,
+        ["Paste"],
+        "Continues from the pointer"
+    );
+    assert!(harness.state().hovered.is_empty());
+
+    harness.get_by_label("Undo").hover();
+    harness.run();
+    assert_eq!(harness.state().hovered, ["Undo"]);
+    assert!(harness.state().highlighted.is_empty());
+}
+
+#[test]
+fn highlight_is_the_active_descendant() {
+    let mut harness = nav_harness();
+    open_with_keyboard(&mut harness);
+    press(&mut harness, Key::ArrowDown);
+
+    let redo = harness.get_by_label("Redo");
+    let mut ancestor = redo.accesskit_node().parent();
+    let mut active_descendant = None;
+    while let Some(node) = ancestor {
+        active_descendant = active_descendant.or(node.data().active_descendant());
+        ancestor = node.parent();
+    }
+    assert_eq!(active_descendant, Some(redo.accesskit_node().id()));
+}
+
+#[derive(Default)]
+struct DisabledState {
+    opt_in: bool,
+    clicked: bool,
+    hovered: bool,
+}
+
+/// A disabled button in front of a background with its own context menu.
+fn disabled_harness(opt_in: bool) -> Harness<'static, DisabledState> {
+    Harness::new_ui_state(
+        |ui, state: &mut DisabledState| {
+            let background = ui.interact(
+                ui.max_rect(),
+                ui.id().with("background"),
+                egui::Sense::click(),
+            );
+            background.context_menu(|ui| {
+                ui.label("Background menu");
+            });
+
+            let response = ui.add_enabled(false, egui::Button::new("Save"));
+            let menu = |ui: &mut egui::Ui| {
+                if !response.enabled() {
+                    ui.label("Why is this disabled?");
+                }
+            };
+            if state.opt_in {
+                response.context_menu_enabled_when_disabled(menu);
+            } else {
+                response.context_menu(menu);
+            }
+            state.clicked |= response.clicked();
+            state.hovered |= response.hovered();
+        },
+        DisabledState {
+            opt_in,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn disabled_widget_can_opt_in_to_its_context_menu() {
+    let mut harness = disabled_harness(true);
+    harness.get_by_label("Save").click();
+    harness.run();
+    assert!(!harness.state().clicked, "Still disabled");
+
+    harness.get_by_label("Save").click_secondary();
+    harness.run();
+    assert!(harness.query_by_label("Why is this disabled?").is_some());
+    assert!(
+        harness.query_by_label("Background menu").is_none(),
+        "The click doesn't fall through"
+    );
+    assert!(!harness.state().hovered, "Looks disabled");
+}
+
+#[test]
+fn disabled_widget_ignores_secondary_clicks_by_default() {
+    let mut harness = disabled_harness(false);
+    harness.get_by_label("Save").click_secondary();
+    harness.run();
+    assert!(harness.query_by_label("Why is this disabled?").is_none());
+    assert!(harness.query_by_label("Background menu").is_some());
+}
@@ -0,0 +1,191 @@
+use egui::accesskit::Role;
+use egui::{CentralPanel, Id, Key, Modal, ModalOutcome, Modifiers, TextEdit};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[derive(Default)]
+struct State {
+    open: bool,
+    outcomes: Vec<ModalOutcome>,
+    close_on_escape: bool,
+    close_on_backdrop_click: bool,
+    text: String,
+}
+
+fn harness(state: State) -> Harness<'static, State> {
+    Harness::new_state(
+        |ctx, state: &mut State| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(TextEdit::singleline(&mut state.text));
+                if ui.button("Open").clicked() {
+                    state.open = true;
+                }
+            });
+
+            if state.open {
+                let modal = Modal::new(Id::new("modal"))
+                    .close_on_escape(state.close_on_escape)
+                    .close_on_backdrop_click(state.close_on_backdrop_click)
+                    .show(ctx, |ui| {
+                        if ui.button("OK").clicked() {
+                            Modal::confirm(ui);
+                        }
+                        if ui.button("Cancel").clicked() {
+                            ui.close();
+                        }
+                    });
+                if let Some(outcome) = modal.outcome() {
+                    state.outcomes.push(outcome);
+                    state.open = false;
+                }
+            }
+        },
+        state,
+    )
+}
+
+fn closable() -> State {
+    State {
+        close_on_escape: true,
+        close_on_backdrop_click: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn buttons_confirm_or_dismiss() {
+    let mut harness = harness(closable());
+    harness.get_by_label("Open").click();
+    harness.run();
+    harness.get_by_label("OK").click();
+    harness.run();
+
+    harness.get_by_label("Open").click();
+    harness.run();
+    harness.get_by_label("Cancel").click();
+    harness.run();
+
+    assert_eq!(
+        harness.state().outcomes,
+        [ModalOutcome::Confirmed, ModalOutcome::Dismissed]
+    );
+}
+
+#[test]
+fn escape_and_backdrop_clicks_can_be_ignored() {
+    let mut harness = harness(State {
+        open: true,
+        ..Default::default()
+    });
+    harness.run();
+    harness.key_press(Key::Escape);
+    harness.run();
+    // Click the backdrop, where the text edit is:
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    assert!(harness.state().open);
+    assert!(harness.state().outcomes.is_empty());
+
+    harness.state_mut().close_on_escape = true;
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert_eq!(harness.state().outcomes, [ModalOutcome::Dismissed]);
+}
+
+#[test]
+fn focus_is_trapped_in_the_modal_and_restored() {
+    let mut harness = harness(closable());
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    assert!(harness.get_by_role(Role::TextInput).is_focused());
+
+    harness.state_mut().open = true;
+    harness.run();
+    assert!(
+        harness.get_by_label("OK").is_focused(),
+        "The first widget of the modal gets the focus"
+    );
+
+    for expected in ["Cancel", "OK", "Cancel"] {
+        harness.key_press(Key::Tab);
+        harness.run();
+        assert!(
+            harness.get_by_label(expected).is_focused(),
+            "Tab cycles within the modal, to {expected}"
+        );
+    }
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("OK").is_focused());
+
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert!(!harness.state().open);
+    assert!(
+        harness.get_by_role(Role::TextInput).is_focused(),
+        "The focus goes back to the text edit"
+    );
+}
+
+#[test]
+fn only_the_topmost_modal_is_interactive() {
+    let mut harness = Harness::new_state(
+        |ctx, (clicks, top_open): &mut (usize, bool)| {
+            Modal::new(Id::new("below")).show(ctx, |ui| {
+                if ui.button("Below").clicked() {
+                    *clicks += 1;
+                }
+            });
+            if *top_open {
+                let modal = Modal::new(Id::new("top"))
+                    .close_on_backdrop_click(false)
+                    .show(ctx, |ui| {
+                        ui.label("Top");
+                    });
+                if modal.should_close() {
+                    *top_open = false;
+                }
+            }
+        },
+        (0, true),
+    );
+    harness.run();
+    harness.get_by_label("Below").click();
+    harness.run();
+    assert_eq!(*harness.state(), (0, true));
+
+    harness.key_press(Key::Escape);
+    harness.run();
+    assert_eq!(*harness.state(), (0, false), "Escape closes the top modal");
+    harness.get_by_label("Below").click();
+    harness.run();
+    assert_eq!(*harness.state(), (1, false));
+}
+
+#[test]
+fn focus_is_restored_when_the_modal_is_no_longer_shown() {
+    let mut harness = Harness::new_state(
+        |ctx, (text, open): &mut (String, bool)| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(TextEdit::singleline(text));
+            });
+            if *open {
+                // Never asks the response whether to close:
+                Modal::new(Id::new("modal")).show(ctx, |ui| {
+                    _ = ui.button("OK");
+                });
+            }
+        },
+        (String::new(), false),
+    );
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+
+    harness.state_mut().1 = true;
+    harness.run();
+    assert!(harness.get_by_label("OK").is_focused());
+
+    harness.state_mut().1 = false;
+    harness.run();
+    assert!(harness.get_by_role(Role::TextInput).is_focused());
+}
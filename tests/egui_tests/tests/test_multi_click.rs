@@ -0,0 +1,79 @@
+use egui::{Pos2, Vec2, vec2};
+use egui_kittest::Harness;
+
+/// How many single, double and triple clicks the area got.
+#[derive(Default)]
+struct Counts {
+    center: Pos2,
+    clicks: [usize; 3],
+}
+
+fn click_area_harness() -> Harness<'static, Counts> {
+    Harness::builder().with_step_dt(0.1).build_ui_state(
+        |ui, counts: &mut Counts| {
+            let (rect, response) = ui.allocate_exact_size(vec2(200.0, 100.0), egui::Sense::click());
+            counts.center = rect.center();
+            if response.triple_clicked() {
+                counts.clicks[2] += 1;
+            } else if response.double_clicked() {
+                counts.clicks[1] += 1;
+            } else if response.clicked() {
+                counts.clicks[0] += 1;
+            }
+        },
+        Counts::default(),
+    )
+}
+
+fn click(harness: &mut Harness<'_, Counts>, pos: Pos2) {
+    harness.click_at(pos);
+    harness.step();
+}
+
+#[test]
+fn quick_clicks_count_up_to_three() {
+    let mut harness = click_area_harness();
+    let pos = harness.state().center;
+    for _ in 0..4 {
+        click(&mut harness, pos);
+    }
+    assert_eq!(
+        harness.state().clicks,
+        [1, 1, 2],
+        "A fourth click stays a triple click"
+    );
+}
+
+#[test]
+fn far_apart_clicks_are_not_a_double_click() {
+    let mut harness = click_area_harness();
+    let pos = harness.state().center;
+    click(&mut harness, pos);
+    click(&mut harness, pos + Vec2::splat(20.0));
+    assert_eq!(harness.state().clicks, [2, 0, 0]);
+
+    harness
+        .ctx
+        .options_mut(|o| o.input_options.max_multi_click_dist = 50.0);
+    click(&mut harness, pos);
+    assert_eq!(harness.state().clicks, [2, 1, 0]);
+}
+
+#[test]
+fn double_click_delay_is_configurable() {
+    let mut harness = click_area_harness();
+    let pos = harness.state().center;
+    click(&mut harness, pos);
+    harness.run_steps(3);
+    click(&mut harness, pos);
+    assert_eq!(harness.state().clicks, [2, 0, 0], "Too slow by default");
+
+    harness
+        .ctx
+        .options_mut(|o| o.input_options.max_double_click_delay = 1.0);
+    harness.run_steps(3);
+    click(&mut harness, pos);
+    harness.run_steps(3);
+    click(&mut harness, pos);
+    assert_eq!(harness.state().clicks, [2, 1, 1]);
+}
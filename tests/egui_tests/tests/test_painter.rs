@@ -0,0 +1,250 @@
+use egui::epaint::{ClipPolygon, PathStroke, RectShape};
+use egui::{Color32, Gradient, LinePattern, Rect, Shape, Stroke, StrokeKind, pos2, vec2};
+use egui_kittest::Harness;
+
+/// Paints a grid over `rect`, and returns the lines that were painted.
+fn grid_lines(rect: Rect, spacing: egui::Vec2) -> Vec<[egui::Pos2; 2]> {
+    let mut lines = Vec::new();
+    Harness::new_ui(|ui| {
+        let painter = ui
+            .painter()
+            .with_clip_rect(Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0)));
+        let painter = painter.with_layer_id(egui::LayerId::background());
+        lines.clear(); // Only keep the last pass
+        painter.grid(rect, spacing, Stroke::new(1.0, Color32::GRAY));
+        painter.for_each_shape(|clipped| {
+            if let Shape::LineSegment { points, .. } = clipped.shape {
+                lines.push(points);
+            }
+        });
+    });
+    lines
+}
+
+#[test]
+fn grid_only_paints_visible_lines() {
+    let huge = Rect::from_min_max(pos2(-1e6, -1e6), pos2(1e6, 1e6));
+    let lines = grid_lines(huge, vec2(10.0, 10.0));
+    let vertical = lines.iter().filter(|[a, b]| a.x == b.x).count();
+    let horizontal = lines.iter().filter(|[a, b]| a.y == b.y).count();
+    assert_eq!(vertical, 11, "x = 0, 10, …, 100");
+    assert_eq!(horizontal, 6, "y = 0, 10, …, 50");
+    for [a, b] in &lines {
+        assert!(a.x >= 0.0 && b.x <= 100.0 && a.y >= 0.0 && b.y <= 50.0);
+    }
+}
+
+#[test]
+fn grid_lines_stay_put_when_panning() {
+    let lines = grid_lines(
+        Rect::from_min_size(pos2(3.0, 7.0), vec2(20.0, 20.0)),
+        vec2(10.0, 0.0),
+    );
+    let xs: Vec<f32> = lines.iter().map(|[a, _]| a.x).collect();
+    assert_eq!(
+        xs,
+        [10.0, 20.0],
+        "Only vertical lines, on the multiples of the spacing"
+    );
+}
+
+/// Renders a white rectangle and a white hairline on black,
+/// and counts the pixels that are neither, i.e. the anti-aliased ones.
+fn count_blended_pixels(feathering: Option<bool>) -> usize {
+    let mut harness = Harness::builder()
+        .with_size(vec2(60.0, 60.0))
+        .build_ui(move |ui| {
+            let painter = ui.painter();
+            painter.rect_filled(painter.clip_rect(), 0.0, Color32::BLACK);
+
+            let mut rect = RectShape::filled(
+                Rect::from_min_size(pos2(10.3, 10.3), vec2(20.0, 20.0)),
+                0.0,
+                Color32::WHITE,
+            )
+            .with_round_to_pixels(false);
+            let mut hairline = PathStroke::new(1.0, Color32::WHITE);
+            if let Some(feathering) = feathering {
+                rect = rect.with_feathering(feathering);
+                hairline = hairline.with_feathering(feathering);
+            }
+            painter.add(rect);
+            painter.add(Shape::line(
+                vec![pos2(10.0, 45.5), pos2(50.0, 45.5)],
+                hairline,
+            ));
+        });
+    let image = harness.render().expect("Failed to render harness");
+    image
+        .pixels()
+        .filter(|pixel| {
+            let [r, g, b, _] = pixel.0;
+            !matches!((r, g, b), (0, 0, 0) | (255, 255, 255))
+        })
+        .count()
+}
+
+#[test]
+fn feathering_can_be_turned_off_per_shape() {
+    let feathered = count_blended_pixels(None);
+    assert!(50 < feathered, "Anti-aliased by default: {feathered}");
+    assert_eq!(count_blended_pixels(Some(true)), feathered);
+    assert_eq!(count_blended_pixels(Some(false)), 0, "Crisp edges");
+}
+
+#[test]
+fn ui_can_be_clipped_to_a_circle() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(60.0, 60.0))
+        .build_ui(|ui| {
+            let rect = ui.clip_rect();
+            ui.painter().rect_filled(rect, 0.0, Color32::BLACK);
+            ui.scope(|ui| {
+                ui.clip_to_polygon(ClipPolygon::circle(pos2(30.0, 30.0), 20.0));
+                ui.painter().rect_filled(rect, 0.0, Color32::WHITE);
+            });
+        });
+    let image = harness.render().expect("Failed to render harness");
+    let gray = |x, y| image.get_pixel(x, y).0[0];
+    assert_eq!(gray(30, 30), 255, "Inside");
+    assert_eq!(gray(30, 5), 0, "Outside of the circle's bounding box");
+    assert_eq!(
+        gray(13, 13),
+        0,
+        "Outside of the circle, in its bounding box"
+    );
+    assert!(
+        image
+            .pixels()
+            .any(|pixel| 0 < pixel.0[0] && pixel.0[0] < 255),
+        "The edge is anti-aliased"
+    );
+}
+
+#[test]
+fn gradients_are_interpolated_and_rounded() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(100.0, 60.0))
+        .build_ui(|ui| {
+            let painter = ui.painter();
+            painter.rect_filled(painter.clip_rect(), 0.0, Color32::BLACK);
+            painter.rect_gradient(
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 30.0)),
+                10.0,
+                Gradient::horizontal(Color32::BLACK, Color32::WHITE),
+            );
+            painter.convex_polygon_gradient(
+                &[pos2(0.0, 30.0), pos2(100.0, 30.0), pos2(0.0, 60.0)],
+                &Gradient::vertical(Color32::WHITE, Color32::RED),
+            );
+        });
+    let image = harness.render().expect("Failed to render harness");
+    let pixel = |x, y| image.get_pixel(x, y).0;
+
+    let row: Vec<u8> = (0..100).map(|x| pixel(x, 15)[1]).collect();
+    assert!(row.windows(2).all(|pair| pair[0] <= pair[1]), "{row:?}");
+    assert!(row[5] < 30 && 225 < row[95], "{row:?}");
+    assert_eq!(pixel(99, 0)[1], 0, "The rounded corner isn't filled");
+
+    assert!(
+        230 < pixel(5, 31)[1],
+        "Almost white at the top: {:?}",
+        pixel(5, 31)
+    );
+    let [r, g, _, _] = pixel(1, 57);
+    assert!(
+        200 < r && g < 50,
+        "Almost red at the bottom: {:?}",
+        pixel(5, 58)
+    );
+    assert_eq!(pixel(90, 55)[0], 0, "Outside of the triangle");
+}
+
+#[test]
+fn gradients_fade_with_the_ui() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(40.0, 40.0))
+        .build_ui(|ui| {
+            let rect = ui.clip_rect();
+            ui.painter().rect_filled(rect, 0.0, Color32::BLACK);
+            ui.multiply_opacity(0.5);
+            ui.painter()
+                .rect_gradient(rect, 0.0, Gradient::radial(Color32::WHITE, Color32::WHITE));
+        });
+    let image = harness.render().expect("Failed to render harness");
+    let gray = image.get_pixel(20, 20).0[0];
+    assert!(50 < gray && gray < 230, "Half faded: {gray}");
+}
+
+/// Renders a crisp, dashed white outline on black
+/// and returns the brightness of the pixels along the top edge.
+fn dashed_top_edge(offset: f32) -> Vec<u8> {
+    let mut harness = Harness::builder()
+        .with_size(vec2(60.0, 60.0))
+        .build_ui(move |ui| {
+            let painter = ui.painter();
+            painter.rect_filled(painter.clip_rect(), 0.0, Color32::BLACK);
+            painter.add(
+                RectShape::stroke(
+                    Rect::from_min_size(pos2(10.0, 10.0), vec2(40.0, 40.0)),
+                    0.0,
+                    Stroke::new(2.0, Color32::WHITE),
+                    StrokeKind::Inside,
+                )
+                .with_feathering(false)
+                .dashed(LinePattern::dashed(6.0, 6.0).with_offset(offset)),
+            );
+        });
+    let image = harness.render().expect("Failed to render harness");
+    (0..60).map(|x| image.get_pixel(x, 11).0[0]).collect()
+}
+
+#[test]
+fn rect_outline_can_be_dashed() {
+    let row = dashed_top_edge(0.0);
+    assert_eq!(row[5], 0, "Outside of the rectangle");
+    assert_eq!(row[14], 255, "The first dash starts in the corner");
+    assert_eq!(row[20], 0, "Gap");
+    assert_eq!(row[26], 255, "Dash");
+    assert_eq!(row[32], 0, "Gap");
+
+    let marching = dashed_top_edge(6.0);
+    assert_eq!(marching[26], 0, "The dashes moved by half a period");
+    assert_eq!(marching[32], 255, "The dashes moved by half a period");
+    assert_eq!(dashed_top_edge(12.0), row, "A whole period later");
+}
+
+#[test]
+fn rotated_text_bounds() {
+    let ctx = egui::Context::default();
+    let pos = pos2(100.0, 100.0);
+    let mut upright = Rect::NOTHING;
+    let mut rotated = Rect::NOTHING;
+    let _ = ctx.run(Default::default(), |ctx| {
+        let painter = ctx.layer_painter(egui::LayerId::background());
+        let font_id = egui::FontId::default();
+        let anchor = egui::Align2::CENTER_CENTER;
+        upright = painter.text(pos, anchor, "Axis label", font_id.clone(), Color32::WHITE);
+        rotated = painter.text_rotated(
+            pos,
+            anchor,
+            -std::f32::consts::FRAC_PI_2,
+            "Axis label",
+            font_id,
+            Color32::WHITE,
+        );
+    });
+
+    assert!(
+        (rotated.width() - upright.height()).abs() < 0.01,
+        "{rotated:?}"
+    );
+    assert!(
+        (rotated.height() - upright.width()).abs() < 0.01,
+        "{rotated:?}"
+    );
+    assert!(
+        (rotated.center() - pos).length() < 0.01,
+        "Rotated around the anchor"
+    );
+}
@@ -0,0 +1,90 @@
+use egui::{Button, Event, Id, PointerButton, Rect, Sense, Vec2, vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+#[derive(Default)]
+struct State {
+    handle_rect: Option<Rect>,
+    dragged: Vec2,
+    other_clicked: bool,
+    other_hovered: bool,
+    capture: Option<Id>,
+}
+
+/// A handle that captures the pointer when pressed, above a button.
+fn harness() -> Harness<'static, State> {
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            let handle = ui.allocate_response(vec2(100.0, 30.0), Sense::click());
+            if handle.is_pointer_button_down_on() && ui.input(|i| i.pointer.any_pressed()) {
+                handle.set_pointer_capture();
+            }
+            if handle.dragged() {
+                state.dragged += handle.drag_delta();
+            }
+            state.handle_rect = Some(handle.rect);
+
+            let other = ui.add(Button::new("Other"));
+            state.other_clicked |= other.clicked();
+            state.other_hovered = other.hovered();
+            state.capture = ui.ctx().pointer_capture();
+        },
+        State::default(),
+    )
+}
+
+#[test]
+fn captured_widget_keeps_the_pointer() {
+    let mut harness = harness();
+    let start = harness.state().handle_rect.unwrap().center();
+    let other = harness.get_by_label("Other").rect().center();
+
+    harness.pointer_down(start, PointerButton::Primary);
+    harness.run();
+    assert!(harness.state().capture.is_some());
+
+    harness.input_mut().events.push(Event::PointerMoved(other));
+    harness.run();
+    assert!(
+        !harness.state().other_hovered,
+        "Nothing else is hovered while the pointer is captured"
+    );
+    assert_eq!(harness.state().dragged, other - start);
+
+    harness.pointer_up(other, PointerButton::Primary);
+    harness.run();
+    assert!(
+        !harness.state().other_clicked,
+        "Releasing over another widget doesn't click it"
+    );
+    assert_eq!(harness.state().capture, None, "Released on pointer up");
+}
+
+#[test]
+fn capture_is_released_on_pointer_up() {
+    let mut harness = harness();
+    let start = harness.state().handle_rect.unwrap().center();
+    harness.pointer_down(start, PointerButton::Primary);
+    harness.run();
+    harness.pointer_up(start, PointerButton::Primary);
+    harness.run();
+    assert_eq!(harness.state().capture, None);
+
+    // Other widgets work as usual again:
+    harness.get_by_label("Other").click();
+    harness.run();
+    assert!(harness.state().other_clicked);
+}
+
+#[test]
+fn capture_is_released_on_escape() {
+    let mut harness = harness();
+    let start = harness.state().handle_rect.unwrap().center();
+    harness.pointer_down(start, PointerButton::Primary);
+    harness.run();
+    assert!(harness.state().capture.is_some());
+
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert_eq!(harness.state().capture, None);
+}
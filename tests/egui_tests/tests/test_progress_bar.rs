@@ -0,0 +1,56 @@
+use egui::ProgressBar;
+use egui::accesskit::Role;
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+fn progress_bar_harness(progress: Option<f32>) -> Harness<'static, Option<f32>> {
+    Harness::new_ui_state(
+        |ui, progress: &mut Option<f32>| {
+            let bar = match *progress {
+                Some(progress) => ProgressBar::new(progress).show_percentage(),
+                None => ProgressBar::indeterminate(),
+            };
+            ui.add(bar.desired_width(150.0));
+        },
+        progress,
+    )
+}
+
+#[test]
+fn indeterminate_progress_bar_has_no_value() {
+    let mut harness = progress_bar_harness(Some(0.5));
+    harness.step();
+    let node = harness.get_by_role(Role::ProgressIndicator);
+    assert_eq!(node.accesskit_node().numeric_value(), Some(50.0));
+
+    *harness.state_mut() = None;
+    harness.step();
+    let node = harness.get_by_role(Role::ProgressIndicator);
+    assert_eq!(node.accesskit_node().numeric_value(), None);
+}
+
+#[test]
+fn switching_to_indeterminate_keeps_size() {
+    let mut harness = progress_bar_harness(Some(0.25));
+    harness.step();
+    let determinate = harness.get_by_role(Role::ProgressIndicator).rect();
+
+    *harness.state_mut() = None;
+    harness.step();
+    let indeterminate = harness.get_by_role(Role::ProgressIndicator).rect();
+    assert_eq!(determinate, indeterminate);
+}
+
+#[test]
+fn indeterminate_progress_bar_animates_while_visible() {
+    let mut harness = progress_bar_harness(None);
+    harness.step();
+    assert!(harness.ctx.has_requested_repaint(), "Should keep animating");
+
+    let mut harness = progress_bar_harness(Some(0.5));
+    harness.step();
+    assert!(
+        !harness.ctx.has_requested_repaint(),
+        "A determinate bar is static"
+    );
+}
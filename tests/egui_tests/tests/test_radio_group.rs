@@ -0,0 +1,96 @@
+use egui::Key;
+use egui::accesskit::{Role, Toggled};
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Fruit {
+    Apple,
+    Banana,
+    Cherry,
+}
+
+fn radio_group_harness(fruit: Fruit) -> Harness<'static, Fruit> {
+    Harness::new_ui_state(
+        |ui, fruit: &mut Fruit| {
+            _ = ui.button("Before");
+            ui.radio_group(
+                fruit,
+                [
+                    (Fruit::Apple, "Apple"),
+                    (Fruit::Banana, "Banana"),
+                    (Fruit::Cherry, "Cherry"),
+                ],
+            );
+            _ = ui.button("After");
+        },
+        fruit,
+    )
+}
+
+fn press(harness: &mut Harness<'_, Fruit>, key: Key) {
+    harness.key_press(key);
+    harness.run();
+}
+
+#[test]
+fn radio_group_is_a_single_tab_stop() {
+    let mut harness = radio_group_harness(Fruit::Banana);
+
+    press(&mut harness, Key::Tab);
+    assert!(harness.get_by_label("Before").is_focused(), "Before");
+    press(&mut harness, Key::Tab);
+    assert!(
+        harness.get_by_label("Banana").is_focused(),
+        "Tab focuses the selected radio button"
+    );
+    press(&mut harness, Key::Tab);
+    assert!(
+        harness.get_by_label("After").is_focused(),
+        "Tab leaves the group"
+    );
+}
+
+#[test]
+fn radio_group_arrow_keys_move_selection_and_focus() {
+    let mut harness = radio_group_harness(Fruit::Banana);
+    press(&mut harness, Key::Tab);
+    press(&mut harness, Key::Tab);
+
+    press(&mut harness, Key::ArrowDown);
+    assert_eq!(*harness.state(), Fruit::Cherry);
+    assert!(harness.get_by_label("Cherry").is_focused(), "Cherry");
+
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(*harness.state(), Fruit::Apple, "Wraps around");
+    assert!(harness.get_by_label("Apple").is_focused(), "Apple");
+
+    press(&mut harness, Key::ArrowUp);
+    assert_eq!(*harness.state(), Fruit::Cherry, "Wraps around backwards");
+
+    press(&mut harness, Key::Tab);
+    assert!(
+        harness.get_by_label("After").is_focused(),
+        "Tab leaves the group"
+    );
+}
+
+#[test]
+fn radio_group_accessibility_tree() {
+    let harness = radio_group_harness(Fruit::Cherry);
+
+    let group = harness.get_by_role(Role::RadioGroup);
+    let radios: Vec<_> = group.get_all_by_role(Role::RadioButton).collect();
+    assert_eq!(radios.len(), 3, "One radio button per alternative");
+    assert_eq!(
+        radios
+            .iter()
+            .map(|radio| radio.accesskit_node().toggled())
+            .collect::<Vec<_>>(),
+        [
+            Some(Toggled::False),
+            Some(Toggled::False),
+            Some(Toggled::True)
+        ]
+    );
+}
@@ -0,0 +1,49 @@
+use egui::{TextureId, Vec2, epaint::Shape};
+use egui_kittest::Harness;
+
+fn contains_text(shapes: &[egui::epaint::ClippedShape], text: &str) -> bool {
+    shapes.iter().any(|clipped| match &clipped.shape {
+        Shape::Text(text_shape) => text_shape.galley.text() == text,
+        _ => false,
+    })
+}
+
+#[test]
+fn render_ui_to_texture_is_painted_off_screen() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(400.0, 200.0))
+        .with_pixels_per_point(2.0)
+        .build_ui_state(
+            |ui, texture_id: &mut Option<TextureId>| {
+                let texture =
+                    ui.ctx()
+                        .render_ui_to_texture("preview", Vec2::new(100.0, 50.0), |ui| {
+                            ui.label("Off-screen");
+                        });
+                ui.image((texture.id(), Vec2::new(50.0, 25.0)));
+                ui.label("On-screen");
+                *texture_id = Some(texture.id());
+            },
+            None,
+        );
+    harness.run();
+
+    let output = harness.output();
+    let texture_id = harness.state().unwrap();
+    let render = output
+        .texture_renders
+        .iter()
+        .find(|render| render.texture_id == texture_id)
+        .expect("The texture should be rendered");
+
+    assert_eq!(render.size_in_pixels, [200, 100]);
+    assert_eq!(render.pixels_per_point, 2.0);
+    assert!(contains_text(&render.shapes, "Off-screen"));
+    assert!(!contains_text(&render.shapes, "On-screen"));
+
+    assert!(contains_text(&output.shapes, "On-screen"));
+    assert!(
+        !contains_text(&output.shapes, "Off-screen"),
+        "Contents rendered to a texture are not painted on screen"
+    );
+}
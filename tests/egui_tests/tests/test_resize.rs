@@ -0,0 +1,122 @@
+use egui::{Context, Id, Pos2, Rect, Resize, Vec2, Window, pos2, vec2};
+use egui_kittest::Harness;
+
+/// The size of the [`Resize`] area and of its contents.
+struct Sizes {
+    area: Rect,
+    contents: Vec2,
+}
+
+fn resize_harness(resize: fn(Resize) -> Resize) -> Harness<'static, Sizes> {
+    Harness::builder().with_step_dt(0.05).build_ui_state(
+        move |ui, sizes: &mut Sizes| {
+            resize(Resize::default().default_size(vec2(160.0, 90.0))).show(ui, |ui| {
+                sizes.area = ui.max_rect();
+                sizes.contents = ui.label("Preview").rect.size();
+            });
+        },
+        Sizes {
+            area: Rect::NOTHING,
+            contents: Vec2::ZERO,
+        },
+    )
+}
+
+/// Where to grab the resize corner.
+fn corner(harness: &Harness<'_, Sizes>) -> Pos2 {
+    harness.state().area.max - vec2(3.0, 3.0)
+}
+
+fn assert_aspect_ratio(size: Vec2, aspect_ratio: f32) {
+    assert!(
+        (size.x / size.y - aspect_ratio).abs() < 0.01,
+        "{size:?} is not {aspect_ratio}:1"
+    );
+}
+
+#[test]
+fn corner_drags_keep_the_aspect_ratio() {
+    let mut harness = resize_harness(|resize| resize.fixed_aspect_ratio(16.0 / 9.0));
+    let before = harness.state().area.size();
+
+    let from = corner(&harness);
+    harness.drag(from, from + vec2(100.0, 10.0));
+    harness.run();
+    let wider = harness.state().area.size();
+    assert!(before.x + 90.0 < wider.x, "The width leads: {wider:?}");
+    assert_aspect_ratio(wider, 16.0 / 9.0);
+
+    let from = corner(&harness);
+    harness.drag(from, from + vec2(-5.0, -50.0));
+    harness.run();
+    let lower = harness.state().area.size();
+    assert!(lower.y < wider.y - 40.0, "The height leads: {lower:?}");
+    assert_aspect_ratio(lower, 16.0 / 9.0);
+}
+
+#[test]
+fn corner_drags_snap_to_the_resize_step() {
+    let mut harness = resize_harness(|resize| resize.resize_step(vec2(8.0, 16.0)));
+    let from = corner(&harness);
+    harness.drag(from, from + vec2(37.0, 29.0));
+    harness.run();
+    let size = harness.state().area.size();
+    assert_eq!(size.x % 8.0, 0.0, "{size:?}");
+    assert_eq!(size.y % 16.0, 0.0, "{size:?}");
+}
+
+#[test]
+fn double_clicking_the_corner_fits_the_contents() {
+    let mut harness = resize_harness(|resize| resize.fixed_aspect_ratio(2.0));
+    let pos = corner(&harness);
+    for _ in 0..2 {
+        harness.click_at(pos);
+        harness.step();
+    }
+    harness.run();
+
+    let Sizes { area, contents } = *harness.state();
+    assert!(area.width() < 160.0, "Shrunk: {area:?}");
+    assert_aspect_ratio(area.size(), 2.0);
+    assert!(
+        (area.width() - contents.x).abs() < 0.01,
+        "Fits the wide contents: {area:?}"
+    );
+    assert!(contents.y <= area.height());
+}
+
+#[test]
+fn window_edge_drags_keep_the_aspect_ratio() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(800.0, 600.0))
+        .with_step_dt(0.05)
+        .build_state(
+            |ctx: &Context, contents: &mut Rect| {
+                Window::new("Video")
+                    .default_pos(pos2(40.0, 40.0))
+                    .fixed_aspect_ratio(2.0)
+                    .show(ctx, |ui| {
+                        *contents = ui.max_rect();
+                        ui.label("Preview");
+                    });
+            },
+            Rect::NOTHING,
+        );
+    let window = harness
+        .ctx
+        .memory(|mem| mem.area_rect(Id::new("Video")))
+        .unwrap();
+    let before = *harness.state();
+
+    let from = window.right_center();
+    harness.drag(from, from + vec2(100.0, 0.0));
+    harness.run();
+    let after = *harness.state();
+    assert_eq!(
+        after.left_top(),
+        before.left_top(),
+        "Grows to the right and down"
+    );
+    assert!(window.width() + 50.0 < after.width(), "{after:?}");
+    assert_aspect_ratio(after.size(), 2.0);
+}
@@ -0,0 +1,79 @@
+use egui::{Rect, Ui, epaint::Shape, text::TextDirection};
+use egui_kittest::Harness;
+
+fn right_to_left(ui: &mut Ui) {
+    ui.style_mut().text_direction = TextDirection::RightToLeft;
+}
+
+/// The leftmost position of the text shape with the given text.
+fn text_shape_x<State>(harness: &Harness<'_, State>, text: &str) -> f32 {
+    harness
+        .output()
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            Shape::Text(text_shape) if text_shape.galley.text() == text => Some(text_shape.pos.x),
+            _ => None,
+        })
+        .expect("The text should be painted")
+}
+
+#[test]
+fn horizontal_fills_from_the_right() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, rects: &mut Vec<Rect>| {
+            right_to_left(ui);
+            ui.horizontal(|ui| {
+                *rects = vec![ui.label("first").rect, ui.label("second").rect];
+            });
+        },
+        vec![],
+    );
+    harness.run();
+
+    let rects = harness.state();
+    assert!(rects[0].min.x > rects[1].max.x, "{rects:?}");
+}
+
+#[test]
+fn checkbox_is_mirrored() {
+    let checkbox_text_offset = |rtl: bool| {
+        let mut harness = Harness::builder().build_ui_state(
+            |ui, rect: &mut Rect| {
+                if rtl {
+                    right_to_left(ui);
+                }
+                *rect = ui.checkbox(&mut true, "Check").rect;
+            },
+            Rect::NOTHING,
+        );
+        harness.run();
+        text_shape_x(&harness, "Check") - harness.state().min.x
+    };
+
+    let icon_width = egui::Style::default().spacing.icon_width;
+    assert!(checkbox_text_offset(false) >= icon_width);
+    assert!(
+        checkbox_text_offset(true) < icon_width,
+        "The box should be to the right of the text"
+    );
+}
+
+#[test]
+fn labels_use_the_text_direction() {
+    let mut harness = Harness::builder().build_ui(|ui| {
+        right_to_left(ui);
+        ui.label("abc");
+    });
+    harness.run();
+
+    let direction = harness
+        .output()
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            Shape::Text(text_shape) => Some(text_shape.galley.job.direction),
+            _ => None,
+        });
+    assert_eq!(direction, Some(TextDirection::RightToLeft));
+}
@@ -0,0 +1,103 @@
+use egui::{Event, Label, Pos2, Rect, Scene, SceneTransform, pos2, vec2};
+use egui_kittest::Harness;
+
+struct State {
+    scene_rect: Rect,
+    transform: SceneTransform,
+    label_rect: Rect,
+    label_hovered: bool,
+}
+
+/// A scene with a label at (100, 50) in the scene.
+fn harness() -> Harness<'static, State> {
+    Harness::builder()
+        .with_size(vec2(400.0, 400.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let response = Scene::new()
+                    .zoom_range(0.1..=10.0)
+                    .show(ui, &mut state.scene_rect, |ui| {
+                        let label = ui.put(
+                            Rect::from_min_size(pos2(100.0, 50.0), vec2(80.0, 20.0)),
+                            Label::new("Node"),
+                        );
+                        state.label_rect = label.rect;
+                        state.label_hovered = label.hovered();
+                    })
+                    .response;
+                state.transform = SceneTransform::from_response(&response);
+            },
+            State {
+                scene_rect: Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0)),
+                transform: SceneTransform::default(),
+                label_rect: Rect::NOTHING,
+                label_hovered: false,
+            },
+        )
+}
+
+#[test]
+fn to_screen_and_back() {
+    let harness = harness();
+    let transform = harness.state().transform;
+    let pos = pos2(12.0, 34.0);
+    let on_screen = transform.to_screen(pos);
+    assert!((transform.to_world(on_screen) - pos).length() < 1e-3);
+
+    let rect = Rect::from_min_size(pos, vec2(10.0, 20.0));
+    let back = transform.rect_to_world(transform.rect_to_screen(rect));
+    assert!((back.min - rect.min).length() < 1e-3);
+    assert!((back.max - rect.max).length() < 1e-3);
+}
+
+#[test]
+fn children_are_transformed() {
+    let mut harness = harness();
+    let before = harness.state().transform;
+    let start = pos2(300.0, 300.0);
+    harness.drag(start, start + vec2(40.0, 20.0));
+    harness.run();
+
+    let state = harness.state();
+    assert_eq!(
+        state.label_rect.min,
+        pos2(100.0, 50.0),
+        "In scene coordinates"
+    );
+
+    let panned = state.transform.to_screen(Pos2::ZERO) - before.to_screen(Pos2::ZERO);
+    assert!(
+        (panned - vec2(40.0, 20.0)).length() < 1e-3,
+        "Panned by the drag: {panned:?}"
+    );
+
+    let on_screen = state.transform.rect_to_screen(state.label_rect);
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(on_screen.center()));
+    harness.run();
+    assert!(harness.state().label_hovered, "The label is where we think");
+}
+
+#[test]
+fn zooms_toward_the_pointer() {
+    let mut harness = harness();
+    let pointer = pos2(120.0, 80.0);
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pointer));
+    harness.run();
+    let before = harness.state().transform;
+
+    harness.input_mut().events.push(Event::Zoom(2.0));
+    harness.run();
+    let after = harness.state().transform;
+
+    assert!((after.zoom() - 2.0 * before.zoom()).abs() < 1e-3);
+    assert!(
+        (after.to_world(pointer) - before.to_world(pointer)).length() < 1e-3,
+        "The point under the pointer stays put"
+    );
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use egui::{
+    Color32, ColorImage, Event, OutputCommand, Rect, ViewportCommand, ViewportId, pos2, vec2,
+};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// A 100x50 point screen at 2 pixels per point, where the gray level is the pixel column.
+fn fake_screen() -> Arc<ColorImage> {
+    let [width, height] = [200, 100];
+    let pixels = (0..width * height)
+        .map(|i| Color32::from_gray((i % width) as u8))
+        .collect();
+    Arc::new(ColorImage::new([width, height], pixels))
+}
+
+/// Do what an integration does with the screenshots egui asks for.
+fn take_screenshots(harness: &mut Harness<'_>, screen: &Arc<ColorImage>) {
+    let commands = harness.output().viewport_output[&ViewportId::ROOT]
+        .commands
+        .clone();
+    for command in commands {
+        if let ViewportCommand::Screenshot(user_data) = command {
+            harness.input_mut().events.push(Event::Screenshot {
+                viewport_id: ViewportId::ROOT,
+                user_data,
+                image: Arc::clone(screen),
+            });
+        }
+    }
+}
+
+fn screenshot_harness(rect: Option<Rect>) -> Harness<'static> {
+    Harness::builder()
+        .with_size(vec2(100.0, 50.0))
+        .with_pixels_per_point(2.0)
+        .build(move |ctx| ctx.request_screenshot(rect))
+}
+
+#[test]
+fn screenshot_of_whole_screen() {
+    let screen = fake_screen();
+    let mut harness = screenshot_harness(None);
+    harness.step();
+    assert!(
+        harness.output().platform_output.screenshots.is_empty(),
+        "Screenshots arrive one frame later"
+    );
+
+    take_screenshots(&mut harness, &screen);
+    harness.step();
+
+    let screenshots = &harness.output().platform_output.screenshots;
+    assert_eq!(screenshots.len(), 1);
+    assert_eq!(screenshots[0].viewport_id, ViewportId::ROOT);
+    assert_eq!(
+        screenshots[0].rect,
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0))
+    );
+    assert_eq!(screenshots[0].image, screen);
+}
+
+#[test]
+fn screenshot_of_region() {
+    let screen = fake_screen();
+    let rect = Rect::from_min_max(pos2(10.0, 5.0), pos2(30.0, 100.0));
+    let mut harness = screenshot_harness(Some(rect));
+    harness.step();
+    take_screenshots(&mut harness, &screen);
+    harness.step();
+
+    let screenshots = &harness.output().platform_output.screenshots;
+    assert_eq!(screenshots.len(), 1);
+    let screenshot = &screenshots[0];
+    assert_eq!(
+        screenshot.rect,
+        Rect::from_min_max(pos2(10.0, 5.0), pos2(30.0, 50.0)),
+        "The region is clipped to the screen"
+    );
+    assert_eq!(screenshot.image.size, [40, 90]);
+    assert_eq!(screenshot.image.pixels[0], Color32::from_gray(20));
+    assert_eq!(screenshot.image.pixels[39], Color32::from_gray(59));
+}
+
+#[test]
+fn copy_screenshot_to_clipboard() {
+    let screen = fake_screen();
+    // Same as in the docs of `Context::copy_image`:
+    let mut harness = Harness::new_ui(|ui| {
+        if ui.button("Copy screenshot").clicked() {
+            ui.ctx().request_screenshot(None);
+        }
+        for screenshot in ui.ctx().output(|o| o.screenshots.clone()) {
+            ui.ctx().copy_image((*screenshot.image).clone());
+        }
+    });
+    harness.get_by_label("Copy screenshot").click();
+    harness.step();
+    take_screenshots(&mut harness, &screen);
+    harness.step();
+
+    let copied: Vec<_> = harness
+        .output()
+        .platform_output
+        .commands
+        .iter()
+        .filter_map(|command| match command {
+            OutputCommand::CopyImage(image) => Some(image.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(copied, vec![(*screen).clone()]);
+}
@@ -0,0 +1,540 @@
+use egui::{
+    Color32, Event, PointerButton, Pos2, ScrollArea, Vec2, pos2,
+    scroll_area::ScrollBarVisibility,
+    scroll_area::ScrollMarker,
+    style::{ScrollAnimation, ScrollStyle},
+};
+use egui_kittest::Harness;
+
+struct State {
+    kinetic: bool,
+    offset: Vec2,
+}
+
+fn scroll_area_harness(kinetic: bool) -> Harness<'static, State> {
+    Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, state: &mut State| {
+                // Selectable labels would steal the drag:
+                ui.style_mut().interaction.selectable_labels = false;
+                let output = ScrollArea::vertical()
+                    .kinetic(state.kinetic)
+                    .show(ui, |ui| {
+                        for i in 0..200 {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                state.offset = output.state.offset;
+            },
+            State {
+                kinetic,
+                offset: Vec2::ZERO,
+            },
+        )
+}
+
+/// Flick the contents upwards, i.e. scroll down.
+fn flick(harness: &mut Harness<'_, State>) {
+    let mut pos = pos2(30.0, 150.0);
+    harness.input_mut().events.push(Event::PointerMoved(pos));
+    harness.step();
+    harness.pointer_down(pos, PointerButton::Primary);
+    harness.step();
+    for _ in 0..5 {
+        pos.y -= 20.0;
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+    }
+    harness.pointer_up(pos, PointerButton::Primary);
+    harness.step();
+}
+
+#[test]
+fn scroll_area_glides_after_drag() {
+    let mut harness = scroll_area_harness(true);
+    flick(&mut harness);
+
+    let released_offset = harness.state().offset.y;
+    assert!(0.0 < released_offset);
+
+    harness.run_steps(10);
+    let gliding_offset = harness.state().offset.y;
+    assert!(released_offset < gliding_offset, "Keeps scrolling");
+
+    // Eventually comes to a stop:
+    harness.run_steps(300);
+    let stopped_offset = harness.state().offset.y;
+    harness.run_steps(10);
+    assert_eq!(harness.state().offset.y, stopped_offset);
+}
+
+#[test]
+fn scroll_area_without_kinetic_stops_on_release() {
+    let mut harness = scroll_area_harness(false);
+    flick(&mut harness);
+
+    let released_offset = harness.state().offset.y;
+    assert!(0.0 < released_offset);
+
+    harness.run_steps(10);
+    assert_eq!(harness.state().offset.y, released_offset);
+}
+
+#[derive(Default)]
+struct AnimatedState {
+    /// Scroll to the top of this row.
+    scroll_to: Option<usize>,
+
+    /// Set the offset explicitly.
+    set_offset: Option<f32>,
+
+    offset: f32,
+    row_height: f32,
+}
+
+fn animated_harness() -> Harness<'static, AnimatedState> {
+    Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, state: &mut AnimatedState| {
+                // The harness turns scroll animations off:
+                ui.ctx()
+                    .style_mut(|style| style.scroll_animation = ScrollAnimation::default());
+                let mut scroll_area = ScrollArea::vertical();
+                if let Some(offset) = state.set_offset.take() {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+                let output = scroll_area.show(ui, |ui| {
+                    for i in 0..200 {
+                        let response = ui.label(format!("Row {i}"));
+                        state.row_height = response.rect.height() + ui.spacing().item_spacing.y;
+                        if state.scroll_to == Some(i) {
+                            response.scroll_to_me(Some(egui::Align::TOP));
+                        }
+                    }
+                });
+                state.scroll_to = None;
+                state.offset = output.state.offset.y;
+            },
+            AnimatedState::default(),
+        )
+}
+
+fn scroll_to_row(harness: &mut Harness<'_, AnimatedState>, row: usize) {
+    harness.state_mut().scroll_to = Some(row);
+    harness.step();
+}
+
+#[test]
+fn scroll_to_is_animated() {
+    let mut harness = animated_harness();
+    scroll_to_row(&mut harness, 50);
+    harness.step();
+    let target = 50.0 * harness.state().row_height;
+    let offset = harness.state().offset;
+    assert!(0.0 < offset && offset < target, "Moving: {offset}");
+
+    harness.run_steps(30);
+    let state = harness.state();
+    assert!((state.offset - target).abs() < state.row_height, "Arrived");
+}
+
+#[test]
+fn wheel_cancels_scroll_animation() {
+    let mut harness = animated_harness();
+    scroll_to_row(&mut harness, 100);
+    harness.step();
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(50.0, 50.0)));
+    harness.input_mut().events.push(Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: egui::vec2(0.0, -1.0),
+        modifiers: Default::default(),
+    });
+    harness.step();
+    let cancelled_at = harness.state().offset;
+
+    harness.run();
+    assert!(
+        (harness.state().offset - cancelled_at).abs() < 2.0,
+        "Stops where the user took over"
+    );
+}
+
+#[test]
+fn explicit_offset_is_instant() {
+    let mut harness = animated_harness();
+    scroll_to_row(&mut harness, 100);
+    harness.step();
+
+    harness.state_mut().set_offset = Some(30.0);
+    harness.step();
+    assert_eq!(harness.state().offset, 30.0);
+    harness.run();
+    assert_eq!(harness.state().offset, 30.0, "Cancels the animation");
+}
+
+#[test]
+fn retargeting_carries_on() {
+    let mut harness = animated_harness();
+    scroll_to_row(&mut harness, 50);
+    harness.run_steps(3);
+    let before = harness.state().offset;
+
+    scroll_to_row(&mut harness, 100);
+    let after = harness.state().offset;
+    harness.step();
+    let speed_before = after - before;
+    let speed_after = harness.state().offset - after;
+    assert!(0.0 < speed_before, "Moving");
+    assert!(
+        speed_before <= speed_after,
+        "Doesn't start over from standstill: {speed_before} then {speed_after}"
+    );
+
+    harness.run_steps(30);
+    let state = harness.state();
+    let target = 100.0 * state.row_height;
+    assert!((state.offset - target).abs() < state.row_height, "Arrived");
+}
+
+struct OverscrollState {
+    overscroll: bool,
+    offset: f32,
+    first_row_top: f32,
+}
+
+fn overscroll_harness(overscroll: bool) -> Harness<'static, OverscrollState> {
+    Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, state: &mut OverscrollState| {
+                ui.style_mut().interaction.selectable_labels = false;
+                let output = ScrollArea::vertical()
+                    .overscroll(state.overscroll)
+                    .show(ui, |ui| {
+                        for i in 0..200 {
+                            let response = ui.label(format!("Row {i}"));
+                            if i == 0 {
+                                state.first_row_top = response.rect.top();
+                            }
+                        }
+                    });
+                state.offset = output.state.offset.y;
+            },
+            OverscrollState {
+                overscroll,
+                offset: 0.0,
+                first_row_top: 0.0,
+            },
+        )
+}
+
+/// Drag the contents by `dy` over `steps` frames, and keep holding.
+fn drag_and_hold(harness: &mut Harness<'_, OverscrollState>, dy: f32, steps: usize) {
+    let mut pos = pos2(30.0, 50.0);
+    harness.input_mut().events.push(Event::PointerMoved(pos));
+    harness.step();
+    harness.pointer_down(pos, PointerButton::Primary);
+    harness.step();
+    for _ in 0..steps {
+        pos.y += dy / steps as f32;
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+    }
+}
+
+fn release(harness: &mut Harness<'_, OverscrollState>) {
+    let pos = harness.ctx.input(|i| i.pointer.latest_pos()).unwrap();
+    harness.pointer_up(pos, PointerButton::Primary);
+    harness.step();
+}
+
+#[test]
+fn overscroll_moves_content_with_resistance() {
+    let mut harness = overscroll_harness(true);
+    let resting_top = harness.state().first_row_top;
+
+    drag_and_hold(&mut harness, 100.0, 10);
+    let state = harness.state();
+    assert_eq!(state.offset, 0.0, "The reported offset stays in bounds");
+    let moved = state.first_row_top - resting_top;
+    assert!(
+        0.0 < moved && moved < 100.0,
+        "Moves less than the pointer: {moved}"
+    );
+}
+
+#[test]
+fn overscroll_springs_back() {
+    let mut harness = overscroll_harness(true);
+    let resting_top = harness.state().first_row_top;
+    drag_and_hold(&mut harness, 100.0, 10);
+    release(&mut harness);
+
+    // After about 300 ms:
+    harness.run_steps(18);
+    assert!((harness.state().first_row_top - resting_top).abs() < 1.0);
+    harness.run();
+    assert_eq!(harness.state().first_row_top, resting_top);
+}
+
+#[test]
+fn no_overscroll_by_default() {
+    let mut harness = overscroll_harness(false);
+    let resting_top = harness.state().first_row_top;
+    drag_and_hold(&mut harness, 100.0, 10);
+    assert_eq!(harness.state().first_row_top, resting_top);
+}
+
+#[test]
+fn no_overscroll_with_mouse_wheel() {
+    let mut harness = overscroll_harness(true);
+    let resting_top = harness.state().first_row_top;
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(30.0, 50.0)));
+    harness.input_mut().events.push(Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: egui::vec2(0.0, 100.0),
+        modifiers: Default::default(),
+    });
+    harness.run_steps(10);
+    assert_eq!(harness.state().first_row_top, resting_top);
+}
+
+#[test]
+fn fling_into_end_bounces() {
+    let mut harness = overscroll_harness(true);
+    let resting_top = harness.state().first_row_top;
+
+    // Scroll down a bit, then fling back up to the start:
+    drag_and_hold(&mut harness, -50.0, 10);
+    release(&mut harness);
+    harness.run_steps(100);
+    drag_and_hold(&mut harness, 60.0, 3);
+    release(&mut harness);
+
+    let mut max_overshoot: f32 = 0.0;
+    for _ in 0..60 {
+        harness.step();
+        max_overshoot = max_overshoot.max(harness.state().first_row_top - resting_top);
+    }
+    assert!(
+        1.0 < max_overshoot && max_overshoot < 50.0,
+        "A small bounce: {max_overshoot}"
+    );
+    assert_eq!(harness.state().first_row_top, resting_top, "Springs back");
+}
+
+struct MarkerState {
+    marker_row: usize,
+    offset: f32,
+    row_height: f32,
+    inner_rect: egui::Rect,
+    content_height: f32,
+}
+
+/// 200 rows, and a marker at the top of `marker_row`.
+fn marker_harness(marker_row: usize) -> Harness<'static, MarkerState> {
+    Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut MarkerState| {
+                let row_height = ui.text_style_height(&egui::TextStyle::Body);
+                state.row_height = row_height + ui.spacing().item_spacing.y;
+                let marker =
+                    ScrollMarker::new(state.marker_row as f32 * state.row_height, Color32::RED);
+                let output = ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .markers([marker])
+                    .show_rows(ui, row_height, 200, |ui, rows| {
+                        for row in rows {
+                            ui.label(format!("Row {row}"));
+                        }
+                    });
+                state.offset = output.state.offset.y;
+                state.inner_rect = output.inner_rect;
+                state.content_height = output.content_size.y;
+            },
+            MarkerState {
+                marker_row,
+                offset: 0.0,
+                row_height: 0.0,
+                inner_rect: egui::Rect::NOTHING,
+                content_height: 0.0,
+            },
+        )
+}
+
+/// Where the marker for `offset` is on the (floating) scroll bar.
+fn marker_pos(state: &MarkerState, offset: f32) -> Pos2 {
+    let rect = state.inner_rect;
+    let y = rect.top() + rect.height() * (offset / state.content_height).clamp(0.0, 1.0);
+    pos2(rect.right() - 4.0, y)
+}
+
+fn click(harness: &mut Harness<'_, MarkerState>, pos: Pos2) {
+    // Hover first, so that the floating scroll bar expands:
+    harness.input_mut().events.push(Event::PointerMoved(pos));
+    harness.run();
+    harness.pointer_down(pos, PointerButton::Primary);
+    harness.step();
+    harness.pointer_up(pos, PointerButton::Primary);
+    harness.step();
+    harness.run();
+}
+
+#[test]
+fn clicking_marker_scrolls_to_it() {
+    let mut harness = marker_harness(150);
+    let state = harness.state();
+    let marker_offset = 150.0 * state.row_height;
+    let pos = marker_pos(state, marker_offset);
+    let view_height = state.inner_rect.height();
+
+    click(&mut harness, pos);
+    let offset = harness.state().offset;
+    let centered = marker_offset - 0.5 * view_height;
+    assert!(
+        (offset - centered).abs() < 1.0,
+        "The marker is in the middle: {offset} vs {centered}"
+    );
+}
+
+#[test]
+fn clicking_track_without_marker_jumps_as_usual() {
+    let mut with_marker = marker_harness(150);
+    let mut without_marker = marker_harness(150);
+    let state = with_marker.state();
+    let pos = marker_pos(state, 0.5 * state.content_height);
+
+    // The marker is far from the click, so the two behave the same:
+    without_marker.state_mut().marker_row = 10;
+    click(&mut with_marker, pos);
+    click(&mut without_marker, pos);
+    assert!(without_marker.state().offset > 0.0, "Jumped");
+    assert_eq!(with_marker.state().offset, without_marker.state().offset);
+}
+
+#[test]
+fn marker_past_the_end_is_clipped_to_the_track() {
+    let mut harness = marker_harness(1000);
+    let state = harness.state();
+    let pos = marker_pos(state, f32::INFINITY) - egui::vec2(0.0, 1.0);
+    let max_offset = state.content_height - state.inner_rect.height();
+
+    click(&mut harness, pos);
+    assert_eq!(harness.state().offset, max_offset);
+}
+
+struct VisibilityState {
+    visibility: [ScrollBarVisibility; 2],
+    num_rows: usize,
+    offset: Vec2,
+    inner_rect: egui::Rect,
+}
+
+fn visibility_harness(visibility: [ScrollBarVisibility; 2]) -> Harness<'static, VisibilityState> {
+    Harness::builder()
+        .with_size(egui::vec2(200.0, 200.0))
+        .build_ui_state(
+            |ui, state: &mut VisibilityState| {
+                ui.style_mut().interaction.selectable_labels = false;
+                ui.spacing_mut().scroll = ScrollStyle::solid();
+                let output = ScrollArea::both()
+                    .auto_shrink(false)
+                    .horizontal_scroll_bar_visibility(state.visibility[0])
+                    .vertical_scroll_bar_visibility(state.visibility[1])
+                    .show(ui, |ui| {
+                        for i in 0..state.num_rows {
+                            ui.label(format!("Row {i}"));
+                        }
+                    });
+                state.offset = output.state.offset;
+                state.inner_rect = output.inner_rect;
+            },
+            VisibilityState {
+                visibility,
+                num_rows: 200,
+                offset: Vec2::ZERO,
+                inner_rect: egui::Rect::NOTHING,
+            },
+        )
+}
+
+#[test]
+fn hidden_scroll_bars_still_scroll_with_the_wheel() {
+    let mut harness = visibility_harness([ScrollBarVisibility::AlwaysHidden; 2]);
+    let full_width = harness.state().inner_rect.width();
+    assert_eq!(full_width, 200.0 - 2.0 * 8.0, "No space for the bars");
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(30.0, 50.0)));
+    harness.input_mut().events.push(Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: egui::vec2(0.0, -100.0),
+        modifiers: Default::default(),
+    });
+    harness.run_steps(10);
+    assert_eq!(harness.state().offset.y, 100.0);
+}
+
+#[test]
+fn hidden_scroll_bars_still_scroll_by_dragging() {
+    let mut harness = visibility_harness([ScrollBarVisibility::AlwaysHidden; 2]);
+    let mut pos = pos2(30.0, 150.0);
+    harness.input_mut().events.push(Event::PointerMoved(pos));
+    harness.step();
+    harness.pointer_down(pos, PointerButton::Primary);
+    harness.step();
+    for _ in 0..5 {
+        pos.y -= 20.0;
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+    }
+    assert!(
+        harness.state().offset.y > 50.0,
+        "{:?}",
+        harness.state().offset
+    );
+}
+
+#[test]
+fn scroll_bar_visibility_is_per_axis() {
+    use ScrollBarVisibility::{AlwaysHidden, AlwaysVisible, VisibleWhenNeeded};
+
+    let bar_width = ScrollStyle::solid().allocated_width();
+    let mut harness = visibility_harness([AlwaysHidden, AlwaysVisible]);
+    harness.state_mut().num_rows = 1;
+    harness.run();
+    let inner = harness.state().inner_rect;
+    assert_eq!(
+        inner.width(),
+        200.0 - 2.0 * 8.0 - bar_width,
+        "Space for the vertical bar, even if it isn't needed"
+    );
+    assert_eq!(inner.height(), 200.0 - 2.0 * 8.0, "No horizontal bar");
+
+    harness.state_mut().num_rows = 200;
+    harness.run();
+    assert_eq!(
+        harness.state().inner_rect,
+        inner,
+        "Growing contents don't change the layout"
+    );
+
+    harness.state_mut().visibility = [VisibleWhenNeeded, AlwaysHidden];
+    harness.run();
+    assert_eq!(harness.state().inner_rect.width(), 200.0 - 2.0 * 8.0);
+}
@@ -0,0 +1,124 @@
+use egui::Key;
+use egui::accesskit::Toggled;
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Period {
+    Day,
+    Week,
+    Month,
+}
+
+struct State {
+    period: Period,
+    equal_widths: bool,
+    changes: usize,
+}
+
+fn harness(period: Period, equal_widths: bool) -> Harness<'static, State> {
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            _ = ui.button("Before");
+            let response = ui.add(
+                egui::SegmentedControl::new(
+                    &mut state.period,
+                    [
+                        (Period::Day, "Day"),
+                        (Period::Week, "Week"),
+                        (Period::Month, "A whole month"),
+                    ],
+                )
+                .equal_widths(state.equal_widths),
+            );
+            if response.changed() {
+                state.changes += 1;
+            }
+            _ = ui.button("After");
+        },
+        State {
+            period,
+            equal_widths,
+            changes: 0,
+        },
+    )
+}
+
+fn press(harness: &mut Harness<'_, State>, key: Key) {
+    harness.key_press(key);
+    harness.run();
+}
+
+#[test]
+fn clicking_selects_a_segment() {
+    let mut harness = harness(Period::Day, false);
+
+    harness.get_by_label("Week").click();
+    harness.run();
+    assert_eq!(harness.state().period, Period::Week);
+    assert_eq!(harness.state().changes, 1);
+    assert_eq!(
+        harness.get_by_label("Week").accesskit_node().toggled(),
+        Some(Toggled::True)
+    );
+    assert_eq!(
+        harness.get_by_label("Day").accesskit_node().toggled(),
+        Some(Toggled::False)
+    );
+
+    harness.get_by_label("Week").click();
+    harness.run();
+    assert_eq!(
+        harness.state().changes,
+        1,
+        "Clicking the selected segment changes nothing"
+    );
+}
+
+#[test]
+fn segmented_control_is_a_single_tab_stop() {
+    let mut harness = harness(Period::Week, false);
+
+    press(&mut harness, Key::Tab);
+    press(&mut harness, Key::Tab);
+    assert!(
+        harness.get_by_label("Week").is_focused(),
+        "Tab focuses the selected segment"
+    );
+
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(harness.state().period, Period::Month);
+    assert!(harness.get_by_label("A whole month").is_focused());
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(harness.state().period, Period::Day, "Wraps around");
+    press(&mut harness, Key::ArrowLeft);
+    assert_eq!(harness.state().period, Period::Month);
+    assert_eq!(harness.state().changes, 3);
+
+    press(&mut harness, Key::Tab);
+    assert!(
+        harness.get_by_label("After").is_focused(),
+        "Tab leaves the group"
+    );
+}
+
+#[test]
+fn segments_are_connected() {
+    for equal_widths in [false, true] {
+        let harness = harness(Period::Day, equal_widths);
+        let rects =
+            ["Day", "Week", "A whole month"].map(|label| harness.get_by_label(label).rect());
+        for pair in rects.windows(2) {
+            assert_eq!(pair[0].right(), pair[1].left(), "No gaps between segments");
+            assert_eq!(pair[0].y_range(), pair[1].y_range());
+        }
+
+        let widths = rects.map(|rect| rect.width());
+        if equal_widths {
+            assert_eq!(widths[0], widths[2]);
+            assert_eq!(widths[1], widths[2]);
+        } else {
+            assert!(widths[0] < widths[2], "Natural widths: {widths:?}");
+        }
+    }
+}
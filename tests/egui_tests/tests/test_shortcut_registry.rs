@@ -0,0 +1,125 @@
+use egui::accesskit::Role;
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+const SAVE_AS: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::S);
+
+/// Records the actions triggered in each pass.
+fn shortcut_harness() -> Harness<'static, Vec<&'static str>> {
+    Harness::new_state(
+        |ctx, triggered: &mut Vec<&'static str>| {
+            ctx.register_shortcut("Save", SAVE);
+            ctx.register_shortcut("Save as", SAVE_AS);
+            for action in ["Save", "Save as"] {
+                if ctx.shortcut_triggered(action) {
+                    triggered.push(action);
+                }
+            }
+        },
+        Vec::new(),
+    )
+}
+
+#[test]
+fn registered_shortcut_triggers_its_action() {
+    let mut harness = shortcut_harness();
+    harness.step();
+    assert!(harness.state().is_empty());
+
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::S);
+    harness.step();
+    assert_eq!(harness.state(), &["Save"]);
+
+    harness.step();
+    assert_eq!(harness.state(), &["Save"], "Only triggered in one pass");
+}
+
+#[test]
+fn most_specific_shortcut_wins() {
+    let mut harness = shortcut_harness();
+    harness.step();
+
+    harness.key_press_modifiers(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+    harness.step();
+    assert_eq!(harness.state(), &["Save as"]);
+}
+
+#[test]
+fn registered_shortcuts_can_be_listed() {
+    let mut harness = shortcut_harness();
+    harness.step();
+
+    let listed = harness.ctx.shortcuts(|registry| {
+        registry
+            .shortcuts()
+            .iter()
+            .map(|registered| (registered.action.clone(), registered.shortcut))
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(
+        listed,
+        [("Save".to_owned(), SAVE), ("Save as".to_owned(), SAVE_AS)]
+    );
+    assert!(
+        harness
+            .ctx
+            .shortcuts(|registry| registry.conflicts().is_empty())
+    );
+}
+
+fn shortcuts_window_harness() -> Harness<'static> {
+    Harness::new(|ctx| {
+        ctx.shortcuts_mut(|registry| {
+            registry.insert_in("File", "Save", SAVE);
+            registry.insert_in("File", "Save as", SAVE_AS);
+            registry.insert_in(
+                "Edit",
+                "Undo",
+                KeyboardShortcut::new(Modifiers::COMMAND, Key::Z),
+            );
+            registry.insert("Help", KeyboardShortcut::new(Modifiers::NONE, Key::F1));
+        });
+        let registry = ctx.shortcuts(|registry| registry.clone());
+        egui::show_shortcuts_window(ctx, &registry);
+    })
+}
+
+#[test]
+fn shortcuts_window_lists_shortcuts_by_category() {
+    let harness = shortcuts_window_harness();
+    for label in ["File", "Edit", "General", "Save", "Save as", "Undo", "Help"] {
+        harness.get_by_label(label);
+    }
+    harness.get_by_label("Ctrl+Shift+S");
+}
+
+#[test]
+fn shortcuts_window_filters_by_search() {
+    let mut harness = shortcuts_window_harness();
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text("undo");
+    harness.run();
+
+    assert!(harness.query_by_label("Undo").is_some());
+    assert!(harness.query_by_label("Edit").is_some());
+    assert!(harness.query_by_label("Save").is_none());
+    assert!(harness.query_by_label("File").is_none());
+}
+
+#[test]
+fn format_shortcut_follows_the_os() {
+    let ctx = egui::Context::default();
+    let _ = ctx.run(Default::default(), |_| {});
+    let arrow_up = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::ALT, Key::ArrowUp);
+
+    ctx.set_os(egui::os::OperatingSystem::Windows);
+    assert_eq!(ctx.format_shortcut(&arrow_up), "Ctrl+Alt+Up");
+
+    // The default fonts don't have the macOS symbols:
+    ctx.set_os(egui::os::OperatingSystem::Mac);
+    assert_eq!(ctx.format_shortcut(&arrow_up), "Option+Cmd+Up");
+}
@@ -0,0 +1,150 @@
+use egui::containers::panel::AutoHide;
+use egui::{CentralPanel, Event, Id, Pos2, SidePanel, pos2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+const NAV: &str = "nav";
+
+/// How wide the side panel is, where the central panel starts,
+/// and if the side panel contents think they are collapsed.
+#[derive(Default)]
+struct State {
+    panel_width: f32,
+    central_left: f32,
+    collapsed: bool,
+}
+
+fn harness() -> Harness<'static, State> {
+    let harness = Harness::builder().with_step_dt(1.0 / 60.0).build_state(
+        |ctx, state: &mut State| {
+            let response = SidePanel::left(NAV)
+                .collapsible(true)
+                .min_collapsed_width(40.0)
+                .default_width(200.0)
+                .show(ctx, |ui| {
+                    state.collapsed = SidePanel::is_collapsed(ui.ctx(), NAV);
+                    ui.label(if state.collapsed { "🏠" } else { "🏠 Home" });
+                    ui.separator(); // Fill the panel
+                })
+                .response;
+            state.panel_width = response.rect.width();
+            CentralPanel::default().show(ctx, |ui| {
+                state.central_left = ui.max_rect().left();
+            });
+        },
+        State::default(),
+    );
+    // The harness turns animations off:
+    harness.ctx.style_mut(|s| s.animation_time = 0.1);
+    harness
+}
+
+fn widths_while_stepping(harness: &mut Harness<'_, State>) -> Vec<f32> {
+    (0..20)
+        .map(|_| {
+            harness.step();
+            let State {
+                panel_width,
+                central_left,
+                ..
+            } = *harness.state();
+            assert!(
+                panel_width <= central_left,
+                "The central panel follows the side panel"
+            );
+            panel_width
+        })
+        .collect()
+}
+
+fn drag(harness: &mut Harness<'_, State>, from: Pos2, to: Pos2) {
+    harness.input_mut().events.push(Event::PointerMoved(from));
+    harness.step();
+    harness.drag(from, to);
+    harness.run();
+}
+
+#[test]
+fn collapsing_animates_the_width() {
+    let mut harness = harness();
+    harness.run();
+    let expanded = harness.state().panel_width;
+    assert!(!harness.state().collapsed);
+
+    harness.get_by_label("Collapse panel").click();
+    let widths = widths_while_stepping(&mut harness);
+    assert!(
+        widths.iter().any(|&w| 40.0 < w && w < expanded),
+        "The width animates: {widths:?}"
+    );
+    assert!(
+        widths.windows(2).all(|pair| pair[1] <= pair[0]),
+        "{widths:?}"
+    );
+    assert_eq!(harness.state().panel_width, 40.0);
+    assert!(harness.state().collapsed);
+    assert!(harness.query_by_label("🏠").is_some(), "Icon-only contents");
+
+    harness.get_by_label("Expand panel").click();
+    widths_while_stepping(&mut harness);
+    assert_eq!(harness.state().panel_width, expanded);
+    assert!(!SidePanel::is_collapsed(&harness.ctx, Id::new(NAV)));
+}
+
+#[test]
+fn only_an_expanded_panel_can_be_resized() {
+    let mut harness = harness();
+    harness.run();
+    let edge = harness.state().panel_width;
+
+    // Drag near the top, away from the collapse handle:
+    drag(&mut harness, pos2(edge, 20.0), pos2(edge + 50.0, 20.0));
+    let resized = harness.state().panel_width;
+    assert!(edge < resized, "{edge} -> {resized}");
+
+    harness.get_by_label("Collapse panel").click();
+    widths_while_stepping(&mut harness);
+    drag(&mut harness, pos2(40.0, 20.0), pos2(150.0, 20.0));
+    assert_eq!(harness.state().panel_width, 40.0);
+
+    harness.get_by_label("Expand panel").click();
+    widths_while_stepping(&mut harness);
+    assert_eq!(
+        harness.state().panel_width,
+        resized,
+        "Expands back to the resized width"
+    );
+}
+
+#[test]
+fn auto_hidden_panel_slides_out_and_back() {
+    let mut harness = Harness::builder().with_step_dt(0.1).build_state(
+        |ctx, width: &mut f32| {
+            let response = SidePanel::right("inspector")
+                .auto_hide(Some(AutoHide::default()))
+                .show(ctx, |ui| {
+                    ui.label("Inspector");
+                })
+                .response;
+            *width = response.rect.width();
+        },
+        0.0,
+    );
+    let shown = *harness.state();
+    assert!(0.0 < shown);
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(10.0, 10.0)));
+    harness.run_steps(30);
+    assert_eq!(*harness.state(), 0.0);
+
+    let right = harness.ctx.screen_rect().right();
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(right - 2.0, 10.0)));
+    harness.run_steps(5);
+    assert_eq!(*harness.state(), shown);
+}
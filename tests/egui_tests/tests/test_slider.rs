@@ -0,0 +1,146 @@
+use std::ops::RangeInclusive;
+
+use egui::{Key, PointerButton, Pos2, RangeSlider, Slider, accesskit::Role, vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn slider_harness(value: f64) -> Harness<'static, f64> {
+    Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.add(
+                Slider::new(value, 0.0..=10.0)
+                    .show_value(false)
+                    .ticks(&[2.5, 5.0, 7.5])
+                    .snap_to_ticks(4.0),
+            );
+        },
+        value,
+    )
+}
+
+fn press_at(harness: &mut Harness<'_, f64>, pos: Pos2) {
+    harness.pointer_down(pos, PointerButton::Primary);
+    harness.step();
+    harness.pointer_up(pos, PointerButton::Primary);
+    harness.step();
+    harness.run();
+}
+
+#[test]
+fn slider_snaps_to_nearby_ticks() {
+    let mut harness = slider_harness(0.0);
+    let center = harness.get_by_role(Role::Slider).rect().center();
+
+    press_at(&mut harness, center + vec2(3.0, 0.0));
+    assert_eq!(*harness.state(), 5.0);
+
+    // Far from any tick, the value is free:
+    press_at(&mut harness, center + vec2(11.0, 0.0));
+    let value = *harness.state();
+    assert!(5.0 < value && value < 7.5, "{value}");
+}
+
+#[test]
+fn slider_arrow_keys_jump_between_ticks() {
+    let mut harness = slider_harness(1.0);
+    harness.get_by_role(Role::Slider).focus();
+    harness.run();
+
+    harness.key_press(Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), 2.5);
+
+    harness.key_press(Key::ArrowRight);
+    harness.key_press(Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), 7.5);
+
+    harness.key_press(Key::ArrowLeft);
+    harness.run();
+    assert_eq!(*harness.state(), 5.0);
+}
+
+fn range_slider_harness(allow_swap: bool) -> Harness<'static, RangeInclusive<f64>> {
+    Harness::new_ui_state(
+        move |ui, range: &mut RangeInclusive<f64>| {
+            ui.add(
+                RangeSlider::new(range, 0.0..=10.0)
+                    .show_value(false)
+                    .step_by(0.5)
+                    .allow_swap(allow_swap),
+            );
+        },
+        2.0..=6.0,
+    )
+}
+
+fn drag(harness: &mut Harness<'_, RangeInclusive<f64>>, from: Pos2, to: Pos2) {
+    harness.drag(from, to);
+    harness.run();
+}
+
+/// The centers of the start and end handles.
+fn handle_centers(harness: &Harness<'_, RangeInclusive<f64>>) -> [Pos2; 2] {
+    let handles: Vec<_> = harness.get_all_by_role(Role::Slider).collect();
+    assert_eq!(handles.len(), 2, "One slider node per handle");
+    [handles[0].rect().center(), handles[1].rect().center()]
+}
+
+#[test]
+fn range_slider_handles_do_not_cross() {
+    let mut harness = range_slider_harness(false);
+    let [start, end] = handle_centers(&harness);
+
+    drag(&mut harness, start, end + vec2(20.0, 0.0));
+    assert_eq!(*harness.state(), 6.0..=6.0);
+}
+
+#[test]
+fn range_slider_handles_can_swap() {
+    let mut harness = range_slider_harness(true);
+    let [start, end] = handle_centers(&harness);
+
+    // Drag the start handle past the end handle, and it becomes the end:
+    let step = (end.x - start.x) / 4.0; // 1.0 in value
+    drag(&mut harness, start, end + vec2(2.0 * step, 0.0));
+    assert_eq!(*harness.state(), 6.0..=8.0);
+}
+
+#[test]
+fn range_slider_span_moves_both_handles() {
+    let mut harness = range_slider_harness(false);
+    let [start, end] = handle_centers(&harness);
+    let step = (end.x - start.x) / 4.0; // 1.0 in value
+
+    let middle = start.lerp(end, 0.5);
+    drag(&mut harness, middle, middle + vec2(3.0 * step, 0.0));
+    assert_eq!(*harness.state(), 5.0..=9.0);
+
+    // Stops at the end of the rail, keeping the width:
+    let [start, end] = handle_centers(&harness);
+    let middle = start.lerp(end, 0.5);
+    drag(&mut harness, middle, middle + vec2(10.0 * step, 0.0));
+    assert_eq!(*harness.state(), 6.0..=10.0);
+}
+
+#[test]
+fn range_slider_keyboard_moves_focused_handle() {
+    let mut harness = range_slider_harness(false);
+
+    harness
+        .get_all_by_role(Role::Slider)
+        .next()
+        .unwrap()
+        .focus();
+    harness.run();
+    harness.key_press(Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), 2.5..=6.0);
+
+    // Tab moves to the end handle:
+    harness.key_press(Key::Tab);
+    harness.run();
+    harness.key_press(Key::ArrowLeft);
+    harness.run();
+    assert_eq!(*harness.state(), 2.5..=5.5);
+}
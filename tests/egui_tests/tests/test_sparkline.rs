@@ -0,0 +1,46 @@
+use egui::{Event, pos2};
+use egui_extras::Sparkline;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn sparkline_harness(values: &'static [f32]) -> Harness<'static> {
+    Harness::builder()
+        .with_size(egui::vec2(216.0, 100.0))
+        .build_ui(move |ui| {
+            ui.add(Sparkline::new(values).height(40.0));
+        })
+}
+
+#[test]
+fn sparkline_shows_nearest_value_on_hover() {
+    let mut harness = sparkline_harness(&[1.0, 2.0, 3.0, 4.0]);
+
+    // The panel has a margin of 8, so the chart spans x = 8..=208:
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(200.0, 20.0)));
+    harness.run();
+    assert!(harness.query_by_label("4").is_some());
+    assert!(harness.query_by_label("3").is_none());
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(pos2(80.0, 20.0)));
+    harness.run();
+    assert!(harness.query_by_label("2").is_some());
+}
+
+#[test]
+fn sparkline_with_degenerate_values() {
+    for values in [&[][..], &[1.0], &[2.0, 2.0], &[f32::NAN, 1.0, f32::NAN]] {
+        let mut harness = sparkline_harness(values);
+        harness
+            .input_mut()
+            .events
+            .push(Event::PointerMoved(pos2(100.0, 20.0)));
+        harness.run();
+        assert!(harness.query_by_label("NaN").is_none());
+    }
+}
@@ -0,0 +1,53 @@
+use egui::{Rect, Spinner, SpinnerStyle};
+use egui_kittest::Harness;
+
+const STYLES: [SpinnerStyle; 3] = [SpinnerStyle::Arc, SpinnerStyle::Dots, SpinnerStyle::Bars];
+
+fn spinner_harness(style: SpinnerStyle, enabled: bool) -> Harness<'static, Rect> {
+    Harness::new_ui_state(
+        move |ui, rect: &mut Rect| {
+            ui.add_enabled_ui(enabled, |ui| {
+                *rect = ui.add(Spinner::new().style(style).size(32.0)).rect;
+            });
+        },
+        Rect::NOTHING,
+    )
+}
+
+#[test]
+fn spinner_styles_paint_within_their_size() {
+    for style in STYLES {
+        let mut harness = spinner_harness(style, true);
+        harness.step();
+        let rect = *harness.state();
+        assert_eq!(rect.size(), egui::vec2(32.0, 32.0));
+
+        let painted = harness
+            .output()
+            .shapes
+            .iter()
+            .map(|clipped| clipped.shape.visual_bounding_rect())
+            .fold(Rect::NOTHING, Rect::union);
+        assert!(painted.is_positive(), "{style:?} paints something");
+        assert!(
+            rect.expand(2.0).contains_rect(painted),
+            "{style:?} paints {painted:?} outside of {rect:?}"
+        );
+    }
+}
+
+#[test]
+fn disabled_spinner_does_not_animate() {
+    for style in STYLES {
+        let mut harness = spinner_harness(style, true);
+        harness.step();
+        assert!(harness.ctx.has_requested_repaint(), "{style:?} animates");
+
+        let mut harness = spinner_harness(style, false);
+        harness.step();
+        assert!(
+            !harness.ctx.has_requested_repaint(),
+            "A disabled {style:?} spinner is static"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use egui::{Event, PointerButton, accesskit, vec2};
+use egui_extras::TabBar;
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+struct State {
+    tabs: Vec<String>,
+    active: usize,
+    closed: Vec<String>,
+    reordered: bool,
+}
+
+fn harness(num_tabs: usize, width: f32) -> Harness<'static, State> {
+    Harness::builder()
+        // Tall enough for the dropdown to list all the tabs:
+        .with_size(vec2(width, 300.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let response =
+                    TabBar::new("tabs")
+                        .show(ui, &mut state.tabs, &mut state.active, |tab| tab.into());
+                state.closed.extend(response.closed);
+                state.reordered |= response.reordered;
+            },
+            State {
+                tabs: (1..=num_tabs).map(|i| format!("Tab {i}")).collect(),
+                active: 0,
+                closed: Vec::new(),
+                reordered: false,
+            },
+        )
+}
+
+#[test]
+fn tabs_close_with_the_button_or_a_middle_click() {
+    let mut harness = harness(3, 400.0);
+    harness.run();
+
+    harness.get_by_label("Tab 2").click();
+    harness.run();
+    assert_eq!(harness.state().active, 1);
+
+    // Each tab has a close button, even if it is only painted on the active and hovered tabs:
+    harness
+        .get_all_by_label("Close tab")
+        .nth(1)
+        .unwrap()
+        .click();
+    harness.run();
+    assert_eq!(harness.state().tabs, ["Tab 1", "Tab 3"]);
+    assert_eq!(harness.state().closed, ["Tab 2"]);
+    assert_eq!(harness.state().active, 1, "The next tab becomes active");
+
+    harness
+        .get_by_label("Tab 1")
+        .click_button(PointerButton::Middle);
+    harness.run();
+    assert_eq!(harness.state().tabs, ["Tab 3"]);
+    assert_eq!(harness.state().closed, ["Tab 2", "Tab 1"]);
+    assert_eq!(harness.state().active, 0, "Still Tab 3");
+}
+
+#[test]
+fn dragging_a_tab_reorders_the_tabs() {
+    let mut harness = harness(3, 400.0);
+    harness.run();
+
+    let from = harness.get_by_label("Tab 1").rect().center();
+    let to = harness.get_by_label("Tab 3").rect().right_center() + vec2(5.0, 0.0);
+
+    harness.pointer_down(from, PointerButton::Primary);
+    harness.step();
+    for step in 1..=8 {
+        let pos = from + (to - from) * step as f32 / 8.0;
+        harness.input_mut().events.push(Event::PointerMoved(pos));
+        harness.step();
+
+        // The drag stays with the tab, even though its index changes:
+        let dragged = harness.ctx.dragged_id().expect("Tab 1 is being dragged");
+        assert_eq!(
+            harness.get_by_label("Tab 1").accesskit_node().id(),
+            accesskit::NodeId::from(dragged.value()),
+        );
+    }
+    harness.pointer_up(to, PointerButton::Primary);
+    harness.run();
+
+    let state = harness.state();
+    assert_eq!(state.tabs, ["Tab 2", "Tab 3", "Tab 1"]);
+    assert_eq!(state.active, 2, "The dragged tab is active");
+    assert!(state.reordered);
+}
+
+#[test]
+fn removing_tabs_during_a_drag_ends_the_drag() {
+    let mut harness = harness(3, 400.0);
+    harness.run();
+
+    let from = harness.get_by_label("Tab 3").rect().center();
+    harness.pointer_down(from, PointerButton::Primary);
+    harness.step();
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(from + vec2(20.0, 0.0)));
+    harness.step();
+    assert!(harness.ctx.dragged_id().is_some());
+
+    harness.state_mut().tabs.truncate(1);
+    harness.state_mut().active = 0;
+    harness
+        .input_mut()
+        .events
+        .push(Event::PointerMoved(from - vec2(300.0, 0.0)));
+    harness.step();
+    harness.pointer_up(from, PointerButton::Primary);
+    harness.run();
+
+    let state = harness.state();
+    assert_eq!(state.tabs, ["Tab 1"]);
+    assert_eq!(state.active, 0);
+}
+
+#[test]
+fn overflowing_tabs_can_be_picked_from_a_dropdown() {
+    let mut harness = harness(10, 200.0);
+    harness.run();
+    assert_eq!(harness.state().active, 0);
+
+    harness.get_by_label("⏷").click();
+    harness.run();
+    // The last one is in the dropdown, the first one is the tab itself:
+    harness.get_all_by_label("Tab 10").last().unwrap().click();
+    harness.run();
+    assert_eq!(harness.state().active, 9);
+}
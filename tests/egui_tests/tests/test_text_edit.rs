@@ -0,0 +1,507 @@
+use egui::{TextEdit, accesskit::Role};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn focused_text_edit_harness<'a>(
+    text: &str,
+    mut text_edit: impl FnMut(&mut String) -> TextEdit<'_> + 'a,
+) -> Harness<'a, (String, bool)> {
+    let mut harness = Harness::new_ui_state(
+        move |ui, (text, truncated): &mut (String, bool)| {
+            let output = text_edit(text).show(ui);
+            *truncated |= output.truncated;
+        },
+        (text.to_owned(), false),
+    );
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness
+}
+
+fn push_event<State>(harness: &mut Harness<'_, State>, event: egui::Event) {
+    harness.input_mut().events.push(event);
+}
+
+#[test]
+fn char_limit_does_not_split_graphemes() {
+    let mut harness = focused_text_edit_harness("ab", |text| {
+        TextEdit::singleline(text).char_limit(4).cursor_at_end(true)
+    });
+
+    // The thumbs-up with a skin tone modifier is two chars, but a single grapheme:
+    harness.get_by_role(Role::TextInput).type_text("c👍🏽");
+    harness.run();
+
+    let (text, truncated) = harness.state();
+    assert_eq!(text, "abc");
+    assert!(truncated);
+}
+
+#[test]
+fn byte_limit_truncates_paste() {
+    let mut harness =
+        focused_text_edit_harness("", |text| TextEdit::singleline(text).byte_limit(5));
+
+    push_event(&mut harness, egui::Event::Paste("äöü".to_owned()));
+    harness.run();
+
+    let (text, truncated) = harness.state();
+    assert_eq!(text, "äö");
+    assert!(truncated);
+}
+
+#[test]
+fn ime_composition_may_exceed_limit_until_committed() {
+    let mut harness =
+        focused_text_edit_harness("ab", |text| TextEdit::singleline(text).char_limit(3));
+
+    push_event(&mut harness, egui::Event::Ime(egui::ImeEvent::Enabled));
+    push_event(
+        &mut harness,
+        egui::Event::Ime(egui::ImeEvent::Preedit("にほ".to_owned())),
+    );
+    harness.run();
+    assert_eq!(harness.state().0, "abにほ");
+    assert!(!harness.state().1);
+
+    push_event(
+        &mut harness,
+        egui::Event::Ime(egui::ImeEvent::Commit("日本".to_owned())),
+    );
+    harness.run();
+    assert_eq!(harness.state().0, "ab日");
+    assert!(harness.state().1);
+}
+
+#[derive(Default)]
+struct PreeditState {
+    text: String,
+    preedit_range: Option<egui::text::CCursorRange>,
+
+    /// Where the preedit text is on screen, according to the galley.
+    preedit_x_range: Option<egui::Rangef>,
+}
+
+#[test]
+fn ime_preedit_is_underlined() {
+    let mut harness = Harness::new_ui_state(
+        |ui, state: &mut PreeditState| {
+            let output = TextEdit::singleline(&mut state.text).show(ui);
+            state.preedit_range = output.state.ime_preedit_range();
+            state.preedit_x_range = state.preedit_range.map(|range| {
+                let [min, max] = range.sorted_cursors();
+                let x = |cursor| output.galley_pos.x + output.galley.pos_from_cursor(cursor).min.x;
+                egui::Rangef::new(x(min), x(max))
+            });
+        },
+        PreeditState {
+            text: "ab".to_owned(),
+            ..Default::default()
+        },
+    );
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+
+    push_event(&mut harness, egui::Event::Ime(egui::ImeEvent::Enabled));
+    push_event(
+        &mut harness,
+        egui::Event::Ime(egui::ImeEvent::Preedit("にほ".to_owned())),
+    );
+    harness.run();
+
+    let state = harness.state();
+    assert_eq!(state.text, "abにほ");
+    let [min, max] = state.preedit_range.unwrap().sorted_cursors();
+    assert_eq!((min.index, max.index), (2, 4));
+
+    let expected = state.preedit_x_range.unwrap();
+    let underlines: Vec<egui::Rangef> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match clipped.shape {
+            egui::Shape::LineSegment { points: [a, b], .. } if a.y == b.y => {
+                Some(egui::Rangef::new(a.x, b.x))
+            }
+            _ => None,
+        })
+        .collect();
+    assert!(
+        underlines
+            .iter()
+            .any(|x| (x.min - expected.min).abs() < 0.5 && (x.max - expected.max).abs() < 0.5),
+        "Only the preedit text {expected:?} is underlined, got {underlines:?}"
+    );
+
+    push_event(
+        &mut harness,
+        egui::Event::Ime(egui::ImeEvent::Commit("日本".to_owned())),
+    );
+    harness.run();
+    assert_eq!(harness.state().text, "ab日本");
+    assert_eq!(harness.state().preedit_range, None);
+}
+
+fn virtualized_harness(text: String) -> Harness<'static, (String, usize, usize)> {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 300.0))
+        .build_ui_state(
+            |ui, (text, num_rows, char_offset): &mut (String, usize, usize)| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    let output = TextEdit::multiline(text)
+                        .id(egui::Id::new("log"))
+                        .virtualized(true)
+                        .show(ui);
+                    *num_rows = output.galley.rows.len();
+                    *char_offset = output.galley_char_offset;
+                });
+            },
+            (text, 0, 0),
+        );
+    // The middle of the text edit is far outside the screen, so we can't click it:
+    harness
+        .ctx
+        .memory_mut(|mem| mem.request_focus(egui::Id::new("log")));
+    harness.run();
+    harness
+}
+
+fn numbered_lines(num_lines: usize) -> String {
+    (0..num_lines)
+        .map(|i| format!("line {i}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn virtualized_text_edit_only_lays_out_visible_lines() {
+    let mut harness = virtualized_harness(numbered_lines(10_000));
+
+    let (_, num_rows, char_offset) = *harness.state();
+    assert!(num_rows < 100, "Laid out {num_rows} rows");
+    assert_eq!(char_offset, 0);
+
+    // The cursor starts out at the end, outside of what is visible.
+    // Typing there scrolls to it:
+    harness.get_by_role(Role::MultilineTextInput).type_text("!");
+    harness.run();
+    let (text, num_rows, char_offset) = harness.state();
+    assert!(text.ends_with("line 9998\nline 9999!"));
+    assert!(*num_rows < 100, "Laid out {num_rows} rows");
+    assert!(0 < *char_offset);
+
+    // Jump back to the start:
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::ArrowUp);
+    harness.run();
+    harness.get_by_role(Role::MultilineTextInput).type_text("?");
+    harness.run();
+    let (text, _, char_offset) = harness.state();
+    assert!(text.starts_with("?line 0\nline 1"));
+    assert_eq!(*char_offset, 0);
+}
+
+#[test]
+fn virtualized_text_edit_keeps_its_lines_up_to_date() {
+    let mut harness = virtualized_harness(numbered_lines(100));
+
+    // The cursor starts out at the end:
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    harness
+        .get_by_role(Role::MultilineTextInput)
+        .type_text("new");
+    harness.run();
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::ArrowUp);
+    harness.run();
+    harness.get_by_role(Role::MultilineTextInput).type_text("?");
+    harness.run();
+    let text = &harness.state().0;
+    assert!(text.starts_with("?line 0\nline 1"), "{text:?}");
+    assert!(text.ends_with("line 99\nnew"), "{text:?}");
+
+    // Changed from the outside:
+    harness.state_mut().0 = numbered_lines(3);
+    harness.run();
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::ArrowDown);
+    harness.run();
+    harness.get_by_role(Role::MultilineTextInput).type_text("!");
+    harness.run();
+    assert_eq!(harness.state().0, "line 0\nline 1\nline 2!");
+}
+
+#[test]
+fn virtualized_text_edit_selects_and_replaces_everything() {
+    let mut harness = virtualized_harness(numbered_lines(10_000));
+
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::A);
+    harness.run();
+    push_event(&mut harness, egui::Event::Copy);
+    harness.step();
+    let copied =
+        harness
+            .output()
+            .platform_output
+            .commands
+            .iter()
+            .find_map(|command| match command {
+                egui::OutputCommand::CopyText(text) => Some(text.clone()),
+                _ => None,
+            });
+    assert_eq!(copied, Some(numbered_lines(10_000)));
+
+    harness.get_by_role(Role::MultilineTextInput).type_text("x");
+    harness.run();
+    assert_eq!(harness.state().0, "x");
+}
+
+#[test]
+fn triple_click_selects_the_paragraph() {
+    const TEXT: &str = "First paragraph\nThe second paragraph\nThird";
+
+    // The text, where the middle of the second paragraph is, and what is selected:
+    type State = (String, egui::Pos2, Option<std::ops::Range<usize>>);
+    let mut harness = Harness::new_ui_state(
+        |ui, (text, middle, selection): &mut State| {
+            let output = TextEdit::multiline(text).show(ui);
+            let cursor = egui::text::CCursor::new(TEXT.find("second").unwrap());
+            *middle = output.galley_pos + output.galley.pos_from_cursor(cursor).center().to_vec2();
+            *selection = output
+                .cursor_range
+                .map(|range| range.as_sorted_char_range());
+        },
+        (TEXT.to_owned(), egui::Pos2::ZERO, None),
+    );
+
+    let pos = harness.state().1;
+    for _ in 0..3 {
+        harness.click_at(pos);
+        harness.step();
+    }
+    harness.run();
+
+    let selection = harness.state().2.clone().expect("Selected");
+    assert_eq!(&TEXT[selection], "The second paragraph");
+}
+
+/// The password, the text that was shown, and what was copied.
+type PasswordState = (String, String, Option<String>);
+
+fn password_harness(reveal: egui::text_edit::PasswordReveal) -> Harness<'static, PasswordState> {
+    Harness::new_ui_state(
+        move |ui, (password, shown, copied): &mut PasswordState| {
+            let output = TextEdit::singleline(password)
+                .password(true)
+                .password_char('*')
+                .password_reveal_button(true)
+                .password_reveal(reveal)
+                .show(ui);
+            *shown = output.galley.text().to_owned();
+            let copied_now = ui.output(|o| {
+                o.commands.iter().find_map(|command| match command {
+                    egui::OutputCommand::CopyText(text) => Some(text.clone()),
+                    _ => None,
+                })
+            });
+            if copied_now.is_some() {
+                *copied = copied_now;
+            }
+        },
+        ("hunter2".to_owned(), String::new(), None),
+    )
+}
+
+fn copy_all(harness: &mut Harness<'_, PasswordState>) -> Option<String> {
+    harness.state_mut().2 = None;
+    harness.get_by_role(Role::PasswordInput).click();
+    harness.run();
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::A);
+    harness.run();
+    push_event(harness, egui::Event::Copy);
+    harness.run();
+    harness.state().2.clone()
+}
+
+#[test]
+fn password_reveal_button_toggles_masking() {
+    use egui::accesskit::Toggled;
+    use egui_kittest::kittest::NodeT as _;
+
+    let mut harness = password_harness(egui::text_edit::PasswordReveal::Toggle);
+    assert_eq!(
+        harness.state().1,
+        "*******",
+        "Masked with the password char"
+    );
+    assert_eq!(copy_all(&mut harness), None, "Not copied while masked");
+
+    harness.get_by_label("Show password").click();
+    harness.run();
+    assert_eq!(harness.state().1, "hunter2");
+    assert_eq!(
+        harness
+            .get_by_label("Show password")
+            .accesskit_node()
+            .toggled(),
+        Some(Toggled::True)
+    );
+    assert_eq!(copy_all(&mut harness).as_deref(), Some("hunter2"));
+
+    // The button is reachable with the keyboard, right after the text edit:
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Show password").is_focused());
+    harness.key_press(egui::Key::Space);
+    harness.run();
+    assert_eq!(harness.state().1, "*******");
+}
+
+#[test]
+fn password_reveal_button_can_reveal_while_held() {
+    let mut harness = password_harness(egui::text_edit::PasswordReveal::Hold);
+
+    let button = harness.get_by_label("Show password").rect().center();
+    push_event(&mut harness, egui::Event::PointerMoved(button));
+    harness.run();
+    harness.pointer_down(button, egui::PointerButton::Primary);
+    harness.run();
+    assert_eq!(harness.state().1, "hunter2", "Revealed while held");
+    harness.pointer_up(button, egui::PointerButton::Primary);
+    harness.run();
+    assert_eq!(harness.state().1, "*******");
+}
+
+fn repaint_delay_with_focused_cursor(blink: bool, off_duration: f32) -> std::time::Duration {
+    let mut harness = Harness::new_ui_state(
+        move |ui, text: &mut String| {
+            let text_cursor = &mut ui.visuals_mut().text_cursor;
+            text_cursor.blink = blink;
+            text_cursor.off_duration = off_duration;
+            ui.text_edit_singleline(text);
+        },
+        String::new(),
+    );
+    harness.get_by_role(Role::TextInput).click();
+    harness.run();
+    harness.output().viewport_output[&egui::ViewportId::ROOT].repaint_delay
+}
+
+#[test]
+fn blinking_cursor_requests_repaint() {
+    let delay = repaint_delay_with_focused_cursor(true, 0.5);
+    assert!(
+        delay <= std::time::Duration::from_secs_f32(0.5),
+        "{delay:?}"
+    );
+}
+
+#[test]
+fn solid_cursor_requests_no_repaint() {
+    for (blink, off_duration) in [(false, 0.5), (true, 0.0)] {
+        let delay = repaint_delay_with_focused_cursor(blink, off_duration);
+        assert_eq!(
+            delay,
+            std::time::Duration::MAX,
+            "blink: {blink}, off_duration: {off_duration}"
+        );
+    }
+}
+
+/// The text, where the galley is, the center of the first row of each line, and what is selected.
+type LineNumbersState = (String, egui::Pos2, Vec<f32>, Option<std::ops::Range<usize>>);
+
+fn line_numbers_harness(text: String) -> Harness<'static, LineNumbersState> {
+    Harness::new_ui_state(
+        |ui, (text, galley_pos, line_centers, selection): &mut LineNumbersState| {
+            let output = TextEdit::multiline(text)
+                .desired_width(150.0)
+                .show_line_numbers(true)
+                .show(ui);
+            *galley_pos = output.galley_pos;
+            let mut starts_new_line = true;
+            line_centers.clear();
+            for row in &output.galley.rows {
+                if starts_new_line {
+                    line_centers.push(output.galley_pos.y + row.rect().center().y);
+                }
+                starts_new_line = row.ends_with_newline;
+            }
+            *selection = output
+                .cursor_range
+                .map(|range| range.as_sorted_char_range());
+        },
+        (text, egui::Pos2::ZERO, Vec::new(), None),
+    )
+}
+
+#[test]
+fn line_numbers_are_aligned_with_the_first_row_of_each_line() {
+    const TEXT: &str = "First\nThe second line is long enough to wrap onto several rows\nThird";
+    let mut harness = line_numbers_harness(TEXT.to_owned());
+    harness.run();
+
+    let (_, galley_pos, line_centers, _) = harness.state().clone();
+    assert_eq!(line_centers.len(), 3);
+    for (i, center) in line_centers.iter().enumerate() {
+        let number = (i + 1).to_string();
+        let painted = harness
+            .output()
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text)
+                    if text.galley.text() == number && text.pos.x < galley_pos.x =>
+                {
+                    Some(text.visual_bounding_rect())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("Line number {number} is painted in the gutter"));
+        assert!(
+            (painted.center().y - center).abs() < 1.0,
+            "Line number {number} at {painted:?}, the line at {center}"
+        );
+    }
+    assert!(
+        line_centers[2] - line_centers[1] > 2.0 * (line_centers[1] - line_centers[0]),
+        "The second line wraps"
+    );
+}
+
+#[test]
+fn clicking_a_line_number_selects_the_line() {
+    const TEXT: &str = "First\nThe second line is long enough to wrap onto several rows\nThird";
+    let mut harness = line_numbers_harness(TEXT.to_owned());
+    harness.run();
+
+    let (_, galley_pos, line_centers, _) = harness.state().clone();
+    let pos = egui::pos2(galley_pos.x - 5.0, line_centers[1]);
+    harness.click_at(pos);
+    harness.run();
+
+    let selection = harness.state().3.clone().expect("Selected");
+    assert_eq!(
+        &TEXT[selection],
+        "The second line is long enough to wrap onto several rows\n"
+    );
+}
+
+#[test]
+fn line_number_gutter_widens_with_the_number_of_digits() {
+    let mut gutter_widths = Vec::new();
+    for num_lines in [9, 10, 99, 100] {
+        let mut harness = line_numbers_harness(numbered_lines(num_lines));
+        harness.run();
+        let text_edit = harness.get_by_role(Role::MultilineTextInput).rect();
+        gutter_widths.push(harness.state().1.x - text_edit.left());
+    }
+    let [nine, ten, ninety_nine, hundred] = gutter_widths[..] else {
+        unreachable!()
+    };
+    assert!(nine < ten, "{gutter_widths:?}");
+    assert_eq!(ten, ninety_nine, "{gutter_widths:?}");
+    assert!(ninety_nine < hundred, "{gutter_widths:?}");
+    assert!(
+        ((ten - nine) - (hundred - ninety_nine)).abs() < 0.01,
+        "One digit wider each time: {gutter_widths:?}"
+    );
+}
@@ -0,0 +1,70 @@
+use egui_extras::{Toast, Toasts};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+fn toasts_harness(toasts: impl IntoIterator<Item = Toast>) -> Harness<'static, Toasts> {
+    let mut harness = Harness::builder()
+        .with_size(egui::vec2(400.0, 300.0))
+        .build_state(|ctx, toasts: &mut Toasts| toasts.show(ctx), Toasts::new());
+    for toast in toasts {
+        harness.state_mut().add(toast);
+    }
+    harness
+}
+
+#[test]
+fn toasts_expire() {
+    let mut harness = toasts_harness([
+        Toast::info("Short").duration(Some(1.0)),
+        Toast::error("Long").duration(Some(3.0)),
+    ]);
+
+    harness.step();
+    assert!(harness.query_by_label("Short").is_some());
+    assert!(harness.query_by_label("Long").is_some());
+
+    // Each step is a quarter of a second:
+    harness.run_steps(8);
+    assert!(harness.query_by_label("Short").is_none());
+    assert!(harness.query_by_label("Long").is_some());
+    assert_eq!(harness.state().len(), 1);
+
+    harness.run_steps(8);
+    assert!(harness.state().is_empty());
+}
+
+#[test]
+fn toasts_are_dismissed_on_click() {
+    let mut harness = toasts_harness([Toast::success("Sticky").duration(None)]);
+
+    harness.run_steps(8);
+    assert_eq!(harness.state().len(), 1);
+
+    harness.get_by_label("Sticky").click();
+    harness.run_steps(4);
+    assert!(harness.state().is_empty());
+}
+
+#[test]
+fn toasts_reflow_when_older_ones_expire() {
+    let mut harness = toasts_harness([
+        Toast::info("First").duration(Some(1.0)),
+        Toast::info("Second").duration(None),
+    ]);
+
+    // Give the new areas a few frames to be sized and placed:
+    harness.run_steps(3);
+    let first = harness.get_by_label("First").rect();
+    let second = harness.get_by_label("Second").rect();
+    assert!(
+        second.max.y < first.min.y,
+        "Newer toasts go above older ones"
+    );
+
+    harness.run_steps(8);
+    let moved = harness.get_by_label("Second").rect();
+    assert!(
+        (moved.max.y - first.max.y).abs() < 1.0,
+        "Moved into the gap"
+    );
+}
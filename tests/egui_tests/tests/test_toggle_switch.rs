@@ -0,0 +1,103 @@
+use egui::Key;
+use egui::accesskit::{Role, Toggled};
+use egui_kittest::Harness;
+use egui_kittest::kittest::{NodeT as _, Queryable as _};
+
+struct State {
+    on: bool,
+    enabled: bool,
+    text_on_left: bool,
+    changes: usize,
+}
+
+fn harness(text_on_left: bool) -> Harness<'static, State> {
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            ui.add_enabled_ui(state.enabled, |ui| {
+                let response = ui.add(
+                    egui::ToggleSwitch::new(&mut state.on, "Dark mode")
+                        .text_on_left(state.text_on_left),
+                );
+                if response.changed() {
+                    state.changes += 1;
+                }
+            });
+        },
+        State {
+            on: false,
+            enabled: true,
+            text_on_left,
+            changes: 0,
+        },
+    )
+}
+
+fn toggled(harness: &Harness<'_, State>) -> Option<Toggled> {
+    harness.get_by_label("Dark mode").accesskit_node().toggled()
+}
+
+#[test]
+fn clicking_toggles() {
+    let mut harness = harness(false);
+    assert_eq!(
+        harness.get_by_label("Dark mode").accesskit_node().role(),
+        Role::Switch
+    );
+    assert_eq!(toggled(&harness), Some(Toggled::False));
+
+    harness.get_by_label("Dark mode").click();
+    harness.run();
+    assert!(harness.state().on);
+    assert_eq!(harness.state().changes, 1);
+    assert_eq!(toggled(&harness), Some(Toggled::True));
+
+    harness.get_by_label("Dark mode").click();
+    harness.run();
+    assert!(!harness.state().on);
+    assert_eq!(harness.state().changes, 2);
+}
+
+#[test]
+fn space_toggles_when_focused() {
+    let mut harness = harness(true);
+    harness.key_press(Key::Tab);
+    harness.run();
+    assert!(harness.get_by_label("Dark mode").is_focused());
+
+    harness.key_press(Key::Space);
+    harness.run();
+    assert!(harness.state().on);
+    assert_eq!(toggled(&harness), Some(Toggled::True));
+}
+
+#[test]
+fn disabled_switch_does_not_toggle() {
+    let mut harness = harness(false);
+    harness.state_mut().enabled = false;
+    harness.run();
+
+    harness.get_by_label("Dark mode").click();
+    harness.run();
+    assert!(!harness.state().on);
+    assert_eq!(harness.state().changes, 0);
+}
+
+#[test]
+fn small_switch_is_smaller() {
+    let harness = Harness::new_ui_state(
+        |ui, on: &mut [bool; 2]| {
+            ui.toggle(&mut on[0]);
+            ui.add(egui::ToggleSwitch::without_text(&mut on[1]).small());
+        },
+        [true, true],
+    );
+    let rects: Vec<_> = harness
+        .get_all_by_role(Role::Switch)
+        .map(|node| node.rect())
+        .collect();
+    let [normal, small] = rects[..] else {
+        panic!("Expected two switches, got {rects:?}");
+    };
+    assert_eq!(normal.height(), small.height(), "Both fit the row height");
+    assert!(small.width() < normal.width(), "{small:?} vs. {normal:?}");
+}
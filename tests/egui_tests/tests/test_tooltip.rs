@@ -0,0 +1,165 @@
+use egui::RichTooltip;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// Short steps, so we don't overshoot the grace time.
+const STEP: f32 = 0.05;
+
+const LONG_BODY: &str =
+    "This body is much longer than the max width of the tooltip, so it has to wrap.";
+
+/// A toolbar of three buttons: one with an instant tooltip, two with a slow one.
+fn toolbar_harness() -> Harness<'static> {
+    Harness::builder().with_step_dt(STEP).build_ui(|ui| {
+        ui.horizontal(|ui| {
+            ui.button("Instant")
+                .on_hover_tooltip(RichTooltip::new("Instant tip").delay(0.0));
+            ui.button("Slow")
+                .on_hover_tooltip(RichTooltip::new("Slow tip").delay(0.6));
+            ui.button("Neighbor")
+                .on_hover_tooltip(RichTooltip::new("Neighbor tip").delay(0.6));
+        });
+    })
+}
+
+fn step_secs(harness: &mut Harness<'_>, secs: f32) {
+    for _ in 0..(secs / STEP).round() as usize {
+        harness.step();
+    }
+}
+
+#[test]
+fn instant_tooltips_show_right_away() {
+    let mut harness = toolbar_harness();
+    harness.get_by_label("Instant").hover();
+    step_secs(&mut harness, 0.2);
+    assert!(harness.query_by_label("Instant tip").is_some());
+}
+
+#[test]
+fn slow_tooltips_wait_for_their_delay() {
+    let mut harness = toolbar_harness();
+    harness.get_by_label("Slow").hover();
+    step_secs(&mut harness, 0.5);
+    assert!(
+        harness.query_by_label("Slow tip").is_none(),
+        "The global delay has passed, but not ours"
+    );
+    step_secs(&mut harness, 0.3);
+    assert!(harness.query_by_label("Slow tip").is_some());
+}
+
+#[test]
+fn moving_to_a_neighbor_skips_the_delay() {
+    let mut harness = toolbar_harness();
+    harness.get_by_label("Slow").hover();
+    step_secs(&mut harness, 0.8);
+    assert!(harness.query_by_label("Slow tip").is_some());
+
+    harness.get_by_label("Neighbor").hover();
+    step_secs(&mut harness, 0.2);
+    assert!(harness.query_by_label("Slow tip").is_none());
+    assert!(harness.query_by_label("Neighbor tip").is_some());
+}
+
+#[test]
+fn rich_tooltip_wraps_at_its_max_width() {
+    let mut harness = Harness::builder().with_step_dt(STEP).build_ui(|ui| {
+        ui.button("Short").on_hover_tooltip(
+            RichTooltip::new("Short tip")
+                .shortcut("Ctrl+S")
+                .max_width(150.0)
+                .delay(0.0),
+        );
+        ui.button("Long").on_hover_tooltip(
+            RichTooltip::new("Long tip")
+                .body(LONG_BODY)
+                .max_width(150.0)
+                .delay(0.0),
+        );
+    });
+
+    harness.get_by_label("Short").hover();
+    step_secs(&mut harness, 0.2);
+    assert!(harness.query_by_label("Ctrl+S").is_some(), "Shortcut hint");
+    let title = harness.get_by_label("Short tip").rect();
+    assert!(title.width() < 100.0, "Doesn't grow to the max: {title:?}");
+
+    harness.get_by_label("Long").hover();
+    step_secs(&mut harness, 0.2);
+    let body = harness.get_by_label(LONG_BODY).rect();
+    assert!(body.width() <= 150.0, "Wraps: {body:?}");
+    assert!(
+        body.height() > 2.0 * harness.get_by_label("Long tip").rect().height(),
+        "Several lines: {body:?}"
+    );
+}
+
+/// The gap `on_hover_ui_at_pointer` leaves between the pointer and the tooltip.
+const GAP: f32 = 12.0;
+
+/// A big canvas with a tooltip that follows the pointer.
+fn canvas_harness() -> Harness<'static> {
+    Harness::builder()
+        .with_size(egui::vec2(400.0, 300.0))
+        .with_step_dt(STEP)
+        .build_ui(|ui| {
+            let (_, response) = ui.allocate_exact_size(ui.available_size(), egui::Sense::hover());
+            response.on_hover_ui_at_pointer(|ui| {
+                ui.label("Value under the pointer");
+            });
+        })
+}
+
+fn move_pointer(harness: &mut Harness<'_>, pos: egui::Pos2) -> egui::Rect {
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(pos));
+    step_secs(harness, 0.2);
+    harness.get_by_label("Value under the pointer").rect()
+}
+
+#[test]
+fn tooltip_at_pointer_follows_the_pointer() {
+    let mut harness = canvas_harness();
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    step_secs(&mut harness, 1.0);
+
+    let first = move_pointer(&mut harness, egui::pos2(50.0, 50.0));
+    assert!(
+        first.left() > 50.0 + GAP && first.top() > 50.0 + GAP,
+        "Below and to the right of the pointer: {first:?}"
+    );
+
+    let second = move_pointer(&mut harness, egui::pos2(100.0, 80.0));
+    assert_eq!(second.min - first.min, egui::vec2(50.0, 30.0), "Follows");
+}
+
+#[test]
+fn tooltip_at_pointer_flips_near_the_edges() {
+    let mut harness = canvas_harness();
+    let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0));
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(egui::pos2(50.0, 50.0)));
+    step_secs(&mut harness, 1.0);
+
+    let right = move_pointer(&mut harness, egui::pos2(390.0, 50.0));
+    assert!(screen.contains_rect(right), "{right:?}");
+    assert!(
+        right.right() < 390.0 - GAP && right.top() > 50.0 + GAP,
+        "Left of the pointer: {right:?}"
+    );
+
+    let corner = move_pointer(&mut harness, egui::pos2(390.0, 290.0));
+    assert!(screen.contains_rect(corner), "{corner:?}");
+    assert!(
+        corner.right() < 390.0 - GAP && corner.bottom() < 290.0 - GAP,
+        "Above and left of the pointer: {corner:?}"
+    );
+}
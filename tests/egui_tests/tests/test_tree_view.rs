@@ -0,0 +1,124 @@
+use egui::{Id, Key};
+use egui_extras::{TreeNode, TreeView};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// ```text
+/// a
+/// ├ a1
+/// └ a2
+///   └ a2x
+/// b
+/// c
+/// └ c1
+/// ```
+const TREE: &[(&str, usize, bool)] = &[
+    ("a", 0, true),
+    ("a1", 1, false),
+    ("a2", 1, true),
+    ("a2x", 2, false),
+    ("b", 0, false),
+    ("c", 0, true),
+    ("c1", 1, false),
+];
+
+#[derive(Default)]
+struct State {
+    shown: Vec<&'static str>,
+    selected: Option<Id>,
+}
+
+fn harness() -> Harness<'static, State> {
+    Harness::builder().build_ui_state(
+        |ui, state: &mut State| {
+            // Selectable labels would steal the clicks on the rows:
+            ui.style_mut().interaction.selectable_labels = false;
+            state.shown.clear();
+            // The whole tree, the tree view skips the descendants of closed nodes:
+            let nodes = TREE.iter().map(|&(name, depth, has_children)| {
+                TreeNode::new(Id::new(name), depth, name).has_children(has_children)
+            });
+            let response = TreeView::new("tree").show(ui, nodes, |ui, node| {
+                state.shown.push(node.item);
+                ui.label(node.item);
+            });
+            state.selected = response.selected;
+        },
+        State::default(),
+    )
+}
+
+fn press(harness: &mut Harness<'_, State>, key: Key) {
+    harness.key_press(key);
+    harness.run();
+}
+
+#[test]
+fn descendants_of_closed_nodes_are_hidden() {
+    let mut harness = harness();
+    harness.run();
+    assert_eq!(harness.state().shown, ["a", "b", "c"]);
+
+    harness.get_by_label("a").click();
+    harness.run();
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(
+        harness.state().shown,
+        ["a", "a1", "a2", "b", "c"],
+        "a2 is still closed"
+    );
+
+    press(&mut harness, Key::Enter);
+    assert_eq!(harness.state().shown, ["a", "b", "c"]);
+}
+
+#[test]
+fn arrow_keys_move_the_selection() {
+    let mut harness = harness();
+    harness.run();
+    harness.get_by_label("a").click();
+    harness.run();
+    assert_eq!(harness.state().selected, Some(Id::new("a")));
+
+    for (key, expected) in [
+        (Key::ArrowDown, "b"),
+        (Key::ArrowDown, "c"),
+        (Key::ArrowDown, "c"),
+        (Key::ArrowUp, "b"),
+        (Key::Home, "a"),
+        (Key::End, "c"),
+    ] {
+        press(&mut harness, key);
+        assert_eq!(
+            harness.state().selected,
+            Some(Id::new(expected)),
+            "{key:?} selects {expected}"
+        );
+    }
+}
+
+#[test]
+fn left_and_right_open_close_and_move() {
+    let mut harness = harness();
+    harness.run();
+    harness.get_by_label("c").click();
+    harness.run();
+
+    // Opens c:
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(harness.state().selected, Some(Id::new("c")));
+    assert_eq!(harness.state().shown, ["a", "b", "c", "c1"]);
+
+    // Moves to the first child:
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(harness.state().selected, Some(Id::new("c1")));
+
+    // Moves to the parent:
+    press(&mut harness, Key::ArrowLeft);
+    assert_eq!(harness.state().selected, Some(Id::new("c")));
+
+    // Closes c:
+    press(&mut harness, Key::ArrowLeft);
+    assert_eq!(harness.state().selected, Some(Id::new("c")));
+    assert_eq!(harness.state().shown, ["a", "b", "c"]);
+}
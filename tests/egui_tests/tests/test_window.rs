@@ -0,0 +1,257 @@
+use egui::{Context, Id, Modifiers, PointerButton, Pos2, Rect, Vec2, Window, pos2, vec2};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable as _;
+
+/// Shows the windows "Dragged" and "Other", side by side.
+fn app(snapping: Option<bool>) -> impl FnMut(&Context) {
+    move |ctx| {
+        let mut window = Window::new("Dragged")
+            .default_pos(pos2(40.0, 40.0))
+            .resizable(false);
+        if let Some(snapping) = snapping {
+            window = window.snapping(snapping);
+        }
+        window.show(ctx, |ui| ui.label("Drag me"));
+
+        Window::new("Other")
+            .default_pos(pos2(200.0, 40.0))
+            .resizable(false)
+            .show(ctx, |ui| ui.label("Snap to me"));
+    }
+}
+
+fn harness(app: impl FnMut(&Context) + 'static) -> Harness<'static> {
+    Harness::builder()
+        .with_size(vec2(800.0, 600.0))
+        .with_step_dt(0.05)
+        .build(app)
+}
+
+fn window_rect(harness: &Harness<'_>, title: &str) -> Rect {
+    harness
+        .ctx
+        .memory(|mem| mem.area_rect(Id::new(title)))
+        .expect("The window was shown")
+}
+
+/// Drag the title bar of the window by `delta`.
+fn drag_window(harness: &mut Harness<'_>, title: &str, delta: Vec2) {
+    let start = window_rect(harness, title).left_top() + vec2(30.0, 10.0);
+
+    harness.drag(start, start + delta);
+    harness.run();
+}
+
+#[test]
+fn snaps_to_screen_edge() {
+    let mut harness = harness(app(Some(true)));
+    let before = window_rect(&harness, "Dragged");
+    drag_window(&mut harness, "Dragged", vec2(5.0 - before.left(), 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 0.0);
+}
+
+#[test]
+fn snaps_to_other_window() {
+    let mut harness = harness(app(Some(true)));
+    let dragged = window_rect(&harness, "Dragged");
+    let other = window_rect(&harness, "Other");
+    assert!(
+        dragged.right() + 20.0 < other.left(),
+        "The windows start apart"
+    );
+
+    drag_window(
+        &mut harness,
+        "Dragged",
+        vec2(other.left() - dragged.right() - 6.0, 0.0),
+    );
+    assert_eq!(window_rect(&harness, "Dragged").right(), other.left());
+}
+
+#[test]
+fn snapping_can_be_enabled_in_style() {
+    let mut harness = harness(|ctx| {
+        ctx.style_mut(|style| style.interaction.window_snapping = true);
+        app(None)(ctx);
+    });
+    let before = window_rect(&harness, "Dragged");
+    drag_window(&mut harness, "Dragged", vec2(5.0 - before.left(), 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 0.0);
+}
+
+#[test]
+fn no_snapping_by_default() {
+    let mut harness = harness(app(None));
+    let before = window_rect(&harness, "Dragged");
+    drag_window(&mut harness, "Dragged", vec2(5.0 - before.left(), 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 5.0);
+}
+
+#[test]
+fn alt_disables_snapping() {
+    let mut harness = harness(app(Some(true)));
+    let before = window_rect(&harness, "Dragged");
+    harness.input_mut().modifiers = Modifiers::ALT;
+    drag_window(&mut harness, "Dragged", vec2(5.0 - before.left(), 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 5.0);
+}
+
+#[test]
+fn lets_go_when_dragged_further() {
+    let mut harness = harness(app(Some(true)));
+    let before = window_rect(&harness, "Dragged");
+    drag_window(&mut harness, "Dragged", vec2(5.0 - before.left(), 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 0.0);
+
+    let before = window_rect(&harness, "Dragged");
+    drag_window(&mut harness, "Dragged", vec2(30.0, 0.0));
+    assert_eq!(
+        window_rect(&harness, "Dragged").left(),
+        before.left() + 30.0,
+        "Follows the pointer again"
+    );
+}
+
+/// Shows the window "Big", which can be maximized.
+fn maximizable_app(ctx: &Context) {
+    Window::new("Big")
+        .maximize_button(true)
+        .default_pos(pos2(40.0, 40.0))
+        .default_size(vec2(200.0, 100.0))
+        .show(ctx, |ui| ui.label("Content"));
+}
+
+fn double_click_title_bar(harness: &mut Harness<'_>, title: &str) {
+    // Wait, so that we don't continue an earlier double-click:
+    harness.run_steps(10);
+
+    let rect = window_rect(harness, title);
+    let pos = pos2(rect.center().x, rect.top() + 10.0);
+    for _ in 0..2 {
+        harness.pointer_down(pos, PointerButton::Primary);
+        harness.step();
+        harness.pointer_up(pos, PointerButton::Primary);
+        harness.step();
+    }
+    harness.run();
+}
+
+#[test]
+fn maximize_and_restore_with_button() {
+    let mut harness = harness(maximizable_app);
+    let normal = window_rect(&harness, "Big");
+
+    harness.get_by_label("Maximize window").click();
+    harness.run();
+    assert_eq!(
+        window_rect(&harness, "Big"),
+        Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))
+    );
+
+    harness.get_by_label("Restore window").click();
+    harness.run();
+    assert_eq!(window_rect(&harness, "Big"), normal, "Restored exactly");
+}
+
+#[test]
+fn maximize_and_restore_with_double_click() {
+    let mut harness = harness(maximizable_app);
+    let normal = window_rect(&harness, "Big");
+
+    double_click_title_bar(&mut harness, "Big");
+    assert_eq!(window_rect(&harness, "Big").size(), vec2(800.0, 600.0));
+    assert!(
+        harness.query_by_label("Content").is_some(),
+        "Double-click maximizes instead of collapsing"
+    );
+
+    double_click_title_bar(&mut harness, "Big");
+    assert_eq!(window_rect(&harness, "Big"), normal);
+}
+
+#[test]
+fn maximized_window_follows_viewport_size() {
+    let mut harness = harness(maximizable_app);
+    harness.get_by_label("Maximize window").click();
+    harness.run();
+
+    harness.set_size(vec2(1000.0, 700.0));
+    harness.run();
+    assert_eq!(window_rect(&harness, "Big").size(), vec2(1000.0, 700.0));
+}
+
+#[test]
+fn dragging_maximized_window_restores_it() {
+    let mut harness = harness(maximizable_app);
+    let normal = window_rect(&harness, "Big");
+    harness.get_by_label("Maximize window").click();
+    harness.run();
+
+    // The drag starts at x = 30, i.e. near the left of the title bar:
+    drag_window(&mut harness, "Big", vec2(100.0, 50.0));
+    let restored = window_rect(&harness, "Big");
+    assert_eq!(restored.size(), normal.size());
+    assert!(harness.query_by_label("Maximize window").is_some());
+
+    // The pointer is over the same part of the title bar:
+    let pointer = pos2(130.0, 60.0);
+    let t = (pointer.x - restored.left()) / restored.width();
+    assert!((t - 30.0 / 800.0).abs() < 0.01, "t = {t}");
+    assert_eq!(pointer.y - restored.top(), 10.0);
+}
+
+#[test]
+fn constrained_to_screen_by_default() {
+    let mut harness = harness(app(None));
+    drag_window(&mut harness, "Dragged", vec2(-200.0, 0.0));
+    assert_eq!(window_rect(&harness, "Dragged").left(), 0.0);
+}
+
+#[test]
+fn constrain_margin_keeps_part_on_screen() {
+    let mut harness = harness(|ctx| {
+        Window::new("Dragged")
+            .default_pos(pos2(40.0, 40.0))
+            .resizable(false)
+            .constrain_margin(20.0)
+            .show(ctx, |ui| ui.label("Drag me"));
+    });
+    drag_window(&mut harness, "Dragged", vec2(-1000.0, -100.0));
+    let rect = window_rect(&harness, "Dragged");
+    assert_eq!(rect.right(), 20.0, "Partly off-screen");
+    assert_eq!(rect.top(), 0.0, "The title bar is always reachable");
+}
+
+/// Shows the unconstrained window "Free" at the given position.
+fn unconstrained_app(recover_offscreen: bool) -> impl FnMut(&Context) {
+    move |ctx| {
+        Window::new("Free")
+            .default_pos(pos2(600.0, 400.0))
+            .resizable(false)
+            .constrain(false)
+            .recover_offscreen(recover_offscreen)
+            .show(ctx, |ui| ui.label("Content"));
+    }
+}
+
+#[test]
+fn window_is_recovered_when_screen_shrinks() {
+    let mut harness = harness(unconstrained_app(true));
+    assert_eq!(window_rect(&harness, "Free").left_top(), pos2(600.0, 400.0));
+
+    harness.set_size(vec2(400.0, 300.0));
+    harness.run();
+    let rect = window_rect(&harness, "Free");
+    assert!(
+        Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0)).contains_rect(rect),
+        "Pulled back on screen: {rect:?}"
+    );
+}
+
+#[test]
+fn recover_offscreen_can_be_disabled() {
+    let mut harness = harness(unconstrained_app(false));
+    harness.set_size(vec2(400.0, 300.0));
+    harness.run();
+    assert_eq!(window_rect(&harness, "Free").left_top(), pos2(600.0, 400.0));
+}